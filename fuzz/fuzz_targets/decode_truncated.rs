@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use robustone::DisasmError;
+
+const ARCHITECTURES: &[&str] = &["riscv32", "riscv64", "aarch64", "x86", "x64", "loongarch64"];
+
+// Every architecture must either decode within the input or report how many
+// bytes it needed; any panic or overrun is a bug.
+fuzz_target!(|data: &[u8]| {
+    let dispatcher = robustone::shared_dispatcher();
+    for arch in ARCHITECTURES {
+        match dispatcher.disassemble_bytes(data, arch, 0) {
+            Ok((_, size)) => assert!(size > 0 && size <= data.len()),
+            Err(DisasmError::Truncated {
+                needed, available, ..
+            }) => assert!(available == data.len() && needed > available),
+            Err(_) => {}
+        }
+    }
+});
@@ -2,7 +2,7 @@
 
 use libfuzzer_sys::fuzz_target;
 use robustone_core::ir::{
-    ArchitectureId, DecodeStatus, DecodedInstruction, Operand, RegisterId, RenderHints,
+    ArchitectureId, DecodeStatus, DecodedInstruction, MemoryOrdering, Operand, RegisterId, RenderHints,
     TextRenderProfile,
 };
 use robustone_core::{
@@ -31,7 +31,7 @@ fn next_i64(data: &[u8], cursor: &mut usize) -> i64 {
 }
 
 fn next_operand(data: &[u8], cursor: &mut usize) -> Operand {
-    match next_byte(data, cursor) % 4 {
+    match next_byte(data, cursor) % 5 {
         0 => Operand::Register {
             register: next_register(data, cursor),
         },
@@ -41,6 +41,10 @@ fn next_operand(data: &[u8], cursor: &mut usize) -> Operand {
         2 => Operand::Memory {
             base: Some(next_register(data, cursor)),
             displacement: next_i64(data, cursor),
+            ordering: MemoryOrdering::RELAXED,
+        },
+        3 => Operand::SystemRegister {
+            number: u32::from(next_byte(data, cursor)) << 4,
         },
         _ => Operand::Text {
             value: match next_byte(data, cursor) % 3 {
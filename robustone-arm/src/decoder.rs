@@ -7,6 +7,9 @@ use robustone_core::{
     types::error::{DecodeErrorKind, DisasmError},
 };
 
+/// Mnemonics produced by [`AArch64Decoder`].
+pub const MNEMONICS: &[&str] = &["add", "mov", "nop", "ret"];
+
 /// Minimal AArch64 decoder.
 pub struct AArch64Decoder;
 
@@ -28,11 +31,11 @@ impl AArch64Decoder {
         addr: u64,
     ) -> Result<DecodedInstruction, DisasmError> {
         if bytes.len() < 4 {
-            return Err(DisasmError::DecodeFailure {
-                kind: DecodeErrorKind::NeedMoreBytes,
-                architecture: Some("aarch64".to_string()),
-                detail: "need 4 bytes for AArch64".to_string(),
-            });
+            return Err(DisasmError::truncated(
+                Some("aarch64".to_string()),
+                4,
+                bytes.len(),
+            ));
         }
 
         let word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
@@ -47,6 +50,7 @@ impl AArch64Decoder {
             size,
             raw_bytes: bytes[..size].to_vec(),
             operands,
+            operand_access: Vec::new(),
             registers_read: Vec::new(),
             registers_written: Vec::new(),
             implicit_registers_read: Vec::new(),
@@ -54,6 +58,7 @@ impl AArch64Decoder {
             groups: Vec::new(),
             status: DecodeStatus::Success,
             render_hints: RenderHints::default(),
+            decoded_by: None,
             render: Some(crate::render::render_aarch64_text_parts),
         })
     }
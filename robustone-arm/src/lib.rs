@@ -7,7 +7,10 @@ pub mod render;
 
 use decoder::AArch64Decoder;
 use robustone_core::{
-    Instruction, common::ArchitectureProfile, ir::DecodedInstruction, traits::ArchitectureHandler,
+    Instruction, canonical_architecture_name,
+    common::ArchitectureProfile,
+    ir::DecodedInstruction,
+    traits::{ArchitectureHandler, MnemonicGroup},
     types::error::DisasmError,
 };
 
@@ -23,6 +26,24 @@ impl ArmHandler {
             decoder: AArch64Decoder::new(),
         }
     }
+
+    /// Rejects profiles asking for an instruction set only A64 stands in
+    /// for today (Thumb, M-class, ARMv8 A32).
+    fn check_modes(profile: &ArchitectureProfile) -> Result<(), DisasmError> {
+        let modes = profile.modes;
+        let unsupported = [
+            (modes.thumb, "thumb"),
+            (modes.mclass, "m"),
+            (modes.v8, "v8"),
+        ];
+        match unsupported.iter().find(|(set, _)| *set) {
+            Some((_, modifier)) => Err(DisasmError::UnsupportedArchitecture(format!(
+                "{}+{modifier}",
+                profile.mode_name
+            ))),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Default for ArmHandler {
@@ -54,6 +75,7 @@ impl ArchitectureHandler for ArmHandler {
         profile: &ArchitectureProfile,
         addr: u64,
     ) -> Result<(DecodedInstruction, usize), DisasmError> {
+        Self::check_modes(profile)?;
         self.decode_instruction(bytes, profile.mode_name, addr)
     }
 
@@ -82,6 +104,7 @@ impl ArchitectureHandler for ArmHandler {
         profile: &ArchitectureProfile,
         addr: u64,
     ) -> Result<(Instruction, usize), DisasmError> {
+        Self::check_modes(profile)?;
         self.disassemble(bytes, profile.mode_name, addr)
     }
 
@@ -90,7 +113,17 @@ impl ArchitectureHandler for ArmHandler {
     }
 
     fn supports(&self, arch_name: &str) -> bool {
-        matches!(arch_name, "arm" | "aarch64" | "arm64" | "aarch64be")
+        matches!(
+            canonical_architecture_name(arch_name),
+            Some("arm" | "aarch64" | "aarch64be")
+        )
+    }
+
+    fn supported_mnemonics(&self, _arch_name: &str) -> Vec<MnemonicGroup> {
+        vec![MnemonicGroup {
+            name: "A64",
+            mnemonics: decoder::MNEMONICS.to_vec(),
+        }]
     }
 }
 
@@ -108,6 +141,23 @@ mod tests {
         assert_eq!(instr.mnemonic, "nop");
     }
 
+    #[test]
+    fn test_profile_mode_modifiers_reach_the_handler() {
+        let handler = ArmHandler::new();
+        let nop = [0x1F, 0x20, 0x03, 0xD5];
+        let profile = ArchitectureProfile::from_spec(&"aarch64".parse().unwrap());
+        assert!(handler.disassemble_with_profile(&nop, &profile, 0).is_ok());
+
+        let profile = ArchitectureProfile::from_spec(&"arm+thumb".parse().unwrap());
+        let error = handler
+            .decode_instruction_with_profile(&nop, &profile, 0)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            DisasmError::UnsupportedArchitecture(arch) if arch == "arm+thumb"
+        ));
+    }
+
     #[test]
     fn test_add_imm_decode() {
         let handler = ArmHandler::new();
@@ -32,13 +32,24 @@ fn format_aarch64_operand(operand: &robustone_core::ir::Operand) -> String {
             }
         }
         Operand::Text { value } => value.clone(),
-        Operand::Memory { base, displacement } => {
+        Operand::Memory {
+            base, displacement, ..
+        } => {
             if let Some(base) = base {
                 format!("[{}, #{}]", aarch64_register_name(base.id), displacement)
             } else {
                 format!("[#{}]", displacement)
             }
         }
+        // Generic `S<op0>_<op1>_C<n>_C<m>_<op2>` system register spelling.
+        Operand::SystemRegister { number } => format!(
+            "s{}_{}_c{}_c{}_{}",
+            (number >> 14) & 0x3,
+            (number >> 11) & 0x7,
+            (number >> 7) & 0xf,
+            (number >> 3) & 0xf,
+            number & 0x7
+        ),
     }
 }
 
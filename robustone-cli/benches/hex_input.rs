@@ -0,0 +1,19 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use robustone_cli::utils::parse_hex_to_bytes;
+use robustone_core::utils::hex::sample_hex_input;
+use std::hint::black_box;
+
+fn bench_hex_input(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cli_hex_input");
+    for size in [4 * 1024, 4 * 1024 * 1024] {
+        let input = sample_hex_input(size);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &input, |b, input| {
+            b.iter(|| black_box(parse_hex_to_bytes(input).unwrap()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_hex_input);
+criterion_main!(benches);
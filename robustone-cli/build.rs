@@ -0,0 +1,43 @@
+//! Records the git commit the CLI is built from for `--version`.
+//!
+//! `ROBUSTONE_GIT_HASH` in the build environment takes precedence, so
+//! packaged builds without a checkout can still report one.
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=ROBUSTONE_GIT_HASH");
+    let hash = std::env::var("ROBUSTONE_GIT_HASH").ok().or_else(git_hash);
+    if let Some(hash) = hash {
+        println!("cargo:rustc-env=ROBUSTONE_GIT_HASH={hash}");
+    }
+
+    // Rebuild when HEAD moves: on checkout, and on commit to the current branch.
+    let git_dir = Path::new("../.git");
+    let head = git_dir.join("HEAD");
+    if head.is_file() {
+        println!("cargo:rerun-if-changed={}", head.display());
+        if let Ok(contents) = std::fs::read_to_string(&head)
+            && let Some(reference) = contents.trim().strip_prefix("ref: ")
+            && git_dir.join(reference).is_file()
+        {
+            println!(
+                "cargo:rerun-if-changed={}",
+                git_dir.join(reference).display()
+            );
+        }
+    }
+}
+
+fn git_hash() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!hash.is_empty()).then_some(hash)
+}
@@ -0,0 +1,128 @@
+//! Address-keyed comments appended to listings.
+//!
+//! Annotations let users produce lightly annotated listings without
+//! post-processing the output. The formatter appends each comment as
+//! `; comment` on the line of the instruction at that address.
+//!
+//! The `--comments` file format is one comment per line:
+//!
+//! ```text
+//! # lines starting with '#' are ignored
+//! 0x80000000 reset vector
+//! 80000004: set up stack pointer
+//! ```
+
+use crate::error::{CliError, Result};
+use crate::utils::parse_address;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Free-text comments keyed by instruction address.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Annotations {
+    comments: BTreeMap<u64, Vec<String>>,
+}
+
+impl Annotations {
+    /// Create an empty annotation set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach a comment to `address`. Multiple comments on one address are
+    /// kept in insertion order.
+    pub fn insert(&mut self, address: u64, comment: impl Into<String>) {
+        self.comments
+            .entry(address)
+            .or_default()
+            .push(comment.into());
+    }
+
+    /// Builder-style variant of [`Self::insert`].
+    pub fn with_comment(mut self, address: u64, comment: impl Into<String>) -> Self {
+        self.insert(address, comment);
+        self
+    }
+
+    /// Return the comments attached to `address`.
+    pub fn get(&self, address: u64) -> &[String] {
+        self.comments
+            .get(&address)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Render the comments for `address` as a single `; ...` suffix.
+    pub fn comment_suffix(&self, address: u64) -> Option<String> {
+        let comments = self.get(address);
+        (!comments.is_empty()).then(|| format!("; {}", comments.join("; ")))
+    }
+
+    /// Check whether no comments are present.
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty()
+    }
+
+    /// Parse the textual `--comments` format.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut annotations = Self::new();
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (address, comment) = line
+                .split_once(|c: char| c == ':' || c.is_whitespace())
+                .ok_or_else(|| {
+                    CliError::parse(
+                        "comments",
+                        format!("line {}: expected `<address> <comment>`", index + 1),
+                    )
+                })?;
+            let address = parse_address(address).map_err(|error| {
+                CliError::parse("comments", format!("line {}: {error}", index + 1))
+            })?;
+            annotations.insert(address, comment.trim());
+        }
+        Ok(annotations)
+    }
+
+    /// Load annotations from a `--comments` file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| CliError::Io(format!("{}: {error}", path.display())))?;
+        Self::parse(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accepts_colon_and_space_separators() {
+        let annotations =
+            Annotations::parse("# header\n0x1000 entry point\n1004: load arg\n\n0x1000 again\n")
+                .unwrap();
+
+        assert_eq!(annotations.get(0x1000), ["entry point", "again"]);
+        assert_eq!(annotations.get(0x1004), ["load arg"]);
+        assert_eq!(
+            annotations.comment_suffix(0x1000).as_deref(),
+            Some("; entry point; again")
+        );
+        assert!(annotations.get(0x2000).is_empty());
+    }
+
+    #[test]
+    fn test_parse_reports_line_numbers() {
+        let error = Annotations::parse("0x10 ok\nzz bad address\n").unwrap_err();
+        assert!(error.to_string().contains("line 2"));
+
+        let error = Annotations::parse("0x10\n").unwrap_err();
+        assert!(error.to_string().contains("line 1"));
+    }
+}
@@ -1,9 +1,11 @@
 use std::str::FromStr;
 
 use crate::error::ParseError;
+use robustone_core::architecture::supported_modifiers;
 use robustone_core::common::ArchitectureProfile;
 use robustone_core::{
-    ArchitectureCapability, all_architecture_capabilities, lookup_architecture_capability,
+    AddressWidth, ArchSpec, ArchSpecError, ArchitectureCapability, ModeModifiers,
+    all_architecture_capabilities, lookup_architecture_capability,
 };
 
 const MODE_BIG_ENDIAN: u32 = 0x100;
@@ -45,6 +47,11 @@ impl Architecture {
         self.capability.category
     }
 
+    /// Mode strings that resolve to this architecture.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.capability.aliases
+    }
+
     pub fn all_architectures() -> Vec<Self> {
         all_architecture_capabilities()
             .iter()
@@ -52,74 +59,35 @@ impl Architecture {
             .collect()
     }
 
-    fn supports_modifier(&self, modifier: &str) -> bool {
-        match self.name() {
-            "riscv32" | "riscv64" | "riscv32e" => is_supported_riscv_modifier(modifier),
-            "arm" | "armle" | "armbe" => {
-                matches!(
-                    modifier,
-                    "thumb" | "m" | "v8" | "noregname" | "regalias" | "be" | "le"
-                )
-            }
-            "thumb" => matches!(modifier, "m" | "v8" | "noregname" | "regalias"),
-            "aarch64" | "aarch64be" => {
-                matches!(modifier, "apple" | "noregname" | "regalias" | "be" | "le")
-            }
-            "x16" | "x32" | "x64" => matches!(modifier, "att" | "intel" | "masm" | "nasm"),
-            "mips" | "mipsel" | "mips64" | "mips64el" => {
-                matches!(
-                    modifier,
-                    "nofloat" | "ptr64" | "noregname" | "nodollar" | "be" | "le"
-                )
-            }
-            "powerpc32" | "powerpc32be" | "powerpc64" | "powerpc64be" => {
-                matches!(
-                    modifier,
-                    "aix"
-                        | "booke"
-                        | "maix"
-                        | "msync"
-                        | "qpx"
-                        | "ps"
-                        | "spe"
-                        | "noregname"
-                        | "percentage"
-                        | "be"
-                        | "le"
-                )
-            }
-            "sparc" | "sparcle" | "sparc64" => matches!(modifier, "v9" | "be" | "le"),
-            "loongarch" | "loongarch64" => false,
-            _ => false,
+    /// Longest instruction encoding in bytes, used to size the listing's
+    /// bytes column the way cstool does. `None` when the ISA has no fixed
+    /// upper bound known to the CLI.
+    pub fn max_instruction_size(&self) -> Option<usize> {
+        match self.category() {
+            "x86" => Some(15),
+            "RISC-V" | "ARM" | "MIPS" | "PowerPC" | "SPARC" | "LoongArch" => Some(4),
+            _ => None,
         }
     }
 
-    fn apply_endianness_modifier(&self, modifier: &str) -> std::result::Result<Self, ParseError> {
-        let canonical_name = match (self.name(), modifier) {
-            ("arm" | "armbe" | "armle", "be") => "armbe",
-            ("arm" | "armbe" | "armle", "le") => "armle",
-            ("aarch64" | "aarch64be", "be") => "aarch64be",
-            ("aarch64" | "aarch64be", "le") => "aarch64",
-            ("mips", "be") | ("mips64", "be") | ("mipsel", "le") | ("mips64el", "le") => {
-                self.name()
-            }
-            ("mips", "le") => "mipsel",
-            ("mips64", "le") => "mips64el",
-            ("mipsel", "be") => "mips",
-            ("mips64el", "be") => "mips64",
-            ("powerpc32" | "powerpc32be", "be") => "powerpc32be",
-            ("powerpc32" | "powerpc32be", "le") => "powerpc32",
-            ("powerpc64" | "powerpc64be", "be") => "powerpc64be",
-            ("powerpc64" | "powerpc64be", "le") => "powerpc64",
-            ("sparc" | "sparcle", "be") => "sparc",
-            ("sparc" | "sparcle", "le") => "sparcle",
-            ("sparc64", "be" | "le") => "sparc64",
-            _ => return Err(ParseError::UnknownOption(modifier.to_string())),
-        };
+    /// Architecture-specific `+option` modifiers accepted after this token.
+    pub fn supported_modifiers(&self) -> &'static [&'static str] {
+        supported_modifiers(self.name())
+    }
 
-        lookup_architecture_capability(canonical_name)
-            .map(Self::new)
-            .ok_or_else(|| ParseError::UnknownArchitecture(canonical_name.to_string()))
+    /// Alignment in bytes every instruction address must satisfy (RISC-V's
+    /// IALIGN assuming the C extension). `1` when there is no constraint.
+    pub fn instruction_alignment(&self) -> u64 {
+        match self.category() {
+            "RISC-V" => 2,
+            "ARM" | "MIPS" | "PowerPC" | "SPARC" | "LoongArch" => 4,
+            _ => 1,
+        }
+    }
+
+    /// Address space width; addresses wrap at its end.
+    pub fn address_width(&self) -> AddressWidth {
+        self.capability.address_width()
     }
 
     pub fn default_mode(&self) -> u32 {
@@ -146,49 +114,46 @@ impl FromStr for Architecture {
 pub struct ArchitectureSpec {
     pub arch: Architecture,
     pub mode: u32,            // Capstone mode bitmask
+    pub modes: ModeModifiers, // Decoder mode switches (endianness, thumb, ...)
     pub options: Vec<String>, // Architecture-specific option modifiers
 }
 
 impl ArchitectureSpec {
     /// Parses an architecture string, supporting `+`-separated modifiers.
+    ///
+    /// Parsing itself is shared with the core crate through [`ArchSpec`].
     pub fn parse(input: &str) -> std::result::Result<Self, ParseError> {
-        if input.trim().is_empty() {
-            return Err(ParseError::EmptyInput);
-        }
-
-        let parts: Vec<&str> = input.split('+').collect();
-        if parts.is_empty() {
-            return Err(ParseError::EmptyInput);
-        }
-
-        let mut arch = Architecture::from_str(parts[0])
-            .map_err(|_| ParseError::UnknownArchitecture(parts[0].to_string()))?;
-
-        let mut mode = arch.default_mode();
-        let mut options = Vec::new();
-
-        for modifier in &parts[1..] {
-            let canonical_modifier = normalize_modifier(modifier);
-            if !arch.supports_modifier(&canonical_modifier) {
-                return Err(ParseError::UnknownOption(modifier.to_string()));
-            }
-
-            if matches!(canonical_modifier.as_str(), "be" | "le") {
-                arch = arch.apply_endianness_modifier(&canonical_modifier)?;
-                mode = arch.default_mode() | endianness_mode_bits(&canonical_modifier);
-                continue;
-            }
-
-            options.push(canonical_modifier);
-        }
+        let spec = ArchSpec::parse(input).map_err(|error| match error {
+            ArchSpecError::Empty => ParseError::EmptyInput,
+            ArchSpecError::UnknownArchitecture(name) => ParseError::UnknownArchitecture(name),
+            ArchSpecError::UnknownOption(option) => ParseError::UnknownOption(option),
+        })?;
+        let arch = Architecture::new(spec.capability);
+        let modes = spec.modes();
+        let endianness = if spec.big_endian { MODE_BIG_ENDIAN } else { 0 };
 
         Ok(ArchitectureSpec {
             arch,
-            mode,
-            options,
+            mode: arch.default_mode() | endianness,
+            modes,
+            options: spec.options,
         })
     }
 
+    /// The profile handed to the handlers' `*_with_profile` methods: the
+    /// RISC-V extension profile where there is one, plus the mode modifiers.
+    pub fn profile(&self) -> ArchitectureProfile {
+        self.riscv_profile()
+            .unwrap_or_else(|| {
+                ArchitectureProfile::from_spec(&ArchSpec {
+                    capability: self.arch.capability,
+                    big_endian: self.is_big_endian(),
+                    options: self.options.clone(),
+                })
+            })
+            .with_modes(self.modes)
+    }
+
     pub fn riscv_profile(&self) -> Option<ArchitectureProfile> {
         let arch_name = self.arch.name();
         if !matches!(arch_name, "riscv32" | "riscv64") {
@@ -203,11 +168,13 @@ impl ArchitectureSpec {
         if !has_extension_modifier {
             // No extension modifiers: use the default GC profile for backward
             // compatibility and parity with Capstone's default RISC-V behavior.
-            return Some(match arch_name {
+            let mut profile = match arch_name {
                 "riscv32" => ArchitectureProfile::riscv32gc(),
                 "riscv64" => ArchitectureProfile::riscv64gc(),
                 _ => unreachable!(),
-            });
+            };
+            self.add_optional_extensions(&mut profile);
+            return Some(profile);
         }
 
         // When explicit extension modifiers are present, build the profile
@@ -256,18 +223,47 @@ impl ArchitectureSpec {
                 "noalias" | "noaliascompressed" => {
                     // Display-only modifiers; do not affect the extension set.
                 }
+                "v" | "zabha" | "zacas" => {
+                    // Added on top of the baseline by `add_optional_extensions`.
+                }
                 _ => {}
             }
         }
 
+        self.add_optional_extensions(&mut profile);
         profile.enabled_extensions.sort_unstable();
         profile.enabled_extensions.dedup();
         Some(profile)
     }
 
+    /// Add V, Zabha, and Zacas for `+v`/`+zabha`/`+zacas`. They extend
+    /// whichever baseline the other modifiers chose and bring in the
+    /// extensions they build on (F and D for V, A for the atomics).
+    fn add_optional_extensions(&self, profile: &mut ArchitectureProfile) {
+        let optional: [(&str, &[&str]); 3] = [
+            ("v", &["F", "D", "V"]),
+            ("zabha", &["A", "Zabha"]),
+            ("zacas", &["A", "Zacas"]),
+        ];
+        for (option, extensions) in optional {
+            if !self.has_option(option) {
+                continue;
+            }
+            for &name in extensions {
+                if !profile.enabled_extensions.contains(&name) {
+                    profile.enabled_extensions.push(name);
+                }
+            }
+        }
+    }
+
     pub fn has_option(&self, option: &str) -> bool {
         self.options.iter().any(|candidate| candidate == option)
     }
+
+    pub fn is_big_endian(&self) -> bool {
+        self.mode & MODE_BIG_ENDIAN != 0
+    }
 }
 
 impl std::fmt::Debug for ArchitectureSpec {
@@ -282,6 +278,54 @@ impl std::fmt::Debug for ArchitectureSpec {
     }
 }
 
+/// Fix to suggest for an `<arch+mode>` string that does not parse: the
+/// closest known architecture name for a typo in the base token (or a
+/// pointer to `--archs`), or the accepted modifiers for an unknown `+option`.
+pub fn suggest_architecture(input: &str) -> Option<String> {
+    let mut parts = input.split('+');
+    let base = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+    match Architecture::parse(&base) {
+        Ok(arch) => {
+            let modifiers = arch.supported_modifiers();
+            if parts.all(|modifier| ArchitectureSpec::parse(&format!("{base}+{modifier}")).is_ok())
+            {
+                return None;
+            }
+            Some(if modifiers.is_empty() {
+                format!("`{}` takes no `+` modifiers", arch.name())
+            } else {
+                format!("`{}` accepts +{}", arch.name(), modifiers.join(", +"))
+            })
+        }
+        Err(_) => all_architecture_capabilities()
+            .iter()
+            .flat_map(|capability| capability.aliases.iter())
+            .map(|alias| (edit_distance(&base, alias), *alias))
+            .filter(|(distance, _)| *distance <= 2)
+            .min()
+            .map_or_else(
+                || Some("run `robustone --archs` for the supported names".to_string()),
+                |(_, alias)| Some(format!("did you mean `{alias}`?")),
+            ),
+    }
+}
+
+/// Levenshtein distance between two short ASCII strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, &ca) in a.as_bytes().iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
 fn supported_architecture_list() -> String {
     all_architecture_capabilities()
         .iter()
@@ -289,27 +333,3 @@ fn supported_architecture_list() -> String {
         .collect::<Vec<_>>()
         .join(", ")
 }
-
-fn endianness_mode_bits(modifier: &str) -> u32 {
-    match modifier {
-        "be" => MODE_BIG_ENDIAN,
-        _ => 0,
-    }
-}
-
-fn is_supported_riscv_modifier(modifier: &str) -> bool {
-    matches!(
-        modifier,
-        "a" | "c" | "fd" | "f" | "d" | "m" | "noalias" | "noaliascompressed"
-    )
-}
-
-fn normalize_modifier(modifier: &str) -> String {
-    match modifier.to_lowercase().as_str() {
-        "at&t" => "att".to_string(),
-        "micro" => "m".to_string(),
-        "big" => "be".to_string(),
-        "little" => "le".to_string(),
-        canonical => canonical.to_string(),
-    }
-}
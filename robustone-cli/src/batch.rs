@@ -0,0 +1,303 @@
+//! Batch mode (`robustone batch jobs.toml`).
+//!
+//! Runs every job of a TOML manifest in one process so regression suites do
+//! not have to spawn one CLI process per input:
+//!
+//! ```toml
+//! [[job]]
+//! name = "boot"              # optional, used in error messages
+//! arch = "riscv64"
+//! file = "boot.bin"          # raw bytes; use `hex = "93001000"` for inline input
+//! address = 0x80000000       # number or hexadecimal string, default 0
+//! output = "out/boot.txt"
+//! format = "text"            # optional: text, json, gdb, gas
+//! ```
+//!
+//! Relative `file` and `output` paths are resolved against the manifest's
+//! directory. A failing job, whatever its format, writes no output and does
+//! not stop the others; failures are reported on stderr once every job has
+//! run and make the batch exit non-zero.
+
+use crate::command::OutputFormat;
+use crate::compressed::read_input;
+use crate::config::DisasmConfig;
+use crate::error::{CliError, Result, exit_code};
+use crate::executor::CliExecutor;
+use crate::output::OutputTarget;
+use crate::utils::parse_address;
+
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Parsed batch manifest.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchManifest {
+    #[serde(default, rename = "job")]
+    pub jobs: Vec<BatchJob>,
+}
+
+/// One `[[job]]` entry of a batch manifest.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchJob {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub arch: String,
+    #[serde(default)]
+    pub hex: Option<String>,
+    #[serde(default)]
+    pub file: Option<PathBuf>,
+    #[serde(default)]
+    pub address: Option<toml::Value>,
+    pub output: PathBuf,
+    #[serde(default)]
+    pub format: OutputFormat,
+}
+
+/// Outcome of one job, in manifest order.
+#[derive(Debug)]
+pub struct JobOutcome {
+    /// Zero-based position of the job in the manifest.
+    pub index: usize,
+    /// Job name, or `#<n>` (one-based) when the manifest gives none.
+    pub label: String,
+    pub result: Result<()>,
+}
+
+impl BatchManifest {
+    /// Read and parse the manifest at `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| CliError::Io(format!("cannot read `{}`: {error}", path.display())))?;
+        Self::parse(&text)
+    }
+
+    /// Parse manifest text.
+    pub fn parse(text: &str) -> Result<Self> {
+        toml::from_str(text).map_err(|error| CliError::parse("batch manifest", error.to_string()))
+    }
+}
+
+impl BatchJob {
+    fn label(&self, index: usize) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("#{}", index + 1))
+    }
+
+    /// Disassemble this job and write its listing, resolving paths against `base`.
+    pub fn run(&self, base: &Path) -> Result<()> {
+        let builder = DisasmConfig::builder()
+            .arch(self.arch.clone())
+            .address(self.address()?)
+            .format(self.format);
+        let builder = match (&self.hex, &self.file) {
+            (Some(hex), None) => builder.hex(hex.clone()),
+            (None, Some(file)) => {
+                let path = base.join(file);
+                builder.bytes(read_input(&path)?)
+            }
+            (Some(_), Some(_)) => {
+                return Err(CliError::validation(
+                    "hex",
+                    "provide either `hex` or `file`, not both",
+                ));
+            }
+            (None, None) => return Err(CliError::MissingArgument("hex".to_string())),
+        };
+
+        let output = CliExecutor::new().try_execute_to_string(&builder.build()?)?;
+        let path = base.join(&self.output);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|error| {
+                CliError::Io(format!("cannot create `{}`: {error}", parent.display()))
+            })?;
+        }
+        OutputTarget::File(path).emit(&output)
+    }
+
+    fn address(&self) -> Result<u64> {
+        match &self.address {
+            None => Ok(0),
+            Some(toml::Value::Integer(value)) => u64::try_from(*value).map_err(|_| {
+                CliError::validation("address", "address must be a non-negative integer")
+            }),
+            Some(toml::Value::String(text)) => parse_address(text),
+            Some(_) => Err(CliError::validation(
+                "address",
+                "address must be a number or a hexadecimal string",
+            )),
+        }
+    }
+}
+
+/// Run every job of `manifest`, using up to `parallelism` worker threads.
+///
+/// Outcomes are returned in manifest order regardless of completion order.
+pub fn run_jobs(manifest: &BatchManifest, base: &Path, parallelism: usize) -> Vec<JobOutcome> {
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(Vec::with_capacity(manifest.jobs.len()));
+    let workers = parallelism.clamp(1, manifest.jobs.len().max(1));
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(job) = manifest.jobs.get(index) else {
+                        break;
+                    };
+                    let outcome = JobOutcome {
+                        index,
+                        label: job.label(index),
+                        result: job.run(base),
+                    };
+                    outcomes
+                        .lock()
+                        .expect("batch outcome lock should not be poisoned")
+                        .push(outcome);
+                }
+            });
+        }
+    });
+
+    let mut outcomes = outcomes
+        .into_inner()
+        .expect("batch outcome lock should not be poisoned");
+    outcomes.sort_by_key(|outcome| outcome.index);
+    outcomes
+}
+
+/// Entry point for `robustone batch`.
+pub fn run_batch(manifest_path: &Path, parallelism: usize) -> Result<()> {
+    let manifest = BatchManifest::load(manifest_path)?;
+    let base = manifest_path.parent().unwrap_or(Path::new(""));
+
+    let outcomes = run_jobs(&manifest, base, parallelism);
+    let failed = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.result.as_ref().err().map(|error| (outcome, error)))
+        .inspect(|(outcome, error)| {
+            eprintln!("robustone: batch job {} failed: {error}", outcome.label);
+        })
+        .count();
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        eprintln!(
+            "robustone: {failed} of {} batch jobs failed",
+            outcomes.len()
+        );
+        Err(CliError::reported(exit_code::FAILURE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_parses_jobs_with_defaults() {
+        let manifest = BatchManifest::parse(
+            r#"
+            [[job]]
+            arch = "riscv32"
+            hex = "93001000"
+            output = "a.txt"
+
+            [[job]]
+            name = "fw"
+            arch = "riscv64"
+            file = "fw.bin"
+            address = "0x1000"
+            output = "fw.s"
+            format = "gas"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.jobs.len(), 2);
+        assert_eq!(manifest.jobs[0].address().unwrap(), 0);
+        assert_eq!(manifest.jobs[0].format, OutputFormat::Text);
+        assert_eq!(manifest.jobs[1].address().unwrap(), 0x1000);
+        assert_eq!(manifest.jobs[1].format, OutputFormat::Gas);
+        assert!(BatchManifest::parse("[[job]]\narch = \"riscv32\"\nbogus = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_run_jobs_writes_outputs_and_keeps_going_after_failures() {
+        let dir = std::env::temp_dir().join(format!("robustone-batch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("code.bin"), [0x13, 0x00, 0x00, 0x00]).unwrap();
+        let manifest = BatchManifest::parse(
+            r#"
+            [[job]]
+            arch = "riscv32"
+            hex = "93001000"
+            address = 0x1000
+            output = "inline.txt"
+
+            [[job]]
+            name = "broken"
+            arch = "riscv32"
+            hex = "zz"
+            output = "broken.txt"
+
+            [[job]]
+            arch = "riscv32"
+            file = "code.bin"
+            output = "file.txt"
+
+            [[job]]
+            name = "broken-json"
+            arch = "riscv32"
+            hex = "zz"
+            output = "broken.json"
+            format = "json"
+            "#,
+        )
+        .unwrap();
+
+        let outcomes = run_jobs(&manifest, &dir, 2);
+        let labels: Vec<_> = outcomes
+            .iter()
+            .map(|outcome| outcome.label.as_str())
+            .collect();
+        assert_eq!(labels, ["#1", "broken", "#3", "broken-json"]);
+        assert!(outcomes[0].result.is_ok());
+        assert!(outcomes[1].result.is_err());
+        assert!(outcomes[2].result.is_ok());
+        assert!(outcomes[3].result.is_err());
+        assert!(!dir.join("broken.json").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("inline.txt")).unwrap(),
+            "1000    li\tra, 1\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join("file.txt")).unwrap(),
+            "0    addi\tzero, zero, 0\n"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    #[test]
+    fn test_run_batch_exits_non_zero_for_a_failing_json_job() {
+        let dir = std::env::temp_dir().join(format!("robustone-batch-json-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let manifest = dir.join("jobs.toml");
+        std::fs::write(
+            &manifest,
+            "[[job]]\narch = \"riscv32\"\nhex = \"zz\"\noutput = \"out.json\"\nformat = \"json\"\n",
+        )
+        .unwrap();
+
+        let error = run_batch(&manifest, 1).unwrap_err();
+        assert_eq!(error.exit_code(), exit_code::FAILURE);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,4 +1,10 @@
-use robustone_core::{ArchitectureCapability, all_architecture_capabilities};
+use crate::arch::{Architecture, ArchitectureSpec};
+use crate::disasm::DisassemblyEngine;
+use crate::error::{CliError, Result};
+
+use robustone_core::{
+    ArchitectureCapability, MnemonicGroup, RegisterInfo, all_architecture_capabilities,
+};
 use serde::Serialize;
 use std::fmt::Write;
 
@@ -112,6 +118,268 @@ pub fn render_capabilities_json() -> String {
         .expect("serializing capability report should succeed")
 }
 
+/// One row of the `--archs` listing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArchitectureListing {
+    pub name: &'static str,
+    pub category: &'static str,
+    pub modes: &'static [&'static str],
+    pub options: Vec<String>,
+    pub status: &'static str,
+}
+
+/// Collect every registered architecture with its accepted mode strings and
+/// `+option` modifiers.
+pub fn architecture_listing() -> Vec<ArchitectureListing> {
+    Architecture::all_architectures()
+        .into_iter()
+        .map(|arch| ArchitectureListing {
+            name: arch.name(),
+            category: arch.category(),
+            modes: arch.aliases(),
+            options: arch
+                .supported_modifiers()
+                .iter()
+                .map(|option| format!("+{option}"))
+                .collect(),
+            status: status_label(arch.is_implemented()),
+        })
+        .collect()
+}
+
+/// Render the cstool-style `<arch+mode>` listing shown by `--archs`.
+pub fn render_architectures_text() -> String {
+    let listing = architecture_listing();
+    let width = listing.iter().map(|row| row.name.len()).max().unwrap_or(0);
+    let mut output = String::new();
+
+    writeln!(
+        output,
+        "Syntax: robustone [OPTIONS] <arch+mode> <hex-code> [start-address]"
+    )
+    .expect("writing archs syntax line should succeed");
+    writeln!(output).expect("writing blank separator should succeed");
+    writeln!(output, "The following <arch+mode> options are supported:")
+        .expect("writing archs header should succeed");
+
+    for row in &listing {
+        writeln!(
+            output,
+            "        {:<width$}  {} ({})",
+            row.name, row.category, row.status
+        )
+        .expect("writing archs row should succeed");
+        let extra_modes = row
+            .modes
+            .iter()
+            .filter(|mode| **mode != row.name)
+            .copied()
+            .collect::<Vec<_>>();
+        if !extra_modes.is_empty() {
+            writeln!(
+                output,
+                "        {:<width$}    modes: {}",
+                "",
+                extra_modes.join(", ")
+            )
+            .expect("writing archs modes should succeed");
+        }
+        if !row.options.is_empty() {
+            writeln!(
+                output,
+                "        {:<width$}    options: {}",
+                "",
+                row.options.join(" ")
+            )
+            .expect("writing archs options should succeed");
+        }
+    }
+
+    writeln!(output).expect("writing blank separator should succeed");
+    write!(
+        output,
+        "Options are appended to the mode with '+', for example `riscv64+noalias` or `x64+att`."
+    )
+    .expect("writing archs footer should succeed");
+    output
+}
+
+/// Render the `--archs` listing as JSON.
+pub fn render_architectures_json() -> String {
+    serde_json::to_string_pretty(&architecture_listing())
+        .expect("serializing architecture listing should succeed")
+}
+
+/// Decodable mnemonics for one architecture, as printed by `--list-insns`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MnemonicListing {
+    pub architecture: &'static str,
+    pub total: usize,
+    pub groups: Vec<MnemonicListingGroup>,
+}
+
+/// One extension's worth of mnemonics in a [`MnemonicListing`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MnemonicListingGroup {
+    pub name: &'static str,
+    pub mnemonics: Vec<&'static str>,
+}
+
+/// Collect the mnemonics the registered handler for `arch` can decode.
+pub fn mnemonic_listing(arch: &str) -> Result<MnemonicListing> {
+    let spec = ArchitectureSpec::parse(arch)
+        .map_err(|e| CliError::parse("architecture", e.to_string()))?;
+    let name = spec.arch.name();
+    let groups = DisassemblyEngine::new(name)
+        .supported_mnemonics(&spec.profile())
+        .ok_or_else(|| {
+            CliError::Architecture(format!(
+                "no decoder is registered for '{name}'; run `robustone --capabilities` to see decode-ready architectures"
+            ))
+        })?
+        .into_iter()
+        .filter(|group| !group.mnemonics.is_empty())
+        .map(|MnemonicGroup { name, mnemonics }| MnemonicListingGroup { name, mnemonics })
+        .collect::<Vec<_>>();
+
+    Ok(MnemonicListing {
+        architecture: name,
+        total: groups.iter().map(|group| group.mnemonics.len()).sum(),
+        groups,
+    })
+}
+
+/// Render the `--list-insns` listing as wrapped text, one block per extension.
+pub fn render_mnemonics_text(listing: &MnemonicListing) -> String {
+    const WIDTH: usize = 78;
+    let mut output = String::new();
+
+    writeln!(
+        output,
+        "Decodable mnemonics for {} ({} total)",
+        listing.architecture, listing.total
+    )
+    .expect("writing mnemonic header should succeed");
+
+    for group in &listing.groups {
+        writeln!(output).expect("writing blank separator should succeed");
+        writeln!(output, "{} ({}):", group.name, group.mnemonics.len())
+            .expect("writing mnemonic group header should succeed");
+
+        let mut line = String::new();
+        for mnemonic in &group.mnemonics {
+            if !line.is_empty() && line.len() + 1 + mnemonic.len() > WIDTH {
+                writeln!(output, "{line}").expect("writing mnemonic line should succeed");
+                line.clear();
+            }
+            line.push_str(if line.is_empty() { "  " } else { " " });
+            line.push_str(mnemonic);
+        }
+        if !line.is_empty() {
+            writeln!(output, "{line}").expect("writing mnemonic line should succeed");
+        }
+    }
+
+    output.trim_end().to_string()
+}
+
+/// Render the `--list-insns` listing as JSON.
+pub fn render_mnemonics_json(listing: &MnemonicListing) -> String {
+    serde_json::to_string_pretty(listing).expect("serializing mnemonic listing should succeed")
+}
+
+/// Registers of one architecture, as printed by `--list-regs`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegisterListing {
+    pub architecture: &'static str,
+    pub registers: Vec<RegisterListingEntry>,
+}
+
+/// One register in a [`RegisterListing`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegisterListingEntry {
+    pub id: u32,
+    pub name: &'static str,
+    pub class: &'static str,
+    pub width: u16,
+    pub aliases: Vec<&'static str>,
+}
+
+/// Collect the registers published by the handler for `arch`.
+pub fn register_listing(arch: &str) -> Result<RegisterListing> {
+    let spec = ArchitectureSpec::parse(arch)
+        .map_err(|e| CliError::parse("architecture", e.to_string()))?;
+    let name = spec.arch.name();
+    let registers = DisassemblyEngine::new(name)
+        .registers(name)
+        .ok_or_else(|| {
+            CliError::Architecture(format!(
+                "no decoder is registered for '{name}'; run `robustone --capabilities` to see decode-ready architectures"
+            ))
+        })?
+        .into_iter()
+        .map(
+            |RegisterInfo {
+                 id,
+                 name,
+                 aliases,
+                 class,
+                 width,
+             }| RegisterListingEntry {
+                id,
+                name,
+                class: class.as_str(),
+                width,
+                aliases,
+            },
+        )
+        .collect();
+
+    Ok(RegisterListing {
+        architecture: name,
+        registers,
+    })
+}
+
+/// Render the `--list-regs` listing as an aligned table.
+pub fn render_registers_text(listing: &RegisterListing) -> String {
+    let mut output = String::new();
+
+    writeln!(
+        output,
+        "Registers for {} ({} total)",
+        listing.architecture,
+        listing.registers.len()
+    )
+    .expect("writing register header should succeed");
+    writeln!(output).expect("writing blank separator should succeed");
+    writeln!(
+        output,
+        "{:>6}  {:<14} {:<6} {:>5}  aliases",
+        "id", "name", "class", "width"
+    )
+    .expect("writing register column header should succeed");
+
+    for register in &listing.registers {
+        let line = format!(
+            "{:>6}  {:<14} {:<6} {:>5}  {}",
+            register.id,
+            register.name,
+            register.class,
+            register.width,
+            register.aliases.join(", ")
+        );
+        writeln!(output, "{}", line.trim_end()).expect("writing register line should succeed");
+    }
+
+    output.trim_end().to_string()
+}
+
+/// Render the `--list-regs` listing as JSON.
+pub fn render_registers_json(listing: &RegisterListing) -> String {
+    serde_json::to_string_pretty(listing).expect("serializing register listing should succeed")
+}
+
 pub fn parser_only_configuration_message(
     input_token: &str,
     capability: &ArchitectureCapability,
@@ -130,7 +398,11 @@ pub fn parser_only_configuration_message(
 }
 
 fn capability_status(capability: &ArchitectureCapability) -> &'static str {
-    if capability.decode_supported {
+    status_label(capability.decode_supported)
+}
+
+fn status_label(decode_supported: bool) -> &'static str {
+    if decode_supported {
         "decode-ready"
     } else {
         "parser-only"
@@ -189,6 +461,86 @@ mod tests {
         assert!(parsed["architectures"][1]["aliases"].is_array());
     }
 
+    #[test]
+    fn test_architecture_listing_includes_modes_and_options() {
+        let listing = architecture_listing();
+        assert_eq!(listing.len(), all_architecture_capabilities().len());
+
+        let riscv64 = listing.iter().find(|row| row.name == "riscv64").unwrap();
+        assert!(riscv64.modes.contains(&"riscv"));
+        assert!(riscv64.options.iter().any(|option| option == "+noalias"));
+
+        let text = render_architectures_text();
+        assert!(text.contains("The following <arch+mode> options are supported:"));
+        assert!(text.contains("options: +att +intel +masm +nasm"));
+
+        let parsed: Value = serde_json::from_str(&render_architectures_json()).unwrap();
+        assert_eq!(parsed[0]["name"], "riscv32");
+        assert_eq!(parsed[0]["status"], "decode-ready");
+    }
+
+    #[test]
+    fn test_mnemonic_listing_groups_by_extension() {
+        let listing = mnemonic_listing("riscv64").unwrap();
+        let names = listing
+            .groups
+            .iter()
+            .map(|group| group.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["I", "A", "M", "F", "D", "C"]);
+        assert!(
+            listing.groups[0].mnemonics.contains(&"addi"),
+            "base integer group should list addi"
+        );
+
+        let text = render_mnemonics_text(&listing);
+        assert!(text.starts_with("Decodable mnemonics for riscv64"));
+        assert!(text.contains("c.addiw"));
+        assert!(!text.contains("th.mveqz"));
+
+        let rv32 = mnemonic_listing("riscv32").unwrap();
+        assert!(
+            rv32.groups
+                .iter()
+                .all(|group| !group.mnemonics.contains(&"ld"))
+        );
+        let zacas = mnemonic_listing("riscv32+zacas").unwrap();
+        assert!(zacas.groups.iter().any(|group| group.name == "Zacas"));
+
+        let parsed: Value = serde_json::from_str(&render_mnemonics_json(&listing)).unwrap();
+        assert_eq!(parsed["total"], listing.total);
+    }
+
+    #[test]
+    fn test_mnemonic_listing_rejects_parser_only_architecture() {
+        let error = mnemonic_listing("mips").unwrap_err();
+        assert!(matches!(error, CliError::Architecture(_)));
+        assert!(mnemonic_listing("bogus").is_err());
+    }
+
+    #[test]
+    fn test_register_listing_follows_xlen() {
+        let rv32 = register_listing("riscv32").unwrap();
+        let s0 = rv32
+            .registers
+            .iter()
+            .find(|register| register.name == "s0")
+            .unwrap();
+        assert_eq!((s0.id, s0.class, s0.width), (8, "gpr", 32));
+        assert_eq!(s0.aliases, ["x8", "fp"]);
+
+        let rv64 = register_listing("riscv64").unwrap();
+        assert_eq!(rv64.registers[8].width, 64);
+
+        let text = render_registers_text(&rv64);
+        assert!(text.starts_with("Registers for riscv64"));
+        assert!(text.contains("mstatus"));
+
+        let parsed: Value = serde_json::from_str(&render_registers_json(&rv64)).unwrap();
+        assert_eq!(parsed["registers"][0]["name"], "zero");
+        assert!(register_listing("mips").is_err());
+    }
+
     #[test]
     fn test_parser_only_configuration_message_points_to_capability_surface() {
         let capability = all_architecture_capabilities()
@@ -0,0 +1,163 @@
+//! Region classification mode (`--classify[=SIZE]`).
+//!
+//! Splits the input into regions of `SIZE` bytes and prints, for each, its
+//! entropy, how much of it decodes for the selected architecture, and a
+//! guess at what it holds:
+//!
+//! ```text
+//! address     size  entropy  decoded  class
+//! 0x00000000  4096     5.91      98%  code
+//! 0x00001000  4096     0.00     100%  data
+//! 0x00002000  4096     7.95      80%  encrypted
+//! ```
+
+use crate::config::DisasmConfig;
+use crate::disasm::DisassemblyEngine;
+use crate::error::Result;
+
+use robustone_core::{DataStep, OnDecodeError, RegionReport, classify_regions, is_data_directive};
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Serialize)]
+struct ClassifyReport<'a> {
+    architecture: &'a str,
+    start_address: u64,
+    region_size: usize,
+    regions: Vec<RegionEntry>,
+}
+
+#[derive(Serialize)]
+struct RegionEntry {
+    address: u64,
+    #[serde(flatten)]
+    report: RegionReport,
+}
+
+/// Classify the configured input in `region_size` regions.
+pub fn classify_input(config: &DisasmConfig, region_size: usize) -> Vec<RegionReport> {
+    let engine = DisassemblyEngine::new(config.arch_name());
+    classify_regions(&config.hex_bytes, region_size, |region| {
+        decoded_fraction(&engine, config, region)
+    })
+}
+
+/// Render the classification as a table, or as JSON when `json` is set.
+pub fn render_classification(
+    config: &DisasmConfig,
+    region_size: usize,
+    json: bool,
+) -> Result<String> {
+    let reports = classify_input(config, region_size);
+    let address_of = |report: &RegionReport| {
+        config
+            .arch_spec
+            .arch
+            .address_width()
+            .offset(config.start_address, report.offset as i64)
+    };
+
+    if json {
+        let report = ClassifyReport {
+            architecture: config.arch_name(),
+            start_address: config.start_address,
+            region_size,
+            regions: reports
+                .into_iter()
+                .map(|report| RegionEntry {
+                    address: address_of(&report),
+                    report,
+                })
+                .collect(),
+        };
+        let mut output = serde_json::to_string_pretty(&report)
+            .expect("serializing a classification report should succeed");
+        output.push('\n');
+        return Ok(output);
+    }
+
+    let mut output = String::from("address     size  entropy  decoded  class\n");
+    for report in &reports {
+        let _ = writeln!(
+            output,
+            "0x{:08x}  {:>4}  {:>7.2}  {:>6.0}%  {}",
+            address_of(report),
+            report.len,
+            report.entropy,
+            report.coverage * 100.0,
+            report.class
+        );
+    }
+    Ok(output)
+}
+
+/// Fraction of `region` covered by decoded instructions in a SKIPDATA sweep.
+fn decoded_fraction(engine: &DisassemblyEngine, config: &DisasmConfig, region: &[u8]) -> f64 {
+    if region.is_empty() {
+        return 0.0;
+    }
+    let region_config = DisasmConfig {
+        hex_bytes: region.to_vec(),
+        on_decode_error: OnDecodeError::Emit(DataStep::Resync),
+        ..config.clone()
+    };
+    let Ok(result) = engine.disassemble(&region_config) else {
+        return 0.0;
+    };
+    let decoded: usize = result
+        .instructions
+        .iter()
+        .filter(|instruction| !is_data_directive(&instruction.mnemonic))
+        .map(|instruction| instruction.size)
+        .sum();
+    decoded as f64 / region.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use robustone_core::RegionClass;
+
+    fn config(arch: &str, bytes: Vec<u8>) -> DisasmConfig {
+        let mut config = DisasmConfig::builder()
+            .arch(arch)
+            .hex("0000")
+            .address(0x8000_0000)
+            .build()
+            .unwrap();
+        config.hex_bytes = bytes;
+        config
+    }
+
+    #[test]
+    fn test_code_and_padding_are_told_apart() {
+        // addi a0, a0, 1; add a1, a1, a0; sw a1, 0(sp); lw a2, 4(sp), repeated
+        let code = hex::decode("130515003385a500 23a0b100 03264100".replace(' ', "")).unwrap();
+        let mut bytes: Vec<u8> = code.iter().copied().cycle().take(256).collect();
+        bytes.extend(std::iter::repeat_n(0xff, 256));
+
+        let reports = classify_input(&config("riscv32", bytes), 256);
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].class, RegionClass::Code);
+        assert_eq!(reports[0].coverage, 1.0);
+        assert_eq!(reports[1].class, RegionClass::Data);
+        assert_eq!(reports[1].coverage, 0.0);
+    }
+
+    #[test]
+    fn test_report_lists_region_addresses() {
+        let output = render_classification(&config("riscv32", vec![0xff; 96]), 64, false).unwrap();
+        assert_eq!(
+            output,
+            "address     size  entropy  decoded  class\n\
+             0x80000000    64     0.00       0%  data\n\
+             0x80000040    32     0.00       0%  data\n"
+        );
+
+        let json = render_classification(&config("riscv32", vec![0xff; 96]), 64, true).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["regions"][1]["address"], 0x8000_0040u64);
+        assert_eq!(parsed["regions"][1]["offset"], 64);
+        assert_eq!(parsed["regions"][1]["class"], "data");
+    }
+}
@@ -1,8 +1,21 @@
+use crate::arch::suggest_architecture;
+use crate::config::{
+    AddressDisplay, ByteGrouping, ColumnLayout, DisasmConfig, parse_decodable_arch_spec,
+};
 use crate::error::{CliError, Result};
-use crate::utils::validate_architecture_legacy as validate_architecture;
-use crate::utils::{parse_address_legacy, parse_hex_code_legacy};
-use clap::{CommandFactory, Parser};
+use crate::limits::ResourceLimits;
+use crate::output::OutputTarget;
+use crate::utils::parse_timeout;
+use crate::utils::{
+    STDIN_HEX_CODE, parse_address_legacy, parse_addressed_hex, parse_hex_code_legacy,
+};
+use clap::builder::TypedValueParser;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use robustone_core::NopStyle;
 use robustone_core::all_architecture_capabilities;
+use robustone_core::ir::TextRenderProfile;
+use serde::Deserialize;
+use std::time::Duration;
 
 /// Robustone - Capstone-compatible disassembly engine CLI tool (cstool style)
 #[derive(Parser, Debug)]
@@ -11,23 +24,28 @@ use robustone_core::all_architecture_capabilities;
     about = "Robustone - Capstone-compatible disassembly engine CLI tool",
     version = clap::crate_version!(),
     author = clap::crate_authors!(),
-    disable_version_flag = true
+    disable_version_flag = true,
+    args_conflicts_with_subcommands = true
 )]
 pub struct Cli {
+    /// Optional long-running or auxiliary mode; plain disassembly needs none.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Target architecture plus optional mode modifiers (e.g., `riscv32`, `arm+thumb`, `x86+intel`).
     #[arg(
         help = "Target architecture with optional mode modifiers",
         long_help = "Specify the target architecture and optional mode modifiers.\n\
 See the registry-derived architecture support section in `robustone --help` for the current canonical tokens and parser-only placeholders."
     )]
-    #[arg(value_parser = validate_architecture)]
     pub arch_mode: Option<String>,
 
     /// Hexadecimal machine code bytes to disassemble (for example `"93001000"`).
     #[arg(
         help = "Hexadecimal machine code to disassemble",
         long_help = "Provide the machine code as a hexadecimal string to be disassembled.\n\
-Example: \"93001000\" for the RISC-V bytes `addi ra, zero, 1`"
+Example: \"93001000\" for the RISC-V bytes `addi ra, zero, 1`\n\
+Pass `-` to read the hex text from standard input."
     )]
     #[arg(value_names = ["HEX_CODE"])]
     pub hex_code: Option<String>,
@@ -47,7 +65,7 @@ If not provided, defaults to 0. Prefix with 0x or use plain hex."
         short = 'd',
         long = "detailed",
         help = "Show detailed instruction information",
-        long_help = "Display additional instruction metadata including operands, encoding format, and size"
+        long_help = "Display additional instruction metadata, listing each operand with its type, value, and access (e.g. `op[1]: MEM base=sp disp=0x10 (read)`)"
     )]
     pub detailed: bool,
 
@@ -65,7 +83,8 @@ If not provided, defaults to 0. Prefix with 0x or use plain hex."
         short = 'r',
         long = "real-detail",
         help = "Show detailed real instruction information (including aliases)",
-        long_help = "Show comprehensive instruction details including register alias mappings and operand details"
+        long_help = "Follow each instruction with a detail block laid out like cstool's `-d`: operand count, each operand's type,\n\
+value, and access, the registers read and modified (including implicit ones), and the instruction groups."
     )]
     pub real_detail: bool,
 
@@ -86,6 +105,330 @@ If not provided, defaults to 0. Prefix with 0x or use plain hex."
     )]
     pub json: bool,
 
+    /// Output layout; `--json` is shorthand for `--format json`.
+    #[arg(
+        long = "format",
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        help = "Output layout (text, json, gdb, gas, trace)",
+        long_help = "Select the output layout.\n\
+`text` is the cstool-style listing, `json` matches `--json`, `gdb` mimics GDB's `x/i` command output,\n\
+`gas` emits a GNU assembler source file that reassembles to the same bytes, and `trace` prints\n\
+`ADDRESS<TAB>ENCODING<TAB>arch:mnemonic:operands` lines to join against QEMU/Spike instruction traces."
+    )]
+    pub format: OutputFormat,
+
+    /// `--syntax`: assembly flavor of the instruction text.
+    #[arg(
+        long = "syntax",
+        value_enum,
+        default_value_t = Syntax::Default,
+        help = "Assembly flavor of the instruction text (default, intel, att, llvm)",
+        long_help = "Select the assembly flavor of the instruction text.\n\
+`default` matches cstool. `intel` and `att` pick the x86 operand syntax; cstool prints Intel.\n\
+`llvm` follows `llvm-mc --disassemble`: compressed instructions in their 32-bit form,\n\
+the aliases LLVM prints (`ret`, `sext.w`, `csrr a0, mstatus`), decimal immediates, and relative branch offsets.\n\
+Architectures without an LLVM printer keep their default text."
+    )]
+    pub syntax: Syntax,
+
+    /// `--nops`: how no-op encodings such as `addi zero, zero, 0` are printed.
+    #[arg(
+        long = "nops",
+        value_enum,
+        default_value_t = Nops::Profile,
+        help = "How to print nops and HINTs that write x0 (profile, collapse, literal)",
+        long_help = "Choose how encodings without architectural effect are printed: the canonical nop and the RISC-V HINTs,\n\
+such as `addi zero, a0, 5`, `c.li zero, 1`, or `c.addi a0, 0`, alike for 32-bit and compressed encodings.\n\
+`profile` keeps what the selected `--syntax` prints (cstool keeps `addi zero, zero, 0`, `llvm` prints `nop`),\n\
+`collapse` prints every one of them as `nop`, and `literal` prints the instruction as encoded, without aliases.\n\
+`--format gas` always keeps the profile's text so the source reassembles to the same bytes."
+    )]
+    pub nops: Nops,
+
+    /// Path to an address-keyed comment file appended to the listing.
+    #[arg(
+        long = "comments",
+        value_name = "FILE",
+        help = "Append comments from FILE to matching addresses",
+        long_help = "Load free-text comments keyed by address and append them as `; comment` to the matching listing lines.\n\
+Each line of FILE is `<address> <comment>` (or `<address>: <comment>`); lines starting with '#' are ignored."
+    )]
+    pub comments: Option<String>,
+
+    /// `--memory-map`: device tree or memory-map file describing the SoC.
+    #[arg(
+        long = "memory-map",
+        value_name = "FILE",
+        help = "Treat MMIO and reserved ranges from FILE as data and label peripheral accesses",
+        long_help = "Load a flattened device tree (`.dtb`) or a text memory map with one `<start> <size> <kind> [name]` line per region,\n\
+where kind is ram, rom, mmio, or reserved. Bytes inside mmio and reserved regions are listed as data directives instead of\n\
+being decoded, and RISC-V loads and stores whose address (built with lui/auipc/addi) falls in a named mmio region get a\n\
+`; uart0+0x5` comment. Compile device tree sources first with `dtc -I dts -O dtb`."
+    )]
+    pub memory_map: Option<String>,
+
+    /// `--address-width`: zero-pad addresses to a fixed number of hex digits.
+    #[arg(
+        long = "address-width",
+        value_name = "DIGITS",
+        value_parser = clap::builder::PossibleValuesParser::new(["4", "8", "16"])
+            .map(|digits| digits.parse::<usize>().expect("possible values are numeric")),
+        help = "Zero-pad addresses to 4, 8, or 16 hex digits",
+        long_help = "Print every address with a fixed number of hex digits (4 for 16-bit, 8 for 32-bit, 16 for 64-bit targets) so columns stay aligned across listings."
+    )]
+    pub address_width: Option<usize>,
+
+    /// `--offsets`: show offsets from the start address instead of absolute addresses.
+    #[arg(
+        long = "offsets",
+        conflicts_with = "no_address",
+        help = "Show offsets from the start address instead of absolute addresses"
+    )]
+    pub offsets: bool,
+
+    /// `--no-address`: omit the address column.
+    #[arg(
+        long = "no-address",
+        help = "Omit the address column",
+        long_help = "Omit the address column from text listings, which keeps diffs of code moved to another address small."
+    )]
+    pub no_address: bool,
+
+    /// `--uppercase`: uppercase mnemonics and hex digits.
+    #[arg(
+        long = "uppercase",
+        help = "Uppercase mnemonics and hex digits",
+        long_help = "Print mnemonics and hex digits (addresses, bytes, and immediates) in uppercase in every output format.\n\
+Register names and the `0x` prefix are left as they are."
+    )]
+    pub uppercase: bool,
+
+    /// `--labels`: emit `.L<n>:` labels at branch targets inside the listing.
+    #[arg(
+        long = "labels",
+        help = "Replace in-range branch targets with local labels",
+        long_help = "Emit `.L<n>:` lines at the targets of PC-relative branches and jumps that land inside the listing,\n\
+and print those branch operands as the label instead of an offset. Applies to the text and gdb listings."
+    )]
+    pub labels: bool,
+
+    /// `--explain`: print the encoding breakdown of every instruction.
+    #[arg(
+        long = "explain",
+        help = "Break each instruction down into its encoding fields",
+        long_help = "After each instruction, print its encoding format, every bit field with its bit range and value,\n\
+the reassembled immediate, and a one-line description. Currently available for RISC-V; applies to the text listing."
+    )]
+    pub explain: bool,
+
+    /// `--expand-compressed`: show the 32-bit form of every RVC instruction.
+    #[arg(
+        long = "expand-compressed",
+        help = "Show the 32-bit canonical form next to compressed instructions",
+        long_help = "Append `; = <instruction>` to each RISC-V compressed (RVC) instruction in the text listing,\n\
+showing the 32-bit instruction it expands to (for example `c.addi sp, -32  ; = addi sp, sp, -32`)."
+    )]
+    pub expand_compressed: bool,
+
+    /// `--dataflow`: annotate register def-use chains.
+    #[arg(
+        long = "dataflow",
+        help = "Show where each register read was defined and where each write is used",
+        long_help = "After each instruction, print the registers it reads with the address of the instruction that last wrote them\n\
+(`live-in` when written before the listing) and the registers it writes with the addresses that read the value.\n\
+The listing is analyzed as straight-line code; branches are not followed. Applies to the text listing."
+    )]
+    pub dataflow: bool,
+
+    /// `--mark-hints`: annotate HINT and reserved encodings.
+    #[arg(
+        long = "mark-hints",
+        help = "Mark HINT and reserved encodings in the listing",
+        long_help = "Append `; hint` to instructions whose encoding the architecture reserves as a HINT (for example a RISC-V\n\
+computational instruction writing x0), and `; reserved` to `--skip-data` items that stand for a reserved encoding.\n\
+JSON output gains an `encoding_class` field on those entries."
+    )]
+    pub mark_hints: bool,
+
+    /// `--raw-bytes`: print each instruction's bytes as one ungrouped hex run.
+    #[arg(
+        long = "raw-bytes",
+        help = "Print instruction bytes as ungrouped hex",
+        long_help = "Print the bytes column as one unbroken hex run per instruction (e.g. `97020000`) instead of cstool's space-separated bytes.\n\
+Only affects `-d`/`-r` text listings."
+    )]
+    pub raw_bytes: bool,
+
+    /// `--word-column`: print each instruction as a single hex word.
+    #[arg(
+        long = "word-column",
+        help = "Print each instruction's encoding as one hex word",
+        long_help = "Add a column with each instruction's encoding read as one number in the target's byte order (e.g. `0x00000297`,\n\
+or `0x4505` for a compressed instruction), which matches the values shown by waveform viewers and logic analyzers."
+    )]
+    pub word_column: bool,
+
+    /// `--hexdump`: show a hexdump panel next to the listing.
+    #[arg(
+        long = "hexdump",
+        conflicts_with_all = ["json", "emulate"],
+        help = "Show a 16-byte hexdump/ASCII panel beside the instructions",
+        long_help = "Print the input as `hexdump -C` style rows of 16 bytes and list, to the right of each row, the instructions\n\
+that start inside it. Bytes that did not decode still appear in the dump, which helps when code and data are mixed."
+    )]
+    pub hexdump: bool,
+
+    /// `-o`: write the listing to a file instead of stdout.
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_name = "FILE",
+        help = "Write the listing to FILE instead of stdout",
+        long_help = "Write the rendered listing (text, gdb, or JSON) to FILE, creating or truncating it.\n\
+Error reports are still written to stderr."
+    )]
+    pub output: Option<String>,
+
+    /// `--no-pager`: never pipe long listings through `$PAGER`.
+    #[arg(
+        long = "no-pager",
+        help = "Do not pipe long listings through $PAGER",
+        long_help = "By default a listing taller than the terminal is piped through `$PAGER` (or `less -R`).\n\
+Paging never happens when stdout is not a terminal or when `-o` is given."
+    )]
+    pub no_pager: bool,
+
+    /// `--time`: report wall time and throughput on stderr.
+    #[arg(
+        long = "time",
+        help = "Report elapsed time, instruction count, and MB/s on stderr",
+        long_help = "After the run, print the wall time spent decoding and formatting, the number of decoded instructions,\n\
+and the input throughput in MB/s to stderr. Writing the listing (and any pager) is not included."
+    )]
+    pub time: bool,
+
+    /// `--unsupported-summary`: list the encodings the run could not decode.
+    #[arg(
+        long = "unsupported-summary",
+        help = "Summarise undecodable encodings and their opcodes on stderr",
+        long_help = "After the run, print each distinct encoding that failed to decode with its count, first address, and\n\
+opcode/funct fields, then a histogram of the opcodes, to stderr. Pair it with `-s` so the run continues past them;\n\
+the output is meant to be attached to extension-support issues."
+    )]
+    pub unsupported_summary: bool,
+
+    /// `--dry-run`: validate everything, then print a summary instead of the listing.
+    #[arg(
+        long = "dry-run",
+        help = "Check the arguments and input, print a summary, and stop",
+        long_help = "Parse and validate the architecture, options, and input (reading files and standard input as usual),\n\
+then print a summary of what would be disassembled instead of decoding it. Problems exit with the usual codes,\n\
+so CI pipelines can check a configuration quickly. Combine with --json for a machine-readable summary."
+    )]
+    pub dry_run: bool,
+
+    /// `--max-instructions`: cap on decoded instructions.
+    #[arg(
+        long = "max-instructions",
+        value_name = "N",
+        help = "Fail once more than N instructions have been decoded",
+        long_help = "Stop with a `limit_exceeded` error (exit code 6) once the listing would hold more than N instructions,\n\
+data items included. Together with `--max-output` and `--timeout`, this keeps CI jobs and services safe from huge inputs."
+    )]
+    pub max_instructions: Option<usize>,
+
+    /// `--max-output`: cap on rendered listing size.
+    #[arg(
+        long = "max-output",
+        value_name = "BYTES",
+        help = "Fail once the rendered listing exceeds BYTES bytes"
+    )]
+    pub max_output: Option<usize>,
+
+    /// `--timeout`: cap on wall-clock time.
+    #[arg(
+        long = "timeout",
+        value_name = "SECONDS",
+        value_parser = parse_timeout,
+        help = "Fail once decoding and formatting take longer than SECONDS (fractions allowed)"
+    )]
+    pub timeout: Option<Duration>,
+
+    /// `--watch`: re-disassemble a binary file whenever it changes.
+    #[arg(
+        long = "watch",
+        value_name = "FILE",
+        conflicts_with = "address",
+        help = "Disassemble FILE and re-run whenever it changes",
+        long_help = "Read raw machine code from FILE instead of a hex argument and re-print the listing\n\
+(or rewrite the `-o` file) every time FILE changes. The positional argument after the architecture is the\n\
+start address: `robustone --watch fw.bin riscv32 80000000`. Stop with Ctrl-C."
+    )]
+    pub watch: Option<String>,
+
+    /// `--object`: disassemble `.text` of an ELF object file.
+    #[arg(
+        long = "object",
+        value_name = "FILE",
+        conflicts_with_all = ["address", "watch"],
+        help = "Disassemble the .text section of an ELF object file or archive, showing relocations",
+        long_help = "Read machine code from the `.text` section of the ELF file FILE instead of a hex argument. A static archive\n\
+(`.a`) is listed member by member under `==> member.o <==` headings, skipping members without code. In a relocatable\n\
+object (`.o`), operands patched by `.rela.text` are shown as the relocation expression, such as\n\
+`auipc a0, %pcrel_hi(counter)` or `jal ra, g`, rather than the placeholder zero (RISC-V relocations only).\n\
+The positional argument after the architecture overrides the start address: `robustone --object f.o riscv64`."
+    )]
+    pub object: Option<String>,
+
+    /// `--symbol`: limit `--object` to the functions matching a pattern.
+    #[arg(
+        long = "symbol",
+        value_name = "PATTERN",
+        requires = "object",
+        help = "With --object, disassemble only the functions whose names match PATTERN",
+        long_help = "List only the byte range of each function symbol in `.text` whose name matches PATTERN, where `*` matches\n\
+any run of characters and `?` any one character: `--symbol main`, `--symbol 'init_*'`. Matches are listed in\n\
+address order, each under a `name:` heading. Symbols without a recorded size run to the next symbol."
+    )]
+    pub symbol: Option<String>,
+
+    /// `--emulate`: execute the input instead of listing it.
+    #[arg(
+        long = "emulate",
+        conflicts_with_all = ["watch", "json"],
+        help = "Execute the input with the RISC-V interpreter and print a trace",
+        long_help = "Load HEX_CODE as memory at ADDRESS, start executing at ADDRESS, and print each instruction with the registers\n\
+and memory it wrote. Stops at `ecall`/`ebreak`, a fault, an instruction the interpreter does not support, or after `--steps`.\n\
+Integer instructions (RV32I/RV64I, M, and their compressed forms) only; riscv32 and riscv64."
+    )]
+    pub emulate: bool,
+
+    /// `--steps`: instruction budget for `--emulate`.
+    #[arg(
+        long = "steps",
+        value_name = "N",
+        default_value_t = crate::emulate::DEFAULT_STEPS,
+        requires = "emulate",
+        help = "Stop `--emulate` after N instructions"
+    )]
+    pub steps: usize,
+
+    /// `--classify`: report per-region entropy and a content guess.
+    #[arg(
+        long = "classify",
+        value_name = "SIZE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "4096",
+        conflicts_with_all = ["watch", "emulate", "hexdump"],
+        help = "Report entropy and a code/data/compressed/encrypted guess per region",
+        long_help = "Instead of a listing, split the input into regions of SIZE bytes (default 4096; `--classify=512`) and print each\n\
+region's Shannon entropy, the share of it that decodes for ARCH_MODE, and a guess at its contents: `code`, `data`,\n\
+`compressed`, or `encrypted`. Use it to find the ranges of an unknown firmware blob worth disassembling. Honours `--json`."
+    )]
+    pub classify: Option<usize>,
+
     // Decoding options group
     /// `-s`: enable SKIPDATA mode to step past undecodable bytes.
     #[arg(
@@ -96,6 +439,30 @@ If not provided, defaults to 0. Prefix with 0x or use plain hex."
     )]
     pub skip_data: bool,
 
+    /// `--data-directives`: list SKIPDATA runs as strings and words.
+    #[arg(
+        long = "data-directives",
+        help = "With -s, list skipped runs as .ascii/.asciz strings and pointer-sized words",
+        long_help = "List each run of bytes SKIPDATA steps over as a whole: printable runs of 4+ characters become\n\
+`.ascii`/`.asciz` strings, aligned pointer-sized chunks become `.word`/`.dword` (`.long`/`.quad` on x86), and the rest\n\
+`.byte`. Without it every data step is its own `.byte` item, as in cstool."
+    )]
+    pub data_directives: bool,
+
+    /// `--misaligned`: policy for instructions at misaligned addresses.
+    #[arg(
+        long = "misaligned",
+        value_enum,
+        default_value_t = MisalignedPolicy::Allow,
+        value_name = "POLICY",
+        help = "Handle instructions at misaligned addresses: error, warn, or allow",
+        long_help = "Check each instruction address against the architecture's alignment \
+(2 bytes for RISC-V with C, otherwise 4; none for x86). `error` stops at the first \
+misaligned instruction, `warn` decodes it and reports a warning, and `allow` skips the \
+check. Useful when interpreting corrupted dumps"
+    )]
+    pub misaligned: MisalignedPolicy,
+
     // System options group
     /// `-v`: print version and build metadata instead of disassembling input.
     #[arg(
@@ -106,6 +473,17 @@ If not provided, defaults to 0. Prefix with 0x or use plain hex."
     )]
     pub version: bool,
 
+    /// `--verbose`: report decode diagnostics on stderr; repeat for more detail.
+    #[arg(
+        long = "verbose",
+        action = clap::ArgAction::Count,
+        global = true,
+        help = "Log decode diagnostics to stderr (repeat to trace)",
+        long_help = "Write decode diagnostics to stderr. `--verbose` reports failed decodes and SKIPDATA resyncs with the rejected bytes; \
+`--verbose --verbose` additionally traces every decoded instruction. There is no short form: `-v` is the cstool-compatible version flag."
+    )]
+    pub verbose: u8,
+
     /// `--capabilities`: show the registry-derived architecture support surface.
     #[arg(
         long = "capabilities",
@@ -117,15 +495,180 @@ This mode does not require an architecture token or disassembly bytes.",
         conflicts_with = "version"
     )]
     pub capabilities: bool,
+
+    /// `--archs`: list accepted `<arch+mode>` tokens and their `+options`.
+    #[arg(
+        long = "archs",
+        help = "List architectures, mode strings, and +options",
+        long_help = "List every registered architecture with the mode strings it accepts and the\n\
+architecture-specific `+option` modifiers, in the style of cstool's usage header.\n\
+Combine with --json for machine-readable output.",
+        conflicts_with_all = ["version", "capabilities"]
+    )]
+    pub archs: bool,
+
+    /// `--list-insns ARCH`: list the mnemonics decodable for one architecture.
+    #[arg(
+        long = "list-insns",
+        value_name = "ARCH",
+        help = "List decodable mnemonics for ARCH, grouped by extension",
+        long_help = "List every mnemonic the decoder for ARCH can produce, grouped by extension.\n\
+Only ARCH's XLEN and enabled extensions count: `riscv32` omits `ld`, and `riscv64+zacas` adds Zacas.\n\
+Mnemonics are canonical decoded names; display aliases such as `li` or `mv` are not listed.\n\
+Combine with --json for machine-readable output.",
+        conflicts_with_all = ["version", "capabilities", "archs"]
+    )]
+    pub list_insns: Option<String>,
+
+    /// `--list-regs ARCH`: list the registers of one architecture.
+    #[arg(
+        long = "list-regs",
+        value_name = "ARCH",
+        help = "List registers for ARCH with their class, width, and aliases",
+        long_help = "List every register of ARCH with its id, class (gpr, fpr, csr, vector), width in bits,\n\
+and alternative names such as `x8`/`fp` for `s0`. Widths follow the mode, so riscv32 and riscv64 differ.\n\
+Combine with --json for machine-readable output.",
+        conflicts_with_all = ["version", "capabilities", "archs", "list_insns"]
+    )]
+    pub list_regs: Option<String>,
+}
+
+/// Auxiliary modes that replace the cstool-style positional invocation.
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Run an HTTP service exposing `POST /disasm` for CI systems and web UIs.
+    Serve {
+        /// Socket address to listen on.
+        #[arg(
+            long = "listen",
+            default_value = "127.0.0.1:8080",
+            help = "Address to bind the HTTP service to"
+        )]
+        listen: String,
+    },
+    /// Run the embedded corpus of known encodings through every handler.
+    Selftest {
+        /// Emit the report as JSON.
+        #[arg(long = "json", help = "Emit the self-test report as JSON")]
+        json: bool,
+    },
+    /// Print a shell completion script generated from the flag definitions.
+    Completions {
+        /// Shell to generate the script for.
+        #[arg(
+            value_enum,
+            help = "Target shell (bash, zsh, fish, powershell, elvish)"
+        )]
+        shell: clap_complete::Shell,
+    },
+    /// Print the roff man page generated from the flag definitions.
+    Man,
+    /// Run every job of a TOML manifest (arch, input, address, output) in one process.
+    Batch {
+        /// Path to the manifest.
+        #[arg(
+            value_name = "MANIFEST",
+            help = "TOML manifest listing `[[job]]` entries"
+        )]
+        manifest: std::path::PathBuf,
+        /// Number of jobs to run in parallel.
+        #[arg(
+            short = 'j',
+            long = "jobs",
+            default_value_t = 1,
+            help = "Number of jobs to run in parallel"
+        )]
+        jobs: usize,
+    },
+    /// Replace the instruction at an address of a binary and write the patched image.
+    Patch {
+        /// Architecture of the image.
+        #[arg(
+            value_name = "ARCH_MODE",
+            help = "Architecture mode, as for disassembly"
+        )]
+        arch: String,
+        /// Raw binary to patch.
+        #[arg(value_name = "FILE", help = "Raw binary image to patch")]
+        input: std::path::PathBuf,
+        /// Address of the instruction to replace.
+        #[arg(
+            value_name = "ADDRESS",
+            help = "Hex address of the instruction to replace"
+        )]
+        address: String,
+        /// Replacement instructions.
+        #[arg(
+            value_name = "ASSEMBLY",
+            help = "RISC-V instructions to assemble, separated by `;`; padded with nops when shorter than the original"
+        )]
+        replacement: String,
+        /// Load address of the image.
+        #[arg(
+            long = "base",
+            value_name = "ADDRESS",
+            default_value = "0",
+            help = "Hex address the first byte of FILE is loaded at"
+        )]
+        base: String,
+        /// Where to write the patched image.
+        #[arg(
+            short = 'o',
+            long = "output",
+            value_name = "FILE",
+            help = "Write the patched image to FILE"
+        )]
+        output: std::path::PathBuf,
+    },
 }
 
 impl Cli {
-    /// Validate the CLI arguments and return a configuration.
-    pub fn validate(&self) -> Result<ValidatedConfig> {
-        let hex_code = self.validate_hex_code()?;
-        let address = self.validate_address()?;
+    /// Validate the CLI arguments and build the disassembly configuration.
+    ///
+    /// Checks the architecture, hex code, and address independently and
+    /// reports every problem at once ([`CliError::InvalidConfig`]), then
+    /// loads the input (standard input for `-`) and the `--comments` file.
+    pub fn validate(&self) -> Result<DisasmConfig> {
+        DisasmConfig::from_cli_args(self.validate_args()?)
+    }
+
+    /// Validate the CLI arguments without loading any input.
+    ///
+    /// Checks the architecture, hex code, and address independently and
+    /// reports every problem at once ([`CliError::InvalidConfig`]) so they
+    /// can be fixed in one pass; a single problem keeps its own error.
+    pub(crate) fn validate_args(&self) -> Result<CliArgs> {
+        let arch = self.validate_arch_mode();
+        let hex_code = self.validate_hex_code();
+        let address = self.validate_address();
+        let (hex_code, address) = match (arch, hex_code, address) {
+            (Ok(()), Ok(hex_code), Ok(address)) => (hex_code, address),
+            (arch, hex_code, address) => {
+                let arch = arch.err().map(|error| {
+                    let suggestion = self.arch_mode.as_deref().and_then(suggest_architecture);
+                    (error, suggestion)
+                });
+                let hex_code = hex_code.err().map(|error| {
+                    let conflict =
+                        matches!(&error, CliError::Validation { field, .. } if field == "address");
+                    let suggestion = (!conflict).then(|| {
+                        "pass whole bytes as hex digits, e.g. `93001000` or `93 00 10 00`"
+                            .to_string()
+                    });
+                    (error, suggestion)
+                });
+                let address = address.err().map(|error| {
+                    let suggestion =
+                        matches!(&error, CliError::Validation { field, .. } if field == "address")
+                            .then(|| "give a hex address such as `0x1000` or `1000`".to_string());
+                    (error, suggestion)
+                });
+                let errors = [arch, hex_code, address].into_iter().flatten().collect();
+                return Err(CliError::from_validation_errors(errors));
+            }
+        };
 
-        Ok(ValidatedConfig {
+        Ok(CliArgs {
             arch_mode: self.arch_mode.clone(),
             hex_code,
             address,
@@ -134,20 +677,54 @@ impl Cli {
             real_detail: self.real_detail,
             skip_data: self.skip_data,
             unsigned_immediate: self.unsigned_immediate,
-            json: self.json,
-            version: self.version,
+            json: self.wants_json(),
+            format: self.format,
+            comments_file: self.comments.clone(),
+            layout: self.column_layout(),
+            uppercase: self.uppercase,
+            labels: self.labels,
+            explain: self.explain,
+            expand_compressed: self.expand_compressed,
+            dataflow: self.dataflow,
+            mark_hints: self.mark_hints,
+            syntax: self.syntax,
+            nops: self.nops,
         })
     }
 
+    /// Validate that the architecture names a decodable target.
+    fn validate_arch_mode(&self) -> Result<()> {
+        match &self.arch_mode {
+            Some(arch_mode) => parse_decodable_arch_spec(arch_mode).map(drop),
+            None => Ok(()),
+        }
+    }
+
     /// Validate hexadecimal code input.
     fn validate_hex_code(&self) -> Result<Option<String>> {
+        if self.watch.is_some() || self.object.is_some() {
+            return Ok(None);
+        }
         match &self.hex_code {
             Some(code) => {
                 if code.trim().is_empty() {
                     return Err(CliError::validation("hex_code", "Empty hex code provided"));
                 }
+                // Standard input is decoded as it is read, when the config is built.
+                if code.trim() == STDIN_HEX_CODE {
+                    return Ok(Some(STDIN_HEX_CODE.to_string()));
+                }
 
-                parse_hex_code_legacy(code)?;
+                if parse_addressed_hex(code)?.is_some() {
+                    if self.address.is_some() {
+                        return Err(CliError::validation(
+                            "address",
+                            "the hex input carries its own addresses; drop `--address`",
+                        ));
+                    }
+                } else {
+                    parse_hex_code_legacy(code)?;
+                }
                 Ok(Some(code.trim().to_string()))
             }
             None => Ok(None),
@@ -156,7 +733,14 @@ impl Cli {
 
     /// Validate address input.
     fn validate_address(&self) -> Result<Option<u64>> {
-        match &self.address {
+        // With `--watch` or `--object` the input comes from the file, so the
+        // second positional argument is the start address.
+        let address = if self.watch.is_some() || self.object.is_some() {
+            &self.hex_code
+        } else {
+            &self.address
+        };
+        match address {
             Some(addr) => {
                 if addr.trim().is_empty() {
                     return Err(CliError::validation("address", "Empty address provided"));
@@ -178,10 +762,77 @@ impl Cli {
         self.capabilities
     }
 
+    /// Column layout requested for the text listing.
+    pub fn column_layout(&self) -> ColumnLayout {
+        ColumnLayout {
+            address: if self.no_address {
+                AddressDisplay::Hidden
+            } else if self.offsets {
+                AddressDisplay::Offset
+            } else {
+                AddressDisplay::Absolute
+            },
+            address_width: self.address_width.unwrap_or(0),
+            byte_grouping: if self.raw_bytes {
+                ByteGrouping::None
+            } else {
+                ByteGrouping::Bytes
+            },
+            word_column: self.word_column,
+            ..ColumnLayout::default()
+        }
+    }
+
+    /// Where the disassembly listing should be written.
+    pub fn output_target(&self) -> OutputTarget {
+        match &self.output {
+            Some(path) => OutputTarget::File(path.into()),
+            None => OutputTarget::Stdout {
+                pager: !self.no_pager,
+            },
+        }
+    }
+
+    /// Check if the architecture listing should be displayed.
+    pub fn should_show_architectures(&self) -> bool {
+        self.archs
+    }
+
+    /// Return the architecture whose mnemonics should be listed, if requested.
+    pub fn list_insns_architecture(&self) -> Option<&str> {
+        self.list_insns.as_deref()
+    }
+
+    /// Validate that `--list-insns` is not mixed with disassembly inputs.
+    pub fn validate_list_insns_request(&self) -> Result<()> {
+        self.validate_report_request("list-insns")
+    }
+
+    /// Return the architecture whose registers should be listed, if requested.
+    pub fn list_regs_architecture(&self) -> Option<&str> {
+        self.list_regs.as_deref()
+    }
+
+    /// Validate that `--list-regs` is not mixed with disassembly inputs.
+    pub fn validate_list_regs_request(&self) -> Result<()> {
+        self.validate_report_request("list-regs")
+    }
+
     /// Validate that capability-report mode is not mixed with disassembly inputs.
     pub fn validate_capabilities_request(&self) -> Result<()> {
-        let has_disassembly_inputs =
-            self.arch_mode.is_some() || self.hex_code.is_some() || self.address.is_some();
+        self.validate_report_request("capabilities")
+    }
+
+    /// Validate that `--archs` is not mixed with disassembly inputs.
+    pub fn validate_architectures_request(&self) -> Result<()> {
+        self.validate_report_request("archs")
+    }
+
+    fn validate_report_request(&self, flag: &str) -> Result<()> {
+        let has_disassembly_inputs = self.arch_mode.is_some()
+            || self.hex_code.is_some()
+            || self.address.is_some()
+            || self.comments.is_some();
         let has_disassembly_flags = self.detailed
             || self.alias_regs
             || self.real_detail
@@ -190,58 +841,124 @@ impl Cli {
 
         if has_disassembly_inputs || has_disassembly_flags {
             return Err(CliError::validation(
-                "capabilities",
-                "`--capabilities` only supports optional `--json`; remove ARCH_MODE/HEX_CODE/ADDRESS and disassembly-only flags",
+                flag,
+                format!(
+                    "`--{flag}` only supports optional `--json`; remove ARCH_MODE/HEX_CODE/ADDRESS and disassembly-only flags"
+                ),
             ));
         }
 
         Ok(())
     }
 
+    /// Check whether output (including errors) should be rendered as JSON.
+    /// The `--max-instructions`, `--max-output`, and `--timeout` caps.
+    pub fn resource_limits(&self) -> ResourceLimits {
+        ResourceLimits {
+            max_instructions: self.max_instructions,
+            max_output_bytes: self.max_output,
+            timeout: self.timeout,
+        }
+    }
+
+    pub fn wants_json(&self) -> bool {
+        self.json || self.format == OutputFormat::Json
+    }
+
     /// Check if the CLI has valid input for disassembly.
     pub fn has_disassembly_input(&self) -> bool {
-        self.hex_code.is_some()
+        self.hex_code.is_some() || self.object.is_some()
     }
 }
 
-/// Validated and processed command-line configuration.
+/// Validated command-line arguments, before any input is loaded.
 #[derive(Debug, Clone)]
-pub struct ValidatedConfig {
-    pub arch_mode: Option<String>,
-    pub hex_code: Option<String>,
-    pub address: Option<u64>,
-    pub detailed: bool,
-    pub alias_regs: bool,
-    pub real_detail: bool,
-    pub skip_data: bool,
-    pub unsigned_immediate: bool,
-    pub json: bool,
-    pub version: bool,
+pub(crate) struct CliArgs {
+    pub(crate) arch_mode: Option<String>,
+    pub(crate) hex_code: Option<String>,
+    pub(crate) address: Option<u64>,
+    pub(crate) detailed: bool,
+    pub(crate) alias_regs: bool,
+    pub(crate) real_detail: bool,
+    pub(crate) skip_data: bool,
+    pub(crate) unsigned_immediate: bool,
+    pub(crate) json: bool,
+    pub(crate) format: OutputFormat,
+    pub(crate) comments_file: Option<String>,
+    pub(crate) layout: ColumnLayout,
+    pub(crate) uppercase: bool,
+    pub(crate) labels: bool,
+    pub(crate) explain: bool,
+    pub(crate) expand_compressed: bool,
+    pub(crate) dataflow: bool,
+    pub(crate) mark_hints: bool,
+    pub(crate) syntax: Syntax,
+    pub(crate) nops: Nops,
 }
 
-impl ValidatedConfig {
+impl CliArgs {
     /// Get the starting address, defaulting to 0 if not provided.
-    pub fn address_or_default(&self) -> u64 {
+    pub(crate) fn address_or_default(&self) -> u64 {
         self.address.unwrap_or(0)
     }
 
-    /// Check if any detailed output mode is enabled.
-    pub fn is_detailed_mode(&self) -> bool {
-        self.detailed || self.real_detail
-    }
-
     /// Get display options as a unified configuration.
-    pub fn display_options(&self) -> DisplayOptions {
+    pub(crate) fn display_options(&self) -> DisplayOptions {
         DisplayOptions {
             detailed: self.detailed,
             alias_regs: self.alias_regs,
             real_detail: self.real_detail,
             unsigned_immediate: self.unsigned_immediate,
             json: self.json,
+            format: self.format,
+            layout: self.layout,
+            uppercase: self.uppercase,
+            labels: self.labels,
+            explain: self.explain,
+            expand_compressed: self.expand_compressed,
+            dataflow: self.dataflow,
+            mark_hints: self.mark_hints,
+            syntax: self.syntax,
+            nops: self.nops,
         }
     }
 }
 
+/// Validated and processed command-line configuration.
+///
+/// This mirrors the parsed CLI flags. Use
+/// [`DisasmConfig::builder`](crate::config::DisasmConfig::builder), or
+/// [`Cli::validate`] for parsed arguments, instead; this struct will stop
+/// being public API in a future release.
+#[deprecated(
+    since = "0.1.0",
+    note = "use DisasmConfig::builder() or Cli::validate()"
+)]
+#[derive(Debug, Clone)]
+pub struct ValidatedConfig {
+    pub arch_mode: Option<String>,
+    pub hex_code: Option<String>,
+    pub address: Option<u64>,
+    pub detailed: bool,
+    pub alias_regs: bool,
+    pub real_detail: bool,
+    pub skip_data: bool,
+    pub unsigned_immediate: bool,
+    pub json: bool,
+    pub format: OutputFormat,
+    pub comments_file: Option<String>,
+    pub version: bool,
+    pub layout: ColumnLayout,
+    pub uppercase: bool,
+    pub labels: bool,
+    pub explain: bool,
+    pub expand_compressed: bool,
+    pub dataflow: bool,
+    pub mark_hints: bool,
+    pub syntax: Syntax,
+    pub nops: Nops,
+}
+
 /// Unified display options for disassembly output.
 #[derive(Debug, Clone)]
 pub struct DisplayOptions {
@@ -250,6 +967,108 @@ pub struct DisplayOptions {
     pub real_detail: bool,
     pub unsigned_immediate: bool,
     pub json: bool,
+    pub format: OutputFormat,
+    /// Column layout of the text listing.
+    pub layout: ColumnLayout,
+    /// Uppercase mnemonics and hex digits.
+    pub uppercase: bool,
+    /// Replace in-range branch targets with `.L<n>` labels.
+    pub labels: bool,
+    /// Print the encoding breakdown of every instruction.
+    pub explain: bool,
+    /// Show the 32-bit canonical form next to compressed instructions.
+    pub expand_compressed: bool,
+    /// Annotate register def-use chains.
+    pub dataflow: bool,
+    /// Mark HINT and reserved encodings.
+    pub mark_hints: bool,
+    /// Assembly flavor of the instruction text.
+    pub syntax: Syntax,
+    /// How no-op encodings are printed.
+    pub nops: Nops,
+}
+
+/// Output layouts selectable with `--format`.
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// cstool-compatible text listing.
+    #[default]
+    Text,
+    /// Structured JSON built from the shared decode IR.
+    Json,
+    /// GDB `x/i`-style listing.
+    Gdb,
+    /// GNU `as` source that reassembles to the same bytes.
+    Gas,
+    /// Address, encoding, and canonical key per line, for joining against
+    /// emulator traces.
+    Trace,
+}
+
+/// Assembly flavors selectable with `--syntax`.
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Syntax {
+    /// cstool-compatible text.
+    #[default]
+    Default,
+    /// Intel operand order on x86 (the default there).
+    Intel,
+    /// AT&T operand order and sigils on x86.
+    Att,
+    /// `llvm-mc --disassemble` conventions.
+    Llvm,
+}
+
+impl Syntax {
+    /// The shared text profile rendering this flavor; `real_detail` picks
+    /// the verbose profile for the default flavors.
+    pub fn text_profile(self, real_detail: bool) -> TextRenderProfile {
+        match self {
+            Self::Att => TextRenderProfile::Att,
+            Self::Llvm => TextRenderProfile::Llvm,
+            Self::Default | Self::Intel if real_detail => TextRenderProfile::VerboseDebug,
+            Self::Default | Self::Intel => TextRenderProfile::Capstone,
+        }
+    }
+}
+
+/// How no-op encodings are printed, selectable with `--nops`.
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Nops {
+    /// Whatever the selected syntax prints.
+    #[default]
+    Profile,
+    /// Print every nop and HINT as `nop`.
+    Collapse,
+    /// Print the encoded instruction without aliases.
+    Literal,
+}
+
+impl Nops {
+    /// The shared render style implementing this choice.
+    pub fn style(self) -> NopStyle {
+        match self {
+            Self::Profile => NopStyle::Profile,
+            Self::Collapse => NopStyle::Collapse,
+            Self::Literal => NopStyle::Literal,
+        }
+    }
+}
+
+/// What to do when an instruction starts at an address the architecture's
+/// alignment rules forbid (`--misaligned`).
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MisalignedPolicy {
+    /// Stop with an error at the first misaligned instruction.
+    Error,
+    /// Decode anyway and report each run of misaligned instructions.
+    Warn,
+    /// Decode without checking alignment.
+    #[default]
+    Allow,
 }
 
 pub fn render_help_text() -> String {
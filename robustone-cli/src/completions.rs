@@ -0,0 +1,50 @@
+//! Shell completion scripts and the man page (`robustone completions`, `robustone man`).
+//!
+//! Both artifacts are rendered from [`Cli::command`], so they always describe
+//! the flags and subcommands this binary actually accepts.
+
+use crate::command::Cli;
+
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+/// Binary name used in generated scripts and the man page header.
+const BIN_NAME: &str = "robustone";
+
+/// Render the completion script for `shell`.
+pub fn render_completions(shell: Shell) -> String {
+    let mut buffer = Vec::new();
+    clap_complete::generate(shell, &mut Cli::command(), BIN_NAME, &mut buffer);
+    String::from_utf8(buffer).expect("completion scripts should be valid UTF-8")
+}
+
+/// Render the roff man page.
+pub fn render_man_page() -> String {
+    let mut buffer = Vec::new();
+    clap_mangen::Man::new(Cli::command().name(BIN_NAME))
+        .render(&mut buffer)
+        .expect("rendering the man page into memory should succeed");
+    String::from_utf8(buffer).expect("the man page should be valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completions_cover_flags_and_subcommands() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let script = render_completions(shell);
+            assert!(script.contains("archs"), "{shell} script lacks --archs");
+            assert!(script.contains("selftest"), "{shell} script lacks selftest");
+        }
+    }
+
+    #[test]
+    fn test_man_page_lists_options() {
+        let page = render_man_page();
+        assert!(page.starts_with(".ie"), "{page}");
+        assert!(page.contains("robustone"));
+        assert!(page.contains("list\\-insns"));
+    }
+}
@@ -0,0 +1,184 @@
+//! Transparent decompression of file inputs.
+//!
+//! Firmware updates often ship compressed. Files read by `--watch`,
+//! `--object`, and `batch` jobs are recognised by their magic bytes and
+//! decompressed before loading, so users do not have to extract them first.
+//! Each format sits behind a feature of the same name, all on by default:
+//! `gzip`, `xz`, and `zstd`.
+
+use crate::error::{CliError, Result};
+
+use std::path::Path;
+
+/// A compressed container recognised by its magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+    Xz,
+    Zstd,
+}
+
+impl Compression {
+    /// Recognise the container `bytes` start with, if any.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        // The gzip magic is followed by the method byte, always 8 (deflate).
+        if bytes.starts_with(&[0x1f, 0x8b, 0x08]) {
+            Some(Self::Gzip)
+        } else if bytes.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+            Some(Self::Xz)
+        } else if bytes.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Some(Self::Zstd)
+        } else {
+            None
+        }
+    }
+
+    /// Format name, which is also the name of the feature that decodes it.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Xz => "xz",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    #[cfg_attr(
+        not(all(feature = "gzip", feature = "xz", feature = "zstd")),
+        allow(unused_variables)
+    )]
+    fn decode(self, bytes: &[u8]) -> std::result::Result<Vec<u8>, String> {
+        match self {
+            #[cfg(feature = "gzip")]
+            Self::Gzip => {
+                use std::io::Read;
+                let mut output = Vec::new();
+                flate2::read::MultiGzDecoder::new(bytes)
+                    .read_to_end(&mut output)
+                    .map_err(|error| error.to_string())?;
+                Ok(output)
+            }
+            #[cfg(feature = "xz")]
+            Self::Xz => {
+                let mut output = Vec::new();
+                lzma_rs::xz_decompress(&mut std::io::BufReader::new(bytes), &mut output)
+                    .map_err(|error| error.to_string())?;
+                Ok(output)
+            }
+            #[cfg(feature = "zstd")]
+            Self::Zstd => {
+                use std::io::Read;
+                // A file may hold several frames back to back.
+                let mut output = Vec::new();
+                let mut input = bytes;
+                while !input.is_empty() {
+                    ruzstd::decoding::StreamingDecoder::new(&mut input)
+                        .map_err(|error| error.to_string())?
+                        .read_to_end(&mut output)
+                        .map_err(|error| error.to_string())?;
+                }
+                Ok(output)
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(format!(
+                "this build cannot read {0} input; rebuild with the `{0}` feature or decompress the file first",
+                self.name()
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Compression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Decompress `bytes` if they are a recognised container; other input is
+/// returned unchanged.
+pub fn decompress(bytes: Vec<u8>) -> Result<Vec<u8>> {
+    let Some(compression) = Compression::detect(&bytes) else {
+        return Ok(bytes);
+    };
+    compression
+        .decode(&bytes)
+        .map_err(|message| CliError::validation("input", format!("{compression}: {message}")))
+}
+
+/// Read the file at `path`, decompressing it if needed.
+pub fn read_input(path: &Path) -> Result<Vec<u8>> {
+    let bytes = std::fs::read(path)
+        .map_err(|error| CliError::Io(format!("cannot read `{}`: {error}", path.display())))?;
+    decompress(bytes).map_err(|error| match error {
+        CliError::Validation {
+            field,
+            message,
+            span,
+        } => CliError::Validation {
+            field,
+            message: format!("`{}` is {message}", path.display()),
+            span,
+        },
+        error => error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `li ra, 1; nop`
+    const CODE: [u8; 8] = [0x93, 0x00, 0x10, 0x00, 0x13, 0x00, 0x00, 0x00];
+
+    #[test]
+    fn test_uncompressed_input_is_unchanged() {
+        assert_eq!(Compression::detect(&CODE), None);
+        assert_eq!(decompress(CODE.to_vec()).unwrap(), CODE);
+        // The gzip magic alone, without the deflate method byte, is not gzip.
+        assert_eq!(
+            decompress(vec![0x1f, 0x8b, 0x00]).unwrap(),
+            [0x1f, 0x8b, 0x00]
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_gzip_input_is_decompressed() {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&CODE).unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert_eq!(Compression::detect(&compressed), Some(Compression::Gzip));
+        assert_eq!(decompress(compressed).unwrap(), CODE);
+    }
+
+    #[cfg(feature = "xz")]
+    #[test]
+    fn test_xz_input_is_decompressed() {
+        let mut compressed = Vec::new();
+        lzma_rs::xz_compress(&mut &CODE[..], &mut compressed).unwrap();
+        assert_eq!(Compression::detect(&compressed), Some(Compression::Xz));
+        assert_eq!(decompress(compressed).unwrap(), CODE);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_multi_frame_zstd_input_is_decompressed() {
+        let frame = ruzstd::encoding::compress_to_vec(
+            &CODE[..],
+            ruzstd::encoding::CompressionLevel::Fastest,
+        );
+        let compressed = [frame.clone(), frame].concat();
+        assert_eq!(Compression::detect(&compressed), Some(Compression::Zstd));
+        assert_eq!(decompress(compressed).unwrap(), [CODE, CODE].concat());
+    }
+
+    #[test]
+    fn test_corrupt_input_names_the_format_and_file() {
+        let path = std::env::temp_dir().join(format!("robustone-xz-{}.bin", std::process::id()));
+        std::fs::write(&path, [0xfd, b'7', b'z', b'X', b'Z', 0x00, 0xff]).unwrap();
+        let message = read_input(&path).unwrap_err().to_string();
+        std::fs::remove_file(&path).unwrap();
+        assert!(message.contains("robustone-xz-"), "{message}");
+        assert!(message.contains("is xz: "), "{message}");
+    }
+}
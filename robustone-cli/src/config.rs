@@ -1,11 +1,12 @@
+use crate::annotations::Annotations;
 use crate::arch::ArchitectureSpec;
 use crate::capabilities::parser_only_configuration_message;
-use crate::command::{DisplayOptions, ValidatedConfig};
+use crate::command::{CliArgs, DisplayOptions, Nops, OutputFormat, Syntax};
 use crate::error::{CliError, Result};
-use crate::utils::parse_hex_to_bytes;
+use crate::utils::{STDIN_HEX_CODE, parse_hex_to_bytes, read_hex_stream};
 
 use robustone_core::ir::TextRenderProfile;
-use robustone_core::lookup_architecture_capability;
+use robustone_core::{NopStyle, OnDecodeError, lookup_architecture_capability};
 
 /// High-level disassembly configuration that unifies all options.
 #[derive(Debug, Clone)]
@@ -14,26 +15,93 @@ pub struct DisasmConfig {
     pub hex_bytes: Vec<u8>,
     pub start_address: u64,
     pub display_options: DisplayOptions,
-    pub skip_data: bool,
+    pub on_decode_error: OnDecodeError,
+    pub annotations: Annotations,
 }
 
 impl DisasmConfig {
+    /// Start building a configuration programmatically.
+    ///
+    /// This is the preferred alternative to filling in a deprecated
+    /// [`ValidatedConfig`](crate::command::ValidatedConfig) struct literal:
+    ///
+    /// ```
+    /// use robustone_cli::DisasmConfig;
+    ///
+    /// let config = DisasmConfig::builder()
+    ///     .arch("riscv64")
+    ///     .hex("93001000")
+    ///     .address(0x8000_0000)
+    ///     .detailed(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(config.start_address, 0x8000_0000);
+    /// ```
+    pub fn builder() -> DisasmConfigBuilder {
+        DisasmConfigBuilder::default()
+    }
+
     /// Create a disassembly configuration from validated CLI input.
-    pub fn from_validated_config(mut config: ValidatedConfig) -> Result<Self> {
+    #[deprecated(
+        since = "0.1.0",
+        note = "use DisasmConfig::builder() or Cli::validate()"
+    )]
+    #[allow(deprecated)]
+    pub fn from_validated_config(config: crate::command::ValidatedConfig) -> Result<Self> {
+        let crate::command::ValidatedConfig {
+            arch_mode,
+            hex_code,
+            address,
+            detailed,
+            alias_regs,
+            real_detail,
+            skip_data,
+            unsigned_immediate,
+            json,
+            format,
+            comments_file,
+            version: _,
+            layout,
+            uppercase,
+            labels,
+            explain,
+            expand_compressed,
+            dataflow,
+            mark_hints,
+            syntax,
+            nops,
+        } = config;
+        Self::from_cli_args(CliArgs {
+            arch_mode,
+            hex_code,
+            address,
+            detailed,
+            alias_regs,
+            real_detail,
+            skip_data,
+            unsigned_immediate,
+            json,
+            format,
+            comments_file,
+            layout,
+            uppercase,
+            labels,
+            explain,
+            expand_compressed,
+            dataflow,
+            mark_hints,
+            syntax,
+            nops,
+        })
+    }
+
+    /// Create a disassembly configuration from validated CLI arguments.
+    pub(crate) fn from_cli_args(mut config: CliArgs) -> Result<Self> {
         // Parse and validate architecture specification
         let arch_mode = config.arch_mode.take().ok_or_else(|| {
             CliError::validation("arch_mode", "Architecture specification is required")
         })?;
-        let arch_spec = ArchitectureSpec::parse(&arch_mode)
-            .map_err(|e| CliError::parse("architecture", e.to_string()))?;
-
-        if let Some(capability) = lookup_architecture_capability(arch_spec.arch.name())
-            && !capability.decode_supported
-        {
-            return Err(CliError::Configuration(parser_only_configuration_message(
-                &arch_mode, capability,
-            )));
-        }
+        let arch_spec = parse_decodable_arch_spec(&arch_mode)?;
 
         let display_options = config.display_options();
 
@@ -43,22 +111,29 @@ impl DisasmConfig {
         let hex_input = config.hex_code.take().ok_or_else(|| {
             CliError::validation("hex_code", "Hexadecimal code is required for disassembly")
         })?;
-        let hex_bytes = parse_hex_to_bytes(&hex_input)?;
+        let hex_bytes = if hex_input == STDIN_HEX_CODE {
+            read_hex_stream(std::io::stdin().lock())?
+        } else {
+            parse_hex_to_bytes(&hex_input)?
+        };
 
         Ok(DisasmConfig {
             arch_spec,
             hex_bytes,
             start_address: config.address_or_default(),
             display_options,
-            skip_data: config.skip_data,
+            on_decode_error: OnDecodeError::from_skip_data(config.skip_data),
+            annotations: match config.comments_file.as_deref() {
+                Some(path) => Annotations::load(path)?,
+                None => Annotations::default(),
+            },
         })
     }
 
     /// Legacy method for backward compatibility.
     /// Builds a configuration from CLI input and performs full validation.
     pub fn config_from_cli(cli: &crate::command::Cli) -> Result<Self> {
-        let validated = cli.validate()?;
-        Self::from_validated_config(validated)
+        cli.validate()
     }
 
     /// Get the architecture name as a string.
@@ -118,13 +193,30 @@ impl DisasmConfig {
             )));
         }
 
-        // Architecture-specific validation
+        // Listing addresses wrap at the address-space width, but a start
+        // address beyond it is a mistake rather than something to wrap.
+        let width = self.arch_spec.arch.address_width();
+        if width.wrap(self.start_address) != self.start_address {
+            return Err(CliError::validation(
+                "address",
+                format!(
+                    "address {:#x} does not fit {} ({}-bit addresses)",
+                    self.start_address,
+                    self.arch_name(),
+                    width.bits()
+                ),
+            ));
+        }
+
+        // Architecture-specific validation. With SKIPDATA a stray trailing
+        // byte is listed as data instead.
         if self.arch_spec.arch.name().starts_with("riscv")
+            && self.on_decode_error == OnDecodeError::Stop
             && !self.hex_bytes.len().is_multiple_of(2)
         {
             return Err(CliError::validation(
                 "hex_code",
-                "RISC-V hex code must have even number of bytes",
+                "RISC-V hex code must have even number of bytes (use --skip-data to list the trailing byte as data)",
             ));
         }
 
@@ -132,35 +224,364 @@ impl DisasmConfig {
     }
 }
 
+/// Fluent builder for [`DisasmConfig`], created with [`DisasmConfig::builder`].
+///
+/// Every option defaults to off; `build` validates the architecture and input
+/// the same way the CLI does.
+#[derive(Debug, Clone, Default)]
+pub struct DisasmConfigBuilder {
+    arch: Option<String>,
+    hex: Option<String>,
+    bytes: Option<Vec<u8>>,
+    address: u64,
+    detailed: bool,
+    alias_regs: bool,
+    real_detail: bool,
+    on_decode_error: OnDecodeError,
+    unsigned_immediate: bool,
+    format: OutputFormat,
+    annotations: Annotations,
+    layout: ColumnLayout,
+    uppercase: bool,
+    labels: bool,
+    explain: bool,
+    expand_compressed: bool,
+    dataflow: bool,
+    mark_hints: bool,
+    syntax: Syntax,
+    nops: Nops,
+}
+
+impl DisasmConfigBuilder {
+    /// Architecture specification, including `+` modifiers (e.g. `riscv32+noalias`).
+    pub fn arch(mut self, arch: impl Into<String>) -> Self {
+        self.arch = Some(arch.into());
+        self
+    }
+
+    /// Instruction bytes as a hex string, in the same syntax as `HEX_CODE`.
+    pub fn hex(mut self, hex: impl Into<String>) -> Self {
+        self.hex = Some(hex.into());
+        self
+    }
+
+    /// Instruction bytes, in memory order.
+    pub fn bytes(mut self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.bytes = Some(bytes.into());
+        self
+    }
+
+    /// Address of the first byte.
+    pub fn address(mut self, address: u64) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Show raw instruction bytes (`-d`).
+    pub fn detailed(mut self, enabled: bool) -> Self {
+        self.detailed = enabled;
+        self
+    }
+
+    /// Prefer ABI register aliases (`-a`).
+    pub fn alias_regs(mut self, enabled: bool) -> Self {
+        self.alias_regs = enabled;
+        self
+    }
+
+    /// Show detail sections (`-r`).
+    pub fn real_detail(mut self, enabled: bool) -> Self {
+        self.real_detail = enabled;
+        self
+    }
+
+    /// Emit `.byte` directives for undecodable bytes (`-s`).
+    pub fn skip_data(mut self, enabled: bool) -> Self {
+        self.on_decode_error = OnDecodeError::from_skip_data(enabled);
+        self
+    }
+
+    /// Choose what happens to bytes that fail to decode; overrides
+    /// [`Self::skip_data`].
+    pub fn on_decode_error(mut self, policy: OnDecodeError) -> Self {
+        self.on_decode_error = policy;
+        self
+    }
+
+    /// Render immediates as unsigned values (`-u`).
+    pub fn unsigned_immediate(mut self, enabled: bool) -> Self {
+        self.unsigned_immediate = enabled;
+        self
+    }
+
+    /// Select the output layout.
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Shorthand for `format(OutputFormat::Json)`.
+    pub fn json(self, enabled: bool) -> Self {
+        self.format(if enabled {
+            OutputFormat::Json
+        } else {
+            OutputFormat::Text
+        })
+    }
+
+    /// Comments appended to the listing.
+    pub fn annotations(mut self, annotations: Annotations) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
+    /// Column layout of the text listing.
+    pub fn layout(mut self, layout: ColumnLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Uppercase mnemonics and hex digits in every output format.
+    pub fn uppercase(mut self, enabled: bool) -> Self {
+        self.uppercase = enabled;
+        self
+    }
+
+    /// Replace in-range branch targets with `.L<n>` labels.
+    pub fn labels(mut self, enabled: bool) -> Self {
+        self.labels = enabled;
+        self
+    }
+
+    /// Print the encoding breakdown of every instruction in text listings.
+    pub fn explain(mut self, enabled: bool) -> Self {
+        self.explain = enabled;
+        self
+    }
+
+    /// Show the 32-bit canonical form next to compressed instructions.
+    pub fn expand_compressed(mut self, enabled: bool) -> Self {
+        self.expand_compressed = enabled;
+        self
+    }
+
+    /// Annotate register def-use chains in text listings.
+    pub fn dataflow(mut self, enabled: bool) -> Self {
+        self.dataflow = enabled;
+        self
+    }
+
+    /// Mark HINT and reserved encodings in the listing.
+    pub fn mark_hints(mut self, enabled: bool) -> Self {
+        self.mark_hints = enabled;
+        self
+    }
+
+    /// Assembly flavor of the instruction text.
+    pub fn syntax(mut self, syntax: Syntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+
+    /// How nops and HINT encodings are printed.
+    pub fn nops(mut self, nops: Nops) -> Self {
+        self.nops = nops;
+        self
+    }
+
+    /// Validate the options and produce a [`DisasmConfig`].
+    pub fn build(self) -> Result<DisasmConfig> {
+        let arch_mode = self.arch.ok_or_else(|| {
+            CliError::validation("arch_mode", "Architecture specification is required")
+        })?;
+        let arch_spec = parse_decodable_arch_spec(&arch_mode)?;
+
+        let hex_bytes = match (self.hex, self.bytes) {
+            (Some(hex), None) => {
+                if hex.trim().is_empty() {
+                    return Err(CliError::validation("hex_code", "Empty hex code provided"));
+                }
+                parse_hex_to_bytes(&hex)?
+            }
+            (None, Some(bytes)) => bytes,
+            (Some(_), Some(_)) => {
+                return Err(CliError::validation(
+                    "hex_code",
+                    "provide either hex or bytes, not both",
+                ));
+            }
+            (None, None) => {
+                return Err(CliError::validation(
+                    "hex_code",
+                    "Hexadecimal code is required for disassembly",
+                ));
+            }
+        };
+
+        let display_options = DisplayOptions {
+            detailed: self.detailed,
+            alias_regs: self.alias_regs,
+            real_detail: self.real_detail,
+            unsigned_immediate: self.unsigned_immediate,
+            json: self.format == OutputFormat::Json,
+            format: self.format,
+            layout: self.layout,
+            uppercase: self.uppercase,
+            labels: self.labels,
+            explain: self.explain,
+            expand_compressed: self.expand_compressed,
+            dataflow: self.dataflow,
+            mark_hints: self.mark_hints,
+            syntax: self.syntax,
+            nops: self.nops,
+        };
+        validate_display_options(&display_options)?;
+
+        let config = DisasmConfig {
+            arch_spec,
+            hex_bytes,
+            start_address: self.address,
+            display_options,
+            on_decode_error: self.on_decode_error,
+            annotations: self.annotations,
+        };
+        config.validate_for_disassembly()?;
+        Ok(config)
+    }
+}
+
+/// How the bytes column of a text listing is split into groups.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ByteGrouping {
+    /// One byte per group, cstool style: `93 00 10 00`.
+    #[default]
+    Bytes,
+    /// Two bytes per group: `9300 1000`.
+    Pairs,
+    /// Four bytes per group: `93001000`.
+    Words,
+    /// A single run of hex digits with no separators.
+    None,
+}
+
+impl ByteGrouping {
+    /// Number of bytes per group, or `None` for an unbroken run.
+    pub fn group_size(self) -> Option<usize> {
+        match self {
+            ByteGrouping::Bytes => Some(1),
+            ByteGrouping::Pairs => Some(2),
+            ByteGrouping::Words => Some(4),
+            ByteGrouping::None => None,
+        }
+    }
+
+    /// Render `bytes` in memory order using this grouping.
+    pub fn render(self, bytes: &[u8]) -> String {
+        let Some(size) = self.group_size() else {
+            return hex::encode(bytes);
+        };
+        bytes
+            .chunks(size)
+            .map(hex::encode)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Separator placed between text listing columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColumnAlignment {
+    /// A tab between mnemonic and operands, as cstool prints.
+    #[default]
+    Tabs,
+    /// Spaces only, for output that must not depend on tab stops.
+    Spaces,
+}
+
+/// What the address column of a text listing shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AddressDisplay {
+    /// The absolute address of each instruction.
+    #[default]
+    Absolute,
+    /// The offset of each instruction from the start address.
+    Offset,
+    /// No address column at all.
+    Hidden,
+}
+
+/// Column layout of the text listing used by `DisassemblyFormatter`.
+///
+/// The default reproduces cstool's layout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColumnLayout {
+    /// What the address column shows.
+    pub address: AddressDisplay,
+    /// Minimum number of hex digits in the address column (zero-padded);
+    /// `0` prints the address at its natural width.
+    pub address_width: usize,
+    /// Grouping of the raw bytes column (shown with `-d`/`-r`).
+    pub byte_grouping: ByteGrouping,
+    /// Show each instruction's bytes as one number (`0x00000297`) in a
+    /// column after the bytes column.
+    pub word_column: bool,
+    /// Minimum mnemonic column width; shorter mnemonics are space-padded.
+    pub mnemonic_width: usize,
+    /// Separator between the mnemonic and operand columns.
+    pub alignment: ColumnAlignment,
+}
+
 /// Configuration for output formatting and display options.
 #[derive(Debug, Clone)]
 pub struct OutputConfig {
     pub text_profile: TextRenderProfile,
+    /// Assembly flavor `text_profile` was derived from.
+    pub syntax: Syntax,
+    /// How nops and HINT encodings are printed.
+    pub nops: NopStyle,
     pub alias_regs: bool,
     pub capstone_aliases: bool,
     pub compressed_aliases: bool,
     pub unsigned_immediate: bool,
     pub show_hex: bool,
     pub show_detail_sections: bool,
+    /// List each instruction's typed operands (`-d`).
+    pub show_operands: bool,
     pub json: bool,
+    pub format: OutputFormat,
+    pub annotations: Annotations,
+    pub layout: ColumnLayout,
+    /// Uppercase mnemonics and hex digits (addresses, bytes, immediates).
+    pub uppercase: bool,
+    /// Replace in-range branch targets with `.L<n>` labels.
+    pub labels: bool,
 }
 
 impl OutputConfig {
     /// Create output configuration based on display options.
     pub fn from_display_options(display: &DisplayOptions) -> Self {
         Self {
-            text_profile: if display.real_detail {
-                TextRenderProfile::VerboseDebug
-            } else {
-                TextRenderProfile::Capstone
-            },
+            text_profile: display.syntax.text_profile(display.real_detail),
+            syntax: display.syntax,
+            nops: display.nops.style(),
             alias_regs: display.alias_regs,
             capstone_aliases: true,
             compressed_aliases: true,
             unsigned_immediate: display.unsigned_immediate,
             show_hex: display.detailed || display.real_detail,
             show_detail_sections: display.real_detail,
+            // `-r` already lists operands in its detail block.
+            show_operands: display.detailed && !display.real_detail,
             json: display.json,
+            format: if display.json {
+                OutputFormat::Json
+            } else {
+                display.format
+            },
+            annotations: Annotations::default(),
+            layout: display.layout,
+            uppercase: display.uppercase,
+            labels: display.labels,
         }
     }
 
@@ -168,13 +589,28 @@ impl OutputConfig {
     pub fn minimal() -> Self {
         Self {
             text_profile: TextRenderProfile::Capstone,
+            syntax: Syntax::Default,
+            nops: NopStyle::Profile,
             alias_regs: false,
             capstone_aliases: true,
             compressed_aliases: true,
             unsigned_immediate: false,
             show_hex: false,
             show_detail_sections: false,
+            show_operands: false,
             json: false,
+            format: OutputFormat::Text,
+            annotations: Annotations::default(),
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
+        }
+    }
+
+    /// Start building an output configuration from [`Self::minimal`].
+    pub fn builder() -> OutputConfigBuilder {
+        OutputConfigBuilder {
+            config: Self::minimal(),
         }
     }
 
@@ -182,17 +618,146 @@ impl OutputConfig {
     pub fn canonical_json() -> Self {
         Self {
             text_profile: TextRenderProfile::Canonical,
+            syntax: Syntax::Default,
+            nops: NopStyle::Profile,
             alias_regs: false,
             capstone_aliases: false,
             compressed_aliases: false,
             unsigned_immediate: false,
             show_hex: false,
             show_detail_sections: false,
+            show_operands: false,
             json: true,
+            format: OutputFormat::Json,
+            annotations: Annotations::default(),
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
         }
     }
 }
 
+/// Fluent builder for [`OutputConfig`], created with [`OutputConfig::builder`].
+#[derive(Debug, Clone)]
+pub struct OutputConfigBuilder {
+    config: OutputConfig,
+}
+
+impl OutputConfigBuilder {
+    /// Operand text style.
+    pub fn text_profile(mut self, profile: TextRenderProfile) -> Self {
+        self.config.text_profile = profile;
+        self
+    }
+
+    /// Assembly flavor; also selects its text profile.
+    pub fn syntax(mut self, syntax: Syntax) -> Self {
+        self.config.syntax = syntax;
+        self.config.text_profile = syntax.text_profile(false);
+        self
+    }
+
+    /// How nops and HINT encodings are printed.
+    pub fn nops(mut self, nops: NopStyle) -> Self {
+        self.config.nops = nops;
+        self
+    }
+
+    /// Prefer ABI register aliases.
+    pub fn alias_regs(mut self, enabled: bool) -> Self {
+        self.config.alias_regs = enabled;
+        self
+    }
+
+    /// Apply Capstone-compatible instruction aliases.
+    pub fn capstone_aliases(mut self, enabled: bool) -> Self {
+        self.config.capstone_aliases = enabled;
+        self
+    }
+
+    /// Apply aliases to compressed instructions.
+    pub fn compressed_aliases(mut self, enabled: bool) -> Self {
+        self.config.compressed_aliases = enabled;
+        self
+    }
+
+    /// Render immediates as unsigned values.
+    pub fn unsigned_immediate(mut self, enabled: bool) -> Self {
+        self.config.unsigned_immediate = enabled;
+        self
+    }
+
+    /// Show raw instruction bytes.
+    pub fn show_hex(mut self, enabled: bool) -> Self {
+        self.config.show_hex = enabled;
+        self
+    }
+
+    /// Show detail sections after each instruction.
+    pub fn show_detail_sections(mut self, enabled: bool) -> Self {
+        self.config.show_detail_sections = enabled;
+        self
+    }
+
+    /// List typed operands after each instruction.
+    pub fn show_operands(mut self, enabled: bool) -> Self {
+        self.config.show_operands = enabled;
+        self
+    }
+
+    /// Select the output layout; keeps `json` in sync with the format.
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        self.config.format = format;
+        self.config.json = format == OutputFormat::Json;
+        self
+    }
+
+    /// Comments appended to the listing.
+    pub fn annotations(mut self, annotations: Annotations) -> Self {
+        self.config.annotations = annotations;
+        self
+    }
+
+    /// Column layout of the text listing.
+    pub fn layout(mut self, layout: ColumnLayout) -> Self {
+        self.config.layout = layout;
+        self
+    }
+
+    /// Uppercase mnemonics and hex digits in every output format.
+    pub fn uppercase(mut self, enabled: bool) -> Self {
+        self.config.uppercase = enabled;
+        self
+    }
+
+    /// Replace in-range branch targets with `.L<n>` labels.
+    pub fn labels(mut self, enabled: bool) -> Self {
+        self.config.labels = enabled;
+        self
+    }
+
+    /// Produce the configured [`OutputConfig`].
+    pub fn build(self) -> OutputConfig {
+        self.config
+    }
+}
+
+/// Parse an architecture specification and reject parser-only targets.
+pub(crate) fn parse_decodable_arch_spec(arch_mode: &str) -> Result<ArchitectureSpec> {
+    let arch_spec = ArchitectureSpec::parse(arch_mode)
+        .map_err(|e| CliError::parse("architecture", e.to_string()))?;
+
+    if let Some(capability) = lookup_architecture_capability(arch_spec.arch.name())
+        && !capability.decode_supported
+    {
+        return Err(CliError::Configuration(parser_only_configuration_message(
+            arch_mode, capability,
+        )));
+    }
+
+    Ok(arch_spec)
+}
+
 fn validate_display_options(display: &DisplayOptions) -> Result<()> {
     let _ = display;
     Ok(())
@@ -201,6 +766,7 @@ fn validate_display_options(display: &DisplayOptions) -> Result<()> {
 impl DisasmConfig {
     pub fn output_config(&self) -> OutputConfig {
         let mut output = OutputConfig::from_display_options(&self.display_options);
+        output.annotations = self.annotations.clone();
 
         if self.arch_spec.has_option("noalias") {
             output.alias_regs = false;
@@ -217,12 +783,22 @@ impl DisasmConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::annotations::Annotations;
     use crate::arch::ArchitectureSpec;
-    use crate::command::{DisplayOptions, ValidatedConfig};
+    use crate::command::{CliArgs, DisplayOptions, Nops, OutputFormat, Syntax};
+
+    #[test]
+    fn test_byte_grouping_renders_in_memory_order() {
+        let bytes = [0x93, 0x00, 0x10, 0x00, 0x82];
+        assert_eq!(ByteGrouping::Bytes.render(&bytes), "93 00 10 00 82");
+        assert_eq!(ByteGrouping::Pairs.render(&bytes), "9300 1000 82");
+        assert_eq!(ByteGrouping::Words.render(&bytes), "93001000 82");
+        assert_eq!(ByteGrouping::None.render(&bytes), "9300100082");
+    }
 
     #[test]
     fn test_config_creation() {
-        let config = ValidatedConfig {
+        let config = CliArgs {
             arch_mode: Some("riscv32".to_string()),
             hex_code: Some("93001000".to_string()),
             address: Some(0x1000),
@@ -232,10 +808,20 @@ mod tests {
             skip_data: false,
             unsigned_immediate: false,
             json: false,
-            version: false,
+            format: OutputFormat::Text,
+            comments_file: None,
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
+            explain: false,
+            expand_compressed: false,
+            dataflow: false,
+            mark_hints: false,
+            syntax: Syntax::Default,
+            nops: Nops::Profile,
         };
 
-        let disasm_config = DisasmConfig::from_validated_config(config).unwrap();
+        let disasm_config = DisasmConfig::from_cli_args(config).unwrap();
         assert_eq!(disasm_config.arch_name(), "riscv32");
         assert_eq!(disasm_config.start_address, 0x1000);
         assert_eq!(disasm_config.hex_bytes.len(), 4);
@@ -249,6 +835,16 @@ mod tests {
             real_detail: false,
             unsigned_immediate: false,
             json: false,
+            format: OutputFormat::Text,
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
+            explain: false,
+            expand_compressed: false,
+            dataflow: false,
+            mark_hints: false,
+            syntax: Syntax::Default,
+            nops: Nops::Profile,
         };
 
         let output = OutputConfig::from_display_options(&display);
@@ -262,6 +858,21 @@ mod tests {
         assert!(!output.json);
     }
 
+    #[test]
+    fn test_output_config_syntax_selects_text_profile() {
+        let output = OutputConfig::builder().syntax(Syntax::Att).build();
+        assert_eq!(output.syntax, Syntax::Att);
+        assert_eq!(output.text_profile, TextRenderProfile::Att);
+
+        let output = OutputConfig::builder().syntax(Syntax::Intel).build();
+        assert_eq!(output.text_profile, TextRenderProfile::Capstone);
+        assert_eq!(
+            Syntax::Intel.text_profile(true),
+            TextRenderProfile::VerboseDebug
+        );
+        assert_eq!(Syntax::Llvm.text_profile(true), TextRenderProfile::Llvm);
+    }
+
     #[test]
     fn test_riscv_noalias_modifiers_adjust_output_config() {
         let config = DisasmConfig {
@@ -274,8 +885,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let output = config.output_config();
 
@@ -305,8 +927,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
 
         let error = config
@@ -316,4 +949,72 @@ mod tests {
         assert!(error.to_string().contains("currently parser-only"));
         assert!(error.to_string().contains("robustone --capabilities"));
     }
+
+    #[test]
+    fn test_builder_matches_validated_config_path() {
+        let built = DisasmConfig::builder()
+            .arch("riscv32")
+            .hex("93001000")
+            .address(0x1000)
+            .detailed(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(built.arch_name(), "riscv32");
+        assert_eq!(built.start_address, 0x1000);
+        assert_eq!(built.hex_bytes, [0x93, 0x00, 0x10, 0x00]);
+        assert!(built.display_options.detailed);
+        assert!(!built.display_options.json);
+
+        let from_bytes = DisasmConfig::builder()
+            .arch("riscv32")
+            .bytes([0x93, 0x00, 0x10, 0x00])
+            .json(true)
+            .build()
+            .unwrap();
+        assert_eq!(from_bytes.hex_bytes, built.hex_bytes);
+        assert_eq!(from_bytes.output_config().format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_builder_validates_inputs() {
+        let missing_arch = DisasmConfig::builder().hex("93001000").build().unwrap_err();
+        assert!(
+            matches!(missing_arch, CliError::Validation { ref field, .. } if field == "arch_mode")
+        );
+
+        let missing_code = DisasmConfig::builder().arch("riscv32").build().unwrap_err();
+        assert!(
+            matches!(missing_code, CliError::Validation { ref field, .. } if field == "hex_code")
+        );
+
+        assert!(
+            DisasmConfig::builder()
+                .arch("riscv32")
+                .hex("93001000")
+                .bytes([0x13])
+                .build()
+                .is_err()
+        );
+        assert!(
+            DisasmConfig::builder()
+                .arch("riscv32e")
+                .hex("93001000")
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_output_config_builder_keeps_json_in_sync() {
+        let output = OutputConfig::builder()
+            .show_hex(true)
+            .format(OutputFormat::Json)
+            .build();
+        assert!(output.show_hex);
+        assert!(output.json);
+
+        let gdb = OutputConfig::builder().format(OutputFormat::Gdb).build();
+        assert!(!gdb.json);
+    }
 }
@@ -1,25 +1,56 @@
-use crate::config::{DisasmConfig, OutputConfig};
+use crate::arch::Architecture;
+use crate::command::{MisalignedPolicy, OutputFormat};
+use crate::config::{AddressDisplay, ColumnAlignment, DisasmConfig, OutputConfig};
+use crate::elf::Relocations;
+use crate::labels::{Labels, branch_target};
+use crate::limits::ResourceLimits;
+use crate::utils::{format_bytes_as_hex, uppercase_hex_literals};
 use robustone_arm::ArmHandler;
-use robustone_core::ir::TextRenderProfile;
+use robustone_core::common::ArchitectureProfile;
+use robustone_core::ir::{ArchitectureId, DecodedInstruction, Operand, TextRenderProfile};
 use robustone_core::{
-    ArchitectureDispatcher, DisasmError, Instruction, render_disassembly, render_instruction_text,
+    AddressWidth, ArchitectureDispatcher, DisasmError, Instruction, is_data_directive,
+    render_disassembly, render_instruction_text,
+};
+use robustone_core::{
+    DataStep, DecodeStats, DefUse, EncodingBreakdown, EncodingClass, InstructionMetadata,
+    MnemonicGroup, NopStyle, OnDecodeError, RegisterInfo, RenderOptions, RenderedIssue,
 };
-use robustone_core::{RenderOptions, RenderedIssue};
 use robustone_loongarch::LoongArchHandler;
 use robustone_riscv::{RiscVHandler, types::RiscVRegister};
 use robustone_x86::X86Handler;
 use serde::Serialize;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::LazyLock;
+use std::time::Instant;
 
-fn create_dispatcher(_arch: &str) -> ArchitectureDispatcher {
+fn create_dispatcher(detail: bool) -> ArchitectureDispatcher {
     let mut dispatcher = ArchitectureDispatcher::new();
     dispatcher.register(Box::new(RiscVHandler::new()));
     dispatcher.register(Box::new(ArmHandler::new()));
     dispatcher.register(Box::new(X86Handler::new()));
     dispatcher.register(Box::new(LoongArchHandler::new()));
+    dispatcher.set_detail(detail);
     dispatcher
 }
 
+// Building a dispatcher constructs every extension table, so engines share
+// one per detail setting instead of building their own.
+static DETAILED_DISPATCHER: LazyLock<ArchitectureDispatcher> =
+    LazyLock::new(|| create_dispatcher(true));
+static PLAIN_DISPATCHER: LazyLock<ArchitectureDispatcher> =
+    LazyLock::new(|| create_dispatcher(false));
+
+fn shared_dispatcher(detail: bool) -> &'static ArchitectureDispatcher {
+    if detail {
+        &DETAILED_DISPATCHER
+    } else {
+        &PLAIN_DISPATCHER
+    }
+}
+
 /// Structured error information captured during disassembly.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct DisassemblyIssue {
@@ -86,12 +117,14 @@ impl DisassemblyIssue {
                 kind,
                 message,
                 architecture: disasm_arch,
+                ..
             } => (
                 kind.clone(),
                 message.clone(),
                 disasm_arch.clone().or(architecture),
             ),
-            crate::error::CliError::Validation { .. } => (
+            crate::error::CliError::Validation { .. }
+            | crate::error::CliError::InvalidConfig(_) => (
                 "validation_error".to_string(),
                 error.to_string(),
                 architecture,
@@ -163,14 +196,212 @@ impl DisassemblyIssue {
         }
     }
 }
+/// `.byte` pseudo-instruction listing `bytes`.
+fn byte_directive(address: u64, bytes: &[u8]) -> Instruction {
+    let operands = bytes
+        .iter()
+        .map(|b| format!("0x{b:02x}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Instruction::new(address, bytes.to_vec(), ".byte".to_string(), operands)
+}
+
+/// Shortest printable run listed as a string rather than as bytes.
+const MIN_STRING_CHARS: usize = 4;
+
+/// How SKIPDATA ranges are turned into data directives.
+#[derive(Debug, Clone, Copy)]
+struct DataLayout {
+    width: AddressWidth,
+    big_endian: bool,
+    /// Directive and size of a pointer-sized value (`.half`/`.word`/`.dword`,
+    /// or `.word`/`.long`/`.quad` for x86, whose `.word` is 16 bits).
+    pointer: (&'static str, usize),
+}
+
+impl DataLayout {
+    fn new(x86: bool, width: AddressWidth, big_endian: bool) -> Self {
+        let pointer = match (width, x86) {
+            (AddressWidth::Bits16, false) => (".half", 2),
+            (AddressWidth::Bits16, true) => (".word", 2),
+            (AddressWidth::Bits32, false) => (".word", 4),
+            (AddressWidth::Bits64, false) => (".dword", 8),
+            (AddressWidth::Bits32, true) => (".long", 4),
+            (AddressWidth::Bits64, true) => (".quad", 8),
+        };
+        Self {
+            width,
+            big_endian,
+            pointer,
+        }
+    }
+}
+
+/// Byte length of the printable UTF-8 string at the start of `bytes`, and
+/// whether a NUL terminator follows it, when it is at least
+/// [`MIN_STRING_CHARS`] characters long.
+fn string_at(bytes: &[u8]) -> Option<(usize, bool)> {
+    let valid = match std::str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(error) => std::str::from_utf8(&bytes[..error.valid_up_to()]).ok()?,
+    };
+    let printable = |c: &char| !c.is_control() || matches!(c, '\t' | '\n' | '\r');
+    let (chars, len) = valid
+        .chars()
+        .take_while(printable)
+        .fold((0, 0), |(chars, len), c| (chars + 1, len + c.len_utf8()));
+    (chars >= MIN_STRING_CHARS).then(|| (len, bytes.get(len) == Some(&0)))
+}
+
+/// `.ascii`/`.asciz` directive for the string in `bytes`.
+fn string_directive(address: u64, bytes: &[u8], terminated: bool) -> Instruction {
+    let text = String::from_utf8_lossy(&bytes[..bytes.len() - usize::from(terminated)]);
+    let escaped: String = text
+        .chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\t' => "\\t".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            c => c.to_string(),
+        })
+        .collect();
+    let mnemonic = if terminated { ".asciz" } else { ".ascii" };
+    Instruction::new(
+        address,
+        bytes.to_vec(),
+        mnemonic.to_string(),
+        format!("\"{escaped}\""),
+    )
+}
+
+/// Data directives for a range SKIPDATA could not decode: printable runs
+/// become `.ascii`/`.asciz`, aligned pointer-sized chunks become `.word`
+/// (or the architecture's equivalent), and whatever is left stays `.byte`.
+fn data_directives(address: u64, bytes: &[u8], layout: DataLayout) -> Vec<Instruction> {
+    let (pointer_directive, pointer_size) = layout.pointer;
+    let mut items = Vec::new();
+    let mut loose_start = None;
+    let mut index = 0;
+    let at = |index: usize| layout.width.offset(address, index as i64);
+    let flush_loose = |items: &mut Vec<Instruction>, start: &mut Option<usize>, end: usize| {
+        if let Some(start) = start.take() {
+            items.push(byte_directive(at(start), &bytes[start..end]));
+        }
+    };
+
+    while index < bytes.len() {
+        if let Some((len, terminated)) = string_at(&bytes[index..]) {
+            flush_loose(&mut items, &mut loose_start, index);
+            let end = index + len + usize::from(terminated);
+            items.push(string_directive(at(index), &bytes[index..end], terminated));
+            index = end;
+        } else if at(index).is_multiple_of(pointer_size as u64)
+            && let Some(chunk) = bytes.get(index..index + pointer_size)
+            && (1..pointer_size).all(|skip| string_at(&bytes[index + skip..]).is_none())
+        {
+            flush_loose(&mut items, &mut loose_start, index);
+            let value = chunk
+                .iter()
+                .enumerate()
+                .fold(0u64, |acc, (position, byte)| {
+                    let shift = if layout.big_endian {
+                        8 * (pointer_size - 1 - position)
+                    } else {
+                        8 * position
+                    };
+                    acc | (u64::from(*byte) << shift)
+                });
+            items.push(Instruction::new(
+                at(index),
+                chunk.to_vec(),
+                pointer_directive.to_string(),
+                format!("0x{value:0digits$x}", digits = pointer_size * 2),
+            ));
+            index += pointer_size;
+        } else {
+            loose_start.get_or_insert(index);
+            index += 1;
+        }
+    }
+    flush_loose(&mut items, &mut loose_start, bytes.len());
+    items
+}
+
+/// Data items for the bytes left after the last complete instruction: a
+/// `.half` per 2-byte unit when `halves` is set, then one `.byte` for the rest.
+fn trailing_data(
+    bytes: &[u8],
+    address: u64,
+    width: AddressWidth,
+    halves: bool,
+    big_endian: bool,
+) -> Vec<Instruction> {
+    let mut items = Vec::new();
+    let mut address = address;
+    let mut rest = bytes;
+    while halves && rest.len() >= 2 {
+        let pair = [rest[0], rest[1]];
+        let value = if big_endian {
+            u16::from_be_bytes(pair)
+        } else {
+            u16::from_le_bytes(pair)
+        };
+        items.push(Instruction::new(
+            address,
+            pair.to_vec(),
+            ".half".to_string(),
+            format!("0x{value:04x}"),
+        ));
+        address = width.offset(address, 2);
+        rest = &rest[2..];
+    }
+    if !rest.is_empty() {
+        items.push(byte_directive(address, rest));
+    }
+    items
+}
+
+/// An encoding the decoder rejected and the run stepped over as data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndecodedEncoding {
+    pub address: u64,
+    /// Stable error kind, as in [`DisassemblyIssue::kind`].
+    pub kind: String,
+    /// Up to eight bytes starting at `address`.
+    pub bytes: Vec<u8>,
+    /// Bytes the data step skipped.
+    pub skipped: usize,
+}
+
 /// Result of a disassembly operation with additional metadata.
 #[derive(Debug)]
 pub struct DisassemblyResult {
     pub instructions: Vec<Instruction>,
     pub start_address: u64,
     pub architecture: String,
+    /// Address space the listing's addresses wrap in.
+    pub address_width: AddressWidth,
     pub bytes_processed: usize,
     pub errors: Vec<DisassemblyIssue>,
+    /// Non-fatal findings, such as instructions at misaligned addresses.
+    pub warnings: Vec<DisassemblyIssue>,
+    /// Encoding breakdowns keyed by instruction address, filled for `--explain`.
+    pub explanations: BTreeMap<u64, EncodingBreakdown>,
+    /// Extension, privilege, and latency class keyed by address, filled for
+    /// `--explain`.
+    pub metadata: BTreeMap<u64, InstructionMetadata>,
+    /// 32-bit canonical forms of compressed instructions, keyed by address.
+    pub expansions: BTreeMap<u64, DecodedInstruction>,
+    /// Register def-use chains over `instructions`, filled for `--dataflow`.
+    pub dataflow: Option<DefUse>,
+    /// HINT and reserved encodings keyed by address, filled for `--mark-hints`.
+    pub encoding_classes: BTreeMap<u64, EncodingClass>,
+    /// Decode failures skipped or listed as data, for `--unsupported-summary`.
+    pub undecoded: Vec<UndecodedEncoding>,
+    /// Whether instructions are stored most significant byte first.
+    pub big_endian: bool,
 }
 
 impl DisassemblyResult {
@@ -179,9 +410,18 @@ impl DisassemblyResult {
         Self {
             instructions: Vec::new(),
             start_address,
+            address_width: AddressWidth::for_architecture(&architecture),
             architecture,
             bytes_processed: 0,
             errors: Vec::new(),
+            warnings: Vec::new(),
+            explanations: BTreeMap::new(),
+            metadata: BTreeMap::new(),
+            expansions: BTreeMap::new(),
+            dataflow: None,
+            encoding_classes: BTreeMap::new(),
+            undecoded: Vec::new(),
+            big_endian: false,
         }
     }
 
@@ -201,6 +441,11 @@ impl DisassemblyResult {
         self.errors.push(error);
     }
 
+    /// Add a non-fatal warning to the result.
+    pub fn add_warning(&mut self, warning: DisassemblyIssue) {
+        self.warnings.push(warning);
+    }
+
     /// Get the number of successfully disassembled instructions.
     pub fn instruction_count(&self) -> usize {
         self.instructions.len()
@@ -218,7 +463,8 @@ impl DisassemblyResult {
 
     /// Get the final address after processing all instructions.
     pub fn final_address(&self) -> u64 {
-        self.start_address + self.bytes_processed as u64
+        self.address_width
+            .offset(self.start_address, self.bytes_processed as i64)
     }
 }
 
@@ -242,11 +488,39 @@ impl<'a> IntoIterator for &'a DisassemblyResult {
     }
 }
 
+/// Decision returned by engine hooks to continue or abort a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookAction {
+    /// Keep disassembling.
+    Continue,
+    /// Stop after the current item and return the partial result.
+    Stop,
+}
+
+/// Callback invoked for every instruction (or skip-data item) produced.
+pub type InstructionHook = Box<dyn FnMut(&Instruction) -> HookAction + Send>;
+
+/// Callback invoked with the input offset and error of every decode failure.
+pub type ErrorHook = Box<dyn FnMut(usize, &DisasmError) -> HookAction + Send>;
+
 /// High-level disassembly engine that processes byte sequences.
+///
+/// An engine is `Send` but not `Sync`: its hooks are `FnMut` and run on the
+/// thread calling [`DisassemblyEngine::disassemble`]. Give each worker
+/// thread its own engine; they all decode through the same process-wide
+/// dispatchers.
 pub struct DisassemblyEngine {
-    dispatcher: RefCell<ArchitectureDispatcher>,
+    dispatcher: Cell<&'static ArchitectureDispatcher>,
     detail: bool,
     skip_data: bool,
+    data_directives: bool,
+    misaligned: MisalignedPolicy,
+    address_width: Option<AddressWidth>,
+    data_ranges: Vec<Range<u64>>,
+    limits: ResourceLimits,
+    instruction_hook: RefCell<Option<InstructionHook>>,
+    error_hook: RefCell<Option<ErrorHook>>,
+    stats: RefCell<DecodeStats>,
 }
 
 impl Default for DisassemblyEngine {
@@ -257,11 +531,22 @@ impl Default for DisassemblyEngine {
 
 impl DisassemblyEngine {
     /// Create a new disassembly engine for the given architecture.
-    pub fn new(arch: &str) -> Self {
+    ///
+    /// Engines are cheap: the handlers live in process-wide dispatchers that
+    /// are built on first use.
+    pub fn new(_arch: &str) -> Self {
         Self {
-            dispatcher: RefCell::new(create_dispatcher(arch)),
+            dispatcher: Cell::new(shared_dispatcher(false)),
             detail: false,
             skip_data: false,
+            data_directives: false,
+            misaligned: MisalignedPolicy::Allow,
+            address_width: None,
+            data_ranges: Vec::new(),
+            limits: ResourceLimits::unlimited(),
+            instruction_hook: RefCell::new(None),
+            error_hook: RefCell::new(None),
+            stats: RefCell::new(DecodeStats::default()),
         }
     }
 
@@ -275,7 +560,7 @@ impl DisassemblyEngine {
     /// This mirrors Capstone's `CS_OPT_DETAIL` option.
     pub fn with_detail(mut self, detail: bool) -> Self {
         self.detail = detail;
-        self.dispatcher.borrow_mut().set_detail(detail);
+        self.dispatcher.set(shared_dispatcher(detail));
         self
     }
 
@@ -285,6 +570,87 @@ impl DisassemblyEngine {
         self
     }
 
+    /// List each SKIPDATA run as a whole, with strings as `.ascii`/`.asciz`
+    /// and aligned pointer-sized values as `.word` (or the architecture's
+    /// equivalent), instead of one `.byte` item per data step as cstool does.
+    pub fn with_data_directives(mut self, data_directives: bool) -> Self {
+        self.data_directives = data_directives;
+        self
+    }
+
+    /// Choose how instructions at misaligned addresses are handled.
+    pub fn with_misaligned_policy(mut self, policy: MisalignedPolicy) -> Self {
+        self.misaligned = policy;
+        self
+    }
+
+    /// Override the address-space width addresses wrap in.
+    ///
+    /// By default it follows the architecture: 32-bit targets wrap from
+    /// `0xffffffff` to `0`, 64-bit targets from `u64::MAX`.
+    pub fn with_address_width(mut self, width: AddressWidth) -> Self {
+        self.address_width = Some(width);
+        self
+    }
+
+    /// List the bytes in `ranges` as data directives instead of decoding them,
+    /// whether or not SKIPDATA is on.
+    ///
+    /// Used for the MMIO and reserved regions of a `--memory-map`.
+    pub fn with_data_ranges(mut self, ranges: Vec<Range<u64>>) -> Self {
+        self.data_ranges = ranges;
+        self
+    }
+
+    /// Cap the instructions and wall-clock time of each [`Self::disassemble`]
+    /// call; a run that reaches a cap fails with
+    /// [`DisasmError::LimitExceeded`]. The output cap is enforced by the
+    /// executor, which renders the listing.
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Register a hook called for every instruction as soon as it is decoded.
+    ///
+    /// Returning [`HookAction::Stop`] ends the run early; instructions seen so
+    /// far (including the current one) are kept in the result.
+    pub fn on_instruction<F>(self, hook: F) -> Self
+    where
+        F: FnMut(&Instruction) -> HookAction + Send + 'static,
+    {
+        *self.instruction_hook.borrow_mut() = Some(Box::new(hook));
+        self
+    }
+
+    /// Register a hook called with `(input_offset, error)` for every decode failure.
+    ///
+    /// Returning [`HookAction::Stop`] ends the run with the partial result and
+    /// the failure recorded in [`DisassemblyResult::errors`] instead of
+    /// returning an error. Under [`OnDecodeError::Callback`], returning
+    /// [`HookAction::Continue`] lists the bytes as data and keeps going.
+    pub fn on_error<F>(self, hook: F) -> Self
+    where
+        F: FnMut(usize, &DisasmError) -> HookAction + Send + 'static,
+    {
+        *self.error_hook.borrow_mut() = Some(Box::new(hook));
+        self
+    }
+
+    fn notify_instruction(&self, instruction: &Instruction) -> HookAction {
+        match self.instruction_hook.borrow_mut().as_mut() {
+            Some(hook) => hook(instruction),
+            None => HookAction::Continue,
+        }
+    }
+
+    fn notify_error(&self, offset: usize, error: &DisasmError) -> HookAction {
+        match self.error_hook.borrow_mut().as_mut() {
+            Some(hook) => hook(offset, error),
+            None => HookAction::Continue,
+        }
+    }
+
     /// Disassemble bytes using the provided configuration.
     pub fn disassemble(&self, config: &DisasmConfig) -> Result<DisassemblyResult, DisasmError> {
         config
@@ -293,80 +659,419 @@ impl DisassemblyEngine {
 
         // Control decode-time detail generation based on display options.
         let detail = config.display_options.detailed || config.display_options.real_detail;
-        self.dispatcher.borrow_mut().set_detail(detail);
-
-        let mut result =
-            DisassemblyResult::new(config.start_address, config.arch_name().to_string());
+        self.dispatcher.set(shared_dispatcher(detail));
+        let explain = config.display_options.explain;
+        let expand_compressed = config.display_options.expand_compressed;
+        let mark_hints = config.display_options.mark_hints;
+
+        let width = self
+            .address_width
+            .unwrap_or_else(|| config.arch_spec.arch.address_width());
+        let mut current_address = width.wrap(config.start_address);
+        let mut result = DisassemblyResult::new(current_address, config.arch_name().to_string());
+        result.address_width = width;
+        result.big_endian = config.arch_spec.is_big_endian();
         let mut offset = 0;
-        let mut current_address = config.start_address;
         let arch_name = config.arch_name();
-        let riscv_profile = config.arch_spec.riscv_profile();
-
+        let profile = config.arch_spec.profile();
+        let handler = self
+            .dispatcher
+            .get()
+            .get_handler(profile.mode_name)
+            .map(|handler| handler.name());
+        let alignment = match config.arch_spec.riscv_profile() {
+            // Without C, IALIGN is 32 bits.
+            Some(profile) if !profile.enabled_extensions.contains(&"C") => 4,
+            _ => config.arch_spec.arch.instruction_alignment(),
+        };
+        let mut in_misaligned_run = false;
+        let data_layout = DataLayout::new(
+            config.arch_spec.arch.category() == "x86",
+            width,
+            config.arch_spec.is_big_endian(),
+        );
+        // Bytes SKIPDATA stepped over since the last instruction, listed as
+        // data directives once the run ends.
+        let mut skipped_run: Vec<u8> = Vec::new();
+        let mut skipped_run_address = current_address;
+        let _span = tracing::debug_span!(
+            "disassemble",
+            arch = arch_name,
+            start = config.start_address,
+            len = config.hex_bytes.len()
+        )
+        .entered();
+
+        let started = Instant::now();
         while offset < config.hex_bytes.len() {
             let slice = &config.hex_bytes[offset..];
+            self.limits
+                .check_instructions(result.instructions.len())
+                .and_then(|()| self.limits.check_deadline(started))
+                .map_err(|error| error.at(offset, current_address, slice))?;
 
-            let disassembly = if let Some(profile) = riscv_profile.as_ref() {
-                self.dispatcher
-                    .borrow()
-                    .disassemble_with_profile(slice, profile, current_address)
-            } else {
+            if let Some(range) = self
+                .data_ranges
+                .iter()
+                .find(|range| range.contains(&current_address))
+            {
+                let len = usize::try_from(range.end - current_address)
+                    .map_or(slice.len(), |len| len.min(slice.len()));
+                if skipped_run.is_empty() {
+                    skipped_run_address = current_address;
+                }
+                skipped_run.extend_from_slice(&slice[..len]);
+                offset += len;
+                current_address = width.offset(current_address, len as i64);
+                in_misaligned_run = false;
+                continue;
+            }
+
+            let misaligned = self.misaligned != MisalignedPolicy::Allow
+                && !current_address.is_multiple_of(alignment);
+            if misaligned && !in_misaligned_run {
+                let message =
+                    format!("address 0x{current_address:x} is not {alignment}-byte aligned");
+                if self.misaligned == MisalignedPolicy::Error {
+                    return Err(DisasmError::InvalidAddress(message).at(
+                        offset,
+                        current_address,
+                        slice,
+                    ));
+                }
+                result.add_warning(DisassemblyIssue {
+                    kind: "misaligned_address".to_string(),
+                    operation: "decode_instruction".to_string(),
+                    message,
+                    architecture: Some(arch_name.to_string()),
+                    address: Some(current_address),
+                    input_offset: Some(offset),
+                    raw_bytes: slice.iter().take(8).copied().collect(),
+                });
+            }
+            in_misaligned_run = misaligned;
+
+            let disassembly =
                 self.dispatcher
-                    .borrow()
-                    .disassemble_bytes(slice, arch_name, current_address)
-            };
+                    .get()
+                    .disassemble_with_profile(slice, &profile, current_address);
+            self.stats.borrow_mut().record(handler, &disassembly);
 
             match disassembly {
                 Ok((instruction, size)) => {
                     if size == 0 {
                         return Err(DisasmError::DecodingError(
                             "Decoder returned zero-length instruction".to_string(),
-                        ));
+                        )
+                        .at(offset, current_address, slice));
+                    }
+                    if self.flush_skipped_run(
+                        &mut skipped_run,
+                        skipped_run_address,
+                        data_layout,
+                        &mut result,
+                    ) == HookAction::Stop
+                    {
+                        break;
                     }
 
+                    if explain && let Some(breakdown) = self.explain_encoding(slice, arch_name) {
+                        result.explanations.insert(current_address, breakdown);
+                    }
+                    if explain
+                        && let Some(decoded) = &instruction.decoded
+                        && let Some(metadata) = self.instruction_metadata(decoded, arch_name)
+                    {
+                        result.metadata.insert(current_address, metadata);
+                    }
+                    if expand_compressed
+                        && let Some(decoded) = &instruction.decoded
+                        && let Some(expanded) = self.expand_compressed(decoded, arch_name)
+                    {
+                        result.expansions.insert(current_address, expanded);
+                    }
+                    if mark_hints
+                        && let Some(decoded) = &instruction.decoded
+                        && decoded.encoding_class() == EncodingClass::Hint
+                    {
+                        result
+                            .encoding_classes
+                            .insert(current_address, EncodingClass::Hint);
+                    }
+                    let action = self.notify_instruction(&instruction);
                     result.add_instruction(instruction);
                     offset += size;
-                    current_address = current_address.saturating_add(size as u64);
+                    current_address = width.offset(current_address, size as i64);
+                    if action == HookAction::Stop {
+                        break;
+                    }
                 }
                 Err(err) => {
-                    if config.skip_data {
-                        // Architecture-aware skip size: RISC-V should resync on
-                        // 2-byte boundaries when possible.
-                        let skip_size = if arch_name.starts_with("riscv") {
-                            let remaining = config.hex_bytes.len() - offset;
-                            if remaining == 1 || !current_address.is_multiple_of(2) {
-                                1
-                            } else {
-                                2 // Skip a 2-byte chunk
-                            }
-                        } else {
-                            1
-                        };
+                    if self.notify_error(offset, &err) == HookAction::Stop {
+                        self.flush_skipped_run(
+                            &mut skipped_run,
+                            skipped_run_address,
+                            data_layout,
+                            &mut result,
+                        );
+                        result.add_error(DisassemblyIssue::from_core_error(
+                            &err,
+                            "decode_instruction",
+                            arch_name,
+                            current_address,
+                            offset,
+                            slice,
+                        ));
+                        break;
+                    }
 
-                        let skipped = &config.hex_bytes[offset..offset + skip_size];
-                        let operands = skipped
-                            .iter()
-                            .map(|b| format!("0x{b:02x}"))
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        let pseudo = Instruction::new(
+                    // `Callback` defers to the error hook, which let the run
+                    // continue: its bytes are listed as data.
+                    let policy = match config.on_decode_error {
+                        OnDecodeError::Callback if self.error_hook.borrow().is_some() => {
+                            OnDecodeError::Emit(DataStep::Resync)
+                        }
+                        policy => policy,
+                    };
+
+                    if policy.emits_data() && matches!(err, DisasmError::Truncated { .. }) {
+                        // The input ends inside an instruction: there is
+                        // nothing to resynchronise on, so the tail is data.
+                        if self.flush_skipped_run(
+                            &mut skipped_run,
+                            skipped_run_address,
+                            data_layout,
+                            &mut result,
+                        ) == HookAction::Stop
+                        {
+                            break;
+                        }
+                        let halves = alignment >= 2 && current_address.is_multiple_of(2);
+                        for item in trailing_data(
+                            &config.hex_bytes[offset..],
                             current_address,
-                            skipped.to_vec(),
-                            ".byte".to_string(),
-                            operands,
+                            width,
+                            halves,
+                            config.arch_spec.is_big_endian(),
+                        ) {
+                            let action = self.notify_instruction(&item);
+                            result.add_instruction(item);
+                            if action == HookAction::Stop {
+                                break;
+                            }
+                        }
+                        break;
+                    } else if let OnDecodeError::SkipAlign(_) = policy {
+                        // The dropped bytes end any data run and leave a
+                        // warning behind instead of a listing item.
+                        let skip_size = policy
+                            .step(arch_name, current_address, slice.len())
+                            .unwrap_or(slice.len());
+                        if self.flush_skipped_run(
+                            &mut skipped_run,
+                            skipped_run_address,
+                            data_layout,
+                            &mut result,
+                        ) == HookAction::Stop
+                        {
+                            break;
+                        }
+                        result.add_warning(DisassemblyIssue::from_core_error(
+                            &err,
+                            "decode_instruction",
+                            arch_name,
+                            current_address,
+                            offset,
+                            &slice[..skip_size],
+                        ));
+                        result.undecoded.push(UndecodedEncoding {
+                            address: current_address,
+                            kind: err.stable_kind().to_string(),
+                            bytes: slice.iter().take(8).copied().collect(),
+                            skipped: skip_size,
+                        });
+                        offset += skip_size;
+                        current_address = width.offset(current_address, skip_size as i64);
+                    } else if let Some(skip_size) =
+                        policy.step(arch_name, current_address, slice.len())
+                    {
+                        // The data step picks the resync size: RISC-V resyncs
+                        // on 2-byte boundaries when possible.
+                        let skipped = &config.hex_bytes[offset..offset + skip_size];
+                        tracing::debug!(
+                            offset,
+                            address = current_address,
+                            skipped = %hex::encode(skipped),
+                            error = %err,
+                            "SKIPDATA resync"
                         );
-                        result.add_instruction(pseudo);
+                        result.undecoded.push(UndecodedEncoding {
+                            address: current_address,
+                            kind: err.stable_kind().to_string(),
+                            bytes: slice.iter().take(8).copied().collect(),
+                            skipped: skip_size,
+                        });
+                        let mut action = HookAction::Continue;
+                        let reserved =
+                            mark_hints && err.encoding_class() == Some(EncodingClass::Reserved);
+                        if reserved || !self.data_directives {
+                            // Each data step gets its own `.byte` item unless
+                            // data directives were asked for; a reserved
+                            // encoding always does, so the mark stays on its
+                            // address.
+                            action = self.flush_skipped_run(
+                                &mut skipped_run,
+                                skipped_run_address,
+                                data_layout,
+                                &mut result,
+                            );
+                            if reserved {
+                                result
+                                    .encoding_classes
+                                    .insert(current_address, EncodingClass::Reserved);
+                            }
+                            if action == HookAction::Continue {
+                                let pseudo = byte_directive(current_address, skipped);
+                                action = self.notify_instruction(&pseudo);
+                                result.add_instruction(pseudo);
+                            }
+                        } else {
+                            if skipped_run.is_empty() {
+                                skipped_run_address = current_address;
+                            }
+                            skipped_run.extend_from_slice(skipped);
+                        }
                         offset += skip_size;
-                        current_address = current_address.saturating_add(skip_size as u64);
+                        current_address = width.offset(current_address, skip_size as i64);
+                        if action == HookAction::Stop {
+                            break;
+                        }
                     } else {
-                        return Err(err);
+                        return Err(err.at(offset, current_address, slice));
                     }
                 }
             }
         }
 
+        self.flush_skipped_run(
+            &mut skipped_run,
+            skipped_run_address,
+            data_layout,
+            &mut result,
+        );
+        self.limits.check_instructions(result.instructions.len())?;
+
+        if config.display_options.dataflow {
+            // x0 always reads as zero, so it neither defines nor carries a value.
+            let ignored: &[u32] = if arch_name.starts_with("riscv") {
+                &[0]
+            } else {
+                &[]
+            };
+            result.dataflow = Some(DefUse::analyze_ignoring(&result.instructions, ignored));
+        }
         Ok(result)
     }
 
+    /// List the bytes of a finished SKIPDATA run as data directives,
+    /// notifying the instruction hook of each; `run` is left empty.
+    fn flush_skipped_run(
+        &self,
+        run: &mut Vec<u8>,
+        address: u64,
+        layout: DataLayout,
+        result: &mut DisassemblyResult,
+    ) -> HookAction {
+        let bytes = std::mem::take(run);
+        for item in data_directives(address, &bytes, layout) {
+            let action = self.notify_instruction(&item);
+            result.add_instruction(item);
+            if action == HookAction::Stop {
+                return action;
+            }
+        }
+        HookAction::Continue
+    }
+
+    /// Break the first instruction in `bytes` into its encoding fields.
+    ///
+    /// Returns `None` when no handler accepts `arch` or the handler does not
+    /// describe its encodings.
+    pub fn explain_encoding(&self, bytes: &[u8], arch: &str) -> Option<EncodingBreakdown> {
+        self.dispatcher
+            .get()
+            .get_handler(arch)?
+            .explain_encoding(bytes, arch)
+    }
+
+    /// Extension, privilege level, and latency class of a decoded instruction.
+    pub fn instruction_metadata(
+        &self,
+        decoded: &DecodedInstruction,
+        arch: &str,
+    ) -> Option<InstructionMetadata> {
+        self.dispatcher
+            .get()
+            .get_handler(arch)?
+            .instruction_metadata(decoded)
+    }
+
+    /// Decode the full-width instruction a compressed instruction stands for.
+    pub fn expand_compressed(
+        &self,
+        decoded: &DecodedInstruction,
+        arch: &str,
+    ) -> Option<DecodedInstruction> {
+        self.dispatcher
+            .get()
+            .get_handler(arch)?
+            .expand_compressed(decoded)
+    }
+
+    /// Check whether any registered handler accepts `arch`.
+    pub fn supports_architecture(&self, arch: &str) -> bool {
+        self.dispatcher.get().supports_architecture(arch)
+    }
+
+    /// Names of the registered architecture handlers.
+    pub fn registered_handlers(&self) -> Vec<&'static str> {
+        self.dispatcher.get().supported_architectures()
+    }
+
+    /// Decode counters for the runs of this engine.
+    ///
+    /// Engines share the process-wide dispatchers but count their own
+    /// decodes, so other engines running at the same time do not show up
+    /// here.
+    pub fn decode_stats(&self) -> DecodeStats {
+        self.stats.borrow().clone()
+    }
+
+    /// Name of the handler that accepts `arch`, if any.
+    pub fn handler_name(&self, arch: &str) -> Option<&'static str> {
+        self.dispatcher
+            .get()
+            .get_handler(arch)
+            .map(|handler| handler.name())
+    }
+
+    /// List the mnemonics the handler for `profile` can decode with its
+    /// extensions, grouped by extension, or `None` if no handler accepts it.
+    pub fn supported_mnemonics(&self, profile: &ArchitectureProfile) -> Option<Vec<MnemonicGroup>> {
+        self.dispatcher
+            .get()
+            .get_handler(profile.mode_name)
+            .map(|handler| handler.supported_mnemonics_with_profile(profile))
+    }
+
+    /// List the registers of `arch`, or `None` if no handler accepts `arch`.
+    pub fn registers(&self, arch: &str) -> Option<Vec<RegisterInfo>> {
+        self.dispatcher
+            .get()
+            .get_handler(arch)
+            .map(|handler| handler.registers(arch))
+    }
+
     /// Disassemble a single instruction at the given address.
     pub fn disassemble_single(
         &self,
@@ -375,7 +1080,7 @@ impl DisassemblyEngine {
         address: u64,
     ) -> Result<(Instruction, usize), DisasmError> {
         self.dispatcher
-            .borrow()
+            .get()
             .disassemble_bytes(bytes, arch_name, address)
     }
 }
@@ -383,12 +1088,23 @@ impl DisassemblyEngine {
 /// Formatter for disassembly output with multiple display modes.
 pub struct DisassemblyFormatter {
     output_config: OutputConfig,
+    relocations: Relocations,
 }
 
 impl DisassemblyFormatter {
     /// Create a new formatter with the given output configuration.
     pub fn new(output_config: OutputConfig) -> Self {
-        Self { output_config }
+        Self {
+            output_config,
+            relocations: Relocations::default(),
+        }
+    }
+
+    /// Render the operands patched by object-file relocations as relocation
+    /// expressions (`%pcrel_hi(sym)`) instead of their placeholder values.
+    pub fn with_relocations(mut self, relocations: Relocations) -> Self {
+        self.relocations = relocations;
+        self
     }
 
     /// Format the disassembly result for display.
@@ -397,19 +1113,48 @@ impl DisassemblyFormatter {
             return self.format_json(result);
         }
 
+        if self.output_config.format == OutputFormat::Gas {
+            return self.format_gas(result);
+        }
+
+        if self.output_config.format == OutputFormat::Trace {
+            return self.format_trace(result);
+        }
+
+        if self.output_config.format == OutputFormat::Gdb {
+            let mut output = self.format_gdb_listing(&result.instructions, None);
+            for error in &result.errors {
+                output.push_str(&format!("; Error: {}\n", error.display_message()));
+            }
+            for warning in &result.warnings {
+                output.push_str(&format!("; Warning: {}\n", warning.display_message()));
+            }
+            return output;
+        }
+
         let mut output = String::new();
         if !result.instructions.is_empty() {
-            let hex_width = result
-                .instructions
-                .iter()
-                .map(|instruction| instruction.bytes.len().saturating_mul(3).saturating_sub(1))
-                .max()
-                .unwrap_or(0);
+            let hex_width = self.bytes_column_width(result);
+            let word_width = self.word_column_width(result);
+            let labels = self.labels(&result.instructions);
 
-            for instruction in &result.instructions {
-                let formatted = self.format_instruction(instruction, hex_width);
+            for (index, instruction) in result.instructions.iter().enumerate() {
+                if let Some(label) = labels.label_at(instruction.address) {
+                    output.push_str(&format!("{label}:\n"));
+                }
+                let formatted =
+                    self.format_instruction(instruction, result, hex_width, word_width, &labels);
                 output.push_str(&formatted);
                 output.push('\n');
+                if let Some(breakdown) = result.explanations.get(&instruction.address) {
+                    output.push_str(&self.format_explanation(breakdown));
+                }
+                if let Some(metadata) = result.metadata.get(&instruction.address) {
+                    output.push_str(&self.format_metadata(metadata));
+                }
+                if let Some(dataflow) = &result.dataflow {
+                    output.push_str(&self.format_dataflow(result, dataflow, index));
+                }
             }
         }
 
@@ -417,6 +1162,9 @@ impl DisassemblyFormatter {
         for error in &result.errors {
             output.push_str(&format!("; Error: {}\n", error.display_message()));
         }
+        for warning in &result.warnings {
+            output.push_str(&format!("; Warning: {}\n", warning.display_message()));
+        }
 
         output
     }
@@ -429,58 +1177,552 @@ impl DisassemblyFormatter {
             .map(DisassemblyIssue::to_rendered_issue)
             .collect::<Vec<_>>();
 
-        serde_json::to_string_pretty(&render_disassembly(
+        let rendered = render_disassembly(
             result.architecture.clone(),
             result.start_address,
             result.bytes_processed,
             errors,
             &result.instructions,
             self.render_options(),
-        ))
-        .expect("JSON serialization should not fail")
+        );
+
+        if self.output_config.annotations.is_empty()
+            && !self.output_config.uppercase
+            && self.relocations.is_empty()
+            && result.warnings.is_empty()
+            && result.encoding_classes.is_empty()
+        {
+            return serde_json::to_string_pretty(&rendered)
+                .expect("JSON serialization should not fail");
+        }
+
+        let mut value =
+            serde_json::to_value(&rendered).expect("JSON serialization should not fail");
+        if !result.warnings.is_empty() {
+            let warnings = result
+                .warnings
+                .iter()
+                .map(DisassemblyIssue::to_rendered_issue)
+                .collect::<Vec<_>>();
+            value["warnings"] =
+                serde_json::to_value(warnings).expect("JSON serialization should not fail");
+        }
+        if let Some(instructions) = value["instructions"].as_array_mut() {
+            for (instruction, entry) in result.instructions.iter().zip(instructions) {
+                let comments = self.output_config.annotations.get(instruction.address);
+                if !comments.is_empty() {
+                    entry["comments"] = serde_json::json!(comments);
+                }
+                if let Some(class) = result.encoding_classes.get(&instruction.address) {
+                    entry["encoding_class"] = serde_json::json!(class);
+                }
+                if self.output_config.uppercase
+                    || self.relocations.get(instruction.address).is_some()
+                {
+                    // Only the rendered text changes; the decoded IR stays canonical.
+                    let (mnemonic, operands) = self.render_instruction_text(instruction);
+                    entry["mnemonic"] = serde_json::json!(mnemonic);
+                    entry["operands"] = serde_json::json!(operands);
+                }
+            }
+        }
+        serde_json::to_string_pretty(&value).expect("JSON serialization should not fail")
+    }
+
+    /// Format instructions the way GDB's `x/Ni` command prints them.
+    ///
+    /// The instruction at `pc`, if any, is marked with GDB's `=>` prefix.
+    pub fn format_gdb_listing(&self, instructions: &[Instruction], pc: Option<u64>) -> String {
+        let mut output = String::new();
+        let labels = self.labels(instructions);
+        for instr in instructions {
+            if let Some(label) = labels.label_at(instr.address) {
+                output.push_str(&format!("{label}:\n"));
+            }
+            let marker = if pc == Some(instr.address) {
+                "=> "
+            } else {
+                "   "
+            };
+            let (mnemonic, operands) = self.render_instruction_text(instr);
+            let operands = labels.substitute_operands(instr.address, &operands);
+            output.push_str(&format!("{marker}0x{}:\t", self.hex(instr.address, 0)));
+            if self.output_config.show_hex {
+                output.push_str(&self.case(format_bytes_as_hex(&instr.bytes, true)));
+                output.push('\t');
+            }
+            output.push_str(&mnemonic);
+            if !operands.is_empty() {
+                output.push('\t');
+                output.push_str(&operands.replace(", ", ","));
+            }
+            if let Some(comment) = self.output_config.annotations.comment_suffix(instr.address) {
+                output.push('\t');
+                output.push_str(&comment);
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Format the result for correlation with emulator traces.
+    ///
+    /// Each line is `ADDRESS<TAB>ENCODING<TAB>KEY`: the address padded to
+    /// the architecture's width, the encoding as the instruction word Spike
+    /// and QEMU print (the raw bytes in memory order on x86), and the key
+    /// `arch:mnemonic:operands`, rendered canonically without aliases or
+    /// spaces so that it does not depend on display options.
+    pub fn format_trace(&self, result: &DisassemblyResult) -> String {
+        let x86 =
+            Architecture::parse(&result.architecture).is_ok_and(|arch| arch.category() == "x86");
+        let address_digits = result.address_width.hex_digits();
+        let options = RenderOptions {
+            text_profile: TextRenderProfile::Canonical,
+            alias_regs: false,
+            capstone_aliases: false,
+            compressed_aliases: false,
+            unsigned_immediate: self.output_config.unsigned_immediate,
+            nops: NopStyle::Literal,
+        };
+
+        let mut output = String::new();
+        for instr in &result.instructions {
+            let encoding = if x86 {
+                hex::encode(&instr.bytes)
+            } else {
+                let value = instr
+                    .bytes
+                    .iter()
+                    .rev()
+                    .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte));
+                format!("0x{value:0width$x}", width = instr.size * 2)
+            };
+            let (mnemonic, operands) = render_instruction_text(instr, options);
+            let operands: String = operands.split_whitespace().collect();
+            output.push_str(&format!(
+                "0x{:0address_digits$x}\t{encoding}\t{}:{}:{operands}\n",
+                instr.address,
+                result.architecture,
+                mnemonic.to_lowercase()
+            ));
+        }
+        output
+    }
+
+    /// Format the result as a GNU `as` source file.
+    ///
+    /// Branch targets inside the listing always get labels; branches whose
+    /// target lies outside it keep their encoding as a `.2byte`/`.4byte`
+    /// directive with the disassembly in a comment. On RISC-V, automatic compression is disabled and
+    /// each compressed instruction is emitted in an `.option rvc` region, so
+    /// every instruction keeps its original size. Undecodable bytes (SKIPDATA)
+    /// are emitted as `.byte` directives.
+    pub fn format_gas(&self, result: &DisassemblyResult) -> String {
+        let category = Architecture::parse(&result.architecture)
+            .map(|arch| arch.category())
+            .unwrap_or_default();
+        let riscv = category == "RISC-V";
+        let labels = Labels::collect(&result.instructions);
+
+        let mut output = format!(
+            "# Disassembled by robustone from {} at 0x{:x}\n",
+            result.architecture, result.start_address
+        );
+        output.push_str("\t.text\n");
+        match category {
+            "RISC-V" => output.push_str("\t.option norvc\n"),
+            "x86" => output.push_str("\t.intel_syntax noprefix\n"),
+            _ => {}
+        }
+
+        for instr in &result.instructions {
+            if let Some(label) = labels.label_at(instr.address) {
+                output.push_str(&format!("{label}:\n"));
+            }
+
+            let (mnemonic, operands) = self.render_instruction_text(instr);
+            let comments = self.output_config.annotations.get(instr.address);
+            let comment = (!comments.is_empty()).then(|| comments.join("; "));
+
+            // A PC-relative target outside the listing has no label to refer
+            // to, so keep the original encoding and show the text as a comment.
+            if labels.target_label(instr.address).is_none() && branch_target(instr).is_some() {
+                let directive = if instr.size == 2 { ".2byte" } else { ".4byte" };
+                let value = instr
+                    .bytes
+                    .iter()
+                    .rev()
+                    .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte));
+                output.push_str(&format!(
+                    "\t{directive}\t0x{value:0width$x}\t# {mnemonic}",
+                    width = instr.size * 2
+                ));
+                if !operands.is_empty() {
+                    output.push('\t');
+                    output.push_str(&operands);
+                }
+                if let Some(comment) = comment {
+                    output.push_str(&format!("; {comment}"));
+                }
+                output.push('\n');
+                continue;
+            }
+
+            let compressed = riscv && instr.size == 2 && !is_data_directive(&instr.mnemonic);
+            if compressed {
+                output.push_str("\t.option push\n\t.option rvc\n");
+            }
+
+            let operands = labels.substitute_operands(instr.address, &operands);
+            output.push('\t');
+            output.push_str(&mnemonic);
+            if !operands.is_empty() {
+                output.push('\t');
+                output.push_str(&operands);
+            }
+            if let Some(comment) = comment {
+                output.push_str(&format!("\t# {comment}"));
+            }
+            output.push('\n');
+
+            if compressed {
+                output.push_str("\t.option pop\n");
+            }
+        }
+
+        for error in &result.errors {
+            output.push_str(&format!("# Error: {}\n", error.display_message()));
+        }
+        for warning in &result.warnings {
+            output.push_str(&format!("# Warning: {}\n", warning.display_message()));
+        }
+        output
+    }
+
+    /// Branch-target labels for `instructions`, or none when `--labels` is off.
+    fn labels(&self, instructions: &[Instruction]) -> Labels {
+        if self.output_config.labels {
+            Labels::collect(instructions)
+        } else {
+            Labels::default()
+        }
+    }
+
+    /// Width of the bytes column: wide enough for the architecture's longest
+    /// encoding, so compressed or short instructions line up with full-width
+    /// ones, and never narrower than the longest instruction actually listed.
+    fn bytes_column_width(&self, result: &DisassemblyResult) -> usize {
+        let grouping = self.output_config.layout.byte_grouping;
+        let isa_width = Architecture::parse(&result.architecture)
+            .ok()
+            .and_then(|arch| arch.max_instruction_size())
+            .map_or(0, |size| grouping.render(&vec![0; size]).len());
+        result
+            .instructions
+            .iter()
+            .map(|instruction| grouping.render(&instruction.bytes).len())
+            .fold(isa_width, usize::max)
+    }
+
+    /// Width of the `--word-column` column, or 0 when it is off: `0x` plus
+    /// two digits per byte of the longest instruction listed.
+    fn word_column_width(&self, result: &DisassemblyResult) -> usize {
+        if !self.output_config.layout.word_column {
+            return 0;
+        }
+        let longest = result
+            .instructions
+            .iter()
+            .map(|instruction| instruction.bytes.len())
+            .max()
+            .unwrap_or(0);
+        2 + 2 * longest
+    }
+
+    /// Format a single instruction.
+    fn format_instruction(
+        &self,
+        instr: &Instruction,
+        result: &DisassemblyResult,
+        hex_width: usize,
+        word_width: usize,
+        labels: &Labels,
+    ) -> String {
+        let layout = self.output_config.layout;
+        let (mnemonic, operands) = self.render_instruction_text(instr);
+        let operands = labels.substitute_operands(instr.address, &operands);
+
+        let address = match layout.address {
+            AddressDisplay::Absolute => Some(instr.address),
+            AddressDisplay::Offset => Some(
+                result
+                    .address_width
+                    .wrap(instr.address.wrapping_sub(result.start_address)),
+            ),
+            AddressDisplay::Hidden => None,
+        };
+        let mut line = match address {
+            Some(address) => self.hex(address, layout.address_width),
+            None => String::new(),
+        };
+        if self.output_config.show_hex {
+            if address.is_some() {
+                line.push_str("  ");
+            }
+            let bytes_str = self.case(layout.byte_grouping.render(&instr.bytes));
+            line.push_str(&format!("{bytes_str:<hex_width$}  "));
+        } else if address.is_some() {
+            line.push_str("    ");
+        }
+        if layout.word_column {
+            let mut bytes = instr.bytes.clone();
+            if !result.big_endian {
+                bytes.reverse();
+            }
+            let word = format!("0x{}", self.case(hex::encode(bytes)));
+            line.push_str(&format!("{word:<word_width$}  "));
+        }
+
+        if operands.is_empty() {
+            line.push_str(&mnemonic);
+        } else {
+            let separator = match layout.alignment {
+                ColumnAlignment::Tabs => '\t',
+                ColumnAlignment::Spaces => ' ',
+            };
+            line.push_str(&format!(
+                "{mnemonic:<width$}{separator}{operands}",
+                width = layout.mnemonic_width
+            ));
+        }
+
+        if let Some(expanded) = result.expansions.get(&instr.address) {
+            line.push_str(&self.format_expansion(expanded));
+        }
+
+        match result.encoding_classes.get(&instr.address) {
+            Some(EncodingClass::Hint) => line.push_str("  ; hint"),
+            Some(EncodingClass::Reserved) => line.push_str("  ; reserved"),
+            _ => {}
+        }
+
+        if let Some(comment) = self.output_config.annotations.comment_suffix(instr.address) {
+            line.push_str("  ");
+            line.push_str(&comment);
+        }
+
+        if self.output_config.show_operands {
+            for operand_line in self.format_operand_lines(instr) {
+                line.push('\n');
+                line.push_str(&operand_line);
+            }
+        }
+
+        if self.output_config.show_detail_sections {
+            let detail_lines = self.format_detail_sections(instr);
+            if !detail_lines.is_empty() {
+                line.push('\n');
+                line.push_str(&detail_lines.join("\n"));
+            }
+        }
+
+        line
+    }
+
+    /// Render the `  ; = <instruction>` suffix for a compressed instruction.
+    fn format_expansion(&self, expanded: &DecodedInstruction) -> String {
+        let options = self.render_options();
+        let alias_regs = options.capstone_aliases
+            && (options.alias_regs
+                || !matches!(options.text_profile, TextRenderProfile::Canonical));
+        // Capstone aliases stay off so the expansion shows the real instruction.
+        let (mnemonic, operands) = expanded.render_text_parts_with_options(
+            options.text_profile,
+            alias_regs,
+            false,
+            false,
+            options.unsigned_immediate,
+        );
+        let (mnemonic, operands) = if self.output_config.uppercase {
+            (mnemonic.to_uppercase(), uppercase_hex_literals(&operands))
+        } else {
+            (mnemonic, operands)
+        };
+        if operands.is_empty() {
+            format!("  ; = {mnemonic}")
+        } else {
+            format!("  ; = {mnemonic} {operands}")
+        }
+    }
+
+    /// Render an encoding breakdown as indented lines below its instruction.
+    fn format_explanation(&self, breakdown: &EncodingBreakdown) -> String {
+        let name_width = breakdown
+            .fields
+            .iter()
+            .map(|field| field.name.len())
+            .max()
+            .unwrap_or(0);
+
+        let mut output = format!("\tEncoding: {}\n", breakdown.format);
+        for field in &breakdown.fields {
+            let range = if field.msb == field.lsb {
+                format!("[{}]", field.msb)
+            } else {
+                format!("[{}:{}]", field.msb, field.lsb)
+            };
+            output.push_str(&format!(
+                "\t  {range:<7} {name:<name_width$} = {value:0width$b} ({value})\n",
+                name = field.name,
+                value = field.value,
+                width = usize::from(field.width()),
+            ));
+        }
+        if let Some(immediate) = breakdown.immediate {
+            output.push_str(&format!("\tImmediate: {immediate}\n"));
+        }
+        output.push_str(&format!("\tDescription: {}\n", breakdown.description));
+        output
+    }
+
+    /// Render the `Extension:`/`Privilege:`/`Latency:` lines for `--explain`.
+    fn format_metadata(&self, metadata: &InstructionMetadata) -> String {
+        let cores = metadata
+            .core_latency
+            .iter()
+            .map(|(core, latency)| format!("{core}: {}", latency.as_str()))
+            .collect::<Vec<_>>();
+        let mut output = format!(
+            "\tExtension: {}\n\tPrivilege: {}\n\tLatency: {}",
+            metadata.extension,
+            metadata.privilege.as_str(),
+            metadata.latency.as_str()
+        );
+        if !cores.is_empty() {
+            output.push_str(&format!(" ({})", cores.join(", ")));
+        }
+        output.push('\n');
+        output
+    }
+
+    /// Render the `Uses:`/`Defs:` lines of instruction `index` for `--dataflow`.
+    fn format_dataflow(
+        &self,
+        result: &DisassemblyResult,
+        dataflow: &DefUse,
+        index: usize,
+    ) -> String {
+        let (Some(instruction), Some(entry)) = (
+            result.instructions.get(index),
+            dataflow.instructions.get(index),
+        ) else {
+            return String::new();
+        };
+        let architecture = instruction_architecture_name(instruction);
+        let alias_regs = self.detail_alias_regs();
+        let address = |index: usize| format!("0x{:x}", result.instructions[index].address);
+
+        let mut output = String::new();
+        if !entry.uses.is_empty() {
+            let uses: Vec<String> = entry
+                .uses
+                .iter()
+                .map(|register_use| {
+                    let name =
+                        format_register_name(architecture, register_use.register, alias_regs);
+                    match register_use.defined_by {
+                        Some(definer) => format!("{name} <- {}", address(definer)),
+                        None => format!("{name} <- live-in"),
+                    }
+                })
+                .collect();
+            output.push_str(&format!("\tUses: {}\n", uses.join(", ")));
+        }
+        if !entry.defs.is_empty() {
+            let defs: Vec<String> = entry
+                .defs
+                .iter()
+                .map(|def| {
+                    let name = format_register_name(architecture, def.register, alias_regs);
+                    if def.used_by.is_empty() {
+                        format!("{name} -> unused")
+                    } else {
+                        let users: Vec<String> =
+                            def.used_by.iter().map(|&user| address(user)).collect();
+                        format!("{name} -> {}", users.join(" "))
+                    }
+                })
+                .collect();
+            output.push_str(&format!("\tDefs: {}\n", defs.join(", ")));
+        }
+        output
     }
 
-    /// Format a single instruction.
-    fn format_instruction(&self, instr: &Instruction, hex_width: usize) -> String {
-        let address_str = format!("{:x}", instr.address);
-        let (mnemonic, operands) = self.render_instruction_text(instr);
+    /// Whether register names in detail lines use ABI aliases (`ra`) over `x1`.
+    fn detail_alias_regs(&self) -> bool {
+        self.output_config.capstone_aliases
+            && (self.output_config.alias_regs
+                || !matches!(
+                    self.output_config.text_profile,
+                    TextRenderProfile::Canonical
+                ))
+    }
 
-        let bytes_str = if self.output_config.show_hex {
-            format!(
-                "{:>width$}",
-                instr
-                    .bytes
-                    .iter()
-                    .map(|b| format!("{b:02x}"))
-                    .collect::<Vec<_>>()
-                    .join(" "),
-                width = hex_width
-            )
-        } else {
-            String::new()
+    /// Render the `-d` operand list: one `op[i]: KIND value (access)` line
+    /// per typed operand of the decoded instruction.
+    fn format_operand_lines(&self, instr: &Instruction) -> Vec<String> {
+        let Some(decoded) = &instr.decoded else {
+            return Vec::new();
         };
-
-        let mut line = if self.output_config.show_hex {
-            if operands.is_empty() {
-                format!("{address_str}  {bytes_str}  {mnemonic}")
+        let architecture = instruction_architecture_name(instr);
+        let alias_regs = self.detail_alias_regs();
+        let register_name = |reg_id: u32| format_register_name(architecture, reg_id, alias_regs);
+        let signed_hex = |value: i64| {
+            if value < 0 {
+                format!("-0x{:x}", value.unsigned_abs())
             } else {
-                format!("{address_str}  {bytes_str}  {mnemonic}\t{operands}")
+                format!("0x{value:x}")
             }
-        } else if operands.is_empty() {
-            format!("{address_str}    {mnemonic}")
-        } else {
-            format!("{address_str}    {mnemonic}\t{operands}")
         };
 
-        if self.output_config.show_detail_sections {
-            let detail_lines = self.format_detail_sections(instr);
-            if !detail_lines.is_empty() {
-                line.push('\n');
-                line.push_str(&detail_lines.join("\n"));
-            }
-        }
-
-        line
+        decoded
+            .typed_operands()
+            .iter()
+            .enumerate()
+            .map(|(index, typed)| {
+                let value = match &typed.operand {
+                    Operand::Register { register } => format!("REG {}", register_name(register.id)),
+                    Operand::Immediate { value } => format!("IMM {}", signed_hex(*value)),
+                    Operand::SystemRegister { number } => format!("SYSREG 0x{number:x}"),
+                    Operand::Text { value } => format!("TEXT {value}"),
+                    Operand::Memory {
+                        base,
+                        displacement,
+                        ordering,
+                    } => {
+                        let base =
+                            base.map_or_else(|| "none".to_string(), |base| register_name(base.id));
+                        let ordering = match (ordering.acquire, ordering.release) {
+                            (false, false) => "",
+                            (true, false) => " aq",
+                            (false, true) => " rl",
+                            (true, true) => " aq rl",
+                        };
+                        format!(
+                            "MEM base={base} disp={}{ordering}",
+                            signed_hex(*displacement)
+                        )
+                    }
+                };
+                let access = match (typed.access.read, typed.access.write) {
+                    (true, false) => " (read)",
+                    (false, true) => " (write)",
+                    (true, true) => " (read/write)",
+                    (false, false) => "",
+                };
+                format!("\top[{index}]: {value}{access}")
+            })
+            .collect()
     }
 
     fn format_detail_sections(&self, instr: &Instruction) -> Vec<String> {
@@ -489,32 +1731,68 @@ impl DisassemblyFormatter {
         };
 
         let mut detail_lines = Vec::new();
-        let detail_alias_regs = self.output_config.capstone_aliases
-            && (self.output_config.alias_regs
-                || !matches!(
-                    self.output_config.text_profile,
-                    TextRenderProfile::Canonical
-                ));
-        if matches!(
-            self.output_config.text_profile,
-            TextRenderProfile::VerboseDebug
-        ) && let Some(decoded) = &instr.decoded
-        {
-            if let Some(opcode_id) = &decoded.opcode_id {
-                detail_lines.push(format!("\tOpcode ID: {opcode_id}"));
-            }
-            if !decoded.groups.is_empty() {
-                detail_lines.push(format!("\tGroups: {}", decoded.groups.join(", ")));
+        let detail_alias_regs = self.detail_alias_regs();
+        let register_name = |reg_id: u32| {
+            format_register_name(detail.architecture_name(), reg_id, detail_alias_regs)
+        };
+
+        // Operands and groups follow cstool's `-d` layout so the two can be diffed.
+        let operands = instr
+            .decoded
+            .as_ref()
+            .map(DecodedInstruction::typed_operands)
+            .unwrap_or_default();
+        if !operands.is_empty() {
+            detail_lines.push(format!("\top_count: {}", operands.len()));
+        }
+        for (index, typed) in operands.iter().enumerate() {
+            let prefix = format!("\t\toperands[{index}]");
+            match &typed.operand {
+                Operand::Register { register } => {
+                    detail_lines.push(format!(
+                        "{prefix}.type: REG = {}",
+                        register_name(register.id)
+                    ));
+                }
+                Operand::Immediate { value } => {
+                    detail_lines.push(format!("{prefix}.type: IMM = 0x{:x}", *value as u64));
+                }
+                Operand::SystemRegister { number } => {
+                    detail_lines.push(format!("{prefix}.type: SYSREG = 0x{number:x}"));
+                }
+                Operand::Text { value } => {
+                    detail_lines.push(format!("{prefix}.type: TEXT = {value}"));
+                }
+                Operand::Memory {
+                    base, displacement, ..
+                } => {
+                    detail_lines.push(format!("{prefix}.type: MEM"));
+                    if let Some(base) = base {
+                        detail_lines.push(format!(
+                            "\t{prefix}.mem.base: REG = {}",
+                            register_name(base.id)
+                        ));
+                    }
+                    if *displacement != 0 {
+                        detail_lines
+                            .push(format!("\t{prefix}.mem.disp: 0x{:x}", *displacement as u64));
+                    }
+                }
             }
-            detail_lines.push(format!("\tStatus: {:?}", decoded.status));
+            let access = match (typed.access.read, typed.access.write) {
+                (true, false) => "READ",
+                (false, true) => "WRITE",
+                (true, true) => "READ | WRITE",
+                (false, false) => continue,
+            };
+            detail_lines.push(format!("{prefix}.access: {access}"));
         }
+
         let registers_read = detail.registers_read();
         if !registers_read.is_empty() {
             let registers = registers_read
                 .iter()
-                .map(|reg_id| {
-                    format_register_name(detail.architecture_name(), *reg_id, detail_alias_regs)
-                })
+                .map(|reg_id| register_name(*reg_id))
                 .collect::<Vec<_>>()
                 .join(", ");
             detail_lines.push(format!("\tRegisters read: {registers}"));
@@ -524,12 +1802,16 @@ impl DisassemblyFormatter {
         if !registers_written.is_empty() {
             let registers = registers_written
                 .iter()
-                .map(|reg_id| {
-                    format_register_name(detail.architecture_name(), *reg_id, detail_alias_regs)
-                })
+                .map(|reg_id| register_name(*reg_id))
                 .collect::<Vec<_>>()
                 .join(", ");
-            detail_lines.push(format!("\tRegisters written: {registers}"));
+            detail_lines.push(format!("\tRegisters modified: {registers}"));
+        }
+
+        if let Some(decoded) = &instr.decoded
+            && !decoded.groups.is_empty()
+        {
+            detail_lines.push(format!("\tGroups: {}", decoded.groups.join(" ")));
         }
 
         detail_lines
@@ -541,7 +1823,33 @@ impl DisassemblyFormatter {
     }
 
     fn render_instruction_text(&self, instr: &Instruction) -> (String, String) {
-        render_instruction_text(instr, self.render_options())
+        let (mnemonic, operands) = self
+            .relocations
+            .render(instr, self.render_options())
+            .unwrap_or_else(|| render_instruction_text(instr, self.render_options()));
+        if self.output_config.uppercase {
+            (mnemonic.to_uppercase(), uppercase_hex_literals(&operands))
+        } else {
+            (mnemonic, operands)
+        }
+    }
+
+    /// Render `value` as zero-padded hex in the configured letter case.
+    fn hex(&self, value: u64, width: usize) -> String {
+        if self.output_config.uppercase {
+            format!("{value:0width$X}")
+        } else {
+            format!("{value:0width$x}")
+        }
+    }
+
+    /// Apply the configured letter case to already-rendered hex digits.
+    fn case(&self, hex: String) -> String {
+        if self.output_config.uppercase {
+            hex.to_ascii_uppercase()
+        } else {
+            hex
+        }
     }
 
     fn render_options(&self) -> RenderOptions {
@@ -551,10 +1859,26 @@ impl DisassemblyFormatter {
             capstone_aliases: self.output_config.capstone_aliases,
             compressed_aliases: self.output_config.compressed_aliases,
             unsigned_immediate: self.output_config.unsigned_immediate,
+            // Collapsing a HINT to `nop` would change the bytes `gas`
+            // assembles, so that format keeps the profile's text.
+            nops: if self.output_config.format == OutputFormat::Gas {
+                NopStyle::Profile
+            } else {
+                self.output_config.nops
+            },
         }
     }
 }
 
+/// Architecture name understood by [`format_register_name`].
+fn instruction_architecture_name(instruction: &Instruction) -> &'static str {
+    match (&instruction.detail, &instruction.decoded) {
+        (Some(detail), _) => detail.architecture_name(),
+        (None, Some(decoded)) if decoded.architecture == ArchitectureId::Riscv => "riscv",
+        _ => "",
+    }
+}
+
 fn format_register_name(architecture_name: &str, reg_id: u32, alias_regs: bool) -> String {
     match architecture_name {
         "riscv" => {
@@ -597,8 +1921,10 @@ pub fn print_instructions(result: &DisassemblyResult, config: &DisasmConfig) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::annotations::Annotations;
     use crate::arch::ArchitectureSpec;
-    use crate::command::DisplayOptions;
+    use crate::command::{DisplayOptions, Nops, OutputFormat, Syntax};
+    use crate::config::ColumnLayout;
     use robustone_core::ir::{ArchitectureId, DecodeStatus, Operand, RegisterId, RenderHints};
     use serde_json::Value;
 
@@ -606,13 +1932,7 @@ mod tests {
     fn test_disassembly_engine() {
         let engine = DisassemblyEngine::new("riscv64");
         // The exact number of architectures may vary, so just check it's a reasonable number
-        assert!(
-            !engine
-                .dispatcher
-                .borrow()
-                .supported_architectures()
-                .is_empty()
-        ); // Basic sanity check
+        assert!(!engine.dispatcher.get().supported_architectures().is_empty()); // Basic sanity check
     }
 
     #[test]
@@ -626,6 +1946,181 @@ mod tests {
         assert!(!result.is_successful());
     }
 
+    fn riscv_config(hex_bytes: Vec<u8>, skip_data: bool) -> DisasmConfig {
+        DisasmConfig {
+            arch_spec: ArchitectureSpec::parse("riscv32").unwrap(),
+            hex_bytes,
+            start_address: 0,
+            display_options: DisplayOptions {
+                detailed: false,
+                alias_regs: false,
+                real_detail: false,
+                unsigned_immediate: false,
+                json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
+            },
+            on_decode_error: OnDecodeError::from_skip_data(skip_data),
+            annotations: Annotations::default(),
+        }
+    }
+
+    #[test]
+    fn test_instruction_hook_streams_and_can_stop_early() {
+        use std::sync::{Arc, Mutex};
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let engine = DisassemblyEngine::new("riscv32").on_instruction(move |instruction| {
+            let mut sink = sink.lock().unwrap();
+            sink.push(instruction.mnemonic.clone());
+            if sink.len() == 2 {
+                HookAction::Stop
+            } else {
+                HookAction::Continue
+            }
+        });
+
+        let config = riscv_config([0x93, 0x00, 0x10, 0x00].repeat(4), false);
+        let result = engine.disassemble(&config).unwrap();
+
+        assert_eq!(result.instruction_count(), 2);
+        assert_eq!(result.bytes_processed, 8);
+        assert_eq!(*seen.lock().unwrap(), ["li", "li"]);
+    }
+
+    #[test]
+    fn test_error_hook_reports_offsets_and_can_stop() {
+        use std::sync::{Arc, Mutex};
+
+        let offsets = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&offsets);
+        let engine = DisassemblyEngine::new("riscv32")
+            .with_skip_data(true)
+            .on_error(move |offset, _| {
+                sink.lock().unwrap().push(offset);
+                HookAction::Continue
+            });
+        let bytes = vec![0x93, 0x00, 0x10, 0x00, 0xff, 0xff, 0x93, 0x00, 0x10, 0x00];
+        let result = engine
+            .disassemble(&riscv_config(bytes.clone(), true))
+            .unwrap();
+        assert_eq!(*offsets.lock().unwrap(), [4]);
+        assert_eq!(result.instruction_count(), 3);
+
+        let engine = DisassemblyEngine::new("riscv32").on_error(|_, _| HookAction::Stop);
+        let result = engine.disassemble(&riscv_config(bytes, false)).unwrap();
+        assert_eq!(result.instruction_count(), 1);
+        assert_eq!(result.error_count(), 1);
+        assert_eq!(result.errors[0].input_offset, Some(4));
+    }
+
+    #[test]
+    fn test_decode_stats_count_only_this_engines_runs() {
+        let busy = std::thread::spawn(|| {
+            let engine = DisassemblyEngine::new("riscv32");
+            for _ in 0..50 {
+                engine
+                    .disassemble(&riscv_config([0x93, 0x00, 0x10, 0x00].repeat(16), false))
+                    .unwrap();
+            }
+        });
+
+        let engine = DisassemblyEngine::new("riscv32").with_skip_data(true);
+        let bytes = vec![0x93, 0x00, 0x10, 0x00, 0xff, 0xff, 0x93, 0x00, 0x10, 0x00];
+        engine
+            .disassemble(&riscv_config(bytes.clone(), true))
+            .unwrap();
+        engine.disassemble(&riscv_config(bytes, true)).unwrap();
+        busy.join().unwrap();
+
+        let stats = engine.decode_stats();
+        assert_eq!(stats.handlers.len(), 1);
+        let riscv = stats.handler("riscv").unwrap();
+        assert_eq!((riscv.decoded, riscv.unknown, riscv.bytes), (4, 2, 16));
+        assert_eq!(stats.unsupported, 0);
+    }
+
+    #[test]
+    fn test_engines_run_on_worker_threads() {
+        let bytes = [0x93, 0x00, 0x10, 0x00, 0x13, 0x01, 0x01, 0xff].repeat(64);
+        let expected = DisassemblyEngine::new("riscv32")
+            .disassemble(&riscv_config(bytes.clone(), false))
+            .unwrap();
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let config = riscv_config(bytes.clone(), false);
+                let engine = DisassemblyEngine::new("riscv32")
+                    .with_detail(true)
+                    .on_instruction(|_| HookAction::Continue);
+                std::thread::spawn(move || engine.disassemble(&config).unwrap())
+            })
+            .collect();
+        for worker in workers {
+            let result = worker.join().unwrap();
+            assert_eq!(result.instruction_count(), expected.instruction_count());
+            assert!(
+                result
+                    .instructions
+                    .iter()
+                    .zip(&expected.instructions)
+                    .all(|(a, b)| a.to_string() == b.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_formatter_appends_address_comments() {
+        let engine = DisassemblyEngine::new("riscv32");
+        let config = DisasmConfig {
+            arch_spec: ArchitectureSpec::parse("riscv32").unwrap(),
+            hex_bytes: vec![0x93, 0x00, 0x10, 0x00, 0x13, 0x01, 0x01, 0xff],
+            start_address: 0x1000,
+            display_options: DisplayOptions {
+                detailed: false,
+                alias_regs: false,
+                real_detail: false,
+                unsigned_immediate: false,
+                json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
+            },
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::new().with_comment(0x1004, "reserve frame"),
+        };
+        let result = engine.disassemble(&config).unwrap();
+
+        let text = DisassemblyFormatter::new(config.output_config()).format(&result);
+        assert_eq!(
+            text,
+            "1000    li\tra, 1\n1004    addi\tsp, sp, -0x10  ; reserve frame\n"
+        );
+
+        let mut json_config = config.output_config();
+        json_config.json = true;
+        let parsed: Value =
+            serde_json::from_str(&DisassemblyFormatter::new(json_config).format(&result)).unwrap();
+        assert!(parsed["instructions"][0].get("comments").is_none());
+        assert_eq!(parsed["instructions"][1]["comments"][0], "reserve frame");
+    }
+
     #[test]
     fn test_json_formatter_includes_decoded_ir() {
         let engine = DisassemblyEngine::new("riscv64");
@@ -639,19 +2134,38 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig {
             text_profile: robustone_core::ir::TextRenderProfile::Capstone,
+            syntax: Syntax::Default,
+            nops: NopStyle::Profile,
             alias_regs: false,
             capstone_aliases: true,
             compressed_aliases: true,
             unsigned_immediate: false,
             show_hex: false,
             show_detail_sections: false,
+            show_operands: false,
             json: true,
+            format: OutputFormat::Text,
+            annotations: Annotations::default(),
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
         });
         let parsed: Value = serde_json::from_str(&formatter.format(&result)).unwrap();
 
@@ -680,6 +2194,7 @@ mod tests {
                 },
                 Operand::Immediate { value: 1 },
             ],
+            operand_access: Vec::new(),
             registers_read: vec![RegisterId::riscv(0)],
             registers_written: vec![RegisterId::riscv(1)],
             implicit_registers_read: Vec::new(),
@@ -690,6 +2205,7 @@ mod tests {
                 capstone_mnemonic: Some("li".to_string()),
                 capstone_hidden_operands: vec![1],
             },
+            decoded_by: None,
             render: Some(robustone_riscv::render::render_riscv_text_parts),
         };
         let instruction =
@@ -698,8 +2214,17 @@ mod tests {
             instructions: vec![instruction],
             start_address: 0,
             architecture: "riscv32".to_string(),
+            address_width: AddressWidth::Bits32,
             bytes_processed: 4,
             errors: Vec::new(),
+            explanations: BTreeMap::new(),
+            metadata: BTreeMap::new(),
+            expansions: BTreeMap::new(),
+            dataflow: None,
+            encoding_classes: BTreeMap::new(),
+            undecoded: Vec::new(),
+            big_endian: false,
+            warnings: Vec::new(),
         };
         let formatter = DisassemblyFormatter::new(OutputConfig::minimal());
         let output = formatter.format(&result);
@@ -722,31 +2247,263 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: true,
+            on_decode_error: OnDecodeError::Emit(DataStep::Resync),
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig {
             text_profile: robustone_core::ir::TextRenderProfile::Capstone,
+            syntax: Syntax::Default,
+            nops: NopStyle::Profile,
             alias_regs: false,
             capstone_aliases: true,
             compressed_aliases: true,
             unsigned_immediate: false,
             show_hex: false,
             show_detail_sections: false,
+            show_operands: false,
             json: true,
+            format: OutputFormat::Text,
+            annotations: Annotations::default(),
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
         });
         let parsed: Value = serde_json::from_str(&formatter.format(&result)).unwrap();
 
         // SKIPDATA should emit data pseudo-instructions, not errors.
         assert!(parsed["errors"].as_array().unwrap().is_empty());
-        assert_eq!(parsed["instructions"][0]["mnemonic"], ".byte");
+        assert_eq!(parsed["instructions"][0]["mnemonic"], ".half");
         assert_eq!(parsed["instructions"][0]["kind"], "data");
-        assert_eq!(parsed["instructions"][0]["operands"], "0xff, 0xff");
+        assert_eq!(parsed["instructions"][0]["operands"], "0xffff");
         assert_eq!(parsed["instructions"][0]["address"], 0x40);
         assert_eq!(parsed["instructions"][0]["size"], 2);
     }
 
+    #[test]
+    fn test_decode_error_policies() {
+        let config = |policy| {
+            DisasmConfig::builder()
+                .arch("riscv32")
+                .hex("ffffffff 13055000")
+                .address(0x1000)
+                .on_decode_error(policy)
+                .build()
+                .unwrap()
+        };
+        let mnemonics = |result: &DisassemblyResult| {
+            result
+                .instructions
+                .iter()
+                .map(|instruction| (instruction.address, instruction.mnemonic.clone()))
+                .collect::<Vec<_>>()
+        };
+        let engine = DisassemblyEngine::new("riscv32");
+
+        assert!(engine.disassemble(&config(OnDecodeError::Stop)).is_err());
+        assert!(
+            engine
+                .disassemble(&config(OnDecodeError::Callback))
+                .is_err()
+        );
+
+        let skipped = engine
+            .disassemble(&config(OnDecodeError::SkipAlign(4)))
+            .unwrap();
+        assert_eq!(mnemonics(&skipped), [(0x1004, "li".to_string())]);
+        assert_eq!(skipped.warnings.len(), 1);
+        assert_eq!(skipped.warnings[0].address, Some(0x1000));
+
+        let engine = engine.on_error(|_, _| HookAction::Continue);
+        let listed = engine
+            .disassemble(&config(OnDecodeError::Callback))
+            .unwrap();
+        assert_eq!(
+            mnemonics(&listed),
+            [
+                (0x1000, ".byte".to_string()),
+                (0x1002, ".byte".to_string()),
+                (0x1004, "li".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skip_data_emits_truncated_tail_as_data_items() {
+        let disassemble = |arch: &str, hex: &str| {
+            let config = DisasmConfig::builder()
+                .arch(arch)
+                .hex(hex)
+                .address(0x1000)
+                .skip_data(true)
+                .build()
+                .unwrap();
+            DisassemblyEngine::new(arch).disassemble(&config).unwrap()
+        };
+        let items = |result: &DisassemblyResult| {
+            result
+                .instructions
+                .iter()
+                .map(|i| (i.address, i.mnemonic.clone(), i.operands.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        // nop, then the first three bytes of another nop.
+        let result = disassemble("riscv32", "13000000 130000");
+        assert_eq!(
+            items(&result)[1..],
+            [
+                (0x1004, ".half".to_string(), "0x0013".to_string()),
+                (0x1006, ".byte".to_string(), "0x00".to_string()),
+            ]
+        );
+        assert_eq!(result.bytes_processed, 7);
+
+        // A stray byte after a compressed instruction.
+        let result = disassemble("riscv32", "0100 00");
+        assert_eq!(
+            items(&result)[1],
+            (0x1002, ".byte".to_string(), "0x00".to_string())
+        );
+
+        // x86 has no 2-byte unit: the partial `mov eax, imm32` is one item.
+        let result = disassemble("x86", "b80100");
+        assert_eq!(
+            items(&result),
+            [(0x1000, ".byte".to_string(), "0xb8, 0x01, 0x00".to_string())]
+        );
+
+        // Without SKIPDATA the truncation is still an error.
+        let config = DisasmConfig::builder()
+            .arch("x86")
+            .hex("b80100")
+            .build()
+            .unwrap();
+        let error = DisassemblyEngine::new("x86")
+            .disassemble(&config)
+            .unwrap_err();
+        assert_eq!(error.stable_kind(), "need_more_bytes");
+    }
+
+    #[test]
+    fn test_skipped_ranges_become_strings_words_and_bytes() {
+        let layout = DataLayout::new(false, AddressWidth::Bits32, false);
+        let items = |address: u64, bytes: &[u8]| {
+            data_directives(address, bytes, layout)
+                .into_iter()
+                .map(|i| (i.address, i.mnemonic, i.operands))
+                .collect::<Vec<_>>()
+        };
+
+        let mut bytes = vec![0xff, 0xff, 0xff, 0xff, 0xfe, 0xff];
+        bytes.extend_from_slice(b"say \"hi\"\n\0");
+        bytes.extend_from_slice(&[0x01, 0x02]);
+        assert_eq!(
+            items(0x1000, &bytes),
+            [
+                (0x1000, ".word".to_string(), "0xffffffff".to_string()),
+                (0x1004, ".byte".to_string(), "0xfe, 0xff".to_string()),
+                (
+                    0x1006,
+                    ".asciz".to_string(),
+                    r#""say \"hi\"\n""#.to_string()
+                ),
+                (0x1010, ".byte".to_string(), "0x01, 0x02".to_string()),
+            ]
+        );
+
+        // Short runs stay bytes; unterminated strings use `.ascii`.
+        assert_eq!(items(0x1001, b"abc")[0].1, ".byte");
+        assert_eq!(
+            items(0x1001, "héllo".as_bytes()),
+            [(0x1001, ".ascii".to_string(), "\"héllo\"".to_string())]
+        );
+
+        let layout = DataLayout::new(true, AddressWidth::Bits64, true);
+        let words = data_directives(0x1000, &[0, 0, 0, 0, 0, 0, 0x12, 0x34], layout);
+        assert_eq!(words[0].mnemonic, ".quad");
+        assert_eq!(words[0].operands, "0x0000000000001234");
+
+        // Real-mode x86 pointers are 16 bits, which x86 assemblers call `.word`.
+        let layout = DataLayout::new(true, AddressWidth::Bits16, false);
+        let words = data_directives(0x100, &[0x34, 0x12], layout);
+        assert_eq!(words[0].mnemonic, ".word");
+        assert_eq!(words[0].operands, "0x1234");
+    }
+
+    #[test]
+    fn test_result_records_address_width_per_mode() {
+        let result = DisassemblyResult::new(0, "x16".to_string());
+        assert_eq!(result.address_width, AddressWidth::Bits16);
+
+        let result = DisassemblyResult::new(0, "x64".to_string());
+        assert_eq!(result.address_width, AddressWidth::Bits64);
+    }
+
+    #[test]
+    fn test_skip_data_coalesces_runs_before_listing_them() {
+        use std::sync::{Arc, Mutex};
+
+        let config = DisasmConfig::builder()
+            .arch("riscv32")
+            .hex("13000000 ffffffff ffff 13000000")
+            .address(0x1000)
+            .skip_data(true)
+            .build()
+            .unwrap();
+        let streamed = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&streamed);
+        let result = DisassemblyEngine::new("riscv32")
+            .with_data_directives(true)
+            .on_instruction(move |instruction| {
+                sink.lock().unwrap().push(instruction.mnemonic.clone());
+                HookAction::Continue
+            })
+            .disassemble(&config)
+            .unwrap();
+
+        let mnemonics: Vec<_> = result
+            .instructions
+            .iter()
+            .map(|i| i.mnemonic.as_str())
+            .collect();
+        assert_eq!(mnemonics, ["addi", ".word", ".byte", "addi"]);
+        assert_eq!(*streamed.lock().unwrap(), mnemonics);
+        assert_eq!(result.bytes_processed, 14);
+
+        // By default each 2-byte resync step is its own `.byte` item, as in
+        // cstool.
+        let result = DisassemblyEngine::new("riscv32")
+            .disassemble(&config)
+            .unwrap();
+        let items: Vec<_> = result
+            .instructions
+            .iter()
+            .map(|i| (i.address, i.mnemonic.as_str(), i.size))
+            .collect();
+        assert_eq!(
+            items,
+            [
+                (0x1000, "addi", 4),
+                (0x1004, ".byte", 2),
+                (0x1006, ".byte", 2),
+                (0x1008, ".byte", 2),
+                (0x100a, "addi", 4),
+            ]
+        );
+    }
+
     #[test]
     fn test_json_formatter_emits_data_pseudo_for_undecodable_compressed() {
         let engine = DisassemblyEngine::new("riscv32");
@@ -760,19 +2517,38 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: true,
+            on_decode_error: OnDecodeError::Emit(DataStep::Resync),
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig {
             text_profile: robustone_core::ir::TextRenderProfile::Capstone,
+            syntax: Syntax::Default,
+            nops: NopStyle::Profile,
             alias_regs: false,
             capstone_aliases: true,
             compressed_aliases: true,
             unsigned_immediate: false,
             show_hex: false,
             show_detail_sections: false,
+            show_operands: false,
             json: true,
+            format: OutputFormat::Text,
+            annotations: Annotations::default(),
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
         });
         let parsed: Value = serde_json::from_str(&formatter.format(&result)).unwrap();
 
@@ -783,6 +2559,270 @@ mod tests {
         assert_eq!(parsed["instructions"][0]["operands"], "0x01, 0x60");
     }
 
+    #[test]
+    fn test_text_formatter_applies_column_layout() {
+        let config = DisasmConfig::builder()
+            .arch("riscv32")
+            .hex("930010008280")
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv32")
+            .disassemble(&config)
+            .unwrap();
+        let formatter = DisassemblyFormatter::new(
+            OutputConfig::builder()
+                .show_hex(true)
+                .layout(ColumnLayout {
+                    address: AddressDisplay::Absolute,
+                    address_width: 8,
+                    byte_grouping: crate::config::ByteGrouping::Pairs,
+                    word_column: false,
+                    mnemonic_width: 6,
+                    alignment: ColumnAlignment::Spaces,
+                })
+                .build(),
+        );
+
+        assert_eq!(
+            formatter.format(&result),
+            "00000000  9300 1000  li     ra, 1\n00000004  8280       jr     ra\n"
+        );
+    }
+
+    #[test]
+    fn test_text_formatter_shows_offsets_or_hides_addresses() {
+        let config = DisasmConfig::builder()
+            .arch("riscv32")
+            .hex("930010008280")
+            .address(0x8000_0000)
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv32")
+            .disassemble(&config)
+            .unwrap();
+        let render = |address| {
+            DisassemblyFormatter::new(
+                OutputConfig::builder()
+                    .layout(ColumnLayout {
+                        address,
+                        ..ColumnLayout::default()
+                    })
+                    .build(),
+            )
+            .format(&result)
+        };
+
+        assert_eq!(
+            render(AddressDisplay::Offset),
+            "0    li\tra, 1\n4    jr\tra\n"
+        );
+        assert_eq!(render(AddressDisplay::Hidden), "li\tra, 1\njr\tra\n");
+    }
+
+    #[test]
+    fn test_uppercase_style_applies_to_every_formatter() {
+        let config = DisasmConfig::builder()
+            .arch("riscv32")
+            .hex("1301b10a")
+            .address(0xabc0)
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv32")
+            .disassemble(&config)
+            .unwrap();
+        let formatter = |format| {
+            DisassemblyFormatter::new(
+                OutputConfig::builder()
+                    .show_hex(true)
+                    .uppercase(true)
+                    .format(format)
+                    .build(),
+            )
+        };
+
+        assert_eq!(
+            formatter(OutputFormat::Text).format(&result),
+            "ABC0  13 01 B1 0A  ADDI\tsp, sp, 0xAB\n"
+        );
+        assert_eq!(
+            formatter(OutputFormat::Gdb).format(&result),
+            "   0xABC0:\t13 01 B1 0A\tADDI\tsp,sp,0xAB\n"
+        );
+        let parsed: Value =
+            serde_json::from_str(&formatter(OutputFormat::Json).format(&result)).unwrap();
+        assert_eq!(parsed["instructions"][0]["mnemonic"], "ADDI");
+        assert_eq!(parsed["instructions"][0]["operands"], "sp, sp, 0xAB");
+        assert_eq!(parsed["instructions"][0]["decoded"]["mnemonic"], "addi");
+    }
+
+    #[test]
+    fn test_nop_styles_apply_to_rvi_and_rvc_no_ops() {
+        // nop, addi zero, a0, 5, c.addi zero, 3, c.li zero, 5, addi sp, sp, 0xab
+        let config = DisasmConfig::builder()
+            .arch("riscv64")
+            .hex("13000000130055000d0015401301b10a")
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv64")
+            .disassemble(&config)
+            .unwrap();
+        let listing = |nops, syntax, format| {
+            DisassemblyFormatter::new(
+                OutputConfig::builder()
+                    .syntax(syntax)
+                    .nops(nops)
+                    .format(format)
+                    .build(),
+            )
+            .format(&result)
+            .lines()
+            .filter_map(|line| Some(line.split_once(' ')?.1.trim_start().to_string()))
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            listing(NopStyle::Profile, Syntax::Default, OutputFormat::Text),
+            [
+                "addi\tzero, zero, 0",
+                "addi\tzero, a0, 5",
+                "addi\tzero, zero, 3",
+                "li\tzero, 5",
+                "addi\tsp, sp, 0xab",
+            ]
+        );
+        assert_eq!(
+            listing(NopStyle::Collapse, Syntax::Default, OutputFormat::Text),
+            ["nop", "nop", "nop", "nop", "addi\tsp, sp, 0xab"]
+        );
+        assert_eq!(
+            listing(NopStyle::Collapse, Syntax::Llvm, OutputFormat::Text),
+            ["nop", "nop", "nop", "nop", "addi\tsp, sp, 171"]
+        );
+        assert_eq!(
+            listing(NopStyle::Literal, Syntax::Default, OutputFormat::Text),
+            [
+                "addi\tzero, zero, 0",
+                "addi\tzero, a0, 5",
+                "c.addi\tzero, zero, 3",
+                "c.li\tzero, 5",
+                "addi\tsp, sp, 0xab",
+            ]
+        );
+        assert_eq!(
+            listing(NopStyle::Literal, Syntax::Llvm, OutputFormat::Text),
+            [
+                "addi\tzero, zero, 0",
+                "addi\tzero, a0, 5",
+                "addi\tzero, zero, 3",
+                "addi\tzero, zero, 5",
+                "addi\tsp, sp, 171",
+            ]
+        );
+        // Collapsed HINTs would reassemble to different bytes.
+        let gas = DisassemblyFormatter::new(
+            OutputConfig::builder()
+                .nops(NopStyle::Collapse)
+                .format(OutputFormat::Gas)
+                .build(),
+        )
+        .format(&result);
+        assert!(gas.contains("\taddi\tzero, a0, 5\n"), "{gas}");
+    }
+
+    #[test]
+    fn test_explain_records_breakdowns_only_for_decoded_instructions() {
+        // c.addi a0, 1 followed by an undecodable halfword
+        let config = DisasmConfig::builder()
+            .arch("riscv64")
+            .hex("0505ffff")
+            .skip_data(true)
+            .explain(true)
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv64")
+            .with_skip_data(true)
+            .disassemble(&config)
+            .unwrap();
+
+        assert_eq!(result.explanations.len(), 1);
+        let breakdown = &result.explanations[&0];
+        assert_eq!(breakdown.format, "CI");
+        assert_eq!(breakdown.immediate, Some(1));
+        assert_eq!(result.metadata.len(), 1);
+        assert_eq!(result.metadata[&0].extension, "C");
+
+        let plain = DisassemblyEngine::new("riscv64")
+            .disassemble(
+                &DisasmConfig::builder()
+                    .arch("riscv64")
+                    .hex("0505")
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert!(plain.explanations.is_empty());
+    }
+
+    #[test]
+    fn test_gas_formatter_emits_reassemblable_source() {
+        // jal .L1; c.jr ra; jal 0x100 (outside the listing); 0xffff data
+        let config = DisasmConfig::builder()
+            .arch("riscv32")
+            .hex("ef0040008280ef000010ffff")
+            .address(0x1000)
+            .skip_data(true)
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv32")
+            .with_skip_data(true)
+            .disassemble(&config)
+            .unwrap();
+        let formatter =
+            DisassemblyFormatter::new(OutputConfig::builder().format(OutputFormat::Gas).build());
+
+        assert_eq!(
+            formatter.format(&result),
+            "# Disassembled by robustone from riscv32 at 0x1000\n\
+             \t.text\n\
+             \t.option norvc\n\
+             \tjal\t.L1\n\
+             .L1:\n\
+             \t.option push\n\
+             \t.option rvc\n\
+             \tjr\tra\n\
+             \t.option pop\n\
+             \t.4byte\t0x100000ef\t# jal\t0x100\n\
+             \t.half\t0xffff\n"
+        );
+    }
+
+    #[test]
+    fn test_trace_formatter_emits_joinable_keys() {
+        // li a0, 5; c.li a0, 7
+        let config = DisasmConfig::builder()
+            .arch("riscv64")
+            .hex("130550001d45")
+            .address(0x8000_0000)
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv64")
+            .disassemble(&config)
+            .unwrap();
+        // Display options such as alias registers do not change the key.
+        let formatter = DisassemblyFormatter::new(
+            OutputConfig::builder()
+                .format(OutputFormat::Trace)
+                .alias_regs(true)
+                .build(),
+        );
+
+        assert_eq!(
+            formatter.format(&result),
+            "0x0000000080000000\t0x00500513\triscv64:addi:x10,x0,5\n\
+             0x0000000080000004\t0x451d\triscv64:c.li:x10,7\n"
+        );
+    }
+
     #[test]
     fn test_json_formatter_counts_skipped_bytes_in_bytes_processed() {
         let engine = DisassemblyEngine::new("riscv64");
@@ -796,19 +2836,38 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: true,
+            on_decode_error: OnDecodeError::Emit(DataStep::Resync),
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig {
             text_profile: robustone_core::ir::TextRenderProfile::Capstone,
+            syntax: Syntax::Default,
+            nops: NopStyle::Profile,
             alias_regs: false,
             capstone_aliases: true,
             compressed_aliases: true,
             unsigned_immediate: false,
             show_hex: false,
             show_detail_sections: false,
+            show_operands: false,
             json: true,
+            format: OutputFormat::Text,
+            annotations: Annotations::default(),
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
         });
         let parsed: Value = serde_json::from_str(&formatter.format(&result)).unwrap();
 
@@ -816,7 +2875,7 @@ mod tests {
         // SKIPDATA emits data pseudo-instructions, not errors.
         assert!(parsed["errors"].as_array().unwrap().is_empty());
         assert_eq!(parsed["instructions"].as_array().unwrap().len(), 1);
-        assert_eq!(parsed["instructions"][0]["mnemonic"], ".byte");
+        assert_eq!(parsed["instructions"][0]["mnemonic"], ".half");
         assert_eq!(parsed["instructions"][0]["kind"], "data");
     }
 
@@ -833,8 +2892,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: true,
+            on_decode_error: OnDecodeError::Emit(DataStep::Resync),
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig::minimal());
@@ -859,19 +2929,38 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: true,
+            on_decode_error: OnDecodeError::Emit(DataStep::Resync),
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig {
             text_profile: robustone_core::ir::TextRenderProfile::Capstone,
+            syntax: Syntax::Default,
+            nops: NopStyle::Profile,
             alias_regs: false,
             capstone_aliases: true,
             compressed_aliases: true,
             unsigned_immediate: false,
             show_hex: false,
             show_detail_sections: false,
+            show_operands: false,
             json: true,
+            format: OutputFormat::Text,
+            annotations: Annotations::default(),
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
         });
         let parsed: Value = serde_json::from_str(&formatter.format(&result)).unwrap();
 
@@ -881,9 +2970,10 @@ mod tests {
         assert_eq!(parsed["instructions"][0]["mnemonic"], ".byte");
         assert_eq!(parsed["instructions"][0]["kind"], "data");
         assert_eq!(parsed["instructions"][0]["operands"], "0xff, 0xff");
-        assert_eq!(parsed["instructions"][1]["mnemonic"], ".byte");
+        // The first unit resyncs; the second is a truncated tail.
+        assert_eq!(parsed["instructions"][1]["mnemonic"], ".half");
         assert_eq!(parsed["instructions"][1]["kind"], "data");
-        assert_eq!(parsed["instructions"][1]["operands"], "0xff, 0xff");
+        assert_eq!(parsed["instructions"][1]["operands"], "0xffff");
     }
 
     #[test]
@@ -899,8 +2989,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig::minimal());
@@ -922,8 +3023,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig::canonical_json());
@@ -947,8 +3059,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter = DisassemblyFormatter::new(OutputConfig::canonical_json());
@@ -972,8 +3095,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: true,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter =
@@ -996,8 +3130,19 @@ mod tests {
                 real_detail: true,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
         let formatter =
@@ -1041,8 +3186,19 @@ mod tests {
                     real_detail: true,
                     unsigned_immediate: false,
                     json: true,
+                    format: OutputFormat::Text,
+                    layout: ColumnLayout::default(),
+                    uppercase: false,
+                    labels: false,
+                    explain: false,
+                    expand_compressed: false,
+                    dataflow: false,
+                    mark_hints: false,
+                    syntax: Syntax::Default,
+                    nops: Nops::Profile,
                 },
-                skip_data: false,
+                on_decode_error: OnDecodeError::Stop,
+                annotations: Annotations::default(),
             };
             let result = engine.disassemble(&config).unwrap();
             let formatter = DisassemblyFormatter::new(OutputConfig::from_display_options(
@@ -1053,10 +3209,9 @@ mod tests {
             assert_eq!(parsed["instructions"][0]["mnemonic"], "csrr");
             assert_eq!(parsed["instructions"][0]["operands"], expected_operands);
             assert_eq!(parsed["instructions"][0]["decoded"]["mnemonic"], "csrrs");
-            assert_eq!(
-                parsed["instructions"][0]["decoded"]["operands"][1]["value"],
-                expected_csr
-            );
+            let csr = &parsed["instructions"][0]["decoded"]["operands"][1];
+            assert_eq!(csr["kind"], "system_register");
+            assert_eq!(csr["number"], expected_csr);
             assert_eq!(parsed["instructions"][0]["decoded"]["groups"][0], "system");
         }
     }
@@ -1067,6 +3222,7 @@ mod tests {
             instructions: Vec::new(),
             start_address: 0,
             architecture: "riscv32".to_string(),
+            address_width: AddressWidth::Bits32,
             bytes_processed: 0,
             errors: vec![
                 DisassemblyIssue::from_core_error(
@@ -1106,6 +3262,14 @@ mod tests {
                     &[0x00, 0x60],
                 ),
             ],
+            explanations: BTreeMap::new(),
+            metadata: BTreeMap::new(),
+            expansions: BTreeMap::new(),
+            dataflow: None,
+            encoding_classes: BTreeMap::new(),
+            undecoded: Vec::new(),
+            big_endian: false,
+            warnings: Vec::new(),
         };
 
         let formatter = DisassemblyFormatter::new(OutputConfig::minimal());
@@ -1131,20 +3295,39 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config).unwrap();
 
         let text_formatter = DisassemblyFormatter::new(OutputConfig {
             text_profile: robustone_core::ir::TextRenderProfile::Canonical,
+            syntax: Syntax::Default,
+            nops: NopStyle::Profile,
             alias_regs: false,
             capstone_aliases: false,
             compressed_aliases: false,
             unsigned_immediate: false,
             show_hex: false,
             show_detail_sections: false,
+            show_operands: false,
             json: false,
+            format: OutputFormat::Text,
+            annotations: Annotations::default(),
+            layout: ColumnLayout::default(),
+            uppercase: false,
+            labels: false,
         });
         let json_formatter = DisassemblyFormatter::new(OutputConfig::canonical_json());
 
@@ -1171,8 +3354,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config_with_detail).unwrap();
         assert!(
@@ -1191,8 +3385,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
         let result = engine.disassemble(&config_without_detail).unwrap();
         assert!(
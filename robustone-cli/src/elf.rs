@@ -0,0 +1,603 @@
+//! Relocatable object input (`--object FILE`).
+//!
+//! Disassembles the `.text` section of an ELF object file (`.o`), or of every
+//! member of a static archive (`.a`). In an
+//! object the `auipc`/`lui` pairs, calls, and branches that refer to other
+//! symbols still hold placeholder immediates, so the operand each relocation
+//! in `.rela.text` patches is rendered as the expression the compiler wrote:
+//!
+//! ```text
+//! 0       auipc   a0, %pcrel_hi(counter)
+//! 4       lw      a1, %pcrel_lo(counter)(a0)
+//! e       lui     a2, %hi(table+0x8)
+//! 12      addi    a2, a2, %lo(table+0x8)
+//! 16      auipc   ra, %pcrel_hi(g)
+//! 1a      jalr    ra, %pcrel_lo(g)(ra)
+//! ```
+//!
+//! `%pcrel_lo` names the symbol of its `%pcrel_hi` partner rather than the
+//! local label the relocation points at. Only RISC-V relocations are
+//! interpreted; objects for other architectures are listed unchanged.
+//!
+//! `--symbol PATTERN` narrows the listing to the functions whose names match
+//! a glob pattern (`main`, `init_*`).
+//!
+//! An archive is listed member by member under `==> member.o <==` headings;
+//! members without code are skipped.
+
+use crate::arch::ArchitectureSpec;
+use crate::compressed::read_input;
+use crate::error::{CliError, Result};
+
+use object::elf;
+use object::{
+    Object, ObjectKind, ObjectSection, ObjectSymbol, RelocationFlags, RelocationTarget, SymbolKind,
+    SymbolSection,
+};
+use robustone_core::ir::TextRenderProfile;
+use robustone_core::{Instruction, RenderOptions, render_instruction_text};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The `.text` section of an object file.
+#[derive(Debug)]
+pub struct ObjectText {
+    /// Address of the first byte: the requested base, or the section address.
+    pub address: u64,
+    pub bytes: Vec<u8>,
+    pub relocations: Relocations,
+    /// Functions defined in the section, in address order.
+    pub symbols: Vec<TextSymbol>,
+}
+
+/// A function symbol and the bytes it covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextSymbol {
+    pub name: String,
+    pub address: u64,
+    /// Size in bytes. Symbols without a recorded size (hand-written
+    /// assembly) extend to the next symbol or the end of the section.
+    pub size: u64,
+}
+
+impl ObjectText {
+    /// The functions whose names match the glob `pattern`, where `*` matches
+    /// any run of characters and `?` any single one.
+    pub fn matching_symbols(&self, pattern: &str) -> Vec<TextSymbol> {
+        self.symbols
+            .iter()
+            .filter(|symbol| glob_match(pattern, &symbol.name))
+            .cloned()
+            .collect()
+    }
+
+    /// The bytes of `symbol`.
+    pub fn symbol_bytes(&self, symbol: &TextSymbol) -> &[u8] {
+        let start = (symbol.address.wrapping_sub(self.address) as usize).min(self.bytes.len());
+        let end = start
+            .saturating_add(symbol.size as usize)
+            .min(self.bytes.len());
+        &self.bytes[start..end]
+    }
+}
+
+fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.chars().next() {
+        None => name.is_empty(),
+        Some('*') => {
+            let rest = &pattern[1..];
+            name.char_indices()
+                .map(|(index, _)| index)
+                .chain([name.len()])
+                .any(|index| glob_match(rest, &name[index..]))
+        }
+        Some(first) => {
+            let mut chars = name.chars();
+            match chars.next() {
+                Some(c) if first == '?' || first == c => {
+                    glob_match(&pattern[first.len_utf8()..], chars.as_str())
+                }
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Relocated operands keyed by instruction address.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Relocations {
+    expressions: BTreeMap<u64, String>,
+}
+
+impl Relocations {
+    /// Check whether no operand is relocated.
+    pub fn is_empty(&self) -> bool {
+        self.expressions.is_empty()
+    }
+
+    /// The expression (e.g. `%pcrel_hi(counter)`) replacing the relocated
+    /// operand of the instruction at `address`.
+    pub fn get(&self, address: u64) -> Option<&str> {
+        self.expressions.get(&address).map(String::as_str)
+    }
+
+    /// Render `instruction` with its relocated operand replaced, or `None`
+    /// when no relocation applies at its address.
+    ///
+    /// Aliases that drop the relocated immediate (`mv` for `addi rd, rs, 0`,
+    /// `jalr ra` for `jalr ra, 0(ra)`) fall back to the base mnemonic.
+    pub fn render(
+        &self,
+        instruction: &Instruction,
+        options: RenderOptions,
+    ) -> Option<(String, String)> {
+        let expression = self.get(instruction.address)?;
+        let aliased = render_instruction_text(instruction, options);
+        let (mnemonic, operands) = match &instruction.decoded {
+            Some(decoded) => {
+                // Same register names as the aliased text, without the aliases.
+                let alias_regs = options.capstone_aliases
+                    && (options.alias_regs
+                        || !matches!(options.text_profile, TextRenderProfile::Canonical));
+                let base = decoded.render_text_parts_with_options(
+                    options.text_profile,
+                    alias_regs,
+                    false,
+                    options.compressed_aliases,
+                    options.unsigned_immediate,
+                );
+                if last_operand(&aliased.1) == last_operand(&base.1) {
+                    aliased
+                } else {
+                    base
+                }
+            }
+            None => aliased,
+        };
+
+        let (leading, last) = match operands.rsplit_once(", ") {
+            Some((leading, last)) => (Some(leading), last),
+            None => (None, operands.as_str()),
+        };
+        // A memory operand keeps its base register: `%lo(x)(a0)`.
+        let replaced = match last.find('(') {
+            Some(paren) if last.ends_with(')') => format!("{expression}{}", &last[paren..]),
+            _ => expression.to_string(),
+        };
+        let operands = match leading {
+            Some(leading) => format!("{leading}, {replaced}"),
+            None => replaced,
+        };
+        Some((mnemonic, operands))
+    }
+}
+
+fn last_operand(operands: &str) -> &str {
+    operands
+        .rsplit_once(", ")
+        .map_or(operands, |(_, last)| last)
+}
+
+/// A range of an object file listed on its own: a whole `.text` section, or
+/// one function selected with `--symbol`.
+#[derive(Debug)]
+pub struct ObjectListing {
+    /// Lines printed above the range in text output: the archive member and
+    /// the function it comes from.
+    pub heading: Vec<String>,
+    pub address: u64,
+    pub bytes: Vec<u8>,
+    pub relocations: Relocations,
+}
+
+/// Read the ranges to list from the object file or archive at `path`.
+///
+/// `spec` must belong to the same architecture family as the objects. Each
+/// `.text` section is placed at `base` when given, otherwise at its own
+/// address (zero in a relocatable object). With `symbol`, only the functions
+/// matching that glob pattern are returned.
+pub fn load_listings(
+    path: &Path,
+    spec: &ArchitectureSpec,
+    base: Option<u64>,
+    symbol: Option<&str>,
+) -> Result<Vec<ObjectListing>> {
+    let data = read_input(path)?;
+    parse_listings(&data, spec, base, symbol)
+}
+
+fn parse_listings(
+    data: &[u8],
+    spec: &ArchitectureSpec,
+    base: Option<u64>,
+    symbol: Option<&str>,
+) -> Result<Vec<ObjectListing>> {
+    let archive = object::read::archive::ArchiveFile::parse(data).ok();
+    let mut objects = Vec::new();
+    match &archive {
+        Some(archive) => {
+            for member in archive.members() {
+                let member = member.map_err(|error| invalid(error.to_string()))?;
+                let name = String::from_utf8_lossy(member.name()).into_owned();
+                let bytes = member
+                    .data(data)
+                    .map_err(|error| invalid(format!("{name}: {error}")))?;
+                let text = parse_object(bytes, spec, base)
+                    .map_err(|error| invalid(format!("{name}: {}", error_message(&error))))?;
+                objects.push((Some(name), text));
+            }
+        }
+        None => objects.push((None, parse_object(data, spec, base)?)),
+    }
+
+    let mut listings = Vec::new();
+    for (member, text) in objects {
+        if text.bytes.is_empty() {
+            continue;
+        }
+        let mut heading = member
+            .map(|member| vec![format!("==> {member} <==")])
+            .unwrap_or_default();
+        match symbol {
+            Some(pattern) => {
+                for function in text.matching_symbols(pattern) {
+                    heading.push(format!("{}:", function.name));
+                    listings.push(ObjectListing {
+                        heading: std::mem::take(&mut heading),
+                        address: function.address,
+                        bytes: text.symbol_bytes(&function).to_vec(),
+                        relocations: text.relocations.clone(),
+                    });
+                }
+            }
+            None => listings.push(ObjectListing {
+                heading,
+                address: text.address,
+                bytes: text.bytes,
+                relocations: text.relocations,
+            }),
+        }
+    }
+
+    if listings.is_empty() {
+        return Err(match (symbol, &archive) {
+            (Some(pattern), _) => CliError::validation(
+                "symbol",
+                format!("no function in the object matches `{pattern}`"),
+            ),
+            (None, Some(_)) => invalid("no archive member has code in `.text`".to_string()),
+            (None, None) => invalid("the object has no code in `.text`".to_string()),
+        });
+    }
+    Ok(listings)
+}
+
+fn invalid(message: String) -> CliError {
+    CliError::validation("object", message)
+}
+
+/// The message of a validation error, without the field prefix.
+fn error_message(error: &CliError) -> String {
+    match error {
+        CliError::Validation { message, .. } => message.clone(),
+        error => error.to_string(),
+    }
+}
+
+fn parse_object(data: &[u8], spec: &ArchitectureSpec, base: Option<u64>) -> Result<ObjectText> {
+    let file = object::File::parse(data).map_err(|error| invalid(error.to_string()))?;
+    if file.format() != object::BinaryFormat::Elf {
+        return Err(invalid("only ELF object files are supported".to_string()));
+    }
+    if let Some(category) = category(file.architecture())
+        && category != spec.arch.category()
+    {
+        return Err(invalid(format!(
+            "the object contains {category} code, not {}",
+            spec.arch.category()
+        )));
+    }
+
+    // Data-only objects have no (or an empty) `.text`; callers skip them.
+    let Some(text) = file.section_by_name(".text") else {
+        return Ok(ObjectText {
+            address: base.unwrap_or_default(),
+            bytes: Vec::new(),
+            relocations: Relocations::default(),
+            symbols: Vec::new(),
+        });
+    };
+    let bytes = text
+        .data()
+        .map_err(|error| invalid(error.to_string()))?
+        .to_vec();
+    let address = base.unwrap_or(text.address());
+
+    let mut relocations = Relocations::default();
+    if file.kind() == ObjectKind::Relocatable && spec.arch.category() == "RISC-V" {
+        let entries = text
+            .relocations()
+            .filter_map(|(offset, relocation)| {
+                let RelocationFlags::Elf { r_type } = relocation.flags() else {
+                    return None;
+                };
+                let RelocationTarget::Symbol(index) = relocation.target() else {
+                    return None;
+                };
+                let symbol = file.symbol_by_index(index).ok()?;
+                Some(RiscVRelocation {
+                    offset,
+                    r_type,
+                    target: target_name(&file, &symbol, relocation.addend()),
+                    label_offset: (symbol.section() == SymbolSection::Section(text.index()))
+                        .then(|| symbol.address()),
+                })
+            })
+            .collect::<Vec<_>>();
+        relocations.expressions = riscv_expressions(&entries)
+            .into_iter()
+            .map(|(offset, expression)| (address.wrapping_add(offset), expression))
+            .collect();
+    }
+
+    let section_address = text.address();
+    let section_len = bytes.len() as u64;
+    let mut starts = file
+        .symbols()
+        .filter(|symbol| {
+            symbol.section() == SymbolSection::Section(text.index())
+                && matches!(symbol.kind(), SymbolKind::Text | SymbolKind::Unknown)
+        })
+        .filter_map(|symbol| {
+            let name = symbol.name().ok()?;
+            // Local labels and `$x`/`$d` mapping symbols are not functions.
+            if name.is_empty() || name.starts_with(".L") || name.starts_with('$') {
+                return None;
+            }
+            let offset = symbol.address().checked_sub(section_address)?;
+            (offset < section_len).then(|| (offset, name.to_string(), symbol.size()))
+        })
+        .collect::<Vec<_>>();
+    starts.sort();
+    let symbols = starts
+        .iter()
+        .enumerate()
+        .map(|(index, (offset, name, size))| {
+            let next = starts[index + 1..]
+                .iter()
+                .map(|(next, _, _)| *next)
+                .find(|next| next > offset)
+                .unwrap_or(section_len);
+            let size = if *size == 0 { next - offset } else { *size };
+            TextSymbol {
+                name: name.clone(),
+                address: address.wrapping_add(*offset),
+                size: size.min(section_len - offset),
+            }
+        })
+        .collect();
+
+    Ok(ObjectText {
+        address,
+        bytes,
+        relocations,
+        symbols,
+    })
+}
+
+/// Architecture family of an object, in [`Architecture::category`] terms.
+///
+/// [`Architecture::category`]: crate::arch::Architecture::category
+fn category(architecture: object::Architecture) -> Option<&'static str> {
+    match architecture {
+        object::Architecture::Riscv32 | object::Architecture::Riscv64 => Some("RISC-V"),
+        object::Architecture::Arm | object::Architecture::Aarch64 => Some("ARM"),
+        object::Architecture::I386 | object::Architecture::X86_64 => Some("x86"),
+        object::Architecture::LoongArch64 => Some("LoongArch"),
+        _ => None,
+    }
+}
+
+/// `symbol+addend`, naming section symbols after their section.
+fn target_name<'data>(
+    file: &object::File<'data>,
+    symbol: &object::Symbol<'data, '_>,
+    addend: i64,
+) -> String {
+    let mut name = symbol.name().unwrap_or_default().to_string();
+    if name.is_empty()
+        && let Some(section) = symbol
+            .section_index()
+            .and_then(|index| file.section_by_index(index).ok())
+    {
+        name = section.name().unwrap_or_default().to_string();
+    }
+    match addend {
+        0 => name,
+        addend if addend < 0 => format!("{name}-0x{:x}", addend.unsigned_abs()),
+        addend => format!("{name}+0x{addend:x}"),
+    }
+}
+
+struct RiscVRelocation {
+    offset: u64,
+    r_type: u32,
+    target: String,
+    /// Offset of the target in `.text`, for `%pcrel_lo` labels.
+    label_offset: Option<u64>,
+}
+
+/// Operand expressions by section offset for the RISC-V relocations.
+fn riscv_expressions(relocations: &[RiscVRelocation]) -> BTreeMap<u64, String> {
+    // `%pcrel_lo` points at the `auipc` holding the matching high part.
+    let high_parts = relocations
+        .iter()
+        .filter(|relocation| {
+            matches!(
+                relocation.r_type,
+                elf::R_RISCV_PCREL_HI20
+                    | elf::R_RISCV_GOT_HI20
+                    | elf::R_RISCV_TLS_GOT_HI20
+                    | elf::R_RISCV_TLS_GD_HI20
+            )
+        })
+        .map(|relocation| (relocation.offset, relocation.target.as_str()))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut expressions = BTreeMap::new();
+    for relocation in relocations {
+        let target = &relocation.target;
+        let expression = match relocation.r_type {
+            elf::R_RISCV_HI20 => format!("%hi({target})"),
+            elf::R_RISCV_LO12_I | elf::R_RISCV_LO12_S => format!("%lo({target})"),
+            elf::R_RISCV_PCREL_HI20 => format!("%pcrel_hi({target})"),
+            elf::R_RISCV_GOT_HI20 => format!("%got_pcrel_hi({target})"),
+            elf::R_RISCV_TLS_GOT_HI20 => format!("%tls_ie_pcrel_hi({target})"),
+            elf::R_RISCV_TLS_GD_HI20 => format!("%tls_gd_pcrel_hi({target})"),
+            elf::R_RISCV_PCREL_LO12_I | elf::R_RISCV_PCREL_LO12_S => {
+                let partner = relocation
+                    .label_offset
+                    .and_then(|offset| high_parts.get(&offset))
+                    .copied()
+                    .unwrap_or(target);
+                format!("%pcrel_lo({partner})")
+            }
+            elf::R_RISCV_TPREL_HI20 => format!("%tprel_hi({target})"),
+            elf::R_RISCV_TPREL_LO12_I | elf::R_RISCV_TPREL_LO12_S => {
+                format!("%tprel_lo({target})")
+            }
+            elf::R_RISCV_BRANCH
+            | elf::R_RISCV_JAL
+            | elf::R_RISCV_RVC_BRANCH
+            | elf::R_RISCV_RVC_JUMP => target.clone(),
+            // `call` expands to an `auipc`/`jalr` pair relocated as one.
+            elf::R_RISCV_CALL | elf::R_RISCV_CALL_PLT => {
+                expressions.insert(relocation.offset + 4, format!("%pcrel_lo({target})"));
+                format!("%pcrel_hi({target})")
+            }
+            _ => continue,
+        };
+        expressions.insert(relocation.offset, expression);
+    }
+    expressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DisasmConfig;
+    use crate::disasm::{DisassemblyEngine, DisassemblyFormatter};
+
+    /// Assembled from `tests/objects/riscv64-relocs.s`.
+    const OBJECT: &[u8] = include_bytes!("../../tests/objects/riscv64-relocs.o");
+    /// `riscv64-relocs.o` and a data-only member.
+    const ARCHIVE: &[u8] = include_bytes!("../../tests/objects/libfixture.a");
+
+    #[test]
+    fn test_text_and_relocations_are_read() {
+        let spec = ArchitectureSpec::parse("riscv64").unwrap();
+        let text = parse_object(OBJECT, &spec, None).unwrap();
+        assert_eq!(text.address, 0);
+        assert_eq!(text.bytes[..4], [0x17, 0x05, 0x00, 0x00]);
+        assert_eq!(text.relocations.get(0), Some("%pcrel_hi(counter)"));
+        assert_eq!(text.relocations.get(4), Some("%pcrel_lo(counter)"));
+        assert_eq!(text.relocations.get(0x12), Some("%lo(table+0x8)"));
+        assert_eq!(text.relocations.get(0x1a), Some("%pcrel_lo(g)"));
+        assert_eq!(text.relocations.get(0x8), None);
+
+        let moved = parse_object(OBJECT, &spec, Some(0x1000)).unwrap();
+        assert_eq!(moved.relocations.get(0x1000), Some("%pcrel_hi(counter)"));
+    }
+
+    #[test]
+    fn test_relocated_operands_are_rendered_as_expressions() {
+        let spec = ArchitectureSpec::parse("riscv64").unwrap();
+        let text = parse_object(OBJECT, &spec, None).unwrap();
+        let config = DisasmConfig::builder()
+            .arch("riscv64")
+            .bytes(text.symbol_bytes(&text.symbols[0]).to_vec())
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv64")
+            .disassemble(&config)
+            .unwrap();
+        let listing = DisassemblyFormatter::new(config.output_config())
+            .with_relocations(text.relocations)
+            .format(&result);
+        assert_eq!(
+            listing,
+            "0    auipc\ta0, %pcrel_hi(counter)\n\
+             4    lw\ta1, %pcrel_lo(counter)(a0)\n\
+             8    addi\ta1, a1, 1\n\
+             a    sw\ta1, %pcrel_lo(counter)(a0)\n\
+             e    lui\ta2, %hi(table+0x8)\n\
+             12    addi\ta2, a2, %lo(table+0x8)\n\
+             16    auipc\tra, %pcrel_hi(g)\n\
+             1a    jalr\t%pcrel_lo(g)(ra)\n\
+             1e    beqz\ta1, f\n\
+             22    jr\tra\n"
+        );
+    }
+
+    #[test]
+    fn test_function_symbols_are_selected_by_pattern() {
+        let spec = ArchitectureSpec::parse("riscv64").unwrap();
+        let text = parse_object(OBJECT, &spec, Some(0x1000)).unwrap();
+        let names: Vec<_> = text.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["f", "init_uart", "init_timer"]);
+
+        let selected = text.matching_symbols("init_*");
+        assert_eq!(selected.len(), 2);
+        assert_eq!(selected[0].address, 0x1024);
+        assert_eq!(text.symbol_bytes(&selected[0]), [0x05, 0x45, 0x82, 0x80]);
+        // `init_timer` has no `.size` and runs to the end of the section.
+        assert_eq!(selected[1].size, 6);
+
+        assert_eq!(text.matching_symbols("?").len(), 1);
+        assert!(text.matching_symbols("init").is_empty());
+        assert_eq!(text.matching_symbols("*").len(), 3);
+    }
+
+    #[test]
+    fn test_archive_members_are_listed_under_headings() {
+        let spec = ArchitectureSpec::parse("riscv64").unwrap();
+        // The data-only member has no code and is skipped.
+        let listings = parse_listings(ARCHIVE, &spec, None, None).unwrap();
+        assert_eq!(listings.len(), 1);
+        assert_eq!(listings[0].heading, ["==> riscv64-relocs.o <=="]);
+        assert_eq!(listings[0].bytes.len(), 0x2e);
+        assert_eq!(listings[0].relocations.get(0), Some("%pcrel_hi(counter)"));
+
+        let listings = parse_listings(ARCHIVE, &spec, None, Some("init_*")).unwrap();
+        let headings: Vec<_> = listings.iter().map(|l| l.heading.clone()).collect();
+        assert_eq!(
+            headings,
+            [
+                vec!["==> riscv64-relocs.o <==", "init_uart:"],
+                vec!["init_timer:"]
+            ]
+        );
+
+        let error = parse_listings(ARCHIVE, &spec, None, Some("main")).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("no function in the object matches `main`")
+        );
+        let spec = ArchitectureSpec::parse("x64").unwrap();
+        let error = parse_listings(ARCHIVE, &spec, None, None).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("riscv64-relocs.o: the object contains RISC-V code")
+        );
+    }
+
+    #[test]
+    fn test_objects_for_another_architecture_are_rejected() {
+        let spec = ArchitectureSpec::parse("aarch64").unwrap();
+        let error = parse_object(OBJECT, &spec, None).unwrap_err();
+        assert!(error.to_string().contains("RISC-V code, not ARM"));
+        assert!(parse_object(b"\x7fELF", &spec, None).is_err());
+    }
+}
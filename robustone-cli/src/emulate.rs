@@ -0,0 +1,129 @@
+//! Emulation mode (`--emulate [--steps N]`).
+//!
+//! Loads the hex input as RAM at the start address, points the PC at its
+//! first byte, and runs up to `--steps` instructions with the RISC-V
+//! interpreter. Each executed instruction is printed with the registers and
+//! memory it wrote, followed by why execution stopped and the final non-zero
+//! registers:
+//!
+//! ```text
+//! 0x00001000: li a0, 5                    a0 = 0x5
+//! 0x00001004: ecall
+//! stopped after 1 steps: ecall at 0x1004
+//! a0 = 0x5
+//! ```
+
+use crate::config::DisasmConfig;
+use crate::error::{CliError, Result};
+
+use robustone_core::OutputStyle;
+use robustone_riscv::emulate::{Emulator, FlatMemory};
+use robustone_riscv::shared::registers::get_register_name;
+use std::fmt::Write;
+
+/// Default value of `--steps`.
+pub const DEFAULT_STEPS: usize = 100;
+
+/// Width of the instruction column before the written values.
+const TEXT_WIDTH: usize = 28;
+
+/// Run the configured input and render the execution trace.
+pub fn render_trace(config: &DisasmConfig, max_steps: usize) -> Result<String> {
+    let memory = FlatMemory::new(config.start_address, config.hex_bytes.clone());
+    let mut emulator = Emulator::new(config.arch_name(), memory).map_err(|_| {
+        CliError::validation(
+            "emulate",
+            format!(
+                "`--emulate` supports riscv32 and riscv64, not `{}`",
+                config.arch_name()
+            ),
+        )
+    })?;
+    emulator.set_pc(config.start_address);
+
+    let style = OutputStyle::compact();
+    let mut output = String::new();
+    let mut executed = 0;
+    let stop = loop {
+        if executed == max_steps {
+            break "step limit reached".to_string();
+        }
+        let step = match emulator.step() {
+            Ok(step) => step,
+            Err(stop) => break stop.to_string(),
+        };
+        executed += 1;
+
+        let effects: Vec<String> = step
+            .register_writes
+            .iter()
+            .map(|(id, value)| format!("{} = 0x{value:x}", register_name(*id)))
+            .chain(step.memory_writes.iter().map(|write| {
+                format!(
+                    "[0x{:x}] = 0x{:x} ({} bytes)",
+                    write.address, write.value, write.width
+                )
+            }))
+            .collect();
+        let line = format!(
+            "0x{:08x}: {:<TEXT_WIDTH$}{}",
+            step.instruction.address,
+            step.instruction.to_line(&style),
+            effects.join(", ")
+        );
+        output.push_str(line.trim_end());
+        output.push('\n');
+    };
+
+    let _ = writeln!(output, "stopped after {executed} steps: {stop}");
+    for id in 1..32 {
+        let value = emulator.register(id);
+        if value != 0 {
+            let _ = writeln!(output, "{} = 0x{value:x}", register_name(id));
+        }
+    }
+    Ok(output)
+}
+
+fn register_name(id: u32) -> &'static str {
+    get_register_name(id as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(arch: &str, hex: &str, steps: usize) -> Result<String> {
+        let config = DisasmConfig::builder()
+            .arch(arch)
+            .hex(hex)
+            .address(0x1000)
+            .build()?;
+        render_trace(&config, steps)
+    }
+
+    #[test]
+    fn test_trace_lists_writes_and_stop_reason() {
+        // li a0, 5; ecall
+        let output = trace("riscv32", "13055000 73000000", 10).unwrap();
+        assert_eq!(
+            output,
+            "0x00001000: li a0, 5                    a0 = 0x5\n\
+             stopped after 1 steps: ecall at 0x1004\n\
+             a0 = 0x5\n"
+        );
+    }
+
+    #[test]
+    fn test_trace_honours_the_step_limit() {
+        // j . (an infinite loop)
+        let output = trace("riscv64", "6f000000", 3).unwrap();
+        assert_eq!(output.lines().count(), 4);
+        assert!(output.ends_with("stopped after 3 steps: step limit reached\n"));
+    }
+
+    #[test]
+    fn test_non_riscv_architectures_are_rejected() {
+        assert!(trace("x86", "90", 1).is_err());
+    }
+}
@@ -12,11 +12,20 @@ pub enum CliError {
     /// Disassembly backend failure.
     Disassembly {
         kind: String,
+        code: u16,
         message: String,
         architecture: Option<String>,
+        location: Option<Box<ErrorLocation>>,
     },
     /// Validation errors with detailed context.
-    Validation { field: String, message: String },
+    Validation {
+        field: String,
+        message: String,
+        /// The offending text in the argument, when it can be pointed at.
+        span: Option<Box<InputSpan>>,
+    },
+    /// Several independent problems found by one validation pass.
+    InvalidConfig(Vec<ValidationIssue>),
     /// Parse errors with detailed context.
     Parse { context: String, message: String },
     /// I/O errors from file operations.
@@ -31,13 +40,186 @@ pub enum CliError {
     Reported(i32),
 }
 
+/// Process exit codes reported by the `robustone` binary.
+///
+/// These values are part of the public contract so shell scripts can branch
+/// on the failure category without parsing messages.
+pub mod exit_code {
+    /// Disassembly (or the requested report) completed.
+    pub const SUCCESS: i32 = 0;
+    /// Unclassified failure, such as an I/O error.
+    pub const FAILURE: i32 = 1;
+    /// The command line could not be parsed.
+    pub const USAGE: i32 = 2;
+    /// The architecture token is unknown or not usable for disassembly.
+    pub const INVALID_ARCHITECTURE: i32 = 3;
+    /// The hex input, address, or an input file is malformed.
+    pub const INVALID_INPUT: i32 = 4;
+    /// Decoding failed and `--skip-data` was not given.
+    pub const DECODE_FAILURE: i32 = 5;
+    /// A `--max-instructions`, `--max-output`, or `--timeout` limit stopped the run.
+    pub const LIMIT_EXCEEDED: i32 = 6;
+}
+
+/// One problem found while validating the command line, reported together
+/// with the others in [`CliError::InvalidConfig`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The argument at fault: `arch_mode`, `hex_code`, or `address`.
+    pub field: String,
+    pub message: String,
+    /// How to fix the problem, when there is an obvious fix.
+    pub suggestion: Option<String>,
+    /// The offending text in the argument, when it can be pointed at.
+    pub span: Option<Box<InputSpan>>,
+}
+
+impl ValidationIssue {
+    /// Describe `error` as an issue with the argument it concerns.
+    pub fn from_error(error: &CliError, suggestion: Option<String>) -> Self {
+        let span = match error {
+            CliError::Validation { span, .. } => span.clone(),
+            _ => None,
+        };
+        let (field, message) = match error {
+            CliError::Validation { field, message, .. } => (field.as_str(), message.clone()),
+            CliError::Parse { context, message } if context == "architecture" => {
+                ("arch_mode", message.clone())
+            }
+            CliError::Parse { context, message } => (context.as_str(), message.clone()),
+            CliError::Architecture(message) | CliError::Configuration(message) => {
+                ("arch_mode", message.clone())
+            }
+            error => ("input", error.to_string()),
+        };
+        Self {
+            field: field.to_string(),
+            message,
+            suggestion,
+            span,
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " ({suggestion})")?;
+        }
+        if let Some(span) = &self.span {
+            write!(f, "\n{span}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The command-line argument an input error was found in, with the range of
+/// the offending text, so the error can echo the argument and mark it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSpan {
+    /// The argument as given.
+    pub input: String,
+    /// Byte offset of the offending text in `input`.
+    pub offset: usize,
+    /// Byte length of the offending text; at least one column is marked.
+    pub len: usize,
+    /// Short note printed after the carets.
+    pub label: String,
+}
+
+/// Characters of context shown on each side of a span in a long argument.
+const SPAN_CONTEXT: usize = 32;
+
+impl fmt::Display for InputSpan {
+    /// Two lines: the line of the argument holding the span, cut to a window
+    /// around it, and carets under the offending text.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let offset = self.offset.min(self.input.len());
+        let end = (offset + self.len).min(self.input.len());
+        let line_start = self.input[..offset]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = self.input[end..]
+            .find('\n')
+            .map_or(self.input.len(), |index| end + index);
+
+        let before: Vec<char> = self.input[line_start..offset].chars().collect();
+        let skipped = before.len().saturating_sub(SPAN_CONTEXT);
+        let marked = self.input[offset..end].chars().count();
+        let after: Vec<char> = self.input[end..line_end].chars().collect();
+        let kept = after.len().min(SPAN_CONTEXT);
+
+        let prefix = if skipped > 0 { "..." } else { "" };
+        let suffix = if kept < after.len() { "..." } else { "" };
+        let column = prefix.len() + before.len() - skipped;
+        write!(
+            f,
+            "    | {prefix}{}{}{}{suffix}\n    | {:column$}{} {}",
+            before[skipped..].iter().collect::<String>(),
+            &self.input[offset..end],
+            after[..kept].iter().collect::<String>(),
+            "",
+            "^".repeat(marked.max(1)),
+            self.label
+        )
+    }
+}
+
+/// Where in the input a disassembly failure happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorLocation {
+    /// Offset of the failing instruction in the input buffer.
+    pub offset: usize,
+    /// Address the failing instruction would have been decoded at.
+    pub address: u64,
+    /// The offending bytes (a short prefix of the remaining input).
+    pub bytes: Vec<u8>,
+}
+
 impl CliError {
     /// Create a new validation error.
     pub fn validation(field: impl Into<String>, message: impl Into<String>) -> Self {
         Self::Validation {
             field: field.into(),
             message: message.into(),
+            span: None,
+        }
+    }
+
+    /// Create a validation error that points at `len` bytes of `input`
+    /// starting at `offset`.
+    pub fn validation_at(
+        field: impl Into<String>,
+        message: impl Into<String>,
+        input: &str,
+        offset: usize,
+        len: usize,
+        label: impl Into<String>,
+    ) -> Self {
+        Self::Validation {
+            field: field.into(),
+            message: message.into(),
+            span: Some(Box::new(InputSpan {
+                input: input.to_string(),
+                offset,
+                len,
+                label: label.into(),
+            })),
+        }
+    }
+
+    /// Re-anchor the span of an error found in one token of `argument`,
+    /// starting at byte `token_offset`, so it echoes the whole argument.
+    pub(crate) fn within(mut self, argument: &str, token_offset: usize) -> Self {
+        if let CliError::Validation {
+            span: Some(span), ..
+        } = &mut self
+        {
+            span.input = argument.to_string();
+            span.offset += token_offset;
         }
+        self
     }
 
     /// Create a new parse error.
@@ -48,6 +230,21 @@ impl CliError {
         }
     }
 
+    /// Combine the errors of one validation pass, each with an optional
+    /// suggestion: a single error without a suggestion is returned as is,
+    /// anything else becomes [`CliError::InvalidConfig`].
+    pub fn from_validation_errors(mut errors: Vec<(CliError, Option<String>)>) -> Self {
+        if errors.len() == 1 && errors[0].1.is_none() {
+            return errors.remove(0).0;
+        }
+        Self::InvalidConfig(
+            errors
+                .iter()
+                .map(|(error, suggestion)| ValidationIssue::from_error(error, suggestion.clone()))
+                .collect(),
+        )
+    }
+
     /// Create a generic error.
     pub fn generic(message: impl Into<String>) -> Self {
         Self::Generic(message.into())
@@ -55,10 +252,19 @@ impl CliError {
 
     /// Create a structured disassembly error from the core layer.
     pub fn disassembly(error: &DisasmError) -> Self {
+        let location = error.location();
         Self::Disassembly {
             kind: error.stable_kind().to_string(),
+            code: error.code(),
             message: error.detail_message(),
             architecture: error.architecture_name().map(str::to_string),
+            location: location.map(|location| {
+                Box::new(ErrorLocation {
+                    offset: location.offset,
+                    address: location.address,
+                    bytes: location.bytes.to_vec(),
+                })
+            }),
         }
     }
 
@@ -68,10 +274,44 @@ impl CliError {
     }
 
     /// Return the process exit code that should be used for this error.
+    ///
+    /// See [`exit_code`] for the documented values.
     pub fn exit_code(&self) -> i32 {
         match self {
             CliError::Reported(code) => *code,
-            _ => 1,
+            CliError::Architecture(_) | CliError::Configuration(_) => {
+                exit_code::INVALID_ARCHITECTURE
+            }
+            CliError::Validation { field, .. } if field == "arch_mode" => {
+                exit_code::INVALID_ARCHITECTURE
+            }
+            CliError::Parse { context, .. } if context == "architecture" => {
+                exit_code::INVALID_ARCHITECTURE
+            }
+            CliError::Validation { field, .. }
+                if matches!(
+                    field.as_str(),
+                    "capabilities" | "archs" | "list-insns" | "list-regs"
+                ) =>
+            {
+                exit_code::USAGE
+            }
+            CliError::InvalidConfig(issues)
+                if issues.iter().any(|issue| issue.field == "arch_mode") =>
+            {
+                exit_code::INVALID_ARCHITECTURE
+            }
+            CliError::Validation { .. } | CliError::Parse { .. } | CliError::InvalidConfig(_) => {
+                exit_code::INVALID_INPUT
+            }
+            CliError::Disassembly { kind, .. } => match kind.as_str() {
+                "unsupported_architecture" => exit_code::INVALID_ARCHITECTURE,
+                "invalid_hex_code" | "invalid_address" => exit_code::INVALID_INPUT,
+                "limit_exceeded" => exit_code::LIMIT_EXCEEDED,
+                _ => exit_code::DECODE_FAILURE,
+            },
+            CliError::MissingArgument(_) | CliError::InvalidCommand(_) => exit_code::USAGE,
+            CliError::Io(_) | CliError::Generic(_) => exit_code::FAILURE,
         }
     }
 
@@ -90,18 +330,50 @@ impl fmt::Display for CliError {
                 kind,
                 message,
                 architecture,
+                location,
+                ..
             } => {
                 if let Some(architecture) = architecture {
                     write!(
                         f,
                         "Disassembly error [{kind}] for {architecture}: {message}"
-                    )
+                    )?;
                 } else {
-                    write!(f, "Disassembly error [{kind}]: {message}")
+                    write!(f, "Disassembly error [{kind}]: {message}")?;
                 }
+                if let Some(location) = location {
+                    write!(
+                        f,
+                        " (offset {}, address 0x{:x}",
+                        location.offset, location.address
+                    )?;
+                    if !location.bytes.is_empty() {
+                        write!(f, ", bytes {}", hex::encode(&location.bytes))?;
+                    }
+                    write!(f, ")")?;
+                }
+                Ok(())
             }
-            CliError::Validation { field, message } => {
-                write!(f, "Validation error for '{field}': {message}")
+            CliError::Validation {
+                field,
+                message,
+                span,
+            } => {
+                write!(f, "Validation error for '{field}': {message}")?;
+                if let Some(span) = span {
+                    write!(f, "\n{span}")?;
+                }
+                Ok(())
+            }
+            CliError::InvalidConfig(issues) if issues.len() == 1 => {
+                write!(f, "Invalid argument: {}", issues[0])
+            }
+            CliError::InvalidConfig(issues) => {
+                write!(f, "Found {} problems with the arguments:", issues.len())?;
+                for issue in issues {
+                    write!(f, "\n  - {issue}")?;
+                }
+                Ok(())
             }
             CliError::Parse { context, message } => {
                 write!(f, "Parse error in '{context}': {message}")
@@ -187,3 +459,129 @@ impl fmt::Display for ParseError {
 impl std::error::Error for ParseError {}
 
 pub type Result<T> = std::result::Result<T, CliError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use robustone_core::types::error::DecodeErrorKind;
+
+    #[test]
+    fn test_disassembly_error_carries_location_from_core() {
+        let core = DisasmError::decode_failure(
+            DecodeErrorKind::InvalidEncoding,
+            Some("riscv32".to_string()),
+            "reserved opcode",
+        )
+        .at(4, 0x1004, &[0xff, 0xff]);
+        let error = CliError::disassembly(&core);
+
+        match &error {
+            CliError::Disassembly {
+                kind,
+                code,
+                location: Some(location),
+                ..
+            } => {
+                assert_eq!(kind, "invalid_encoding");
+                assert_eq!(*code, 202);
+                assert_eq!(location.offset, 4);
+                assert_eq!(location.address, 0x1004);
+                assert_eq!(location.bytes, [0xff, 0xff]);
+            }
+            other => panic!("expected disassembly error, got {other:?}"),
+        }
+        assert_eq!(
+            error.to_string(),
+            "Disassembly error [invalid_encoding] for riscv32: reserved opcode (offset 4, address 0x1004, bytes ffff)"
+        );
+    }
+
+    #[test]
+    fn test_exit_code_classifies_failure_categories() {
+        assert_eq!(
+            CliError::validation("arch_mode", "unknown").exit_code(),
+            exit_code::INVALID_ARCHITECTURE
+        );
+        assert_eq!(
+            CliError::validation("hex_code", "bad digit").exit_code(),
+            exit_code::INVALID_INPUT
+        );
+        assert_eq!(
+            CliError::MissingArgument("hex_code".to_string()).exit_code(),
+            exit_code::USAGE
+        );
+
+        let decode = DisasmError::decode_failure(
+            DecodeErrorKind::InvalidEncoding,
+            Some("riscv32".to_string()),
+            "reserved opcode",
+        );
+        assert_eq!(
+            CliError::disassembly(&decode).exit_code(),
+            exit_code::DECODE_FAILURE
+        );
+        assert_eq!(
+            CliError::disassembly(&DisasmError::UnsupportedArchitecture("mips".to_string()))
+                .exit_code(),
+            exit_code::INVALID_ARCHITECTURE
+        );
+        assert_eq!(CliError::reported(7).exit_code(), 7);
+    }
+
+    #[test]
+    fn test_every_exit_code_is_in_the_contract() {
+        let contract = include_str!("../../docs/public-contract.md");
+        for code in [
+            exit_code::SUCCESS,
+            exit_code::FAILURE,
+            exit_code::USAGE,
+            exit_code::INVALID_ARCHITECTURE,
+            exit_code::INVALID_INPUT,
+            exit_code::DECODE_FAILURE,
+            exit_code::LIMIT_EXCEEDED,
+        ] {
+            let row = format!("| `{code}` |");
+            assert!(
+                contract.lines().any(|line| line.starts_with(&row)),
+                "docs/public-contract.md has no exit code row for {row}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validation_errors_are_reported_together() {
+        let single = CliError::from_validation_errors(vec![(
+            CliError::validation("address", "Invalid hexadecimal address format"),
+            None,
+        )]);
+        assert!(matches!(single, CliError::Validation { ref field, .. } if field == "address"));
+
+        let long = format!("{} zz {}", "00".repeat(40), "11".repeat(40));
+        let error = CliError::validation_at("hex_token", "bad", &long, 81, 2, "here");
+        let rendered = error.to_string();
+        let lines: Vec<_> = rendered.lines().collect();
+        assert_eq!(
+            lines[1],
+            format!("    | ...{} zz {}...", "0".repeat(31), "1".repeat(31))
+        );
+        assert_eq!(lines[2], format!("    | {}^^ here", " ".repeat(35)));
+
+        let combined = CliError::from_validation_errors(vec![
+            (
+                CliError::parse("architecture", "Unknown architecture: riscv6"),
+                Some("did you mean `riscv64`?".to_string()),
+            ),
+            (
+                CliError::validation("hex_code", "Invalid hex character: g"),
+                None,
+            ),
+        ]);
+        assert_eq!(combined.exit_code(), exit_code::INVALID_ARCHITECTURE);
+        assert_eq!(
+            combined.to_string(),
+            "Found 2 problems with the arguments:\n  \
+             - arch_mode: Unknown architecture: riscv6 (did you mean `riscv64`?)\n  \
+             - hex_code: Invalid hex character: g"
+        );
+    }
+}
@@ -3,20 +3,33 @@
 //! This module wires together argument parsing, configuration building,
 //! and the actual disassembly pipeline exposed through the CLI.
 
-use crate::capabilities::{render_capabilities_json, render_capabilities_text};
-use crate::command::{Cli, DisplayOptions, render_help_text, render_short_help_text};
+use crate::arch::ArchitectureSpec;
+use crate::capabilities::{
+    mnemonic_listing, register_listing, render_architectures_json, render_architectures_text,
+    render_capabilities_json, render_capabilities_text, render_mnemonics_json,
+    render_mnemonics_text, render_registers_json, render_registers_text,
+};
+use crate::command::{Cli, Command, DisplayOptions, render_help_text, render_short_help_text};
 use crate::config::{DisasmConfig, OutputConfig};
 use crate::disasm::{DisassemblyEngine, DisassemblyFormatter, DisassemblyIssue, DisassemblyResult};
-use crate::error::{CliError, Result};
-use crate::version_info::print_version_info;
-
-use clap::Parser;
+use crate::elf::{ObjectListing, Relocations};
+use crate::error::{CliError, Result, exit_code};
+use crate::limits::ResourceLimits;
+use crate::memory_map::MemoryMap;
+use crate::unsupported::{render_unsupported_summary, summarize_unsupported};
+use crate::utils::{AddressedHex, parse_address, parse_addressed_hex};
+use crate::version_info::{VersionInfo, print_version_info};
+
+use clap::{CommandFactory, Parser, ValueEnum};
 use std::ffi::OsString;
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 /// High-level application executor that orchestrates the entire CLI workflow.
 pub struct CliExecutor {
     #[allow(dead_code)]
     engine: DisassemblyEngine,
+    limits: ResourceLimits,
 }
 
 impl CliExecutor {
@@ -24,113 +37,373 @@ impl CliExecutor {
     pub fn new() -> Self {
         Self {
             engine: DisassemblyEngine::new("riscv64"),
+            limits: ResourceLimits::unlimited(),
         }
     }
 
+    /// Enforce `limits` on the runs started through this executor's API
+    /// methods. Command-line runs use the `--max-*` and `--timeout` flags.
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Execute the CLI workflow.
     pub fn run(&self) -> Result<()> {
         let args = std::env::args_os().collect::<Vec<_>>();
         match Cli::try_parse_from(args.clone()) {
             Ok(cli) => self.execute_cli(cli),
             Err(error)
-                if args.iter().any(|arg| arg == "--json")
+                if requests_json_output(&args)
                     && !matches!(
                         error.kind(),
                         clap::error::ErrorKind::DisplayHelp
                             | clap::error::ErrorKind::DisplayVersion
                     ) =>
             {
-                println!("{}", self.render_clap_error_json(&args, &error));
-                Err(CliError::reported(2))
+                eprintln!("{}", self.render_clap_error_json(&args, &error));
+                Err(CliError::reported(clap_exit_code(&error)))
             }
-            Err(error) if matches!(error.kind(), clap::error::ErrorKind::DisplayHelp) => {
+            Err(error)
+                if matches!(error.kind(), clap::error::ErrorKind::DisplayHelp)
+                    && !is_subcommand_invocation(&args) =>
+            {
                 print!("{}", self.render_display_help(&args));
                 Ok(())
             }
-            Err(error) => {
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    clap::error::ErrorKind::DisplayHelp | clap::error::ErrorKind::DisplayVersion
+                ) =>
+            {
                 error.exit();
             }
+            Err(error) => {
+                let _ = error.print();
+                Err(CliError::reported(clap_exit_code(&error)))
+            }
         }
     }
 
     /// Execute the workflow with the provided CLI arguments.
     fn execute_cli(&self, cli: Cli) -> Result<()> {
+        crate::logging::init(cli.verbose);
+
+        if let Some(command) = &cli.command {
+            return self.execute_command(command);
+        }
+
         if cli.should_show_capabilities() {
             if let Err(error) = cli.validate_capabilities_request() {
-                if cli.json {
-                    println!(
-                        "{}",
-                        self.render_cli_error_json(&cli, &error, "validate_capabilities")
-                    );
-                    return Err(CliError::reported(1));
+                if cli.wants_json() {
+                    let rendered =
+                        self.render_cli_error_json(&cli, &error, "validate_capabilities");
+                    return Err(report_json_error(rendered, &error));
+                }
+                return Err(error);
+            }
+            println!("{}", self.render_capabilities(cli.wants_json()));
+            return Ok(());
+        }
+
+        if cli.should_show_architectures() {
+            if let Err(error) = cli.validate_architectures_request() {
+                if cli.wants_json() {
+                    let rendered = self.render_cli_error_json(&cli, &error, "validate_archs");
+                    return Err(report_json_error(rendered, &error));
                 }
                 return Err(error);
             }
-            println!("{}", self.render_capabilities(cli.json));
+            if cli.wants_json() {
+                println!("{}", render_architectures_json());
+            } else {
+                println!("{}", render_architectures_text());
+            }
+            return Ok(());
+        }
+
+        if let Some(arch) = cli.list_insns_architecture() {
+            let listing = cli
+                .validate_list_insns_request()
+                .and_then(|()| mnemonic_listing(arch));
+            let listing = match listing {
+                Ok(listing) => listing,
+                Err(error) if cli.wants_json() => {
+                    let rendered = self.render_cli_error_json(&cli, &error, "list_insns");
+                    return Err(report_json_error(rendered, &error));
+                }
+                Err(error) => return Err(error),
+            };
+            if cli.wants_json() {
+                println!("{}", render_mnemonics_json(&listing));
+            } else {
+                println!("{}", render_mnemonics_text(&listing));
+            }
+            return Ok(());
+        }
+
+        if let Some(arch) = cli.list_regs_architecture() {
+            let listing = cli
+                .validate_list_regs_request()
+                .and_then(|()| register_listing(arch));
+            let listing = match listing {
+                Ok(listing) => listing,
+                Err(error) if cli.wants_json() => {
+                    let rendered = self.render_cli_error_json(&cli, &error, "list_regs");
+                    return Err(report_json_error(rendered, &error));
+                }
+                Err(error) => return Err(error),
+            };
+            if cli.wants_json() {
+                println!("{}", render_registers_json(&listing));
+            } else {
+                println!("{}", render_registers_text(&listing));
+            }
             return Ok(());
         }
 
         // Handle version display request
         if cli.should_show_version() {
-            print_version_info();
+            if cli.wants_json() {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&VersionInfo::current())
+                        .expect("serializing version info should succeed")
+                );
+            } else {
+                print_version_info();
+            }
             return Ok(());
         }
 
+        if let Some(path) = &cli.watch {
+            return crate::watch::watch(&cli, std::path::Path::new(path));
+        }
+
         // Validate and process the command-line arguments
-        let validated_config = match cli.validate() {
+        let mut validated_config = match cli.validate_args() {
             Ok(config) => config,
-            Err(error) if cli.json => {
-                println!(
-                    "{}",
-                    self.render_cli_error_json(&cli, &error, "validate_cli")
-                );
-                return Err(CliError::reported(1));
+            Err(error) if cli.wants_json() => {
+                let rendered = self.render_cli_error_json(&cli, &error, "validate_cli");
+                return Err(report_json_error(rendered, &error));
             }
             Err(error) => return Err(error),
         };
 
+        // `--object` supplies the bytes and start address from the file.
+        let mut listings = Vec::new();
+        if let Some(path) = &cli.object
+            && let Some(arch_mode) = validated_config.arch_mode.as_deref()
+        {
+            let loaded = ArchitectureSpec::parse(arch_mode)
+                .map_err(CliError::from)
+                .and_then(|spec| {
+                    crate::elf::load_listings(
+                        Path::new(path),
+                        &spec,
+                        validated_config.address,
+                        cli.symbol.as_deref(),
+                    )
+                })
+                .and_then(|listings| check_listing_count(&cli, listings));
+            listings = match loaded {
+                Ok(listings) => listings,
+                Err(error) if cli.wants_json() => {
+                    let rendered = self.render_cli_error_json(&cli, &error, "load_object");
+                    return Err(report_json_error(rendered, &error));
+                }
+                Err(error) => return Err(error),
+            };
+            validated_config.hex_code = Some(hex::encode(&listings[0].bytes));
+            validated_config.address = Some(listings[0].address);
+        } else if let Some(code) = validated_config.hex_code.as_deref() {
+            // `address: bytes` input becomes one listing per contiguous run.
+            let runs = match parse_addressed_hex(code)
+                .and_then(|runs| runs.map_or(Ok(Vec::new()), |runs| check_run_count(&cli, runs)))
+            {
+                Ok(runs) => runs,
+                Err(error) if cli.wants_json() => {
+                    let rendered = self.render_cli_error_json(&cli, &error, "validate_cli");
+                    return Err(report_json_error(rendered, &error));
+                }
+                Err(error) => return Err(error),
+            };
+            if let Some(first) = runs.first() {
+                validated_config.hex_code = Some(hex::encode(&first.bytes));
+                validated_config.address = Some(first.address);
+            }
+            if runs.len() > 1 {
+                listings = runs
+                    .into_iter()
+                    .map(|run| ObjectListing {
+                        heading: Vec::new(),
+                        address: run.address,
+                        bytes: run.bytes,
+                        relocations: Relocations::default(),
+                    })
+                    .collect();
+            }
+        }
+
         // Create disassembly configuration
-        let disasm_config = match DisasmConfig::from_validated_config(validated_config) {
+        let disasm_config = match DisasmConfig::from_cli_args(validated_config) {
             Ok(config) => config,
-            Err(error) if cli.json => {
-                println!(
-                    "{}",
-                    self.render_cli_error_json(&cli, &error, "build_config")
-                );
-                return Err(CliError::reported(1));
+            Err(error) if cli.wants_json() => {
+                let rendered = self.render_cli_error_json(&cli, &error, "build_config");
+                return Err(report_json_error(rendered, &error));
             }
             Err(error) => return Err(error),
         };
 
+        let memory_map = match cli.memory_map.as_deref().map(MemoryMap::load) {
+            None => MemoryMap::default(),
+            Some(Ok(map)) => map,
+            Some(Err(error)) if cli.wants_json() => {
+                let rendered = self.render_cli_error_json(&cli, &error, "load_memory_map");
+                return Err(report_json_error(rendered, &error));
+            }
+            Some(Err(error)) => return Err(error),
+        };
+
+        if cli.dry_run && cli.has_disassembly_input() {
+            let ranges: Vec<_> = if listings.is_empty() {
+                vec![(disasm_config.start_address, disasm_config.hex_bytes.len())]
+            } else {
+                listings
+                    .iter()
+                    .map(|listing| (listing.address, listing.bytes.len()))
+                    .collect()
+            };
+            return self.report_dry_run(&cli, &disasm_config, &ranges);
+        }
+
         // Execute the appropriate action
-        if cli.has_disassembly_input() {
-            self.execute_disassembly(&disasm_config)
-        } else if cli.json {
-            println!(
-                "{}",
-                self.render_cli_error_json(
-                    &cli,
-                    &CliError::MissingArgument("hex_code".to_string()),
-                    "validate_cli",
-                )
-            );
-            Err(CliError::reported(1))
+        if let Some(region_size) = cli.classify
+            && cli.has_disassembly_input()
+        {
+            if region_size == 0 {
+                return Err(CliError::validation(
+                    "classify",
+                    "`--classify` region size must be at least 1",
+                ));
+            }
+            let report = crate::classify::render_classification(
+                &disasm_config,
+                region_size,
+                cli.wants_json(),
+            )?;
+            cli.output_target().emit(&report)
+        } else if cli.emulate && cli.has_disassembly_input() {
+            let trace = crate::emulate::render_trace(&disasm_config, cli.steps)?;
+            cli.output_target().emit(&trace)
+        } else if cli.has_disassembly_input() {
+            // Archive members and `--symbol` matches are listed one after another.
+            let sections = if listings.is_empty() {
+                vec![ListingSection {
+                    heading: Vec::new(),
+                    config: disasm_config,
+                    relocations: Relocations::default(),
+                }]
+            } else {
+                listings
+                    .into_iter()
+                    .map(|listing| ListingSection {
+                        heading: listing.heading,
+                        config: DisasmConfig {
+                            hex_bytes: listing.bytes,
+                            start_address: listing.address,
+                            ..disasm_config.clone()
+                        },
+                        relocations: listing.relocations,
+                    })
+                    .collect()
+            };
+            self.execute_disassembly(&sections, &memory_map, &cli)
+        } else if cli.wants_json() {
+            let error = CliError::MissingArgument("hex_code".to_string());
+            let rendered = self.render_cli_error_json(&cli, &error, "validate_cli");
+            Err(report_json_error(rendered, &error))
         } else {
             Err(CliError::MissingArgument("hex_code".to_string()))
         }
     }
 
+    /// Execute an auxiliary subcommand.
+    fn execute_command(&self, command: &Command) -> Result<()> {
+        match command {
+            Command::Serve { listen } => crate::serve::serve(listen),
+            Command::Selftest { json } => {
+                let report = crate::selftest::run_selftest();
+                if *json {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&report)
+                            .expect("serializing selftest report should succeed")
+                    );
+                } else {
+                    println!("{}", report.render_text());
+                }
+                if report.is_success() {
+                    Ok(())
+                } else {
+                    Err(CliError::reported(exit_code::FAILURE))
+                }
+            }
+            Command::Completions { shell } => {
+                print!("{}", crate::completions::render_completions(*shell));
+                Ok(())
+            }
+            Command::Man => {
+                print!("{}", crate::completions::render_man_page());
+                Ok(())
+            }
+            Command::Batch { manifest, jobs } => crate::batch::run_batch(manifest, *jobs),
+            Command::Patch {
+                arch,
+                input,
+                address,
+                replacement,
+                base,
+                output,
+            } => {
+                let summary = crate::patch::run_patch(
+                    arch,
+                    input,
+                    parse_address(base)?,
+                    parse_address(address)?,
+                    replacement,
+                    output,
+                )?;
+                println!("{summary}");
+                Ok(())
+            }
+        }
+    }
+
     /// Execute the disassembly pipeline.
-    fn execute_disassembly(&self, config: &DisasmConfig) -> Result<()> {
+    ///
+    /// Each section is disassembled on its own and listed under its heading
+    /// (the archive member or `--symbol` function it comes from). Output,
+    /// timing, and limit flags are read from `cli`.
+    fn execute_disassembly(
+        &self,
+        sections: &[ListingSection],
+        memory_map: &MemoryMap,
+        cli: &Cli,
+    ) -> Result<()> {
+        let limits = cli.resource_limits();
+        let Some(ListingSection { config, .. }) = sections.first() else {
+            return Ok(());
+        };
+
         // Validate the configuration for disassembly
         match config.validate_for_disassembly() {
             Ok(()) => {}
             Err(error) if config.display_options.json => {
-                println!(
-                    "{}",
-                    self.render_config_error_json(config, &error, "validate_disassembly_config")
-                );
-                return Err(CliError::reported(1));
+                let rendered =
+                    self.render_config_error_json(config, &error, "validate_disassembly_config");
+                return Err(report_json_error(rendered, &error));
             }
             Err(error) => return Err(error),
         }
@@ -138,23 +411,84 @@ impl CliExecutor {
         // Create engine with correct architecture and options.
         let engine = DisassemblyEngine::new(config.arch_name())
             .with_detail(config.display_options.detailed || config.display_options.real_detail)
-            .with_skip_data(config.skip_data);
+            .with_skip_data(config.on_decode_error.emits_data())
+            .with_data_directives(cli.data_directives)
+            .with_misaligned_policy(cli.misaligned)
+            .with_data_ranges(memory_map.data_ranges())
+            .with_limits(limits);
+
+        let started = Instant::now();
+        let mut listing = String::new();
+        let mut result = DisassemblyResult::new(config.start_address, config.arch_name().into());
+        for section in sections {
+            let config = &section.config;
+
+            // Perform the disassembly
+            let section_result = match engine.disassemble(config) {
+                Ok(result) => result,
+                Err(error) if config.display_options.json => {
+                    let rendered = self.render_fatal_json(config, &error);
+                    return Err(report_json_error(rendered, &CliError::disassembly(&error)));
+                }
+                Err(error) => return Err(CliError::disassembly(&error)),
+            };
 
-        // Perform the disassembly
-        let result = match engine.disassemble(config) {
-            Ok(result) => result,
-            Err(error) if config.display_options.json => {
-                println!("{}", self.render_fatal_json(config, &error));
-                return Err(CliError::reported(1));
+            // Format the results
+            if !section.heading.is_empty() && !config.display_options.json {
+                if !listing.is_empty() {
+                    listing.push('\n');
+                }
+                for line in &section.heading {
+                    listing.push_str(line);
+                    listing.push('\n');
+                }
             }
-            Err(error) => return Err(CliError::disassembly(&error)),
-        };
-
-        // Format and output the results
-        let output_config = config.output_config();
-        let formatter = DisassemblyFormatter::new(output_config);
+            if cli.hexdump {
+                listing.push_str(&crate::hexdump::render_hexdump(
+                    &config.hex_bytes,
+                    &section_result,
+                ));
+            } else {
+                let mut output_config = config.output_config();
+                memory_map.annotate(
+                    &section_result.instructions,
+                    section_result.address_width,
+                    &mut output_config.annotations,
+                );
+                listing.push_str(
+                    &DisassemblyFormatter::new(output_config)
+                        .with_relocations(section.relocations.clone())
+                        .format(&section_result),
+                );
+            }
+            result.bytes_processed += section_result.bytes_processed;
+            result.instructions.extend(section_result.instructions);
+            result.errors.extend(section_result.errors);
+            result.undecoded.extend(section_result.undecoded);
+
+            // Sections are capped together, not one by one.
+            if let Err(error) = limits
+                .check_instructions(result.instructions.len())
+                .and_then(|()| limits.check_output(listing.len()))
+                .and_then(|()| limits.check_deadline(started))
+            {
+                if config.display_options.json {
+                    let rendered = self.render_fatal_json(config, &error);
+                    return Err(report_json_error(rendered, &CliError::disassembly(&error)));
+                }
+                return Err(CliError::disassembly(&error));
+            }
+        }
+        let elapsed = started.elapsed();
 
-        formatter.print(&result);
+        match cli.output_target().emit(&listing) {
+            Ok(()) => {}
+            Err(error) if config.display_options.json => {
+                let rendered = self.render_config_error_json(config, &error, "write_output");
+                return Err(report_json_error(rendered, &error));
+            }
+            Err(error) => return Err(error),
+        }
 
         // Print summary if there were errors in skip-data mode
         if !result.is_successful() && !config.display_options.json {
@@ -164,6 +498,14 @@ impl CliExecutor {
             );
         }
 
+        if cli.time {
+            eprintln!("{}", render_timing(&result, elapsed));
+        }
+        if cli.unsupported_summary {
+            let encodings = summarize_unsupported(&result.undecoded, &engine, config.arch_name());
+            eprint!("{}", render_unsupported_summary(&encodings));
+        }
+
         Ok(())
     }
 
@@ -176,21 +518,19 @@ impl CliExecutor {
         match config.validate_for_disassembly() {
             Ok(()) => {}
             Err(error) if config.display_options.json => {
-                println!(
-                    "{}",
-                    self.render_config_error_json(config, &error, "validate_disassembly_config")
-                );
-                return Err(CliError::reported(1));
+                let rendered =
+                    self.render_config_error_json(config, &error, "validate_disassembly_config");
+                return Err(report_json_error(rendered, &error));
             }
             Err(error) => return Err(error),
         }
 
-        let engine = DisassemblyEngine::new(config.arch_name());
+        let engine = DisassemblyEngine::new(config.arch_name()).with_limits(self.limits);
         let result = match engine.disassemble(config) {
             Ok(result) => result,
             Err(error) if config.display_options.json => {
-                println!("{}", self.render_fatal_json(config, &error));
-                return Err(CliError::reported(1));
+                let rendered = self.render_fatal_json(config, &error);
+                return Err(report_json_error(rendered, &CliError::disassembly(&error)));
             }
             Err(error) => return Err(CliError::disassembly(&error)),
         };
@@ -216,6 +556,10 @@ pub fn run() -> Result<()> {
 /// Advanced execution modes for specific use cases.
 impl CliExecutor {
     /// Execute disassembly and return the result as a string instead of printing.
+    ///
+    /// With JSON output, failures are rendered as an error envelope and
+    /// returned as `Ok`; see [`Self::try_execute_to_string`] to get them as
+    /// errors instead.
     pub fn execute_to_string(&self, config: &DisasmConfig) -> Result<String> {
         match config.validate_for_disassembly() {
             Ok(()) => {}
@@ -229,26 +573,41 @@ impl CliExecutor {
             Err(error) => return Err(error),
         }
 
-        let engine = DisassemblyEngine::new(config.arch_name());
-        let result = match engine.disassemble(config) {
-            Ok(result) => result,
-            Err(error) if config.display_options.json => {
-                return Ok(self.render_fatal_json(config, &error));
-            }
-            Err(error) => return Err(CliError::disassembly(&error)),
-        };
+        match self.render_to_string(config) {
+            Ok(output) => Ok(output),
+            Err(error) if config.display_options.json => Ok(self.render_fatal_json(config, &error)),
+            Err(error) => Err(CliError::disassembly(&error)),
+        }
+    }
+
+    /// Like [`Self::execute_to_string`], but every failure is returned as an
+    /// error, whatever the output format.
+    pub fn try_execute_to_string(&self, config: &DisasmConfig) -> Result<String> {
+        config.validate_for_disassembly()?;
+        self.render_to_string(config)
+            .map_err(|error| CliError::disassembly(&error))
+    }
+
+    fn render_to_string(
+        &self,
+        config: &DisasmConfig,
+    ) -> std::result::Result<String, robustone_core::DisasmError> {
+        let engine = DisassemblyEngine::new(config.arch_name()).with_limits(self.limits);
+        let result = engine.disassemble(config)?;
 
         let output_config = config.output_config();
         let formatter = DisassemblyFormatter::new(output_config);
 
-        Ok(formatter.format(&result))
+        let output = formatter.format(&result);
+        self.limits.check_output(output.len())?;
+        Ok(output)
     }
 
     /// Execute disassembly with minimal output (mnemonics only).
     pub fn execute_minimal(&self, config: &DisasmConfig) -> Result<String> {
         config.validate_for_disassembly()?;
 
-        let engine = DisassemblyEngine::new(config.arch_name());
+        let engine = DisassemblyEngine::new(config.arch_name()).with_limits(self.limits);
         let result = engine
             .disassemble(config)
             .map_err(|error| CliError::disassembly(&error))?;
@@ -256,14 +615,42 @@ impl CliExecutor {
         let output_config = OutputConfig::minimal();
         let formatter = DisassemblyFormatter::new(output_config);
 
-        Ok(formatter.format(&result))
+        let output = formatter.format(&result);
+        self.limits
+            .check_output(output.len())
+            .map_err(|error| CliError::disassembly(&error))?;
+        Ok(output)
     }
 
-    /// Validate CLI arguments without executing disassembly.
+    /// Validate CLI arguments and input without executing disassembly.
+    ///
+    /// This is `--dry-run`: everything is checked and loaded as for a real
+    /// run, then a summary is printed instead of the listing.
     pub fn validate_only(&self) -> Result<()> {
-        let cli = Cli::try_parse_from(std::env::args_os())
+        let mut cli = Cli::try_parse_from(std::env::args_os())
             .map_err(|error| CliError::InvalidCommand(error.to_string()))?;
-        cli.validate()?;
+        cli.dry_run = true;
+        self.execute_cli(cli)
+    }
+
+    /// Finish a `--dry-run`: check the configuration the way disassembly
+    /// would, then print what would have been listed.
+    fn report_dry_run(
+        &self,
+        cli: &Cli,
+        config: &DisasmConfig,
+        ranges: &[(u64, usize)],
+    ) -> Result<()> {
+        match config.validate_for_disassembly() {
+            Ok(()) => {}
+            Err(error) if cli.wants_json() => {
+                let rendered =
+                    self.render_cli_error_json(cli, &error, "validate_disassembly_config");
+                return Err(report_json_error(rendered, &error));
+            }
+            Err(error) => return Err(error),
+        }
+        println!("{}", render_dry_run(cli, config, ranges));
         Ok(())
     }
 
@@ -272,6 +659,10 @@ impl CliExecutor {
         config: &DisasmConfig,
         error: &robustone_core::DisasmError,
     ) -> String {
+        let (address, offset) = error
+            .location()
+            .map(|location| (location.address, location.offset))
+            .unwrap_or((config.start_address, 0));
         self.render_issue_json(
             config.start_address,
             config.arch_name().to_string(),
@@ -280,9 +671,9 @@ impl CliExecutor {
                 error,
                 "decode_instruction",
                 config.arch_name(),
-                config.start_address,
-                0,
-                &config.hex_bytes,
+                address,
+                offset,
+                &config.hex_bytes[offset.min(config.hex_bytes.len())..],
             ),
         )
     }
@@ -317,7 +708,17 @@ impl CliExecutor {
                 alias_regs: cli.alias_regs,
                 real_detail: cli.real_detail,
                 unsigned_immediate: cli.unsigned_immediate,
-                json: cli.json,
+                json: cli.wants_json(),
+                format: cli.format,
+                layout: cli.column_layout(),
+                uppercase: cli.uppercase,
+                labels: cli.labels,
+                explain: cli.explain,
+                expand_compressed: cli.expand_compressed,
+                dataflow: cli.dataflow,
+                mark_hints: cli.mark_hints,
+                syntax: cli.syntax,
+                nops: cli.nops,
             }),
             DisassemblyIssue::from_cli_error(error, operation, cli.arch_mode.clone(), None),
         )
@@ -370,12 +771,182 @@ impl CliExecutor {
     }
 }
 
+/// Print a rendered JSON error envelope to stderr and keep only its exit code.
+/// One range of the input, disassembled and formatted on its own.
+struct ListingSection {
+    /// Lines printed above the range in text output.
+    heading: Vec<String>,
+    config: DisasmConfig,
+    relocations: Relocations,
+}
+
+fn report_json_error(rendered: String, error: &CliError) -> CliError {
+    eprintln!("{rendered}");
+    CliError::reported(error.exit_code())
+}
+
+/// Map a clap parse failure to a documented exit code.
+///
+/// The architecture token is validated by clap, so an unknown architecture
+/// surfaces here rather than as a [`CliError`].
+fn clap_exit_code(error: &clap::Error) -> i32 {
+    use clap::error::{ContextKind, ContextValue, ErrorKind};
+
+    let invalid_architecture = error.kind() == ErrorKind::ValueValidation
+        && matches!(
+            error.get(ContextKind::InvalidArg),
+            Some(ContextValue::String(arg)) if arg.contains("ARCH_MODE")
+        );
+    if invalid_architecture {
+        exit_code::INVALID_ARCHITECTURE
+    } else {
+        exit_code::USAGE
+    }
+}
+
+/// Detect `--json`, `--format json`, or `--format=json` before clap has parsed
+/// the arguments, so parse failures can be reported as JSON too.
+fn requests_json_output(args: &[OsString]) -> bool {
+    let mut args = args.iter().filter_map(|arg| arg.to_str());
+    while let Some(arg) = args.next() {
+        match arg {
+            "--json" | "--format=json" => return true,
+            "--format" if args.next() == Some("json") => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn is_subcommand_invocation(args: &[OsString]) -> bool {
+    guess_architecture_argument(args)
+        .is_some_and(|name| Cli::command().find_subcommand(&name).is_some())
+}
+
+/// One-line `--time` report: wall time, instruction count, and input throughput.
+/// Several object ranges can only be listed one after another, not fed to
+/// JSON output, `--emulate`, or `--classify`.
+fn check_listing_count(cli: &Cli, listings: Vec<ObjectListing>) -> Result<Vec<ObjectListing>> {
+    if listings.len() > 1 && (cli.wants_json() || cli.emulate || cli.classify.is_some()) {
+        let field = if cli.symbol.is_some() {
+            "symbol"
+        } else {
+            "object"
+        };
+        return Err(CliError::validation(
+            field,
+            format!(
+                "the input has {} ranges to list; JSON output, `--emulate`, and `--classify` take exactly one (narrow it with `--symbol`)",
+                listings.len()
+            ),
+        ));
+    }
+    Ok(listings)
+}
+
+fn check_run_count(cli: &Cli, runs: Vec<AddressedHex>) -> Result<Vec<AddressedHex>> {
+    if runs.len() > 1 && (cli.wants_json() || cli.emulate || cli.classify.is_some()) {
+        return Err(CliError::validation(
+            "hex_code",
+            format!(
+                "the input has {} address ranges with gaps between them; JSON output, `--emulate`, and `--classify` take one contiguous range",
+                runs.len()
+            ),
+        ));
+    }
+    Ok(runs)
+}
+
+/// The `--dry-run` summary: the architecture and options, the input ranges,
+/// and the selected output.
+fn render_dry_run(cli: &Cli, config: &DisasmConfig, ranges: &[(u64, usize)]) -> String {
+    let total: usize = ranges.iter().map(|(_, len)| len).sum();
+    let format = cli
+        .format
+        .to_possible_value()
+        .map_or_else(String::new, |value| value.get_name().to_string());
+    if cli.wants_json() {
+        let ranges: Vec<_> = ranges
+            .iter()
+            .map(|(address, len)| serde_json::json!({ "address": address, "bytes": len }))
+            .collect();
+        let summary = serde_json::json!({
+            "dry_run": true,
+            "architecture": config.arch_name(),
+            "options": config.arch_spec.options,
+            "bytes": total,
+            "ranges": ranges,
+            "format": "json",
+        });
+        return serde_json::to_string_pretty(&summary)
+            .expect("serializing the dry-run summary should succeed");
+    }
+
+    let mut lines = vec![
+        "Dry run: the configuration is valid; nothing was disassembled.".to_string(),
+        format!("  architecture: {}", config.arch_name()),
+    ];
+    if !config.arch_spec.options.is_empty() {
+        lines.push(format!(
+            "  options:      +{}",
+            config.arch_spec.options.join(" +")
+        ));
+    }
+    let plural = if ranges.len() == 1 { "" } else { "s" };
+    lines.push(format!(
+        "  input:        {total} bytes in {} range{plural}",
+        ranges.len()
+    ));
+    for (address, len) in ranges {
+        lines.push(format!("    0x{address:x}: {len} bytes"));
+    }
+    lines.push(format!("  output:       {format}"));
+    lines.join("\n")
+}
+
+fn render_timing(result: &DisassemblyResult, elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs_f64().max(f64::EPSILON);
+    let megabytes = result.bytes_processed as f64 / 1_000_000.0;
+    format!(
+        "Time: {:.3} ms, {} instructions, {} bytes, {:.2} MB/s",
+        elapsed.as_secs_f64() * 1000.0,
+        result.instruction_count(),
+        result.bytes_processed,
+        megabytes / seconds
+    )
+}
+
+fn guess_architecture_argument(args: &[OsString]) -> Option<String> {
+    let mut args = args.iter().skip(1).filter_map(|arg| arg.to_str());
+    while let Some(arg) = args.next() {
+        if matches!(
+            arg,
+            "--format"
+                | "--comments"
+                | "--memory-map"
+                | "-o"
+                | "--output"
+                | "--watch"
+                | "--object"
+                | "--symbol"
+        ) {
+            args.next();
+        } else if !arg.starts_with('-') {
+            return Some(arg.to_string());
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::annotations::Annotations;
     use crate::arch::ArchitectureSpec;
-    use crate::command::DisplayOptions;
+    use crate::command::{DisplayOptions, Nops, OutputFormat, Syntax};
+    use crate::config::ColumnLayout;
     use crate::config::DisasmConfig;
+    use robustone_core::OnDecodeError;
     use serde_json::Value;
 
     #[test]
@@ -390,6 +961,25 @@ mod tests {
         // Basic test that default executor works
     }
 
+    #[test]
+    fn test_render_timing_reports_count_and_throughput() {
+        let mut result = DisassemblyResult::new(0, "riscv32".to_string());
+        for _ in 0..2 {
+            result.add_instruction(robustone_core::Instruction::new(
+                0,
+                vec![0x13, 0, 0, 0],
+                "nop".to_string(),
+                String::new(),
+            ));
+        }
+        result.advance_bytes(999_992);
+
+        assert_eq!(
+            render_timing(&result, Duration::from_millis(500)),
+            "Time: 500.000 ms, 2 instructions, 1000000 bytes, 2.00 MB/s"
+        );
+    }
+
     #[test]
     fn test_execute_to_string_returns_json_for_fatal_decode_errors() {
         let executor = CliExecutor::new();
@@ -403,8 +993,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
 
         let output = executor
@@ -429,8 +1030,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
 
         let output = executor
@@ -455,8 +1067,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
 
         let error = executor
@@ -478,8 +1101,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: true,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
 
         let output = executor
@@ -529,8 +1163,19 @@ mod tests {
                 real_detail: false,
                 unsigned_immediate: false,
                 json: false,
+                format: OutputFormat::Text,
+                layout: ColumnLayout::default(),
+                uppercase: false,
+                labels: false,
+                explain: false,
+                expand_compressed: false,
+                dataflow: false,
+                mark_hints: false,
+                syntax: Syntax::Default,
+                nops: Nops::Profile,
             },
-            skip_data: false,
+            on_decode_error: OnDecodeError::Stop,
+            annotations: Annotations::default(),
         };
 
         let error = executor
@@ -578,11 +1223,3 @@ mod tests {
         assert!(output.contains("--capabilities"));
     }
 }
-
-fn guess_architecture_argument(args: &[OsString]) -> Option<String> {
-    args.iter()
-        .skip(1)
-        .filter_map(|arg| arg.to_str())
-        .find(|arg| !arg.starts_with('-'))
-        .map(str::to_string)
-}
@@ -0,0 +1,191 @@
+//! Helpers for debug stubs and emulators that render code views.
+//!
+//! A [`GdbBridge`] disassembles `N` instructions starting at an address by
+//! pulling bytes through a caller-supplied memory-read callback, and renders
+//! them in the same layout as GDB's `x/Ni` command.
+
+use crate::command::OutputFormat;
+use crate::config::OutputConfig;
+use crate::disasm::{DisassemblyEngine, DisassemblyFormatter};
+use crate::error::{CliError, Result};
+
+use robustone_core::Instruction;
+
+/// Number of bytes requested from the memory callback per instruction.
+///
+/// This covers the longest encoding of every registered architecture
+/// (x86 instructions are at most 15 bytes).
+pub const MAX_INSTRUCTION_BYTES: usize = 16;
+
+/// Disassembles instructions out of target memory for debugger front-ends.
+pub struct GdbBridge {
+    engine: DisassemblyEngine,
+    arch: String,
+    output_config: OutputConfig,
+}
+
+impl GdbBridge {
+    /// Create a bridge for `arch` (for example `riscv64`).
+    pub fn new(arch: &str) -> Result<Self> {
+        let engine = DisassemblyEngine::new(arch);
+        let output_config = OutputConfig::builder().format(OutputFormat::Gdb).build();
+
+        let bridge = Self {
+            engine,
+            arch: arch.to_string(),
+            output_config,
+        };
+        if !bridge.engine.supports_architecture(arch) {
+            return Err(CliError::Architecture(format!(
+                "no decoder is registered for '{arch}'"
+            )));
+        }
+        Ok(bridge)
+    }
+
+    /// Show raw instruction bytes between the address and the mnemonic,
+    /// like GDB's `disassemble /r`.
+    pub fn with_raw_bytes(mut self, show: bool) -> Self {
+        self.output_config.show_hex = show;
+        self
+    }
+
+    /// Disassemble up to `count` instructions starting at `address`.
+    ///
+    /// `read_memory(address, buffer)` fills `buffer` with target memory and
+    /// returns how many bytes were readable. Decoding stops early when memory
+    /// becomes unreadable. Undecodable bytes are reported as `(bad)`, as GDB
+    /// does, so the listing stays in sync.
+    pub fn disassemble<F>(
+        &self,
+        address: u64,
+        count: usize,
+        mut read_memory: F,
+    ) -> Result<Vec<Instruction>>
+    where
+        F: FnMut(u64, &mut [u8]) -> usize,
+    {
+        let mut instructions = Vec::with_capacity(count);
+        let mut current = address;
+        let mut buffer = [0u8; MAX_INSTRUCTION_BYTES];
+
+        while instructions.len() < count {
+            let available = read_memory(current, &mut buffer).min(MAX_INSTRUCTION_BYTES);
+            if available == 0 {
+                if instructions.is_empty() {
+                    return Err(CliError::Io(format!(
+                        "Cannot access memory at address 0x{current:x}"
+                    )));
+                }
+                break;
+            }
+
+            let bytes = &buffer[..available];
+            let instruction = match self.engine.disassemble_single(bytes, &self.arch, current) {
+                Ok((instruction, size)) if size > 0 => instruction,
+                _ => {
+                    let step = self.bad_step(available);
+                    Instruction::new(
+                        current,
+                        bytes[..step].to_vec(),
+                        "(bad)".to_string(),
+                        String::new(),
+                    )
+                }
+            };
+
+            current = current.wrapping_add(instruction.size as u64);
+            instructions.push(instruction);
+        }
+
+        Ok(instructions)
+    }
+
+    /// Render `x/{count}i address`, marking `pc` with `=>` when it is listed.
+    pub fn examine<F>(
+        &self,
+        address: u64,
+        count: usize,
+        pc: Option<u64>,
+        read_memory: F,
+    ) -> Result<String>
+    where
+        F: FnMut(u64, &mut [u8]) -> usize,
+    {
+        let instructions = self.disassemble(address, count, read_memory)?;
+        let formatter = DisassemblyFormatter::new(self.output_config.clone());
+        Ok(formatter.format_gdb_listing(&instructions, pc))
+    }
+
+    fn bad_step(&self, available: usize) -> usize {
+        let step = if self.arch.starts_with("riscv") { 2 } else { 1 };
+        step.min(available)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flat_memory(base: u64, bytes: &[u8]) -> impl FnMut(u64, &mut [u8]) -> usize + '_ {
+        move |address, buffer| {
+            let Some(offset) = address.checked_sub(base) else {
+                return 0;
+            };
+            let offset = offset as usize;
+            if offset >= bytes.len() {
+                return 0;
+            }
+            let len = buffer.len().min(bytes.len() - offset);
+            buffer[..len].copy_from_slice(&bytes[offset..offset + len]);
+            len
+        }
+    }
+
+    #[test]
+    fn test_examine_renders_gdb_layout_with_pc_marker() {
+        let bridge = GdbBridge::new("riscv64").unwrap();
+        let memory = [0x93, 0x00, 0x10, 0x00, 0x13, 0x01, 0x01, 0xff];
+        let output = bridge
+            .examine(
+                0x8000_0000,
+                2,
+                Some(0x8000_0004),
+                flat_memory(0x8000_0000, &memory),
+            )
+            .unwrap();
+
+        assert_eq!(
+            output,
+            "   0x80000000:\tli\tra,1\n=> 0x80000004:\taddi\tsp,sp,-0x10\n"
+        );
+    }
+
+    #[test]
+    fn test_disassemble_stops_at_unreadable_memory() {
+        let bridge = GdbBridge::new("riscv32").unwrap();
+        let memory = [0x93, 0x00, 0x10, 0x00];
+        let instructions = bridge
+            .disassemble(0x1000, 10, flat_memory(0x1000, &memory))
+            .unwrap();
+        assert_eq!(instructions.len(), 1);
+
+        let error = bridge
+            .disassemble(0x2000, 1, flat_memory(0x1000, &memory))
+            .expect_err("unreadable start address should fail");
+        assert!(error.to_string().contains("0x2000"));
+    }
+
+    #[test]
+    fn test_undecodable_bytes_render_as_bad() {
+        let bridge = GdbBridge::new("riscv32").unwrap().with_raw_bytes(true);
+        let memory = [0xff, 0xff, 0xff, 0xff];
+        let output = bridge.examine(0, 1, None, flat_memory(0, &memory)).unwrap();
+        assert_eq!(output, "   0x0:\tff ff\t(bad)\n");
+    }
+
+    #[test]
+    fn test_new_rejects_unknown_architecture() {
+        assert!(GdbBridge::new("mips").is_err());
+    }
+}
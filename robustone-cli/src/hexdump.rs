@@ -0,0 +1,139 @@
+//! Side-by-side hexdump view (`--hexdump`).
+//!
+//! Prints the input as classic 16-byte `hexdump -C` rows and lists, to the
+//! right of each row, the instructions that start inside it. Combined with
+//! `--skip-data`, bytes listed as `.byte` data can be read in the ASCII
+//! column:
+//!
+//! ```text
+//! 00001000  13 05 50 00 73 00 00 00  ff ff ff ff              |..P.s.......    |  1000: li a0, 5
+//!                                                                                 1004: ecall
+//!                                                                                 1008: .byte 0xff, 0xff
+//!                                                                                 100a: .half 0xffff
+//! ```
+
+use crate::disasm::DisassemblyResult;
+
+/// Bytes per hexdump row.
+const ROW_BYTES: usize = 16;
+
+/// Render `bytes` (loaded at `result.start_address`) next to the listing.
+pub fn render_hexdump(bytes: &[u8], result: &DisassemblyResult) -> String {
+    let mut output = String::new();
+    let mut instructions = result.instructions.iter().peekable();
+
+    for (row_index, row) in bytes.chunks(ROW_BYTES).enumerate() {
+        let row_offset = row_index * ROW_BYTES;
+        let row_address = result
+            .address_width
+            .offset(result.start_address, row_offset as i64);
+        let panel = format_panel(row_address, row);
+
+        let mut listing = Vec::new();
+        while let Some(instruction) = instructions.peek() {
+            let offset = instruction.address.wrapping_sub(result.start_address) as usize;
+            if offset >= row_offset + ROW_BYTES {
+                break;
+            }
+            let text = format!("{} {}", instruction.mnemonic, instruction.operands);
+            listing.push(format!("{:x}: {}", instruction.address, text.trim_end()));
+            instructions.next();
+        }
+
+        if listing.is_empty() {
+            output.push_str(&panel);
+            output.push('\n');
+        }
+        for (line_index, text) in listing.iter().enumerate() {
+            if line_index == 0 {
+                output.push_str(&format!("{panel}  {text}\n"));
+            } else {
+                output.push_str(&format!("{:width$}  {text}\n", "", width = panel.len()));
+            }
+        }
+    }
+
+    for error in &result.errors {
+        output.push_str(&format!("; Error: {}\n", error.display_message()));
+    }
+    for warning in &result.warnings {
+        output.push_str(&format!("; Warning: {}\n", warning.display_message()));
+    }
+    output
+}
+
+/// One `hexdump -C` row: address, two groups of eight bytes, and the
+/// printable-ASCII column. Short rows are padded so the listing stays aligned.
+fn format_panel(address: u64, row: &[u8]) -> String {
+    let mut hex = String::new();
+    for index in 0..ROW_BYTES {
+        if index == ROW_BYTES / 2 {
+            hex.push(' ');
+        }
+        match row.get(index) {
+            Some(byte) => hex.push_str(&format!("{byte:02x} ")),
+            None => hex.push_str("   "),
+        }
+    }
+    let ascii: String = row
+        .iter()
+        .map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    format!("{address:08x}  {hex} |{ascii:<ROW_BYTES$}|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DisasmConfig;
+    use crate::disasm::DisassemblyEngine;
+
+    fn hexdump(arch: &str, hex: &str) -> String {
+        let config = DisasmConfig::builder()
+            .arch(arch)
+            .hex(hex)
+            .address(0x1000)
+            .skip_data(true)
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new(arch).disassemble(&config).unwrap();
+        render_hexdump(&config.hex_bytes, &result)
+    }
+
+    #[test]
+    fn test_rows_list_the_instructions_they_contain() {
+        // li a0, 5; ecall; then four bytes of data
+        let output = hexdump("riscv32", "13055000 73000000 ffffffff");
+        let indent = " ".repeat(80);
+        assert_eq!(
+            output,
+            format!(
+                "00001000  13 05 50 00 73 00 00 00  ff ff ff ff              \
+                 |..P.s.......    |  1000: li a0, 5\n\
+                 {indent}1004: ecall\n\
+                 {indent}1008: .byte 0xff, 0xff\n\
+                 {indent}100a: .half 0xffff\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_instructions_are_listed_on_the_row_they_start_in() {
+        // c.nop, then four nops, the last straddling the row boundary
+        let output = hexdump("riscv32", "0100 13000000 13000000 13000000 13000000 ffff");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[4].ends_with("100e: addi zero, zero, 0"));
+        assert_eq!(
+            lines[5],
+            "00001010  00 00 ff ff                                       \
+             |....            |  1012: .half 0xffff"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Synthetic local labels for branch targets (`--labels`).
+//!
+//! When a PC-relative branch or jump lands on an instruction inside the same
+//! listing, the target gets a `.L<n>` label and the branch operand refers to
+//! that label instead of a raw offset, so the listing can be fed back to an
+//! assembler.
+
+use robustone_core::ir::{ArchitectureId, DecodedInstruction, Operand};
+use robustone_core::{AddressWidth, Instruction};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Labels assigned to the branch targets of one listing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Labels {
+    /// Label name by target address.
+    by_address: BTreeMap<u64, String>,
+    /// Target address by branch instruction address.
+    targets: BTreeMap<u64, u64>,
+}
+
+impl Labels {
+    /// Assign labels to every in-range branch target, numbered in address order.
+    pub fn collect(instructions: &[Instruction]) -> Self {
+        let starts = instructions
+            .iter()
+            .map(|instruction| instruction.address)
+            .collect::<BTreeSet<_>>();
+
+        let targets = instructions
+            .iter()
+            .filter_map(|instruction| {
+                let target = branch_target(instruction)?;
+                starts
+                    .contains(&target)
+                    .then_some((instruction.address, target))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut addresses = targets.values().copied().collect::<Vec<_>>();
+        addresses.sort_unstable();
+        addresses.dedup();
+        let by_address = addresses
+            .into_iter()
+            .enumerate()
+            .map(|(index, address)| (address, format!(".L{}", index + 1)))
+            .collect();
+
+        Self {
+            by_address,
+            targets,
+        }
+    }
+
+    /// Check whether no labels were assigned.
+    pub fn is_empty(&self) -> bool {
+        self.by_address.is_empty()
+    }
+
+    /// The label defined at `address`, if any.
+    pub fn label_at(&self, address: u64) -> Option<&str> {
+        self.by_address.get(&address).map(String::as_str)
+    }
+
+    /// The label the branch at `address` refers to, if its target is labelled.
+    pub fn target_label(&self, address: u64) -> Option<&str> {
+        self.targets
+            .get(&address)
+            .and_then(|target| self.label_at(*target))
+    }
+
+    /// Replace the target operand (always the last one) with its label.
+    pub fn substitute_operands(&self, address: u64, operands: &str) -> String {
+        let Some(label) = self.target_label(address) else {
+            return operands.to_string();
+        };
+        match operands.rsplit_once(", ") {
+            Some((leading, _)) => format!("{leading}, {label}"),
+            None => label.to_string(),
+        }
+    }
+}
+
+/// Absolute target of a PC-relative branch or jump, if `instruction` is one.
+///
+/// The target wraps within the address space of the decoding mode, so a
+/// backward branch near address zero on riscv32 lands near `0xffffffff`.
+pub fn branch_target(instruction: &Instruction) -> Option<u64> {
+    let decoded = instruction.decoded.as_ref()?;
+    if !is_pc_relative_transfer(decoded) {
+        return None;
+    }
+    let width = AddressWidth::for_architecture(&decoded.mode);
+    match decoded.operands.last()? {
+        Operand::Immediate { value } => Some(width.offset(instruction.address, *value)),
+        _ => None,
+    }
+}
+
+/// Whether the last operand of `decoded` is an offset from its own address.
+///
+/// Only RISC-V encodes this in the shared IR today; other architectures opt in
+/// once their decoders expose relative targets the same way.
+fn is_pc_relative_transfer(decoded: &DecodedInstruction) -> bool {
+    match decoded.architecture {
+        ArchitectureId::Riscv => {
+            decoded.groups.iter().any(|group| group == "branch")
+                || matches!(decoded.mnemonic.as_str(), "jal" | "c.j" | "c.jal")
+        }
+        ArchitectureId::Arm | ArchitectureId::X86 | ArchitectureId::LoongArch => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DisasmConfig;
+    use crate::disasm::DisassemblyEngine;
+
+    fn listing(hex: &str) -> Vec<Instruction> {
+        let config = DisasmConfig::builder()
+            .arch("riscv32")
+            .hex(hex)
+            .address(0x1000)
+            .build()
+            .unwrap();
+        DisassemblyEngine::new("riscv32")
+            .disassemble(&config)
+            .unwrap()
+            .instructions
+    }
+
+    #[test]
+    fn test_in_range_targets_get_labels_in_address_order() {
+        // 0x1000: jal 0xc -> 0x100c; 0x1004: nop; 0x1008: nop; 0x100c: beqz zero, -0xc -> 0x1000
+        let instructions = listing("ef00c0001300000013000000e30a00fe");
+        let labels = Labels::collect(&instructions);
+
+        assert_eq!(labels.label_at(0x1000), Some(".L1"));
+        assert_eq!(labels.label_at(0x100c), Some(".L2"));
+        assert_eq!(labels.target_label(0x1000), Some(".L2"));
+        assert_eq!(
+            labels.substitute_operands(0x100c, "zero, zero, -0xc"),
+            "zero, zero, .L1"
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_and_indirect_jumps_are_not_labelled() {
+        // jal 0x100 (outside the listing); jalr zero, 0(ra)
+        let labels = Labels::collect(&listing("ef000010 67800000"));
+        assert!(labels.is_empty());
+    }
+}
@@ -4,23 +4,53 @@
 //! This library provides a clean, modern API for disassembling machine code
 //! across multiple architectures with extensive configuration options.
 
+pub mod annotations;
 pub mod arch;
+pub mod batch;
 pub mod capabilities;
+pub mod classify;
 pub mod command;
+pub mod completions;
+pub mod compressed;
 pub mod config;
 pub mod disasm;
+pub mod elf;
+pub mod emulate;
 pub mod error;
 pub mod executor;
+pub mod gdb_bridge;
+pub mod hexdump;
+pub mod labels;
+pub mod limits;
+pub mod logging;
+pub mod memory_map;
+pub mod output;
+pub mod patch;
+pub mod selftest;
+pub mod serve;
+pub mod unsupported;
 pub mod utils;
 pub mod version_info;
+pub mod watch;
 
 // Re-export modern API surface for convenient use
+pub use annotations::Annotations;
 pub use arch::{Architecture, ArchitectureSpec};
-pub use command::{Cli, DisplayOptions, ValidatedConfig};
-pub use config::{DisasmConfig, OutputConfig};
-pub use disasm::{DisassemblyEngine, DisassemblyFormatter, DisassemblyResult};
-pub use error::{CliError, ParseError, Result, ValidationError};
+#[allow(deprecated)]
+pub use command::ValidatedConfig;
+pub use command::{Cli, Command, DisplayOptions, OutputFormat};
+pub use config::{
+    AddressDisplay, ByteGrouping, ColumnAlignment, ColumnLayout, DisasmConfig, DisasmConfigBuilder,
+    OutputConfig, OutputConfigBuilder,
+};
+pub use disasm::{DisassemblyEngine, DisassemblyFormatter, DisassemblyResult, HookAction};
+pub use error::{
+    CliError, InputSpan, ParseError, Result, ValidationError, ValidationIssue, exit_code,
+};
 pub use executor::CliExecutor;
+pub use gdb_bridge::GdbBridge;
+pub use limits::ResourceLimits;
+pub use version_info::VersionInfo;
 
 /// Main library interface for programmatic use.
 pub struct RobustoneCli {
@@ -66,18 +96,11 @@ impl Default for RobustoneCli {
 pub fn disassemble_hex(hex_code: &str, architecture: &str, address: Option<u64>) -> Result<String> {
     let cli = RobustoneCli::new();
 
-    let config = DisasmConfig::from_validated_config(ValidatedConfig {
-        arch_mode: Some(architecture.to_string()),
-        hex_code: Some(hex_code.to_string()),
-        address,
-        detailed: false,
-        alias_regs: false,
-        real_detail: false,
-        skip_data: false,
-        unsigned_immediate: false,
-        json: false,
-        version: false,
-    })?;
+    let config = DisasmConfig::builder()
+        .arch(architecture)
+        .hex(hex_code)
+        .address(address.unwrap_or(0))
+        .build()?;
 
     cli.execute_minimal(&config)
 }
@@ -122,19 +145,12 @@ mod smoke_tests {
     #[test]
     fn test_default_cli_wrapper() {
         let cli = RobustoneCli::default();
-        let config = DisasmConfig::from_validated_config(ValidatedConfig {
-            arch_mode: Some("riscv32".to_string()),
-            hex_code: Some("93001000".to_string()),
-            address: Some(0x1000),
-            detailed: false,
-            alias_regs: false,
-            real_detail: false,
-            skip_data: false,
-            unsigned_immediate: false,
-            json: false,
-            version: false,
-        })
-        .expect("configuration should be valid");
+        let config = DisasmConfig::builder()
+            .arch("riscv32")
+            .hex("93001000")
+            .address(0x1000)
+            .build()
+            .expect("configuration should be valid");
 
         let output = cli
             .execute_minimal(&config)
@@ -0,0 +1,110 @@
+//! Resource limits for disassembly runs (`--max-instructions`,
+//! `--max-output`, `--timeout`).
+//!
+//! `robustone serve` and CI jobs disassemble input they do not control.
+//! [`ResourceLimits`] caps how many instructions a run decodes, how much
+//! listing text it renders, and how long it may take; a run that reaches a
+//! cap fails with a `limit_exceeded` error (exit code
+//! [`exit_code::LIMIT_EXCEEDED`](crate::error::exit_code::LIMIT_EXCEEDED))
+//! instead of tying up the host.
+
+use robustone_core::DisasmError;
+use std::time::{Duration, Instant};
+
+/// Caps enforced while disassembling and formatting; `None` is unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResourceLimits {
+    /// Most instructions (data items included) one run may produce.
+    pub max_instructions: Option<usize>,
+    /// Largest rendered listing, in bytes.
+    pub max_output_bytes: Option<usize>,
+    /// Longest wall-clock time one run may take.
+    pub timeout: Option<Duration>,
+}
+
+impl ResourceLimits {
+    /// No limits, the default for the command line.
+    pub const fn unlimited() -> Self {
+        Self {
+            max_instructions: None,
+            max_output_bytes: None,
+            timeout: None,
+        }
+    }
+
+    /// Limits `robustone serve` applies to every request.
+    ///
+    /// They sit well above what a [`MAX_BODY_BYTES`](crate::serve::MAX_BODY_BYTES)
+    /// request needs, so only pathological requests hit them.
+    pub const fn service() -> Self {
+        Self {
+            max_instructions: Some(1 << 20),
+            max_output_bytes: Some(256 << 20),
+            timeout: Some(Duration::from_secs(10)),
+        }
+    }
+
+    /// Whether no limit is set.
+    pub fn is_unlimited(&self) -> bool {
+        *self == Self::unlimited()
+    }
+
+    /// Fail once a run has produced more than the allowed instructions.
+    pub fn check_instructions(&self, count: usize) -> Result<(), DisasmError> {
+        match self.max_instructions {
+            Some(max) if count > max => Err(DisasmError::LimitExceeded(format!(
+                "more than {max} instructions (--max-instructions)"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fail once the rendered listing grows past the allowed size.
+    pub fn check_output(&self, len: usize) -> Result<(), DisasmError> {
+        match self.max_output_bytes {
+            Some(max) if len > max => Err(DisasmError::LimitExceeded(format!(
+                "output exceeds {max} bytes (--max-output)"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fail once a run started at `started` has taken longer than allowed.
+    pub fn check_deadline(&self, started: Instant) -> Result<(), DisasmError> {
+        match self.timeout {
+            Some(timeout) if started.elapsed() > timeout => Err(DisasmError::LimitExceeded(
+                format!("run took longer than {timeout:?} (--timeout)"),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits_trip_past_their_cap() {
+        let limits = ResourceLimits {
+            max_instructions: Some(2),
+            max_output_bytes: Some(10),
+            timeout: Some(Duration::ZERO),
+        };
+        assert!(limits.check_instructions(2).is_ok());
+        let error = limits.check_instructions(3).unwrap_err();
+        assert_eq!(error.stable_kind(), "limit_exceeded");
+        assert!(limits.check_output(10).is_ok());
+        assert!(limits.check_output(11).is_err());
+        assert!(
+            limits
+                .check_deadline(Instant::now() - Duration::from_millis(1))
+                .is_err()
+        );
+
+        let unlimited = ResourceLimits::unlimited();
+        assert!(unlimited.is_unlimited());
+        assert!(unlimited.check_instructions(usize::MAX).is_ok());
+        assert!(unlimited.check_deadline(Instant::now()).is_ok());
+    }
+}
@@ -0,0 +1,48 @@
+//! Diagnostic logging for `--verbose`.
+//!
+//! Decode events are emitted through `tracing` by `robustone-core` and the CLI
+//! pipeline. Nothing is printed unless the user asks for it; each `--verbose` raises
+//! the level by one step and events are written to stderr so listings on
+//! stdout stay machine-readable.
+
+use tracing::level_filters::LevelFilter;
+
+/// Map the `--verbose` repeat count to the maximum level that is reported.
+pub fn level_for_verbosity(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::OFF,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Install the stderr subscriber for the requested verbosity.
+///
+/// Does nothing at verbosity zero or when a subscriber is already installed,
+/// which keeps repeated executor runs (tests, embedding) harmless.
+pub fn init(verbosity: u8) {
+    let level = level_for_verbosity(verbosity);
+    if level == LevelFilter::OFF {
+        return;
+    }
+
+    let _ = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_target(false)
+        .with_ansi(false)
+        .with_writer(std::io::stderr)
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_maps_to_levels() {
+        assert_eq!(level_for_verbosity(0), LevelFilter::OFF);
+        assert_eq!(level_for_verbosity(1), LevelFilter::DEBUG);
+        assert_eq!(level_for_verbosity(2), LevelFilter::TRACE);
+        assert_eq!(level_for_verbosity(5), LevelFilter::TRACE);
+    }
+}
@@ -0,0 +1,659 @@
+//! Memory maps for SoC images (`--memory-map FILE`).
+//!
+//! A full flash or ROM image interleaves code with MMIO windows, reserved
+//! firmware areas, and padding. Given a memory map, the listing shows MMIO
+//! and reserved ranges as data directives instead of decoding them, and
+//! RISC-V loads and stores whose address resolves into an MMIO range (after
+//! a `lui`/`auipc`/`addi` sequence) get a `; uart0+0x5` comment.
+//!
+//! The map is either a flattened device tree blob or a text file with one
+//! region per line:
+//!
+//! ```text
+//! # start      size        kind      name
+//! 0x10000000   0x100       mmio      uart0
+//! 0x80000000   0x80000     reserved  opensbi
+//! 0x80080000   0x7f80000   ram
+//! ```
+//!
+//! `kind` is `ram`, `rom`, `mmio`, or `reserved`; the name is optional. In a
+//! device tree, `memory` nodes are RAM, children of `/reserved-memory` and
+//! `/memreserve/` entries are reserved, and every other node with a `reg`
+//! property is MMIO named after the node (`serial@10000000`). Bus `ranges`
+//! are assumed to be identity mappings. Device tree sources must be
+//! compiled first with `dtc -I dts -O dtb`.
+
+use crate::annotations::Annotations;
+use crate::error::{CliError, Result};
+use crate::utils::parse_address;
+
+use robustone_core::ir::{ArchitectureId, DecodedInstruction, Operand};
+use robustone_core::{AddressWidth, Instruction};
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::Path;
+
+const FDT_MAGIC: u32 = 0xd00d_feed;
+const FDT_BEGIN_NODE: u32 = 1;
+const FDT_END_NODE: u32 = 2;
+const FDT_PROP: u32 = 3;
+const FDT_NOP: u32 = 4;
+const FDT_END: u32 = 9;
+
+/// What a region of the address space holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionKind {
+    Ram,
+    Rom,
+    Mmio,
+    Reserved,
+}
+
+impl RegionKind {
+    fn parse(text: &str) -> Option<Self> {
+        match text {
+            "ram" => Some(Self::Ram),
+            "rom" => Some(Self::Rom),
+            "mmio" => Some(Self::Mmio),
+            "reserved" => Some(Self::Reserved),
+            _ => None,
+        }
+    }
+
+    /// Whether bytes in the region are listed as data rather than decoded.
+    pub fn is_data(self) -> bool {
+        matches!(self, Self::Mmio | Self::Reserved)
+    }
+}
+
+/// One region of a memory map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub start: u64,
+    pub size: u64,
+    pub kind: RegionKind,
+    pub name: Option<String>,
+}
+
+impl Region {
+    /// The addresses the region covers.
+    pub fn range(&self) -> Range<u64> {
+        self.start..self.start.saturating_add(self.size)
+    }
+}
+
+/// Regions loaded from a `--memory-map` file, sorted by start address.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryMap {
+    regions: Vec<Region>,
+}
+
+impl MemoryMap {
+    /// Build a map from `regions`; empty regions are dropped.
+    pub fn new(mut regions: Vec<Region>) -> Self {
+        regions.retain(|region| region.size > 0);
+        regions.sort_by_key(|region| region.start);
+        Self { regions }
+    }
+
+    /// The regions in address order.
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// Load a device tree blob or a text memory map.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|error| CliError::Io(format!("{}: {error}", path.display())))?;
+        let located = |error: CliError| match error {
+            CliError::Parse { context, message } => CliError::Parse {
+                context,
+                message: format!("{}: {message}", path.display()),
+            },
+            error => error,
+        };
+        if bytes.starts_with(&FDT_MAGIC.to_be_bytes()) {
+            return Self::parse_dtb(&bytes).map_err(located);
+        }
+        let text = String::from_utf8(bytes).map_err(|_| {
+            located(CliError::parse(
+                "memory_map",
+                "neither a device tree blob nor a text memory map",
+            ))
+        })?;
+        Self::parse(&text).map_err(located)
+    }
+
+    /// Parse the text format: `<start> <size> <kind> [name]` per line.
+    pub fn parse(text: &str) -> Result<Self> {
+        if text.trim_start().starts_with("/dts-v1/") {
+            return Err(CliError::parse(
+                "memory_map",
+                "device tree sources are not supported; compile with `dtc -I dts -O dtb` first",
+            ));
+        }
+        let mut regions = Vec::new();
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: String| {
+                CliError::parse("memory_map", format!("line {}: {message}", index + 1))
+            };
+            let mut fields = line.split_whitespace();
+            let (Some(start), Some(size), Some(kind)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(error("expected `<start> <size> <kind> [name]`".to_string()));
+            };
+            let name = fields.collect::<Vec<_>>().join(" ");
+            regions.push(Region {
+                start: parse_address(start).map_err(|e| error(e.to_string()))?,
+                size: parse_address(size).map_err(|e| error(e.to_string()))?,
+                kind: RegionKind::parse(kind).ok_or_else(|| {
+                    error(format!(
+                        "unknown region kind `{kind}`; expected ram, rom, mmio, or reserved"
+                    ))
+                })?,
+                name: (!name.is_empty()).then_some(name),
+            });
+        }
+        Ok(Self::new(regions))
+    }
+
+    /// Parse a flattened device tree blob.
+    pub fn parse_dtb(bytes: &[u8]) -> Result<Self> {
+        Fdt::new(bytes)
+            .and_then(|fdt| fdt.regions())
+            .map(Self::new)
+            .map_err(|message| CliError::parse("memory_map", format!("device tree: {message}")))
+    }
+
+    /// Ranges whose bytes are listed as data.
+    pub fn data_ranges(&self) -> Vec<Range<u64>> {
+        self.regions
+            .iter()
+            .filter(|region| region.kind.is_data())
+            .map(Region::range)
+            .collect()
+    }
+
+    /// `name+0xoffset` for an address inside a named MMIO region.
+    pub fn peripheral_label(&self, address: u64) -> Option<String> {
+        let region = self
+            .regions
+            .iter()
+            .filter(|region| region.kind == RegionKind::Mmio && region.range().contains(&address))
+            .min_by_key(|region| region.size)?;
+        let name = region.name.as_deref()?;
+        Some(match address - region.start {
+            0 => name.to_string(),
+            offset => format!("{name}+0x{offset:x}"),
+        })
+    }
+
+    /// Comment every RISC-V load or store whose address resolves into a
+    /// named MMIO region.
+    ///
+    /// Register values are tracked through `lui`, `auipc`, and `addi`
+    /// within straight-line code; any branch or jump forgets them.
+    pub fn annotate(
+        &self,
+        instructions: &[Instruction],
+        width: AddressWidth,
+        annotations: &mut Annotations,
+    ) {
+        if self.regions.is_empty() {
+            return;
+        }
+        let mut values = BTreeMap::new();
+        for instruction in instructions {
+            let Some(decoded) = &instruction.decoded else {
+                continue;
+            };
+            if decoded.architecture != ArchitectureId::Riscv {
+                continue;
+            }
+            for operand in &decoded.operands {
+                if let Operand::Memory {
+                    base: Some(base),
+                    displacement,
+                    ..
+                } = operand
+                    && let Some(value) = register_value(&values, base.id)
+                    && let Some(label) = self.peripheral_label(width.offset(value, *displacement))
+                {
+                    annotations.insert(instruction.address, label);
+                }
+            }
+            track_constants(decoded, width, &mut values);
+        }
+    }
+}
+
+/// Value of register `id`, with `x0` always zero.
+fn register_value(values: &BTreeMap<u32, u64>, id: u32) -> Option<u64> {
+    if id == 0 {
+        Some(0)
+    } else {
+        values.get(&id).copied()
+    }
+}
+
+/// Update the known register values after `decoded`.
+fn track_constants(
+    decoded: &DecodedInstruction,
+    width: AddressWidth,
+    values: &mut BTreeMap<u32, u64>,
+) {
+    if decoded
+        .groups
+        .iter()
+        .any(|group| group == "branch" || group == "control_flow")
+    {
+        values.clear();
+        return;
+    }
+
+    let upper = |imm: i64| i64::from((imm << 12) as i32);
+    let word = |value: u64| i64::from(value as i32) as u64;
+    let value = match (decoded.mnemonic.as_str(), decoded.operands.as_slice()) {
+        ("lui" | "c.lui", [Operand::Register { .. }, Operand::Immediate { value }]) => {
+            Some(width.wrap(upper(*value) as u64))
+        }
+        ("auipc", [Operand::Register { .. }, Operand::Immediate { value }]) => {
+            Some(width.offset(decoded.address, upper(*value)))
+        }
+        ("c.li", [Operand::Register { .. }, Operand::Immediate { value }]) => {
+            Some(width.wrap(*value as u64))
+        }
+        (
+            "addi" | "addiw",
+            [
+                Operand::Register { .. },
+                Operand::Register { register },
+                Operand::Immediate { value },
+            ],
+        ) => register_value(values, register.id).map(|base| width.offset(base, *value)),
+        ("c.addi" | "c.addiw", [Operand::Register { register }, Operand::Immediate { value }]) => {
+            register_value(values, register.id).map(|base| width.offset(base, *value))
+        }
+        _ => None,
+    }
+    .map(|value| {
+        if decoded.mnemonic.ends_with("addiw") {
+            word(value)
+        } else {
+            value
+        }
+    });
+
+    for register in decoded
+        .registers_written
+        .iter()
+        .chain(&decoded.implicit_registers_written)
+    {
+        values.remove(&register.id);
+    }
+    if let (Some(value), Some(Operand::Register { register })) = (value, decoded.operands.first()) {
+        values.insert(register.id, value);
+    }
+}
+
+/// Cursor over a flattened device tree.
+struct Fdt<'a> {
+    structure: &'a [u8],
+    strings: &'a [u8],
+    reservations: Vec<(u64, u64)>,
+}
+
+impl<'a> Fdt<'a> {
+    fn new(bytes: &'a [u8]) -> std::result::Result<Self, String> {
+        let header = |index: usize| be32(bytes, index * 4).ok_or("truncated header");
+        if header(0)? != FDT_MAGIC {
+            return Err("bad magic".to_string());
+        }
+        let total = (header(1)? as usize).min(bytes.len());
+        let section = |offset: u32, size: u32| {
+            bytes[..total]
+                .get(offset as usize..offset as usize + size as usize)
+                .ok_or_else(|| "section outside the blob".to_string())
+        };
+        let structure = section(header(2)?, header(9)?)?;
+        let strings = section(header(3)?, header(8)?)?;
+
+        let mut reservations = Vec::new();
+        let mut offset = header(4)? as usize;
+        loop {
+            let (Some(address), Some(size)) = (be64(bytes, offset), be64(bytes, offset + 8)) else {
+                return Err("truncated memory reservation block".to_string());
+            };
+            if address == 0 && size == 0 {
+                break;
+            }
+            reservations.push((address, size));
+            offset += 16;
+        }
+        Ok(Self {
+            structure,
+            strings,
+            reservations,
+        })
+    }
+
+    fn regions(&self) -> std::result::Result<Vec<Region>, String> {
+        struct Node {
+            name: String,
+            address_cells: u32,
+            size_cells: u32,
+            reg: Option<Vec<u8>>,
+            memory: bool,
+        }
+
+        let mut regions: Vec<Region> = self
+            .reservations
+            .iter()
+            .map(|&(start, size)| Region {
+                start,
+                size,
+                kind: RegionKind::Reserved,
+                name: Some("/memreserve/".to_string()),
+            })
+            .collect();
+        let mut stack: Vec<Node> = Vec::new();
+        let mut offset = 0;
+        loop {
+            let token = be32(self.structure, offset).ok_or("unterminated structure block")?;
+            offset += 4;
+            match token {
+                FDT_BEGIN_NODE => {
+                    let name = c_string(self.structure, offset).ok_or("unterminated node name")?;
+                    offset = align4(offset + name.len() + 1);
+                    stack.push(Node {
+                        memory: name == "memory" || name.starts_with("memory@"),
+                        name: name.to_string(),
+                        address_cells: 2,
+                        size_cells: 1,
+                        reg: None,
+                    });
+                }
+                FDT_PROP => {
+                    let len = be32(self.structure, offset).ok_or("truncated property")? as usize;
+                    let name_offset =
+                        be32(self.structure, offset + 4).ok_or("truncated property")? as usize;
+                    let value = self
+                        .structure
+                        .get(offset + 8..offset + 8 + len)
+                        .ok_or("truncated property")?;
+                    offset = align4(offset + 8 + len);
+                    let name = c_string(self.strings, name_offset).ok_or("bad property name")?;
+                    let node = stack.last_mut().ok_or("property outside a node")?;
+                    match name {
+                        "#address-cells" => node.address_cells = be32(value, 0).unwrap_or(2),
+                        "#size-cells" => node.size_cells = be32(value, 0).unwrap_or(1),
+                        "reg" => node.reg = Some(value.to_vec()),
+                        "device_type" => node.memory |= value == b"memory\0",
+                        _ => {}
+                    }
+                }
+                FDT_END_NODE => {
+                    let node = stack.pop().ok_or("unbalanced node end")?;
+                    let (Some(reg), Some(parent)) = (node.reg, stack.last()) else {
+                        continue;
+                    };
+                    let kind = if node.memory {
+                        RegionKind::Ram
+                    } else if parent.name == "reserved-memory" {
+                        RegionKind::Reserved
+                    } else {
+                        RegionKind::Mmio
+                    };
+                    let (address_cells, size_cells) = (parent.address_cells, parent.size_cells);
+                    let entry = (address_cells + size_cells) as usize * 4;
+                    if size_cells == 0 || entry == 0 {
+                        continue;
+                    }
+                    for chunk in reg.chunks_exact(entry) {
+                        let (address, size) = chunk.split_at(address_cells as usize * 4);
+                        regions.push(Region {
+                            start: cells(address),
+                            size: cells(size),
+                            kind,
+                            name: Some(node.name.clone()),
+                        });
+                    }
+                }
+                FDT_NOP => {}
+                FDT_END => return Ok(regions),
+                other => return Err(format!("unknown token 0x{other:x}")),
+            }
+        }
+    }
+}
+
+fn be32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let chunk = bytes.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes(chunk.try_into().ok()?))
+}
+
+fn be64(bytes: &[u8], offset: usize) -> Option<u64> {
+    let chunk = bytes.get(offset..offset + 8)?;
+    Some(u64::from_be_bytes(chunk.try_into().ok()?))
+}
+
+/// Big-endian cells folded into one value; only the low two cells count.
+fn cells(bytes: &[u8]) -> u64 {
+    bytes.chunks_exact(4).fold(0, |acc, cell| {
+        (acc << 32) | u64::from(be32(cell, 0).unwrap_or(0))
+    })
+}
+
+fn c_string(bytes: &[u8], offset: usize) -> Option<&str> {
+    let rest = bytes.get(offset..)?;
+    let end = rest.iter().position(|&byte| byte == 0)?;
+    std::str::from_utf8(&rest[..end]).ok()
+}
+
+fn align4(offset: usize) -> usize {
+    offset.next_multiple_of(4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DisasmConfig;
+    use crate::disasm::DisassemblyEngine;
+
+    const MAP: &str = "# start size kind name\n\
+                       0x10000000 0x100 mmio uart0\n\
+                       0x0c000000 0x4000000 mmio plic\n\
+                       0x8000000e 0x6 reserved\n";
+
+    #[test]
+    fn test_text_map_is_parsed_in_address_order() {
+        let map = MemoryMap::parse(MAP).unwrap();
+        let starts: Vec<u64> = map.regions().iter().map(|region| region.start).collect();
+        assert_eq!(starts, [0x0c00_0000, 0x1000_0000, 0x8000_000e]);
+        assert_eq!(map.data_ranges()[1], 0x1000_0000..0x1000_0100);
+        assert_eq!(map.peripheral_label(0x1000_0000).as_deref(), Some("uart0"));
+        assert_eq!(
+            map.peripheral_label(0x1000_0005).as_deref(),
+            Some("uart0+0x5")
+        );
+        assert_eq!(map.peripheral_label(0x1000_0100), None);
+        // Reserved regions are data but carry no peripheral label.
+        assert_eq!(map.peripheral_label(0x8000_000e), None);
+    }
+
+    #[test]
+    fn test_malformed_maps_are_rejected() {
+        let error = MemoryMap::parse("0x1000 0x10 flash boot\n").unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("line 1: unknown region kind `flash`")
+        );
+        assert!(MemoryMap::parse("0x1000 0x10\n").is_err());
+        let error = MemoryMap::parse("/dts-v1/;\n/ { };\n").unwrap_err();
+        assert!(error.to_string().contains("dtc -I dts -O dtb"));
+    }
+
+    #[test]
+    fn test_device_tree_nodes_become_regions() {
+        let mut dtb = FdtWriter::default();
+        dtb.begin("");
+        dtb.prop_u32("#address-cells", &[2]);
+        dtb.prop_u32("#size-cells", &[2]);
+        dtb.begin("memory@80000000");
+        dtb.prop("device_type", b"memory\0");
+        dtb.prop_u32("reg", &[0, 0x8000_0000, 0, 0x800_0000]);
+        dtb.end();
+        dtb.begin("reserved-memory");
+        dtb.prop_u32("#address-cells", &[2]);
+        dtb.prop_u32("#size-cells", &[2]);
+        dtb.begin("mmode_resv0@80000000");
+        dtb.prop_u32("reg", &[0, 0x8000_0000, 0, 0x4_0000]);
+        dtb.end();
+        dtb.end();
+        dtb.begin("soc");
+        dtb.prop_u32("#address-cells", &[2]);
+        dtb.prop_u32("#size-cells", &[2]);
+        dtb.begin("serial@10000000");
+        dtb.prop_u32("reg", &[0, 0x1000_0000, 0, 0x100]);
+        dtb.end();
+        dtb.end();
+        dtb.begin("cpus");
+        dtb.prop_u32("#address-cells", &[1]);
+        dtb.prop_u32("#size-cells", &[0]);
+        dtb.begin("cpu@0");
+        dtb.prop_u32("reg", &[0]);
+        dtb.end();
+        dtb.end();
+        dtb.end();
+
+        let map = MemoryMap::parse_dtb(&dtb.finish(&[(0x8004_0000, 0x1000)])).unwrap();
+        let regions: Vec<(u64, u64, RegionKind, &str)> = map
+            .regions()
+            .iter()
+            .map(|region| {
+                let name = region.name.as_deref().unwrap_or_default();
+                (region.start, region.size, region.kind, name)
+            })
+            .collect();
+        assert_eq!(
+            regions,
+            [
+                (0x1000_0000, 0x100, RegionKind::Mmio, "serial@10000000"),
+                (0x8000_0000, 0x800_0000, RegionKind::Ram, "memory@80000000"),
+                (
+                    0x8000_0000,
+                    0x4_0000,
+                    RegionKind::Reserved,
+                    "mmode_resv0@80000000"
+                ),
+                (0x8004_0000, 0x1000, RegionKind::Reserved, "/memreserve/"),
+            ]
+        );
+        assert!(MemoryMap::parse_dtb(&FDT_MAGIC.to_be_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_listing_marks_data_and_labels_peripheral_accesses() {
+        let map = MemoryMap::parse(MAP).unwrap();
+        // lui a0, 0x10000; lbu a1, 5(a0); sb a1, 0(a0); ret; 6 reserved
+        // bytes; lui a0, 0x10000; j +4; sb a1, 0(a0)
+        let config = DisasmConfig::builder()
+            .arch("riscv64")
+            .hex("37050010 83455500 2300b500 8280 ffffffff0000 37050010 11a0 2300b500")
+            .address(0x8000_0000)
+            .build()
+            .unwrap();
+        let result = DisassemblyEngine::new("riscv64")
+            .with_data_ranges(map.data_ranges())
+            .disassemble(&config)
+            .unwrap();
+        let data = &result.instructions[4];
+        assert_eq!(
+            (data.address, data.mnemonic.as_str()),
+            (0x8000_000e, ".byte")
+        );
+        assert_eq!(data.size, 6);
+
+        let mut annotations = Annotations::new();
+        map.annotate(&result.instructions, result.address_width, &mut annotations);
+        assert_eq!(annotations.get(0x8000_0004), ["uart0+0x5"]);
+        assert_eq!(annotations.get(0x8000_0008), ["uart0"]);
+        // The jump forgets `a0`, so the store after it is not labelled.
+        assert!(annotations.get(0x8000_001a).is_empty());
+    }
+
+    /// Minimal flattened device tree writer for the tests.
+    #[derive(Default)]
+    struct FdtWriter {
+        structure: Vec<u8>,
+        strings: Vec<u8>,
+    }
+
+    impl FdtWriter {
+        fn begin(&mut self, name: &str) {
+            self.structure.extend(FDT_BEGIN_NODE.to_be_bytes());
+            self.structure.extend(name.as_bytes());
+            self.structure.push(0);
+            self.pad();
+        }
+
+        fn end(&mut self) {
+            self.structure.extend(FDT_END_NODE.to_be_bytes());
+        }
+
+        fn prop(&mut self, name: &str, value: &[u8]) {
+            let name_offset = self.strings.len() as u32;
+            self.strings.extend(name.as_bytes());
+            self.strings.push(0);
+            self.structure.extend(FDT_PROP.to_be_bytes());
+            self.structure.extend((value.len() as u32).to_be_bytes());
+            self.structure.extend(name_offset.to_be_bytes());
+            self.structure.extend(value);
+            self.pad();
+        }
+
+        fn prop_u32(&mut self, name: &str, cells: &[u32]) {
+            let value: Vec<u8> = cells.iter().flat_map(|cell| cell.to_be_bytes()).collect();
+            self.prop(name, &value);
+        }
+
+        fn pad(&mut self) {
+            self.structure.resize(align4(self.structure.len()), 0);
+        }
+
+        fn finish(mut self, reservations: &[(u64, u64)]) -> Vec<u8> {
+            self.structure.extend(FDT_END.to_be_bytes());
+            let rsvmap = 40;
+            let structure = rsvmap + (reservations.len() + 1) * 16;
+            let strings = structure + self.structure.len();
+            let total = strings + self.strings.len();
+            let header = [
+                FDT_MAGIC,
+                total as u32,
+                structure as u32,
+                strings as u32,
+                rsvmap as u32,
+                17,
+                16,
+                0,
+                self.strings.len() as u32,
+                self.structure.len() as u32,
+            ];
+            let mut blob: Vec<u8> = header.iter().flat_map(|word| word.to_be_bytes()).collect();
+            for (address, size) in reservations.iter().chain([&(0, 0)]) {
+                blob.extend(address.to_be_bytes());
+                blob.extend(size.to_be_bytes());
+            }
+            blob.extend(self.structure);
+            blob.extend(self.strings);
+            blob
+        }
+    }
+}
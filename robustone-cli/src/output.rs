@@ -0,0 +1,134 @@
+//! Destination handling for rendered listings (`-o FILE` and `$PAGER`).
+//!
+//! The executor renders a listing to a string first and hands it to
+//! [`OutputTarget::emit`], so text and JSON output are redirected the same way.
+
+use crate::error::{CliError, Result};
+
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// Pager used when `$PAGER` is unset.
+const DEFAULT_PAGER: &str = "less -R";
+
+/// Where a rendered listing is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputTarget {
+    /// Standard output, optionally piped through a pager when it is a terminal.
+    Stdout { pager: bool },
+    /// A file that is created or truncated.
+    File(PathBuf),
+}
+
+impl OutputTarget {
+    /// Write `text` to this target.
+    ///
+    /// A closed stdout (for example `robustone ... | head`) is not an error.
+    pub fn emit(&self, text: &str) -> Result<()> {
+        match self {
+            OutputTarget::File(path) => std::fs::write(path, text).map_err(|error| {
+                CliError::Io(format!("cannot write `{}`: {error}", path.display()))
+            }),
+            OutputTarget::Stdout { pager } => {
+                if *pager && should_page(text) && page(text) {
+                    return Ok(());
+                }
+                write_stdout(text)
+            }
+        }
+    }
+}
+
+/// Decide whether `text` is tall enough to page on the current terminal.
+fn should_page(text: &str) -> bool {
+    if !io::stdout().is_terminal() {
+        return false;
+    }
+    terminal_size::terminal_size()
+        .is_some_and(|(_, terminal_size::Height(rows))| exceeds_height(text, rows))
+}
+
+/// Check whether `text` needs more rows than the terminal has, keeping one
+/// row free for the shell prompt.
+fn exceeds_height(text: &str, rows: u16) -> bool {
+    text.lines().count() >= usize::from(rows)
+}
+
+/// The pager command line: `$PAGER` when set and non-empty, otherwise `less -R`.
+fn pager_command() -> String {
+    std::env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string())
+}
+
+/// Pipe `text` through the pager. Returns `false` if the pager could not be
+/// started so the caller can fall back to plain stdout.
+fn page(text: &str) -> bool {
+    let command = pager_command();
+    let spawned = if cfg!(windows) {
+        Command::new("cmd")
+            .args(["/C", &command])
+            .stdin(Stdio::piped())
+            .spawn()
+    } else {
+        Command::new("sh")
+            .args(["-c", &command])
+            .stdin(Stdio::piped())
+            .spawn()
+    };
+    let Ok(mut child) = spawned else {
+        return false;
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager before reading everything.
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    let _ = child.wait();
+    true
+}
+
+fn write_stdout(text: &str) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    match stdout
+        .write_all(text.as_bytes())
+        .and_then(|()| stdout.flush())
+    {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_height_leaves_room_for_prompt() {
+        assert!(!exceeds_height("a\nb\n", 3));
+        assert!(exceeds_height("a\nb\nc\n", 3));
+        assert!(!exceeds_height("", 1));
+    }
+
+    #[test]
+    fn test_file_target_writes_listing() {
+        let path =
+            std::env::temp_dir().join(format!("robustone-output-{}.txt", std::process::id()));
+        OutputTarget::File(path.clone())
+            .emit("0  nop\n")
+            .expect("writing to a temp file should succeed");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "0  nop\n");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_file_target_reports_unwritable_path() {
+        let error = OutputTarget::File(PathBuf::from("/nonexistent-dir/out.txt"))
+            .emit("")
+            .expect_err("missing directory should fail");
+        assert!(matches!(error, CliError::Io(_)));
+    }
+}
@@ -0,0 +1,269 @@
+//! Patch subcommand (`robustone patch`).
+//!
+//! Assembles a replacement for the instruction at an address of a raw
+//! binary and writes the patched image:
+//!
+//! ```text
+//! $ robustone patch riscv32 fw.bin 80000010 "li a0, 7" --base 80000000 -o fw-patched.bin
+//! 0x80000010: jal 0xf0 -> li a0, 7
+//! ```
+//!
+//! The replacement is assembled with [`robustone_riscv::assemble`], so only
+//! RISC-V images can be patched. It may not be longer than the instruction
+//! it replaces; a shorter one is padded with `nop` (and `c.nop` for a
+//! 2-byte remainder when the profile has C). The padded bytes must decode
+//! under the image's profile, which rejects, say, `c.` forms without C.
+
+use crate::arch::ArchitectureSpec;
+use crate::config::DisasmConfig;
+use crate::disasm::DisassemblyEngine;
+use crate::error::{CliError, Result};
+
+use robustone_core::{Instruction, is_data_directive};
+use robustone_riscv::assemble::assemble;
+use robustone_riscv::decoder::Xlen;
+use std::path::Path;
+
+/// Longest RISC-V instruction the decoder handles.
+const MAX_INSTRUCTION_BYTES: usize = 4;
+
+/// A replacement ready to be written.
+#[derive(Debug)]
+pub struct Patch {
+    /// Offset of the patched instruction in the image.
+    pub offset: usize,
+    /// Replacement bytes, padding included.
+    pub bytes: Vec<u8>,
+    /// Text of the original instruction.
+    pub original: String,
+    /// Text of each instruction of the padded replacement.
+    pub replacement: Vec<String>,
+}
+
+/// Plan the replacement of the instruction at `address` in `image` (loaded
+/// at `base`) with the assembly `replacement`.
+pub fn plan_patch(
+    arch: &str,
+    image: &[u8],
+    base: u64,
+    address: u64,
+    replacement: &str,
+) -> Result<Patch> {
+    let spec = ArchitectureSpec::parse(arch)?;
+    let xlen = match spec.arch.name() {
+        "riscv32" => Xlen::X32,
+        "riscv64" => Xlen::X64,
+        name => {
+            return Err(CliError::validation(
+                "arch_mode",
+                format!("`patch` assembles RISC-V only; there is no assembler for {name}"),
+            ));
+        }
+    };
+
+    let offset = usize::try_from(address.wrapping_sub(base))
+        .ok()
+        .filter(|offset| *offset < image.len())
+        .ok_or_else(|| {
+            CliError::validation(
+                "address",
+                format!(
+                    "0x{address:x} is outside the image (0x{base:x}..0x{:x})",
+                    base.wrapping_add(image.len() as u64)
+                ),
+            )
+        })?;
+
+    let window = &image[offset..image.len().min(offset + MAX_INSTRUCTION_BYTES)];
+    let original = decode(arch, window, address, true)?
+        .into_iter()
+        .next()
+        .filter(|instruction| !is_data_directive(&instruction.mnemonic))
+        .ok_or_else(|| {
+            CliError::validation(
+                "address",
+                format!("no instruction decodes at 0x{address:x}"),
+            )
+        })?;
+
+    let mut bytes = assemble(replacement, xlen)
+        .map_err(|error| CliError::validation("replacement", error.to_string()))?;
+    if bytes.is_empty() {
+        return Err(CliError::validation(
+            "replacement",
+            "Empty replacement provided",
+        ));
+    }
+    if bytes.len() > original.size {
+        return Err(CliError::validation(
+            "replacement",
+            format!(
+                "replacement is {} bytes but `{}` at 0x{address:x} is {} bytes",
+                bytes.len(),
+                text(&original),
+                original.size
+            ),
+        ));
+    }
+    bytes.extend(padding(&spec, original.size - bytes.len())?);
+
+    let replacement = decode(arch, &bytes, address, false)?
+        .iter()
+        .map(text)
+        .collect();
+    Ok(Patch {
+        offset,
+        bytes,
+        original: text(&original),
+        replacement,
+    })
+}
+
+/// Apply the patch to `input` and write the result to `output`, returning
+/// the one-line summary printed by the subcommand.
+pub fn run_patch(
+    arch: &str,
+    input: &Path,
+    base: u64,
+    address: u64,
+    replacement: &str,
+    output: &Path,
+) -> Result<String> {
+    let mut image = std::fs::read(input).map_err(|error| {
+        CliError::validation("input", format!("cannot read {}: {error}", input.display()))
+    })?;
+    let patch = plan_patch(arch, &image, base, address, replacement)?;
+    image[patch.offset..patch.offset + patch.bytes.len()].copy_from_slice(&patch.bytes);
+    std::fs::write(output, &image).map_err(|error| {
+        CliError::validation(
+            "output",
+            format!("cannot write {}: {error}", output.display()),
+        )
+    })?;
+    Ok(format!(
+        "0x{address:x}: {} -> {}",
+        patch.original,
+        patch.replacement.join("; ")
+    ))
+}
+
+fn decode(arch: &str, bytes: &[u8], address: u64, skip_data: bool) -> Result<Vec<Instruction>> {
+    let config = DisasmConfig::builder()
+        .arch(arch)
+        .bytes(bytes.to_vec())
+        .address(address)
+        .skip_data(skip_data)
+        .build()?;
+    let result = DisassemblyEngine::new(config.arch_name())
+        .disassemble(&config)
+        .map_err(|error| CliError::validation("replacement", error.to_string()))?;
+    Ok(result.instructions)
+}
+
+/// `nop` encodings filling `len` bytes, largest first.
+fn padding(spec: &ArchitectureSpec, len: usize) -> Result<Vec<u8>> {
+    let compressed = spec
+        .riscv_profile()
+        .is_some_and(|profile| profile.enabled_extensions.contains(&"C"));
+    let nops: &[&[u8]] = if compressed {
+        &[&[0x13, 0x00, 0x00, 0x00], &[0x01, 0x00]]
+    } else {
+        &[&[0x13, 0x00, 0x00, 0x00]]
+    };
+
+    let mut bytes = Vec::with_capacity(len);
+    for nop in nops {
+        while len - bytes.len() >= nop.len() {
+            bytes.extend_from_slice(nop);
+        }
+    }
+    if bytes.len() != len {
+        return Err(CliError::validation(
+            "replacement",
+            format!(
+                "cannot pad {len} bytes with {} no-ops; supply a replacement of the original size",
+                spec.arch.name()
+            ),
+        ));
+    }
+    Ok(bytes)
+}
+
+fn text(instruction: &Instruction) -> String {
+    instruction.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // li a0, 5; jal ra, +0x100; ret
+    const IMAGE: &str = "13055000 ef000010 8280";
+
+    fn image() -> Vec<u8> {
+        crate::utils::parse_hex_to_bytes(IMAGE).unwrap()
+    }
+
+    #[test]
+    fn test_replacement_of_the_same_size_is_used_as_is() {
+        let patch = plan_patch("riscv32", &image(), 0x8000_0000, 0x8000_0004, "li a0, 7").unwrap();
+        assert_eq!(patch.offset, 4);
+        assert_eq!(patch.bytes, [0x13, 0x05, 0x70, 0x00]);
+        assert_eq!(patch.original, "jal 0x100");
+        assert_eq!(patch.replacement, ["li a0, 7"]);
+    }
+
+    #[test]
+    fn test_short_replacements_are_padded_with_nops() {
+        // A compressed replacement for a 4-byte instruction.
+        let patch = plan_patch("riscv32", &image(), 0, 4, "c.li a0, 7").unwrap();
+        assert_eq!(patch.bytes, [0x1d, 0x45, 0x01, 0x00]);
+        assert_eq!(patch.replacement, ["li a0, 7", "addi zero, zero, 0"]);
+
+        // Without C the replacement does not decode.
+        assert!(plan_patch("riscv32+m", &image(), 0, 4, "c.li a0, 7").is_err());
+
+        // `li` of a 32-bit value takes two instructions.
+        let patch = plan_patch("riscv32", &image(), 0, 0, "li a0, 0x12345678");
+        assert!(
+            patch
+                .unwrap_err()
+                .to_string()
+                .contains("replacement is 8 bytes")
+        );
+    }
+
+    #[test]
+    fn test_invalid_replacements_are_rejected() {
+        // Longer than the 2-byte `ret`.
+        let error = plan_patch("riscv32", &image(), 0, 8, "li a0, 7").unwrap_err();
+        assert!(error.to_string().contains("replacement is 4 bytes"));
+        // Not an instruction.
+        let error = plan_patch("riscv32", &image(), 0, 4, "li a0").unwrap_err();
+        assert!(error.to_string().contains("cannot assemble `li a0`"));
+        // Outside the image.
+        assert!(plan_patch("riscv32", &image(), 0, 10, "c.nop").is_err());
+        // No assembler for the architecture.
+        let error = plan_patch("x32", &[0xb8, 1, 0, 0, 0], 0, 0, "nop").unwrap_err();
+        assert!(error.to_string().contains("RISC-V only"));
+    }
+
+    #[test]
+    fn test_run_patch_writes_the_patched_image() {
+        let dir = std::env::temp_dir().join(format!("robustone-patch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("in.bin");
+        let output = dir.join("out.bin");
+        std::fs::write(&input, image()).unwrap();
+
+        let summary = run_patch("riscv32", &input, 0x1000, 0x1000, "c.nop", &output).unwrap();
+        assert_eq!(
+            summary,
+            "0x1000: li a0, 5 -> addi zero, zero, 0; addi zero, zero, 0"
+        );
+        let patched = std::fs::read(&output).unwrap();
+        assert_eq!(patched[..4], [0x01, 0x00, 0x01, 0x00]);
+        assert_eq!(patched[4..], image()[4..]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
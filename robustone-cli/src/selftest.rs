@@ -0,0 +1,232 @@
+//! Built-in self-test (`robustone selftest`).
+//!
+//! The self-test runs a small embedded corpus of known encodings through the
+//! registered architecture handlers and compares the Capstone-style text with
+//! the expected listing. Packagers can use it to check that a build (including
+//! unusual feature combinations) decodes correctly on the target platform
+//! without any external test data.
+
+use crate::disasm::DisassemblyEngine;
+
+use robustone_core::ir::TextRenderProfile;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// One embedded encoding and the listing it must produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SelfTestVector {
+    pub arch: &'static str,
+    pub hex: &'static str,
+    pub expected: &'static str,
+}
+
+const fn vector(arch: &'static str, hex: &'static str, expected: &'static str) -> SelfTestVector {
+    SelfTestVector {
+        arch,
+        hex,
+        expected,
+    }
+}
+
+/// The embedded corpus. Each vector holds a single instruction in memory order.
+pub const VECTORS: &[SelfTestVector] = &[
+    vector("riscv32", "93001000", "li ra, 1"),
+    vector("riscv32", "130101ff", "addi sp, sp, -0x10"),
+    vector("riscv32", "73000000", "ecall"),
+    vector("riscv32", "8280", "jr ra"),
+    vector("riscv64", "97000000", "auipc ra, 0"),
+    vector("riscv64", "3b85a500", "addw a0, a1, a0"),
+    vector("riscv64", "b3025202", "mul t0, tp, t0"),
+    vector("riscv64", "2f25c510", "lr.w a0, (a0)"),
+    vector("riscv64", "07b50500", "fld fa0, 0(a1)"),
+    vector("riscv64", "d3071000", "fadd.s fa5, ft0, ft1, rne"),
+    vector("riscv64", "0865", "ld a0, 8(a0)"),
+    vector("aarch64", "1f2003d5", "nop"),
+    vector("aarch64", "c0035fd6", "ret"),
+    vector("x32", "90", "nop"),
+    vector("x32", "55", "push ebp"),
+    vector("x32", "c3", "ret"),
+    vector("loongarch64", "47150000", "clz.w $a3, $a6"),
+    vector("loongarch64", "a0080000", "movgr2scr $scr0, $a1"),
+];
+
+/// Result of running one vector.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "status")]
+pub enum SelfTestStatus {
+    /// The listing matched.
+    Pass,
+    /// The handler produced a different listing or failed to decode.
+    Fail { actual: String },
+    /// No handler for the vector's architecture is compiled into this build.
+    Skip,
+}
+
+/// One vector paired with its outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SelfTestOutcome {
+    #[serde(flatten)]
+    pub vector: SelfTestVector,
+    pub handler: Option<&'static str>,
+    #[serde(flatten)]
+    pub status: SelfTestStatus,
+}
+
+/// Aggregate report printed by `robustone selftest`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SelfTestReport {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// Registered handlers that no vector exercised.
+    pub uncovered_handlers: Vec<&'static str>,
+    pub outcomes: Vec<SelfTestOutcome>,
+}
+
+impl SelfTestReport {
+    /// Check whether every runnable vector passed.
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+
+    /// Render a human-readable pass/fail report.
+    pub fn render_text(&self) -> String {
+        let mut output = String::new();
+        for outcome in &self.outcomes {
+            let vector = &outcome.vector;
+            let label = match &outcome.status {
+                SelfTestStatus::Pass => "PASS",
+                SelfTestStatus::Fail { .. } => "FAIL",
+                SelfTestStatus::Skip => "SKIP",
+            };
+            write!(
+                output,
+                "{label}  {:<12} {:<10} {}",
+                vector.arch, vector.hex, vector.expected
+            )
+            .expect("writing selftest row should succeed");
+            if let SelfTestStatus::Fail { actual } = &outcome.status {
+                write!(output, "  (got: {actual})")
+                    .expect("writing selftest failure should succeed");
+            }
+            output.push('\n');
+        }
+
+        for handler in &self.uncovered_handlers {
+            writeln!(output, "WARN  handler `{handler}` has no embedded vectors")
+                .expect("writing selftest warning should succeed");
+        }
+
+        write!(
+            output,
+            "\n{} passed, {} failed, {} skipped",
+            self.passed, self.failed, self.skipped
+        )
+        .expect("writing selftest summary should succeed");
+        output
+    }
+}
+
+/// Run the embedded corpus against every registered handler.
+pub fn run_selftest() -> SelfTestReport {
+    run_vectors(VECTORS)
+}
+
+/// Run an arbitrary corpus; used by [`run_selftest`] and tests.
+pub fn run_vectors(vectors: &[SelfTestVector]) -> SelfTestReport {
+    let engine = DisassemblyEngine::new_engine();
+    let outcomes = vectors
+        .iter()
+        .map(|vector| {
+            let handler = engine.handler_name(vector.arch);
+            let status = if handler.is_none() {
+                SelfTestStatus::Skip
+            } else {
+                run_vector(&engine, vector)
+            };
+            SelfTestOutcome {
+                vector: *vector,
+                handler,
+                status,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let uncovered_handlers = engine
+        .registered_handlers()
+        .into_iter()
+        .filter(|name| {
+            !outcomes
+                .iter()
+                .any(|outcome| outcome.handler == Some(*name))
+        })
+        .collect();
+
+    let count = |predicate: fn(&SelfTestStatus) -> bool| {
+        outcomes
+            .iter()
+            .filter(|outcome| predicate(&outcome.status))
+            .count()
+    };
+
+    SelfTestReport {
+        passed: count(|status| matches!(status, SelfTestStatus::Pass)),
+        failed: count(|status| matches!(status, SelfTestStatus::Fail { .. })),
+        skipped: count(|status| matches!(status, SelfTestStatus::Skip)),
+        uncovered_handlers,
+        outcomes,
+    }
+}
+
+fn run_vector(engine: &DisassemblyEngine, vector: &SelfTestVector) -> SelfTestStatus {
+    let bytes = match hex::decode(vector.hex) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            return SelfTestStatus::Fail {
+                actual: format!("invalid vector hex: {error}"),
+            };
+        }
+    };
+
+    let actual = match engine.disassemble_single(&bytes, vector.arch, 0) {
+        Ok((instruction, size)) if size == bytes.len() => {
+            let (mnemonic, operands) = instruction.rendered_text_parts(TextRenderProfile::Capstone);
+            format!("{mnemonic} {operands}").trim_end().to_string()
+        }
+        Ok((_, size)) => format!("consumed {size} of {} bytes", bytes.len()),
+        Err(error) => error.to_string(),
+    };
+
+    if actual == vector.expected {
+        SelfTestStatus::Pass
+    } else {
+        SelfTestStatus::Fail { actual }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_corpus_passes_and_covers_every_handler() {
+        let report = run_selftest();
+        assert!(report.is_success(), "{}", report.render_text());
+        assert_eq!(report.passed, VECTORS.len());
+        assert!(report.uncovered_handlers.is_empty());
+    }
+
+    #[test]
+    fn test_mismatches_and_unknown_architectures_are_reported() {
+        let report = run_vectors(&[
+            vector("riscv32", "93001000", "addi ra, zero, 1"),
+            vector("mips", "00000000", "nop"),
+        ]);
+
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.skipped, 1);
+        assert!(!report.is_success());
+        assert!(report.render_text().contains("(got: li ra, 1)"));
+        assert!(report.render_text().contains("1 failed, 1 skipped"));
+    }
+}
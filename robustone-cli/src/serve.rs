@@ -0,0 +1,496 @@
+//! HTTP service mode (`robustone serve`).
+//!
+//! The service exposes a single JSON endpoint so CI systems and web UIs can
+//! call the engine without spawning one CLI process per request:
+//!
+//! ```text
+//! POST /disasm
+//! {"arch": "riscv32", "hex": "93001000", "addr": "0x1000"}
+//! ```
+//!
+//! Instead of `hex`, callers may send base64-encoded bytes in `b64`. `addr`
+//! accepts either a JSON number or a hexadecimal string. The response body
+//! is the same envelope produced by `robustone --json`. `GET /health`
+//! returns `{"status":"ok"}` for liveness probes.
+//!
+//! The server speaks a minimal subset of HTTP/1.1 (one request per
+//! connection, `Content-Length` bodies) and handles each connection on its
+//! own thread with a dedicated executor, up to [`MAX_CONNECTIONS`] at once;
+//! connections beyond that get a `503` right away. Every request runs under
+//! [`ResourceLimits::service`], whose timeout also bounds each socket write
+//! and is the deadline for receiving the whole request; a request that hits
+//! a limit gets an envelope whose error has the `limit_exceeded` kind. The
+//! request line and headers may take at most [`MAX_HEADER_BYTES`] and
+//! [`MAX_HEADERS`] lines, beyond which the client gets a `431`.
+
+use crate::config::DisasmConfig;
+use crate::error::{CliError, Result};
+use crate::executor::CliExecutor;
+use crate::limits::ResourceLimits;
+use crate::utils::parse_address;
+
+use base64::Engine as _;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Largest request body the service accepts, in bytes.
+pub const MAX_BODY_BYTES: usize = 1 << 20;
+
+/// Largest request line plus headers the service accepts, in bytes.
+pub const MAX_HEADER_BYTES: usize = 8 << 10;
+
+/// Most header lines the service accepts in one request.
+pub const MAX_HEADERS: usize = 64;
+
+/// Most connections the service handles at once.
+pub const MAX_CONNECTIONS: usize = 64;
+
+/// Request payload accepted by `POST /disasm`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisasmRequest {
+    pub arch: String,
+    #[serde(default)]
+    pub hex: Option<String>,
+    #[serde(default)]
+    pub b64: Option<String>,
+    #[serde(default)]
+    pub addr: Option<Value>,
+    #[serde(default)]
+    pub detail: bool,
+    #[serde(default)]
+    pub skip_data: bool,
+}
+
+/// A fully rendered HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    fn json(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    fn error(status: u16, kind: &str, message: impl Into<String>) -> Self {
+        Self::json(
+            status,
+            json!({ "error": { "kind": kind, "message": message.into() } }).to_string(),
+        )
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+/// Bind `listen` and serve requests until the process is terminated.
+pub fn serve(listen: &str) -> Result<()> {
+    let listener = TcpListener::bind(listen)
+        .map_err(|error| CliError::Io(format!("failed to bind {listen}: {error}")))?;
+    eprintln!("robustone: serving on http://{}", listener.local_addr()?);
+    serve_listener(listener)
+}
+
+/// Serve requests from an already-bound listener.
+pub fn serve_listener(listener: TcpListener) -> Result<()> {
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let mut stream = stream?;
+        let Some(slot) = ConnectionSlot::acquire(&active, MAX_CONNECTIONS) else {
+            let busy = HttpResponse::error(
+                503,
+                "busy",
+                format!("already serving {MAX_CONNECTIONS} connections"),
+            );
+            if let Err(error) =
+                set_socket_timeouts(&stream).and_then(|()| write_response(&mut stream, &busy))
+            {
+                eprintln!("robustone: connection error: {error}");
+            }
+            continue;
+        };
+        std::thread::spawn(move || {
+            let _slot = slot;
+            if let Err(error) = handle_connection(stream) {
+                eprintln!("robustone: connection error: {error}");
+            }
+        });
+    }
+    Ok(())
+}
+
+/// One of the [`MAX_CONNECTIONS`] connections being served, released on drop.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn acquire(active: &Arc<AtomicUsize>, max: usize) -> Option<Self> {
+        active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                (count < max).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(Arc::clone(active)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Bound every read and write on `stream` by the service timeout, so a
+/// client that stops sending or reading cannot hold a connection forever.
+fn set_socket_timeouts(stream: &TcpStream) -> Result<()> {
+    let timeout = ResourceLimits::service().timeout;
+    stream.set_read_timeout(timeout)?;
+    stream.set_write_timeout(timeout)?;
+    Ok(())
+}
+
+/// Read a single request from `stream`, route it, and write the response.
+pub fn handle_connection(mut stream: TcpStream) -> Result<()> {
+    set_socket_timeouts(&stream)?;
+    let deadline = ResourceLimits::service()
+        .timeout
+        .map(|timeout| Instant::now() + timeout);
+    let response = match read_request(DeadlineReader::new(&stream, deadline)) {
+        Ok((method, path, body)) => route(&method, &path, &body),
+        Err(response) => response,
+    };
+    write_response(&mut stream, &response)
+}
+
+fn write_response(stream: &mut TcpStream, response: &HttpResponse) -> Result<()> {
+    let payload = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    );
+    stream.write_all(payload.as_bytes())?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Dispatch a parsed request to the matching endpoint.
+pub fn route(method: &str, path: &str, body: &str) -> HttpResponse {
+    match (method, path) {
+        ("POST", "/disasm") => handle_disasm(body),
+        ("GET", "/health") => HttpResponse::json(200, json!({ "status": "ok" }).to_string()),
+        (_, "/disasm") | (_, "/health") => HttpResponse::error(
+            405,
+            "method_not_allowed",
+            format!("{method} is not supported for {path}"),
+        ),
+        _ => HttpResponse::error(404, "not_found", format!("no endpoint at {path}")),
+    }
+}
+
+/// Handle a `POST /disasm` body and render the JSON disassembly envelope.
+pub fn handle_disasm(body: &str) -> HttpResponse {
+    let request: DisasmRequest = match serde_json::from_str(body) {
+        Ok(request) => request,
+        Err(error) => return HttpResponse::error(400, "invalid_request", error.to_string()),
+    };
+
+    match disassemble_request(request) {
+        Ok(output) => HttpResponse::json(200, output),
+        Err(error) => HttpResponse::error(400, "invalid_request", error.to_string()),
+    }
+}
+
+fn disassemble_request(request: DisasmRequest) -> Result<String> {
+    let hex_code = match (request.hex, request.b64) {
+        (Some(hex), None) => hex,
+        (None, Some(b64)) => {
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(b64.trim())
+                .map_err(|error| CliError::validation("b64", error.to_string()))?;
+            hex::encode(bytes)
+        }
+        (Some(_), Some(_)) => {
+            return Err(CliError::validation(
+                "hex",
+                "provide either `hex` or `b64`, not both",
+            ));
+        }
+        (None, None) => return Err(CliError::MissingArgument("hex".to_string())),
+    };
+
+    let address = match request.addr {
+        None | Some(Value::Null) => None,
+        Some(Value::Number(number)) => Some(number.as_u64().ok_or_else(|| {
+            CliError::validation("addr", "address must be a non-negative integer")
+        })?),
+        Some(Value::String(text)) => Some(parse_address(&text)?),
+        Some(_) => {
+            return Err(CliError::validation(
+                "addr",
+                "address must be a number or a hexadecimal string",
+            ));
+        }
+    };
+
+    let config = DisasmConfig::builder()
+        .arch(request.arch)
+        .hex(hex_code)
+        .address(address.unwrap_or(0))
+        .detailed(request.detail)
+        .skip_data(request.skip_data)
+        .json(true)
+        .build()?;
+
+    CliExecutor::new()
+        .with_limits(ResourceLimits::service())
+        .execute_to_string(&config)
+}
+
+/// Reads from a socket until a fixed deadline, however slowly the peer
+/// trickles bytes in.
+struct DeadlineReader<'a> {
+    stream: &'a TcpStream,
+    deadline: Option<Instant>,
+}
+
+impl<'a> DeadlineReader<'a> {
+    fn new(stream: &'a TcpStream, deadline: Option<Instant>) -> Self {
+        Self { stream, deadline }
+    }
+}
+
+impl Read for DeadlineReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if let Some(deadline) = self.deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(ErrorKind::TimedOut.into());
+            }
+            self.stream.set_read_timeout(Some(remaining))?;
+        }
+        let mut stream = self.stream;
+        stream.read(buf)
+    }
+}
+
+fn read_request(stream: impl Read) -> std::result::Result<(String, String, String), HttpResponse> {
+    let bad_request = |message: &str| HttpResponse::error(400, "bad_request", message);
+    let too_large =
+        |message: String| HttpResponse::error(431, "request_header_fields_too_large", message);
+    let mut reader = BufReader::new(stream);
+    let mut head = (&mut reader).take(MAX_HEADER_BYTES as u64);
+
+    let mut request_line = String::new();
+    head.read_line(&mut request_line)
+        .map_err(|_| bad_request("failed to read request line"))?;
+    if !request_line.ends_with('\n') && head.limit() == 0 {
+        return Err(too_large(format!(
+            "request line and headers exceed {MAX_HEADER_BYTES} bytes"
+        )));
+    }
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(bad_request("malformed request line"));
+    };
+    let path = target.split('?').next().unwrap_or(target).to_string();
+    let method = method.to_string();
+
+    let mut content_length = 0usize;
+    let mut headers = 0usize;
+    loop {
+        let mut line = String::new();
+        let read = head
+            .read_line(&mut line)
+            .map_err(|_| bad_request("failed to read headers"))?;
+        if !line.ends_with('\n') && head.limit() == 0 {
+            return Err(too_large(format!(
+                "request line and headers exceed {MAX_HEADER_BYTES} bytes"
+            )));
+        }
+        let line = line.trim_end();
+        if read == 0 || line.is_empty() {
+            break;
+        }
+        headers += 1;
+        if headers > MAX_HEADERS {
+            return Err(too_large(format!(
+                "request has more than {MAX_HEADERS} headers"
+            )));
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value
+                .trim()
+                .parse()
+                .map_err(|_| bad_request("invalid Content-Length header"))?;
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(HttpResponse::error(
+            413,
+            "payload_too_large",
+            format!("request body exceeds {MAX_BODY_BYTES} bytes"),
+        ));
+    }
+
+    let mut body = vec![0u8; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| bad_request("request body shorter than Content-Length"))?;
+    let body = String::from_utf8(body).map_err(|_| bad_request("request body is not UTF-8"))?;
+
+    Ok((method, path, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disasm_endpoint_renders_json_envelope() {
+        let response = route(
+            "POST",
+            "/disasm",
+            r#"{"arch":"riscv32","hex":"93001000","addr":"0x1000"}"#,
+        );
+        assert_eq!(response.status, 200);
+
+        let body: Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["start_address"], 0x1000);
+        assert_eq!(body["instructions"][0]["mnemonic"], "li");
+    }
+
+    #[test]
+    fn test_disasm_endpoint_accepts_base64_and_numeric_address() {
+        let response = route(
+            "POST",
+            "/disasm",
+            r#"{"arch":"riscv32","b64":"kwAQAA==","addr":16}"#,
+        );
+        assert_eq!(response.status, 200);
+
+        let body: Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["instructions"][0]["address"], 16);
+    }
+
+    #[test]
+    fn test_disasm_endpoint_rejects_bad_requests() {
+        assert_eq!(route("POST", "/disasm", "not json").status, 400);
+        assert_eq!(
+            route("POST", "/disasm", r#"{"arch":"riscv32"}"#).status,
+            400
+        );
+        assert_eq!(
+            route("POST", "/disasm", r#"{"arch":"bogus","hex":"00"}"#).status,
+            400
+        );
+        assert_eq!(route("GET", "/disasm", "").status, 405);
+        assert_eq!(route("GET", "/nope", "").status, 404);
+    }
+
+    #[test]
+    fn test_handle_connection_round_trip() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            handle_connection(stream).unwrap();
+        });
+
+        let body = r#"{"arch":"riscv32","hex":"93001000"}"#;
+        let mut client = TcpStream::connect(address).unwrap();
+        write!(
+            client,
+            "POST /disasm HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        server.join().unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("\"mnemonic\": \"li\""));
+    }
+
+    #[test]
+    fn test_oversized_request_line_is_rejected() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            handle_connection(stream).unwrap();
+        });
+
+        let target = "a".repeat(MAX_HEADER_BYTES - "GET /".len());
+        let mut client = TcpStream::connect(address).unwrap();
+        write!(client, "GET /{target}").unwrap();
+
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        server.join().unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 431 Request Header Fields Too Large"));
+    }
+
+    #[test]
+    fn test_header_count_is_capped() {
+        let mut request = String::from("GET /health HTTP/1.1\r\n");
+        for index in 0..=MAX_HEADERS {
+            request.push_str(&format!("X-Filler-{index}: 1\r\n"));
+        }
+        request.push_str("\r\n");
+
+        let response = read_request(request.as_bytes()).unwrap_err();
+        assert_eq!(response.status, 431);
+    }
+
+    #[test]
+    fn test_connection_slots_cap_concurrent_connections() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let first = ConnectionSlot::acquire(&active, 2).unwrap();
+        let second = ConnectionSlot::acquire(&active, 2).unwrap();
+        assert!(ConnectionSlot::acquire(&active, 2).is_none());
+
+        drop(first);
+        let third = ConnectionSlot::acquire(&active, 2);
+        assert!(third.is_some());
+        drop((second, third));
+        assert_eq!(active.load(Ordering::Acquire), 0);
+    }
+
+    #[test]
+    fn test_sockets_time_out_with_the_service_limit() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let _client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+
+        set_socket_timeouts(&stream).unwrap();
+        let timeout = ResourceLimits::service().timeout;
+        assert!(timeout.is_some());
+        assert_eq!(stream.read_timeout().unwrap(), timeout);
+        assert_eq!(stream.write_timeout().unwrap(), timeout);
+    }
+}
@@ -1,12 +1,58 @@
 use crate::arch::{Architecture, ArchitectureSpec};
 use crate::capabilities::{render_capabilities_json, render_capabilities_text};
-use crate::command::{Cli, render_help_text};
+use crate::command::{Cli, MisalignedPolicy, Syntax, render_help_text};
 use crate::config::{DisasmConfig, OutputConfig};
-use crate::disasm::{DisassemblyFormatter, process_input};
+use crate::disasm::{DisassemblyEngine, DisassemblyFormatter, process_input};
+use crate::error::CliError;
 use clap::Parser;
 use robustone_core::all_architecture_capabilities;
 use serde_json::Value;
 
+#[test]
+fn test_cli_parses_llvm_syntax() {
+    let cli = Cli::try_parse_from(["robustone", "--syntax", "llvm", "riscv64", "13054506"])
+        .expect("--syntax llvm should parse");
+    assert_eq!(cli.syntax, Syntax::Llvm);
+    assert_eq!(cli.validate().unwrap().display_options.syntax, Syntax::Llvm);
+
+    let cli = Cli::try_parse_from(["robustone", "riscv64", "13054506"]).unwrap();
+    assert_eq!(cli.syntax, Syntax::Default);
+    assert!(Cli::try_parse_from(["robustone", "--syntax", "gnu", "riscv64", "00"]).is_err());
+}
+
+#[test]
+fn test_validate_reports_every_argument_problem() {
+    let cli = Cli::try_parse_from(["robustone", "riscv6", "zz", "0xq"]).unwrap();
+    let Err(CliError::InvalidConfig(issues)) = cli.validate() else {
+        panic!("expected every problem to be collected");
+    };
+    let fields: Vec<_> = issues.iter().map(|issue| issue.field.as_str()).collect();
+    assert_eq!(fields, ["arch_mode", "hex_token", "address"]);
+    assert_eq!(
+        issues[0].suggestion.as_deref(),
+        Some("did you mean `riscv`?")
+    );
+    assert!(issues.iter().all(|issue| issue.suggestion.is_some()));
+
+    let cli = Cli::try_parse_from(["robustone", "riscv64", "1305", "0xg"]).unwrap();
+    assert!(matches!(
+        cli.validate(),
+        Err(CliError::InvalidConfig(ref issues)) if issues.len() == 1
+    ));
+}
+
+#[test]
+fn test_att_syntax_renders_x86_operands_in_att_order() {
+    let cli = Cli::try_parse_from(["robustone", "--syntax", "att", "x32", "b878563412"]).unwrap();
+    let config = cli.validate().unwrap();
+    let output = config.output_config();
+    assert_eq!(output.syntax, Syntax::Att);
+
+    let result = process_input(&config).unwrap();
+    let text = DisassemblyFormatter::new(output).format(&result);
+    assert!(text.contains("mov\t$0x12345678, %eax"), "{text}");
+}
+
 #[test]
 fn test_cli_basic_parsing() {
     let args = vec!["robustone", "riscv32", "93001000"];
@@ -19,6 +65,397 @@ fn test_cli_basic_parsing() {
     assert!(!cli.json);
 }
 
+#[test]
+fn test_cli_parses_serve_subcommand() {
+    let cli = Cli::try_parse_from(["robustone", "serve", "--listen", "0.0.0.0:9000"])
+        .expect("serve subcommand should parse");
+
+    assert_eq!(
+        cli.command,
+        Some(crate::command::Command::Serve {
+            listen: "0.0.0.0:9000".to_string()
+        })
+    );
+    assert_eq!(cli.arch_mode, None);
+}
+
+#[test]
+fn test_cli_parses_selftest_subcommand() {
+    let cli = Cli::try_parse_from(["robustone", "selftest", "--json"])
+        .expect("selftest subcommand should parse");
+
+    assert_eq!(
+        cli.command,
+        Some(crate::command::Command::Selftest { json: true })
+    );
+}
+
+#[test]
+fn test_cli_parses_completions_and_man_subcommands() {
+    let cli = Cli::try_parse_from(["robustone", "completions", "zsh"])
+        .expect("completions subcommand should parse");
+    assert_eq!(
+        cli.command,
+        Some(crate::command::Command::Completions {
+            shell: clap_complete::Shell::Zsh
+        })
+    );
+
+    let cli = Cli::try_parse_from(["robustone", "man"]).expect("man subcommand should parse");
+    assert_eq!(cli.command, Some(crate::command::Command::Man));
+
+    assert!(Cli::try_parse_from(["robustone", "completions", "tcsh"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_batch_subcommand() {
+    let cli = Cli::try_parse_from(["robustone", "batch", "-j", "4", "jobs.toml"])
+        .expect("batch subcommand should parse");
+    assert_eq!(
+        cli.command,
+        Some(crate::command::Command::Batch {
+            manifest: "jobs.toml".into(),
+            jobs: 4
+        })
+    );
+}
+
+#[test]
+fn test_cli_counts_long_verbose_flag_and_keeps_version_on_short_v() {
+    let cli = Cli::try_parse_from(["robustone", "--verbose", "--verbose", "riscv32", "93001000"])
+        .expect("repeated --verbose should parse");
+    assert_eq!(cli.verbose, 2);
+    assert!(!cli.version);
+
+    let cli = Cli::try_parse_from(["robustone", "-v"]).expect("-v should parse");
+    assert!(cli.version);
+    assert_eq!(cli.verbose, 0);
+    assert!(Cli::try_parse_from(["robustone", "-V"]).is_err());
+}
+
+#[test]
+fn test_cli_output_target_prefers_file_then_pager_setting() {
+    use crate::output::OutputTarget;
+
+    let cli = Cli::try_parse_from(["robustone", "-o", "out.txt", "riscv32", "93001000"])
+        .expect("-o should parse");
+    assert_eq!(cli.output_target(), OutputTarget::File("out.txt".into()));
+
+    let cli = Cli::try_parse_from(["robustone", "--no-pager", "riscv32", "93001000"])
+        .expect("--no-pager should parse");
+    assert_eq!(cli.output_target(), OutputTarget::Stdout { pager: false });
+}
+
+#[test]
+fn test_raw_bytes_flag_reaches_output_layout() {
+    let cli = Cli::try_parse_from(["robustone", "-d", "--raw-bytes", "riscv32", "970200009102"])
+        .expect("--raw-bytes should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+    let formatter = crate::disasm::DisassemblyFormatter::new(config.output_config());
+    let result = crate::disasm::DisassemblyEngine::new("riscv32")
+        .disassemble(&config)
+        .expect("listing should decode");
+
+    assert_eq!(
+        formatter.format(&result),
+        "0  97020000  auipc\tt0, 0\n\
+         \top[0]: REG t0 (write)\n\
+         \top[1]: IMM 0x0 (read)\n\
+         4  9102      addi\tt0, t0, 4\n\
+         \top[0]: REG t0 (write)\n\
+         \top[1]: REG t0 (read)\n\
+         \top[2]: IMM 0x4 (read)\n"
+    );
+}
+
+#[test]
+fn test_word_column_prints_each_encoding_as_one_value() {
+    let cli = Cli::try_parse_from(["robustone", "--word-column", "riscv32", "970200000545"])
+        .expect("--word-column should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+    let formatter = crate::disasm::DisassemblyFormatter::new(config.output_config());
+    let result = crate::disasm::DisassemblyEngine::new("riscv32")
+        .disassemble(&config)
+        .expect("listing should decode");
+
+    assert_eq!(
+        formatter.format(&result),
+        "0    0x00000297  auipc\tt0, 0\n\
+         4    0x4505      li\ta0, 1\n"
+    );
+}
+
+#[test]
+fn test_detailed_output_lists_typed_operands() {
+    let cli = Cli::try_parse_from(["robustone", "-d", "riscv64", "03358100 2f25b600"])
+        .expect("-d should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+    let formatter = crate::disasm::DisassemblyFormatter::new(config.output_config());
+    let result = crate::disasm::DisassemblyEngine::new("riscv64")
+        .disassemble(&config)
+        .expect("listing should decode");
+    let output = formatter.format(&result);
+
+    assert!(output.contains("\top[0]: REG a0 (write)\n\top[1]: MEM base=sp disp=0x8 (read)\n"));
+    assert!(
+        output.contains("\top[1]: MEM base=a2 disp=0x0 (read/write)\n\top[2]: REG a1 (read)\n")
+    );
+}
+
+#[test]
+fn test_address_flags_select_column_layout() {
+    use crate::config::AddressDisplay;
+
+    let cli = Cli::try_parse_from([
+        "robustone",
+        "--address-width",
+        "16",
+        "--offsets",
+        "riscv32",
+        "93001000",
+        "1000",
+    ])
+    .expect("address flags should parse");
+    let layout = cli.column_layout();
+    assert_eq!(layout.address, AddressDisplay::Offset);
+    assert_eq!(layout.address_width, 16);
+
+    let cli = Cli::try_parse_from(["robustone", "--no-address", "riscv32", "93001000"])
+        .expect("--no-address should parse");
+    assert_eq!(cli.column_layout().address, AddressDisplay::Hidden);
+
+    assert!(Cli::try_parse_from(["robustone", "--address-width", "12", "riscv32", "00"]).is_err());
+    assert!(
+        Cli::try_parse_from(["robustone", "--offsets", "--no-address", "riscv32", "00"]).is_err()
+    );
+}
+
+#[test]
+fn test_labels_flag_rewrites_in_range_branch_targets() {
+    let cli = Cli::try_parse_from([
+        "robustone",
+        "--labels",
+        "riscv32",
+        "ef00c0001300000013000000e30a00fe",
+        "1000",
+    ])
+    .expect("--labels should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+    let result = crate::disasm::DisassemblyEngine::new("riscv32")
+        .disassemble(&config)
+        .expect("listing should decode");
+    let listing = crate::disasm::DisassemblyFormatter::new(config.output_config()).format(&result);
+
+    assert!(listing.starts_with(".L1:\n1000    jal\t.L2\n"), "{listing}");
+    assert!(
+        listing.ends_with(".L2:\n100c    beqz\tzero, .L1\n"),
+        "{listing}"
+    );
+}
+
+#[test]
+fn test_explain_flag_prints_encoding_breakdown() {
+    let cli = Cli::try_parse_from(["robustone", "--explain", "riscv64", "130101ff"])
+        .expect("--explain should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+    let output = DisassemblyFormatter::new(config.output_config())
+        .format(&process_input(&config).expect("decode should succeed"));
+
+    assert_eq!(
+        output,
+        "0    addi\tsp, sp, -0x10\n\
+         \tEncoding: I-type (OP-IMM)\n\
+         \t  [31:20] imm[11:0] = 111111110000 (4080)\n\
+         \t  [19:15] rs1       = 00010 (2)\n\
+         \t  [14:12] funct3    = 000 (0)\n\
+         \t  [11:7]  rd        = 00010 (2)\n\
+         \t  [6:0]   opcode    = 0010011 (19)\n\
+         \tImmediate: -16\n\
+         \tDescription: Add immediate\n\
+         \tExtension: I\n\
+         \tPrivilege: user\n\
+         \tLatency: single (rocket: single, sifive-u74: single)\n"
+    );
+}
+
+#[test]
+fn test_expand_compressed_flag_appends_canonical_form() {
+    let cli = Cli::try_parse_from([
+        "robustone",
+        "--expand-compressed",
+        "riscv64+noaliascompressed",
+        "82801301010f",
+    ])
+    .expect("--expand-compressed should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+    let output = DisassemblyFormatter::new(config.output_config())
+        .format(&process_input(&config).expect("decode should succeed"));
+
+    assert_eq!(
+        output,
+        "0    c.jr\tra  ; = jalr zero, 0(ra)\n\
+         2    addi\tsp, sp, 0xf0\n"
+    );
+}
+
+#[test]
+fn test_dataflow_flag_prints_def_use_chains() {
+    // li a0, 5; addi a1, a0, 1; add a0, a0, a1
+    let cli = Cli::try_parse_from([
+        "robustone",
+        "--dataflow",
+        "riscv64",
+        "13055000930515003305b500",
+    ])
+    .expect("--dataflow should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+    let output = DisassemblyFormatter::new(config.output_config())
+        .format(&process_input(&config).expect("decode should succeed"));
+
+    assert_eq!(
+        output,
+        "0    li\ta0, 5\n\
+         \tDefs: a0 -> 0x4 0x8\n\
+         4    addi\ta1, a0, 1\n\
+         \tUses: a0 <- 0x0\n\
+         \tDefs: a1 -> 0x8\n\
+         8    add\ta0, a0, a1\n\
+         \tUses: a0 <- 0x0, a1 <- 0x4\n\
+         \tDefs: a0 -> unused\n"
+    );
+}
+
+#[test]
+fn test_misaligned_policy_controls_odd_addresses() {
+    let cli = Cli::try_parse_from([
+        "robustone",
+        "--misaligned",
+        "warn",
+        "riscv32",
+        "13055000",
+        "0x1001",
+    ])
+    .expect("--misaligned should parse");
+    assert_eq!(cli.misaligned, MisalignedPolicy::Warn);
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+
+    let result = DisassemblyEngine::new(config.arch_name())
+        .with_misaligned_policy(MisalignedPolicy::Warn)
+        .disassemble(&config)
+        .expect("warn should still decode");
+    assert_eq!(result.instructions.len(), 1);
+    assert_eq!(result.warnings[0].kind, "misaligned_address");
+    assert_eq!(result.warnings[0].address, Some(0x1001));
+
+    let error = DisassemblyEngine::new(config.arch_name())
+        .with_misaligned_policy(MisalignedPolicy::Error)
+        .disassemble(&config)
+        .expect_err("error should reject the odd address");
+    assert_eq!(error.stable_kind(), "invalid_address");
+
+    let result = process_input(&config).expect("allow is the default");
+    assert!(result.warnings.is_empty());
+
+    // Without C, RISC-V instructions must be 4-byte aligned.
+    let config = DisasmConfig::builder()
+        .arch("riscv32+m")
+        .hex("13055000")
+        .address(0x1002)
+        .build()
+        .expect("config should build");
+    let result = DisassemblyEngine::new(config.arch_name())
+        .with_misaligned_policy(MisalignedPolicy::Warn)
+        .disassemble(&config)
+        .expect("warn should still decode");
+    assert_eq!(result.warnings.len(), 1);
+}
+
+#[test]
+fn test_addresses_wrap_at_the_architecture_address_width() {
+    let listing = |arch: &str, address: u64, hex: &str| {
+        let config = DisasmConfig::builder()
+            .arch(arch)
+            .hex(hex)
+            .address(address)
+            .build()
+            .expect("config should build");
+        process_input(&config).expect("input should decode")
+    };
+
+    // nop; nop across the top of the 32-bit address space.
+    let result = listing("riscv32", 0xffff_fffc, "13000000 13000000");
+    let addresses: Vec<u64> = result.instructions.iter().map(|i| i.address).collect();
+    assert_eq!(addresses, [0xffff_fffc, 0x0]);
+    assert_eq!(result.final_address(), 0x4);
+
+    let result = listing("riscv64", u64::MAX - 3, "13000000 13000000");
+    assert_eq!(result.instructions[1].address, 0x0);
+    assert_eq!(result.final_address(), 0x4);
+
+    // beqz zero, -0xc at 0x4 resolves below zero.
+    let result = listing("riscv32", 0x4, "e30a00fe");
+    assert_eq!(
+        crate::labels::branch_target(&result.instructions[0]),
+        Some(0xffff_fff8)
+    );
+    let result = listing("riscv64", 0x4, "e30a00fe");
+    assert_eq!(
+        crate::labels::branch_target(&result.instructions[0]),
+        Some(0xffff_ffff_ffff_fff8)
+    );
+}
+
+#[test]
+fn test_start_address_wider_than_the_architecture_is_rejected() {
+    let build = |arch: &str| {
+        DisasmConfig::builder()
+            .arch(arch)
+            .hex("13000000")
+            .address(0x1_0000_0000)
+            .build()
+    };
+
+    let error = build("riscv32").expect_err("a 33-bit address should not fit riscv32");
+    assert!(
+        error
+            .to_string()
+            .contains("address 0x100000000 does not fit riscv32 (32-bit addresses)"),
+        "{error}"
+    );
+    assert!(build("riscv64").is_ok());
+}
+
+#[test]
+fn test_format_flag_accepts_gas() {
+    let cli = Cli::try_parse_from(["robustone", "--format", "gas", "riscv64", "93001000"])
+        .expect("gas format should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+
+    assert_eq!(
+        config.output_config().format,
+        crate::command::OutputFormat::Gas
+    );
+}
+
+#[test]
+fn test_format_flag_selects_gdb_layout_and_json_alias() {
+    let cli = Cli::try_parse_from(["robustone", "--format", "gdb", "riscv32", "93001000"])
+        .expect("gdb format should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("config should build");
+    let output = DisassemblyFormatter::new(config.output_config())
+        .format(&process_input(&config).expect("decode should succeed"));
+    assert_eq!(output, "   0x0:\tli\tra,1\n");
+
+    let cli = Cli::try_parse_from(["robustone", "--format", "json", "riscv32", "93001000"])
+        .expect("json format should parse");
+    assert!(
+        cli.validate()
+            .expect("config should validate")
+            .display_options
+            .json
+    );
+}
+
 #[test]
 fn test_architecture_spec_rejects_incompatible_modifier() {
     assert!(ArchitectureSpec::parse("riscv32+intel").is_err());
@@ -57,6 +494,24 @@ fn test_architecture_spec_accepts_endianness_modifiers() {
     assert_eq!(sparc64_be.mode, 0x100);
 }
 
+#[test]
+fn test_architecture_spec_keeps_mode_modifiers_apart_from_options() {
+    let spec = ArchitectureSpec::parse("armbe+thumb").expect("armbe+thumb should parse");
+    assert_eq!(spec.arch.name(), "armbe");
+    assert!(spec.modes.big_endian && spec.modes.thumb);
+    let profile = spec.profile();
+    assert_eq!(profile.mode_name, "armbe");
+    assert_eq!(profile.modes, spec.modes);
+
+    let spec = ArchitectureSpec::parse("mipsel+micromips").expect("mipsel+micromips should parse");
+    assert!(spec.modes.micromips && !spec.modes.big_endian);
+
+    // RISC-V keeps its extension profile; there are no mode switches.
+    let profile = ArchitectureSpec::parse("riscv32+c").unwrap().profile();
+    assert!(profile.enabled_extensions.contains(&"C"));
+    assert_eq!(profile.modes, Default::default());
+}
+
 #[test]
 fn test_architecture_spec_accepts_cstool_style_modifier_sets() {
     assert!(ArchitectureSpec::parse("arm+noregname").is_ok());
@@ -74,6 +529,19 @@ fn test_architecture_spec_accepts_riscv_capstone_modifiers() {
     assert!(ArchitectureSpec::parse("riscv32+intel").is_err());
 }
 
+#[test]
+fn test_architecture_spec_resolves_toolchain_style_aliases() {
+    let spec = ArchitectureSpec::parse("rv64gc+noalias").expect("rv64gc should resolve");
+    assert_eq!(spec.arch.name(), "riscv64");
+    assert!(spec.has_option("noalias"));
+
+    let args = vec!["robustone", "riscv32gc", "13055000"];
+    let cli = Cli::try_parse_from(args).expect("CLI arguments should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("configuration should be valid");
+    let result = process_input(&config).expect("aliases should reach the RISC-V handler");
+    assert_eq!(result.instructions[0].mnemonic, "li");
+}
+
 #[test]
 fn test_process_input_honors_riscv_extension_modifiers() {
     // +a does not include F, so fadd.s should fail with unsupported_extension.
@@ -114,6 +582,50 @@ fn test_noalias_modifier_disables_riscv_alias_rendering() {
     assert!(!output.contains("li\tra"));
 }
 
+#[test]
+fn test_noalias_modifier_disables_csr_pseudo_instructions() {
+    let render = |arch: &str| {
+        // frcsr a0; fsrm a0; rdcycle t0
+        let cli = Cli::try_parse_from(["robustone", arch, "73253000 73102500 f32200c0"])
+            .expect("CLI arguments should parse");
+        let config = DisasmConfig::config_from_cli(&cli).expect("configuration should be valid");
+        let result = process_input(&config).expect("disassembly should succeed");
+        DisassemblyFormatter::new(config.output_config()).format(&result)
+    };
+
+    let output = render("riscv64");
+    assert!(output.contains("frcsr\ta0\n"));
+    assert!(output.contains("fsrm\ta0\n"));
+    assert!(output.contains("rdcycle\tt0\n"));
+
+    let output = render("riscv64+noalias");
+    assert!(output.contains("csrrs\tx10, fcsr, x0\n"));
+    assert!(output.contains("csrrw\tx0, frm, x10\n"));
+    assert!(output.contains("csrrs\tx5, cycle, x0\n"));
+}
+
+#[test]
+fn test_mark_hints_annotates_hint_and_reserved_encodings() {
+    // c.addi x0, 1 (HINT); c.nop; reserved c.addi4spn; addi x0, x0, 1 (HINT)
+    let hex = "0500 0100 0800 13001000";
+    let cli = Cli::try_parse_from(["robustone", "--skip-data", "--mark-hints", "riscv64", hex])
+        .expect("CLI arguments should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("configuration should be valid");
+    let result = process_input(&config).expect("disassembly should succeed");
+    let output = DisassemblyFormatter::new(config.output_config()).format(&result);
+    let marks: Vec<_> = output
+        .lines()
+        .map(|line| line.rsplit_once("  ; ").map(|(_, mark)| mark))
+        .collect();
+    assert_eq!(marks, [Some("hint"), None, Some("reserved"), Some("hint")]);
+
+    let cli = Cli::try_parse_from(["robustone", "--skip-data", "riscv64", hex])
+        .expect("CLI arguments should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("configuration should be valid");
+    let result = process_input(&config).expect("disassembly should succeed");
+    assert!(result.encoding_classes.is_empty());
+}
+
 #[test]
 fn test_noalias_modifier_disables_aliases_in_real_detail_sections() {
     let args = vec!["robustone", "-r", "riscv32+noalias", "93001000"];
@@ -124,9 +636,35 @@ fn test_noalias_modifier_disables_aliases_in_real_detail_sections() {
     let output = formatter.format(&result);
 
     assert!(output.contains("Registers read: x0"));
-    assert!(output.contains("Registers written: x1"));
+    assert!(output.contains("Registers modified: x1"));
     assert!(!output.contains("Registers read: zero"));
-    assert!(!output.contains("Registers written: ra"));
+    assert!(!output.contains("Registers modified: ra"));
+}
+
+#[test]
+fn test_real_detail_prints_cstool_style_detail_block() {
+    // ld a0, 8(sp)
+    let args = vec!["robustone", "-r", "riscv64", "03358100"];
+    let cli = Cli::try_parse_from(args).expect("CLI arguments should parse");
+    let config = DisasmConfig::config_from_cli(&cli).expect("configuration should be valid");
+    let result = process_input(&config).expect("disassembly should succeed");
+    let output = DisassemblyFormatter::new(config.output_config()).format(&result);
+
+    assert_eq!(
+        output.lines().skip(1).collect::<Vec<_>>(),
+        [
+            "\top_count: 2",
+            "\t\toperands[0].type: REG = a0",
+            "\t\toperands[0].access: WRITE",
+            "\t\toperands[1].type: MEM",
+            "\t\t\toperands[1].mem.base: REG = sp",
+            "\t\t\toperands[1].mem.disp: 0x8",
+            "\t\toperands[1].access: READ",
+            "\tRegisters read: sp",
+            "\tRegisters modified: a0",
+            "\tGroups: load",
+        ]
+    );
 }
 
 #[test]
@@ -139,9 +677,9 @@ fn test_real_detail_defaults_to_capstone_register_aliases() {
     let output = formatter.format(&result);
 
     assert!(output.contains("Registers read: zero"));
-    assert!(output.contains("Registers written: ra"));
+    assert!(output.contains("Registers modified: ra"));
     assert!(!output.contains("Registers read: x0"));
-    assert!(!output.contains("Registers written: x1"));
+    assert!(!output.contains("Registers modified: x1"));
 }
 
 #[test]
@@ -217,7 +755,7 @@ fn test_real_detail_output_uses_instruction_addresses() {
     let output = formatter.format(&result);
 
     assert!(output.contains("1000"));
-    assert!(output.contains("Registers written"));
+    assert!(output.contains("Registers modified"));
 }
 
 #[test]
@@ -361,6 +899,29 @@ fn test_riscv_profile_modifier_builds_correct_extension_set() {
     let profile = spec.riscv_profile().expect("should return profile");
     assert!(profile.enabled_extensions.contains(&"D"));
     assert!(profile.enabled_extensions.contains(&"F"));
+
+    // +zacas extends the GC default instead of replacing it.
+    let spec = ArchitectureSpec::parse("riscv64+zacas").unwrap();
+    let profile = spec.riscv_profile().expect("should return profile");
+    let mut exts = profile.enabled_extensions.clone();
+    exts.sort_unstable();
+    assert_eq!(exts, vec!["A", "C", "D", "F", "I", "M", "Zacas"]);
+
+    // +v brings in the F and D it builds on.
+    let spec = ArchitectureSpec::parse("riscv32+c+v").unwrap();
+    let profile = spec.riscv_profile().expect("should return profile");
+    assert_eq!(
+        profile.enabled_extensions,
+        vec!["C", "D", "F", "I", "M", "V"]
+    );
+
+    // +zabha brings in the A it builds on.
+    let spec = ArchitectureSpec::parse("riscv32+c+zabha").unwrap();
+    let profile = spec.riscv_profile().expect("should return profile");
+    assert_eq!(
+        profile.enabled_extensions,
+        vec!["A", "C", "I", "M", "Zabha"]
+    );
 }
 
 #[test]
@@ -464,3 +1025,100 @@ fn test_riscv_new_compressed_instructions_decode() {
     let result = cli_decode("riscv64", "d3001140").unwrap();
     assert_eq!(result.instructions[0].mnemonic, "fcvt.s.d");
 }
+
+#[test]
+fn test_cli_parses_archs_listing_flag() {
+    let cli = Cli::try_parse_from(["robustone", "--archs"]).expect("flag should parse");
+    assert!(cli.should_show_architectures());
+    assert!(cli.validate_architectures_request().is_ok());
+
+    assert!(Cli::try_parse_from(["robustone", "--archs", "--capabilities"]).is_err());
+
+    let mixed = Cli::try_parse_from(["robustone", "--archs", "riscv32", "93001000"])
+        .expect("arguments should still parse before validation");
+    let error = mixed
+        .validate_architectures_request()
+        .expect_err("disassembly inputs should be rejected");
+    assert!(error.to_string().contains("`--archs`"));
+}
+
+#[test]
+fn test_hexdump_flag_conflicts_with_json() {
+    assert!(Cli::try_parse_from(["robustone", "--hexdump", "riscv32", "93001000"]).is_ok());
+    assert!(
+        Cli::try_parse_from(["robustone", "--hexdump", "--json", "riscv32", "93001000"]).is_err()
+    );
+}
+
+#[test]
+fn test_classify_takes_an_optional_region_size() {
+    let cli = Cli::try_parse_from(["robustone", "--classify", "riscv32", "93001000"])
+        .expect("--classify should parse without a value");
+    assert_eq!(cli.classify, Some(4096));
+    assert_eq!(cli.arch_mode.as_deref(), Some("riscv32"));
+
+    let cli = Cli::try_parse_from(["robustone", "--classify=512", "riscv32", "93001000"])
+        .expect("--classify=SIZE should parse");
+    assert_eq!(cli.classify, Some(512));
+}
+
+#[test]
+fn test_object_input_takes_the_address_positionally() {
+    let cli = Cli::try_parse_from(["robustone", "--object", "f.o", "riscv64", "80000000"])
+        .expect("--object with a start address should parse");
+    let validated = cli.validate_args().expect("--object needs no hex argument");
+    assert_eq!(validated.hex_code, None);
+    assert_eq!(validated.address, Some(0x8000_0000));
+    assert!(cli.has_disassembly_input());
+
+    assert!(Cli::try_parse_from(["robustone", "--object", "f.o", "--watch", "f.bin"]).is_err());
+}
+
+#[test]
+fn test_symbol_requires_object_input() {
+    assert!(Cli::try_parse_from(["robustone", "--symbol", "main", "riscv64", "13000000"]).is_err());
+    let cli = Cli::try_parse_from([
+        "robustone",
+        "--object",
+        "f.o",
+        "--symbol",
+        "init_*",
+        "riscv64",
+    ])
+    .expect("--symbol with --object should parse");
+    assert_eq!(cli.symbol.as_deref(), Some("init_*"));
+}
+
+#[test]
+fn test_memory_map_flag_parses() {
+    let cli = Cli::try_parse_from([
+        "robustone",
+        "--memory-map",
+        "soc.dtb",
+        "riscv64",
+        "13000000",
+        "80000000",
+    ])
+    .expect("--memory-map should parse");
+    assert_eq!(cli.memory_map.as_deref(), Some("soc.dtb"));
+    assert_eq!(cli.validate().unwrap().start_address, 0x8000_0000);
+}
+
+#[test]
+fn test_resource_limit_flags_parse() {
+    let cli = Cli::try_parse_from([
+        "robustone",
+        "--max-instructions",
+        "5",
+        "--timeout",
+        "0.5",
+        "riscv64",
+        "13000000",
+    ])
+    .expect("limit flags should parse");
+    let limits = cli.resource_limits();
+    assert_eq!(limits.max_instructions, Some(5));
+    assert_eq!(limits.max_output_bytes, None);
+    assert_eq!(limits.timeout, Some(std::time::Duration::from_millis(500)));
+    assert!(Cli::try_parse_from(["robustone", "--timeout", "-1", "riscv64", "13000000"]).is_err());
+}
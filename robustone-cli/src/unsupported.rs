@@ -0,0 +1,212 @@
+//! Unsupported-encoding summary (`--unsupported-summary`).
+//!
+//! Groups the encodings a run stepped over as data by their exact bytes and
+//! prints each one with its count and, when the handler can split it, its
+//! opcode and function fields, followed by a histogram of the opcodes:
+//!
+//! ```text
+//! Unsupported encodings: 1 distinct, 2 occurrences
+//!   count  first       bytes     format              fields
+//!       2  0x00001000  0b501000  unknown (custom-0)  funct7=0x0 rs2=0x1 rs1=0x0 funct3=0x5 rd=0x0 opcode=0xb
+//! Opcodes:
+//!       2  opcode=0xb  unknown (custom-0)
+//! ```
+//!
+//! The report is meant to be pasted into extension-support issues as is.
+
+use crate::disasm::{DisassemblyEngine, UndecodedEncoding};
+
+use robustone_core::traits::EncodingBreakdown;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// One distinct encoding the decoder rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedEncoding {
+    /// The encoding's bytes, in input order.
+    pub bytes: Vec<u8>,
+    /// Stable error kind of the first occurrence.
+    pub kind: String,
+    pub first_address: u64,
+    pub count: usize,
+    /// Field split from the architecture handler, when it offers one.
+    pub breakdown: Option<EncodingBreakdown>,
+}
+
+/// Group `undecoded` by encoding, most frequent first.
+///
+/// An encoding spans the bits of its breakdown when the handler splits it,
+/// and the bytes the data step skipped otherwise. Failures inside an encoding
+/// already counted, left by a data step shorter than it, are not counted again.
+pub fn summarize_unsupported(
+    undecoded: &[UndecodedEncoding],
+    engine: &DisassemblyEngine,
+    arch: &str,
+) -> Vec<UnsupportedEncoding> {
+    let mut grouped: BTreeMap<Vec<u8>, UnsupportedEncoding> = BTreeMap::new();
+    let mut covered = 0..0;
+    for item in undecoded {
+        if covered.contains(&item.address) {
+            continue;
+        }
+        let breakdown = engine.explain_encoding(&item.bytes, arch);
+        let len = breakdown
+            .as_ref()
+            .and_then(|breakdown| breakdown.fields.first())
+            .map_or(item.skipped, |field| usize::from(field.msb) / 8 + 1)
+            .clamp(1, item.bytes.len().max(1));
+        let bytes = item.bytes[..len.min(item.bytes.len())].to_vec();
+        covered = item.address..item.address.saturating_add(bytes.len() as u64);
+        grouped
+            .entry(bytes.clone())
+            .or_insert_with(|| UnsupportedEncoding {
+                bytes,
+                kind: item.kind.clone(),
+                first_address: item.address,
+                count: 0,
+                breakdown,
+            })
+            .count += 1;
+    }
+
+    let mut encodings: Vec<_> = grouped.into_values().collect();
+    encodings.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then(a.first_address.cmp(&b.first_address))
+    });
+    encodings
+}
+
+/// Render the summary for stderr.
+pub fn render_unsupported_summary(encodings: &[UnsupportedEncoding]) -> String {
+    if encodings.is_empty() {
+        return "Unsupported encodings: none\n".to_string();
+    }
+
+    let total: usize = encodings.iter().map(|encoding| encoding.count).sum();
+    let mut out = format!(
+        "Unsupported encodings: {} distinct, {total} occurrence{}\n",
+        encodings.len(),
+        if total == 1 { "" } else { "s" }
+    );
+    let rows: Vec<[String; 5]> = encodings
+        .iter()
+        .map(|encoding| {
+            let (format, fields) = match &encoding.breakdown {
+                Some(breakdown) => (
+                    breakdown.format.clone(),
+                    breakdown
+                        .fields
+                        .iter()
+                        .map(|field| format!("{}={:#x}", field.name, field.value))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                ),
+                None => (encoding.kind.clone(), String::new()),
+            };
+            [
+                encoding.count.to_string(),
+                format!("0x{:08x}", encoding.first_address),
+                hex::encode(&encoding.bytes),
+                format,
+                fields,
+            ]
+        })
+        .collect();
+    let header = ["count", "first", "bytes", "format", "fields"];
+    let widths: Vec<usize> = (0..4)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain([header[column].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut push_row = |cells: [&str; 5]| {
+        let line = format!(
+            "  {:>w0$}  {:<w1$}  {:<w2$}  {:<w3$}  {}",
+            cells[0],
+            cells[1],
+            cells[2],
+            cells[3],
+            cells[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+        let _ = writeln!(out, "{}", line.trim_end());
+    };
+    push_row(header);
+    for row in &rows {
+        push_row([&row[0], &row[1], &row[2], &row[3], &row[4]]);
+    }
+
+    let mut opcodes: BTreeMap<String, usize> = BTreeMap::new();
+    for encoding in encodings {
+        let key = match &encoding.breakdown {
+            Some(breakdown) => {
+                let opcode = breakdown
+                    .fields
+                    .iter()
+                    .rev()
+                    .find(|field| field.name == "opcode" || field.name == "op")
+                    .map(|field| format!("{}={:#x}", field.name, field.value))
+                    .unwrap_or_default();
+                format!("{opcode}  {}", breakdown.format)
+            }
+            None => format!("byte={:#04x}", encoding.bytes.first().copied().unwrap_or(0)),
+        };
+        *opcodes.entry(key).or_default() += encoding.count;
+    }
+    let mut opcodes: Vec<_> = opcodes.into_iter().collect();
+    opcodes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    out.push_str("Opcodes:\n");
+    for (key, count) in opcodes {
+        let _ = writeln!(out, "  {count:>w$}  {key}", w = widths[0]);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DisasmConfig;
+
+    #[test]
+    fn test_summary_groups_custom_opcodes_with_their_fields() {
+        // Two custom-0 words (as XTheadCondMov would use), addi, another custom-0.
+        let config = DisasmConfig::builder()
+            .arch("riscv64")
+            .hex("0b501000 0b501000 13051500 0b602000")
+            .address(0x1000)
+            .skip_data(true)
+            .build()
+            .unwrap();
+        let engine = DisassemblyEngine::new("riscv64").with_skip_data(true);
+        let result = engine.disassemble(&config).unwrap();
+        // The 2-byte data step also tries the upper halves of the first two
+        // words; the last one's (`2000`) decodes as c.addi4spn.
+        assert_eq!(result.undecoded.len(), 5);
+
+        let encodings = summarize_unsupported(&result.undecoded, &engine, "riscv64");
+        assert_eq!(encodings.len(), 2);
+        assert_eq!(encodings[0].bytes, [0x0b, 0x50, 0x10, 0x00]);
+        assert_eq!(encodings[0].count, 2);
+        assert_eq!(encodings[0].first_address, 0x1000);
+        assert_eq!(encodings[1].first_address, 0x100c);
+
+        let text = render_unsupported_summary(&encodings);
+        assert!(text.starts_with("Unsupported encodings: 2 distinct, 3 occurrences\n"));
+        assert!(text.contains(
+            "0b501000  unknown (custom-0)  funct7=0x0 rs2=0x1 rs1=0x0 funct3=0x5 rd=0x0 opcode=0xb\n"
+        ));
+        assert!(text.ends_with("Opcodes:\n      3  opcode=0xb  unknown (custom-0)\n"));
+        assert_eq!(
+            render_unsupported_summary(&[]),
+            "Unsupported encodings: none\n"
+        );
+    }
+}
@@ -5,6 +5,10 @@
 
 use crate::error::{CliError, Result};
 use robustone_core::lookup_architecture_capability;
+use robustone_core::types::error::DisasmError;
+use robustone_core::utils::hex::{HexStreamDecoder, decode_hex_digits};
+use std::io::Read;
+use std::time::Duration;
 
 /// Validate an architecture string with comprehensive error reporting.
 pub fn validate_architecture(arch_str: &str) -> Result<String> {
@@ -52,7 +56,8 @@ pub fn parse_hex_code(input: &str) -> Result<Vec<String>> {
             continue;
         }
 
-        let normalized = normalize_hex_token(raw)?;
+        let normalized =
+            normalize_hex_token(raw).map_err(|error| error.within(input, offset_in(input, raw)))?;
         words.push(normalized);
     }
 
@@ -74,8 +79,16 @@ pub fn parse_address(input: &str) -> Result<u64> {
 
     let normalized = normalize_hex_number(input, "address")?;
 
-    u64::from_str_radix(normalized, 16)
-        .map_err(|_| CliError::validation("address", "Invalid hexadecimal address format"))
+    u64::from_str_radix(normalized, 16).map_err(|_| {
+        CliError::validation_at(
+            "address",
+            "Invalid hexadecimal address format",
+            input,
+            offset_in(input, normalized),
+            normalized.len(),
+            "does not fit in 64 bits",
+        )
+    })
 }
 
 /// Convert hex words to raw bytes.
@@ -83,63 +96,231 @@ pub fn hex_words_to_bytes(words: &[String]) -> Result<Vec<u8>> {
     let mut bytes: Vec<u8> = Vec::new();
 
     for word in words {
-        let normalized = normalize_hex_token(word)?;
-        let hex_part = &normalized[2..]; // Remove "0x" prefix
-
-        for i in (0..hex_part.len()).step_by(2) {
-            let byte_str = &hex_part[i..i + 2];
-            let byte = u8::from_str_radix(byte_str, 16).map_err(|_| {
-                CliError::validation("hex_code", format!("Invalid hex byte: {byte_str}"))
-            })?;
-            bytes.push(byte);
-        }
+        append_hex_token(word, &mut bytes)?;
     }
 
     Ok(bytes)
 }
 
 /// Parse hex string directly to bytes (convenience function).
+///
+/// Accepts the same input as [`parse_hex_code`] but decodes each token
+/// straight into the output without building intermediate strings, so
+/// multi-megabyte inputs parse at table-lookup speed.
 pub fn parse_hex_to_bytes(input: &str) -> Result<Vec<u8>> {
-    let words = parse_hex_code(input)?;
-    hex_words_to_bytes(&words)
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(CliError::validation("hex_code", "Empty hex code provided"));
+    }
+
+    let mut bytes = Vec::with_capacity(trimmed.len() / 2);
+    for token in trimmed.split_whitespace() {
+        append_hex_token(token, &mut bytes)
+            .map_err(|error| error.within(input, offset_in(input, token)))?;
+    }
+    Ok(bytes)
 }
 
-/// Normalize a hex token to canonical format (0x prefix + lowercase).
-fn normalize_hex_token(token: &str) -> Result<String> {
-    let trimmed = token.trim().to_lowercase();
+/// Decode one hex token, validated like [`normalize_hex_token`], onto `out`.
+fn append_hex_token(token: &str, out: &mut Vec<u8>) -> Result<()> {
+    let hex_part = hex_token_digits(token)?;
+    decode_hex_digits(hex_part.as_bytes(), out)
+        .map_err(|index| invalid_hex_character(token, offset_in(token, hex_part) + index))
+}
 
-    if trimmed.is_empty() {
+/// The digits of one hex token after any `0x` prefix, rejecting empty and
+/// odd-length tokens. Errors point into `token`.
+fn hex_token_digits(token: &str) -> Result<&str> {
+    if token.is_empty() {
         return Err(CliError::validation("hex_token", "Empty hex token"));
     }
 
-    let hex_part = if trimmed.strip_prefix("0x").is_some() {
-        &trimmed[2..]
-    } else {
-        &trimmed
+    let hex_part = token
+        .strip_prefix("0x")
+        .or_else(|| token.strip_prefix("0X"))
+        .unwrap_or(token);
+    if hex_part.is_empty() {
+        return Err(CliError::validation_at(
+            "hex_token",
+            "Empty hex content",
+            token,
+            0,
+            token.len(),
+            "no digits after the prefix",
+        ));
+    }
+    if !hex_part.len().is_multiple_of(2) {
+        return Err(CliError::validation_at(
+            "hex_token",
+            "Hex token must have even number of digits",
+            token,
+            0,
+            token.len(),
+            "odd number of digits",
+        ));
+    }
+    Ok(hex_part)
+}
+
+/// Error for the character at byte `index` of `token`, which is not a hex digit.
+fn invalid_hex_character(token: &str, index: usize) -> CliError {
+    let c = token[index..].chars().next().unwrap_or_default();
+    let shown = c.to_lowercase().next().unwrap_or(c);
+    CliError::validation_at(
+        "hex_token",
+        format!("Invalid hex character: {shown}"),
+        token,
+        index,
+        c.len_utf8(),
+        "not a hex digit",
+    )
+}
+
+/// Byte offset of `part`, a subslice of `whole`, within `whole`.
+fn offset_in(whole: &str, part: &str) -> usize {
+    part.as_ptr().addr() - whole.as_ptr().addr()
+}
+
+/// The hex argument that reads the hex text from standard input.
+pub const STDIN_HEX_CODE: &str = "-";
+
+/// Decode hex text from `reader` as it arrives, as in `cat code.hex | robustone riscv64 -`.
+///
+/// Only the decoded bytes are kept, so the text is never held in memory as a
+/// whole. Tokens follow the same rules as the hex argument and may be split
+/// across reads.
+pub fn read_hex_stream(mut reader: impl Read) -> Result<Vec<u8>> {
+    let mut decoder = HexStreamDecoder::new();
+    let mut bytes = Vec::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error.into()),
+        };
+        decoder
+            .feed(&buffer[..read], &mut bytes)
+            .map_err(stream_error)?;
+    }
+    decoder.finish().map_err(stream_error)?;
+
+    if bytes.is_empty() {
+        return Err(CliError::validation(
+            "hex_code",
+            "No hex input on standard input",
+        ));
+    }
+    Ok(bytes)
+}
+
+fn stream_error(error: DisasmError) -> CliError {
+    match error {
+        DisasmError::DecodingError(detail) => CliError::validation("hex_code", detail),
+        other => CliError::validation("hex_code", other.to_string()),
+    }
+}
+
+/// A contiguous run of bytes at its own address, from hex input that
+/// carries addresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressedHex {
+    pub address: u64,
+    pub bytes: Vec<u8>,
+}
+
+/// Split hex input whose bytes are preceded by `address:` tokens, as pasted
+/// from objdump or a monitor (`8000: 97 02 00 00`), into contiguous runs.
+///
+/// Returns `Ok(None)` when no line starts with an address, so plain hex
+/// input keeps its usual meaning. Bytes are read in memory order like the
+/// rest of the hex input. On an address line, the first token that is not
+/// hex ends the line (objdump's disassembly text); other lines continue the
+/// current run when they hold only hex and are skipped otherwise (section
+/// and symbol headers). A run starting where the previous one ended is
+/// merged into it.
+pub fn parse_addressed_hex(input: &str) -> Result<Option<Vec<AddressedHex>>> {
+    let address_token = |token: &str| {
+        token
+            .strip_suffix(':')
+            .and_then(|address| parse_address(address).ok())
     };
+    let has_addresses = input.lines().any(|line| {
+        line.split_whitespace()
+            .next()
+            .is_some_and(|token| address_token(token).is_some())
+    });
+    if !has_addresses {
+        return Ok(None);
+    }
 
-    if hex_part.is_empty() {
-        return Err(CliError::validation("hex_token", "Empty hex content"));
+    let mut runs: Vec<AddressedHex> = Vec::new();
+    for line in input.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let addressed = tokens
+            .first()
+            .is_some_and(|token| address_token(token).is_some());
+        if !addressed
+            && !tokens
+                .iter()
+                .all(|token| normalize_hex_token(token).is_ok())
+        {
+            continue;
+        }
+        for token in tokens {
+            if let Some(address) = address_token(token) {
+                match runs.last() {
+                    Some(run) if run.address + run.bytes.len() as u64 == address => {}
+                    Some(run) if address < run.address + run.bytes.len() as u64 => {
+                        return Err(CliError::validation(
+                            "hex_code",
+                            format!("address 0x{address:x} overlaps the bytes before it"),
+                        ));
+                    }
+                    _ => runs.push(AddressedHex {
+                        address,
+                        bytes: Vec::new(),
+                    }),
+                }
+                continue;
+            }
+            let Ok(word) = normalize_hex_token(token) else {
+                break;
+            };
+            let Some(run) = runs.last_mut() else {
+                return Err(CliError::validation(
+                    "hex_code",
+                    format!("`{token}` comes before the first `address:` token"),
+                ));
+            };
+            run.bytes.extend(hex_words_to_bytes(&[word])?);
+        }
     }
 
-    if hex_part.len() % 2 != 0 {
+    runs.retain(|run| !run.bytes.is_empty());
+    if runs.is_empty() {
         return Err(CliError::validation(
-            "hex_token",
-            "Hex token must have even number of digits",
+            "hex_code",
+            "No valid hex tokens found",
         ));
     }
+    Ok(Some(runs))
+}
+
+/// Normalize a hex token to canonical format (0x prefix + lowercase).
+fn normalize_hex_token(token: &str) -> Result<String> {
+    let hex_part = hex_token_digits(token)?;
 
     // Validate all characters are hexadecimal
-    for c in hex_part.chars() {
-        if !c.is_ascii_hexdigit() {
-            return Err(CliError::validation(
-                "hex_token",
-                format!("Invalid hex character: {c}"),
-            ));
-        }
+    if let Some(index) = hex_part.find(|c: char| !c.is_ascii_hexdigit()) {
+        return Err(invalid_hex_character(
+            token,
+            offset_in(token, hex_part) + index,
+        ));
     }
 
-    Ok(format!("0x{hex_part}"))
+    Ok(format!("0x{}", hex_part.to_ascii_lowercase()))
 }
 
 fn normalize_hex_number<'a>(token: &'a str, field: &'static str) -> Result<&'a str> {
@@ -159,10 +340,15 @@ fn normalize_hex_number<'a>(token: &'a str, field: &'static str) -> Result<&'a s
         return Err(CliError::validation(field, "Empty hexadecimal value"));
     }
 
-    if !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
-        return Err(CliError::validation(
+    if let Some(index) = hex_part.find(|c: char| !c.is_ascii_hexdigit()) {
+        let len = hex_part[index..].chars().next().map_or(1, char::len_utf8);
+        return Err(CliError::validation_at(
             field,
             "Invalid hexadecimal address format",
+            token,
+            offset_in(token, hex_part) + index,
+            len,
+            "not a hex digit",
         ));
     }
 
@@ -190,6 +376,38 @@ pub fn format_bytes_as_hex(bytes: &[u8], with_spaces: bool) -> String {
     }
 }
 
+/// Uppercase the digits of every `0x` literal in `text`, keeping the prefix.
+///
+/// Register names and other identifiers are left untouched, so
+/// `addi a0, a0, 0xff` becomes `addi a0, a0, 0xFF`.
+pub fn uppercase_hex_literals(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut output = String::with_capacity(text.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let starts_literal = bytes[index] == b'0'
+            && bytes.get(index + 1) == Some(&b'x')
+            && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+        if !starts_literal {
+            let ch = text[index..]
+                .chars()
+                .next()
+                .expect("index is on a char boundary");
+            output.push(ch);
+            index += ch.len_utf8();
+            continue;
+        }
+
+        output.push_str("0x");
+        index += 2;
+        while index < bytes.len() && bytes[index].is_ascii_hexdigit() {
+            output.push(bytes[index].to_ascii_uppercase() as char);
+            index += 1;
+        }
+    }
+    output
+}
+
 /// Validate that a string represents a valid hexadecimal number.
 pub fn is_valid_hex(input: &str) -> bool {
     let trimmed = input.trim();
@@ -208,10 +426,24 @@ pub fn is_valid_hex(input: &str) -> bool {
 
 /// Calculate the number of bytes needed to represent hex data.
 pub fn hex_byte_count(hex_str: &str) -> Result<usize> {
-    let normalized = normalize_hex_token(hex_str)?;
+    let normalized = normalize_hex_token(hex_str.trim())?;
     Ok(normalized.len() / 2 - 1) // Subtract 1 for "0x" prefix
 }
 
+/// Parse a `--timeout` value in seconds, such as `5` or `0.25`.
+pub fn parse_timeout(input: &str) -> Result<Duration> {
+    let seconds: f64 = input
+        .trim()
+        .parse()
+        .map_err(|_| CliError::validation("timeout", format!("invalid seconds: {input}")))?;
+    Duration::try_from_secs_f64(seconds).map_err(|_| {
+        CliError::validation(
+            "timeout",
+            "timeout must be a non-negative number of seconds",
+        )
+    })
+}
+
 // Legacy re-exports for backward compatibility
 pub use self::{
     parse_address as parse_address_legacy, parse_hex_code as parse_hex_code_legacy,
@@ -222,6 +454,17 @@ pub use self::{
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_uppercase_hex_literals_only_touches_hex_digits() {
+        assert_eq!(
+            uppercase_hex_literals("addi a0, a0, -0xff"),
+            "addi a0, a0, -0xFF"
+        );
+        assert_eq!(uppercase_hex_literals("ld a0, 0x1c(fp)"), "ld a0, 0x1C(fp)");
+        assert_eq!(uppercase_hex_literals("la a0x0, 10"), "la a0x0, 10");
+        assert_eq!(uppercase_hex_literals("$fa0, $a0"), "$fa0, $a0");
+    }
+
     #[test]
     fn test_validate_architecture() {
         assert!(validate_architecture("riscv32").is_ok());
@@ -245,6 +488,40 @@ mod tests {
         assert_eq!(result, vec!["0x1234", "0x5678"]);
     }
 
+    #[test]
+    fn test_hex_errors_point_at_the_offending_character() {
+        let span = |error: CliError| match error {
+            CliError::Validation {
+                span: Some(span), ..
+            } => (span.input, span.offset, span.len),
+            other => panic!("expected a span, got {other:?}"),
+        };
+
+        let input = "1305 0x12x4";
+        assert_eq!(
+            span(parse_hex_code(input).unwrap_err()),
+            (input.to_string(), 9, 1)
+        );
+        assert_eq!(
+            span(parse_hex_to_bytes(input).unwrap_err()),
+            (input.to_string(), 9, 1)
+        );
+        assert_eq!(
+            span(parse_hex_code("13 050").unwrap_err()),
+            ("13 050".to_string(), 3, 3)
+        );
+        assert_eq!(
+            span(parse_address(" 0x10g0").unwrap_err()),
+            (" 0x10g0".to_string(), 5, 1)
+        );
+        assert_eq!(
+            parse_hex_code("12x4").unwrap_err().to_string(),
+            "Validation error for 'hex_token': Invalid hex character: x\n    \
+             | 12x4\n    \
+             |   ^ not a hex digit"
+        );
+    }
+
     #[test]
     fn test_parse_address() {
         assert_eq!(parse_address("0x1000").unwrap(), 0x1000);
@@ -261,6 +538,53 @@ mod tests {
         assert_eq!(bytes, vec![0x12, 0x34, 0x56, 0x78]);
     }
 
+    #[test]
+    fn test_read_hex_stream_joins_tokens_split_across_reads() {
+        let reader = std::io::Cursor::new("93 0").chain(std::io::Cursor::new("x0100 00\n"));
+        assert_eq!(
+            read_hex_stream(reader).unwrap(),
+            vec![0x93, 0x01, 0x00, 0x00]
+        );
+
+        assert!(read_hex_stream(std::io::Cursor::new("  \n")).is_err());
+        assert!(read_hex_stream(std::io::Cursor::new("93 0")).is_err());
+    }
+
+    #[test]
+    fn test_parse_addressed_hex_splits_runs_at_gaps() {
+        assert_eq!(parse_addressed_hex("97020000 93828244").unwrap(), None);
+
+        let runs = parse_addressed_hex("8000: 97 02 00 00\n8004: 93 82 82 44\n8010: 73 00 10 00")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            runs,
+            [
+                AddressedHex {
+                    address: 0x8000,
+                    bytes: vec![0x97, 0x02, 0x00, 0x00, 0x93, 0x82, 0x82, 0x44],
+                },
+                AddressedHex {
+                    address: 0x8010,
+                    bytes: vec![0x73, 0x00, 0x10, 0x00],
+                },
+            ]
+        );
+
+        // objdump headers and disassembly text are skipped.
+        let runs = parse_addressed_hex(
+            "0000000080000000 <_start>:\n80000000:\t97020000 \tauipc\tt0,0x0\n80000004:\t8280 \tret",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].address, 0x8000_0000);
+        assert_eq!(runs[0].bytes, [0x97, 0x02, 0x00, 0x00, 0x82, 0x80]);
+
+        assert!(parse_addressed_hex("8004: 97020000\n8000: 13000000").is_err());
+        assert!(parse_addressed_hex("13000000\n8000: 97020000").is_err());
+    }
+
     #[test]
     fn test_is_valid_hex() {
         assert!(is_valid_hex("0x1234"));
@@ -1,10 +1,66 @@
 //! Version information presenter.
 //!
 //! Provides the `-v/--version` CLI output with dynamic capability statistics
-//! sourced from the shared architecture registry.
+//! sourced from the shared architecture registry, plus the build options
+//! ([`VersionInfo`]) in the spirit of `cstool -v`.
 
 use robustone_core::all_architecture_capabilities;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// What this build of robustone is and what it can decode.
+///
+/// `robustone --version` prints it after the capability summary, and
+/// `robustone --version --json` emits it as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VersionInfo {
+    /// Version of the CLI crate.
+    pub version: &'static str,
+    /// Short hash of the git commit built from, when known.
+    pub git_hash: Option<&'static str>,
+    /// Canonical names of the architectures with a decode backend.
+    pub architectures: Vec<&'static str>,
+    /// Canonical names of the architectures that are only parsed.
+    pub parser_only: Vec<&'static str>,
+    /// RISC-V extensions whose decoders are compiled in.
+    pub riscv_extensions: Vec<&'static str>,
+    /// Cargo features the CLI was built with.
+    pub features: Vec<&'static str>,
+}
+
+impl VersionInfo {
+    /// Describe the running build.
+    pub fn current() -> Self {
+        let capabilities = all_architecture_capabilities();
+        let names = |decodes: bool| {
+            capabilities
+                .iter()
+                .filter(|capability| capability.decode_supported == decodes)
+                .map(|capability| capability.canonical_name)
+                .collect()
+        };
+        let features = [
+            ("gzip", cfg!(feature = "gzip")),
+            ("xz", cfg!(feature = "xz")),
+            ("zstd", cfg!(feature = "zstd")),
+        ];
+        Self {
+            version: clap::crate_version!(),
+            git_hash: option_env!("ROBUSTONE_GIT_HASH"),
+            architectures: names(true),
+            parser_only: names(false),
+            riscv_extensions: robustone_riscv::extensions::create_extensions()
+                .iter()
+                .map(|extension| extension.name())
+                .collect(),
+            features: features
+                .into_iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(name, _)| name)
+                .collect(),
+        }
+    }
+}
 
 /// Print the full version banner along with capability stats.
 pub fn print_version_info() {
@@ -12,6 +68,7 @@ pub fn print_version_info() {
     print_architecture_summary();
     print_implementation_status();
     print_detailed_status();
+    print_build_info();
 }
 
 /// Print the basic banner headline.
@@ -60,8 +117,8 @@ fn print_implementation_status() {
     println!("Supported Architectures:");
 
     let capabilities = all_architecture_capabilities();
-    let mut categories: HashMap<&str, Vec<&robustone_core::ArchitectureCapability>> =
-        HashMap::new();
+    let mut categories: BTreeMap<&str, Vec<&robustone_core::ArchitectureCapability>> =
+        BTreeMap::new();
 
     for capability in capabilities {
         categories
@@ -110,6 +167,24 @@ fn print_detailed_status() {
     println!();
 }
 
+/// Print the build options: commit, compiled-in decoders, and features.
+fn print_build_info() {
+    let info = VersionInfo::current();
+    let list = |items: &[&str]| {
+        if items.is_empty() {
+            "none".to_string()
+        } else {
+            items.join(", ")
+        }
+    };
+
+    println!("Build:");
+    println!("  Commit: {}", info.git_hash.unwrap_or("unknown"));
+    println!("  Decoders: {}", list(&info.architectures));
+    println!("  RISC-V extensions: {}", list(&info.riscv_extensions));
+    println!("  Features: {}", list(&info.features));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,10 +194,21 @@ mod tests {
         print_version_info();
     }
 
+    #[test]
+    fn test_version_info_lists_compiled_in_decoders() {
+        let info = VersionInfo::current();
+        assert_eq!(info.version, clap::crate_version!());
+        assert!(info.architectures.contains(&"riscv64"));
+        assert!(!info.parser_only.contains(&"riscv64"));
+        assert_eq!(info.riscv_extensions.first(), Some(&"I"));
+        assert!(info.riscv_extensions.contains(&"C"));
+        assert_eq!(info.features.contains(&"gzip"), cfg!(feature = "gzip"));
+    }
+
     #[test]
     fn test_architecture_categories() {
         let capabilities = all_architecture_capabilities();
-        let mut categories = HashMap::new();
+        let mut categories = BTreeMap::new();
 
         for capability in capabilities {
             categories
@@ -0,0 +1,124 @@
+//! Watch mode (`--watch FILE`).
+//!
+//! Re-disassembles a raw binary whenever it changes, for compile/inspect
+//! loops. The file is polled for a new modification time or size rather than
+//! subscribed to, which behaves the same on every platform and survives
+//! build tools that replace the file instead of rewriting it.
+
+use crate::command::Cli;
+use crate::compressed::read_input;
+use crate::config::DisasmConfig;
+use crate::error::{CliError, Result};
+use crate::executor::CliExecutor;
+use crate::output::OutputTarget;
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How often the watched file is checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Modification time and size; a change in either triggers a new listing.
+type Stamp = (Option<SystemTime>, u64);
+
+/// Renders a fresh listing each time the watched file changes.
+pub struct Watcher<'a> {
+    cli: &'a Cli,
+    path: PathBuf,
+    last: Option<Stamp>,
+}
+
+impl<'a> Watcher<'a> {
+    /// Watch `path`, disassembling it with the options given on `cli`.
+    pub fn new(cli: &'a Cli, path: impl Into<PathBuf>) -> Self {
+        Self {
+            cli,
+            path: path.into(),
+            last: None,
+        }
+    }
+
+    /// Return a new listing if the file changed since the previous poll.
+    ///
+    /// A file that is missing or empty (for example while a linker is still
+    /// writing it) is treated as unchanged so the next complete write is picked up.
+    pub fn poll(&mut self) -> Option<Result<String>> {
+        let metadata = std::fs::metadata(&self.path).ok()?;
+        let stamp = (metadata.modified().ok(), metadata.len());
+        if metadata.len() == 0 || self.last == Some(stamp) {
+            return None;
+        }
+        self.last = Some(stamp);
+        Some(self.render())
+    }
+
+    fn render(&self) -> Result<String> {
+        let bytes = read_input(&self.path)?;
+        let mut validated = self.cli.validate_args()?;
+        validated.hex_code = Some(hex::encode(bytes));
+        let config = DisasmConfig::from_cli_args(validated)?;
+        CliExecutor::new().execute_to_string(&config)
+    }
+}
+
+/// Entry point for `--watch`: poll `path` forever, re-printing on change.
+///
+/// Listings go to stdout (never paged) or overwrite the `-o` file; each
+/// refresh is announced on stderr. Errors are reported and watching continues.
+pub fn watch(cli: &Cli, path: &Path) -> Result<()> {
+    if !path.is_file() {
+        return Err(CliError::Io(format!(
+            "cannot watch `{}`: not a file",
+            path.display()
+        )));
+    }
+
+    let target = match cli.output_target() {
+        OutputTarget::Stdout { .. } => OutputTarget::Stdout { pager: false },
+        file => file,
+    };
+    let mut watcher = Watcher::new(cli, path);
+    loop {
+        if let Some(listing) = watcher.poll() {
+            eprintln!("robustone: {} changed, disassembling", path.display());
+            if let Err(error) = listing.and_then(|listing| target.emit(&listing)) {
+                eprintln!("robustone: {error}");
+            }
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn test_watcher_renders_only_when_the_file_changes() {
+        let path = std::env::temp_dir().join(format!("robustone-watch-{}.bin", std::process::id()));
+        std::fs::write(&path, [0x93, 0x00, 0x10, 0x00]).unwrap();
+        let cli = Cli::try_parse_from([
+            "robustone",
+            "--watch",
+            path.to_str().unwrap(),
+            "riscv32",
+            "1000",
+        ])
+        .unwrap();
+
+        let mut watcher = Watcher::new(&cli, &path);
+        assert_eq!(watcher.poll().unwrap().unwrap(), "1000    li\tra, 1\n");
+        assert!(watcher.poll().is_none());
+
+        std::fs::write(&path, [0x93, 0x00, 0x10, 0x00, 0x13, 0x00, 0x00, 0x00]).unwrap();
+        assert_eq!(
+            watcher.poll().unwrap().unwrap(),
+            "1000    li\tra, 1\n1004    addi\tzero, zero, 0\n"
+        );
+
+        std::fs::write(&path, []).unwrap();
+        assert!(watcher.poll().is_none());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,20 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use robustone_core::utils::HexParser;
+use robustone_core::utils::hex::sample_hex_input;
+use std::hint::black_box;
+
+fn bench_hex_parse(c: &mut Criterion) {
+    let parser = HexParser::new();
+    let mut group = c.benchmark_group("hex_parse");
+    for size in [4 * 1024, 4 * 1024 * 1024] {
+        let input = sample_hex_input(size);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &input, |b, input| {
+            b.iter(|| black_box(parser.parse(input, None).unwrap()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_hex_parse);
+criterion_main!(benches);
@@ -10,7 +10,6 @@ fn bench_riscv_decode(c: &mut Criterion) {
         RiscVHandler::from_profile(&profile).expect("profile should build a handler"),
     ));
     let bytes = [0x93, 0x00, 0x10, 0x00];
-    let hex = "93001000";
 
     c.bench_function("riscv32_decode_ir", |b| {
         b.iter(|| black_box(dispatcher.decode_with_profile(&bytes, &profile, 0).unwrap()));
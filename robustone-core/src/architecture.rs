@@ -6,6 +6,8 @@
 
 use std::fmt;
 use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ArchitectureCapability {
@@ -27,11 +29,89 @@ impl ArchitectureCapability {
     pub fn implementation_status(&self) -> &'static str {
         if self.decode_supported { "✅" } else { "❌" }
     }
+
+    /// Width of the architecture's address space.
+    pub fn address_width(&self) -> AddressWidth {
+        AddressWidth::for_architecture(self.canonical_name)
+    }
+
+    /// Default operand size: what an instruction without size prefixes or
+    /// width-specific opcodes operates on.
+    ///
+    /// Usually the address width, except that x86-64 keeps 32-bit operands
+    /// (`REX.W` widens them) and real-mode x86 uses 16-bit ones.
+    pub fn operand_width(&self) -> AddressWidth {
+        match self.canonical_name {
+            "x64" => AddressWidth::Bits32,
+            _ => self.address_width(),
+        }
+    }
+}
+
+/// Width of an address space. Address arithmetic (stepping past an
+/// instruction, resolving a PC-relative target) wraps modulo `2^bits`, the
+/// way the program counter does on the hardware.
+///
+/// The same widths describe operand sizes; see
+/// [`ArchitectureCapability::operand_width`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AddressWidth {
+    /// Real-mode x86 (`x16`), where offsets wrap within a 64 KiB segment.
+    Bits16,
+    Bits32,
+    #[default]
+    Bits64,
+}
+
+impl AddressWidth {
+    /// Address width of the canonical architecture `canonical_name`.
+    ///
+    /// Only the 64-bit architectures use the full `u64` range and `x16`
+    /// wraps at 16 bits; everything else, including names the registry does
+    /// not know, wraps at 32 bits.
+    pub fn for_architecture(canonical_name: &str) -> Self {
+        match canonical_name {
+            "x16" => AddressWidth::Bits16,
+            "riscv64" | "aarch64" | "aarch64be" | "x64" | "mips64" | "mips64el" | "powerpc64"
+            | "powerpc64be" | "sparc64" | "systemz" | "loongarch64" | "bpf" | "evm" => {
+                AddressWidth::Bits64
+            }
+            _ => AddressWidth::Bits32,
+        }
+    }
+
+    pub fn bits(self) -> u32 {
+        match self {
+            AddressWidth::Bits16 => 16,
+            AddressWidth::Bits32 => 32,
+            AddressWidth::Bits64 => 64,
+        }
+    }
+
+    /// Hex digits needed to print any value of this width.
+    pub fn hex_digits(self) -> usize {
+        self.bits() as usize / 4
+    }
+
+    /// Mask selecting the bits of an address that fit in this width.
+    pub fn mask(self) -> u64 {
+        u64::MAX >> (64 - self.bits())
+    }
+
+    /// Reduce `address` into this address space.
+    pub fn wrap(self, address: u64) -> u64 {
+        address & self.mask()
+    }
+
+    /// `address + delta`, wrapping around the end of the address space.
+    pub fn offset(self, address: u64, delta: i64) -> u64 {
+        self.wrap(address.wrapping_add_signed(delta))
+    }
 }
 
-const RISCV32_ALIASES: &[&str] = &["riscv32"];
-const RISCV64_ALIASES: &[&str] = &["riscv64", "riscv"];
-const RISCV32E_ALIASES: &[&str] = &["riscv32e"];
+const RISCV32_ALIASES: &[&str] = &["riscv32", "rv32", "rv32gc", "riscv32gc"];
+const RISCV64_ALIASES: &[&str] = &["riscv64", "riscv", "rv64", "rv64gc", "riscv64gc"];
+const RISCV32E_ALIASES: &[&str] = &["riscv32e", "rv32e"];
 const ARM_ALIASES: &[&str] = &["arm", "armv7"];
 const ARMLE_ALIASES: &[&str] = &["armle"];
 const ARMBE_ALIASES: &[&str] = &["armbe"];
@@ -59,7 +139,7 @@ const TMS320C64X_ALIASES: &[&str] = &["tms320c64x", "c64x"];
 const M680X_ALIASES: &[&str] = &["m680x"];
 const EVM_ALIASES: &[&str] = &["evm"];
 const BPF_ALIASES: &[&str] = &["bpf"];
-const LOONGARCH64_ALIASES: &[&str] = &["loongarch", "loongarch64"];
+const LOONGARCH64_ALIASES: &[&str] = &["loongarch", "loongarch64", "loongarch32"];
 
 const ARCHITECTURE_CAPABILITIES: &[ArchitectureCapability] = &[
     ArchitectureCapability {
@@ -366,6 +446,241 @@ fn normalize_architecture_token(token: &str) -> String {
     token.trim().to_ascii_lowercase()
 }
 
+const RISCV_MODIFIERS: &[&str] = &[
+    "a",
+    "c",
+    "fd",
+    "f",
+    "d",
+    "m",
+    "v",
+    "zabha",
+    "zacas",
+    "noalias",
+    "noaliascompressed",
+];
+
+/// Architecture-specific `+option` modifiers accepted after `canonical_name`.
+pub fn supported_modifiers(canonical_name: &str) -> &'static [&'static str] {
+    match canonical_name {
+        "riscv32" | "riscv64" | "riscv32e" => RISCV_MODIFIERS,
+        "arm" | "armle" | "armbe" => &["thumb", "m", "v8", "noregname", "regalias", "be", "le"],
+        "thumb" => &["m", "v8", "noregname", "regalias"],
+        "aarch64" | "aarch64be" => &["apple", "noregname", "regalias", "be", "le"],
+        "x16" | "x32" | "x64" => &["att", "intel", "masm", "nasm"],
+        "mips" | "mipsel" | "mips64" | "mips64el" => &[
+            "micromips",
+            "nofloat",
+            "ptr64",
+            "noregname",
+            "nodollar",
+            "be",
+            "le",
+        ],
+        "powerpc32" | "powerpc32be" | "powerpc64" | "powerpc64be" => &[
+            "aix",
+            "booke",
+            "maix",
+            "msync",
+            "qpx",
+            "ps",
+            "spe",
+            "noregname",
+            "percentage",
+            "be",
+            "le",
+        ],
+        "sparc" | "sparcle" | "sparc64" => &["v9", "be", "le"],
+        _ => &[],
+    }
+}
+
+/// Errors produced by [`ArchSpec::parse`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ArchSpecError {
+    #[error("empty architecture input")]
+    Empty,
+    #[error("unknown architecture: {0}")]
+    UnknownArchitecture(String),
+    #[error("unknown option: {0}")]
+    UnknownOption(String),
+}
+
+/// A parsed `<arch>[+<modifier>...]` string.
+///
+/// This is the single parser behind the CLI's `<arch+mode>` argument and the
+/// handlers' `supports()` checks: the base token is resolved through the
+/// capability registry, so aliases such as `rv64` or `riscv64gc` land on the
+/// same canonical name everywhere. `+be`/`+le` switch to the matching
+/// endianness variant instead of being kept as options.
+///
+/// ```rust
+/// use robustone_core::ArchSpec;
+/// let spec = ArchSpec::parse("arm64+BE").unwrap();
+/// assert_eq!(spec.name(), "aarch64be");
+/// assert!(spec.big_endian);
+///
+/// let spec = ArchSpec::parse("rv32gc+noalias").unwrap();
+/// assert_eq!(spec.name(), "riscv32");
+/// assert_eq!(spec.options, ["noalias"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchSpec {
+    pub capability: &'static ArchitectureCapability,
+    /// Set when the last endianness modifier selected big-endian.
+    pub big_endian: bool,
+    /// Remaining modifiers in canonical spelling, in input order.
+    pub options: Vec<String>,
+}
+
+impl ArchSpec {
+    pub fn parse(input: &str) -> Result<Self, ArchSpecError> {
+        if input.trim().is_empty() {
+            return Err(ArchSpecError::Empty);
+        }
+
+        let mut parts = input.split('+');
+        let base = parts.next().unwrap_or_default();
+        let mut capability = lookup_architecture_capability(base)
+            .ok_or_else(|| ArchSpecError::UnknownArchitecture(base.to_string()))?;
+        let mut big_endian = false;
+        let mut options = Vec::new();
+
+        for modifier in parts {
+            let canonical_modifier = normalize_modifier(capability.canonical_name, modifier);
+            if !supported_modifiers(capability.canonical_name)
+                .contains(&canonical_modifier.as_str())
+            {
+                return Err(ArchSpecError::UnknownOption(modifier.to_string()));
+            }
+
+            if matches!(canonical_modifier.as_str(), "be" | "le") {
+                let variant = endianness_variant(capability.canonical_name, &canonical_modifier)
+                    .ok_or_else(|| ArchSpecError::UnknownOption(modifier.to_string()))?;
+                capability = lookup_architecture_capability(variant)
+                    .ok_or_else(|| ArchSpecError::UnknownArchitecture(variant.to_string()))?;
+                big_endian = canonical_modifier == "be";
+                continue;
+            }
+
+            options.push(canonical_modifier);
+        }
+
+        Ok(Self {
+            capability,
+            big_endian,
+            options,
+        })
+    }
+
+    /// Canonical name of the resolved architecture.
+    pub fn name(&self) -> &'static str {
+        self.capability.canonical_name
+    }
+
+    pub fn has_option(&self, option: &str) -> bool {
+        self.options.iter().any(|candidate| candidate == option)
+    }
+
+    /// The decoder mode switches this string selects.
+    ///
+    /// ```rust
+    /// use robustone_core::ArchSpec;
+    /// let modes = ArchSpec::parse("armbe+thumb+m").unwrap().modes();
+    /// assert!(modes.big_endian && modes.thumb && modes.mclass);
+    ///
+    /// let modes = ArchSpec::parse("mipsel+micro").unwrap().modes();
+    /// assert!(modes.micromips && !modes.big_endian);
+    /// ```
+    pub fn modes(&self) -> ModeModifiers {
+        let arm = matches!(self.name(), "arm" | "armle" | "armbe" | "thumb");
+        ModeModifiers {
+            big_endian: is_big_endian_variant(self.name()),
+            thumb: self.name() == "thumb" || self.has_option("thumb"),
+            mclass: arm && self.has_option("m"),
+            v8: arm && self.has_option("v8"),
+            micromips: self.has_option("micromips"),
+        }
+    }
+}
+
+/// Decoder mode switches carried by an architecture string, kept apart from
+/// the base architecture and from display-only options such as `noregname`.
+///
+/// These are the switches Capstone folds into `cs_mode`; handlers receive
+/// them through [`ArchitectureProfile::modes`](crate::common::ArchitectureProfile::modes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ModeModifiers {
+    /// Big-endian instruction stream (`armbe`, `mips`, `+be`).
+    pub big_endian: bool,
+    /// Thumb instruction set (`thumb`, `arm+thumb`).
+    pub thumb: bool,
+    /// ARM Cortex-M profile (`+m`, `+micro`).
+    pub mclass: bool,
+    /// ARMv8 A32/T32 encodings (`+v8`).
+    pub v8: bool,
+    /// microMIPS instruction set (`+micromips`, `+micro`).
+    pub micromips: bool,
+}
+
+impl FromStr for ArchSpec {
+    type Err = ArchSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn normalize_modifier(canonical_name: &str, modifier: &str) -> String {
+    match modifier.to_lowercase().as_str() {
+        "at&t" => "att".to_string(),
+        // cstool's `+micro` is M-class on ARM and microMIPS on MIPS.
+        "micro" if canonical_name.starts_with("mips") => "micromips".to_string(),
+        "micro" => "m".to_string(),
+        "big" => "be".to_string(),
+        "little" => "le".to_string(),
+        canonical => canonical.to_string(),
+    }
+}
+
+/// Whether the canonical architecture `canonical_name` decodes a
+/// big-endian instruction stream.
+fn is_big_endian_variant(canonical_name: &str) -> bool {
+    matches!(
+        canonical_name,
+        "armbe"
+            | "aarch64be"
+            | "mips"
+            | "mips64"
+            | "powerpc32be"
+            | "powerpc64be"
+            | "sparc"
+            | "sparc64"
+    )
+}
+
+fn endianness_variant(canonical_name: &'static str, modifier: &str) -> Option<&'static str> {
+    Some(match (canonical_name, modifier) {
+        ("arm" | "armbe" | "armle", "be") => "armbe",
+        ("arm" | "armbe" | "armle", "le") => "armle",
+        ("aarch64" | "aarch64be", "be") => "aarch64be",
+        ("aarch64" | "aarch64be", "le") => "aarch64",
+        ("mips", "be") | ("mips64", "be") | ("mipsel", "le") | ("mips64el", "le") => canonical_name,
+        ("mips", "le") => "mipsel",
+        ("mips64", "le") => "mips64el",
+        ("mipsel", "be") => "mips",
+        ("mips64el", "be") => "mips64",
+        ("powerpc32" | "powerpc32be", "be") => "powerpc32be",
+        ("powerpc32" | "powerpc32be", "le") => "powerpc32",
+        ("powerpc64" | "powerpc64be", "be") => "powerpc64be",
+        ("powerpc64" | "powerpc64be", "le") => "powerpc64",
+        ("sparc" | "sparcle", "be") => "sparc",
+        ("sparc" | "sparcle", "le") => "sparcle",
+        ("sparc64", "be" | "le") => "sparc64",
+        _ => return None,
+    })
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Architecture {
     RiscV32,
@@ -570,6 +885,94 @@ mod tests {
         assert!(!capability.json_supported);
     }
 
+    #[test]
+    fn test_arch_spec_resolves_aliases_and_modifiers() {
+        assert_eq!(ArchSpec::parse("rv64").unwrap().name(), "riscv64");
+        assert_eq!(ArchSpec::parse("RISCV64GC").unwrap().name(), "riscv64");
+        assert_eq!(ArchSpec::parse("rv32e").unwrap().name(), "riscv32e");
+
+        let spec = ArchSpec::parse("mips+little").unwrap();
+        assert_eq!(spec.name(), "mipsel");
+        assert!(!spec.big_endian);
+
+        let spec = ArchSpec::parse("x86+AT&T").unwrap();
+        assert_eq!(spec.name(), "x32");
+        assert!(spec.has_option("att"));
+
+        assert_eq!(ArchSpec::parse(" "), Err(ArchSpecError::Empty));
+        assert_eq!(
+            ArchSpec::parse("vax"),
+            Err(ArchSpecError::UnknownArchitecture("vax".to_string()))
+        );
+        assert_eq!(
+            ArchSpec::parse("riscv32+thumb"),
+            Err(ArchSpecError::UnknownOption("thumb".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_arch_spec_separates_mode_modifiers() {
+        let spec = ArchSpec::parse("armbe+thumb+noregname").unwrap();
+        assert_eq!(spec.name(), "armbe");
+        assert_eq!(
+            spec.modes(),
+            ModeModifiers {
+                big_endian: true,
+                thumb: true,
+                ..ModeModifiers::default()
+            }
+        );
+
+        let spec = ArchSpec::parse("mipsel+micromips").unwrap();
+        assert_eq!(spec.options, ["micromips"]);
+        assert!(spec.modes().micromips && !spec.modes().big_endian);
+        assert!(ArchSpec::parse("mips64").unwrap().modes().big_endian);
+
+        assert!(ArchSpec::parse("thumb+m").unwrap().modes().mclass);
+        assert!(ArchSpec::parse("arm+micro").unwrap().modes().mclass);
+        assert_eq!(
+            ArchSpec::parse("rv64gc+c").unwrap().modes(),
+            ModeModifiers::default()
+        );
+        assert_eq!(
+            ArchSpec::parse("x86+micromips"),
+            Err(ArchSpecError::UnknownOption("micromips".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_real_mode_x86_uses_16_bit_addresses_and_operands() {
+        let x16 = lookup_architecture_capability("x16").unwrap();
+        assert_eq!(x16.address_width(), AddressWidth::Bits16);
+        assert_eq!(x16.operand_width(), AddressWidth::Bits16);
+        assert_eq!(x16.address_width().hex_digits(), 4);
+        assert_eq!(x16.address_width().offset(0xfffe, 4), 0x2);
+
+        let x64 = lookup_architecture_capability("x86-64").unwrap();
+        assert_eq!(x64.address_width(), AddressWidth::Bits64);
+        assert_eq!(x64.operand_width(), AddressWidth::Bits32);
+        let rv64 = lookup_architecture_capability("riscv64").unwrap();
+        assert_eq!(rv64.operand_width(), AddressWidth::Bits64);
+    }
+
+    #[test]
+    fn test_address_width_wraps_per_architecture() {
+        let width = lookup_architecture_capability("riscv32")
+            .unwrap()
+            .address_width();
+        assert_eq!(width, AddressWidth::Bits32);
+        assert_eq!(width.offset(0xffff_fffe, 4), 0x2);
+        assert_eq!(width.offset(0x2, -4), 0xffff_fffe);
+        assert_eq!(width.wrap(0x1_0000_1000), 0x1000);
+
+        let width = lookup_architecture_capability("riscv64")
+            .unwrap()
+            .address_width();
+        assert_eq!(width, AddressWidth::Bits64);
+        assert_eq!(width.offset(u64::MAX, 1), 0);
+        assert_eq!(width.wrap(0x1_0000_1000), 0x1_0000_1000);
+    }
+
     #[test]
     fn test_architecture_utils_alignment() {
         assert!(is_address_aligned(0x1000, 4));
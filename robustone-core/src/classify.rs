@@ -0,0 +1,201 @@
+//! Entropy-based region classification for unknown blobs.
+//!
+//! [`classify_regions`] splits a buffer into fixed-size regions and guesses
+//! what each holds from its Shannon entropy, how evenly its byte values are
+//! spread, and how much of it decodes as instructions. The guess is meant
+//! to point users at the ranges worth disassembling, not to be exact:
+//!
+//! - near-maximal entropy with a flat byte histogram looks **encrypted**,
+//! - near-maximal entropy with a lumpier histogram looks **compressed**,
+//! - moderate entropy that mostly decodes looks like **code**,
+//! - anything else (padding, tables, strings) is **data**.
+
+use serde::Serialize;
+
+/// Entropy, in bits per byte, from which a region counts as high-entropy.
+pub const HIGH_ENTROPY: f64 = 7.2;
+
+/// Entropy below which a region is too repetitive to be code, even when it
+/// decodes (zero padding decodes on several architectures).
+pub const MIN_CODE_ENTROPY: f64 = 3.0;
+
+/// Fraction of a region that must decode for it to count as code.
+pub const MIN_CODE_COVERAGE: f64 = 0.9;
+
+/// What a region most likely holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegionClass {
+    Code,
+    Data,
+    Compressed,
+    Encrypted,
+}
+
+impl RegionClass {
+    /// Lowercase name used in reports.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RegionClass::Code => "code",
+            RegionClass::Data => "data",
+            RegionClass::Compressed => "compressed",
+            RegionClass::Encrypted => "encrypted",
+        }
+    }
+}
+
+impl std::fmt::Display for RegionClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Measurements and the resulting guess for one region.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RegionReport {
+    /// Offset of the region in the input.
+    pub offset: usize,
+    pub len: usize,
+    /// Shannon entropy in bits per byte (0.0 to 8.0).
+    pub entropy: f64,
+    /// Fraction of the region's bytes covered by decoded instructions.
+    pub coverage: f64,
+    pub class: RegionClass,
+}
+
+/// Shannon entropy of `bytes` in bits per byte; `0.0` for an empty slice.
+pub fn shannon_entropy(bytes: &[u8]) -> f64 {
+    let len = bytes.len() as f64;
+    histogram(bytes)
+        .iter()
+        .filter(|&&count| count != 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            p * (1.0 / p).log2()
+        })
+        .sum()
+}
+
+/// Split `bytes` into `region_size` regions (the last may be shorter) and
+/// classify each. `coverage` returns the fraction of a region that decodes
+/// as instructions for the target architecture.
+///
+/// # Panics
+///
+/// Panics if `region_size` is zero.
+pub fn classify_regions(
+    bytes: &[u8],
+    region_size: usize,
+    mut coverage: impl FnMut(&[u8]) -> f64,
+) -> Vec<RegionReport> {
+    assert!(region_size > 0, "region size must be non-zero");
+    bytes
+        .chunks(region_size)
+        .enumerate()
+        .map(|(index, region)| {
+            let entropy = shannon_entropy(region);
+            let coverage = coverage(region);
+            RegionReport {
+                offset: index * region_size,
+                len: region.len(),
+                entropy,
+                coverage,
+                class: classify(region, entropy, coverage),
+            }
+        })
+        .collect()
+}
+
+fn classify(region: &[u8], entropy: f64, coverage: f64) -> RegionClass {
+    if entropy >= HIGH_ENTROPY {
+        // Ciphertext is close to uniform; compressed streams keep some
+        // structure. Allow three standard deviations of the chi-square
+        // statistic (255 degrees of freedom) around uniform.
+        let expected = region.len() as f64 / 256.0;
+        let chi_square: f64 = histogram(region)
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+        if chi_square <= 255.0 + 3.0 * (2.0f64 * 255.0).sqrt() {
+            RegionClass::Encrypted
+        } else {
+            RegionClass::Compressed
+        }
+    } else if entropy >= MIN_CODE_ENTROPY && coverage >= MIN_CODE_COVERAGE {
+        RegionClass::Code
+    } else {
+        RegionClass::Data
+    }
+}
+
+fn histogram(bytes: &[u8]) -> [usize; 256] {
+    let mut counts = [0usize; 256];
+    for &byte in bytes {
+        counts[byte as usize] += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic xorshift bytes standing in for ciphertext.
+    fn pseudo_random(len: usize) -> Vec<u8> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 24) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_entropy_spans_zero_to_eight_bits() {
+        assert_eq!(shannon_entropy(&[]), 0.0);
+        assert!(shannon_entropy(&[0x41; 64]).is_sign_positive());
+        assert_eq!(shannon_entropy(&[0x41; 64]), 0.0);
+        assert_eq!(shannon_entropy(&[0, 1, 2, 3]), 2.0);
+        let all_values: Vec<u8> = (0..=255).collect();
+        assert_eq!(shannon_entropy(&all_values), 8.0);
+    }
+
+    #[test]
+    fn test_regions_are_classified_by_entropy_and_coverage() {
+        let mut blob = vec![0u8; 4096];
+        blob.extend(pseudo_random(4096));
+        // A skewed but high-entropy histogram: every value, with the low
+        // half of the byte range twice as common.
+        blob.extend((0..4096u32).map(|i| {
+            if i % 3 == 2 {
+                (i % 128) as u8 | 0x80
+            } else {
+                (i % 128) as u8
+            }
+        }));
+        blob.extend((0..4096u32).map(|i| (i % 64) as u8));
+
+        let reports = classify_regions(&blob, 4096, |region| {
+            if region.iter().all(|&b| b < 64) {
+                1.0
+            } else {
+                0.0
+            }
+        });
+        let classes: Vec<_> = reports.iter().map(|report| report.class).collect();
+        assert_eq!(
+            classes,
+            [
+                RegionClass::Data,
+                RegionClass::Encrypted,
+                RegionClass::Compressed,
+                RegionClass::Code,
+            ]
+        );
+        assert_eq!(reports[3].offset, 3 * 4096);
+        assert_eq!(reports[3].entropy, 6.0);
+    }
+}
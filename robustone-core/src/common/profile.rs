@@ -1,6 +1,6 @@
 //! Architecture profile types shared across decode backends.
 
-use crate::architecture::Architecture;
+use crate::architecture::{ArchSpec, Architecture, ModeModifiers};
 use crate::utils::Endianness;
 
 /// A concrete architecture configuration used by low-level decode APIs.
@@ -11,9 +11,44 @@ pub struct ArchitectureProfile {
     pub bit_width: u8,
     pub endianness: Endianness,
     pub enabled_extensions: Vec<&'static str>,
+    /// Mode switches (endianness, Thumb, M-class, microMIPS) from the
+    /// architecture string, separate from the base architecture.
+    pub modes: ModeModifiers,
 }
 
 impl ArchitectureProfile {
+    /// Create the profile a parsed architecture string selects, with its
+    /// mode modifiers and no extension set.
+    ///
+    /// RISC-V callers that care about extensions start from one of the
+    /// RISC-V constructors and add [`Self::with_modes`] instead.
+    pub fn from_spec(spec: &ArchSpec) -> Self {
+        let modes = spec.modes();
+        Self {
+            architecture: Architecture::from(spec.name()),
+            mode_name: spec.name(),
+            bit_width: spec.capability.address_width().bits() as u8,
+            endianness: if modes.big_endian {
+                Endianness::Big
+            } else {
+                Endianness::Little
+            },
+            enabled_extensions: Vec::new(),
+            modes,
+        }
+    }
+
+    /// Replace the mode modifiers, keeping endianness in step with them.
+    pub fn with_modes(mut self, modes: ModeModifiers) -> Self {
+        self.endianness = if modes.big_endian {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        };
+        self.modes = modes;
+        self
+    }
+
     /// Create a canonical RV32I profile (base integer only).
     pub fn riscv32i() -> Self {
         Self {
@@ -22,6 +57,7 @@ impl ArchitectureProfile {
             bit_width: 32,
             endianness: Endianness::Little,
             enabled_extensions: vec!["I"],
+            modes: ModeModifiers::default(),
         }
     }
 
@@ -33,6 +69,7 @@ impl ArchitectureProfile {
             bit_width: 32,
             endianness: Endianness::Little,
             enabled_extensions: vec!["I"],
+            modes: ModeModifiers::default(),
         }
     }
 
@@ -44,6 +81,7 @@ impl ArchitectureProfile {
             bit_width: 64,
             endianness: Endianness::Little,
             enabled_extensions: vec!["I"],
+            modes: ModeModifiers::default(),
         }
     }
 
@@ -55,6 +93,7 @@ impl ArchitectureProfile {
             bit_width: 32,
             endianness: Endianness::Little,
             enabled_extensions: vec!["I", "M", "A", "F", "D", "C"],
+            modes: ModeModifiers::default(),
         }
     }
 
@@ -66,6 +105,7 @@ impl ArchitectureProfile {
             bit_width: 64,
             endianness: Endianness::Little,
             enabled_extensions: vec!["I", "M", "A", "F", "D", "C"],
+            modes: ModeModifiers::default(),
         }
     }
 
@@ -82,6 +122,7 @@ impl ArchitectureProfile {
             bit_width,
             endianness: Endianness::Little,
             enabled_extensions,
+            modes: ModeModifiers::default(),
         }
     }
 }
@@ -102,4 +143,18 @@ mod tests {
         assert_eq!(rv64.bit_width, 64);
         assert!(rv64.enabled_extensions.contains(&"D"));
     }
+
+    #[test]
+    fn test_profile_from_spec_carries_mode_modifiers() {
+        let profile = ArchitectureProfile::from_spec(&ArchSpec::parse("armbe+thumb").unwrap());
+        assert_eq!(profile.architecture, Architecture::Arm);
+        assert_eq!(profile.mode_name, "armbe");
+        assert_eq!(profile.bit_width, 32);
+        assert_eq!(profile.endianness, Endianness::Big);
+        assert!(profile.modes.thumb);
+
+        let profile = ArchitectureProfile::from_spec(&ArchSpec::parse("aarch64").unwrap());
+        assert_eq!(profile.bit_width, 64);
+        assert_eq!(profile.modes, ModeModifiers::default());
+    }
 }
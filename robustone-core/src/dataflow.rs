@@ -0,0 +1,157 @@
+//! Def-use chains over a decoded block.
+//!
+//! [`DefUse::analyze`] links every register read to the instruction whose
+//! write it observes, and every write to the instructions that read it.
+//! Register accesses come from [`Instruction::registers_accessed`], so
+//! implicit operands (such as the link register of a call) take part.
+//!
+//! The block is treated as straight-line code in listing order: branches are
+//! not followed, so the result is exact for a basic block and an
+//! approximation for anything longer.
+
+use crate::types::instruction::Instruction;
+use std::collections::BTreeMap;
+
+/// One register read and the write it observes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterUse {
+    pub register: u32,
+    /// Index of the defining instruction, or `None` when the value comes
+    /// from before the block (the register is live-in).
+    pub defined_by: Option<usize>,
+}
+
+/// One register write and the reads that observe it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterDef {
+    pub register: u32,
+    /// Indexes of the instructions that read this value, in order.
+    pub used_by: Vec<usize>,
+}
+
+/// Uses and definitions of one instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InstructionDataflow {
+    pub uses: Vec<RegisterUse>,
+    pub defs: Vec<RegisterDef>,
+}
+
+/// Def-use chains for a sequence of instructions.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DefUse {
+    /// One entry per analyzed instruction, in input order.
+    pub instructions: Vec<InstructionDataflow>,
+    /// Index of the last instruction to write each register.
+    pub last_writes: BTreeMap<u32, usize>,
+}
+
+impl DefUse {
+    /// Analyze `instructions` as one straight-line block.
+    ///
+    /// An instruction's reads are resolved before its own writes, so
+    /// `addi a0, a0, 1` uses the previous definition of `a0`.
+    pub fn analyze(instructions: &[Instruction]) -> Self {
+        Self::analyze_ignoring(instructions, &[])
+    }
+
+    /// Like [`analyze`](Self::analyze), but skipping accesses to `ignored`
+    /// registers, such as a hard-wired zero register.
+    pub fn analyze_ignoring(instructions: &[Instruction], ignored: &[u32]) -> Self {
+        let mut analysis = Self {
+            instructions: Vec::with_capacity(instructions.len()),
+            last_writes: BTreeMap::new(),
+        };
+        // Register -> (defining instruction, position in its `defs`).
+        let mut live: BTreeMap<u32, (usize, usize)> = BTreeMap::new();
+
+        for (index, instruction) in instructions.iter().enumerate() {
+            let (read, written) = instruction.registers_accessed();
+            let mut entry = InstructionDataflow::default();
+
+            for register in read.into_iter().filter(|reg| !ignored.contains(reg)) {
+                let defined_by = live.get(&register).map(|&(definer, slot)| {
+                    analysis.instructions[definer].defs[slot]
+                        .used_by
+                        .push(index);
+                    definer
+                });
+                entry.uses.push(RegisterUse {
+                    register,
+                    defined_by,
+                });
+            }
+            for register in written.into_iter().filter(|reg| !ignored.contains(reg)) {
+                live.insert(register, (index, entry.defs.len()));
+                analysis.last_writes.insert(register, index);
+                entry.defs.push(RegisterDef {
+                    register,
+                    used_by: Vec::new(),
+                });
+            }
+            analysis.instructions.push(entry);
+        }
+        analysis
+    }
+
+    /// Registers read before any write in the block, in first-read order.
+    pub fn live_in(&self) -> Vec<u32> {
+        let mut registers = Vec::new();
+        for register_use in self.instructions.iter().flat_map(|entry| &entry.uses) {
+            if register_use.defined_by.is_none() && !registers.contains(&register_use.register) {
+                registers.push(register_use.register);
+            }
+        }
+        registers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::instruction::BasicInstructionDetail;
+
+    fn instruction(reads: &[u32], writes: &[u32]) -> Instruction {
+        let mut detail = BasicInstructionDetail::new("test");
+        for &reg in reads {
+            detail = detail.reads_register(reg);
+        }
+        for &reg in writes {
+            detail = detail.writes_register(reg);
+        }
+        Instruction::with_detail(0, Vec::new(), "op".into(), String::new(), Box::new(detail))
+    }
+
+    #[test]
+    fn test_def_use_links_reads_to_the_latest_write() {
+        // 0: a = ...; 1: b = a; 2: a = a + b; 3: use a
+        let analysis = DefUse::analyze(&[
+            instruction(&[], &[1]),
+            instruction(&[1], &[2]),
+            instruction(&[1, 2], &[1]),
+            instruction(&[1, 3], &[]),
+        ]);
+
+        assert_eq!(analysis.instructions[0].defs[0].used_by, [1, 2]);
+        assert_eq!(
+            analysis.instructions[2].uses,
+            [
+                RegisterUse {
+                    register: 1,
+                    defined_by: Some(0)
+                },
+                RegisterUse {
+                    register: 2,
+                    defined_by: Some(1)
+                },
+            ]
+        );
+        assert_eq!(analysis.instructions[2].defs[0].used_by, [3]);
+        assert_eq!(analysis.instructions[3].uses[1].defined_by, None);
+        assert_eq!(analysis.live_in(), [3]);
+        assert_eq!(analysis.last_writes, BTreeMap::from([(1, 2), (2, 1)]));
+
+        let analysis = DefUse::analyze_ignoring(&[instruction(&[0, 1], &[0])], &[0]);
+        assert_eq!(analysis.instructions[0].uses.len(), 1);
+        assert!(analysis.instructions[0].defs.is_empty());
+    }
+}
@@ -26,12 +26,44 @@ pub enum DecodeStatus {
     Unimplemented,
 }
 
+/// Where an encoding falls in the architecture's encoding space.
+///
+/// Decoded instructions are [`Defined`](Self::Defined),
+/// [`Hint`](Self::Hint), or [`LongEncoding`](Self::LongEncoding) (see
+/// [`DecodedInstruction::encoding_class`]);
+/// rejected ones are [`Reserved`](Self::Reserved) or
+/// [`Unknown`](Self::Unknown) (see
+/// [`DisasmError::encoding_class`](crate::types::error::DisasmError::encoding_class)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncodingClass {
+    /// An ordinary instruction.
+    Defined,
+    /// A HINT: a valid encoding whose architectural effect is nil, such as
+    /// a RISC-V computational instruction writing `x0`.
+    Hint,
+    /// An instruction longer than the decoder supports, recognised only by
+    /// its length prefix, such as a RISC-V 48- or 64-bit encoding.
+    LongEncoding,
+    /// Reserved by the specification; it does not decode.
+    Reserved,
+    /// Not an encoding the decoder knows.
+    Unknown,
+}
+
 /// Text output profiles derived from the shared IR.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TextRenderProfile {
     Capstone,
     Canonical,
     VerboseDebug,
+    /// `llvm-mc --disassemble` conventions: LLVM's printed aliases and
+    /// decimal immediates. Backends without an LLVM printer render their
+    /// Capstone text.
+    Llvm,
+    /// AT&T operand order and sigils (`mov $0x1, %eax`). Only x86 has an
+    /// AT&T printer; other backends render their Capstone text.
+    Att,
 }
 
 /// Function signature for architecture-specific instruction text rendering.
@@ -88,9 +120,113 @@ pub enum Operand {
     Memory {
         base: Option<RegisterId>,
         displacement: i64,
+        /// Ordering constraints of an atomic access; omitted from JSON when
+        /// the access is relaxed.
+        #[serde(skip_serializing_if = "MemoryOrdering::is_relaxed")]
+        ordering: MemoryOrdering,
+    },
+    /// An architecture-defined system register named by its encoded number,
+    /// such as a RISC-V CSR.
+    SystemRegister {
+        number: u32,
     },
 }
 
+/// Memory-ordering constraints of an atomic access, such as the RISC-V `aq`
+/// and `rl` bits of `lr`, `sc`, and the AMOs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+pub struct MemoryOrdering {
+    /// No later memory access may be observed before this one.
+    pub acquire: bool,
+    /// No earlier memory access may be observed after this one.
+    pub release: bool,
+}
+
+impl MemoryOrdering {
+    /// Neither acquire nor release: the ordering of a plain access.
+    pub const RELAXED: Self = Self {
+        acquire: false,
+        release: false,
+    };
+
+    /// Whether the access carries no ordering constraint.
+    pub const fn is_relaxed(&self) -> bool {
+        !self.acquire && !self.release
+    }
+}
+
+/// Operand kind without its payload, for counting and filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperandType {
+    Register,
+    Immediate,
+    Text,
+    Memory,
+    SystemRegister,
+}
+
+impl Operand {
+    /// The kind of this operand.
+    pub fn operand_type(&self) -> OperandType {
+        match self {
+            Operand::Register { .. } => OperandType::Register,
+            Operand::Immediate { .. } => OperandType::Immediate,
+            Operand::Text { .. } => OperandType::Text,
+            Operand::Memory { .. } => OperandType::Memory,
+            Operand::SystemRegister { .. } => OperandType::SystemRegister,
+        }
+    }
+}
+
+/// How an instruction accesses an operand (mirrors Capstone's `cs_ac_type`).
+///
+/// For memory operands this describes the memory access, not the base register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+pub struct Access {
+    /// The operand is read.
+    pub read: bool,
+    /// The operand is written.
+    pub write: bool,
+}
+
+impl Access {
+    pub const fn read() -> Self {
+        Self {
+            read: true,
+            write: false,
+        }
+    }
+
+    pub const fn write() -> Self {
+        Self {
+            read: false,
+            write: true,
+        }
+    }
+
+    pub const fn read_write() -> Self {
+        Self {
+            read: true,
+            write: true,
+        }
+    }
+
+    pub const fn none() -> Self {
+        Self {
+            read: false,
+            write: false,
+        }
+    }
+}
+
+/// An operand together with how the instruction accesses it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TypedOperand {
+    #[serde(flatten)]
+    pub operand: Operand,
+    pub access: Access,
+}
+
 /// Display-oriented rendering hints derived from the structured decode result.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
 pub struct RenderHints {
@@ -113,6 +249,10 @@ pub struct DecodedInstruction {
     pub size: usize,
     pub raw_bytes: Vec<u8>,
     pub operands: Vec<Operand>,
+    /// Per-operand access, parallel to `operands`. Backends that do not track
+    /// access leave this empty; see [`DecodedInstruction::typed_operands`].
+    #[serde(skip)]
+    pub operand_access: Vec<Access>,
     pub registers_read: Vec<RegisterId>,
     pub registers_written: Vec<RegisterId>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -124,6 +264,11 @@ pub struct DecodedInstruction {
     pub status: DecodeStatus,
     #[serde(default)]
     pub render_hints: RenderHints,
+    /// Name of the decoder extension that recognised the encoding, such as
+    /// `"C"` or `"XTheadCondMov"` on RISC-V. `None` for backends with a
+    /// single decoder and for encodings no extension claimed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded_by: Option<&'static str>,
     /// Optional architecture-specific renderer. Set by architecture crates
     /// (e.g. `robustone-riscv`) so that text rendering can happen outside
     /// `robustone-core`.
@@ -145,6 +290,12 @@ impl DecodedInstruction {
         self
     }
 
+    /// Record the decoder extension that recognised this instruction.
+    pub fn with_decoded_by(mut self, extension: &'static str) -> Self {
+        self.decoded_by = Some(extension);
+        self
+    }
+
     /// Set a Capstone-facing alias mnemonic and optional hidden operands.
     pub fn with_capstone_alias(
         mut self,
@@ -162,6 +313,56 @@ impl DecodedInstruction {
         self
     }
 
+    /// [`EncodingClass::Hint`] or [`EncodingClass::LongEncoding`] when the
+    /// backend tagged the instruction with the `hint` or `long_encoding`
+    /// group, [`EncodingClass::Defined`] otherwise.
+    pub fn encoding_class(&self) -> EncodingClass {
+        if self.groups.iter().any(|group| group == "hint") {
+            EncodingClass::Hint
+        } else if self.groups.iter().any(|group| group == "long_encoding") {
+            EncodingClass::LongEncoding
+        } else {
+            EncodingClass::Defined
+        }
+    }
+
+    /// Whether the encoding has no architectural effect: the canonical `nop`
+    /// or a HINT, tagged by the backend with the `nop` or `hint` group.
+    pub fn is_no_op(&self) -> bool {
+        self.groups
+            .iter()
+            .any(|group| group == "nop" || group == "hint")
+    }
+
+    /// Pair each operand with its access.
+    ///
+    /// Without backend-reported access, register operands are classified from
+    /// `registers_read`/`registers_written` and other operands get
+    /// [`Access::none`].
+    pub fn typed_operands(&self) -> Vec<TypedOperand> {
+        self.operands
+            .iter()
+            .enumerate()
+            .map(|(index, operand)| {
+                let access =
+                    self.operand_access
+                        .get(index)
+                        .copied()
+                        .unwrap_or_else(|| match operand {
+                            Operand::Register { register } => Access {
+                                read: self.registers_read.contains(register),
+                                write: self.registers_written.contains(register),
+                            },
+                            _ => Access::none(),
+                        });
+                TypedOperand {
+                    operand: operand.clone(),
+                    access,
+                }
+            })
+            .collect()
+    }
+
     /// Render the instruction into mnemonic / operands text using the shared IR.
     pub fn render_text_parts(&self, profile: TextRenderProfile) -> (String, String) {
         self.render_text_parts_with_options(
@@ -233,6 +434,7 @@ fn format_generic_operand(operand: &Operand) -> String {
         Operand::Memory {
             base: Some(base),
             displacement,
+            ..
         } => {
             format!(
                 "{}({}:{})",
@@ -244,7 +446,9 @@ fn format_generic_operand(operand: &Operand) -> String {
         Operand::Memory {
             base: None,
             displacement,
+            ..
         } => displacement.to_string(),
+        Operand::SystemRegister { number } => format!("sysreg:{number:#x}"),
     }
 }
 
@@ -262,6 +466,7 @@ mod tests {
             size: 4,
             raw_bytes: vec![0; 4],
             operands,
+            operand_access: Vec::new(),
             registers_read: Vec::new(),
             registers_written: Vec::new(),
             implicit_registers_read: Vec::new(),
@@ -269,6 +474,7 @@ mod tests {
             groups: Vec::new(),
             status: DecodeStatus::Success,
             render_hints: RenderHints::default(),
+            decoded_by: None,
             render: None,
         }
     }
@@ -303,6 +509,7 @@ mod tests {
                 Operand::Memory {
                     base: Some(RegisterId::riscv(2)),
                     displacement: 8,
+                    ordering: MemoryOrdering::RELAXED,
                 },
             ],
         );
@@ -350,4 +557,32 @@ mod tests {
         // Generic renderer does not apply capstone mnemonic aliases
         assert_eq!(mnemonic, "addi");
     }
+
+    #[test]
+    fn typed_operands_fall_back_to_register_lists() {
+        let mut instruction = sample_instruction(
+            "addi",
+            vec![
+                Operand::Register {
+                    register: RegisterId::riscv(1),
+                },
+                Operand::Register {
+                    register: RegisterId::riscv(2),
+                },
+                Operand::Immediate { value: 1 },
+            ],
+        );
+        instruction.registers_read = vec![RegisterId::riscv(2)];
+        instruction.registers_written = vec![RegisterId::riscv(1)];
+
+        let accesses: Vec<_> = instruction
+            .typed_operands()
+            .into_iter()
+            .map(|operand| operand.access)
+            .collect();
+        assert_eq!(accesses, [Access::write(), Access::read(), Access::none()]);
+
+        instruction.operand_access = vec![Access::write(), Access::read(), Access::read()];
+        assert_eq!(instruction.typed_operands()[2].access, Access::read());
+    }
 }
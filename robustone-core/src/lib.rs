@@ -29,7 +29,7 @@
 //! let dispatcher = dispatcher();
 //! match dispatcher.disassemble_bytes(&[0x93, 0x00, 0x10, 0x00], "riscv32", 0x1000) {
 //!     Ok((instruction, size)) => {
-//!         println!("Instruction: {} {}", instruction.mnemonic, instruction.operands);
+//!         println!("Instruction: {instruction}");
 //!     }
 //!     Err(DisasmError::UnsupportedArchitecture(arch)) => {
 //!         eprintln!("Architecture '{}' not supported", arch);
@@ -43,51 +43,151 @@
 //! `ArchitectureDispatcher::new()` and `ArchitectureDispatcher::default()` return an
 //! empty dispatcher with no handlers registered. You must call `register()` to add
 //! architecture backends before disassembling.
+//!
+//! # Concurrency
+//!
+//! Decoding takes `&self` and handlers keep no per-call state, so one
+//! [`ArchitectureDispatcher`] can serve any number of threads at once, from
+//! an `Arc` or a `static`. Handlers must be `Send + Sync`, and the results
+//! ([`Instruction`], [`DecodedInstruction`], [`DisasmError`]) are
+//! `Send + Sync` too, so they can be handed back to whichever thread asked.
+//! Configuration such as [`ArchitectureDispatcher::set_detail`] takes
+//! `&mut self` and belongs before the dispatcher is shared.
 
 pub mod architecture;
+pub mod classify;
 pub mod common;
+pub mod dataflow;
 pub mod ir;
+pub mod recovery;
+#[doc(hidden)]
 pub mod render;
+pub mod stats;
 pub mod traits;
 pub mod types;
+#[doc(hidden)]
 pub mod utils;
 
+/// Helpers shared by the bundled backends and the CLI: text rendering
+/// internals, hex parsing, bit-field decoding and encoding, and endianness
+/// conversion. The render options and the JSON envelope are in the prelude.
+///
+/// They are public so the workspace crates can use them, but they are not
+/// part of the stable surface and may change in any minor release. The
+/// `render` and `utils` paths at the crate root are the same modules.
+#[doc(hidden)]
+pub mod internal {
+    pub use crate::render;
+    pub use crate::utils;
+}
+
 /// Robustone prelude.
 ///
-/// Re-exports frequently used types and traits for convenient importing.
-/// This module provides access to the most common functionality needed for
-/// using the disassembly engine.
+/// `use robustone_core::prelude::*` imports the stable library surface,
+/// [`prelude::v2`](v2). Items outside it are not covered by the versioning
+/// policy in `docs/public-contract.md`.
 pub mod prelude {
-    pub use crate::architecture::{
-        Architecture, ArchitectureCapability, all_architecture_capabilities,
-        canonical_architecture_name, is_address_aligned, lookup_architecture_capability,
-    };
-    pub use crate::common::ArchitectureProfile;
-    pub use crate::ir::{ArchitectureId, DecodeStatus, DecodedInstruction, Operand, RegisterId};
-    pub use crate::render::{
-        RenderOptions, RenderedDisassembly, RenderedInstruction, RenderedIssue, render_disassembly,
-        render_instruction_text,
-    };
-    pub use crate::traits::{ArchitectureHandler, BasicInstructionDetail, Detail};
-    pub use crate::types::{DisasmError, Instruction};
-    pub use crate::utils::{Endianness, HexParser};
+    pub use self::v2::*;
+
+    /// The stable surface: the [`ArchitectureDispatcher`] handle, the
+    /// [`Instruction`] and [`DecodedInstruction`] it produces with their
+    /// typed operands, the options [`Instruction::to_line`] and
+    /// [`render_disassembly`] take and the JSON envelope it builds,
+    /// architecture lookup, the [`ArchitectureHandler`] plumbing for
+    /// backends, and errors.
+    ///
+    /// Within a minor version this list only grows; `tests/api_surface.rs`
+    /// names every item. Parsing and bit-field helpers are deliberately left
+    /// out:
+    ///
+    /// ```compile_fail
+    /// use robustone_core::prelude::*;
+    ///
+    /// let _ = HexParser::new();
+    /// ```
+    pub mod v2 {
+        pub use crate::ArchitectureDispatcher;
+        pub use crate::architecture::{
+            AddressWidth, ArchSpec, ArchSpecError, Architecture, ArchitectureCapability,
+            ModeModifiers, all_architecture_capabilities, canonical_architecture_name,
+            lookup_architecture_capability,
+        };
+        pub use crate::common::ArchitectureProfile;
+        pub use crate::ir::{
+            Access, ArchitectureId, DecodeStatus, DecodedInstruction, EncodingClass, Operand,
+            OperandType, RegisterId, TextRenderProfile, TypedOperand,
+        };
+        pub use crate::recovery::{DataStep, OnDecodeError};
+        pub use crate::render::{
+            NopStyle, OutputStyle, RenderOptions, RenderedDisassembly, RenderedInstruction,
+            RenderedIssue, render_disassembly,
+        };
+        pub use crate::stats::{DecodeStats, HandlerStats};
+        pub use crate::traits::{
+            ArchitectureHandler, BasicInstructionDetail, Detail, SemanticFlags,
+        };
+        pub use crate::types::error::{DecodeErrorKind, DecodeFailure};
+        pub use crate::types::{DisasmError, Instruction};
+    }
+
+    /// The prelude before v2, which also re-exported the renderers, the hex
+    /// and endianness helpers, and handler introspection types.
+    #[deprecated(
+        note = "use `robustone_core::prelude` (v2); renderers and helpers are in `robustone_core::internal`"
+    )]
+    pub mod v1 {
+        pub use crate::architecture::{
+            AddressWidth, ArchSpec, ArchSpecError, Architecture, ArchitectureCapability,
+            ModeModifiers, all_architecture_capabilities, canonical_architecture_name,
+            is_address_aligned, lookup_architecture_capability,
+        };
+        pub use crate::common::ArchitectureProfile;
+        pub use crate::dataflow::DefUse;
+        pub use crate::ir::{
+            Access, ArchitectureId, DecodeStatus, DecodedInstruction, EncodingClass, Operand,
+            OperandType, RegisterId, TypedOperand,
+        };
+        pub use crate::recovery::{DataStep, OnDecodeError};
+        pub use crate::render::{
+            OutputStyle, RenderOptions, RenderedDisassembly, RenderedInstruction, RenderedIssue,
+            render_disassembly, render_instruction_text,
+        };
+        pub use crate::traits::{
+            ArchitectureHandler, BasicInstructionDetail, Detail, EncodingBreakdown, EncodingField,
+            InstructionMetadata, LatencyClass, MnemonicGroup, PrivilegeLevel, RegisterClass,
+            RegisterInfo, SemanticFlags,
+        };
+        pub use crate::types::{DisasmError, Instruction};
+        pub use crate::utils::{Endianness, HexParser};
+    }
 }
 
 pub use architecture::{
-    ArchitectureCapability, all_architecture_capabilities, canonical_architecture_name,
-    lookup_architecture_capability,
+    AddressWidth, ArchSpec, ArchSpecError, ArchitectureCapability, ModeModifiers,
+    all_architecture_capabilities, canonical_architecture_name, lookup_architecture_capability,
 };
-pub use ir::DecodedInstruction;
+pub use classify::{RegionClass, RegionReport, classify_regions, shannon_entropy};
+pub use dataflow::DefUse;
+pub use ir::{Access, DecodedInstruction, EncodingClass, OperandType, TypedOperand};
+pub use recovery::{DataStep, OnDecodeError};
 pub use render::{
-    RenderOptions, RenderedDisassembly, RenderedInstruction, RenderedIssue, render_disassembly,
-    render_instruction_text,
+    NopStyle, OutputStyle, RenderOptions, RenderedDisassembly, RenderedInstruction, RenderedIssue,
+    render_disassembly,
+};
+#[doc(hidden)]
+pub use render::{is_data_directive, render_instruction_text};
+pub use stats::{DecodeStats, HandlerStats};
+pub use traits::instruction::{Detail, SemanticFlags};
+pub use traits::{
+    ArchitectureHandler, EncodingBreakdown, EncodingField, InstructionMetadata, LatencyClass,
+    MnemonicGroup, PrivilegeLevel, RegisterClass, RegisterInfo,
 };
-pub use traits::ArchitectureHandler;
-pub use traits::instruction::Detail;
 pub use types::error::DisasmError;
 pub use types::instruction::Instruction;
 
+use crate::stats::HandlerCounters;
 use crate::utils::HexParser;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Runtime dispatcher that selects the appropriate architecture handler.
 ///
@@ -99,17 +199,37 @@ use crate::utils::HexParser;
 /// # Thread Safety
 ///
 /// The dispatcher is thread-safe and can be shared across multiple threads
-/// since all handlers are required to implement `Sync`.
+/// (or stored in a `static`) since all handlers are required to implement
+/// `Send + Sync`.
+///
+/// # Statistics
+///
+/// Every dispatched decode is counted against the handler that served it;
+/// read the counters with [`Self::stats`].
 pub struct ArchitectureDispatcher {
     handlers: Vec<Box<dyn ArchitectureHandler>>,
+    counters: Vec<HandlerCounters>,
+    unsupported: AtomicU64,
     hex_parser: HexParser,
 }
 
+// Dispatchers are shared through `static`s and `Arc`s; keep them and their
+// results thread-safe.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ArchitectureDispatcher>();
+    assert_send_sync::<Instruction>();
+    assert_send_sync::<DecodedInstruction>();
+    assert_send_sync::<DisasmError>();
+};
+
 impl ArchitectureDispatcher {
     /// Creates a new empty dispatcher.
     pub fn new() -> Self {
         Self {
             handlers: Vec::new(),
+            counters: Vec::new(),
+            unsupported: AtomicU64::new(0),
             hex_parser: HexParser::new(),
         }
     }
@@ -124,6 +244,7 @@ impl ArchitectureDispatcher {
     /// * `handler` - A boxed architecture handler to register
     pub fn register(&mut self, handler: Box<dyn ArchitectureHandler>) {
         self.handlers.push(handler);
+        self.counters.push(HandlerCounters::default());
     }
 
     /// Sets the detail flag on all registered handlers.
@@ -226,7 +347,7 @@ impl ArchitectureDispatcher {
     /// let bytes = [0x93, 0x00, 0x10, 0x00]; // addi ra, zero, 1
     /// match dispatcher.disassemble_bytes(&bytes, "riscv32", 0x1000) {
     ///     Ok((instruction, size)) => {
-    ///         println!("Instruction: {} {}", instruction.mnemonic, instruction.operands);
+    ///         println!("Instruction: {instruction}");
     ///         println!("Size: {} bytes", size);
     ///     }
     ///     Err(e) => eprintln!("Error: {:?}", e),
@@ -239,14 +360,29 @@ impl ArchitectureDispatcher {
         address: u64,
     ) -> Result<(Instruction, usize), DisasmError> {
         // Find the first handler that supports this architecture
-        for handler in &self.handlers {
+        for (handler, counters) in self.handlers.iter().zip(&self.counters) {
             if handler.supports(arch) {
-                return handler.disassemble(bytes, arch, address);
+                let result = checked_size(handler.disassemble(bytes, arch, address), arch, bytes);
+                trace_decode(handler.name(), arch, bytes, address, &result);
+                counters.record(&result);
+                return result;
             }
         }
 
         // No handler found for this architecture
-        Err(DisasmError::UnsupportedArchitecture(arch.to_string()))
+        Err(self.unsupported(arch))
+    }
+
+    /// Parse `hex` into instruction bytes for `arch`, accepting the same
+    /// spellings as the CLI (`0x` prefixes, whitespace between bytes).
+    ///
+    /// ```rust
+    /// use robustone_core::ArchitectureDispatcher;
+    /// let dispatcher = ArchitectureDispatcher::new();
+    /// assert_eq!(dispatcher.parse_hex("0x9300 1000", "riscv32").unwrap(), [0x93, 0, 0x10, 0]);
+    /// ```
+    pub fn parse_hex(&self, hex: &str, arch: &str) -> Result<Vec<u8>, DisasmError> {
+        self.hex_parser.parse_for_architecture(hex, arch)
     }
 
     /// Decode raw instruction bytes into the shared IR.
@@ -256,13 +392,20 @@ impl ArchitectureDispatcher {
         arch: &str,
         address: u64,
     ) -> Result<(DecodedInstruction, usize), DisasmError> {
-        for handler in &self.handlers {
+        for (handler, counters) in self.handlers.iter().zip(&self.counters) {
             if handler.supports(arch) {
-                return handler.decode_instruction(bytes, arch, address);
+                let result = checked_size(
+                    handler.decode_instruction(bytes, arch, address),
+                    arch,
+                    bytes,
+                );
+                trace_decode(handler.name(), arch, bytes, address, &result);
+                counters.record(&result);
+                return result;
             }
         }
 
-        Err(DisasmError::UnsupportedArchitecture(arch.to_string()))
+        Err(self.unsupported(arch))
     }
 
     /// Decode bytes using an explicit architecture profile.
@@ -272,15 +415,20 @@ impl ArchitectureDispatcher {
         profile: &crate::common::ArchitectureProfile,
         address: u64,
     ) -> Result<(DecodedInstruction, usize), DisasmError> {
-        for handler in &self.handlers {
+        for (handler, counters) in self.handlers.iter().zip(&self.counters) {
             if handler.supports(profile.mode_name) {
-                return handler.decode_instruction_with_profile(bytes, profile, address);
+                let result = checked_size(
+                    handler.decode_instruction_with_profile(bytes, profile, address),
+                    profile.mode_name,
+                    bytes,
+                );
+                trace_decode(handler.name(), profile.mode_name, bytes, address, &result);
+                counters.record(&result);
+                return result;
             }
         }
 
-        Err(DisasmError::UnsupportedArchitecture(
-            profile.architecture.as_str().to_string(),
-        ))
+        Err(self.unsupported(profile.architecture.as_str()))
     }
 
     /// Disassemble bytes using an explicit architecture profile.
@@ -290,15 +438,20 @@ impl ArchitectureDispatcher {
         profile: &crate::common::ArchitectureProfile,
         address: u64,
     ) -> Result<(Instruction, usize), DisasmError> {
-        for handler in &self.handlers {
+        for (handler, counters) in self.handlers.iter().zip(&self.counters) {
             if handler.supports(profile.mode_name) {
-                return handler.disassemble_with_profile(bytes, profile, address);
+                let result = checked_size(
+                    handler.disassemble_with_profile(bytes, profile, address),
+                    profile.mode_name,
+                    bytes,
+                );
+                trace_decode(handler.name(), profile.mode_name, bytes, address, &result);
+                counters.record(&result);
+                return result;
             }
         }
 
-        Err(DisasmError::UnsupportedArchitecture(
-            profile.architecture.as_str().to_string(),
-        ))
+        Err(self.unsupported(profile.architecture.as_str()))
     }
 
     /// Returns a list of all registered architecture names.
@@ -376,6 +529,44 @@ impl ArchitectureDispatcher {
             .find(|h| h.supports(arch_name))
             .map(|h| h.as_ref())
     }
+
+    /// Returns a snapshot of the decode counters: instructions decoded,
+    /// attempts rejected and bytes consumed per handler, plus requests for
+    /// architectures no handler supports.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let dispatcher = robustone::shared_dispatcher();
+    /// dispatcher.disassemble_bytes(&[0x13, 0x00, 0x00, 0x00], "riscv32", 0).unwrap();
+    /// let stats = dispatcher.stats();
+    /// assert!(stats.handler("riscv").unwrap().decoded >= 1);
+    /// println!("{}", stats.to_prometheus("robustone"));
+    /// ```
+    pub fn stats(&self) -> DecodeStats {
+        DecodeStats {
+            handlers: self
+                .handlers
+                .iter()
+                .zip(&self.counters)
+                .map(|(handler, counters)| counters.snapshot(handler.name()))
+                .collect(),
+            unsupported: self.unsupported.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Resets every decode counter to zero.
+    pub fn reset_stats(&self) {
+        for counters in &self.counters {
+            counters.reset();
+        }
+        self.unsupported.store(0, Ordering::Relaxed);
+    }
+
+    fn unsupported(&self, arch: &str) -> DisasmError {
+        self.unsupported.fetch_add(1, Ordering::Relaxed);
+        DisasmError::UnsupportedArchitecture(arch.to_string())
+    }
 }
 
 impl Default for ArchitectureDispatcher {
@@ -384,6 +575,105 @@ impl Default for ArchitectureDispatcher {
     }
 }
 
+/// Reject sizes a handler should never report: zero (which would stall a
+/// decode loop) or more bytes than it was given. Callers slice the input by
+/// the returned size, so this keeps a faulty handler from causing a panic.
+fn checked_size<T>(
+    result: Result<(T, usize), DisasmError>,
+    mode: &str,
+    bytes: &[u8],
+) -> Result<(T, usize), DisasmError> {
+    match result {
+        Ok((_, 0)) => Err(DisasmError::DecodingError(
+            "decoder returned a zero-length instruction".to_string(),
+        )),
+        Ok((_, size)) if size > bytes.len() => Err(DisasmError::truncated(
+            Some(mode.to_string()),
+            size,
+            bytes.len(),
+        )),
+        other => other,
+    }
+}
+
+/// Record the outcome of a dispatched decode through the enabled
+/// diagnostics facades (`tracing`, `log`, and `defmt` features).
+///
+/// Successful decodes are logged at `TRACE`; failures are logged at `DEBUG`
+/// with the handler, mode, address, and the leading bytes that were rejected
+/// so misdecodes reported from the field can be reproduced. `defmt` gets the
+/// error's stable kind and code instead of its message, to keep formatting
+/// off the target; it is only wired up on bare-metal targets.
+#[cfg(any(
+    feature = "tracing",
+    feature = "log",
+    all(feature = "defmt", target_os = "none")
+))]
+fn trace_decode<T>(
+    handler: &'static str,
+    mode: &str,
+    bytes: &[u8],
+    address: u64,
+    result: &Result<(T, usize), DisasmError>,
+) {
+    match result {
+        Ok((_, size)) => {
+            let decoded = &bytes[..(*size).min(bytes.len())];
+            #[cfg(feature = "tracing")]
+            tracing::trace!(handler, mode, address, size, bytes = %hex::encode(decoded), "decoded instruction");
+            #[cfg(feature = "log")]
+            log::trace!(
+                "{handler} {mode} {address:#x}: decoded {size} bytes ({})",
+                hex::encode(decoded)
+            );
+            #[cfg(all(feature = "defmt", target_os = "none"))]
+            defmt::trace!(
+                "{=str} {=str} {=u64:#x}: decoded {=usize} bytes ({=[u8]:02x})",
+                handler,
+                mode,
+                address,
+                size,
+                decoded
+            );
+        }
+        Err(error) => {
+            let rejected = &bytes[..bytes.len().min(8)];
+            #[cfg(feature = "tracing")]
+            tracing::debug!(handler, mode, address, bytes = %hex::encode(rejected), %error, "decode failed");
+            #[cfg(feature = "log")]
+            log::debug!(
+                "{handler} {mode} {address:#x}: decode failed ({}): {error}",
+                hex::encode(rejected)
+            );
+            #[cfg(all(feature = "defmt", target_os = "none"))]
+            defmt::debug!(
+                "{=str} {=str} {=u64:#x}: decode failed ({=[u8]:02x}): {=str} ({=u16})",
+                handler,
+                mode,
+                address,
+                rejected,
+                error.stable_kind(),
+                error.code()
+            );
+        }
+    }
+}
+
+#[cfg(not(any(
+    feature = "tracing",
+    feature = "log",
+    all(feature = "defmt", target_os = "none")
+)))]
+#[inline(always)]
+fn trace_decode<T>(
+    _handler: &'static str,
+    _mode: &str,
+    _bytes: &[u8],
+    _address: u64,
+    _result: &Result<(T, usize), DisasmError>,
+) {
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -432,6 +722,88 @@ mod tests {
         assert_eq!(decoded.render_hints.capstone_hidden_operands, vec![1]);
     }
 
+    #[test]
+    fn test_dispatch_applies_the_xlen_named_by_the_arch_string() {
+        let dispatcher = dispatcher_with_riscv();
+        // ld a0, 0(a0) and slli a0, a0, 33 are RV64-only.
+        for bytes in [[0x03, 0x35, 0x05, 0x00], [0x13, 0x15, 0x15, 0x02]] {
+            let error = dispatcher
+                .decode_instruction(&bytes, "riscv32", 0)
+                .expect_err("RV32 should reject RV64-only encodings");
+            assert!(matches!(
+                error.stable_kind(),
+                "unsupported_mode" | "invalid_encoding"
+            ));
+            for arch in ["riscv64", "rv64gc"] {
+                let (decoded, _) = dispatcher
+                    .decode_instruction(&bytes, arch, 0)
+                    .expect("RV64 should decode");
+                assert_eq!(decoded.mode, "riscv64");
+            }
+        }
+
+        let (decoded, _) = dispatcher
+            .decode_instruction(&[0x13, 0x55, 0x15, 0x42], "riscv64", 0)
+            .expect("srai with a 6-bit shamt should decode");
+        assert_eq!(decoded.mnemonic, "srai");
+    }
+
+    /// Reports whatever size it was built with, regardless of the input.
+    struct FixedSizeHandler(usize);
+
+    impl ArchitectureHandler for FixedSizeHandler {
+        fn decode_instruction(
+            &self,
+            _bytes: &[u8],
+            _arch_name: &str,
+            _addr: u64,
+        ) -> Result<(DecodedInstruction, usize), DisasmError> {
+            Err(DisasmError::DecodingError("unused".to_string()))
+        }
+
+        fn disassemble(
+            &self,
+            _bytes: &[u8],
+            _arch_name: &str,
+            addr: u64,
+        ) -> Result<(Instruction, usize), DisasmError> {
+            let instruction = Instruction::new(addr, Vec::new(), "op".into(), String::new());
+            Ok((instruction, self.0))
+        }
+
+        fn name(&self) -> &'static str {
+            "fixed"
+        }
+
+        fn supports(&self, arch_name: &str) -> bool {
+            arch_name == "fixed"
+        }
+    }
+
+    #[test]
+    fn test_dispatcher_rejects_sizes_outside_the_input() {
+        let mut dispatcher = ArchitectureDispatcher::new();
+        dispatcher.register(Box::new(FixedSizeHandler(8)));
+        let error = dispatcher
+            .disassemble_bytes(&[0; 4], "fixed", 0)
+            .expect_err("an overlong size should be rejected");
+        assert!(matches!(
+            error,
+            DisasmError::Truncated {
+                needed: 8,
+                available: 4,
+                ..
+            }
+        ));
+
+        let mut dispatcher = ArchitectureDispatcher::new();
+        dispatcher.register(Box::new(FixedSizeHandler(0)));
+        let error = dispatcher
+            .disassemble_bytes(&[0; 4], "fixed", 0)
+            .expect_err("a zero size should be rejected");
+        assert_eq!(error.stable_kind(), "decoding_error");
+    }
+
     #[test]
     fn test_invalid_encoding_returns_structured_error() {
         let dispatcher = dispatcher_with_riscv();
@@ -441,6 +813,32 @@ mod tests {
         assert_eq!(error.stable_kind(), "invalid_encoding");
     }
 
+    #[test]
+    fn test_dispatcher_counts_decodes_per_handler() {
+        let dispatcher = dispatcher_with_riscv();
+        dispatcher
+            .disassemble_bytes(&[0x13, 0x00, 0x00, 0x00], "riscv32", 0)
+            .unwrap();
+        dispatcher
+            .decode_instruction(&[0x82, 0x80], "riscv64", 4)
+            .unwrap();
+        assert!(
+            dispatcher
+                .decode_instruction(&[0xff, 0xff, 0xff, 0xff], "riscv32", 6)
+                .is_err()
+        );
+        assert!(dispatcher.disassemble_bytes(&[0x00], "z80", 0).is_err());
+
+        let stats = dispatcher.stats();
+        let riscv = stats.handler("riscv").unwrap();
+        assert_eq!((riscv.decoded, riscv.unknown, riscv.bytes), (2, 1, 6));
+        assert_eq!(stats.unsupported, 1);
+
+        dispatcher.reset_stats();
+        assert_eq!(dispatcher.stats().decoded(), 0);
+        assert_eq!(dispatcher.stats().unsupported, 0);
+    }
+
     #[test]
     fn test_decode_with_profile_enforces_enabled_extensions() {
         let profile = ArchitectureProfile::riscv(
@@ -539,14 +937,14 @@ mod tests {
     fn test_rv64_only_lr_d_reports_unsupported_mode_on_rv32() {
         let dispatcher = dispatcher_with_riscv();
         let (decoded, size) = dispatcher
-            .decode_instruction(&[0x2f, 0xb4, 0x02, 0x12], "riscv64", 0)
+            .decode_instruction(&[0x2f, 0xb4, 0x02, 0x10], "riscv64", 0)
             .expect("RV64 should decode lr.d");
 
         assert_eq!(size, 4);
         assert_eq!(decoded.mnemonic, "lr.d");
 
         let error = dispatcher
-            .decode_instruction(&[0x2f, 0xb4, 0x02, 0x12], "riscv32", 0)
+            .decode_instruction(&[0x2f, 0xb4, 0x02, 0x10], "riscv32", 0)
             .expect_err("RV32 should reject lr.d with unsupported_mode");
 
         assert_eq!(error.stable_kind(), "unsupported_mode");
@@ -733,4 +1131,41 @@ mod tests {
             "unsupported_extension"
         );
     }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_decode_failures_reach_log_facade() {
+        use std::sync::Mutex;
+
+        struct Capture(Mutex<Vec<String>>);
+
+        impl log::Log for Capture {
+            fn enabled(&self, _: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                self.0.lock().unwrap().push(record.args().to_string());
+            }
+
+            fn flush(&self) {}
+        }
+
+        static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+        log::set_logger(&CAPTURE).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let dispatcher = dispatcher_with_riscv();
+        assert!(
+            dispatcher
+                .disassemble_bytes(&[0xff, 0xff, 0xff, 0xff], "riscv32", 0x1000)
+                .is_err()
+        );
+        let records = CAPTURE.0.lock().unwrap();
+        assert!(
+            records
+                .iter()
+                .any(|record| record.contains("0x1000: decode failed (ffffffff)"))
+        );
+    }
 }
@@ -0,0 +1,109 @@
+//! Decode error recovery policy.
+//!
+//! A disassembly run over a buffer has to decide what happens when an
+//! instruction fails to decode: give up, list the bytes as data, or drop
+//! them and resynchronise. [`OnDecodeError`] names that choice so the
+//! library sweep and the CLI engine apply it the same way.
+
+/// How many bytes each data item covers when undecodable bytes are listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataStep {
+    /// The architecture's resynchronisation step: two bytes at an even
+    /// RISC-V address, so decoding resumes on a parcel boundary, and one
+    /// byte everywhere else.
+    #[default]
+    Resync,
+    /// A fixed number of bytes; zero counts as one.
+    Bytes(usize),
+}
+
+impl DataStep {
+    /// Bytes covered by one step at `address` on `arch`, with `remaining`
+    /// bytes of input left.
+    pub fn size(self, arch: &str, address: u64, remaining: usize) -> usize {
+        let size = match self {
+            DataStep::Resync if arch.starts_with("riscv") && address.is_multiple_of(2) => 2,
+            DataStep::Resync => 1,
+            DataStep::Bytes(size) => size.max(1),
+        };
+        size.min(remaining)
+    }
+}
+
+/// What a disassembly run does when an instruction fails to decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnDecodeError {
+    /// End the run and return the error.
+    #[default]
+    Stop,
+    /// List the bytes as data items, one [`DataStep`] at a time, and keep
+    /// decoding after them. This is Capstone's SKIPDATA.
+    Emit(DataStep),
+    /// Drop the bytes up to the next address aligned to the given boundary
+    /// and keep decoding there. Boundaries below two drop a single byte.
+    SkipAlign(usize),
+    /// Let the caller's error callback choose, failure by failure. Without
+    /// a callback this behaves like [`OnDecodeError::Stop`].
+    Callback,
+}
+
+impl OnDecodeError {
+    /// The policy SKIPDATA-style options select: `Emit` when `skip_data` is
+    /// set, `Stop` otherwise.
+    pub fn from_skip_data(skip_data: bool) -> Self {
+        if skip_data {
+            OnDecodeError::Emit(DataStep::Resync)
+        } else {
+            OnDecodeError::Stop
+        }
+    }
+
+    /// Whether undecodable bytes end up in the listing as data.
+    pub fn emits_data(self) -> bool {
+        matches!(self, OnDecodeError::Emit(_))
+    }
+
+    /// Bytes to step over after a failure at `address` on `arch`, with
+    /// `remaining` bytes of input left, or `None` when the run stops.
+    ///
+    /// `Callback` is resolved by the caller and yields `None` here.
+    pub fn step(self, arch: &str, address: u64, remaining: usize) -> Option<usize> {
+        match self {
+            OnDecodeError::Stop | OnDecodeError::Callback => None,
+            OnDecodeError::Emit(step) => Some(step.size(arch, address, remaining)),
+            OnDecodeError::SkipAlign(boundary) => {
+                let boundary = boundary.max(1) as u64;
+                let skip = (boundary - address % boundary) as usize;
+                Some(skip.min(remaining))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_follow_policy() {
+        let emit = OnDecodeError::Emit(DataStep::Resync);
+        assert_eq!(emit.step("riscv64", 0x1000, 8), Some(2));
+        assert_eq!(emit.step("riscv64", 0x1001, 8), Some(1));
+        assert_eq!(emit.step("riscv64", 0x1000, 1), Some(1));
+        assert_eq!(emit.step("x86_64", 0x1000, 8), Some(1));
+        assert_eq!(
+            OnDecodeError::Emit(DataStep::Bytes(4)).step("arm", 0, 3),
+            Some(3)
+        );
+
+        let skip = OnDecodeError::SkipAlign(4);
+        assert_eq!(skip.step("riscv64", 0x1000, 8), Some(4));
+        assert_eq!(skip.step("riscv64", 0x1002, 8), Some(2));
+        assert_eq!(OnDecodeError::SkipAlign(0).step("arm", 7, 8), Some(1));
+
+        assert_eq!(OnDecodeError::Stop.step("riscv64", 0, 8), None);
+        assert_eq!(OnDecodeError::Callback.step("riscv64", 0, 8), None);
+        assert_eq!(OnDecodeError::from_skip_data(true), emit);
+        assert!(!OnDecodeError::from_skip_data(false).emits_data());
+    }
+}
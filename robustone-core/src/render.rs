@@ -10,6 +10,64 @@ pub struct RenderOptions {
     pub capstone_aliases: bool,
     pub compressed_aliases: bool,
     pub unsigned_immediate: bool,
+    pub nops: NopStyle,
+}
+
+/// How encodings without architectural effect ([`DecodedInstruction::is_no_op`])
+/// are rendered, such as `addi zero, zero, 0` and the RISC-V HINTs that
+/// write `x0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NopStyle {
+    /// Whatever the text profile prints: Capstone keeps `addi zero, zero, 0`,
+    /// `llvm-mc` prints `nop` for the canonical encoding only.
+    #[default]
+    Profile,
+    /// Print every no-op as `nop` (`c.nop` for compressed encodings in the
+    /// canonical profile), dropping its operands.
+    Collapse,
+    /// Print every no-op as the instruction it encodes, without aliases.
+    Literal,
+}
+
+impl Default for RenderOptions {
+    /// Capstone-compatible text, as printed by the CLI without display flags.
+    fn default() -> Self {
+        Self {
+            text_profile: TextRenderProfile::Capstone,
+            alias_regs: false,
+            capstone_aliases: true,
+            compressed_aliases: true,
+            unsigned_immediate: false,
+            nops: NopStyle::Profile,
+        }
+    }
+}
+
+/// Line layout used by [`Instruction::to_line`] and the `Display` impl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OutputStyle {
+    /// How mnemonic and operand text is rendered.
+    pub render: RenderOptions,
+    /// Prefix the line with `0x<address>: `.
+    pub show_address: bool,
+    /// Print the raw instruction bytes before the text.
+    pub show_bytes: bool,
+}
+
+impl OutputStyle {
+    /// Mnemonic and operands only: `li ra, 1`.
+    pub fn compact() -> Self {
+        Self::default()
+    }
+
+    /// Address, bytes, and text: `0x00001000: 93 00 10 00  li ra, 1`.
+    pub fn detailed() -> Self {
+        Self {
+            show_address: true,
+            show_bytes: true,
+            ..Self::default()
+        }
+    }
 }
 
 /// Core-owned rendered instruction payload for text/JSON surfaces.
@@ -28,7 +86,7 @@ pub struct RenderedInstruction {
 impl RenderedInstruction {
     pub fn from_instruction(instruction: &Instruction, options: RenderOptions) -> Self {
         let (mnemonic, operands) = render_instruction_text(instruction, options);
-        let kind = if mnemonic == ".byte" {
+        let kind = if is_data_directive(&mnemonic) {
             "data".to_string()
         } else {
             "instruction".to_string()
@@ -71,19 +129,37 @@ pub struct RenderedDisassembly {
     pub instructions: Vec<RenderedInstruction>,
 }
 
+/// Whether `mnemonic` is a data directive (`.byte`, `.half`, `.word`,
+/// `.ascii`, ...) emitted for bytes that do not decode, rather than an
+/// instruction.
+pub fn is_data_directive(mnemonic: &str) -> bool {
+    matches!(
+        mnemonic,
+        ".byte" | ".half" | ".word" | ".dword" | ".long" | ".quad" | ".ascii" | ".asciz"
+    )
+}
+
 pub fn render_instruction_text(
     instruction: &Instruction,
     options: RenderOptions,
 ) -> (String, String) {
     if let Some(decoded) = &instruction.decoded {
+        let no_op = decoded.is_no_op();
+        if no_op && options.nops == NopStyle::Collapse {
+            let compressed = decoded.mnemonic.starts_with("c.")
+                && matches!(options.text_profile, TextRenderProfile::Canonical);
+            let mnemonic = if compressed { "c.nop" } else { "nop" };
+            return (mnemonic.to_string(), String::new());
+        }
+        let aliases = !(no_op && options.nops == NopStyle::Literal);
         let alias_regs = options.capstone_aliases
             && (options.alias_regs
                 || !matches!(options.text_profile, TextRenderProfile::Canonical));
         return decoded.render_text_parts_with_options(
             options.text_profile,
             alias_regs,
-            options.capstone_aliases,
-            options.compressed_aliases,
+            options.capstone_aliases && aliases,
+            options.compressed_aliases && aliases,
             options.unsigned_immediate,
         );
     }
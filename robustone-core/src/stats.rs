@@ -0,0 +1,268 @@
+//! Decode counters kept by [`ArchitectureDispatcher`](crate::ArchitectureDispatcher).
+//!
+//! Every dispatched decode bumps the counters of the handler that served it,
+//! so a long-running service can read a [`DecodeStats`] snapshot after a run
+//! and export it, for example with [`DecodeStats::to_prometheus`].
+
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counters for one handler, updated through `&self` so shared dispatchers
+/// can record from any thread.
+#[derive(Debug, Default)]
+pub(crate) struct HandlerCounters {
+    decoded: AtomicU64,
+    unknown: AtomicU64,
+    bytes: AtomicU64,
+}
+
+impl HandlerCounters {
+    pub(crate) fn record<T, E>(&self, result: &Result<(T, usize), E>) {
+        match result {
+            Ok((_, size)) => {
+                self.decoded.fetch_add(1, Ordering::Relaxed);
+                self.bytes.fetch_add(*size as u64, Ordering::Relaxed);
+            }
+            Err(_) => {
+                self.unknown.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub(crate) fn snapshot(&self, handler: &'static str) -> HandlerStats {
+        HandlerStats {
+            handler,
+            decoded: self.decoded.load(Ordering::Relaxed),
+            unknown: self.unknown.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        self.decoded.store(0, Ordering::Relaxed);
+        self.unknown.store(0, Ordering::Relaxed);
+        self.bytes.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Metric name, help text and the per-handler value of one exported counter.
+type PrometheusCounter = (&'static str, &'static str, fn(&HandlerStats) -> u64);
+
+/// Decode counts for one registered handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct HandlerStats {
+    /// The handler's [`name`](crate::ArchitectureHandler::name).
+    pub handler: &'static str,
+    /// Instructions the handler decoded.
+    pub decoded: u64,
+    /// Decode attempts the handler rejected; callers usually list these as
+    /// `unknown` or as data.
+    pub unknown: u64,
+    /// Bytes consumed by the decoded instructions.
+    pub bytes: u64,
+}
+
+/// A snapshot of a dispatcher's decode counters.
+///
+/// Counters start at zero when the dispatcher is created and only grow until
+/// [`ArchitectureDispatcher::reset_stats`](crate::ArchitectureDispatcher::reset_stats).
+/// They are updated with relaxed atomics, so a snapshot taken while other
+/// threads decode is consistent per counter but not across counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DecodeStats {
+    /// One entry per registered handler, in registration order.
+    pub handlers: Vec<HandlerStats>,
+    /// Requests for an architecture no handler supports.
+    pub unsupported: u64,
+}
+
+impl DecodeStats {
+    /// Counters for the handler named `handler`.
+    pub fn handler(&self, handler: &str) -> Option<&HandlerStats> {
+        self.handlers.iter().find(|stats| stats.handler == handler)
+    }
+
+    /// Instructions decoded across all handlers.
+    pub fn decoded(&self) -> u64 {
+        self.handlers.iter().map(|stats| stats.decoded).sum()
+    }
+
+    /// Rejected decode attempts across all handlers.
+    pub fn unknown(&self) -> u64 {
+        self.handlers.iter().map(|stats| stats.unknown).sum()
+    }
+
+    /// Bytes consumed across all handlers.
+    pub fn bytes(&self) -> u64 {
+        self.handlers.iter().map(|stats| stats.bytes).sum()
+    }
+
+    /// Count one decode attempt served by `handler`, the way the dispatcher
+    /// does; `None` counts a request no handler supports.
+    ///
+    /// Lets callers that share a dispatcher keep counts of their own runs.
+    pub fn record<T, E>(&mut self, handler: Option<&'static str>, result: &Result<(T, usize), E>) {
+        let Some(handler) = handler else {
+            self.unsupported += 1;
+            return;
+        };
+        let index = match self
+            .handlers
+            .iter()
+            .position(|stats| stats.handler == handler)
+        {
+            Some(index) => index,
+            None => {
+                self.handlers.push(HandlerStats {
+                    handler,
+                    decoded: 0,
+                    unknown: 0,
+                    bytes: 0,
+                });
+                self.handlers.len() - 1
+            }
+        };
+        let stats = &mut self.handlers[index];
+        match result {
+            Ok((_, size)) => {
+                stats.decoded += 1;
+                stats.bytes += *size as u64;
+            }
+            Err(_) => stats.unknown += 1,
+        }
+    }
+
+    /// Add `other`'s counters to these, matching handlers by name.
+    pub fn merge(&mut self, other: &DecodeStats) {
+        for theirs in &other.handlers {
+            match self
+                .handlers
+                .iter_mut()
+                .find(|ours| ours.handler == theirs.handler)
+            {
+                Some(ours) => {
+                    ours.decoded += theirs.decoded;
+                    ours.unknown += theirs.unknown;
+                    ours.bytes += theirs.bytes;
+                }
+                None => self.handlers.push(*theirs),
+            }
+        }
+        self.unsupported += other.unsupported;
+    }
+
+    /// Render the counters in the Prometheus text exposition format, with
+    /// metric names starting with `prefix` (for example `robustone`).
+    ///
+    /// ```rust
+    /// use robustone_core::DecodeStats;
+    ///
+    /// let text = DecodeStats::default().to_prometheus("robustone");
+    /// assert!(text.contains("robustone_unsupported_architecture_total 0"));
+    /// ```
+    pub fn to_prometheus(&self, prefix: &str) -> String {
+        let mut out = String::new();
+        let counters: [PrometheusCounter; 3] = [
+            (
+                "decoded_instructions_total",
+                "Instructions decoded.",
+                |stats| stats.decoded,
+            ),
+            (
+                "unknown_instructions_total",
+                "Decode attempts rejected by the handler.",
+                |stats| stats.unknown,
+            ),
+            (
+                "decoded_bytes_total",
+                "Bytes consumed by decoded instructions.",
+                |stats| stats.bytes,
+            ),
+        ];
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP {prefix}_{name} {help}");
+            let _ = writeln!(out, "# TYPE {prefix}_{name} counter");
+            for stats in &self.handlers {
+                let _ = writeln!(
+                    out,
+                    "{prefix}_{name}{{handler=\"{}\"}} {}",
+                    stats.handler,
+                    value(stats)
+                );
+            }
+        }
+        let name = "unsupported_architecture_total";
+        let _ = writeln!(
+            out,
+            "# HELP {prefix}_{name} Requests for an architecture no handler supports."
+        );
+        let _ = writeln!(out, "# TYPE {prefix}_{name} counter");
+        let _ = writeln!(out, "{prefix}_{name} {}", self.unsupported);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_adds_counters_by_handler_name() {
+        let riscv = HandlerStats {
+            handler: "riscv",
+            decoded: 2,
+            unknown: 1,
+            bytes: 6,
+        };
+        let mut stats = DecodeStats {
+            handlers: vec![riscv],
+            unsupported: 1,
+        };
+        stats.merge(&DecodeStats {
+            handlers: vec![
+                riscv,
+                HandlerStats {
+                    handler: "x86",
+                    decoded: 1,
+                    unknown: 0,
+                    bytes: 3,
+                },
+            ],
+            unsupported: 0,
+        });
+
+        assert_eq!(stats.handler("riscv").unwrap().decoded, 4);
+        assert_eq!(stats.handler("x86").unwrap().bytes, 3);
+        assert_eq!(
+            (stats.decoded(), stats.unknown(), stats.bytes()),
+            (5, 2, 15)
+        );
+        assert_eq!(stats.unsupported, 1);
+
+        let text = stats.to_prometheus("robustone");
+        assert!(text.contains("# TYPE robustone_decoded_instructions_total counter\n"));
+        assert!(text.contains("robustone_decoded_instructions_total{handler=\"riscv\"} 4\n"));
+        assert!(text.contains("robustone_unknown_instructions_total{handler=\"riscv\"} 2\n"));
+        assert!(text.contains("robustone_decoded_bytes_total{handler=\"x86\"} 3\n"));
+    }
+
+    #[test]
+    fn test_record_counts_one_attempt() {
+        let mut stats = DecodeStats::default();
+        stats.record(Some("riscv"), &Ok::<_, ()>(((), 4)));
+        stats.record(Some("riscv"), &Err::<((), usize), _>(()));
+        stats.record(None, &Err::<((), usize), _>(()));
+
+        assert_eq!(
+            stats.handler("riscv"),
+            Some(&HandlerStats {
+                handler: "riscv",
+                decoded: 1,
+                unknown: 1,
+                bytes: 4,
+            })
+        );
+        assert_eq!(stats.unsupported, 1);
+    }
+}
@@ -8,6 +8,155 @@ use crate::ir::DecodedInstruction;
 use crate::types::error::DisasmError;
 use crate::types::instruction::Instruction;
 
+/// A named set of mnemonics, typically one ISA extension or instruction class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MnemonicGroup {
+    /// Extension or family name (for example `M` or `XTheadCondMov`).
+    pub name: &'static str,
+    /// Canonical mnemonics the handler can decode in this group.
+    pub mnemonics: Vec<&'static str>,
+}
+
+/// One bit field of an instruction encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingField {
+    /// Field name as written in the ISA manual (for example `rd` or `imm[11:0]`).
+    pub name: &'static str,
+    /// Most significant bit of the field, inclusive.
+    pub msb: u8,
+    /// Least significant bit of the field, inclusive.
+    pub lsb: u8,
+    /// Raw field value, right-aligned.
+    pub value: u32,
+}
+
+impl EncodingField {
+    /// Width of the field in bits.
+    pub fn width(&self) -> u8 {
+        self.msb - self.lsb + 1
+    }
+}
+
+/// Field-by-field decomposition of one encoded instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingBreakdown {
+    /// Encoding format, such as `I-type` or `CR`.
+    pub format: String,
+    /// Fields from the most significant bit down.
+    pub fields: Vec<EncodingField>,
+    /// Reassembled immediate, when the format carries one.
+    pub immediate: Option<i64>,
+    /// One-line description of what the instruction does.
+    pub description: String,
+}
+
+/// Lowest privilege level an instruction executes at without trapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrivilegeLevel {
+    User,
+    Supervisor,
+    Hypervisor,
+    Machine,
+}
+
+impl PrivilegeLevel {
+    /// Lowercase name used in listings (`user`, `supervisor`, ...).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PrivilegeLevel::User => "user",
+            PrivilegeLevel::Supervisor => "supervisor",
+            PrivilegeLevel::Hypervisor => "hypervisor",
+            PrivilegeLevel::Machine => "machine",
+        }
+    }
+}
+
+/// Approximate cost of an instruction, as a class rather than a cycle count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LatencyClass {
+    /// Result available to the next instruction.
+    Single,
+    /// A few cycles, pipelined (multiply, most floating point).
+    Short,
+    /// Tens of cycles, iterative (divide, square root).
+    Long,
+    /// Depends on the memory system (loads, stores, atomics).
+    Memory,
+    /// Drains the pipeline (fences, CSR accesses, traps).
+    Serializing,
+}
+
+impl LatencyClass {
+    /// Lowercase name used in listings (`single`, `short`, ...).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LatencyClass::Single => "single",
+            LatencyClass::Short => "short",
+            LatencyClass::Long => "long",
+            LatencyClass::Memory => "memory",
+            LatencyClass::Serializing => "serializing",
+        }
+    }
+}
+
+/// What an instruction needs to run and roughly what it costs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionMetadata {
+    /// Extension that defines the instruction (for example `M` or `Zicsr`).
+    pub extension: &'static str,
+    pub privilege: PrivilegeLevel,
+    /// Latency class on a typical single-issue in-order core.
+    pub latency: LatencyClass,
+    /// Latency class on selected cores, by core name.
+    pub core_latency: Vec<(&'static str, LatencyClass)>,
+}
+
+/// Broad register file a register belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RegisterClass {
+    /// General-purpose integer register.
+    GeneralPurpose,
+    /// Floating-point register.
+    FloatingPoint,
+    /// Control and status register.
+    ControlStatus,
+    /// Vector register.
+    Vector,
+}
+
+impl RegisterClass {
+    /// Short lowercase name used in listings (`gpr`, `fpr`, `csr`, `vector`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RegisterClass::GeneralPurpose => "gpr",
+            RegisterClass::FloatingPoint => "fpr",
+            RegisterClass::ControlStatus => "csr",
+            RegisterClass::Vector => "vector",
+        }
+    }
+}
+
+/// Metadata for one architectural register.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterInfo {
+    /// Identifier used by [`RegisterId`](crate::ir::RegisterId) in the decoded IR.
+    pub id: u32,
+    /// Preferred name, as printed in listings (for example `s0`).
+    pub name: &'static str,
+    /// Other accepted spellings (for example `x8` and `fp`).
+    pub aliases: Vec<&'static str>,
+    pub class: RegisterClass,
+    /// Register width in bits for the queried mode.
+    pub width: u16,
+}
+
+impl RegisterInfo {
+    /// Check whether `name` is this register's name or one of its aliases.
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name || self.aliases.contains(&name)
+    }
+}
+
 /// Trait that all architecture-specific disassemblers must implement.
 ///
 /// This trait provides a unified interface for disassembling instructions
@@ -17,8 +166,9 @@ use crate::types::instruction::Instruction;
 ///
 /// # Thread Safety
 ///
-/// All implementations must be thread-safe (`Sync`) because handlers may
-/// be shared across multiple threads in the dispatcher.
+/// All implementations must be `Send + Sync` because handlers may be shared
+/// across multiple threads in the dispatcher, including a process-wide one
+/// held in a `static`.
 ///
 /// # Required Methods
 ///
@@ -64,7 +214,7 @@ use crate::types::instruction::Instruction;
 ///     }
 /// }
 /// ```
-pub trait ArchitectureHandler: Sync {
+pub trait ArchitectureHandler: Send + Sync {
     /// Decodes a single instruction into the shared IR.
     fn decode_instruction(
         &self,
@@ -169,4 +319,65 @@ pub trait ArchitectureHandler: Sync {
     /// The default implementation is a no-op for handlers that do not yet
     /// implement detail toggling.
     fn set_detail(&mut self, _detail: bool) {}
+
+    /// Lists every mnemonic this handler can decode for `arch_name`, grouped
+    /// by extension.
+    ///
+    /// Only the extensions the handler enables for that mode are listed, and
+    /// mode-specific encodings (such as RV64-only instructions) are left out
+    /// of the other modes.
+    ///
+    /// Mnemonics are the canonical decoded names, not display aliases such as
+    /// RISC-V `li` or `mv`. The default implementation returns no groups for
+    /// handlers that do not publish their coverage.
+    fn supported_mnemonics(&self, _arch_name: &str) -> Vec<MnemonicGroup> {
+        Vec::new()
+    }
+
+    /// Lists the mnemonics decodable with an explicit architecture profile.
+    ///
+    /// Handlers that care about profile extension sets should override this.
+    fn supported_mnemonics_with_profile(
+        &self,
+        profile: &ArchitectureProfile,
+    ) -> Vec<MnemonicGroup> {
+        self.supported_mnemonics(profile.mode_name)
+    }
+
+    /// Breaks the first instruction in `bytes` into its encoding fields.
+    ///
+    /// Used by explain-style listings. Handlers may also split bytes they
+    /// cannot decode, which lets reports on unsupported encodings show their
+    /// fields. The default implementation returns `None` for handlers that
+    /// do not describe their encodings.
+    fn explain_encoding(&self, _bytes: &[u8], _arch_name: &str) -> Option<EncodingBreakdown> {
+        None
+    }
+
+    /// Reports the extension, privilege level, and latency class of a
+    /// decoded instruction.
+    ///
+    /// The default implementation returns `None` for handlers that do not
+    /// publish instruction metadata.
+    fn instruction_metadata(&self, _decoded: &DecodedInstruction) -> Option<InstructionMetadata> {
+        None
+    }
+
+    /// Decodes the full-width instruction a compressed encoding stands for.
+    ///
+    /// Used to show RISC-V RVC instructions next to their 32-bit canonical
+    /// form. The default implementation returns `None` for handlers without
+    /// compressed encodings.
+    fn expand_compressed(&self, _decoded: &DecodedInstruction) -> Option<DecodedInstruction> {
+        None
+    }
+
+    /// Lists the registers visible in `arch_name`, ordered by id.
+    ///
+    /// Widths follow the mode (for example XLEN on RISC-V). The default
+    /// implementation returns no registers for handlers that do not publish
+    /// register metadata.
+    fn registers(&self, _arch_name: &str) -> Vec<RegisterInfo> {
+        Vec::new()
+    }
 }
@@ -1,6 +1,26 @@
 //! Instruction detail traits.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+/// What an instruction may do, for analyzers that should not need
+/// per-mnemonic tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SemanticFlags {
+    /// Reads memory (loads, load-reserved, atomic read-modify-write).
+    pub loads_memory: bool,
+    /// Writes memory (stores, store-conditional, atomic read-modify-write).
+    pub stores_memory: bool,
+    /// May transfer control somewhere other than the next instruction.
+    pub changes_control_flow: bool,
+    /// The control transfer depends on a condition.
+    pub conditional: bool,
+    /// Calls a subroutine, saving the return address in a link register.
+    pub call: bool,
+    /// Returns from a subroutine through its link register.
+    pub ret: bool,
+    /// Only executable above the least-privileged mode.
+    pub privileged: bool,
+}
 
 /// Trait for architecture-specific instruction details.
 ///
@@ -14,6 +34,14 @@ pub trait Detail: std::fmt::Debug + Send + Sync {
 
     /// Returns a list of register identifiers that are written by this instruction.
     fn registers_written(&self) -> &[u32];
+
+    /// Returns the instruction's semantic flags.
+    ///
+    /// The default implementation reports no effects, for details that do
+    /// not classify their instructions.
+    fn semantics(&self) -> SemanticFlags {
+        SemanticFlags::default()
+    }
 }
 
 /// A generic implementation of `Detail` for simple use cases.
@@ -23,7 +51,7 @@ pub struct BasicInstructionDetail {
     pub regs_read: Vec<u32>,
     pub regs_write: Vec<u32>,
     pub groups: Vec<String>,
-    pub properties: HashMap<String, String>,
+    pub properties: BTreeMap<String, String>,
 }
 
 impl BasicInstructionDetail {
@@ -33,7 +61,7 @@ impl BasicInstructionDetail {
             regs_read: Vec::new(),
             regs_write: Vec::new(),
             groups: Vec::new(),
-            properties: HashMap::new(),
+            properties: BTreeMap::new(),
         }
     }
 
@@ -6,5 +6,8 @@
 pub mod architecture;
 pub mod instruction;
 
-pub use architecture::ArchitectureHandler;
-pub use instruction::{BasicInstructionDetail, Detail};
+pub use architecture::{
+    ArchitectureHandler, EncodingBreakdown, EncodingField, InstructionMetadata, LatencyClass,
+    MnemonicGroup, PrivilegeLevel, RegisterClass, RegisterInfo,
+};
+pub use instruction::{BasicInstructionDetail, Detail, SemanticFlags};
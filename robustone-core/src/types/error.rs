@@ -1,3 +1,4 @@
+use crate::ir::EncodingClass;
 use thiserror::Error;
 
 /// Machine-readable decode failure classes.
@@ -8,6 +9,8 @@ pub enum DecodeErrorKind {
     UnsupportedExtension,
     UnimplementedInstruction,
     UnsupportedMode,
+    /// An encoding the specification reserves; it is not an instruction.
+    ReservedEncoding,
 }
 
 impl std::fmt::Display for DecodeErrorKind {
@@ -18,6 +21,7 @@ impl std::fmt::Display for DecodeErrorKind {
             DecodeErrorKind::UnsupportedExtension => "unsupported_extension",
             DecodeErrorKind::UnimplementedInstruction => "unimplemented_instruction",
             DecodeErrorKind::UnsupportedMode => "unsupported_mode",
+            DecodeErrorKind::ReservedEncoding => "reserved_encoding",
         };
         write!(f, "{text}")
     }
@@ -63,15 +67,60 @@ pub enum DisasmError {
         architecture: Option<String>,
         detail: String,
     },
+    /// The input ends inside an instruction.
+    ///
+    /// Reported as the `need_more_bytes` decode kind, with the byte counts
+    /// kept so callers can tell how much more input to supply (see
+    /// [`DisasmError::bytes_needed`]).
+    #[error(
+        "ERROR: decoding failed (need_more_bytes){arch}: need {needed} bytes, {available} available ({more} more)",
+        arch = architecture
+            .as_ref()
+            .map(|arch| format!(" for {arch}"))
+            .unwrap_or_default(),
+        more = needed.saturating_sub(*available)
+    )]
+    Truncated {
+        architecture: Option<String>,
+        needed: usize,
+        available: usize,
+    },
     #[error("ERROR: Decoding failed: {0}")]
     DecodingError(String),
     #[error("ERROR: invalid assembly code: {0}")]
     InvalidHexCode(String),
     #[error("ERROR: invalid address argument: {0}")]
     InvalidAddress(String),
+    /// A configured resource limit (instruction count, output size, or
+    /// run time) stopped the run.
+    #[error("ERROR: limit exceeded: {0}")]
+    LimitExceeded(String),
+    /// An error annotated with where in the input stream it happened.
+    #[error("{source} (at offset {offset}, address 0x{address:x})")]
+    Located {
+        #[source]
+        source: Box<DisasmError>,
+        offset: usize,
+        address: u64,
+        bytes: Vec<u8>,
+    },
+}
+
+/// Input-stream position attached to a [`DisasmError::Located`] error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorLocation<'a> {
+    /// Offset of the failing instruction in the input buffer.
+    pub offset: usize,
+    /// Address the failing instruction would have been decoded at.
+    pub address: u64,
+    /// The offending bytes (at most [`DisasmError::CONTEXT_BYTES`]).
+    pub bytes: &'a [u8],
 }
 
 impl DisasmError {
+    /// Number of input bytes kept as context on located errors.
+    pub const CONTEXT_BYTES: usize = 8;
+
     /// Create a structured decode failure.
     pub fn decode_failure(
         kind: DecodeErrorKind,
@@ -86,9 +135,110 @@ impl DisasmError {
         }
     }
 
+    /// Create a truncation error for an instruction of `needed` bytes when
+    /// only `available` remain.
+    pub fn truncated(
+        architecture: impl Into<Option<String>>,
+        needed: usize,
+        available: usize,
+    ) -> Self {
+        Self::Truncated {
+            architecture: architecture.into(),
+            needed,
+            available,
+        }
+    }
+
+    /// Attach the input offset, address, and offending bytes to this error.
+    ///
+    /// Only the first [`Self::CONTEXT_BYTES`] bytes are kept. Errors that are
+    /// already located are returned unchanged.
+    pub fn at(self, offset: usize, address: u64, bytes: &[u8]) -> Self {
+        if matches!(self, DisasmError::Located { .. }) {
+            return self;
+        }
+        Self::Located {
+            source: Box::new(self),
+            offset,
+            address,
+            bytes: bytes.iter().take(Self::CONTEXT_BYTES).copied().collect(),
+        }
+    }
+
+    /// Return the error without any location wrapper.
+    pub fn root(&self) -> &DisasmError {
+        match self {
+            DisasmError::Located { source, .. } => source.root(),
+            other => other,
+        }
+    }
+
+    /// Return where in the input this error happened, when known.
+    pub fn location(&self) -> Option<ErrorLocation<'_>> {
+        match self {
+            DisasmError::Located {
+                offset,
+                address,
+                bytes,
+                ..
+            } => Some(ErrorLocation {
+                offset: *offset,
+                address: *address,
+                bytes,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Classify the rejected encoding: [`EncodingClass::Reserved`] for
+    /// encodings the specification reserves, [`EncodingClass::Unknown`] for
+    /// ones it does not define, and `None` for failures that say nothing
+    /// about the encoding itself (truncation, a disabled extension, ...).
+    pub fn encoding_class(&self) -> Option<EncodingClass> {
+        match self.decode_kind()? {
+            DecodeErrorKind::ReservedEncoding => Some(EncodingClass::Reserved),
+            DecodeErrorKind::InvalidEncoding => Some(EncodingClass::Unknown),
+            _ => None,
+        }
+    }
+
+    /// Return the decode failure class for structured decode errors.
+    pub fn decode_kind(&self) -> Option<DecodeErrorKind> {
+        match self.root() {
+            DisasmError::DecodeFailure { kind, .. } => Some(*kind),
+            DisasmError::Truncated { .. } => Some(DecodeErrorKind::NeedMoreBytes),
+            _ => None,
+        }
+    }
+
+    /// Return a stable numeric error code for scripts and tooling.
+    ///
+    /// Codes are grouped by category: `1xx` architecture selection,
+    /// `2xx` decode failures, `3xx` input errors, `4xx` resource limits.
+    pub fn code(&self) -> u16 {
+        match self.root() {
+            DisasmError::UnsupportedArchitecture(_) => 100,
+            DisasmError::DecodeFailure { kind, .. } => match kind {
+                DecodeErrorKind::NeedMoreBytes => 201,
+                DecodeErrorKind::InvalidEncoding => 202,
+                DecodeErrorKind::UnsupportedExtension => 203,
+                DecodeErrorKind::UnimplementedInstruction => 204,
+                DecodeErrorKind::UnsupportedMode => 205,
+                DecodeErrorKind::ReservedEncoding => 206,
+            },
+            DisasmError::Truncated { .. } => 201,
+            DisasmError::DecodingError(_) => 200,
+            DisasmError::InvalidHexCode(_) => 301,
+            DisasmError::InvalidAddress(_) => 302,
+            DisasmError::LimitExceeded(_) => 400,
+            DisasmError::Located { .. } => unreachable!("root() strips location wrappers"),
+        }
+    }
+
     /// Return a stable, machine-readable error kind identifier.
     pub fn stable_kind(&self) -> &'static str {
         match self {
+            DisasmError::Located { source, .. } => source.stable_kind(),
             DisasmError::UnsupportedArchitecture(_) => "unsupported_architecture",
             DisasmError::DecodeFailure { kind, .. } => match kind {
                 DecodeErrorKind::NeedMoreBytes => "need_more_bytes",
@@ -96,10 +246,28 @@ impl DisasmError {
                 DecodeErrorKind::UnsupportedExtension => "unsupported_extension",
                 DecodeErrorKind::UnimplementedInstruction => "unimplemented_instruction",
                 DecodeErrorKind::UnsupportedMode => "unsupported_mode",
+                DecodeErrorKind::ReservedEncoding => "reserved_encoding",
             },
+            DisasmError::Truncated { .. } => "need_more_bytes",
             DisasmError::DecodingError(_) => "decoding_error",
             DisasmError::InvalidHexCode(_) => "invalid_hex_code",
             DisasmError::InvalidAddress(_) => "invalid_address",
+            DisasmError::LimitExceeded(_) => "limit_exceeded",
+        }
+    }
+
+    /// How many more input bytes the instruction needs, for truncated input.
+    ///
+    /// RISC-V lengths come from the first parcel, so this is exact (2, 4, 6,
+    /// 8, ...) except when a single byte of an 80-bit-or-longer encoding is
+    /// available: the count is then the minimum, and retrying with that many
+    /// more bytes may report a further shortfall.
+    pub fn bytes_needed(&self) -> Option<usize> {
+        match self.root() {
+            DisasmError::Truncated {
+                needed, available, ..
+            } => Some(needed.saturating_sub(*available)),
+            _ => None,
         }
     }
 
@@ -107,7 +275,9 @@ impl DisasmError {
     pub fn architecture_name(&self) -> Option<&str> {
         match self {
             DisasmError::UnsupportedArchitecture(arch) => Some(arch.as_str()),
-            DisasmError::DecodeFailure { architecture, .. } => architecture.as_deref(),
+            DisasmError::DecodeFailure { architecture, .. }
+            | DisasmError::Truncated { architecture, .. } => architecture.as_deref(),
+            DisasmError::Located { source, .. } => source.architecture_name(),
             _ => None,
         }
     }
@@ -119,9 +289,91 @@ impl DisasmError {
                 format!("unsupported architecture: {arch}")
             }
             DisasmError::DecodeFailure { detail, .. } => detail.clone(),
+            DisasmError::Truncated {
+                needed, available, ..
+            } => format!(
+                "need {needed} bytes, {available} available ({} more)",
+                needed.saturating_sub(*available)
+            ),
             DisasmError::DecodingError(detail) => detail.clone(),
             DisasmError::InvalidHexCode(detail) => detail.clone(),
             DisasmError::InvalidAddress(detail) => detail.clone(),
+            DisasmError::LimitExceeded(detail) => detail.clone(),
+            DisasmError::Located { source, .. } => source.detail_message(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_located_error_preserves_kind_and_context() {
+        let error = DisasmError::decode_failure(
+            DecodeErrorKind::NeedMoreBytes,
+            Some("riscv32".to_string()),
+            "need 4 bytes",
+        )
+        .at(
+            6,
+            0x1006,
+            &[0x13, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08],
+        );
+
+        assert_eq!(error.stable_kind(), "need_more_bytes");
+        assert_eq!(error.decode_kind(), Some(DecodeErrorKind::NeedMoreBytes));
+        assert_eq!(error.code(), 201);
+        assert_eq!(error.architecture_name(), Some("riscv32"));
+        assert_eq!(error.detail_message(), "need 4 bytes");
+
+        let location = error.location().expect("location should be attached");
+        assert_eq!(location.offset, 6);
+        assert_eq!(location.address, 0x1006);
+        assert_eq!(location.bytes.len(), DisasmError::CONTEXT_BYTES);
+        assert!(error.to_string().ends_with("(at offset 6, address 0x1006)"));
+    }
+
+    #[test]
+    fn test_at_does_not_nest_locations() {
+        let error = DisasmError::InvalidHexCode("zz".to_string())
+            .at(0, 0, &[])
+            .at(4, 4, &[1]);
+        assert_eq!(error.location().unwrap().offset, 0);
+        assert_eq!(error.code(), 301);
+    }
+
+    #[test]
+    fn test_truncation_reports_the_missing_byte_count() {
+        let error =
+            DisasmError::truncated(Some("riscv64".to_string()), 6, 2).at(8, 0x1008, &[0x1f, 0]);
+        assert_eq!(error.bytes_needed(), Some(4));
+        assert_eq!(error.detail_message(), "need 6 bytes, 2 available (4 more)");
+        assert!(
+            error
+                .to_string()
+                .contains("need 6 bytes, 2 available (4 more)")
+        );
+        assert_eq!(
+            DisasmError::InvalidHexCode("zz".to_string()).bytes_needed(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_encoding_class_separates_reserved_from_unknown() {
+        let reserved = |kind| DisasmError::decode_failure(kind, None::<String>, "").at(0, 0, &[]);
+        let error = reserved(DecodeErrorKind::ReservedEncoding);
+        assert_eq!(error.encoding_class(), Some(EncodingClass::Reserved));
+        assert_eq!(error.stable_kind(), "reserved_encoding");
+        assert_eq!(error.code(), 206);
+        assert_eq!(
+            reserved(DecodeErrorKind::InvalidEncoding).encoding_class(),
+            Some(EncodingClass::Unknown)
+        );
+        assert_eq!(
+            reserved(DecodeErrorKind::UnsupportedExtension).encoding_class(),
+            None
+        );
+    }
+}
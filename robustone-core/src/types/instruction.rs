@@ -1,9 +1,18 @@
 //! Instruction type definition.
 
-use crate::ir::{DecodedInstruction, TextRenderProfile};
-use crate::traits::instruction::{BasicInstructionDetail, Detail};
+use crate::ir::{DecodedInstruction, OperandType, TextRenderProfile, TypedOperand};
+use crate::render::{OutputStyle, render_instruction_text};
+use crate::traits::instruction::{BasicInstructionDetail, Detail, SemanticFlags};
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// Decoded instruction returned by the disassembler.
+///
+/// Two instructions are equal when they decode the same bytes at the same
+/// address to the same text and IR. The architecture `detail` is derived
+/// from those and is not compared, so instructions can be deduplicated and
+/// used as map keys regardless of whether detail generation was enabled.
 #[derive(Debug)]
 pub struct Instruction {
     pub address: u64,
@@ -29,6 +38,30 @@ impl Default for Instruction {
     }
 }
 
+impl PartialEq for Instruction {
+    fn eq(&self, other: &Self) -> bool {
+        self.address == other.address
+            && self.bytes == other.bytes
+            && self.mnemonic == other.mnemonic
+            && self.operands == other.operands
+            && self.size == other.size
+            && self.decoded == other.decoded
+    }
+}
+
+impl Eq for Instruction {}
+
+impl Hash for Instruction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // `decoded` is left out: equal instructions already share these fields.
+        self.address.hash(state);
+        self.bytes.hash(state);
+        self.mnemonic.hash(state);
+        self.operands.hash(state);
+        self.size.hash(state);
+    }
+}
+
 impl Instruction {
     pub fn new(address: u64, bytes: Vec<u8>, mnemonic: String, operands: String) -> Self {
         let size = bytes.len();
@@ -118,6 +151,73 @@ impl Instruction {
         self.mnemonic == "unknown"
     }
 
+    /// Architecture-neutral operands with their access, taken from the decoded
+    /// IR. Empty for instructions without IR, such as data pseudo-instructions.
+    pub fn typed_operands(&self) -> Vec<TypedOperand> {
+        self.decoded
+            .as_ref()
+            .map(DecodedInstruction::typed_operands)
+            .unwrap_or_default()
+    }
+
+    /// Registers read and written by this instruction, including implicit
+    /// ones, like Capstone's `cs_regs_access`.
+    ///
+    /// Uses the architecture `detail` when present and the decoded IR
+    /// otherwise. Each list is in first-access order without duplicates.
+    pub fn registers_accessed(&self) -> (Vec<u32>, Vec<u32>) {
+        if let Some(detail) = &self.detail {
+            return (
+                dedup_ids(detail.registers_read().iter().copied()),
+                dedup_ids(detail.registers_written().iter().copied()),
+            );
+        }
+        let Some(decoded) = &self.decoded else {
+            return (Vec::new(), Vec::new());
+        };
+        let read = decoded
+            .registers_read
+            .iter()
+            .chain(&decoded.implicit_registers_read)
+            .map(|register| register.id);
+        let written = decoded
+            .registers_written
+            .iter()
+            .chain(&decoded.implicit_registers_written)
+            .map(|register| register.id);
+        (dedup_ids(read), dedup_ids(written))
+    }
+
+    /// Check whether this instruction reads register `reg`.
+    pub fn reads_reg(&self, reg: u32) -> bool {
+        self.registers_accessed().0.contains(&reg)
+    }
+
+    /// Check whether this instruction writes register `reg`.
+    pub fn writes_reg(&self, reg: u32) -> bool {
+        self.registers_accessed().1.contains(&reg)
+    }
+
+    /// Semantic flags reported by the architecture `detail`; all false when
+    /// detail generation is off.
+    pub fn semantics(&self) -> SemanticFlags {
+        self.detail
+            .as_ref()
+            .map(|detail| detail.semantics())
+            .unwrap_or_default()
+    }
+
+    /// Number of operands of kind `operand_type`, like Capstone's `cs_op_count`.
+    pub fn operand_count(&self, operand_type: OperandType) -> usize {
+        self.decoded.as_ref().map_or(0, |decoded| {
+            decoded
+                .operands
+                .iter()
+                .filter(|operand| operand.operand_type() == operand_type)
+                .count()
+        })
+    }
+
     /// Return text rendered from the shared IR when available, otherwise fall
     /// back to the legacy compatibility fields.
     pub fn rendered_text_parts(&self, profile: TextRenderProfile) -> (String, String) {
@@ -131,6 +231,54 @@ impl Instruction {
         let (mnemonic, operands) = self.rendered_text_parts(TextRenderProfile::Capstone);
         format!("0x{:08x}: {:<7} {}", self.address, mnemonic, operands)
     }
+
+    /// Render this instruction as a single line laid out by `style`.
+    pub fn to_line(&self, style: &OutputStyle) -> String {
+        let mut line = String::new();
+        if style.show_address {
+            line.push_str(&format!("0x{:08x}: ", self.address));
+        }
+        if style.show_bytes {
+            let bytes: Vec<String> = self
+                .bytes
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect();
+            line.push_str(&bytes.join(" "));
+            line.push_str("  ");
+        }
+
+        let (mnemonic, operands) = render_instruction_text(self, style.render);
+        line.push_str(&mnemonic);
+        if !operands.is_empty() {
+            line.push(' ');
+            line.push_str(&operands);
+        }
+        line
+    }
+}
+
+fn dedup_ids(ids: impl Iterator<Item = u32>) -> Vec<u32> {
+    let mut unique = Vec::new();
+    for id in ids {
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    unique
+}
+
+/// `{}` prints the compact form (`li ra, 1`); `{:#}` prints the detailed form
+/// with address and bytes (`0x00001000: 93 00 10 00  li ra, 1`).
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = if f.alternate() {
+            OutputStyle::detailed()
+        } else {
+            OutputStyle::compact()
+        };
+        f.write_str(&self.to_line(&style))
+    }
 }
 
 #[cfg(test)]
@@ -172,6 +320,57 @@ mod tests {
         assert_eq!(formatted, "0x00000100: mov     rax, rbx");
     }
 
+    #[test]
+    fn test_instruction_equality_ignores_detail() {
+        let plain = Instruction::new(0x100, vec![0x13, 0, 0, 0], "nop".to_string(), String::new());
+        let detailed = Instruction::with_basic_detail(
+            0x100,
+            vec![0x13, 0, 0, 0],
+            "nop".to_string(),
+            String::new(),
+            "riscv",
+        );
+        let moved = Instruction::new(0x104, vec![0x13, 0, 0, 0], "nop".to_string(), String::new());
+
+        assert_eq!(plain, detailed);
+        assert_ne!(plain, moved);
+
+        let hash = |instruction: &Instruction| {
+            let mut hasher = std::hash::DefaultHasher::new();
+            instruction.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&plain), hash(&detailed));
+
+        let mut instructions = vec![plain, detailed, moved];
+        instructions.dedup();
+        assert_eq!(instructions.len(), 2);
+    }
+
+    #[test]
+    fn test_display_compact_and_detailed_forms() {
+        let instruction = Instruction::new(
+            0x1000,
+            vec![0x48, 0x89, 0xD8],
+            "mov".to_string(),
+            "rax, rbx".to_string(),
+        );
+        let bare = Instruction::new(0x1003, vec![0xC3], "ret".to_string(), String::new());
+
+        assert_eq!(instruction.to_string(), "mov rax, rbx");
+        assert_eq!(
+            format!("{instruction:#}"),
+            "0x00001000: 48 89 d8  mov rax, rbx"
+        );
+        assert_eq!(bare.to_string(), "ret");
+
+        let style = OutputStyle {
+            show_address: true,
+            ..OutputStyle::compact()
+        };
+        assert_eq!(bare.to_line(&style), "0x00001003: ret");
+    }
+
     #[test]
     fn test_rendered_text_parts_prefer_decoded_ir() {
         let decoded = DecodedInstruction {
@@ -191,6 +390,7 @@ mod tests {
                 },
                 Operand::Immediate { value: 1 },
             ],
+            operand_access: Vec::new(),
             registers_read: vec![RegisterId::riscv(0)],
             registers_written: vec![RegisterId::riscv(1)],
             implicit_registers_read: Vec::new(),
@@ -201,6 +401,7 @@ mod tests {
                 capstone_mnemonic: Some("li".to_string()),
                 capstone_hidden_operands: vec![1],
             },
+            decoded_by: None,
             render: None,
         };
         let instruction =
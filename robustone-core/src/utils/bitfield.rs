@@ -0,0 +1,246 @@
+//! Bitfield extraction helpers for instruction decoders.
+//!
+//! ISA manuals describe encodings as `(msb, lsb)` bit ranges, so these
+//! helpers take the same pairs instead of a shift and a mask. Instruction
+//! words are handled as `u64`, which covers every fixed-width encoding the
+//! architecture handlers decode; narrower words widen with `u64::from`.
+//!
+//! A [`FieldTable`] lists the named fields of one encoding format so a
+//! decoder can declare the layout once and slice every word through it.
+
+use crate::traits::EncodingField;
+
+/// Extracts bits `msb..=lsb` of `word`, right-aligned.
+///
+/// # Example
+/// ```
+/// use robustone_core::utils::bitfield::extract;
+/// // The rd field of `addi x1, x0, 1`.
+/// assert_eq!(extract(0x0010_0093, 11, 7), 1);
+/// ```
+pub const fn extract(word: u64, msb: u8, lsb: u8) -> u64 {
+    debug_assert!(msb >= lsb && msb < 64);
+    let width = msb - lsb + 1;
+    (word >> lsb) & mask(width)
+}
+
+/// Extracts bits `msb..=lsb` of `word` and sign-extends them from bit `msb`.
+pub const fn extract_signed(word: u64, msb: u8, lsb: u8) -> i64 {
+    sign_extend(extract(word, msb, lsb), msb - lsb + 1)
+}
+
+/// Sign-extends the low `width` bits of `value`.
+///
+/// Bits above `width` are ignored, so the result does not depend on
+/// whether the caller masked `value` first.
+pub const fn sign_extend(value: u64, width: u8) -> i64 {
+    debug_assert!(width > 0 && width <= 64);
+    let shift = 64 - width as u32;
+    ((value << shift) as i64) >> shift
+}
+
+/// Reassembles an immediate whose bits are scattered across the word.
+///
+/// Each piece is `(msb, lsb, position)`: bits `msb..=lsb` of `word` land at
+/// bit `position` of the result. The RISC-V B-type offset, for example, is
+/// `[(31, 31, 12), (7, 7, 11), (30, 25, 5), (11, 8, 1)]`.
+pub const fn gather(word: u64, pieces: &[(u8, u8, u8)]) -> u64 {
+    let mut value = 0;
+    let mut index = 0;
+    while index < pieces.len() {
+        let (msb, lsb, position) = pieces[index];
+        value |= extract(word, msb, lsb) << position;
+        index += 1;
+    }
+    value
+}
+
+const fn mask(width: u8) -> u64 {
+    if width >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << width) - 1
+    }
+}
+
+/// One named field of an encoding format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitField {
+    /// Field name as written in the ISA manual (for example `rd` or `imm[11:0]`).
+    pub name: &'static str,
+    /// Most significant bit of the field, inclusive.
+    pub msb: u8,
+    /// Least significant bit of the field, inclusive.
+    pub lsb: u8,
+    /// Whether the field holds a two's complement value.
+    pub signed: bool,
+}
+
+impl BitField {
+    /// An unsigned field covering bits `msb..=lsb`.
+    pub const fn new(name: &'static str, msb: u8, lsb: u8) -> Self {
+        Self {
+            name,
+            msb,
+            lsb,
+            signed: false,
+        }
+    }
+
+    /// A signed field covering bits `msb..=lsb`.
+    pub const fn signed(name: &'static str, msb: u8, lsb: u8) -> Self {
+        Self {
+            name,
+            msb,
+            lsb,
+            signed: true,
+        }
+    }
+
+    /// Width of the field in bits.
+    pub const fn width(&self) -> u8 {
+        self.msb - self.lsb + 1
+    }
+
+    /// The raw field bits of `word`, right-aligned.
+    pub const fn raw(&self, word: u64) -> u64 {
+        extract(word, self.msb, self.lsb)
+    }
+
+    /// The field value of `word`, sign-extended when the field is signed.
+    pub const fn value(&self, word: u64) -> i64 {
+        if self.signed {
+            extract_signed(word, self.msb, self.lsb)
+        } else {
+            self.raw(word) as i64
+        }
+    }
+}
+
+/// The fields of one encoding format, listed from the most significant bit
+/// down.
+///
+/// # Example
+/// ```
+/// use robustone_core::utils::bitfield::{BitField, FieldTable};
+///
+/// const I_TYPE: FieldTable = FieldTable::new(&[
+///     BitField::signed("imm[11:0]", 31, 20),
+///     BitField::new("rs1", 19, 15),
+///     BitField::new("funct3", 14, 12),
+///     BitField::new("rd", 11, 7),
+///     BitField::new("opcode", 6, 0),
+/// ]);
+///
+/// // addi x1, x0, -1
+/// assert_eq!(I_TYPE.value(0xfff0_0093, "imm[11:0]"), Some(-1));
+/// assert_eq!(I_TYPE.value(0xfff0_0093, "rd"), Some(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldTable {
+    fields: &'static [BitField],
+}
+
+impl FieldTable {
+    /// Creates a table from `fields`.
+    pub const fn new(fields: &'static [BitField]) -> Self {
+        Self { fields }
+    }
+
+    /// The fields in declaration order.
+    pub const fn fields(&self) -> &'static [BitField] {
+        self.fields
+    }
+
+    /// The field called `name`.
+    pub fn get(&self, name: &str) -> Option<&'static BitField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// The value of the field called `name` in `word`.
+    pub fn value(&self, word: u64, name: &str) -> Option<i64> {
+        self.get(name).map(|field| field.value(word))
+    }
+
+    /// Slices `word` into every field, for encoding breakdowns.
+    pub fn slice(&self, word: u64) -> Vec<EncodingField> {
+        self.fields
+            .iter()
+            .map(|field| EncodingField {
+                name: field.name,
+                msb: field.msb,
+                lsb: field.lsb,
+                value: field.raw(word) as u32,
+            })
+            .collect()
+    }
+
+    /// Whether the fields cover bits `width - 1..=0` exactly once.
+    pub fn covers(&self, width: u8) -> bool {
+        let mut seen = 0u64;
+        for field in self.fields {
+            if field.msb >= width {
+                return false;
+            }
+            let bits = mask(field.width()) << field.lsb;
+            if seen & bits != 0 {
+                return false;
+            }
+            seen |= bits;
+        }
+        seen == mask(width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_and_sign_extend() {
+        assert_eq!(extract(0xdead_beef, 31, 16), 0xdead);
+        assert_eq!(extract(0xdead_beef, 3, 0), 0xf);
+        assert_eq!(extract(u64::MAX, 63, 0), u64::MAX);
+        assert_eq!(extract_signed(0x8000_0000, 31, 20), -2048);
+        assert_eq!(extract_signed(0x7ff0_0000, 31, 20), 2047);
+        assert_eq!(sign_extend(0xfff, 12), -1);
+        assert_eq!(sign_extend(0x1_0fff, 12), -1);
+        assert_eq!(sign_extend(0x7ff, 12), 2047);
+        assert_eq!(sign_extend(u64::MAX, 64), -1);
+    }
+
+    #[test]
+    fn test_gather_reassembles_branch_offset() {
+        const B_IMM: &[(u8, u8, u8)] = &[(31, 31, 12), (7, 7, 11), (30, 25, 5), (11, 8, 1)];
+        // beq x0, x0, -4
+        let word = 0xfe00_0ee3;
+        assert_eq!(sign_extend(gather(word, B_IMM), 13), -4);
+        // beq x0, x0, 8
+        assert_eq!(gather(0x0000_0463, B_IMM), 8);
+    }
+
+    #[test]
+    fn test_field_table_slices_word() {
+        const R_TYPE: FieldTable = FieldTable::new(&[
+            BitField::new("funct7", 31, 25),
+            BitField::new("rs2", 24, 20),
+            BitField::new("rs1", 19, 15),
+            BitField::new("funct3", 14, 12),
+            BitField::new("rd", 11, 7),
+            BitField::new("opcode", 6, 0),
+        ]);
+        assert!(R_TYPE.covers(32));
+        assert!(!R_TYPE.covers(16));
+
+        // sub x3, x1, x2
+        let word = 0x4020_81b3;
+        assert_eq!(R_TYPE.value(word, "funct7"), Some(0x20));
+        assert_eq!(R_TYPE.value(word, "rd"), Some(3));
+        assert_eq!(R_TYPE.value(word, "imm"), None);
+
+        let fields = R_TYPE.slice(word);
+        assert_eq!(fields.len(), 6);
+        assert_eq!((fields[1].name, fields[1].value), ("rs2", 2));
+        assert_eq!(fields[1].width(), 5);
+    }
+}
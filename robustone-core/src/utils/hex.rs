@@ -7,6 +7,55 @@
 use crate::types::error::DisasmError;
 use crate::utils::Endianness;
 
+/// Marks bytes that are not hexadecimal digits in [`HEX_VALUES`].
+const INVALID: u8 = 0xff;
+
+/// The value of every ASCII hexadecimal digit, indexed by byte.
+const HEX_VALUES: [u8; 256] = {
+    let mut table = [INVALID; 256];
+    let mut digit = 0;
+    while digit < 10 {
+        table[b'0' as usize + digit] = digit as u8;
+        digit += 1;
+    }
+    let mut letter = 0;
+    while letter < 6 {
+        table[b'a' as usize + letter] = 10 + letter as u8;
+        table[b'A' as usize + letter] = 10 + letter as u8;
+        letter += 1;
+    }
+    table
+};
+
+/// The value of the hexadecimal digit `byte`, in either case.
+pub const fn hex_digit_value(byte: u8) -> Option<u8> {
+    match HEX_VALUES[byte as usize] {
+        INVALID => None,
+        value => Some(value),
+    }
+}
+
+/// Decodes pairs of hexadecimal digits from `digits` and appends the bytes
+/// to `out`.
+///
+/// `digits` must hold an even number of digits and nothing else; on the
+/// first byte that is not a digit, returns its index and leaves `out` with
+/// the bytes decoded before it. This is the shared fast path for hex input:
+/// one table lookup per digit and no intermediate strings.
+pub fn decode_hex_digits(digits: &[u8], out: &mut Vec<u8>) -> Result<(), usize> {
+    debug_assert!(digits.len().is_multiple_of(2));
+    out.reserve(digits.len() / 2);
+    for (pair, chunk) in digits.chunks_exact(2).enumerate() {
+        let high = HEX_VALUES[chunk[0] as usize];
+        let low = HEX_VALUES[chunk[1] as usize];
+        if (high | low) > 0x0f {
+            return Err(pair * 2 + usize::from(high <= 0x0f));
+        }
+        out.push((high << 4) | low);
+    }
+    Ok(())
+}
+
 /// Parser for hexadecimal strings with various formats and prefixes.
 ///
 /// This parser preserves the byte order exactly as it appears in the input.
@@ -47,7 +96,7 @@ impl HexParser {
     /// # Examples
     ///
     /// ```rust
-    /// use robustone_core::prelude::*;
+    /// use robustone_core::internal::utils::HexParser;
     /// let parser = HexParser::new();
     /// let bytes = parser.parse("deadbeef", None).unwrap();
     /// assert_eq!(bytes, vec![0xde, 0xad, 0xbe, 0xef]);
@@ -57,8 +106,52 @@ impl HexParser {
         hex_str: &str,
         _endianness: Option<Endianness>,
     ) -> Result<Vec<u8>, DisasmError> {
-        let cleaned = self.clean_hex_string(hex_str)?;
-        self.convert_to_bytes(&cleaned)
+        let trimmed = hex_str.trim();
+        let digits = trimmed
+            .strip_prefix("0x")
+            .or_else(|| trimmed.strip_prefix("0X"))
+            .unwrap_or(trimmed);
+        let input = digits.as_bytes();
+
+        // One pass over the input: whitespace is skipped, every digit is
+        // looked up once, and each completed pair is pushed as a byte.
+        let mut bytes = Vec::with_capacity(input.len() / 2);
+        let mut high: Option<u8> = None;
+        let mut index = 0;
+        while index < input.len() {
+            let byte = input[index];
+            let value = HEX_VALUES[byte as usize];
+            if value != INVALID {
+                match high.take() {
+                    Some(high) => bytes.push((high << 4) | value),
+                    None => high = Some(value),
+                }
+                index += 1;
+            } else if byte.is_ascii() && char::from(byte).is_whitespace() {
+                index += 1;
+            } else {
+                match digits[index..].chars().next() {
+                    Some(c) if !byte.is_ascii() && c.is_whitespace() => index += c.len_utf8(),
+                    _ => {
+                        return Err(DisasmError::DecodingError(
+                            "Invalid hexadecimal characters found".to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if bytes.is_empty() && high.is_none() {
+            return Err(DisasmError::DecodingError(
+                "Empty hexadecimal string".to_string(),
+            ));
+        }
+        if high.is_some() {
+            return Err(DisasmError::DecodingError(
+                "Hexadecimal string must have even number of characters".to_string(),
+            ));
+        }
+        Ok(bytes)
     }
 
     /// Parses a hex string with architecture-specific byte order handling.
@@ -83,57 +176,6 @@ impl HexParser {
         self.parse(hex_str, None)
     }
 
-    /// Cleans and normalizes a hexadecimal string.
-    ///
-    /// This method removes prefixes, whitespace, and validates that the string
-    /// contains only valid hexadecimal characters.
-    fn clean_hex_string(&self, input: &str) -> Result<String, DisasmError> {
-        let trimmed = input.trim().to_lowercase();
-
-        // Remove common prefixes
-        let no_prefix = if let Some(stripped) = trimmed.strip_prefix("0x") {
-            stripped
-        } else {
-            &trimmed
-        };
-
-        // Remove whitespace and validate characters
-        let cleaned: String = no_prefix.chars().filter(|c| !c.is_whitespace()).collect();
-
-        if cleaned.is_empty() {
-            return Err(DisasmError::DecodingError(
-                "Empty hexadecimal string".to_string(),
-            ));
-        }
-
-        // Validate that all characters are valid hex
-        if !cleaned.chars().all(|c| c.is_ascii_hexdigit()) {
-            return Err(DisasmError::DecodingError(
-                "Invalid hexadecimal characters found".to_string(),
-            ));
-        }
-
-        // Ensure even number of characters
-        if !cleaned.len().is_multiple_of(2) {
-            return Err(DisasmError::DecodingError(
-                "Hexadecimal string must have even number of characters".to_string(),
-            ));
-        }
-
-        Ok(cleaned)
-    }
-
-    /// Converts cleaned hex string to byte vector.
-    fn convert_to_bytes(&self, cleaned: &str) -> Result<Vec<u8>, DisasmError> {
-        match hex::decode(cleaned) {
-            Ok(bytes) => Ok(bytes),
-            Err(e) => Err(DisasmError::DecodingError(format!(
-                "Invalid hexadecimal input: {}",
-                e
-            ))),
-        }
-    }
-
     /// Determines the appropriate endianness for a given architecture.
     ///
     /// This method contains architecture-specific knowledge about byte ordering.
@@ -159,6 +201,143 @@ impl HexParser {
     }
 }
 
+/// Where the stream decoder is within the current whitespace-separated token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum TokenState {
+    /// Between tokens.
+    #[default]
+    Idle,
+    /// The token so far is a single `0`, which may start a `0x` prefix.
+    LeadingZero,
+    /// Just after a `0x` prefix, before any digit.
+    Prefix,
+    /// Inside the digits; holds the high nibble of an unfinished byte.
+    Digits(Option<u8>),
+}
+
+/// Incremental hex decoder for input that arrives in chunks, such as a pipe.
+///
+/// Accepts the same tokens as the CLI hex argument: whitespace-separated
+/// groups of an even number of digits, each with an optional `0x` prefix.
+/// A token, a prefix, or a byte may be split across chunks, so callers can
+/// feed whatever their reader returns and keep only the decoded bytes.
+/// Only ASCII whitespace separates tokens.
+///
+/// # Examples
+///
+/// ```rust
+/// use robustone_core::utils::hex::HexStreamDecoder;
+/// let mut decoder = HexStreamDecoder::new();
+/// let mut bytes = Vec::new();
+/// decoder.feed(b"93 0", &mut bytes).unwrap();
+/// decoder.feed(b"x0100 00\n", &mut bytes).unwrap();
+/// decoder.finish().unwrap();
+/// assert_eq!(bytes, vec![0x93, 0x01, 0x00, 0x00]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HexStreamDecoder {
+    state: TokenState,
+    offset: u64,
+}
+
+impl HexStreamDecoder {
+    /// Creates a decoder positioned before the first token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of input bytes consumed so far.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Decodes `chunk` and appends every completed byte to `out`.
+    ///
+    /// Errors name the input offset of the offending character, counted
+    /// from the start of the stream.
+    pub fn feed(&mut self, chunk: &[u8], out: &mut Vec<u8>) -> Result<(), DisasmError> {
+        out.reserve(chunk.len() / 2);
+        for &byte in chunk {
+            let value = HEX_VALUES[byte as usize];
+            self.state = if value != INVALID {
+                match self.state {
+                    TokenState::Idle if byte == b'0' => TokenState::LeadingZero,
+                    TokenState::LeadingZero => {
+                        out.push(value);
+                        TokenState::Digits(None)
+                    }
+                    TokenState::Digits(Some(high)) => {
+                        out.push((high << 4) | value);
+                        TokenState::Digits(None)
+                    }
+                    _ => TokenState::Digits(Some(value)),
+                }
+            } else if is_separator(byte) {
+                self.end_token()?;
+                TokenState::Idle
+            } else if self.state == TokenState::LeadingZero && (byte == b'x' || byte == b'X') {
+                TokenState::Prefix
+            } else {
+                return Err(self.invalid_character(byte));
+            };
+            self.offset += 1;
+        }
+        Ok(())
+    }
+
+    /// Checks that the input did not stop inside a token.
+    pub fn finish(mut self) -> Result<(), DisasmError> {
+        self.end_token()
+    }
+
+    fn end_token(&mut self) -> Result<(), DisasmError> {
+        match self.state {
+            TokenState::Idle | TokenState::Digits(None) => Ok(()),
+            TokenState::Prefix => Err(DisasmError::DecodingError(format!(
+                "Empty hex content before offset {}",
+                self.offset
+            ))),
+            TokenState::LeadingZero | TokenState::Digits(Some(_)) => {
+                Err(DisasmError::DecodingError(format!(
+                    "Hex token must have even number of digits (token ends at offset {})",
+                    self.offset
+                )))
+            }
+        }
+    }
+
+    fn invalid_character(&self, byte: u8) -> DisasmError {
+        let shown = if byte.is_ascii_graphic() {
+            format!("'{}'", char::from(byte))
+        } else {
+            format!("byte 0x{byte:02x}")
+        };
+        DisasmError::DecodingError(format!(
+            "Invalid hex character {shown} at offset {}",
+            self.offset
+        ))
+    }
+}
+
+/// `size` bytes of hex text in the spaced 32-bit word layout users paste,
+/// shared by the hex parsing benchmarks of this crate and the CLI.
+#[doc(hidden)]
+pub fn sample_hex_input(size: usize) -> String {
+    let mut text = String::with_capacity(size * 9 / 4 + 1);
+    for index in 0..size {
+        if index > 0 && index.is_multiple_of(4) {
+            text.push(' ');
+        }
+        text.push_str(&format!("{:02x}", (index * 37) as u8));
+    }
+    text
+}
+
+/// Whether `byte` separates tokens in streamed hex input.
+fn is_separator(byte: u8) -> bool {
+    byte.is_ascii() && char::from(byte).is_whitespace()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,5 +408,60 @@ mod tests {
 
         // Invalid characters
         assert!(parser.parse("xyz", None).is_err());
+        assert!(parser.parse("12 3g", None).is_err());
+    }
+
+    #[test]
+    fn test_decode_hex_digits_reports_first_bad_digit() {
+        let mut out = Vec::new();
+        assert_eq!(decode_hex_digits(b"00fFA9", &mut out), Ok(()));
+        assert_eq!(out, vec![0x00, 0xff, 0xa9]);
+
+        out.clear();
+        assert_eq!(decode_hex_digits(b"12g4", &mut out), Err(2));
+        assert_eq!(decode_hex_digits(b"123z", &mut out), Err(3));
+        assert_eq!(out, vec![0x12, 0x12]);
+        assert_eq!(hex_digit_value(b'F'), Some(15));
+        assert_eq!(hex_digit_value(b' '), None);
+    }
+
+    #[test]
+    fn test_stream_decoder_matches_whole_input_at_every_split() {
+        let input = b"0x93001000 13 0X0101\t00\n0a0b  ";
+        let expected = vec![0x93, 0x00, 0x10, 0x00, 0x13, 0x01, 0x01, 0x00, 0x0a, 0x0b];
+        for split in 0..=input.len() {
+            let mut decoder = HexStreamDecoder::new();
+            let mut bytes = Vec::new();
+            decoder.feed(&input[..split], &mut bytes).unwrap();
+            decoder.feed(&input[split..], &mut bytes).unwrap();
+            decoder.finish().unwrap();
+            assert_eq!(bytes, expected, "split at {split}");
+        }
+    }
+
+    #[test]
+    fn test_stream_decoder_rejects_malformed_tokens() {
+        let decode = |input: &[u8]| {
+            let mut decoder = HexStreamDecoder::new();
+            let mut bytes = Vec::new();
+            decoder
+                .feed(input, &mut bytes)
+                .and_then(|()| decoder.finish())
+                .map_err(|error| error.to_string())
+        };
+        assert!(decode(b"12 3g").unwrap_err().contains("'g' at offset 4"));
+        assert!(decode(b"123").unwrap_err().contains("even number"));
+        assert!(decode(b"12 0").unwrap_err().contains("even number"));
+        assert!(decode(b"0x").unwrap_err().contains("Empty hex content"));
+        assert!(decode(b"1x").is_err());
+        assert!(decode(b"\xc2\xa0").unwrap_err().contains("byte 0xc2"));
+        assert_eq!(decode(b" \n"), Ok(()));
+    }
+
+    #[test]
+    fn test_unicode_whitespace_is_skipped() {
+        let parser = HexParser::new();
+        assert_eq!(parser.parse("de\u{a0}ad", None).unwrap(), vec![0xde, 0xad]);
+        assert!(parser.parse("de\u{e9}ad", None).is_err());
     }
 }
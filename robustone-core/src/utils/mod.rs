@@ -7,11 +7,14 @@
 //! # Modules
 //!
 //! - [`hex`]: Hexadecimal string parsing utilities
+//! - [`bitfield`]: Bitfield extraction and sign extension for instruction decoders
 //! - [`endian`]: Endianness handling utilities for multi-architecture support
 
+pub mod bitfield;
 pub mod endian;
 pub mod hex;
 
 // Re-export main utilities with explicit names to avoid conflicts
+pub use bitfield::{BitField, FieldTable};
 pub use endian::{EndianConvert, Endianness};
 pub use hex::HexParser;
@@ -0,0 +1,71 @@
+//! Pins the stable surface of `robustone_core::prelude`.
+//!
+//! Every item the v2 prelude promises is named here, so removing or renaming
+//! one fails this test's build. Adding an item is fine; removing one is a
+//! breaking change under the versioning policy in `docs/public-contract.md`.
+
+#![allow(unused_imports)]
+
+use robustone_core::prelude::{
+    Access, AddressWidth, ArchSpec, ArchSpecError, Architecture, ArchitectureCapability,
+    ArchitectureDispatcher, ArchitectureHandler, ArchitectureId, ArchitectureProfile,
+    BasicInstructionDetail, DataStep, DecodeErrorKind, DecodeFailure, DecodeStats, DecodeStatus,
+    DecodedInstruction, Detail, DisasmError, EncodingClass, HandlerStats, Instruction,
+    ModeModifiers, NopStyle, OnDecodeError, Operand, OperandType, OutputStyle, RegisterId,
+    RenderOptions, RenderedDisassembly, RenderedInstruction, RenderedIssue, SemanticFlags,
+    TextRenderProfile, TypedOperand, all_architecture_capabilities, canonical_architecture_name,
+    lookup_architecture_capability, render_disassembly,
+};
+
+/// Signature shared by the dispatcher's byte-decoding entry points.
+type DecodeFn<T> = fn(&ArchitectureDispatcher, &[u8], &str, u64) -> Result<(T, usize), DisasmError>;
+
+/// Signature of the JSON envelope builder.
+type RenderFn = fn(
+    String,
+    u64,
+    usize,
+    Vec<RenderedIssue>,
+    &[Instruction],
+    RenderOptions,
+) -> RenderedDisassembly;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_prelude_keeps_the_dispatcher_signatures() {
+    assert_send_sync::<ArchitectureDispatcher>();
+    assert_send_sync::<Instruction>();
+    assert_send_sync::<DecodedInstruction>();
+    assert_send_sync::<DisasmError>();
+
+    let _: DecodeFn<Instruction> = ArchitectureDispatcher::disassemble_bytes;
+    let _: DecodeFn<DecodedInstruction> = ArchitectureDispatcher::decode_instruction;
+    let _: fn(&mut ArchitectureDispatcher, Box<dyn ArchitectureHandler>) =
+        ArchitectureDispatcher::register;
+    let _: fn(&ArchitectureDispatcher) -> DecodeStats = ArchitectureDispatcher::stats;
+    let _: fn(&ArchitectureDispatcher, &str, &str) -> Result<Vec<u8>, DisasmError> =
+        ArchitectureDispatcher::parse_hex;
+}
+
+#[test]
+fn test_prelude_covers_the_rendering_signatures() {
+    let _: fn(&Instruction, &OutputStyle) -> String = Instruction::to_line;
+    let _: RenderFn = render_disassembly;
+
+    let style = OutputStyle::detailed();
+    assert_eq!(style.render.text_profile, TextRenderProfile::Capstone);
+    assert_eq!(style.render.nops, NopStyle::Profile);
+}
+
+#[test]
+fn test_prelude_v2_is_the_default_prelude() {
+    use robustone_core::prelude::v2;
+
+    let dispatcher: v2::ArchitectureDispatcher = ArchitectureDispatcher::new();
+    assert!(dispatcher.supported_architectures().is_empty());
+    assert_eq!(
+        v2::canonical_architecture_name("rv64"),
+        canonical_architecture_name("rv64")
+    );
+}
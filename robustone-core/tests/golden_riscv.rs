@@ -70,6 +70,7 @@ fn assert_case(case: GoldenCase) {
             robustone::ir::Operand::Immediate { .. } => "immediate",
             robustone::ir::Operand::Text { .. } => "text",
             robustone::ir::Operand::Memory { .. } => "memory",
+            robustone::ir::Operand::SystemRegister { .. } => "system_register",
         })
         .collect::<Vec<_>>();
     assert_eq!(operand_kinds, case.expected_ir.operand_kinds);
@@ -90,6 +91,11 @@ fn test_c_lui_golden_fixture() {
     assert_case(load_case("c_lui.json"));
 }
 
+#[test]
+fn test_c_lui_negative_golden_fixture() {
+    assert_case(load_case("c_lui_negative.json"));
+}
+
 #[test]
 fn test_c_addiw_golden_fixture() {
     assert_case(load_case("c_addiw.json"));
@@ -160,6 +166,21 @@ fn test_csrr_scounteren_golden_fixture() {
     assert_case(load_case("csrr_scounteren.json"));
 }
 
+#[test]
+fn test_frcsr_golden_fixture() {
+    assert_case(load_case("frcsr.json"));
+}
+
+#[test]
+fn test_fscsr_golden_fixture() {
+    assert_case(load_case("fscsr.json"));
+}
+
+#[test]
+fn test_fsrmi_golden_fixture() {
+    assert_case(load_case("fsrmi.json"));
+}
+
 #[test]
 fn test_ir_rendering_covers_control_flow_and_atomic_variants() {
     let dispatcher = dispatcher();
@@ -205,21 +226,23 @@ fn test_ir_rendering_covers_control_flow_and_atomic_variants() {
             (instruction.mnemonic.clone(), instruction.operands.clone()),
             expected_capstone
         );
+        assert_eq!(decoded.render_canonical_text_parts(), expected_canonical);
     }
 }
 
 #[test]
-fn test_invalid_compressed_encoding_reports_failure() {
+fn test_reserved_compressed_encoding_reports_failure() {
     let dispatcher = dispatcher();
+    // c.addiw with rd = x0 is reserved on RV64.
     let error = dispatcher
         .decode_instruction(&[0x01, 0x20], "riscv64", 0)
-        .expect_err("invalid compressed encoding should fail");
+        .expect_err("reserved compressed encoding should fail");
 
     match error {
         robustone::DisasmError::DecodeFailure { kind, .. } => {
             assert_eq!(
                 kind,
-                robustone::types::error::DecodeErrorKind::InvalidEncoding
+                robustone::types::error::DecodeErrorKind::ReservedEncoding
             );
         }
         other => panic!("expected decode failure, got {other:?}"),
@@ -1,10 +1,10 @@
 use proptest::prelude::*;
 use robustone as rt;
 use robustone_core::ir::{
-    ArchitectureId, DecodeStatus, DecodedInstruction, Operand, RegisterId, RenderHints,
-    TextRenderProfile,
+    ArchitectureId, DecodeStatus, DecodedInstruction, MemoryOrdering, Operand, RegisterId,
+    RenderHints, TextRenderProfile,
 };
-use robustone_core::{Instruction, RenderOptions, render_instruction_text};
+use robustone_core::{Instruction, NopStyle, RenderOptions, render_instruction_text};
 use std::panic::AssertUnwindSafe;
 
 fn dispatcher_with_riscv() -> rt::ArchitectureDispatcher {
@@ -55,7 +55,7 @@ fn collect_register_ids(decoded: &DecodedInstruction) -> Vec<u32> {
                     ids.push(register.id);
                 }
             }
-            Operand::Immediate { .. } | Operand::Text { .. } => {}
+            Operand::Immediate { .. } | Operand::Text { .. } | Operand::SystemRegister { .. } => {}
         }
     }
 
@@ -84,6 +84,7 @@ fn render_options(profile: TextRenderProfile) -> RenderOptions {
         capstone_aliases: !matches!(profile, TextRenderProfile::Canonical),
         compressed_aliases: !matches!(profile, TextRenderProfile::Canonical),
         unsigned_immediate: false,
+        nops: NopStyle::Profile,
     }
 }
 
@@ -146,6 +147,7 @@ prop_compose! {
             size,
             raw_bytes,
             operands,
+            operand_access: Vec::new(),
             registers_read,
             registers_written,
             implicit_registers_read,
@@ -156,6 +158,7 @@ prop_compose! {
                 capstone_mnemonic,
                 capstone_hidden_operands,
             },
+            decoded_by: None,
             render: None,
         }
     }
@@ -168,8 +171,13 @@ fn operand_strategy() -> impl Strategy<Value = Operand> {
             .clone()
             .prop_map(|register| Operand::Register { register }),
         any::<i64>().prop_map(|value| Operand::Immediate { value }),
-        (prop::option::of(register_strategy.clone()), any::<i64>())
-            .prop_map(|(base, displacement)| Operand::Memory { base, displacement }),
+        (prop::option::of(register_strategy.clone()), any::<i64>()).prop_map(
+            |(base, displacement)| Operand::Memory {
+                base,
+                displacement,
+                ordering: MemoryOrdering::RELAXED,
+            }
+        ),
         prop::sample::select(vec![
             "rne".to_string(),
             "rtz".to_string(),
@@ -204,8 +212,11 @@ proptest! {
         if bytes.len() < 2 {
             prop_assert!(result.is_err());
             if let Err(error) = result {
-                let is_structured = matches!(error, rt::DisasmError::DecodeFailure { .. });
-                prop_assert!(is_structured);
+                let is_truncated = matches!(
+                    error,
+                    rt::DisasmError::Truncated { needed: 2 | 4 | 6 | 8 | 10, available: 1, .. }
+                );
+                prop_assert!(is_truncated);
             }
         }
     }
@@ -0,0 +1,56 @@
+//! Checks that every stable error kind is documented in
+//! `docs/public-contract.md`.
+
+use robustone_core::prelude::{DecodeErrorKind, DisasmError};
+
+const CONTRACT: &str = include_str!("../../docs/public-contract.md");
+
+/// One error of each shape `stable_kind` distinguishes. The match keeps
+/// this list in step with `DisasmError`: a new variant fails to compile
+/// here until it gets a sample.
+fn samples() -> Vec<DisasmError> {
+    let mut samples = vec![
+        DisasmError::UnsupportedArchitecture("bogus".to_string()),
+        DisasmError::truncated(None, 4, 2),
+        DisasmError::DecodingError("failed".to_string()),
+        DisasmError::InvalidHexCode("zz".to_string()),
+        DisasmError::InvalidAddress("zz".to_string()),
+        DisasmError::LimitExceeded("max instructions".to_string()),
+    ];
+    samples.extend(
+        [
+            DecodeErrorKind::NeedMoreBytes,
+            DecodeErrorKind::InvalidEncoding,
+            DecodeErrorKind::UnsupportedExtension,
+            DecodeErrorKind::UnimplementedInstruction,
+            DecodeErrorKind::UnsupportedMode,
+            DecodeErrorKind::ReservedEncoding,
+        ]
+        .map(|kind| DisasmError::decode_failure(kind, None, "failed")),
+    );
+
+    for sample in &samples {
+        match sample {
+            DisasmError::UnsupportedArchitecture(_)
+            | DisasmError::DecodeFailure { .. }
+            | DisasmError::Truncated { .. }
+            | DisasmError::DecodingError(_)
+            | DisasmError::InvalidHexCode(_)
+            | DisasmError::InvalidAddress(_)
+            | DisasmError::LimitExceeded(_)
+            | DisasmError::Located { .. } => {}
+        }
+    }
+    samples
+}
+
+#[test]
+fn test_every_stable_kind_is_in_the_error_table() {
+    for error in samples() {
+        let row = format!("| `{}` |", error.stable_kind());
+        assert!(
+            CONTRACT.lines().any(|line| line.starts_with(&row)),
+            "docs/public-contract.md has no error table row for {row}"
+        );
+    }
+}
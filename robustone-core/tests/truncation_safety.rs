@@ -0,0 +1,62 @@
+use proptest::prelude::*;
+use robustone as rt;
+use std::panic::AssertUnwindSafe;
+
+const ARCHITECTURES: &[&str] = &["riscv32", "riscv64", "aarch64", "x86", "x64", "loongarch64"];
+
+proptest! {
+    #[test]
+    fn test_decoders_never_panic_or_overrun_short_input(
+        bytes in prop::collection::vec(any::<u8>(), 0..8),
+        start in 0usize..4,
+    ) {
+        let dispatcher = rt::shared_dispatcher();
+        // Decode from an offset so the slice does not start on an aligned
+        // boundary of the original buffer.
+        let input = &bytes[start.min(bytes.len())..];
+        for arch in ARCHITECTURES {
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                dispatcher.disassemble_bytes(input, arch, 0x1001)
+            }));
+            prop_assert!(result.is_ok(), "{arch} panicked on {input:02x?}");
+            match result.unwrap() {
+                Ok((_, size)) => prop_assert!(size > 0 && size <= input.len()),
+                Err(rt::DisasmError::Truncated { needed, available, .. }) => {
+                    prop_assert_eq!(available, input.len());
+                    prop_assert!(needed > available);
+                }
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn test_every_prefix_of_a_valid_instruction_is_truncated() {
+    let dispatcher = rt::shared_dispatcher();
+    let cases: &[(&str, &[u8])] = &[
+        ("riscv32", &[0x93, 0x00, 0x10, 0x00]),
+        ("aarch64", &[0x1f, 0x20, 0x03, 0xd5]),
+        ("x86", &[0xb8, 0x01, 0x00, 0x00, 0x00]),
+        ("loongarch64", &[0x00, 0x00, 0x40, 0x03]),
+    ];
+    for (arch, encoding) in cases {
+        dispatcher
+            .disassemble_bytes(encoding, arch, 0)
+            .unwrap_or_else(|error| panic!("{arch} should decode the full encoding: {error}"));
+        for available in 0..encoding.len() {
+            let error = dispatcher
+                .disassemble_bytes(&encoding[..available], arch, 0)
+                .expect_err("a prefix should not decode");
+            assert!(
+                matches!(
+                    error,
+                    rt::DisasmError::Truncated { needed, available: got, .. }
+                        if got == available && needed > available
+                ),
+                "{arch} with {available} bytes: {error:?}"
+            );
+            assert_eq!(error.stable_kind(), "need_more_bytes");
+        }
+    }
+}
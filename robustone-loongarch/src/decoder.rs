@@ -34,11 +34,11 @@ impl LoongArchDecoder {
         addr: u64,
     ) -> Result<DecodedInstruction, DisasmError> {
         if bytes.len() < 4 {
-            return Err(DisasmError::DecodeFailure {
-                kind: DecodeErrorKind::NeedMoreBytes,
-                architecture: Some("loongarch64".to_string()),
-                detail: "need 4 bytes for LoongArch".to_string(),
-            });
+            return Err(DisasmError::truncated(
+                Some("loongarch64".to_string()),
+                4,
+                bytes.len(),
+            ));
         }
 
         let word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
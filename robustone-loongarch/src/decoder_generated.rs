@@ -2787,3 +2787,1199 @@ pub fn decode_loongarch_word(
     };
     Ok((mnemonic, operands, 4))
 }
+
+/// Every mnemonic the generated decoder can produce, sorted.
+pub const MNEMONICS: &[&str] = &[
+    "adc.b",
+    "adc.d",
+    "adc.h",
+    "adc.w",
+    "add.w",
+    "addi.w",
+    "addu12i.d",
+    "addu12i.w",
+    "alsl.w",
+    "and",
+    "andi",
+    "andn",
+    "armadc.w",
+    "armadd.w",
+    "armand.w",
+    "armmfflag",
+    "armmov.d",
+    "armmov.w",
+    "armmove",
+    "armmtflag",
+    "armnot.w",
+    "armor.w",
+    "armrotr.w",
+    "armrotri.w",
+    "armrrx.w",
+    "armsbc.w",
+    "armsll.w",
+    "armslli.w",
+    "armsra.w",
+    "armsrai.w",
+    "armsrl.w",
+    "armsrli.w",
+    "armsub.w",
+    "armxor.w",
+    "b",
+    "bceqz",
+    "bcnez",
+    "beq",
+    "beqz",
+    "bge",
+    "bgeu",
+    "bitrev.4b",
+    "bitrev.w",
+    "bl",
+    "blt",
+    "bltu",
+    "bne",
+    "bnez",
+    "break",
+    "bstrins.w",
+    "bstrpick.w",
+    "bytepick.w",
+    "cacop",
+    "clo.w",
+    "clz.w",
+    "cpucfg",
+    "crc.w.b.w",
+    "crc.w.d.w",
+    "crc.w.h.w",
+    "crc.w.w.w",
+    "crcc.w.b.w",
+    "crcc.w.d.w",
+    "crcc.w.h.w",
+    "crcc.w.w.w",
+    "csrrd",
+    "csrwr",
+    "csrxchg",
+    "cto.w",
+    "ctz.w",
+    "dbar",
+    "dbcl",
+    "div.w",
+    "div.wu",
+    "ertn",
+    "fabs.d",
+    "fabs.s",
+    "fadd.d",
+    "fadd.s",
+    "fclass.d",
+    "fclass.s",
+    "fcmp.caf.d",
+    "fcmp.caf.s",
+    "fcmp.ceq.d",
+    "fcmp.ceq.s",
+    "fcmp.cle.d",
+    "fcmp.cle.s",
+    "fcmp.clt.d",
+    "fcmp.clt.s",
+    "fcmp.cne.d",
+    "fcmp.cne.s",
+    "fcmp.cor.d",
+    "fcmp.cor.s",
+    "fcmp.cueq.d",
+    "fcmp.cueq.s",
+    "fcmp.cule.d",
+    "fcmp.cule.s",
+    "fcmp.cult.d",
+    "fcmp.cult.s",
+    "fcmp.cun.d",
+    "fcmp.cun.s",
+    "fcmp.cune.d",
+    "fcmp.cune.s",
+    "fcmp.saf.d",
+    "fcmp.saf.s",
+    "fcmp.seq.d",
+    "fcmp.seq.s",
+    "fcmp.sle.d",
+    "fcmp.sle.s",
+    "fcmp.slt.d",
+    "fcmp.slt.s",
+    "fcmp.sne.d",
+    "fcmp.sne.s",
+    "fcmp.sor.d",
+    "fcmp.sor.s",
+    "fcmp.sueq.d",
+    "fcmp.sueq.s",
+    "fcmp.sule.d",
+    "fcmp.sule.s",
+    "fcmp.sult.d",
+    "fcmp.sult.s",
+    "fcmp.sun.d",
+    "fcmp.sun.s",
+    "fcmp.sune.d",
+    "fcmp.sune.s",
+    "fcopysign.d",
+    "fcopysign.s",
+    "fcvt.d.ld",
+    "fcvt.d.s",
+    "fcvt.ld.d",
+    "fcvt.s.d",
+    "fcvt.ud.d",
+    "fdiv.d",
+    "fdiv.s",
+    "ffint.d.l",
+    "ffint.d.w",
+    "ffint.s.l",
+    "ffint.s.w",
+    "fld.d",
+    "fld.s",
+    "fldgt.d",
+    "fldgt.s",
+    "fldle.d",
+    "fldle.s",
+    "fldx.d",
+    "fldx.s",
+    "flogb.d",
+    "flogb.s",
+    "fmadd.d",
+    "fmadd.s",
+    "fmax.d",
+    "fmax.s",
+    "fmaxa.d",
+    "fmaxa.s",
+    "fmin.d",
+    "fmin.s",
+    "fmina.d",
+    "fmina.s",
+    "fmov.d",
+    "fmov.s",
+    "fmsub.d",
+    "fmsub.s",
+    "fmul.d",
+    "fmul.s",
+    "fneg.d",
+    "fneg.s",
+    "fnmadd.d",
+    "fnmadd.s",
+    "fnmsub.d",
+    "fnmsub.s",
+    "frecip.d",
+    "frecip.s",
+    "frecipe.d",
+    "frecipe.s",
+    "frint.d",
+    "frint.s",
+    "frsqrt.d",
+    "frsqrt.s",
+    "frsqrte.d",
+    "frsqrte.s",
+    "fscaleb.d",
+    "fscaleb.s",
+    "fsel",
+    "fsqrt.d",
+    "fsqrt.s",
+    "fst.d",
+    "fst.s",
+    "fstgt.d",
+    "fstgt.s",
+    "fstle.d",
+    "fstle.s",
+    "fstx.d",
+    "fstx.s",
+    "fsub.d",
+    "fsub.s",
+    "ftint.l.d",
+    "ftint.l.s",
+    "ftint.w.d",
+    "ftint.w.s",
+    "ftintrm.l.d",
+    "ftintrm.l.s",
+    "ftintrm.w.d",
+    "ftintrm.w.s",
+    "ftintrne.l.d",
+    "ftintrne.l.s",
+    "ftintrne.w.d",
+    "ftintrne.w.s",
+    "ftintrp.l.d",
+    "ftintrp.l.s",
+    "ftintrp.w.d",
+    "ftintrp.w.s",
+    "ftintrz.l.d",
+    "ftintrz.l.s",
+    "ftintrz.w.d",
+    "ftintrz.w.s",
+    "gcsrrd",
+    "gcsrwr",
+    "gcsrxchg",
+    "gtlbflush",
+    "hvcl",
+    "ibar",
+    "idle",
+    "invtlb",
+    "iocsrrd.b",
+    "iocsrrd.h",
+    "iocsrrd.w",
+    "iocsrwr.b",
+    "iocsrwr.h",
+    "iocsrwr.w",
+    "jirl",
+    "jiscr0",
+    "jiscr1",
+    "ld.b",
+    "ld.bu",
+    "ld.h",
+    "ld.hu",
+    "ld.w",
+    "lddir",
+    "ldgt.b",
+    "ldgt.d",
+    "ldgt.h",
+    "ldgt.w",
+    "ldl.d",
+    "ldl.w",
+    "ldle.b",
+    "ldle.d",
+    "ldle.h",
+    "ldle.w",
+    "ldpte",
+    "ldr.d",
+    "ldr.w",
+    "ll.w",
+    "llacq.w",
+    "lu12i.w",
+    "maskeqz",
+    "masknez",
+    "mod.w",
+    "mod.wu",
+    "movcf2fr",
+    "movcf2gr",
+    "move",
+    "movfcsr2gr",
+    "movfr2cf",
+    "movfr2gr.s",
+    "movgr2cf",
+    "movgr2fcsr",
+    "movgr2fr.w",
+    "movgr2scr",
+    "movscr2gr",
+    "mul.w",
+    "mulh.w",
+    "mulh.wu",
+    "nop",
+    "nor",
+    "or",
+    "ori",
+    "orn",
+    "pcaddi",
+    "pcaddu12i",
+    "pcalau12i",
+    "preld",
+    "rcr.b",
+    "rcr.d",
+    "rcr.h",
+    "rcr.w",
+    "rcri.b",
+    "rcri.d",
+    "rcri.h",
+    "rcri.w",
+    "rdtimeh.w",
+    "rdtimel.w",
+    "revb.2h",
+    "rotr.b",
+    "rotr.h",
+    "rotr.w",
+    "rotri.b",
+    "rotri.h",
+    "rotri.w",
+    "sbc.b",
+    "sbc.d",
+    "sbc.h",
+    "sbc.w",
+    "sc.w",
+    "screl.w",
+    "setarmj",
+    "setx86j",
+    "setx86loope",
+    "setx86loopne",
+    "sll.w",
+    "slli.w",
+    "slt",
+    "slti",
+    "sltu",
+    "sltui",
+    "sra.w",
+    "srai.w",
+    "srl.w",
+    "srli.w",
+    "st.b",
+    "st.h",
+    "st.w",
+    "stgt.b",
+    "stgt.d",
+    "stgt.h",
+    "stgt.w",
+    "stl.d",
+    "stl.w",
+    "stle.b",
+    "stle.d",
+    "stle.h",
+    "stle.w",
+    "str.d",
+    "str.w",
+    "sub.w",
+    "syscall",
+    "tlbclr",
+    "tlbfill",
+    "tlbflush",
+    "tlbrd",
+    "tlbsrch",
+    "tlbwr",
+    "vext2xv.d.b",
+    "vext2xv.d.h",
+    "vext2xv.d.w",
+    "vext2xv.du.bu",
+    "vext2xv.du.hu",
+    "vext2xv.du.wu",
+    "vext2xv.h.b",
+    "vext2xv.hu.bu",
+    "vext2xv.w.b",
+    "vext2xv.w.h",
+    "vext2xv.wu.bu",
+    "vext2xv.wu.hu",
+    "vreplvei.b",
+    "vreplvei.d",
+    "vreplvei.h",
+    "vreplvei.w",
+    "x86adc.b",
+    "x86adc.d",
+    "x86adc.h",
+    "x86adc.w",
+    "x86add.b",
+    "x86add.d",
+    "x86add.du",
+    "x86add.h",
+    "x86add.w",
+    "x86add.wu",
+    "x86and.b",
+    "x86and.d",
+    "x86and.h",
+    "x86and.w",
+    "x86clrtm",
+    "x86dec.b",
+    "x86dec.d",
+    "x86dec.h",
+    "x86dec.w",
+    "x86dectop",
+    "x86inc.b",
+    "x86inc.d",
+    "x86inc.h",
+    "x86inc.w",
+    "x86inctop",
+    "x86mfflag",
+    "x86mftop",
+    "x86mtflag",
+    "x86mttop",
+    "x86mul.b",
+    "x86mul.bu",
+    "x86mul.d",
+    "x86mul.du",
+    "x86mul.h",
+    "x86mul.hu",
+    "x86mul.w",
+    "x86mul.wu",
+    "x86or.b",
+    "x86or.d",
+    "x86or.h",
+    "x86or.w",
+    "x86rcl.b",
+    "x86rcl.d",
+    "x86rcl.h",
+    "x86rcl.w",
+    "x86rcli.b",
+    "x86rcli.d",
+    "x86rcli.h",
+    "x86rcli.w",
+    "x86rcr.b",
+    "x86rcr.d",
+    "x86rcr.h",
+    "x86rcr.w",
+    "x86rcri.b",
+    "x86rcri.d",
+    "x86rcri.h",
+    "x86rcri.w",
+    "x86rotl.b",
+    "x86rotl.d",
+    "x86rotl.h",
+    "x86rotl.w",
+    "x86rotli.b",
+    "x86rotli.d",
+    "x86rotli.h",
+    "x86rotli.w",
+    "x86rotr.b",
+    "x86rotr.d",
+    "x86rotr.h",
+    "x86rotr.w",
+    "x86rotri.b",
+    "x86rotri.d",
+    "x86rotri.h",
+    "x86rotri.w",
+    "x86sbc.b",
+    "x86sbc.d",
+    "x86sbc.h",
+    "x86sbc.w",
+    "x86settag",
+    "x86settm",
+    "x86sll.b",
+    "x86sll.d",
+    "x86sll.h",
+    "x86sll.w",
+    "x86slli.b",
+    "x86slli.d",
+    "x86slli.h",
+    "x86slli.w",
+    "x86sra.b",
+    "x86sra.d",
+    "x86sra.h",
+    "x86sra.w",
+    "x86srai.b",
+    "x86srai.d",
+    "x86srai.h",
+    "x86srai.w",
+    "x86srl.b",
+    "x86srl.d",
+    "x86srl.h",
+    "x86srl.w",
+    "x86srli.b",
+    "x86srli.d",
+    "x86srli.h",
+    "x86srli.w",
+    "x86sub.b",
+    "x86sub.d",
+    "x86sub.du",
+    "x86sub.h",
+    "x86sub.w",
+    "x86sub.wu",
+    "x86xor.b",
+    "x86xor.d",
+    "x86xor.h",
+    "x86xor.w",
+    "xor",
+    "xori",
+    "xvabsd.b",
+    "xvabsd.bu",
+    "xvabsd.d",
+    "xvabsd.du",
+    "xvabsd.h",
+    "xvabsd.hu",
+    "xvabsd.w",
+    "xvabsd.wu",
+    "xvadd.b",
+    "xvadd.d",
+    "xvadd.h",
+    "xvadd.q",
+    "xvadd.w",
+    "xvadda.b",
+    "xvadda.d",
+    "xvadda.h",
+    "xvadda.w",
+    "xvaddi.bu",
+    "xvaddi.du",
+    "xvaddi.hu",
+    "xvaddi.wu",
+    "xvaddwev.d.w",
+    "xvaddwev.d.wu",
+    "xvaddwev.d.wu.w",
+    "xvaddwev.h.b",
+    "xvaddwev.h.bu",
+    "xvaddwev.h.bu.b",
+    "xvaddwev.q.d",
+    "xvaddwev.q.du",
+    "xvaddwev.q.du.d",
+    "xvaddwev.w.h",
+    "xvaddwev.w.hu",
+    "xvaddwev.w.hu.h",
+    "xvaddwod.d.w",
+    "xvaddwod.d.wu",
+    "xvaddwod.d.wu.w",
+    "xvaddwod.h.b",
+    "xvaddwod.h.bu",
+    "xvaddwod.h.bu.b",
+    "xvaddwod.q.d",
+    "xvaddwod.q.du",
+    "xvaddwod.q.du.d",
+    "xvaddwod.w.h",
+    "xvaddwod.w.hu",
+    "xvaddwod.w.hu.h",
+    "xvand.v",
+    "xvandi.b",
+    "xvandn.v",
+    "xvavg.b",
+    "xvavg.bu",
+    "xvavg.d",
+    "xvavg.du",
+    "xvavg.h",
+    "xvavg.hu",
+    "xvavg.w",
+    "xvavg.wu",
+    "xvavgr.b",
+    "xvavgr.bu",
+    "xvavgr.d",
+    "xvavgr.du",
+    "xvavgr.h",
+    "xvavgr.hu",
+    "xvavgr.w",
+    "xvavgr.wu",
+    "xvbitclr.b",
+    "xvbitclr.d",
+    "xvbitclr.h",
+    "xvbitclr.w",
+    "xvbitclri.b",
+    "xvbitclri.d",
+    "xvbitclri.h",
+    "xvbitclri.w",
+    "xvbitrev.b",
+    "xvbitrev.d",
+    "xvbitrev.h",
+    "xvbitrev.w",
+    "xvbitrevi.b",
+    "xvbitrevi.d",
+    "xvbitrevi.h",
+    "xvbitrevi.w",
+    "xvbitsel.v",
+    "xvbitseli.b",
+    "xvbitset.b",
+    "xvbitset.d",
+    "xvbitset.h",
+    "xvbitset.w",
+    "xvbitseti.b",
+    "xvbitseti.d",
+    "xvbitseti.h",
+    "xvbitseti.w",
+    "xvbsll.v",
+    "xvbsrl.v",
+    "xvclo.b",
+    "xvclo.d",
+    "xvclo.h",
+    "xvclo.w",
+    "xvclz.b",
+    "xvclz.d",
+    "xvclz.h",
+    "xvclz.w",
+    "xvdiv.b",
+    "xvdiv.bu",
+    "xvdiv.d",
+    "xvdiv.du",
+    "xvdiv.h",
+    "xvdiv.hu",
+    "xvdiv.w",
+    "xvdiv.wu",
+    "xvexth.d.w",
+    "xvexth.du.wu",
+    "xvexth.h.b",
+    "xvexth.hu.bu",
+    "xvexth.q.d",
+    "xvexth.qu.du",
+    "xvexth.w.h",
+    "xvexth.wu.hu",
+    "xvextl.q.d",
+    "xvextl.qu.du",
+    "xvextrins.b",
+    "xvextrins.d",
+    "xvextrins.h",
+    "xvextrins.w",
+    "xvfadd.d",
+    "xvfadd.s",
+    "xvfclass.d",
+    "xvfclass.s",
+    "xvfcmp.caf.d",
+    "xvfcmp.caf.s",
+    "xvfcmp.ceq.d",
+    "xvfcmp.ceq.s",
+    "xvfcmp.cle.d",
+    "xvfcmp.cle.s",
+    "xvfcmp.clt.d",
+    "xvfcmp.clt.s",
+    "xvfcmp.cne.d",
+    "xvfcmp.cne.s",
+    "xvfcmp.cor.d",
+    "xvfcmp.cor.s",
+    "xvfcmp.cueq.d",
+    "xvfcmp.cueq.s",
+    "xvfcmp.cule.d",
+    "xvfcmp.cule.s",
+    "xvfcmp.cult.d",
+    "xvfcmp.cult.s",
+    "xvfcmp.cun.d",
+    "xvfcmp.cun.s",
+    "xvfcmp.cune.d",
+    "xvfcmp.cune.s",
+    "xvfcmp.saf.d",
+    "xvfcmp.saf.s",
+    "xvfcmp.seq.d",
+    "xvfcmp.seq.s",
+    "xvfcmp.sle.d",
+    "xvfcmp.sle.s",
+    "xvfcmp.slt.d",
+    "xvfcmp.slt.s",
+    "xvfcmp.sne.d",
+    "xvfcmp.sne.s",
+    "xvfcmp.sor.d",
+    "xvfcmp.sor.s",
+    "xvfcmp.sueq.d",
+    "xvfcmp.sueq.s",
+    "xvfcmp.sule.d",
+    "xvfcmp.sule.s",
+    "xvfcmp.sult.d",
+    "xvfcmp.sult.s",
+    "xvfcmp.sun.d",
+    "xvfcmp.sun.s",
+    "xvfcmp.sune.d",
+    "xvfcmp.sune.s",
+    "xvfcvt.h.s",
+    "xvfcvt.s.d",
+    "xvfcvth.d.s",
+    "xvfcvth.s.h",
+    "xvfcvtl.d.s",
+    "xvfcvtl.s.h",
+    "xvfdiv.d",
+    "xvfdiv.s",
+    "xvffint.d.l",
+    "xvffint.d.lu",
+    "xvffint.s.l",
+    "xvffint.s.w",
+    "xvffint.s.wu",
+    "xvffinth.d.w",
+    "xvffintl.d.w",
+    "xvflogb.d",
+    "xvflogb.s",
+    "xvfmadd.d",
+    "xvfmadd.s",
+    "xvfmax.d",
+    "xvfmax.s",
+    "xvfmaxa.d",
+    "xvfmaxa.s",
+    "xvfmin.d",
+    "xvfmin.s",
+    "xvfmina.d",
+    "xvfmina.s",
+    "xvfmsub.d",
+    "xvfmsub.s",
+    "xvfmul.d",
+    "xvfmul.s",
+    "xvfnmadd.d",
+    "xvfnmadd.s",
+    "xvfnmsub.d",
+    "xvfnmsub.s",
+    "xvfrecip.d",
+    "xvfrecip.s",
+    "xvfrecipe.d",
+    "xvfrecipe.s",
+    "xvfrint.d",
+    "xvfrint.s",
+    "xvfrintrm.d",
+    "xvfrintrm.s",
+    "xvfrintrne.d",
+    "xvfrintrne.s",
+    "xvfrintrp.d",
+    "xvfrintrp.s",
+    "xvfrintrz.d",
+    "xvfrintrz.s",
+    "xvfrsqrt.d",
+    "xvfrsqrt.s",
+    "xvfrsqrte.d",
+    "xvfrsqrte.s",
+    "xvfrstp.b",
+    "xvfrstp.h",
+    "xvfrstpi.b",
+    "xvfrstpi.h",
+    "xvfsqrt.d",
+    "xvfsqrt.s",
+    "xvfsub.d",
+    "xvfsub.s",
+    "xvftint.l.d",
+    "xvftint.lu.d",
+    "xvftint.w.d",
+    "xvftint.w.s",
+    "xvftint.wu.s",
+    "xvftinth.l.s",
+    "xvftintl.l.s",
+    "xvftintrm.l.d",
+    "xvftintrm.w.d",
+    "xvftintrm.w.s",
+    "xvftintrmh.l.s",
+    "xvftintrml.l.s",
+    "xvftintrne.l.d",
+    "xvftintrne.w.d",
+    "xvftintrne.w.s",
+    "xvftintrneh.l.s",
+    "xvftintrnel.l.s",
+    "xvftintrp.l.d",
+    "xvftintrp.w.d",
+    "xvftintrp.w.s",
+    "xvftintrph.l.s",
+    "xvftintrpl.l.s",
+    "xvftintrz.l.d",
+    "xvftintrz.lu.d",
+    "xvftintrz.w.d",
+    "xvftintrz.w.s",
+    "xvftintrz.wu.s",
+    "xvftintrzh.l.s",
+    "xvftintrzl.l.s",
+    "xvhaddw.d.w",
+    "xvhaddw.du.wu",
+    "xvhaddw.h.b",
+    "xvhaddw.hu.bu",
+    "xvhaddw.q.d",
+    "xvhaddw.qu.du",
+    "xvhaddw.w.h",
+    "xvhaddw.wu.hu",
+    "xvhsubw.d.w",
+    "xvhsubw.du.wu",
+    "xvhsubw.h.b",
+    "xvhsubw.hu.bu",
+    "xvhsubw.q.d",
+    "xvhsubw.qu.du",
+    "xvhsubw.w.h",
+    "xvhsubw.wu.hu",
+    "xvilvh.b",
+    "xvilvh.d",
+    "xvilvh.h",
+    "xvilvh.w",
+    "xvilvl.b",
+    "xvilvl.d",
+    "xvilvl.h",
+    "xvilvl.w",
+    "xvinsgr2vr.d",
+    "xvinsgr2vr.w",
+    "xvinsve0.d",
+    "xvinsve0.w",
+    "xvld",
+    "xvldrepl.b",
+    "xvldrepl.d",
+    "xvldrepl.h",
+    "xvldrepl.w",
+    "xvldx",
+    "xvmadd.b",
+    "xvmadd.d",
+    "xvmadd.h",
+    "xvmadd.w",
+    "xvmaddwev.d.w",
+    "xvmaddwev.d.wu",
+    "xvmaddwev.d.wu.w",
+    "xvmaddwev.h.b",
+    "xvmaddwev.h.bu",
+    "xvmaddwev.h.bu.b",
+    "xvmaddwev.q.d",
+    "xvmaddwev.q.du",
+    "xvmaddwev.q.du.d",
+    "xvmaddwev.w.h",
+    "xvmaddwev.w.hu",
+    "xvmaddwev.w.hu.h",
+    "xvmaddwod.d.w",
+    "xvmaddwod.d.wu",
+    "xvmaddwod.d.wu.w",
+    "xvmaddwod.h.b",
+    "xvmaddwod.h.bu",
+    "xvmaddwod.h.bu.b",
+    "xvmaddwod.q.d",
+    "xvmaddwod.q.du",
+    "xvmaddwod.q.du.d",
+    "xvmaddwod.w.h",
+    "xvmaddwod.w.hu",
+    "xvmaddwod.w.hu.h",
+    "xvmax.b",
+    "xvmax.bu",
+    "xvmax.d",
+    "xvmax.du",
+    "xvmax.h",
+    "xvmax.hu",
+    "xvmax.w",
+    "xvmax.wu",
+    "xvmaxi.b",
+    "xvmaxi.bu",
+    "xvmaxi.d",
+    "xvmaxi.du",
+    "xvmaxi.h",
+    "xvmaxi.hu",
+    "xvmaxi.w",
+    "xvmaxi.wu",
+    "xvmin.b",
+    "xvmin.bu",
+    "xvmin.d",
+    "xvmin.du",
+    "xvmin.h",
+    "xvmin.hu",
+    "xvmin.w",
+    "xvmin.wu",
+    "xvmini.b",
+    "xvmini.bu",
+    "xvmini.d",
+    "xvmini.du",
+    "xvmini.h",
+    "xvmini.hu",
+    "xvmini.w",
+    "xvmini.wu",
+    "xvmod.b",
+    "xvmod.bu",
+    "xvmod.d",
+    "xvmod.du",
+    "xvmod.h",
+    "xvmod.hu",
+    "xvmod.w",
+    "xvmod.wu",
+    "xvmskgez.b",
+    "xvmskltz.b",
+    "xvmskltz.d",
+    "xvmskltz.h",
+    "xvmskltz.w",
+    "xvmsknz.b",
+    "xvmsub.b",
+    "xvmsub.d",
+    "xvmsub.h",
+    "xvmsub.w",
+    "xvmuh.b",
+    "xvmuh.bu",
+    "xvmuh.d",
+    "xvmuh.du",
+    "xvmuh.h",
+    "xvmuh.hu",
+    "xvmuh.w",
+    "xvmuh.wu",
+    "xvmul.b",
+    "xvmul.d",
+    "xvmul.h",
+    "xvmul.w",
+    "xvmulwev.d.w",
+    "xvmulwev.d.wu",
+    "xvmulwev.d.wu.w",
+    "xvmulwev.h.b",
+    "xvmulwev.h.bu",
+    "xvmulwev.h.bu.b",
+    "xvmulwev.q.d",
+    "xvmulwev.q.du",
+    "xvmulwev.q.du.d",
+    "xvmulwev.w.h",
+    "xvmulwev.w.hu",
+    "xvmulwev.w.hu.h",
+    "xvmulwod.d.w",
+    "xvmulwod.d.wu",
+    "xvmulwod.d.wu.w",
+    "xvmulwod.h.b",
+    "xvmulwod.h.bu",
+    "xvmulwod.h.bu.b",
+    "xvmulwod.q.d",
+    "xvmulwod.q.du",
+    "xvmulwod.q.du.d",
+    "xvmulwod.w.h",
+    "xvmulwod.w.hu",
+    "xvmulwod.w.hu.h",
+    "xvneg.b",
+    "xvneg.d",
+    "xvneg.h",
+    "xvneg.w",
+    "xvnor.v",
+    "xvnori.b",
+    "xvor.v",
+    "xvori.b",
+    "xvorn.v",
+    "xvpackev.b",
+    "xvpackev.d",
+    "xvpackev.h",
+    "xvpackev.w",
+    "xvpackod.b",
+    "xvpackod.d",
+    "xvpackod.h",
+    "xvpackod.w",
+    "xvpcnt.b",
+    "xvpcnt.d",
+    "xvpcnt.h",
+    "xvpcnt.w",
+    "xvperm.w",
+    "xvpermi.d",
+    "xvpermi.q",
+    "xvpermi.w",
+    "xvpickev.b",
+    "xvpickev.d",
+    "xvpickev.h",
+    "xvpickev.w",
+    "xvpickod.b",
+    "xvpickod.d",
+    "xvpickod.h",
+    "xvpickod.w",
+    "xvpickve.d",
+    "xvpickve.w",
+    "xvpickve2gr.d",
+    "xvpickve2gr.du",
+    "xvpickve2gr.w",
+    "xvpickve2gr.wu",
+    "xvrepl128vei.b",
+    "xvrepl128vei.d",
+    "xvrepl128vei.h",
+    "xvrepl128vei.w",
+    "xvreplgr2vr.b",
+    "xvreplgr2vr.d",
+    "xvreplgr2vr.h",
+    "xvreplgr2vr.w",
+    "xvreplve.b",
+    "xvreplve.d",
+    "xvreplve.h",
+    "xvreplve.w",
+    "xvreplve0.b",
+    "xvreplve0.d",
+    "xvreplve0.h",
+    "xvreplve0.q",
+    "xvreplve0.w",
+    "xvrotr.b",
+    "xvrotr.d",
+    "xvrotr.h",
+    "xvrotr.w",
+    "xvrotri.b",
+    "xvrotri.d",
+    "xvrotri.h",
+    "xvrotri.w",
+    "xvsadd.b",
+    "xvsadd.bu",
+    "xvsadd.d",
+    "xvsadd.du",
+    "xvsadd.h",
+    "xvsadd.hu",
+    "xvsadd.w",
+    "xvsadd.wu",
+    "xvsat.b",
+    "xvsat.bu",
+    "xvsat.d",
+    "xvsat.du",
+    "xvsat.h",
+    "xvsat.hu",
+    "xvsat.w",
+    "xvsat.wu",
+    "xvseq.b",
+    "xvseq.d",
+    "xvseq.h",
+    "xvseq.w",
+    "xvseqi.b",
+    "xvseqi.d",
+    "xvseqi.h",
+    "xvseqi.w",
+    "xvsetallnez.b",
+    "xvsetallnez.d",
+    "xvsetallnez.h",
+    "xvsetallnez.w",
+    "xvsetanyeqz.b",
+    "xvsetanyeqz.d",
+    "xvsetanyeqz.h",
+    "xvsetanyeqz.w",
+    "xvseteqz.v",
+    "xvsetnez.v",
+    "xvshuf.b",
+    "xvshuf.d",
+    "xvshuf.h",
+    "xvshuf.w",
+    "xvshuf4i.b",
+    "xvshuf4i.d",
+    "xvshuf4i.h",
+    "xvshuf4i.w",
+    "xvsigncov.b",
+    "xvsigncov.d",
+    "xvsigncov.h",
+    "xvsigncov.w",
+    "xvsle.b",
+    "xvsle.bu",
+    "xvsle.d",
+    "xvsle.du",
+    "xvsle.h",
+    "xvsle.hu",
+    "xvsle.w",
+    "xvsle.wu",
+    "xvslei.b",
+    "xvslei.bu",
+    "xvslei.d",
+    "xvslei.du",
+    "xvslei.h",
+    "xvslei.hu",
+    "xvslei.w",
+    "xvslei.wu",
+    "xvsll.b",
+    "xvsll.d",
+    "xvsll.h",
+    "xvsll.w",
+    "xvslli.b",
+    "xvslli.d",
+    "xvslli.h",
+    "xvslli.w",
+    "xvsllwil.d.w",
+    "xvsllwil.du.wu",
+    "xvsllwil.h.b",
+    "xvsllwil.hu.bu",
+    "xvsllwil.w.h",
+    "xvsllwil.wu.hu",
+    "xvslt.b",
+    "xvslt.bu",
+    "xvslt.d",
+    "xvslt.du",
+    "xvslt.h",
+    "xvslt.hu",
+    "xvslt.w",
+    "xvslt.wu",
+    "xvslti.b",
+    "xvslti.bu",
+    "xvslti.d",
+    "xvslti.du",
+    "xvslti.h",
+    "xvslti.hu",
+    "xvslti.w",
+    "xvslti.wu",
+    "xvsra.b",
+    "xvsra.d",
+    "xvsra.h",
+    "xvsra.w",
+    "xvsrai.b",
+    "xvsrai.d",
+    "xvsrai.h",
+    "xvsrai.w",
+    "xvsran.b.h",
+    "xvsran.h.w",
+    "xvsran.w.d",
+    "xvsrani.b.h",
+    "xvsrani.d.q",
+    "xvsrani.h.w",
+    "xvsrani.w.d",
+    "xvsrar.b",
+    "xvsrar.d",
+    "xvsrar.h",
+    "xvsrar.w",
+    "xvsrari.b",
+    "xvsrari.d",
+    "xvsrari.h",
+    "xvsrari.w",
+    "xvsrarn.b.h",
+    "xvsrarn.h.w",
+    "xvsrarn.w.d",
+    "xvsrarni.b.h",
+    "xvsrarni.d.q",
+    "xvsrarni.h.w",
+    "xvsrarni.w.d",
+    "xvsrl.b",
+    "xvsrl.d",
+    "xvsrl.h",
+    "xvsrl.w",
+    "xvsrli.b",
+    "xvsrli.d",
+    "xvsrli.h",
+    "xvsrli.w",
+    "xvsrln.b.h",
+    "xvsrln.h.w",
+    "xvsrln.w.d",
+    "xvsrlni.b.h",
+    "xvsrlni.d.q",
+    "xvsrlni.h.w",
+    "xvsrlni.w.d",
+    "xvsrlr.b",
+    "xvsrlr.d",
+    "xvsrlr.h",
+    "xvsrlr.w",
+    "xvsrlri.b",
+    "xvsrlri.d",
+    "xvsrlri.h",
+    "xvsrlri.w",
+    "xvsrlrn.b.h",
+    "xvsrlrn.h.w",
+    "xvsrlrn.w.d",
+    "xvsrlrni.b.h",
+    "xvsrlrni.d.q",
+    "xvsrlrni.h.w",
+    "xvsrlrni.w.d",
+    "xvssran.b.h",
+    "xvssran.bu.h",
+    "xvssran.h.w",
+    "xvssran.hu.w",
+    "xvssran.w.d",
+    "xvssran.wu.d",
+    "xvssrani.b.h",
+    "xvssrani.bu.h",
+    "xvssrani.d.q",
+    "xvssrani.du.q",
+    "xvssrani.h.w",
+    "xvssrani.hu.w",
+    "xvssrani.w.d",
+    "xvssrani.wu.d",
+    "xvssrarn.b.h",
+    "xvssrarn.bu.h",
+    "xvssrarn.h.w",
+    "xvssrarn.hu.w",
+    "xvssrarn.w.d",
+    "xvssrarn.wu.d",
+    "xvssrarni.b.h",
+    "xvssrarni.bu.h",
+    "xvssrarni.d.q",
+    "xvssrarni.du.q",
+    "xvssrarni.h.w",
+    "xvssrarni.hu.w",
+    "xvssrarni.w.d",
+    "xvssrarni.wu.d",
+    "xvssrln.b.h",
+    "xvssrln.bu.h",
+    "xvssrln.h.w",
+    "xvssrln.hu.w",
+    "xvssrln.w.d",
+    "xvssrln.wu.d",
+    "xvssrlni.b.h",
+    "xvssrlni.bu.h",
+    "xvssrlni.d.q",
+    "xvssrlni.du.q",
+    "xvssrlni.h.w",
+    "xvssrlni.hu.w",
+    "xvssrlni.w.d",
+    "xvssrlni.wu.d",
+    "xvssrlrn.b.h",
+    "xvssrlrn.bu.h",
+    "xvssrlrn.h.w",
+    "xvssrlrn.hu.w",
+    "xvssrlrn.w.d",
+    "xvssrlrn.wu.d",
+    "xvssrlrni.b.h",
+    "xvssrlrni.bu.h",
+    "xvssrlrni.d.q",
+    "xvssrlrni.du.q",
+    "xvssrlrni.h.w",
+    "xvssrlrni.hu.w",
+    "xvssrlrni.w.d",
+    "xvssrlrni.wu.d",
+    "xvssub.b",
+    "xvssub.bu",
+    "xvssub.d",
+    "xvssub.du",
+    "xvssub.h",
+    "xvssub.hu",
+    "xvssub.w",
+    "xvssub.wu",
+    "xvst",
+    "xvstelm.b",
+    "xvstelm.d",
+    "xvstelm.h",
+    "xvstelm.w",
+    "xvstx",
+    "xvsub.b",
+    "xvsub.d",
+    "xvsub.h",
+    "xvsub.q",
+    "xvsub.w",
+    "xvsubi.bu",
+    "xvsubi.du",
+    "xvsubi.hu",
+    "xvsubi.wu",
+    "xvsubwev.d.w",
+    "xvsubwev.d.wu",
+    "xvsubwev.h.b",
+    "xvsubwev.h.bu",
+    "xvsubwev.q.d",
+    "xvsubwev.q.du",
+    "xvsubwev.w.h",
+    "xvsubwev.w.hu",
+    "xvsubwod.d.w",
+    "xvsubwod.d.wu",
+    "xvsubwod.h.b",
+    "xvsubwod.h.bu",
+    "xvsubwod.q.d",
+    "xvsubwod.q.du",
+    "xvsubwod.w.h",
+    "xvsubwod.w.hu",
+    "xvxor.v",
+    "xvxori.b",
+];
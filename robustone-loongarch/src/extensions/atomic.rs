@@ -32,4 +32,8 @@ impl InstructionFamily for AtomicFamily {
     fn name(&self) -> &'static str {
         "atomic"
     }
+
+    fn handles(&self, mnemonic: &str) -> bool {
+        is_atomic(mnemonic)
+    }
 }
@@ -487,4 +487,8 @@ impl InstructionFamily for BaseFamily {
     fn name(&self) -> &'static str {
         "base"
     }
+
+    fn handles(&self, mnemonic: &str) -> bool {
+        is_base(mnemonic)
+    }
 }
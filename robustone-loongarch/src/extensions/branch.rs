@@ -46,4 +46,8 @@ impl InstructionFamily for BranchFamily {
     fn name(&self) -> &'static str {
         "branch"
     }
+
+    fn handles(&self, mnemonic: &str) -> bool {
+        is_branch(mnemonic)
+    }
 }
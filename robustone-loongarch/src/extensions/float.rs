@@ -32,4 +32,8 @@ impl InstructionFamily for FloatFamily {
     fn name(&self) -> &'static str {
         "float"
     }
+
+    fn handles(&self, mnemonic: &str) -> bool {
+        is_float(mnemonic)
+    }
 }
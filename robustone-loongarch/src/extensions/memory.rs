@@ -103,4 +103,8 @@ impl InstructionFamily for MemoryFamily {
     fn name(&self) -> &'static str {
         "memory"
     }
+
+    fn handles(&self, mnemonic: &str) -> bool {
+        is_memory(mnemonic)
+    }
 }
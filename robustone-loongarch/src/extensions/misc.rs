@@ -32,4 +32,8 @@ impl InstructionFamily for MiscFamily {
     fn name(&self) -> &'static str {
         "misc"
     }
+
+    fn handles(&self, mnemonic: &str) -> bool {
+        is_misc(mnemonic)
+    }
 }
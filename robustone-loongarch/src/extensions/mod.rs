@@ -4,6 +4,7 @@
 //! mirroring the proven architecture of `robustone-riscv/src/extensions/`.
 
 use robustone_core::ir::{ArchitectureId, DecodeStatus, DecodedInstruction, RenderHints};
+use robustone_core::traits::MnemonicGroup;
 use robustone_core::types::error::DisasmError;
 
 pub mod atomic;
@@ -15,7 +16,7 @@ pub mod misc;
 pub mod vector;
 
 /// Common trait implemented by every instruction family.
-pub trait InstructionFamily: Sync {
+pub trait InstructionFamily: Send + Sync {
     /// Attempt to decode `word` at `addr`.
     ///
     /// Returns `Some(Ok(...))` when the family successfully decodes the
@@ -25,6 +26,9 @@ pub trait InstructionFamily: Sync {
 
     /// Human-readable family name (used for diagnostics).
     fn name(&self) -> &'static str;
+
+    /// Check whether a generated-decoder mnemonic belongs to this family.
+    fn handles(&self, mnemonic: &str) -> bool;
 }
 
 /// Build a [`DecodedInstruction`] from decoded components.
@@ -47,6 +51,7 @@ pub(crate) fn build_decoded_instruction(
         size,
         raw_bytes: word.to_le_bytes().to_vec(),
         operands,
+        operand_access: Vec::new(),
         registers_read: Vec::new(),
         registers_written: Vec::new(),
         implicit_registers_read: Vec::new(),
@@ -54,6 +59,7 @@ pub(crate) fn build_decoded_instruction(
         groups: Vec::new(),
         status: DecodeStatus::Success,
         render_hints: RenderHints::default(),
+        decoded_by: None,
         render: Some(crate::render::render_loongarch_text_parts),
     }
 }
@@ -74,3 +80,23 @@ pub fn create_families() -> Vec<Box<dyn InstructionFamily>> {
         Box::new(base::BaseFamily),
     ]
 }
+
+/// Group every decodable mnemonic under the first family that claims it,
+/// mirroring the order in which the decoder tries families.
+pub fn mnemonic_groups() -> Vec<MnemonicGroup> {
+    let families = create_families();
+    let mut groups = families
+        .iter()
+        .map(|family| MnemonicGroup {
+            name: family.name(),
+            mnemonics: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+
+    for mnemonic in crate::decoder_generated::MNEMONICS {
+        if let Some(index) = families.iter().position(|family| family.handles(mnemonic)) {
+            groups[index].mnemonics.push(mnemonic);
+        }
+    }
+    groups
+}
@@ -32,4 +32,8 @@ impl InstructionFamily for VectorFamily {
     fn name(&self) -> &'static str {
         "vector"
     }
+
+    fn handles(&self, mnemonic: &str) -> bool {
+        is_vector(mnemonic)
+    }
 }
@@ -43,10 +43,11 @@ pub use robustone_core::Instruction;
 use arch::LoongArchInstructionDetail;
 use decoder::LoongArchDecoder;
 use robustone_core::{
+    canonical_architecture_name,
     common::ArchitectureProfile,
     ir::{DecodedInstruction, TextRenderProfile},
-    traits::ArchitectureHandler,
     traits::instruction::Detail,
+    traits::{ArchitectureHandler, MnemonicGroup},
     types::error::DisasmError,
 };
 
@@ -159,7 +160,11 @@ impl ArchitectureHandler for LoongArchHandler {
     }
 
     fn supports(&self, arch_name: &str) -> bool {
-        matches!(arch_name, "loongarch" | "loongarch64" | "loongarch32")
+        canonical_architecture_name(arch_name) == Some("loongarch64")
+    }
+
+    fn supported_mnemonics(&self, _arch_name: &str) -> Vec<MnemonicGroup> {
+        extensions::mnemonic_groups()
     }
 }
 
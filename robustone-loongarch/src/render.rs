@@ -124,7 +124,7 @@ pub fn render_loongarch_text_parts(
         .collect::<Vec<_>>();
 
     let is_pc_relative = PC_RELATIVE_MNEMONICS.contains(&mnemonic.as_str());
-    let pc = instruction.address as i64;
+    let pc = instruction.address;
     let imm_mask = immediate_mask_for_mnemonic(&mnemonic);
 
     let mut operands = visible_operands
@@ -136,7 +136,11 @@ pub fn render_loongarch_text_parts(
                 && i == visible_operands.len() - 1
                 && let Operand::Immediate { value } = operand
             {
-                return format_loongarch_immediate(value + pc, unsigned_immediate, imm_mask);
+                return format_loongarch_immediate(
+                    pc.wrapping_add_signed(*value) as i64,
+                    unsigned_immediate,
+                    imm_mask,
+                );
             }
             format_loongarch_operand(operand, alias_regs, unsigned_immediate, imm_mask)
         })
@@ -168,6 +172,7 @@ fn format_loongarch_operand(
         Operand::Memory {
             base: Some(base),
             displacement,
+            ..
         } => format!(
             "{}({})",
             format_loongarch_immediate(*displacement, unsigned_immediate, imm_mask),
@@ -176,7 +181,11 @@ fn format_loongarch_operand(
         Operand::Memory {
             base: None,
             displacement,
+            ..
         } => format_loongarch_immediate(*displacement, unsigned_immediate, imm_mask),
+        Operand::SystemRegister { number } => {
+            format_loongarch_immediate(i64::from(*number), unsigned_immediate, imm_mask)
+        }
     }
 }
 
@@ -0,0 +1,195 @@
+//! Alias mnemonics and the canonical instructions they stand for.
+//!
+//! RISC-V assembly has many spellings for one encoding: pseudo-instructions
+//! from the ISA manual (`mv`, `bgt`, `zext.b`), the aliases the decoder
+//! prints (`li`, `beqz`, `csrr`), and names that older specifications used
+//! (`fmv.x.s`, `scall`). This table maps each alias to its canonical
+//! mnemonic together with the operand rewrite, so a search for `blt` can
+//! also match `bgt` lines and an assembler can expand the alias.
+//!
+//! Multi-instruction pseudo-instructions (`la`, `call`, `tail`, `li` with a
+//! large immediate) are not listed: they have no single canonical form.
+
+/// One alias mnemonic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alias {
+    /// The alias as written, such as `bgt`.
+    pub alias: &'static str,
+    /// The canonical mnemonic it decodes as, such as `blt`.
+    pub canonical: &'static str,
+    /// Operand template of the alias, such as `bgt rs, rt, offset`.
+    pub syntax: &'static str,
+    /// The same instruction in canonical form, such as `blt rt, rs, offset`.
+    pub expansion: &'static str,
+}
+
+const fn alias(
+    alias: &'static str,
+    canonical: &'static str,
+    syntax: &'static str,
+    expansion: &'static str,
+) -> Alias {
+    Alias {
+        alias,
+        canonical,
+        syntax,
+        expansion,
+    }
+}
+
+/// Every single-instruction alias, grouped as in the ISA manual's
+/// pseudo-instruction tables.
+pub const ALIASES: &[Alias] = &[
+    // Integer computation.
+    alias("nop", "addi", "nop", "addi x0, x0, 0"),
+    alias("li", "addi", "li rd, imm", "addi rd, x0, imm"),
+    alias("mv", "addi", "mv rd, rs", "addi rd, rs, 0"),
+    alias("not", "xori", "not rd, rs", "xori rd, rs, -1"),
+    alias("neg", "sub", "neg rd, rs", "sub rd, x0, rs"),
+    alias("negw", "subw", "negw rd, rs", "subw rd, x0, rs"),
+    alias("sext.w", "addiw", "sext.w rd, rs", "addiw rd, rs, 0"),
+    alias("zext.b", "andi", "zext.b rd, rs", "andi rd, rs, 255"),
+    alias("seqz", "sltiu", "seqz rd, rs", "sltiu rd, rs, 1"),
+    alias("snez", "sltu", "snez rd, rs", "sltu rd, x0, rs"),
+    alias("sltz", "slt", "sltz rd, rs", "slt rd, rs, x0"),
+    alias("sgtz", "slt", "sgtz rd, rs", "slt rd, x0, rs"),
+    alias("sgt", "slt", "sgt rd, rs, rt", "slt rd, rt, rs"),
+    alias("sgtu", "sltu", "sgtu rd, rs, rt", "sltu rd, rt, rs"),
+    // Branches.
+    alias("beqz", "beq", "beqz rs, offset", "beq rs, x0, offset"),
+    alias("bnez", "bne", "bnez rs, offset", "bne rs, x0, offset"),
+    alias("blez", "bge", "blez rs, offset", "bge x0, rs, offset"),
+    alias("bgez", "bge", "bgez rs, offset", "bge rs, x0, offset"),
+    alias("bltz", "blt", "bltz rs, offset", "blt rs, x0, offset"),
+    alias("bgtz", "blt", "bgtz rs, offset", "blt x0, rs, offset"),
+    alias("bgt", "blt", "bgt rs, rt, offset", "blt rt, rs, offset"),
+    alias("ble", "bge", "ble rs, rt, offset", "bge rt, rs, offset"),
+    alias("bgtu", "bltu", "bgtu rs, rt, offset", "bltu rt, rs, offset"),
+    alias("bleu", "bgeu", "bleu rs, rt, offset", "bgeu rt, rs, offset"),
+    // Jumps.
+    alias("j", "jal", "j offset", "jal x0, offset"),
+    alias("jr", "jalr", "jr rs", "jalr x0, 0(rs)"),
+    alias("ret", "jalr", "ret", "jalr x0, 0(x1)"),
+    // Floating point.
+    alias("fmv.s", "fsgnj.s", "fmv.s rd, rs", "fsgnj.s rd, rs, rs"),
+    alias("fabs.s", "fsgnjx.s", "fabs.s rd, rs", "fsgnjx.s rd, rs, rs"),
+    alias("fneg.s", "fsgnjn.s", "fneg.s rd, rs", "fsgnjn.s rd, rs, rs"),
+    alias("fmv.d", "fsgnj.d", "fmv.d rd, rs", "fsgnj.d rd, rs, rs"),
+    alias("fabs.d", "fsgnjx.d", "fabs.d rd, rs", "fsgnjx.d rd, rs, rs"),
+    alias("fneg.d", "fsgnjn.d", "fneg.d rd, rs", "fsgnjn.d rd, rs, rs"),
+    // Control and status registers.
+    alias("csrr", "csrrs", "csrr rd, csr", "csrrs rd, csr, x0"),
+    alias("csrw", "csrrw", "csrw csr, rs", "csrrw x0, csr, rs"),
+    alias("csrs", "csrrs", "csrs csr, rs", "csrrs x0, csr, rs"),
+    alias("csrc", "csrrc", "csrc csr, rs", "csrrc x0, csr, rs"),
+    alias("csrwi", "csrrwi", "csrwi csr, imm", "csrrwi x0, csr, imm"),
+    alias("csrsi", "csrrsi", "csrsi csr, imm", "csrrsi x0, csr, imm"),
+    alias("csrci", "csrrci", "csrci csr, imm", "csrrci x0, csr, imm"),
+    alias("rdcycle", "csrrs", "rdcycle rd", "csrrs rd, cycle, x0"),
+    alias("rdtime", "csrrs", "rdtime rd", "csrrs rd, time, x0"),
+    alias(
+        "rdinstret",
+        "csrrs",
+        "rdinstret rd",
+        "csrrs rd, instret, x0",
+    ),
+    alias("frcsr", "csrrs", "frcsr rd", "csrrs rd, fcsr, x0"),
+    alias("fscsr", "csrrw", "fscsr rd, rs", "csrrw rd, fcsr, rs"),
+    alias("frrm", "csrrs", "frrm rd", "csrrs rd, frm, x0"),
+    alias("fsrm", "csrrw", "fsrm rd, rs", "csrrw rd, frm, rs"),
+    alias("frflags", "csrrs", "frflags rd", "csrrs rd, fflags, x0"),
+    alias("fsflags", "csrrw", "fsflags rd, rs", "csrrw rd, fflags, rs"),
+    // Names from earlier versions of the specification.
+    alias("fmv.x.s", "fmv.x.w", "fmv.x.s rd, rs", "fmv.x.w rd, rs"),
+    alias("fmv.s.x", "fmv.w.x", "fmv.s.x rd, rs", "fmv.w.x rd, rs"),
+    alias("scall", "ecall", "scall", "ecall"),
+    alias("sbreak", "ebreak", "sbreak", "ebreak"),
+];
+
+/// The alias entry for `mnemonic`, if it is an alias.
+///
+/// A few aliases (`jal offset`, `jalr rs`, `fence`) share their canonical
+/// spelling and are not listed.
+pub fn lookup(mnemonic: &str) -> Option<&'static Alias> {
+    ALIASES.iter().find(|entry| entry.alias == mnemonic)
+}
+
+/// The canonical mnemonic `mnemonic` decodes as: the alias target, or the
+/// mnemonic itself when it is not an alias.
+pub fn canonical_mnemonic(mnemonic: &str) -> &str {
+    lookup(mnemonic).map_or(mnemonic, |entry| entry.canonical)
+}
+
+/// Every alias of the canonical mnemonic `canonical`, in table order.
+pub fn aliases_of(canonical: &str) -> impl Iterator<Item = &'static Alias> + '_ {
+    ALIASES
+        .iter()
+        .filter(move |entry| entry.canonical == canonical)
+}
+
+/// Whether two spellings can name the same instruction, such as `bgt` and
+/// `blt` or `fmv.x.s` and `fmv.x.w`.
+///
+/// This compares canonical mnemonics only: `zext.b` matches every `andi`,
+/// not just the ones with an immediate of 255.
+pub fn same_mnemonic(left: &str, right: &str) -> bool {
+    canonical_mnemonic(left) == canonical_mnemonic(right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "ext-f", feature = "ext-d"))]
+    #[test]
+    fn test_aliases_resolve_to_decodable_mnemonics() {
+        use crate::extensions::create_extensions;
+
+        let decodable: Vec<&str> = create_extensions()
+            .iter()
+            .flat_map(|extension| extension.mnemonics().to_vec())
+            .collect();
+        for entry in ALIASES {
+            assert!(
+                decodable.contains(&entry.canonical),
+                "{} maps to unknown {}",
+                entry.alias,
+                entry.canonical
+            );
+            assert!(
+                !decodable.contains(&entry.alias),
+                "{} is canonical",
+                entry.alias
+            );
+            assert!(entry.syntax.starts_with(entry.alias), "{}", entry.syntax);
+            assert_eq!(
+                entry.expansion.split_whitespace().next(),
+                Some(entry.canonical),
+                "{}",
+                entry.expansion
+            );
+            assert_eq!(
+                lookup(entry.alias),
+                Some(entry),
+                "duplicate {}",
+                entry.alias
+            );
+        }
+    }
+
+    #[test]
+    fn test_spellings_of_one_instruction_match() {
+        let bgt = lookup("bgt").unwrap();
+        assert_eq!(bgt.canonical, "blt");
+        assert_eq!(bgt.expansion, "blt rt, rs, offset");
+
+        assert_eq!(canonical_mnemonic("zext.b"), "andi");
+        assert_eq!(canonical_mnemonic("andi"), "andi");
+        assert!(same_mnemonic("fmv.x.s", "fmv.x.w"));
+        assert!(same_mnemonic("bgt", "bltz"));
+        assert!(!same_mnemonic("bgt", "bge"));
+
+        let names: Vec<&str> = aliases_of("jalr").map(|entry| entry.alias).collect();
+        assert_eq!(names, ["jr", "ret"]);
+    }
+}
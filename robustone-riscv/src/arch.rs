@@ -3,15 +3,30 @@
 //! This module defines RISC-V specific types and extensions used by
 //! the RISC-V instruction decoder and handler.
 
-use robustone_core::traits::instruction::Detail;
+use crate::explain::instruction_format;
+use crate::types::{RiscVInstructionFormat, VType};
+use robustone_core::ir::{DecodedInstruction, Operand};
+use robustone_core::traits::instruction::{Detail, SemanticFlags};
 
-/// Simple RISC-V instruction detail containing register access information.
+/// RISC-V instruction detail: register access plus raw encoding fields.
 #[derive(Debug, Clone)]
 pub struct RiscVInstructionDetail {
     /// Registers read by this instruction
     pub regs_read: Vec<u32>,
     /// Registers written by this instruction
     pub regs_write: Vec<u32>,
+    /// Encoding format, or `None` outside the base formats
+    pub format: Option<RiscVInstructionFormat>,
+    /// Major opcode: bits `[6:0]`, or the quadrant `[1:0]` for compressed encodings
+    pub opcode: u8,
+    /// `funct3` field, for formats that have one
+    pub funct3: Option<u8>,
+    /// `funct7` field of R-type encodings
+    pub funct7: Option<u8>,
+    /// Vector type settings of `vsetvli` and `vsetivli`
+    pub vtype: Option<VType>,
+    /// Memory, control-flow, and privilege effects
+    pub semantics: SemanticFlags,
 }
 
 impl Default for RiscVInstructionDetail {
@@ -26,9 +41,54 @@ impl RiscVInstructionDetail {
         Self {
             regs_read: Vec::new(),
             regs_write: Vec::new(),
+            format: None,
+            opcode: 0,
+            funct3: None,
+            funct7: None,
+            vtype: None,
+            semantics: SemanticFlags::default(),
         }
     }
 
+    /// Records the semantic flags of `decoded`.
+    pub fn with_semantics(mut self, decoded: &DecodedInstruction) -> Self {
+        self.semantics = semantic_flags(decoded);
+        self
+    }
+
+    /// Records the format and raw opcode/funct fields of `decoded`, and the
+    /// `vtype` settings of a vector configuration instruction.
+    pub fn with_encoding(mut self, decoded: &DecodedInstruction) -> Self {
+        self.vtype = VType::from_decoded(decoded);
+        let Some(format) = instruction_format(decoded) else {
+            return self;
+        };
+        let bytes = &decoded.raw_bytes;
+        self.format = Some(format);
+        if format.is_compressed() {
+            let half = u16::from_le_bytes([bytes[0], bytes[1]]);
+            self.opcode = (half & 0b11) as u8;
+            self.funct3 = (!matches!(
+                format,
+                RiscVInstructionFormat::CR | RiscVInstructionFormat::CA
+            ))
+            .then_some((half >> 13) as u8);
+        } else {
+            let word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            self.opcode = (word & 0x7f) as u8;
+            self.funct3 = matches!(
+                format,
+                RiscVInstructionFormat::R
+                    | RiscVInstructionFormat::I
+                    | RiscVInstructionFormat::S
+                    | RiscVInstructionFormat::B
+            )
+            .then_some(((word >> 12) & 0b111) as u8);
+            self.funct7 = (format == RiscVInstructionFormat::R).then_some((word >> 25) as u8);
+        }
+        self
+    }
+
     /// Adds a register to the read list.
     pub fn reads_register(mut self, reg: u32) -> Self {
         self.regs_read.push(reg);
@@ -42,6 +102,63 @@ impl RiscVInstructionDetail {
     }
 }
 
+/// Classifies what a decoded RISC-V instruction may do.
+pub fn semantic_flags(decoded: &DecodedInstruction) -> SemanticFlags {
+    let mnemonic = decoded.mnemonic.as_str();
+    let base = mnemonic.strip_prefix("c.").unwrap_or(mnemonic);
+    let amo = base.starts_with("amo");
+
+    let loads_memory = amo
+        || base.starts_with("lr.")
+        || matches!(
+            base.trim_end_matches("sp"),
+            "lb" | "lh" | "lw" | "ld" | "lbu" | "lhu" | "lwu" | "flw" | "fld"
+        );
+    let stores_memory = amo
+        || base.starts_with("sc.")
+        || matches!(
+            base.trim_end_matches("sp"),
+            "sb" | "sh" | "sw" | "sd" | "fsw" | "fsd"
+        );
+    let conditional = matches!(
+        base,
+        "beq" | "bne" | "blt" | "bge" | "bltu" | "bgeu" | "beqz" | "bnez"
+    );
+    let changes_control_flow = conditional
+        || matches!(
+            base,
+            "j" | "jal" | "jr" | "jalr" | "ecall" | "ebreak" | "uret" | "sret" | "mret"
+        );
+
+    // CSR bits [9:8] hold the lowest privilege level allowed to access it.
+    let privileged_csr = decoded.operands.iter().any(|operand| {
+        matches!(operand, Operand::SystemRegister { number } if (number >> 8) & 0b11 != 0)
+    });
+    let privileged = privileged_csr
+        || matches!(
+            base,
+            "sret"
+                | "mret"
+                | "wfi"
+                | "sfence.vma"
+                | "sinval.vma"
+                | "sfence.w.inval"
+                | "sfence.inval.ir"
+        );
+
+    let in_group = |name: &str| decoded.groups.iter().any(|group| group == name);
+
+    SemanticFlags {
+        loads_memory,
+        stores_memory,
+        changes_control_flow,
+        conditional,
+        call: in_group("call"),
+        ret: in_group("ret"),
+        privileged,
+    }
+}
+
 /// Re-export register type from the existing types module
 pub use crate::types::RiscVRegister;
 
@@ -57,6 +174,10 @@ impl Detail for RiscVInstructionDetail {
     fn registers_written(&self) -> &[u32] {
         &self.regs_write
     }
+
+    fn semantics(&self) -> SemanticFlags {
+        self.semantics
+    }
 }
 
 #[cfg(test)]
@@ -94,5 +215,76 @@ mod tests {
 
         assert_eq!(detail.regs_read, vec![5]);
         assert_eq!(detail.regs_write, vec![10]);
+        assert_eq!(detail.format, None);
+    }
+
+    #[cfg(all(feature = "ext-a", feature = "ext-c"))]
+    #[test]
+    fn test_semantic_flags_classify_memory_control_flow_and_privilege() {
+        use robustone_core::ArchitectureHandler;
+
+        let handler = crate::RiscVHandler::rv64();
+        let flags = |bytes: &[u8]| {
+            let (decoded, _) = handler.decode_instruction(bytes, "riscv64", 0).unwrap();
+            semantic_flags(&decoded)
+        };
+
+        // ld a0, 8(sp)
+        let load = flags(&[0x03, 0x35, 0x81, 0x00]);
+        assert!(load.loads_memory && !load.stores_memory && !load.changes_control_flow);
+        // c.sdsp ra, 8(sp)
+        assert!(flags(&[0x06, 0xe4]).stores_memory);
+        // amoadd.w a0, a1, (a2)
+        let amo = flags(&[0x2f, 0x25, 0xb6, 0x00]);
+        assert!(amo.loads_memory && amo.stores_memory);
+        // beq a0, a1, 8
+        let branch = flags(&[0x63, 0x04, 0xb5, 0x00]);
+        assert!(branch.changes_control_flow && branch.conditional);
+        // c.jr ra
+        let ret = flags(&[0x82, 0x80]);
+        assert!(ret.changes_control_flow && !ret.conditional);
+        assert!(ret.ret && !ret.call);
+        // jal ra, 16
+        assert!(flags(&[0xef, 0x00, 0x00, 0x01]).call);
+        // mret, csrr a0, mstatus, csrr a0, cycle
+        assert!(flags(&[0x73, 0x00, 0x20, 0x30]).privileged);
+        assert!(flags(&[0x73, 0x25, 0x00, 0x30]).privileged);
+        assert!(!flags(&[0x73, 0x25, 0x00, 0xc0]).privileged);
+        // addi a0, a0, 1
+        assert_eq!(flags(&[0x13, 0x05, 0x15, 0x00]), SemanticFlags::default());
+    }
+
+    #[cfg(feature = "ext-c")]
+    #[test]
+    fn test_riscv_instruction_detail_records_encoding_fields() {
+        use robustone_core::ArchitectureHandler;
+
+        let handler = crate::RiscVHandler::rv64();
+        let encoding = |bytes: &[u8]| {
+            let (decoded, _) = handler.decode_instruction(bytes, "riscv64", 0).unwrap();
+            let detail = RiscVInstructionDetail::new().with_encoding(&decoded);
+            (detail.format, detail.opcode, detail.funct3, detail.funct7)
+        };
+
+        // sub a0, a1, a2
+        assert_eq!(
+            encoding(&[0x33, 0x85, 0xc5, 0x40]),
+            (Some(RiscVInstructionFormat::R), 0x33, Some(0), Some(0x20))
+        );
+        // lui a0, 0x12345
+        assert_eq!(
+            encoding(&[0x37, 0x55, 0x34, 0x12]),
+            (Some(RiscVInstructionFormat::U), 0x37, None, None)
+        );
+        // c.addi a0, 1
+        assert_eq!(
+            encoding(&[0x05, 0x05]),
+            (Some(RiscVInstructionFormat::CI), 0b01, Some(0), None)
+        );
+        // c.add a0, a1
+        assert_eq!(
+            encoding(&[0x2e, 0x95]),
+            (Some(RiscVInstructionFormat::CR), 0b10, None, None)
+        );
     }
 }
@@ -0,0 +1,886 @@
+//! Assembler for a subset of RISC-V (`assemble` feature).
+//!
+//! Turns assembly text, in the syntax the disassembler prints, back into
+//! machine code so that listings can be edited and re-encoded:
+//!
+//! ```text
+//! li a0, 7        =>  13 05 70 00
+//! c.li a0, 7      =>  1d 45
+//! beq a0, a1, 8   =>  63 04 b5 00
+//! ```
+//!
+//! # Syntax
+//!
+//! - Statements are separated by newlines or `;`, and `#` starts a comment.
+//! - Registers are written by number (`x10`) or ABI name (`a0`, `fp`).
+//! - Immediates are decimal or `0x` hexadecimal, optionally negative.
+//! - Branch and jump targets are offsets from the instruction, as the
+//!   disassembler prints them; there are no labels.
+//! - Loads and stores take `offset(base)`; `jalr` also accepts `rd, rs1, offset`.
+//! - Negative branch offsets may be written as 32-bit two's complement
+//!   (`0xfffffff0`), the way the disassembler prints them.
+//!
+//! # Coverage
+//!
+//! RV32I/RV64I, M, and Zicsr (CSRs by name or number), `fence`, the
+//! privileged returns and `wfi`, the common pseudo-instructions (`nop`,
+//! `li`, `mv`, `not`, `neg`, `ret`, `j`, `jr`, `beqz`, `csrr`, ...), and the
+//! compressed instructions written with their `c.` mnemonic. Without a `c.`
+//! prefix the full-width encoding is always emitted. Atomics, floating
+//! point, and vector instructions are not assembled.
+
+use crate::decoder::Xlen;
+use crate::shared::operands::csr_name_lookup;
+use std::fmt;
+
+/// A statement that could not be assembled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssembleError {
+    /// The statement, as written.
+    pub statement: String,
+    pub message: String,
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot assemble `{}`: {}", self.statement, self.message)
+    }
+}
+
+impl std::error::Error for AssembleError {}
+
+/// One encoded instruction.
+enum Encoding {
+    Full(u32),
+    Compressed(u16),
+}
+
+/// Assemble `source` for `xlen`, returning the little-endian machine code of
+/// every statement in order.
+///
+/// ```rust
+/// use robustone_riscv::assemble::assemble;
+/// use robustone_riscv::decoder::Xlen;
+///
+/// let bytes = assemble("li a0, 7; c.nop", Xlen::X32).unwrap();
+/// assert_eq!(bytes, [0x13, 0x05, 0x70, 0x00, 0x01, 0x00]);
+/// ```
+pub fn assemble(source: &str, xlen: Xlen) -> Result<Vec<u8>, AssembleError> {
+    let mut bytes = Vec::new();
+    for statement in source.lines().flat_map(|line| line.split(';')) {
+        let statement = statement
+            .split_once('#')
+            .map_or(statement, |(code, _)| code)
+            .trim();
+        if statement.is_empty() {
+            continue;
+        }
+        let encodings = assemble_statement(statement, xlen).map_err(|message| AssembleError {
+            statement: statement.to_string(),
+            message,
+        })?;
+        for encoding in encodings {
+            match encoding {
+                Encoding::Full(word) => bytes.extend_from_slice(&word.to_le_bytes()),
+                Encoding::Compressed(half) => bytes.extend_from_slice(&half.to_le_bytes()),
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+type Assembled = Result<Vec<Encoding>, String>;
+
+fn assemble_statement(statement: &str, xlen: Xlen) -> Assembled {
+    let (mnemonic, rest) = statement
+        .split_once(char::is_whitespace)
+        .unwrap_or((statement, ""));
+    let mnemonic = mnemonic.to_ascii_lowercase();
+    let operands: Vec<&str> = if rest.trim().is_empty() {
+        Vec::new()
+    } else {
+        rest.split(',').map(str::trim).collect()
+    };
+    let ops = Operands {
+        list: &operands,
+        xlen,
+    };
+    let rv64 = xlen == Xlen::X64;
+
+    if let Some(compressed) = mnemonic.strip_prefix("c.") {
+        return assemble_compressed(compressed, &ops).map(|half| vec![Encoding::Compressed(half)]);
+    }
+
+    let one = |word: u32| Ok(vec![Encoding::Full(word)]);
+    let rv64_only = |word: Result<u32, String>| {
+        if rv64 {
+            word.map(|word| vec![Encoding::Full(word)])
+        } else {
+            Err(format!("`{mnemonic}` needs RV64"))
+        }
+    };
+
+    match mnemonic.as_str() {
+        // RV32I
+        "lui" => one(ops.u_type(0x37)?),
+        "auipc" => one(ops.u_type(0x17)?),
+        "jal" => match operands.len() {
+            1 => one(j_type(0x6f, 1, ops.jump_offset(0)?)),
+            _ => {
+                ops.count(2)?;
+                one(j_type(0x6f, ops.reg(0)?, ops.jump_offset(1)?))
+            }
+        },
+        "jalr" => match operands.len() {
+            1 if operands[0].contains('(') => {
+                let (offset, base) = ops.memory(0)?;
+                one(i_type(0x67, 0, 1, base, offset))
+            }
+            1 => one(i_type(0x67, 0, 1, ops.reg(0)?, 0)),
+            2 => {
+                let (offset, base) = ops.memory(1)?;
+                one(i_type(0x67, 0, ops.reg(0)?, base, offset))
+            }
+            _ => {
+                ops.count(3)?;
+                one(i_type(0x67, 0, ops.reg(0)?, ops.reg(1)?, ops.imm12(2)?))
+            }
+        },
+        "beq" => one(ops.branch(0)?),
+        "bne" => one(ops.branch(1)?),
+        "blt" => one(ops.branch(4)?),
+        "bge" => one(ops.branch(5)?),
+        "bltu" => one(ops.branch(6)?),
+        "bgeu" => one(ops.branch(7)?),
+        "lb" => one(ops.load(0)?),
+        "lh" => one(ops.load(1)?),
+        "lw" => one(ops.load(2)?),
+        "lbu" => one(ops.load(4)?),
+        "lhu" => one(ops.load(5)?),
+        "sb" => one(ops.store(0)?),
+        "sh" => one(ops.store(1)?),
+        "sw" => one(ops.store(2)?),
+        "addi" => one(ops.op_imm(0x13, 0)?),
+        "slti" => one(ops.op_imm(0x13, 2)?),
+        "sltiu" => one(ops.op_imm(0x13, 3)?),
+        "xori" => one(ops.op_imm(0x13, 4)?),
+        "ori" => one(ops.op_imm(0x13, 6)?),
+        "andi" => one(ops.op_imm(0x13, 7)?),
+        "slli" => one(ops.shift(0x13, 1, 0x00, xlen_bits(xlen))?),
+        "srli" => one(ops.shift(0x13, 5, 0x00, xlen_bits(xlen))?),
+        "srai" => one(ops.shift(0x13, 5, 0x20, xlen_bits(xlen))?),
+        "add" => one(ops.op(0x33, 0, 0x00)?),
+        "sub" => one(ops.op(0x33, 0, 0x20)?),
+        "sll" => one(ops.op(0x33, 1, 0x00)?),
+        "slt" => one(ops.op(0x33, 2, 0x00)?),
+        "sltu" => one(ops.op(0x33, 3, 0x00)?),
+        "xor" => one(ops.op(0x33, 4, 0x00)?),
+        "srl" => one(ops.op(0x33, 5, 0x00)?),
+        "sra" => one(ops.op(0x33, 5, 0x20)?),
+        "or" => one(ops.op(0x33, 6, 0x00)?),
+        "and" => one(ops.op(0x33, 7, 0x00)?),
+        "fence" => match operands.len() {
+            0 => one(0x0ff0_000f),
+            _ => {
+                ops.count(2)?;
+                let pred = fence_set(operands[0])?;
+                let succ = fence_set(operands[1])?;
+                one((pred << 24) | (succ << 20) | 0x0f)
+            }
+        },
+        "fence.i" => ops.count(0).and_then(|()| one(0x0000_100f)),
+        "fence.tso" => ops.count(0).and_then(|()| one(0x8330_000f)),
+        "ecall" => ops.count(0).and_then(|()| one(0x0000_0073)),
+        "ebreak" => ops.count(0).and_then(|()| one(0x0010_0073)),
+        "sret" => ops.count(0).and_then(|()| one(0x1020_0073)),
+        "mret" => ops.count(0).and_then(|()| one(0x3020_0073)),
+        "wfi" => ops.count(0).and_then(|()| one(0x1050_0073)),
+
+        // RV64I
+        "ld" => rv64_only(ops.load(3)),
+        "lwu" => rv64_only(ops.load(6)),
+        "sd" => rv64_only(ops.store(3)),
+        "addiw" => rv64_only(ops.op_imm(0x1b, 0)),
+        "slliw" => rv64_only(ops.shift(0x1b, 1, 0x00, 32)),
+        "srliw" => rv64_only(ops.shift(0x1b, 5, 0x00, 32)),
+        "sraiw" => rv64_only(ops.shift(0x1b, 5, 0x20, 32)),
+        "addw" => rv64_only(ops.op(0x3b, 0, 0x00)),
+        "subw" => rv64_only(ops.op(0x3b, 0, 0x20)),
+        "sllw" => rv64_only(ops.op(0x3b, 1, 0x00)),
+        "srlw" => rv64_only(ops.op(0x3b, 5, 0x00)),
+        "sraw" => rv64_only(ops.op(0x3b, 5, 0x20)),
+
+        // M
+        "mul" => one(ops.op(0x33, 0, 0x01)?),
+        "mulh" => one(ops.op(0x33, 1, 0x01)?),
+        "mulhsu" => one(ops.op(0x33, 2, 0x01)?),
+        "mulhu" => one(ops.op(0x33, 3, 0x01)?),
+        "div" => one(ops.op(0x33, 4, 0x01)?),
+        "divu" => one(ops.op(0x33, 5, 0x01)?),
+        "rem" => one(ops.op(0x33, 6, 0x01)?),
+        "remu" => one(ops.op(0x33, 7, 0x01)?),
+        "mulw" => rv64_only(ops.op(0x3b, 0, 0x01)),
+        "divw" => rv64_only(ops.op(0x3b, 4, 0x01)),
+        "divuw" => rv64_only(ops.op(0x3b, 5, 0x01)),
+        "remw" => rv64_only(ops.op(0x3b, 6, 0x01)),
+        "remuw" => rv64_only(ops.op(0x3b, 7, 0x01)),
+
+        // Zicsr
+        "csrrw" => one(ops.csr(1, 0, 1, 2)?),
+        "csrrs" => one(ops.csr(2, 0, 1, 2)?),
+        "csrrc" => one(ops.csr(3, 0, 1, 2)?),
+        "csrrwi" => one(ops.csr_imm(5, 0, 1, 2)?),
+        "csrrsi" => one(ops.csr_imm(6, 0, 1, 2)?),
+        "csrrci" => one(ops.csr_imm(7, 0, 1, 2)?),
+        "csrr" => {
+            ops.count(2)?;
+            one(csr_type(2, ops.reg(0)?, ops.csr_number(1)?, 0))
+        }
+        "csrw" | "csrs" | "csrc" => {
+            ops.count(2)?;
+            let funct3 = match mnemonic.as_str() {
+                "csrw" => 1,
+                "csrs" => 2,
+                _ => 3,
+            };
+            one(csr_type(funct3, 0, ops.csr_number(0)?, ops.reg(1)?))
+        }
+        "csrwi" | "csrsi" | "csrci" => {
+            ops.count(2)?;
+            let funct3 = match mnemonic.as_str() {
+                "csrwi" => 5,
+                "csrsi" => 6,
+                _ => 7,
+            };
+            one(csr_type(funct3, 0, ops.csr_number(0)?, ops.uimm(1, 5)?))
+        }
+
+        // Pseudo-instructions
+        "nop" => ops.count(0).and_then(|()| one(i_type(0x13, 0, 0, 0, 0))),
+        "li" => {
+            ops.count(2)?;
+            load_immediate(ops.reg(0)?, ops.imm(1)?, xlen)
+        }
+        "mv" => one(ops.unary(|rd, rs| i_type(0x13, 0, rd, rs, 0))?),
+        "not" => one(ops.unary(|rd, rs| i_type(0x13, 4, rd, rs, -1))?),
+        "neg" => one(ops.unary(|rd, rs| r_type(0x33, 0, 0x20, rd, 0, rs))?),
+        "negw" => rv64_only(ops.unary(|rd, rs| r_type(0x3b, 0, 0x20, rd, 0, rs))),
+        "sext.w" => rv64_only(ops.unary(|rd, rs| i_type(0x1b, 0, rd, rs, 0))),
+        "zext.b" => one(ops.unary(|rd, rs| i_type(0x13, 7, rd, rs, 0xff))?),
+        "seqz" => one(ops.unary(|rd, rs| i_type(0x13, 3, rd, rs, 1))?),
+        "snez" => one(ops.unary(|rd, rs| r_type(0x33, 3, 0x00, rd, 0, rs))?),
+        "sltz" => one(ops.unary(|rd, rs| r_type(0x33, 2, 0x00, rd, rs, 0))?),
+        "sgtz" => one(ops.unary(|rd, rs| r_type(0x33, 2, 0x00, rd, 0, rs))?),
+        "beqz" => one(ops.branch_zero(0, false)?),
+        "bnez" => one(ops.branch_zero(1, false)?),
+        "bltz" => one(ops.branch_zero(4, false)?),
+        "bgez" => one(ops.branch_zero(5, false)?),
+        "bgtz" => one(ops.branch_zero(4, true)?),
+        "blez" => one(ops.branch_zero(5, true)?),
+        "bgt" => one(ops.branch_swapped(4)?),
+        "ble" => one(ops.branch_swapped(5)?),
+        "bgtu" => one(ops.branch_swapped(6)?),
+        "bleu" => one(ops.branch_swapped(7)?),
+        "j" => {
+            ops.count(1)?;
+            one(j_type(0x6f, 0, ops.jump_offset(0)?))
+        }
+        "jr" => {
+            ops.count(1)?;
+            one(i_type(0x67, 0, 0, ops.reg(0)?, 0))
+        }
+        "ret" => ops.count(0).and_then(|()| one(i_type(0x67, 0, 0, 1, 0))),
+        _ => Err(format!("unknown or unsupported mnemonic `{mnemonic}`")),
+    }
+}
+
+fn assemble_compressed(mnemonic: &str, ops: &Operands<'_>) -> Result<u16, String> {
+    let rv32 = ops.xlen == Xlen::X32;
+    match mnemonic {
+        "nop" => ops.count(0).map(|()| 0x0001),
+        "ebreak" => ops.count(0).map(|()| 0x9002),
+        "addi" | "li" => {
+            ops.count(2)?;
+            let rd = ops.nonzero_reg(0)?;
+            let imm = ops.imm_range(1, -32, 31)?;
+            if mnemonic == "addi" && imm == 0 {
+                return Err("`c.addi` needs a non-zero immediate".to_string());
+            }
+            let funct3 = if mnemonic == "addi" { 0 } else { 2 };
+            Ok(ci_type(funct3, rd, imm as u32, 0b01))
+        }
+        "slli" => {
+            ops.count(2)?;
+            let rd = ops.nonzero_reg(0)?;
+            let shamt = ops.uimm(1, if rv32 { 5 } else { 6 })?;
+            if shamt == 0 {
+                return Err("`c.slli` needs a non-zero shift amount".to_string());
+            }
+            Ok(ci_type(0, rd, shamt, 0b10))
+        }
+        "mv" | "add" => {
+            ops.count(2)?;
+            let rd = ops.nonzero_reg(0)?;
+            let rs2 = ops.nonzero_reg(1)?;
+            let bit12 = u16::from(mnemonic == "add");
+            Ok((0b100 << 13) | (bit12 << 12) | ((rd as u16) << 7) | ((rs2 as u16) << 2) | 0b10)
+        }
+        "jr" | "jalr" => {
+            ops.count(1)?;
+            let rs1 = ops.nonzero_reg(0)?;
+            let bit12 = u16::from(mnemonic == "jalr");
+            Ok((0b100 << 13) | (bit12 << 12) | ((rs1 as u16) << 7) | 0b10)
+        }
+        "j" | "jal" => {
+            ops.count(1)?;
+            if mnemonic == "jal" && !rv32 {
+                return Err("`c.jal` is RV32-only".to_string());
+            }
+            let offset = ops.offset(0, 12)? as u32;
+            let bits = (bit(offset, 11) << 12)
+                | (bit(offset, 4) << 11)
+                | (bits_at(offset, 9, 8) << 9)
+                | (bit(offset, 10) << 8)
+                | (bit(offset, 6) << 7)
+                | (bit(offset, 7) << 6)
+                | (bits_at(offset, 3, 1) << 3)
+                | (bit(offset, 5) << 2);
+            let funct3 = if mnemonic == "j" { 0b101 } else { 0b001 };
+            Ok((funct3 << 13) | bits as u16 | 0b01)
+        }
+        "beqz" | "bnez" => {
+            ops.count(2)?;
+            let rs1 = ops.compressed_reg(0)?;
+            let offset = ops.offset(1, 9)? as u32;
+            let bits = (bit(offset, 8) << 12)
+                | (bits_at(offset, 4, 3) << 10)
+                | (bits_at(offset, 7, 6) << 5)
+                | (bits_at(offset, 2, 1) << 3)
+                | (bit(offset, 5) << 2);
+            let funct3 = if mnemonic == "beqz" { 0b110 } else { 0b111 };
+            Ok((funct3 << 13) | bits as u16 | ((rs1 as u16) << 7) | 0b01)
+        }
+        _ => Err(format!(
+            "unknown or unsupported compressed mnemonic `c.{mnemonic}`"
+        )),
+    }
+}
+
+/// `li`: one `addi` for 12-bit values, otherwise `lui` plus `addi`
+/// (`addiw` on RV64) for values that fit in 32 bits.
+fn load_immediate(rd: u32, value: i64, xlen: Xlen) -> Assembled {
+    let value = match xlen {
+        // RV32 registers are 32 bits wide, so `0xffffffff` means -1.
+        Xlen::X32 if (0..=u32::MAX as i64).contains(&value) => value as u32 as i32 as i64,
+        _ => value,
+    };
+    if (-2048..2048).contains(&value) {
+        return Ok(vec![Encoding::Full(i_type(0x13, 0, rd, 0, value as i32))]);
+    }
+    if !(i32::MIN as i64..=i32::MAX as i64).contains(&value) {
+        return Err("`li` only loads values that fit in 32 bits".to_string());
+    }
+    let low = ((value << 52) >> 52) as i32;
+    let high = (((value - low as i64) >> 12) as u32) & 0xfffff;
+    let mut encodings = vec![Encoding::Full((high << 12) | (rd << 7) | 0x37)];
+    if low != 0 {
+        let opcode = if xlen == Xlen::X64 { 0x1b } else { 0x13 };
+        encodings.push(Encoding::Full(i_type(opcode, 0, rd, rd, low)));
+    }
+    Ok(encodings)
+}
+
+/// Operands of one statement, with the accessors that parse them.
+struct Operands<'a> {
+    list: &'a [&'a str],
+    xlen: Xlen,
+}
+
+impl Operands<'_> {
+    fn count(&self, expected: usize) -> Result<(), String> {
+        if self.list.len() == expected {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected {expected} operand{}, found {}",
+                if expected == 1 { "" } else { "s" },
+                self.list.len()
+            ))
+        }
+    }
+
+    fn get(&self, index: usize) -> Result<&str, String> {
+        self.list
+            .get(index)
+            .copied()
+            .ok_or_else(|| format!("missing operand {}", index + 1))
+    }
+
+    fn reg(&self, index: usize) -> Result<u32, String> {
+        let text = self.get(index)?;
+        register(text).ok_or_else(|| format!("`{text}` is not an integer register"))
+    }
+
+    fn nonzero_reg(&self, index: usize) -> Result<u32, String> {
+        match self.reg(index)? {
+            0 => Err("this compressed instruction cannot use `zero`".to_string()),
+            reg => Ok(reg),
+        }
+    }
+
+    /// A register of the compressed set, `s0`-`a5` (`x8`-`x15`), as its
+    /// 3-bit field.
+    fn compressed_reg(&self, index: usize) -> Result<u32, String> {
+        match self.reg(index)? {
+            reg @ 8..=15 => Ok(reg - 8),
+            _ => Err(format!(
+                "`{}` is not one of s0, s1, a0-a5",
+                self.get(index)?
+            )),
+        }
+    }
+
+    fn imm(&self, index: usize) -> Result<i64, String> {
+        let text = self.get(index)?;
+        immediate(text).ok_or_else(|| format!("`{text}` is not an immediate"))
+    }
+
+    fn imm_range(&self, index: usize, min: i64, max: i64) -> Result<i64, String> {
+        let value = self.imm(index)?;
+        if (min..=max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(format!("{value} is outside {min}..={max}"))
+        }
+    }
+
+    fn imm12(&self, index: usize) -> Result<i32, String> {
+        self.imm_range(index, -2048, 2047).map(|value| value as i32)
+    }
+
+    fn uimm(&self, index: usize, bits: u32) -> Result<u32, String> {
+        self.imm_range(index, 0, (1 << bits) - 1)
+            .map(|value| value as u32)
+    }
+
+    /// An even PC-relative offset that fits in `bits` signed bits.
+    ///
+    /// The disassembler prints negative offsets as 32-bit two's complement
+    /// (`0xfffffff0`), so those are read back as negative.
+    fn offset(&self, index: usize, bits: u32) -> Result<i64, String> {
+        let limit = 1i64 << (bits - 1);
+        let mut value = self.imm(index)?;
+        if (1 << 31..1 << 32).contains(&value) {
+            value -= 1 << 32;
+        }
+        if !(-limit..limit - 1).contains(&value) {
+            return Err(format!(
+                "offset {value} is outside {}..={}",
+                -limit,
+                limit - 2
+            ));
+        }
+        if value % 2 != 0 {
+            return Err(format!("offset {value} is not a multiple of 2"));
+        }
+        Ok(value)
+    }
+
+    fn jump_offset(&self, index: usize) -> Result<i64, String> {
+        self.offset(index, 21)
+    }
+
+    /// `offset(base)`, with the offset defaulting to 0.
+    fn memory(&self, index: usize) -> Result<(i32, u32), String> {
+        let text = self.get(index)?;
+        let (offset, base) = text
+            .strip_suffix(')')
+            .and_then(|text| text.split_once('('))
+            .ok_or_else(|| format!("`{text}` is not an `offset(base)` operand"))?;
+        let offset = match offset.trim() {
+            "" => 0,
+            offset => immediate(offset)
+                .filter(|value| (-2048..2048).contains(value))
+                .ok_or_else(|| format!("`{offset}` is not a 12-bit offset"))?
+                as i32,
+        };
+        let base = register(base.trim())
+            .ok_or_else(|| format!("`{}` is not an integer register", base.trim()))?;
+        Ok((offset, base))
+    }
+
+    fn csr_number(&self, index: usize) -> Result<u32, String> {
+        let text = self.get(index)?;
+        if let Some(value) = immediate(text) {
+            return u32::try_from(value)
+                .ok()
+                .filter(|value| *value < 0x1000)
+                .ok_or_else(|| format!("CSR number {value} is outside 0..=0xfff"));
+        }
+        let name = text.to_ascii_lowercase();
+        (0..0x1000u16)
+            .find(|csr| csr_name_lookup(*csr) == Some(name.as_str()))
+            .map(u32::from)
+            .ok_or_else(|| format!("unknown CSR `{text}`"))
+    }
+
+    fn u_type(&self, opcode: u32) -> Result<u32, String> {
+        self.count(2)?;
+        let value = self.imm_range(1, -(1 << 19), (1 << 20) - 1)? as u32 & 0xfffff;
+        Ok((value << 12) | (self.reg(0)? << 7) | opcode)
+    }
+
+    fn branch(&self, funct3: u32) -> Result<u32, String> {
+        self.count(3)?;
+        Ok(b_type(
+            funct3,
+            self.reg(0)?,
+            self.reg(1)?,
+            self.offset(2, 13)?,
+        ))
+    }
+
+    /// `bgt a, b` and friends: the base branch with its registers swapped.
+    fn branch_swapped(&self, funct3: u32) -> Result<u32, String> {
+        self.count(3)?;
+        Ok(b_type(
+            funct3,
+            self.reg(1)?,
+            self.reg(0)?,
+            self.offset(2, 13)?,
+        ))
+    }
+
+    /// `beqz rs` and friends: compare against `zero`, on the right or, when
+    /// `zero_first`, on the left.
+    fn branch_zero(&self, funct3: u32, zero_first: bool) -> Result<u32, String> {
+        self.count(2)?;
+        let rs = self.reg(0)?;
+        let (rs1, rs2) = if zero_first { (0, rs) } else { (rs, 0) };
+        Ok(b_type(funct3, rs1, rs2, self.offset(1, 13)?))
+    }
+
+    fn load(&self, funct3: u32) -> Result<u32, String> {
+        self.count(2)?;
+        let (offset, base) = self.memory(1)?;
+        Ok(i_type(0x03, funct3, self.reg(0)?, base, offset))
+    }
+
+    fn store(&self, funct3: u32) -> Result<u32, String> {
+        self.count(2)?;
+        let (offset, base) = self.memory(1)?;
+        let offset = offset as u32;
+        Ok((bits_at(offset, 11, 5) << 25)
+            | (self.reg(0)? << 20)
+            | (base << 15)
+            | (funct3 << 12)
+            | (bits_at(offset, 4, 0) << 7)
+            | 0x23)
+    }
+
+    fn op_imm(&self, opcode: u32, funct3: u32) -> Result<u32, String> {
+        self.count(3)?;
+        Ok(i_type(
+            opcode,
+            funct3,
+            self.reg(0)?,
+            self.reg(1)?,
+            self.imm12(2)?,
+        ))
+    }
+
+    fn shift(&self, opcode: u32, funct3: u32, funct7: u32, width: u32) -> Result<u32, String> {
+        self.count(3)?;
+        let shamt = self.imm_range(2, 0, i64::from(width) - 1)? as u32;
+        Ok((funct7 << 25)
+            | (shamt << 20)
+            | (self.reg(1)? << 15)
+            | (funct3 << 12)
+            | (self.reg(0)? << 7)
+            | opcode)
+    }
+
+    fn op(&self, opcode: u32, funct3: u32, funct7: u32) -> Result<u32, String> {
+        self.count(3)?;
+        Ok(r_type(
+            opcode,
+            funct3,
+            funct7,
+            self.reg(0)?,
+            self.reg(1)?,
+            self.reg(2)?,
+        ))
+    }
+
+    fn unary(&self, encode: impl Fn(u32, u32) -> u32) -> Result<u32, String> {
+        self.count(2)?;
+        Ok(encode(self.reg(0)?, self.reg(1)?))
+    }
+
+    fn csr(&self, funct3: u32, rd: usize, csr: usize, rs1: usize) -> Result<u32, String> {
+        self.count(3)?;
+        Ok(csr_type(
+            funct3,
+            self.reg(rd)?,
+            self.csr_number(csr)?,
+            self.reg(rs1)?,
+        ))
+    }
+
+    fn csr_imm(&self, funct3: u32, rd: usize, csr: usize, uimm: usize) -> Result<u32, String> {
+        self.count(3)?;
+        Ok(csr_type(
+            funct3,
+            self.reg(rd)?,
+            self.csr_number(csr)?,
+            self.uimm(uimm, 5)?,
+        ))
+    }
+}
+
+fn xlen_bits(xlen: Xlen) -> u32 {
+    match xlen {
+        Xlen::X32 => 32,
+        Xlen::X64 => 64,
+    }
+}
+
+fn bit(value: u32, index: u32) -> u32 {
+    (value >> index) & 1
+}
+
+/// Bits `high..=low` of `value`, shifted down to bit 0.
+fn bits_at(value: u32, high: u32, low: u32) -> u32 {
+    (value >> low) & ((1 << (high - low + 1)) - 1)
+}
+
+fn r_type(opcode: u32, funct3: u32, funct7: u32, rd: u32, rs1: u32, rs2: u32) -> u32 {
+    (funct7 << 25) | (rs2 << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+fn i_type(opcode: u32, funct3: u32, rd: u32, rs1: u32, imm: i32) -> u32 {
+    ((imm as u32 & 0xfff) << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+fn b_type(funct3: u32, rs1: u32, rs2: u32, offset: i64) -> u32 {
+    let offset = offset as u32;
+    (bit(offset, 12) << 31)
+        | (bits_at(offset, 10, 5) << 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | (bits_at(offset, 4, 1) << 8)
+        | (bit(offset, 11) << 7)
+        | 0x63
+}
+
+fn j_type(opcode: u32, rd: u32, offset: i64) -> u32 {
+    let offset = offset as u32;
+    (bit(offset, 20) << 31)
+        | (bits_at(offset, 10, 1) << 21)
+        | (bit(offset, 11) << 20)
+        | (bits_at(offset, 19, 12) << 12)
+        | (rd << 7)
+        | opcode
+}
+
+fn csr_type(funct3: u32, rd: u32, csr: u32, rs1: u32) -> u32 {
+    (csr << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | 0x73
+}
+
+/// CI format: `funct3 | imm[5] | rd | imm[4:0] | op`.
+fn ci_type(funct3: u16, rd: u32, imm: u32, op: u16) -> u16 {
+    (funct3 << 13)
+        | ((bit(imm, 5) as u16) << 12)
+        | ((rd as u16) << 7)
+        | ((bits_at(imm, 4, 0) as u16) << 2)
+        | op
+}
+
+/// `fence` predecessor or successor set, such as `rw` or `iorw`.
+fn fence_set(text: &str) -> Result<u32, String> {
+    text.chars().try_fold(0, |set, flag| {
+        let bit = match flag.to_ascii_lowercase() {
+            'i' => 8,
+            'o' => 4,
+            'r' => 2,
+            'w' => 1,
+            _ => return Err(format!("`{text}` is not a fence set of i, o, r, w")),
+        };
+        Ok(set | bit)
+    })
+}
+
+fn immediate(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let magnitude = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+        None => digits.parse::<u64>().ok()?,
+    };
+    if negative {
+        0i64.checked_sub_unsigned(magnitude)
+    } else {
+        Some(magnitude as i64)
+    }
+}
+
+fn register(text: &str) -> Option<u32> {
+    const ABI_NAMES: [&str; 32] = [
+        "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
+        "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+        "t5", "t6",
+    ];
+    let text = text.to_ascii_lowercase();
+    if text == "fp" {
+        return Some(8);
+    }
+    if let Some(digits) = text.strip_prefix('x')
+        && (digits == "0" || !digits.starts_with('0'))
+        && let Ok(number) = digits.parse::<u32>()
+        && number < 32
+    {
+        return Some(number);
+    }
+    ABI_NAMES
+        .iter()
+        .position(|name| *name == text)
+        .map(|index| index as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RiscVHandler;
+    use robustone_core::ArchitectureHandler;
+
+    /// Assemble `source`, then decode the bytes back into text.
+    fn round_trip(source: &str, arch: &str) -> Vec<String> {
+        let xlen = if arch == "riscv64" {
+            Xlen::X64
+        } else {
+            Xlen::X32
+        };
+        let bytes = assemble(source, xlen).unwrap();
+        let handler = RiscVHandler::new();
+        let mut offset = 0;
+        let mut text = Vec::new();
+        while offset < bytes.len() {
+            let (instruction, size) = handler.disassemble(&bytes[offset..], arch, 0).unwrap();
+            text.push(
+                format!("{} {}", instruction.mnemonic, instruction.operands)
+                    .trim_end()
+                    .to_string(),
+            );
+            offset += size;
+        }
+        text
+    }
+
+    #[test]
+    fn test_base_instructions_match_known_encodings() {
+        let cases: [(&str, &[u8]); 8] = [
+            ("addi a0, zero, 5", &[0x13, 0x05, 0x50, 0x00]),
+            ("jal ra, 0x100", &[0xef, 0x00, 0x00, 0x10]),
+            ("beq a0, a1, 8", &[0x63, 0x04, 0xb5, 0x00]),
+            ("sub a1, a0, t2", &[0xb3, 0x05, 0x75, 0x40]),
+            ("sd ra, 8(sp)", &[0x23, 0x34, 0x11, 0x00]),
+            ("fence", &[0x0f, 0x00, 0xf0, 0x0f]),
+            ("c.li a0, 7", &[0x1d, 0x45]),
+            ("c.jr ra", &[0x82, 0x80]),
+        ];
+        for (source, expected) in cases {
+            assert_eq!(assemble(source, Xlen::X64).unwrap(), expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn test_assembled_code_decodes_back_to_the_source() {
+        let cases = [
+            ("lui a0, 0x12345", "lui a0, 0x12345"),
+            ("lw a1, -4(s0)", "lw a1, -4(s0)"),
+            ("sw a1, 12(sp)", "sw a1, 0xc(sp)"),
+            ("slli t0, t1, 3", "slli t0, t1, 3"),
+            ("srai a0, a0, 31", "srai a0, a0, 0x1f"),
+            ("mul a0, a1, a2", "mul a0, a1, a2"),
+            ("remu a0, a1, a2", "remu a0, a1, a2"),
+            ("bne a0, zero, -16", "bnez a0, 0xfffffff0"),
+            ("bgt a0, a1, 8", "blt a1, a0, 8"),
+            ("j -8", "j 0xfffffff8"),
+            ("jalr ra, 0(a0)", "jalr 0(a0)"),
+            ("ret", "jalr zero, 0(ra)"),
+            ("mv a0, a1", "addi a0, a1, 0"),
+            ("not a0, a1", "xori a0, a1, -1"),
+            ("neg a0, a1", "sub a0, zero, a1"),
+            ("csrr a0, mstatus", "csrr a0, 0x300"),
+            ("ecall", "ecall"),
+            ("c.addi sp, -16", "addi sp, sp, -0x10"),
+            ("c.mv a0, a1", "mv a0, a1"),
+            ("c.beqz a0, 6", "c.beqz a0, 6"),
+            ("c.j -2", "c.j 0xfffffffe"),
+        ];
+        for (source, expected) in cases {
+            assert_eq!(round_trip(source, "riscv32"), [expected], "{source}");
+        }
+        assert_eq!(
+            round_trip("addiw a0, a0, 1", "riscv64"),
+            ["addiw a0, a0, 1"]
+        );
+        assert_eq!(round_trip("ld a0, 16(sp)", "riscv64"), ["ld a0, 0x10(sp)"]);
+    }
+
+    #[test]
+    fn test_disassembler_output_reassembles_to_the_same_bytes() {
+        let handler = RiscVHandler::new();
+        for hex in [
+            "13055000", "ef000010", "63040500", "e31805fe", "6ff09fff", "67800000", "73253030",
+            "b3057540", "23261100", "0f00f00f",
+        ] {
+            let bytes = hex::decode(hex).unwrap();
+            let (instruction, _) = handler.disassemble(&bytes, "riscv32", 0).unwrap();
+            let text = format!("{} {}", instruction.mnemonic, instruction.operands);
+            assert_eq!(assemble(&text, Xlen::X32).unwrap(), bytes, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_li_expands_to_lui_and_addi() {
+        assert_eq!(round_trip("li a0, 7", "riscv32"), ["li a0, 7"]);
+        assert_eq!(
+            round_trip("li a0, 0x12345678", "riscv32"),
+            ["lui a0, 0x12345", "addi a0, a0, 0x678"]
+        );
+        assert_eq!(
+            round_trip("li a0, 0x7ffff800", "riscv64"),
+            ["lui a0, 0x80000", "addiw a0, a0, -0x800"]
+        );
+        assert_eq!(round_trip("li a0, 0x1000", "riscv32"), ["lui a0, 1"]);
+        assert_eq!(
+            assemble("li a0, 0xffffffff", Xlen::X32).unwrap(),
+            assemble("li a0, -1", Xlen::X32).unwrap()
+        );
+        assert!(assemble("li a0, 0x100000000", Xlen::X64).is_err());
+    }
+
+    #[test]
+    fn test_statements_are_split_and_errors_name_the_statement() {
+        let bytes = assemble("nop # pad\nc.nop; ebreak", Xlen::X32).unwrap();
+        assert_eq!(bytes.len(), 10);
+
+        let error = assemble("nop; addi a0, a0, 4096", Xlen::X32).unwrap_err();
+        assert_eq!(error.statement, "addi a0, a0, 4096");
+        assert!(error.to_string().contains("outside -2048..=2047"));
+
+        assert!(assemble("ld a0, 0(sp)", Xlen::X32).is_err());
+        assert!(assemble("beq a0, a1, 3", Xlen::X32).is_err());
+        assert!(assemble("c.beqz t0, 4", Xlen::X32).is_err());
+        assert!(assemble("fadd.s fa0, fa1, fa2", Xlen::X32).is_err());
+        assert!(assemble("addi q0, a0, 1", Xlen::X32).is_err());
+    }
+}
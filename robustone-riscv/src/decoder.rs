@@ -7,11 +7,14 @@
 use super::extensions::standard::Standard;
 use super::extensions::{Extensions, InstructionExtension, create_extensions};
 use super::shared::encoding::convenience as bits;
+use super::shared::registers::register_table;
 use super::types::*;
 use robustone_core::common::ArchitectureProfile;
 use robustone_core::ir::{
-    ArchitectureId, DecodeStatus, DecodedInstruction, Operand, RegisterId, RenderHints,
+    ArchitectureId, DecodeStatus, DecodedInstruction, MemoryOrdering, Operand, RegisterId,
+    RenderHints,
 };
+use robustone_core::traits::{MnemonicGroup, RegisterInfo};
 use robustone_core::types::error::DisasmError;
 use robustone_core::utils::Endianness;
 
@@ -32,15 +35,43 @@ pub struct RiscVDecoder {
 
 impl RiscVDecoder {
     /// Construct a decoder with the provided XLEN and extension bitmask.
+    ///
+    /// Extensions the enabled ones depend on are enabled as well (see
+    /// [`Extensions::with_implied`]), so D without F decodes as D with F.
     pub fn new(xlen: Xlen, extensions: Extensions) -> Self {
         let extension_handlers = create_extensions();
         Self {
             xlen,
-            extensions,
+            extensions: extensions.with_implied(),
             extension_handlers,
         }
     }
 
+    /// XLEN the decoder was built for.
+    pub fn xlen(&self) -> Xlen {
+        self.xlen
+    }
+
+    /// Extensions the decoder accepts, implied ones included.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Enable an extension, and the ones it depends on, on this decoder.
+    ///
+    /// The decoder keeps its handler objects, so toggling an extension and
+    /// decoding the same bytes again costs no allocation. Names are those of
+    /// [`Extensions::from_enabled_extensions`]; an extension whose `ext-*`
+    /// feature is compiled out stays undecodable.
+    pub fn enable(&mut self, extension: &str) -> Result<(), DisasmError> {
+        self.extensions.enable(extension)
+    }
+
+    /// Disable an extension, and the ones that depend on it, on this decoder.
+    pub fn disable(&mut self, extension: &str) -> Result<(), DisasmError> {
+        self.extensions.disable(extension)
+    }
+
     /// Create a decoder with full RV32GC support.
     pub fn rv32gc() -> Self {
         Self::new(Xlen::X32, Extensions::rv32gc())
@@ -94,19 +125,20 @@ impl RiscVDecoder {
         address: u64,
     ) -> Result<DecodedInstruction, DisasmError> {
         if bytes.is_empty() {
-            return Err(DisasmError::decode_failure(
-                crate::types::error::DecodeErrorKind::NeedMoreBytes,
-                Some(arch_name.to_string()),
-                "no bytes provided",
-            ));
+            return Err(DisasmError::truncated(Some(arch_name.to_string()), 2, 0));
         }
 
-        // Decoding priority:
-        // 1. If at least two bytes are available and the low bits are not `0b11`, decode as
-        //    a compressed instruction.
-        // 2. Otherwise attempt a standard 32-bit instruction.
-        if bytes.len() >= 2 && (bytes[0] & 0x3) != 0x3 {
-            // Compressed encoding (two low bits are not `0b11`).
+        // The low bits of the first parcel give the length: anything but `0b11`
+        // is a 16-bit compressed instruction, `0b11` a 32-bit one unless bits
+        // [6:2] announce a longer encoding (see `instruction_length`).
+        if (bytes[0] & 0x3) != 0x3 {
+            if bytes.len() < 2 {
+                return Err(DisasmError::truncated(
+                    Some(arch_name.to_string()),
+                    2,
+                    bytes.len(),
+                ));
+            }
             if !self.extensions.standard.contains(Standard::C) {
                 return Err(DisasmError::decode_failure(
                     crate::types::error::DecodeErrorKind::UnsupportedExtension,
@@ -116,19 +148,29 @@ impl RiscVDecoder {
             }
             let decoded = self.decode_compressed_instruction(bytes, address)?;
             let raw_bytes = bytes[..decoded.size].to_vec();
-            Ok(decoded.with_context(arch_name, address, raw_bytes))
-        } else if bytes.len() >= 4 {
-            // Standard instruction (low bits equal `0b11`) or fallback when compression fails.
-            let decoded = self.decode_standard_instruction(bytes, address)?;
-            let raw_bytes = bytes[..decoded.size].to_vec();
-            Ok(decoded.with_context(arch_name, address, raw_bytes))
-        } else {
-            Err(DisasmError::decode_failure(
-                crate::types::error::DecodeErrorKind::NeedMoreBytes,
+            return Ok(decoded.with_context(arch_name, address, raw_bytes));
+        }
+
+        // With a single byte, bits [14:12] of an 80-bit-or-longer prefix are
+        // missing; reading them as zero gives the shortest such length. The
+        // reserved 192-bit-and-longer prefix has no length, so it is read as
+        // a 32-bit word and reported as unrecognised.
+        let parcel = u16::from_le_bytes([bytes[0], bytes.get(1).copied().unwrap_or(0)]);
+        let length = instruction_length(parcel).unwrap_or(4);
+        if bytes.len() < length {
+            return Err(DisasmError::truncated(
                 Some(arch_name.to_string()),
-                "incomplete instruction",
-            ))
+                length,
+                bytes.len(),
+            ));
         }
+        let decoded = if length == 4 {
+            self.decode_standard_instruction(bytes, address)?
+        } else {
+            long_encoding(&bytes[..length])
+        };
+        let raw_bytes = bytes[..decoded.size].to_vec();
+        Ok(decoded.with_context(arch_name, address, raw_bytes))
     }
 
     /// Decode a 32-bit standard instruction using extension modules.
@@ -177,10 +219,24 @@ impl RiscVDecoder {
             }
 
             if let Some(result) = extension.try_decode_standard(
-                opcode, funct3, funct7, rd, rs1, rs2, funct12, imm_i, imm_s, imm_b, imm_u, imm_j,
+                opcode,
+                funct3,
+                funct7,
+                rd,
+                rs1,
+                rs2,
+                funct12,
+                imm_i,
+                imm_s,
+                imm_b,
+                imm_u,
+                imm_j,
                 self.xlen,
+                &self.extensions,
             ) {
-                return result.map_err(|error| self.normalize_extension_error(error));
+                return result
+                    .map(|decoded| decoded.with_decoded_by(extension.name()))
+                    .map_err(|error| self.normalize_extension_error(error));
             }
         }
 
@@ -208,10 +264,8 @@ impl RiscVDecoder {
         let rs2p = ((instruction >> 2) & 0x7) as u8; // bits 4..2 (0..7)
 
         // Decode immediate fields for each compressed encoding shape.
-        // CIW format for c.addi4spn: nzuimm[5:4|9:6|3:2] (bits[12:5] of instruction)
-        let nzuimm_ciw = ((instruction >> 5) & 0x3) << 2
-            | ((instruction >> 11) & 0x3) << 4
-            | ((instruction >> 7) & 0xf) << 6;
+        // CIW format for c.addi4spn: nzuimm[5:4|9:6|2|3] (bits[12:5] of instruction)
+        let nzuimm_ciw = bits::nzuimm_ciw(instruction);
 
         // CL format for c.lw/c.flw: uimm[6|5:3|2]
         let uimm_cl = ((instruction >> 5) & 0x1) << 6
@@ -307,7 +361,9 @@ impl RiscVDecoder {
                 uimm_sdsp,
                 uimm_cldsp,
             ) {
-                return result.map_err(|error| self.normalize_extension_error(error));
+                return result
+                    .map(|decoded| decoded.with_decoded_by(extension.name()))
+                    .map_err(|error| self.normalize_extension_error(error));
             }
         }
 
@@ -315,6 +371,35 @@ impl RiscVDecoder {
         self.decode_c_unknown(instruction)
     }
 
+    /// Mnemonics this decoder accepts for its XLEN, grouped by enabled extension.
+    pub fn supported_mnemonics(&self) -> Vec<MnemonicGroup> {
+        self.extension_handlers
+            .iter()
+            .filter(|extension| extension.is_enabled(&self.extensions))
+            .map(|extension| MnemonicGroup {
+                name: extension.name(),
+                mnemonics: extension.decodable_mnemonics(self.xlen, &self.extensions),
+            })
+            .collect()
+    }
+
+    /// Register metadata for this decoder's XLEN and floating-point extensions.
+    pub fn registers(&self) -> Vec<RegisterInfo> {
+        let xlen = match self.xlen {
+            Xlen::X32 => 32,
+            Xlen::X64 => 64,
+        };
+        let standard = &self.extensions.standard;
+        let flen = if standard.contains(Standard::D) {
+            Some(64)
+        } else if standard.contains(Standard::F) {
+            Some(32)
+        } else {
+            None
+        };
+        register_table(xlen, flen)
+    }
+
     fn mode_name(&self) -> &'static str {
         match self.xlen {
             Xlen::X32 => "riscv32",
@@ -390,6 +475,16 @@ impl RiscVDecoder {
             return Some("A");
         }
 
+        // amocas (funct5 0b00101) is Zacas; byte and halfword AMOs are Zabha.
+        if opcode == 0b010_1111 {
+            if funct7 >> 2 == 0b00101 && !self.extensions.standard.contains(Standard::ZACAS) {
+                return Some("Zacas");
+            }
+            if funct3 <= 0b001 && !self.extensions.standard.contains(Standard::ZABHA) {
+                return Some("Zabha");
+            }
+        }
+
         if matches!(
             opcode,
             0b000_0111 | 0b010_0111 | 0b100_0011 | 0b100_0111 | 0b100_1011 | 0b100_1111
@@ -428,6 +523,13 @@ impl RiscVDecoder {
             return Some("M");
         }
 
+        if opcode == 0b101_0111
+            && funct3 == 0b111
+            && !self.extensions.standard.contains(Standard::V)
+        {
+            return Some("V");
+        }
+
         None
     }
 
@@ -472,6 +574,7 @@ impl RiscVDecoder {
                 imm_u,
                 imm_j,
                 probe_decoder.xlen,
+                &probe_decoder.extensions,
             ) {
                 return Some(result);
             }
@@ -487,6 +590,9 @@ impl RiscVDecoder {
             "D" => standard |= Standard::F | Standard::D,
             "F" => standard |= Standard::F,
             "M" => standard |= Standard::M,
+            "V" => standard |= Standard::F | Standard::D | Standard::V,
+            "Zabha" => standard |= Standard::A | Standard::ZABHA,
+            "Zacas" => standard |= Standard::A | Standard::ZACAS,
             _ => {}
         }
 
@@ -531,6 +637,48 @@ impl RiscVDecoder {
     }
 }
 
+/// Length in bytes of the instruction whose first 16-bit parcel is
+/// `parcel`, following the variable-length encoding scheme of the
+/// unprivileged specification: 2, 4, 6 (48-bit), 8 (64-bit), or 10 to 22 for
+/// the 80-to-176-bit prefixes. Returns `None` for the reserved prefix of
+/// 192 bits and longer.
+pub fn instruction_length(parcel: u16) -> Option<usize> {
+    if parcel & 0b11 != 0b11 {
+        Some(2)
+    } else if parcel & 0b1_1100 != 0b1_1100 {
+        Some(4)
+    } else if parcel & 0b10_0000 == 0 {
+        Some(6)
+    } else if parcel & 0b100_0000 == 0 {
+        Some(8)
+    } else {
+        match (parcel >> 12) & 0b111 {
+            0b111 => None,
+            nnn => Some(10 + 2 * usize::from(nnn)),
+        }
+    }
+}
+
+/// An instruction longer than 32 bits, kept whole so the stream stays in
+/// sync. It renders as `.insn <length>, <value>`, the GNU assembler form
+/// that reproduces it, with the length always in decimal, and carries the
+/// `long_encoding` group.
+fn long_encoding(bytes: &[u8]) -> DecodedInstruction {
+    let value: String = bytes
+        .iter()
+        .rev()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    let mut decoded =
+        build_riscv_decoded_instruction(".insn", RiscVInstructionFormat::R, bytes.len(), vec![]);
+    for text in [bytes.len().to_string(), format!("0x{value}")] {
+        decoded.operands.push(Operand::Text { value: text });
+        decoded.operand_access.push(Access::none());
+    }
+    decoded.groups.push("long_encoding".to_string());
+    decoded
+}
+
 pub(crate) fn build_riscv_decoded_instruction(
     mnemonic: impl Into<String>,
     _format: RiscVInstructionFormat,
@@ -540,7 +688,7 @@ pub(crate) fn build_riscv_decoded_instruction(
     let mnemonic = mnemonic.into();
     let mut registers_read = Vec::new();
     let mut registers_written = Vec::new();
-    let operands = operands_detail
+    let operands: Vec<Operand> = operands_detail
         .iter()
         .map(|operand| match &operand.value {
             RiscVOperandValue::Register(reg) => {
@@ -557,6 +705,12 @@ pub(crate) fn build_riscv_decoded_instruction(
             RiscVOperandValue::RoundingMode(rm) => Operand::Text {
                 value: rounding_mode_name(*rm).to_string(),
             },
+            RiscVOperandValue::VectorType(vtype) => Operand::Text {
+                value: vtype.to_string(),
+            },
+            RiscVOperandValue::Csr(csr) => Operand::SystemRegister {
+                number: u32::from(*csr),
+            },
             RiscVOperandValue::Memory(memory) => {
                 let base = Some(RegisterId::riscv(memory.base));
                 if let Some(base_register) = base {
@@ -565,12 +719,25 @@ pub(crate) fn build_riscv_decoded_instruction(
                 Operand::Memory {
                     base,
                     displacement: memory.disp,
+                    ordering: MemoryOrdering::RELAXED,
                 }
             }
         })
         .collect();
 
     let (implicit_registers_read, implicit_registers_written) = infer_implicit_registers(&mnemonic);
+    let mut groups = infer_groups(&mnemonic);
+    if let Some(group) = control_transfer_group(&mnemonic, &operands) {
+        groups.push(group.to_string());
+    }
+    let memory_access = infer_memory_access(&mnemonic, &groups);
+    let operand_access = operands_detail
+        .iter()
+        .map(|operand| match operand.value {
+            RiscVOperandValue::Memory(_) => memory_access,
+            _ => operand.access,
+        })
+        .collect();
 
     DecodedInstruction {
         architecture: ArchitectureId::Riscv,
@@ -581,13 +748,15 @@ pub(crate) fn build_riscv_decoded_instruction(
         size,
         raw_bytes: Vec::new(),
         operands,
+        operand_access,
         registers_read,
         registers_written,
         implicit_registers_read,
         implicit_registers_written,
-        groups: infer_groups(&mnemonic),
+        groups,
         status: DecodeStatus::Success,
         render_hints: RenderHints::default(),
+        decoded_by: None,
         render: Some(crate::render::render_riscv_text_parts),
     }
 }
@@ -644,7 +813,16 @@ fn infer_groups(mnemonic: &str) -> Vec<String> {
     if mnemonic.starts_with("csr")
         || matches!(
             mnemonic,
-            "ecall" | "ebreak" | "uret" | "sret" | "mret" | "wfi" | "sfence.vma"
+            "ecall"
+                | "ebreak"
+                | "uret"
+                | "sret"
+                | "mret"
+                | "wfi"
+                | "sfence.vma"
+                | "sinval.vma"
+                | "sfence.w.inval"
+                | "sfence.inval.ir"
         )
     {
         groups.push("system".to_string());
@@ -656,6 +834,51 @@ fn infer_groups(mnemonic: &str) -> Vec<String> {
     groups
 }
 
+/// Link registers: `x1` (`ra`) and `x5` (`t0`), per the return-address
+/// stack hints of the unprivileged specification.
+fn is_link_register(register: Option<RegisterId>) -> bool {
+    register.is_some_and(|register| {
+        register == RegisterId::riscv(1) || register == RegisterId::riscv(5)
+    })
+}
+
+/// Classify an unconditional jump as a `call`, a `ret`, or a plain `jump`.
+///
+/// A jump that writes a link register is a call. `jalr`/`c.jr` that write
+/// nothing and jump through a link register are returns. Every other jump,
+/// including `jal zero` and indirect jumps through other registers, is a
+/// plain jump.
+fn control_transfer_group(mnemonic: &str, operands: &[Operand]) -> Option<&'static str> {
+    let register = |index: usize| match operands.get(index) {
+        Some(Operand::Register { register }) => Some(*register),
+        Some(Operand::Memory { base, .. }) => *base,
+        _ => None,
+    };
+    let writes_nothing = |index: usize| register(index) == Some(RegisterId::riscv(0));
+    Some(match mnemonic {
+        "c.jal" | "c.jalr" => "call",
+        "c.j" => "jump",
+        "c.jr" if is_link_register(register(0)) => "ret",
+        "c.jr" => "jump",
+        "jal" | "jalr" if is_link_register(register(0)) => "call",
+        "jalr" if writes_nothing(0) && is_link_register(register(1)) => "ret",
+        "jal" | "jalr" => "jump",
+        _ => return None,
+    })
+}
+
+/// Access to the memory named by a memory operand (operand builders only
+/// record that the base register is read).
+fn infer_memory_access(mnemonic: &str, groups: &[String]) -> Access {
+    if mnemonic.starts_with("amo") {
+        Access::read_write()
+    } else if mnemonic.starts_with("sc.") || groups.iter().any(|group| group == "store") {
+        Access::write()
+    } else {
+        Access::read()
+    }
+}
+
 fn infer_implicit_registers(mnemonic: &str) -> (Vec<RegisterId>, Vec<RegisterId>) {
     match mnemonic {
         "c.jal" | "c.jalr" => (Vec::new(), vec![RegisterId::riscv(1)]),
@@ -668,6 +891,54 @@ mod tests {
     use super::*;
     use crate::riscv::extensions::standard::Standard;
 
+    #[cfg(feature = "ext-thead")]
+    #[test]
+    fn test_extensions_toggle_on_an_existing_decoder() {
+        // th.mveqz ra, sp, gp
+        let bytes = [0x8b, 0x10, 0x31, 0x40];
+        let mut decoder = RiscVDecoder::rv64gc();
+        assert!(decoder.decode(&bytes, "riscv64", 0).is_err());
+
+        decoder.enable("XTheadCondMov").unwrap();
+        let decoded = decoder.decode(&bytes, "riscv64", 0).unwrap();
+        assert_eq!(decoded.mnemonic, "th.mveqz");
+        assert_eq!(decoded.decoded_by, Some("XTheadCondMov"));
+
+        decoder.disable("xtheadcondmov").unwrap();
+        assert!(decoder.decode(&bytes, "riscv64", 0).is_err());
+    }
+
+    #[test]
+    fn test_disabling_an_extension_disables_its_dependents() {
+        let mut decoder = RiscVDecoder::rv64gc();
+        decoder.disable("F").unwrap();
+        assert_eq!(
+            decoder.extensions().iter_enabled().collect::<Vec<_>>(),
+            ["I", "M", "A", "C"]
+        );
+        decoder.enable("D").unwrap();
+        assert!(decoder.extensions().iter_enabled().any(|name| name == "F"));
+
+        assert!(decoder.disable("I").is_err());
+        assert!(decoder.enable("Q").is_err());
+    }
+
+    #[cfg(feature = "ext-c")]
+    #[test]
+    fn test_decoded_instructions_name_the_extension_that_claimed_them() {
+        let decoder = RiscVDecoder::rv64gc();
+        let addi = decoder
+            .decode(&[0x13, 0x05, 0x50, 0x00], "riscv64", 0)
+            .unwrap();
+        assert_eq!(addi.decoded_by, Some("I"));
+        let c_jr = decoder.decode(&[0x82, 0x80], "riscv64", 0).unwrap();
+        assert_eq!(c_jr.decoded_by, Some("C"));
+        let long = decoder
+            .decode(&[0x1f, 0x00, 0x00, 0x00, 0x80, 0x12], "riscv64", 0)
+            .unwrap();
+        assert_eq!(long.decoded_by, None);
+    }
+
     #[test]
     fn test_refactored_decoder_creation() {
         let decoder = RiscVDecoder::rv32gc();
@@ -702,6 +973,31 @@ mod tests {
         assert_eq!(instr.size, 4);
     }
 
+    #[cfg(feature = "ext-c")]
+    #[test]
+    fn test_jumps_are_classified_as_call_return_or_jump() {
+        let decoder = RiscVDecoder::rv64gc();
+        let kind = |bytes: &[u8]| {
+            let decoded = decoder.decode(bytes, "riscv64", 0).unwrap();
+            ["call", "ret", "jump"]
+                .into_iter()
+                .find(|kind| decoded.groups.iter().any(|group| group == kind))
+        };
+
+        assert_eq!(kind(&[0xef, 0x00, 0x00, 0x01]), Some("call")); // jal ra, 16
+        assert_eq!(kind(&[0xef, 0x02, 0x00, 0x01]), Some("call")); // jal t0, 16
+        assert_eq!(kind(&[0x6f, 0x00, 0x00, 0x01]), Some("jump")); // jal zero, 16
+        assert_eq!(kind(&[0xe7, 0x00, 0x05, 0x00]), Some("call")); // jalr ra, 0(a0)
+        assert_eq!(kind(&[0x67, 0x80, 0x00, 0x00]), Some("ret")); // jalr zero, 0(ra)
+        assert_eq!(kind(&[0x67, 0x00, 0x05, 0x00]), Some("jump")); // jalr zero, 0(a0)
+        assert_eq!(kind(&[0x82, 0x80]), Some("ret")); // c.jr ra
+        assert_eq!(kind(&[0x82, 0x85]), Some("jump")); // c.jr a1
+        assert_eq!(kind(&[0x02, 0x95]), Some("call")); // c.jalr a0
+        assert_eq!(kind(&[0x01, 0xa0]), Some("jump")); // c.j 0
+        assert_eq!(kind(&[0x63, 0x04, 0xb5, 0x00]), None); // beq a0, a1, 8
+    }
+
+    #[cfg(feature = "ext-c")]
     #[test]
     fn test_compressed_instruction_decoding() {
         let decoder = RiscVDecoder::rv32gc();
@@ -734,6 +1030,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "ext-f")]
     #[test]
     fn test_valid_fp_opcode_reports_missing_extension() {
         let decoder = RiscVDecoder::new(
@@ -755,6 +1052,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "ext-d")]
     #[test]
     fn test_fcvt_s_d_decodes() {
         let decoder = RiscVDecoder::rv64gc();
@@ -809,6 +1107,7 @@ mod tests {
         assert!(result.groups.contains(&"system".to_string()));
     }
 
+    #[cfg(feature = "ext-c")]
     #[test]
     fn test_rv64c_ld_sd_decodes() {
         let decoder = RiscVDecoder::rv64gc();
@@ -823,6 +1122,7 @@ mod tests {
         assert_eq!(result.size, 2);
     }
 
+    #[cfg(feature = "ext-c")]
     #[test]
     fn test_rv64c_ldsp_sdsp_decodes() {
         let decoder = RiscVDecoder::rv64gc();
@@ -837,6 +1137,7 @@ mod tests {
         assert_eq!(result.size, 2);
     }
 
+    #[cfg(all(feature = "ext-c", feature = "ext-f"))]
     #[test]
     fn test_rv32c_fp_load_store_decodes() {
         let decoder = RiscVDecoder::rv32gc();
@@ -851,6 +1152,7 @@ mod tests {
         assert_eq!(result.size, 2);
     }
 
+    #[cfg(all(feature = "ext-c", feature = "ext-f"))]
     #[test]
     fn test_rv32c_fp_stack_decodes() {
         let decoder = RiscVDecoder::rv32gc();
@@ -865,6 +1167,7 @@ mod tests {
         assert_eq!(result.size, 2);
     }
 
+    #[cfg(all(feature = "ext-c", feature = "ext-d"))]
     #[test]
     fn test_rv64c_fp_decodes() {
         let decoder = RiscVDecoder::rv64gc();
@@ -879,6 +1182,7 @@ mod tests {
         assert_eq!(result.size, 2);
     }
 
+    #[cfg(all(feature = "ext-c", feature = "ext-d"))]
     #[test]
     fn test_rv64c_fp_stack_decodes() {
         let decoder = RiscVDecoder::rv64gc();
@@ -910,4 +1214,116 @@ mod tests {
             other => panic!("expected invalid encoding, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_long_encodings_keep_the_stream_in_sync() {
+        assert_eq!(instruction_length(0x0013), Some(4));
+        assert_eq!(instruction_length(0x001f), Some(6));
+        assert_eq!(instruction_length(0x003f), Some(8));
+        assert_eq!(instruction_length(0x107f), Some(12));
+        assert_eq!(instruction_length(0x707f), None);
+
+        let decoder = RiscVDecoder::rv64gc();
+        // A 48-bit instruction followed by nop.
+        let bytes = [0x1f, 0x00, 0x00, 0x00, 0x80, 0x12, 0x13, 0x00, 0x00, 0x00];
+        let decoded = decoder.decode(&bytes, "riscv64", 0).unwrap();
+        assert_eq!(decoded.size, 6);
+        assert_eq!(
+            decoded.encoding_class(),
+            robustone_core::ir::EncodingClass::LongEncoding
+        );
+        let (mnemonic, operands) = crate::render::render_riscv_text_parts(
+            &decoded,
+            robustone_core::ir::TextRenderProfile::Capstone,
+            true,
+            true,
+            true,
+            false,
+        );
+        assert_eq!(format!("{mnemonic} {operands}"), ".insn 6, 0x12800000001f");
+        assert_eq!(decoder.decode(&bytes[6..], "riscv64", 6).unwrap().size, 4);
+
+        // The length stays decimal past 9 bytes, as the assembler reads it.
+        let mut bytes = [0u8; 12];
+        bytes[..2].copy_from_slice(&0x107fu16.to_le_bytes());
+        let decoded = decoder.decode(&bytes, "riscv64", 0).unwrap();
+        let (_, operands) = crate::render::render_riscv_text_parts(
+            &decoded,
+            robustone_core::ir::TextRenderProfile::Capstone,
+            true,
+            true,
+            true,
+            false,
+        );
+        assert_eq!(operands, "12, 0x00000000000000000000107f");
+
+        let needed = |bytes: &[u8]| match decoder.decode(bytes, "riscv64", 0) {
+            Err(DisasmError::Truncated { needed, .. }) => needed,
+            other => panic!("expected truncation, got {other:?}"),
+        };
+        assert_eq!(needed(&[0x3f, 0x00, 0x00, 0x00]), 8);
+        assert_eq!(needed(&[0x1f]), 6);
+        assert_eq!(needed(&[0x03]), 4);
+        assert_eq!(needed(&[0x01]), 2);
+
+        assert_eq!(needed(&[0x7f, 0x70]), 4);
+    }
+
+    #[cfg(feature = "ext-v")]
+    #[test]
+    fn test_vector_configuration_decodes_vtype() {
+        let mut decoder = RiscVDecoder::rv64gc();
+        let render = |decoded: &DecodedInstruction| {
+            let (mnemonic, operands) = crate::render::render_riscv_text_parts(
+                decoded,
+                robustone_core::ir::TextRenderProfile::Capstone,
+                true,
+                true,
+                true,
+                false,
+            );
+            format!("{mnemonic} {operands}")
+        };
+        // vsetvli a0, a1, e32, m4, ta, ma
+        let vsetvli = [0x57, 0xf5, 0x25, 0x0d];
+        let error = decoder.decode(&vsetvli, "riscv64", 0).unwrap_err();
+        assert!(
+            error.to_string().contains("requires V extension"),
+            "{error}"
+        );
+
+        decoder.enable("V").unwrap();
+        let decoded = decoder.decode(&vsetvli, "riscv64", 0).unwrap();
+        assert_eq!(render(&decoded), "vsetvli a0, a1, e32, m4, ta, ma");
+        assert_eq!(
+            VType::from_decoded(&decoded),
+            Some(VType {
+                sew: 32,
+                lmul: Lmul::M4,
+                tail_agnostic: true,
+                mask_agnostic: true,
+            })
+        );
+
+        // vsetivli a0, 4, e8, mf2, tu, mu; vsetvl a0, a1, a2
+        let decoded = decoder
+            .decode(&[0x57, 0x75, 0x72, 0xc0], "riscv64", 0)
+            .unwrap();
+        assert_eq!(render(&decoded), "vsetivli a0, 4, e8, mf2, tu, mu");
+        assert_eq!(VType::from_decoded(&decoded).unwrap().lmul, Lmul::Mf2);
+        let detail = crate::arch::RiscVInstructionDetail::new().with_encoding(&decoded);
+        assert_eq!(detail.vtype.map(|vtype| vtype.sew), Some(8));
+        let decoded = decoder
+            .decode(&[0x57, 0xf5, 0xc5, 0x80], "riscv64", 0)
+            .unwrap();
+        assert_eq!(render(&decoded), "vsetvl a0, a1, a2");
+        assert_eq!(VType::from_decoded(&decoded), None);
+
+        // A reserved SEW keeps the raw immediate.
+        let decoded = decoder
+            .decode(&[0x57, 0xf5, 0x05, 0x02], "riscv64", 0)
+            .unwrap();
+        assert_eq!(render(&decoded), "vsetvli a0, a1, 0x20");
+        assert_eq!(VType::from_decoded(&decoded), None);
+    }
 }
@@ -0,0 +1,488 @@
+//! Single-hart interpreter (`emulate` feature).
+//!
+//! Executes decoded instructions by evaluating their [`lift`](crate::lift)
+//! statements against an integer register file and a caller-supplied
+//! [`Memory`]. It is meant for checking decode semantics and running small
+//! snippets, not for booting systems: there are no CSRs, privilege modes,
+//! or interrupts, and anything the lifter does not cover stops execution.
+//!
+//! ```
+//! use robustone_riscv::emulate::{Emulator, FlatMemory, Stop};
+//!
+//! // li a0, 5; addi a0, a0, 1; ecall
+//! let code = [0x13, 0x05, 0x50, 0x00, 0x13, 0x05, 0x15, 0x00, 0x73, 0x00, 0x00, 0x00];
+//! let mut emulator = Emulator::new("riscv64", FlatMemory::new(0x1000, code.to_vec())).unwrap();
+//! emulator.set_pc(0x1000);
+//!
+//! while emulator.step().is_ok() {}
+//! assert_eq!(emulator.register(10), 6);
+//! ```
+
+use crate::RiscVHandler;
+use crate::lift::{BinOp, Expr, Stmt, TrapKind};
+use robustone_core::{ArchitectureHandler, DisasmError, Instruction};
+use std::fmt;
+
+/// Byte-addressed memory seen by the emulator.
+///
+/// Accesses are little-endian and may be unaligned. Returning `false`
+/// reports an access fault and stops execution.
+pub trait Memory {
+    /// Fill `buffer` with the bytes starting at `address`.
+    fn read(&mut self, address: u64, buffer: &mut [u8]) -> bool;
+
+    /// Write `data` starting at `address`.
+    fn write(&mut self, address: u64, data: &[u8]) -> bool;
+}
+
+/// One contiguous block of RAM starting at `base`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatMemory {
+    base: u64,
+    bytes: Vec<u8>,
+}
+
+impl FlatMemory {
+    pub fn new(base: u64, bytes: Vec<u8>) -> Self {
+        Self { base, bytes }
+    }
+
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn range(&self, address: u64, len: usize) -> Option<std::ops::Range<usize>> {
+        let start = usize::try_from(address.checked_sub(self.base)?).ok()?;
+        let end = start.checked_add(len)?;
+        (end <= self.bytes.len()).then_some(start..end)
+    }
+}
+
+impl Memory for FlatMemory {
+    fn read(&mut self, address: u64, buffer: &mut [u8]) -> bool {
+        match self.range(address, buffer.len()) {
+            Some(range) => {
+                buffer.copy_from_slice(&self.bytes[range]);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn write(&mut self, address: u64, data: &[u8]) -> bool {
+        match self.range(address, data.len()) {
+            Some(range) => {
+                self.bytes[range].copy_from_slice(data);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// A memory write performed by one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryWrite {
+    pub address: u64,
+    /// Width in bytes.
+    pub width: u8,
+    pub value: u64,
+}
+
+/// Effects of one executed instruction.
+#[derive(Debug)]
+pub struct Step {
+    pub instruction: Instruction,
+    /// Registers written, in statement order, as `(register id, value)`.
+    pub register_writes: Vec<(u32, u64)>,
+    pub memory_writes: Vec<MemoryWrite>,
+    pub next_pc: u64,
+}
+
+/// Why execution stopped. The PC is left at the offending instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    /// `ecall` or `ebreak`.
+    Trap { address: u64, kind: TrapKind },
+    /// The instruction at `address` could not be fetched.
+    FetchFault { address: u64 },
+    /// The bytes at `address` do not decode.
+    InvalidInstruction { address: u64 },
+    /// The instruction decodes but the lifter does not cover it.
+    Unsupported { address: u64, mnemonic: String },
+    /// A load or store touched unmapped memory.
+    MemoryFault { pc: u64, address: u64 },
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stop::Trap {
+                address,
+                kind: TrapKind::EnvironmentCall,
+            } => write!(f, "ecall at 0x{address:x}"),
+            Stop::Trap {
+                address,
+                kind: TrapKind::Breakpoint,
+            } => write!(f, "ebreak at 0x{address:x}"),
+            Stop::FetchFault { address } => {
+                write!(f, "cannot fetch an instruction at 0x{address:x}")
+            }
+            Stop::InvalidInstruction { address } => {
+                write!(f, "invalid instruction at 0x{address:x}")
+            }
+            Stop::Unsupported { address, mnemonic } => {
+                write!(
+                    f,
+                    "`{mnemonic}` at 0x{address:x} is not supported by the emulator"
+                )
+            }
+            Stop::MemoryFault { pc, address } => {
+                write!(f, "memory access to 0x{address:x} faulted at 0x{pc:x}")
+            }
+        }
+    }
+}
+
+/// Integer register file, program counter, and memory of one hart.
+pub struct Emulator<M> {
+    handler: RiscVHandler,
+    arch: &'static str,
+    xlen: u32,
+    pc: u64,
+    registers: [u64; 32],
+    memory: M,
+}
+
+impl<M: Memory> Emulator<M> {
+    /// Create an emulator for `riscv32` or `riscv64` with all registers and
+    /// the PC set to zero.
+    pub fn new(arch_name: &str, memory: M) -> Result<Self, DisasmError> {
+        let (handler, arch, xlen) = match arch_name {
+            "riscv32" => (RiscVHandler::rv32(), "riscv32", 32),
+            "riscv64" | "riscv" => (RiscVHandler::rv64(), "riscv64", 64),
+            other => return Err(DisasmError::UnsupportedArchitecture(other.to_string())),
+        };
+        Ok(Self {
+            handler,
+            arch,
+            xlen,
+            pc: 0,
+            registers: [0; 32],
+            memory,
+        })
+    }
+
+    pub fn pc(&self) -> u64 {
+        self.pc
+    }
+
+    pub fn set_pc(&mut self, pc: u64) {
+        self.pc = pc & self.mask();
+    }
+
+    /// Value of integer register `id` (0-31); other ids read as zero.
+    pub fn register(&self, id: u32) -> u64 {
+        self.registers.get(id as usize).copied().unwrap_or(0)
+    }
+
+    /// Set integer register `id`. Writes to `x0` and to other ids are ignored.
+    pub fn set_register(&mut self, id: u32, value: u64) {
+        let value = value & self.mask();
+        if let Some(register) = self.registers.get_mut(id as usize).filter(|_| id != 0) {
+            *register = value;
+        }
+    }
+
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    pub fn memory_mut(&mut self) -> &mut M {
+        &mut self.memory
+    }
+
+    /// Execute the instruction at the PC.
+    ///
+    /// On success the PC moves to [`Step::next_pc`]. On [`Stop`] the PC and
+    /// registers are unchanged, although a faulting instruction may already
+    /// have written memory.
+    pub fn step(&mut self) -> Result<Step, Stop> {
+        let address = self.pc;
+        let bytes = self.fetch(address)?;
+        let (instruction, _) = self
+            .handler
+            .disassemble(&bytes, self.arch, address)
+            .map_err(|_| Stop::InvalidInstruction { address })?;
+        let lifted = instruction
+            .decoded
+            .as_ref()
+            .and_then(|decoded| self.handler.lift(decoded))
+            .ok_or_else(|| Stop::Unsupported {
+                address,
+                mnemonic: instruction.mnemonic.clone(),
+            })?;
+
+        let mut registers = self.registers;
+        let mut step = Step {
+            instruction,
+            register_writes: Vec::new(),
+            memory_writes: Vec::new(),
+            next_pc: address.wrapping_add(lifted.size as u64) & self.mask(),
+        };
+        for statement in &lifted.statements {
+            match statement {
+                Stmt::SetReg { reg, value } => {
+                    let value = self.eval(value, &registers)?;
+                    if let Some(register) = registers.get_mut(*reg as usize) {
+                        *register = value;
+                        step.register_writes.push((*reg, value));
+                    }
+                }
+                Stmt::Store { addr, width, value } => {
+                    let target = self.eval(addr, &registers)?;
+                    let value = self.eval(value, &registers)?;
+                    let data = value.to_le_bytes();
+                    if !self.memory.write(target, &data[..usize::from(*width)]) {
+                        return Err(Stop::MemoryFault {
+                            pc: address,
+                            address: target,
+                        });
+                    }
+                    step.memory_writes.push(MemoryWrite {
+                        address: target,
+                        width: *width,
+                        value: value & width_mask(u32::from(*width) * 8),
+                    });
+                }
+                Stmt::Jump { target } => step.next_pc = self.eval(target, &registers)?,
+                Stmt::Branch { condition, target } => {
+                    if self.eval(condition, &registers)? != 0 {
+                        step.next_pc = target & self.mask();
+                    }
+                }
+                Stmt::Trap(kind) => {
+                    return Err(Stop::Trap {
+                        address,
+                        kind: *kind,
+                    });
+                }
+            }
+        }
+
+        self.registers = registers;
+        self.pc = step.next_pc;
+        Ok(step)
+    }
+
+    fn fetch(&mut self, address: u64) -> Result<Vec<u8>, Stop> {
+        let fault = Stop::FetchFault { address };
+        let mut bytes = vec![0; 2];
+        if !self.memory.read(address, &mut bytes) {
+            return Err(fault);
+        }
+        if bytes[0] & 0b11 == 0b11 {
+            bytes.resize(4, 0);
+            if !self.memory.read(address.wrapping_add(2), &mut bytes[2..]) {
+                return Err(fault);
+            }
+        }
+        Ok(bytes)
+    }
+
+    fn eval(&mut self, expr: &Expr, registers: &[u64; 32]) -> Result<u64, Stop> {
+        let mask = self.mask();
+        Ok(match expr {
+            Expr::Const(value) => value & mask,
+            Expr::Reg(id) => registers.get(*id as usize).copied().unwrap_or(0),
+            Expr::Load {
+                addr,
+                width,
+                signed,
+            } => {
+                let address = self.eval(addr, registers)?;
+                let mut data = [0; 8];
+                if !self.memory.read(address, &mut data[..usize::from(*width)]) {
+                    return Err(Stop::MemoryFault {
+                        pc: self.pc,
+                        address,
+                    });
+                }
+                extend(u64::from_le_bytes(data), u32::from(*width) * 8, *signed) & mask
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                let lhs = self.eval(lhs, registers)?;
+                let rhs = self.eval(rhs, registers)?;
+                binary(*op, lhs, rhs, self.xlen) & mask
+            }
+            Expr::Extend {
+                value,
+                from_bits,
+                signed,
+            } => extend(self.eval(value, registers)?, *from_bits, *signed) & mask,
+        })
+    }
+
+    /// Registers and the PC are XLEN bits wide.
+    fn mask(&self) -> u64 {
+        width_mask(self.xlen)
+    }
+}
+
+fn width_mask(bits: u32) -> u64 {
+    if bits >= 64 {
+        u64::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// Keep the low `bits` bits of `value`, sign- or zero-extended to 64 bits.
+fn extend(value: u64, bits: u32, signed: bool) -> u64 {
+    if bits >= 64 {
+        value
+    } else if signed {
+        let shift = 64 - bits;
+        (((value << shift) as i64) >> shift) as u64
+    } else {
+        value & width_mask(bits)
+    }
+}
+
+/// Evaluate `op` on XLEN-bit operands; the caller truncates the result.
+fn binary(op: BinOp, lhs: u64, rhs: u64, xlen: u32) -> u64 {
+    let signed = |value: u64| extend(value, xlen, true) as i64;
+    let shift = (rhs & u64::from(xlen - 1)) as u32;
+    match op {
+        BinOp::Add => lhs.wrapping_add(rhs),
+        BinOp::Sub => lhs.wrapping_sub(rhs),
+        BinOp::And => lhs & rhs,
+        BinOp::Or => lhs | rhs,
+        BinOp::Xor => lhs ^ rhs,
+        BinOp::Shl => lhs << shift,
+        BinOp::Shr => lhs >> shift,
+        BinOp::Sar => (signed(lhs) >> shift) as u64,
+        BinOp::Eq => u64::from(lhs == rhs),
+        BinOp::Ne => u64::from(lhs != rhs),
+        BinOp::Lt => u64::from(signed(lhs) < signed(rhs)),
+        BinOp::Ltu => u64::from(lhs < rhs),
+        BinOp::Ge => u64::from(signed(lhs) >= signed(rhs)),
+        BinOp::Geu => u64::from(lhs >= rhs),
+        BinOp::Mul => lhs.wrapping_mul(rhs),
+        BinOp::MulH => ((i128::from(signed(lhs)) * i128::from(signed(rhs))) >> xlen) as u64,
+        BinOp::MulHsu => ((i128::from(signed(lhs)) * i128::from(rhs)) >> xlen) as u64,
+        BinOp::MulHu => ((u128::from(lhs) * u128::from(rhs)) >> xlen) as u64,
+        // Widening to i128 makes the MIN / -1 overflow case come out as
+        // MIN (quotient) and 0 (remainder), as the ISA requires.
+        BinOp::Div if rhs == 0 => u64::MAX,
+        BinOp::Div => (i128::from(signed(lhs)) / i128::from(signed(rhs))) as u64,
+        BinOp::Rem if rhs == 0 => lhs,
+        BinOp::Rem => (i128::from(signed(lhs)) % i128::from(signed(rhs))) as u64,
+        BinOp::DivU if rhs == 0 => u64::MAX,
+        BinOp::DivU => lhs / rhs,
+        BinOp::RemU if rhs == 0 => lhs,
+        BinOp::RemU => lhs % rhs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(arch: &str, hex: &str, max_steps: usize) -> (Emulator<FlatMemory>, Vec<Step>, Stop) {
+        let code = hex::decode(hex.replace(' ', "")).unwrap();
+        let mut emulator = Emulator::new(arch, FlatMemory::new(0x1000, code)).unwrap();
+        emulator.set_pc(0x1000);
+        let mut steps = Vec::new();
+        for _ in 0..max_steps {
+            match emulator.step() {
+                Ok(step) => steps.push(step),
+                Err(stop) => return (emulator, steps, stop),
+            }
+        }
+        panic!("program did not stop within {max_steps} steps");
+    }
+
+    #[cfg(feature = "ext-c")]
+    #[test]
+    fn test_loop_with_branch_and_compressed_instructions() {
+        // li a0, 0; li a1, 5; loop: c.addi a0, 2; addi a1, a1, -1; bnez a1, loop; ebreak
+        let (emulator, steps, stop) = run(
+            "riscv64",
+            "13050000 93055000 0905 9385f5ff e39d05fe 73001000",
+            100,
+        );
+        assert_eq!(emulator.register(10), 10);
+        assert_eq!(emulator.register(11), 0);
+        assert_eq!(steps.len(), 2 + 5 * 3);
+        assert_eq!(
+            stop,
+            Stop::Trap {
+                address: 0x1012,
+                kind: TrapKind::Breakpoint
+            }
+        );
+    }
+
+    #[test]
+    fn test_memory_and_xlen_wrapping() {
+        // li a0, -1; auipc t0, 0; sw a0, 32(t0); lbu a1, 32(t0); lw a2, 32(t0);
+        // ecall, followed by 16 bytes of data.
+        let code = "1305f0ff 97020000 23a0a202 83c50202 03a60202 73000000";
+        let mut hex = code.to_string();
+        hex.push_str(&"00".repeat(16));
+        let (emulator, steps, _) = run("riscv64", &hex, 10);
+        assert_eq!(emulator.register(11), 0xff);
+        assert_eq!(emulator.register(12), u64::MAX);
+        assert_eq!(
+            steps[2].memory_writes,
+            [MemoryWrite {
+                address: 0x1024,
+                width: 4,
+                value: 0xffff_ffff
+            }]
+        );
+
+        let (emulator, _, _) = run("riscv32", "1305f0ff 13051500 73000000", 10);
+        assert_eq!(emulator.register(10), 0);
+    }
+
+    #[cfg(feature = "ext-a")]
+    #[test]
+    fn test_stop_reasons_leave_state_untouched() {
+        let (emulator, _, stop) = run("riscv64", "13051000", 10);
+        assert_eq!(stop, Stop::FetchFault { address: 0x1004 });
+        assert_eq!(emulator.pc(), 0x1004);
+
+        // lw a0, 0(zero) faults outside the code block.
+        let (emulator, _, stop) = run("riscv64", "03250000", 10);
+        assert_eq!(
+            stop,
+            Stop::MemoryFault {
+                pc: 0x1000,
+                address: 0
+            }
+        );
+        assert_eq!(emulator.pc(), 0x1000);
+
+        let (_, _, stop) = run("riscv64", "2f25b600", 10);
+        assert!(matches!(stop, Stop::Unsupported { ref mnemonic, .. } if mnemonic == "amoadd.w"));
+    }
+
+    #[test]
+    fn test_division_edge_cases_follow_the_isa() {
+        assert_eq!(binary(BinOp::Div, 7, 0, 64), u64::MAX);
+        assert_eq!(binary(BinOp::Rem, 7, 0, 64), 7);
+        assert_eq!(binary(BinOp::Div, 1 << 63, u64::MAX, 64), 1 << 63);
+        assert_eq!(binary(BinOp::Rem, 1 << 63, u64::MAX, 64), 0);
+        assert_eq!(binary(BinOp::MulHu, u64::MAX, u64::MAX, 64), u64::MAX - 1);
+        assert_eq!(
+            binary(BinOp::MulH, 0xffff_ffff, 2, 32) & 0xffff_ffff,
+            0xffff_ffff
+        );
+    }
+}
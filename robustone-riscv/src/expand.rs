@@ -0,0 +1,189 @@
+//! Compressed-to-canonical expansion.
+//!
+//! Every RVC instruction is defined as shorthand for one 32-bit instruction.
+//! This module re-encodes a decoded RVC instruction as that 32-bit word so
+//! the regular decoder can produce its canonical form.
+
+use robustone_core::ir::{DecodedInstruction, Operand};
+
+const OP_LOAD: u32 = 0x03;
+const OP_LOAD_FP: u32 = 0x07;
+const OP_IMM: u32 = 0x13;
+const OP_IMM_32: u32 = 0x1b;
+const OP_STORE: u32 = 0x23;
+const OP_STORE_FP: u32 = 0x27;
+const OP: u32 = 0x33;
+const OP_LUI: u32 = 0x37;
+const OP_32: u32 = 0x3b;
+const OP_BRANCH: u32 = 0x63;
+const OP_JALR: u32 = 0x67;
+const OP_JAL: u32 = 0x6f;
+
+const ZERO: u32 = 0;
+const RA: u32 = 1;
+const SP: u32 = 2;
+
+/// Encodes the 32-bit instruction a decoded RVC instruction expands to.
+///
+/// Returns `None` for 32-bit instructions and for `c.unimp`, which has no
+/// 32-bit equivalent.
+pub fn canonical_encoding(decoded: &DecodedInstruction) -> Option<u32> {
+    if decoded.size != 2 {
+        return None;
+    }
+
+    let operands = &decoded.operands;
+    let reg = |index: usize| match operands.get(index)? {
+        Operand::Register { register } => Some(register.id & 0x1f),
+        _ => None,
+    };
+    let imm = |index: usize| match operands.get(index)? {
+        Operand::Immediate { value } => Some(*value),
+        _ => None,
+    };
+    let mem = |index: usize| match operands.get(index)? {
+        Operand::Memory {
+            base: Some(base),
+            displacement,
+            ..
+        } => Some((base.id & 0x1f, *displacement)),
+        _ => None,
+    };
+
+    let word = match decoded.mnemonic.as_str() {
+        "c.addi4spn" => i_type(imm(2)?, SP, 0b000, reg(0)?, OP_IMM),
+        "c.addi16sp" => i_type(imm(1)?, SP, 0b000, SP, OP_IMM),
+        "c.addi" => i_type(imm(2)?, reg(0)?, 0b000, reg(0)?, OP_IMM),
+        "c.addiw" => i_type(imm(2)?, reg(0)?, 0b000, reg(0)?, OP_IMM_32),
+        "c.li" => i_type(imm(1)?, ZERO, 0b000, reg(0)?, OP_IMM),
+        "c.lui" => u_type(imm(1)? << 12, reg(0)?, OP_LUI),
+        "c.slli" => i_type(imm(1)? & 0x3f, reg(0)?, 0b001, reg(0)?, OP_IMM),
+        "c.srli" => i_type(imm(1)? & 0x3f, reg(0)?, 0b101, reg(0)?, OP_IMM),
+        "c.srai" => i_type((imm(1)? & 0x3f) | 0x400, reg(0)?, 0b101, reg(0)?, OP_IMM),
+        "c.andi" => i_type(imm(1)?, reg(0)?, 0b111, reg(0)?, OP_IMM),
+        "c.mv" => r_type(0, reg(1)?, ZERO, 0b000, reg(0)?, OP),
+        "c.add" => r_type(0, reg(2)?, reg(0)?, 0b000, reg(0)?, OP),
+        "c.sub" => r_type(0x20, reg(1)?, reg(0)?, 0b000, reg(0)?, OP),
+        "c.xor" => r_type(0, reg(1)?, reg(0)?, 0b100, reg(0)?, OP),
+        "c.or" => r_type(0, reg(1)?, reg(0)?, 0b110, reg(0)?, OP),
+        "c.and" => r_type(0, reg(1)?, reg(0)?, 0b111, reg(0)?, OP),
+        "c.subw" => r_type(0x20, reg(2)?, reg(0)?, 0b000, reg(0)?, OP_32),
+        "c.addw" => r_type(0, reg(2)?, reg(0)?, 0b000, reg(0)?, OP_32),
+        "c.j" => j_type(imm(0)?, ZERO),
+        "c.jal" => j_type(imm(0)?, RA),
+        "c.jr" => i_type(0, reg(0)?, 0b000, ZERO, OP_JALR),
+        "c.jalr" => i_type(0, reg(0)?, 0b000, RA, OP_JALR),
+        "c.beqz" => b_type(imm(1)?, ZERO, reg(0)?, 0b000),
+        "c.bnez" => b_type(imm(1)?, ZERO, reg(0)?, 0b001),
+        "c.lw" | "c.lwsp" => load(reg(0)?, mem(1)?, 0b010, OP_LOAD),
+        "c.ld" | "c.ldsp" => load(reg(0)?, mem(1)?, 0b011, OP_LOAD),
+        "c.flw" | "c.flwsp" => load(reg(0)?, mem(1)?, 0b010, OP_LOAD_FP),
+        "c.fld" | "c.fldsp" => load(reg(0)?, mem(1)?, 0b011, OP_LOAD_FP),
+        "c.sw" | "c.swsp" => store(reg(0)?, mem(1)?, 0b010, OP_STORE),
+        "c.sd" | "c.sdsp" => store(reg(0)?, mem(1)?, 0b011, OP_STORE),
+        "c.fsw" | "c.fswsp" => store(reg(0)?, mem(1)?, 0b010, OP_STORE_FP),
+        "c.fsd" | "c.fsdsp" => store(reg(0)?, mem(1)?, 0b011, OP_STORE_FP),
+        // c.ebreak decodes straight to its canonical mnemonic.
+        "ebreak" => 0x0010_0073,
+        _ => return None,
+    };
+    Some(word)
+}
+
+fn r_type(funct7: u32, rs2: u32, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    (funct7 << 25) | (rs2 << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+fn i_type(imm: i64, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    ((imm as u32 & 0xfff) << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+fn s_type(imm: i64, rs2: u32, rs1: u32, funct3: u32, opcode: u32) -> u32 {
+    let imm = imm as u32;
+    (((imm >> 5) & 0x7f) << 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | ((imm & 0x1f) << 7)
+        | opcode
+}
+
+fn b_type(imm: i64, rs2: u32, rs1: u32, funct3: u32) -> u32 {
+    let imm = imm as u32;
+    (((imm >> 12) & 0x1) << 31)
+        | (((imm >> 5) & 0x3f) << 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | (((imm >> 1) & 0xf) << 8)
+        | (((imm >> 11) & 0x1) << 7)
+        | OP_BRANCH
+}
+
+fn u_type(imm: i64, rd: u32, opcode: u32) -> u32 {
+    (imm as u32 & 0xffff_f000) | (rd << 7) | opcode
+}
+
+fn j_type(imm: i64, rd: u32) -> u32 {
+    let imm = imm as u32;
+    (((imm >> 20) & 0x1) << 31)
+        | (((imm >> 1) & 0x3ff) << 21)
+        | (((imm >> 11) & 0x1) << 20)
+        | (((imm >> 12) & 0xff) << 12)
+        | (rd << 7)
+        | OP_JAL
+}
+
+fn load(rd: u32, (base, offset): (u32, i64), funct3: u32, opcode: u32) -> u32 {
+    i_type(offset, base, funct3, rd, opcode)
+}
+
+fn store(rs2: u32, (base, offset): (u32, i64), funct3: u32, opcode: u32) -> u32 {
+    s_type(offset, rs2, base, funct3, opcode)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RiscVHandler;
+    use robustone_core::ArchitectureHandler;
+
+    #[cfg(feature = "ext-c")]
+    fn expand(hex: &str) -> (String, String) {
+        let handler = RiscVHandler::new();
+        let bytes = hex::decode(hex).unwrap();
+        let (compressed, _) = handler.decode_instruction(&bytes, "riscv64", 0).unwrap();
+        let expanded = handler
+            .expand_compressed(&compressed)
+            .expect("compressed instruction should expand");
+        assert_eq!(expanded.size, 4);
+        let operands = expanded
+            .render_text_parts_with_options(
+                robustone_core::ir::TextRenderProfile::Canonical,
+                true,
+                false,
+                false,
+                false,
+            )
+            .1;
+        (expanded.mnemonic, operands)
+    }
+
+    #[cfg(feature = "ext-c")]
+    #[test]
+    fn test_compressed_instructions_expand_to_their_32_bit_forms() {
+        assert_eq!(expand("0505"), ("addi".into(), "a0, a0, 1".into()));
+        assert_eq!(expand("8280"), ("jalr".into(), "zero, 0(ra)".into()));
+        assert_eq!(expand("2a85"), ("add".into(), "a0, zero, a0".into()));
+        assert_eq!(expand("0c41"), ("lw".into(), "a1, 0(a0)".into()));
+        assert_eq!(expand("2e95"), ("add".into(), "a0, a0, a1".into()));
+    }
+
+    #[test]
+    fn test_standard_instructions_do_not_expand() {
+        let handler = RiscVHandler::new();
+        let (addi, _) = handler
+            .decode_instruction(&[0x13, 0x01, 0x01, 0xff], "riscv64", 0)
+            .unwrap();
+        assert!(handler.expand_compressed(&addi).is_none());
+    }
+}
@@ -0,0 +1,674 @@
+//! Encoding breakdowns for explain-style listings.
+//!
+//! Splits a decoded RISC-V instruction back into the bit fields of its base
+//! or compressed format and pairs it with a one-line description.
+
+use crate::shared::encoding::convenience;
+use crate::types::RiscVInstructionFormat;
+use robustone_core::ir::{DecodedInstruction, Operand};
+use robustone_core::traits::{EncodingBreakdown, EncodingField};
+use robustone_core::utils::bitfield;
+
+/// Builds the encoding breakdown for an instruction the decoder accepted.
+pub fn explain(decoded: &DecodedInstruction) -> Option<EncodingBreakdown> {
+    let bytes = decoded.raw_bytes.as_slice();
+    match decoded.size {
+        2 if bytes.len() >= 2 => Some(explain_compressed(
+            u16::from_le_bytes([bytes[0], bytes[1]]),
+            decoded,
+        )),
+        4 if bytes.len() >= 4 => Some(explain_standard(
+            u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            decoded,
+        )),
+        _ => None,
+    }
+}
+
+/// Splits bytes the decoder rejected into the fields every encoding of
+/// their length shares, so unsupported encodings can be reported exactly.
+pub fn explain_unknown(bytes: &[u8]) -> Option<EncodingBreakdown> {
+    let half = u16::from_le_bytes([*bytes.first()?, *bytes.get(1)?]);
+    if half & 0b11 != 0b11 {
+        return Some(EncodingBreakdown {
+            format: "unknown (compressed)".to_string(),
+            fields: slice_fields(u32::from(half), C_UNKNOWN),
+            immediate: None,
+            description: "Unrecognised compressed encoding.".to_string(),
+        });
+    }
+
+    let word = u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?);
+    let format = match opcode_name(convenience::extract_fields(word).opcode) {
+        Some(name) => format!("unknown ({name})"),
+        None => "unknown".to_string(),
+    };
+    Some(EncodingBreakdown {
+        format,
+        fields: slice_fields(word, R_TYPE),
+        immediate: None,
+        description: "Unrecognised encoding.".to_string(),
+    })
+}
+
+/// Encoding format of an instruction the decoder accepted.
+///
+/// Returns `None` for opcodes outside the base formats.
+pub fn instruction_format(decoded: &DecodedInstruction) -> Option<RiscVInstructionFormat> {
+    let bytes = decoded.raw_bytes.as_slice();
+    match decoded.size {
+        2 if bytes.len() >= 2 => Some(compressed_format(
+            u16::from_le_bytes([bytes[0], bytes[1]]),
+            &decoded.mnemonic,
+        )),
+        4 if bytes.len() >= 4 => standard_format(
+            convenience::extract_fields(u32::from_le_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3],
+            ]))
+            .opcode,
+        ),
+        _ => None,
+    }
+}
+
+fn standard_format(opcode: u32) -> Option<RiscVInstructionFormat> {
+    Some(match opcode {
+        // 0x0b is custom-0, which XTheadCondMov fills with R-type encodings.
+        0x0b | 0x2f | 0x33 | 0x3b | 0x53 => RiscVInstructionFormat::R,
+        0x43 | 0x47 | 0x4b | 0x4f => RiscVInstructionFormat::R4,
+        0x03 | 0x07 | 0x0f | 0x13 | 0x1b | 0x67 | 0x73 => RiscVInstructionFormat::I,
+        0x23 | 0x27 => RiscVInstructionFormat::S,
+        0x63 => RiscVInstructionFormat::B,
+        0x17 | 0x37 => RiscVInstructionFormat::U,
+        0x6f => RiscVInstructionFormat::J,
+        _ => return None,
+    })
+}
+
+fn compressed_format(half: u16, mnemonic: &str) -> RiscVInstructionFormat {
+    let fields = convenience::extract_compressed_fields(half);
+    match (fields.opcode, fields.funct3) {
+        (0b00, 0b000) => RiscVInstructionFormat::CIW,
+        (0b00, 0b001..=0b011) => RiscVInstructionFormat::CL,
+        (0b00, _) => RiscVInstructionFormat::CS,
+        (0b01, 0b001) if mnemonic == "c.jal" => RiscVInstructionFormat::CJ,
+        (0b01, 0b000..=0b011) => RiscVInstructionFormat::CI,
+        (0b01, 0b100) if (half >> 10) & 0b11 == 0b11 => RiscVInstructionFormat::CA,
+        (0b01, 0b101) => RiscVInstructionFormat::CJ,
+        (0b01, _) => RiscVInstructionFormat::CB,
+        (_, 0b000..=0b011) => RiscVInstructionFormat::CI,
+        (_, 0b100) => RiscVInstructionFormat::CR,
+        _ => RiscVInstructionFormat::CSS,
+    }
+}
+
+/// Layout of one field: name, most significant bit, least significant bit.
+type Layout = &'static [(&'static str, u8, u8)];
+
+const R_TYPE: Layout = &[
+    ("funct7", 31, 25),
+    ("rs2", 24, 20),
+    ("rs1", 19, 15),
+    ("funct3", 14, 12),
+    ("rd", 11, 7),
+    ("opcode", 6, 0),
+];
+const R_TYPE_FP: Layout = &[
+    ("funct7", 31, 25),
+    ("rs2", 24, 20),
+    ("rs1", 19, 15),
+    ("rm", 14, 12),
+    ("rd", 11, 7),
+    ("opcode", 6, 0),
+];
+const R_TYPE_AMO: Layout = &[
+    ("funct5", 31, 27),
+    ("aq", 26, 26),
+    ("rl", 25, 25),
+    ("rs2", 24, 20),
+    ("rs1", 19, 15),
+    ("funct3", 14, 12),
+    ("rd", 11, 7),
+    ("opcode", 6, 0),
+];
+const R4_TYPE: Layout = &[
+    ("rs3", 31, 27),
+    ("fmt", 26, 25),
+    ("rs2", 24, 20),
+    ("rs1", 19, 15),
+    ("rm", 14, 12),
+    ("rd", 11, 7),
+    ("opcode", 6, 0),
+];
+const I_TYPE: Layout = &[
+    ("imm[11:0]", 31, 20),
+    ("rs1", 19, 15),
+    ("funct3", 14, 12),
+    ("rd", 11, 7),
+    ("opcode", 6, 0),
+];
+const I_TYPE_CSR: Layout = &[
+    ("csr", 31, 20),
+    ("rs1", 19, 15),
+    ("funct3", 14, 12),
+    ("rd", 11, 7),
+    ("opcode", 6, 0),
+];
+const I_TYPE_CSR_IMM: Layout = &[
+    ("csr", 31, 20),
+    ("uimm[4:0]", 19, 15),
+    ("funct3", 14, 12),
+    ("rd", 11, 7),
+    ("opcode", 6, 0),
+];
+const S_TYPE: Layout = &[
+    ("imm[11:5]", 31, 25),
+    ("rs2", 24, 20),
+    ("rs1", 19, 15),
+    ("funct3", 14, 12),
+    ("imm[4:0]", 11, 7),
+    ("opcode", 6, 0),
+];
+const B_TYPE: Layout = &[
+    ("imm[12]", 31, 31),
+    ("imm[10:5]", 30, 25),
+    ("rs2", 24, 20),
+    ("rs1", 19, 15),
+    ("funct3", 14, 12),
+    ("imm[4:1]", 11, 8),
+    ("imm[11]", 7, 7),
+    ("opcode", 6, 0),
+];
+const U_TYPE: Layout = &[("imm[31:12]", 31, 12), ("rd", 11, 7), ("opcode", 6, 0)];
+const J_TYPE: Layout = &[
+    ("imm[20]", 31, 31),
+    ("imm[10:1]", 30, 21),
+    ("imm[11]", 20, 20),
+    ("imm[19:12]", 19, 12),
+    ("rd", 11, 7),
+    ("opcode", 6, 0),
+];
+
+const CR: Layout = &[
+    ("funct4", 15, 12),
+    ("rd/rs1", 11, 7),
+    ("rs2", 6, 2),
+    ("op", 1, 0),
+];
+const CI: Layout = &[
+    ("funct3", 15, 13),
+    ("imm", 12, 12),
+    ("rd/rs1", 11, 7),
+    ("imm", 6, 2),
+    ("op", 1, 0),
+];
+const CSS: Layout = &[
+    ("funct3", 15, 13),
+    ("imm", 12, 7),
+    ("rs2", 6, 2),
+    ("op", 1, 0),
+];
+const CIW: Layout = &[
+    ("funct3", 15, 13),
+    ("imm", 12, 5),
+    ("rd'", 4, 2),
+    ("op", 1, 0),
+];
+const CL: Layout = &[
+    ("funct3", 15, 13),
+    ("imm", 12, 10),
+    ("rs1'", 9, 7),
+    ("imm", 6, 5),
+    ("rd'", 4, 2),
+    ("op", 1, 0),
+];
+const CS: Layout = &[
+    ("funct3", 15, 13),
+    ("imm", 12, 10),
+    ("rs1'", 9, 7),
+    ("imm", 6, 5),
+    ("rs2'", 4, 2),
+    ("op", 1, 0),
+];
+const CA: Layout = &[
+    ("funct6", 15, 10),
+    ("rd'/rs1'", 9, 7),
+    ("funct2", 6, 5),
+    ("rs2'", 4, 2),
+    ("op", 1, 0),
+];
+const CB: Layout = &[
+    ("funct3", 15, 13),
+    ("offset", 12, 10),
+    ("rd'/rs1'", 9, 7),
+    ("offset", 6, 2),
+    ("op", 1, 0),
+];
+const CJ: Layout = &[("funct3", 15, 13), ("jump target", 12, 2), ("op", 1, 0)];
+const C_UNKNOWN: Layout = &[("funct3", 15, 13), ("bits[12:2]", 12, 2), ("op", 1, 0)];
+
+fn explain_standard(word: u32, decoded: &DecodedInstruction) -> EncodingBreakdown {
+    let fields = convenience::extract_fields(word);
+    let (format, layout, immediate): (&str, Layout, Option<i64>) = match fields.opcode {
+        0x33 | 0x3b => ("R-type", R_TYPE, None),
+        0x53 => ("R-type", R_TYPE_FP, None),
+        0x2f => ("R-type", R_TYPE_AMO, None),
+        0x43 | 0x47 | 0x4b | 0x4f => ("R4-type", R4_TYPE, None),
+        0x73 if fields.funct3 >= 5 => ("I-type", I_TYPE_CSR_IMM, None),
+        0x73 if fields.funct3 != 0 => ("I-type", I_TYPE_CSR, None),
+        0x03 | 0x07 | 0x0f | 0x13 | 0x1b | 0x67 | 0x73 => (
+            "I-type",
+            I_TYPE,
+            Some(convenience::extract_i_type(word).imm),
+        ),
+        0x23 | 0x27 => (
+            "S-type",
+            S_TYPE,
+            Some(convenience::extract_s_type(word).imm),
+        ),
+        0x63 => (
+            "B-type",
+            B_TYPE,
+            Some(convenience::extract_b_type(word).imm),
+        ),
+        0x37 | 0x17 => (
+            "U-type",
+            U_TYPE,
+            Some(convenience::extract_u_type(word).imm),
+        ),
+        0x6f => (
+            "J-type",
+            J_TYPE,
+            Some(convenience::extract_j_type(word).imm),
+        ),
+        _ => ("unknown", R_TYPE, None),
+    };
+
+    let format = match opcode_name(fields.opcode) {
+        Some(name) => format!("{format} ({name})"),
+        None => format.to_string(),
+    };
+
+    EncodingBreakdown {
+        format,
+        fields: slice_fields(word, layout),
+        immediate,
+        description: describe(&decoded.mnemonic),
+    }
+}
+
+fn explain_compressed(half: u16, decoded: &DecodedInstruction) -> EncodingBreakdown {
+    let format = compressed_format(half, &decoded.mnemonic);
+    let layout = match format {
+        RiscVInstructionFormat::CIW => CIW,
+        RiscVInstructionFormat::CL => CL,
+        RiscVInstructionFormat::CS => CS,
+        RiscVInstructionFormat::CJ => CJ,
+        RiscVInstructionFormat::CA => CA,
+        RiscVInstructionFormat::CB => CB,
+        RiscVInstructionFormat::CR => CR,
+        RiscVInstructionFormat::CSS => CSS,
+        _ => CI,
+    };
+
+    let immediate = if matches!(
+        format,
+        RiscVInstructionFormat::CR | RiscVInstructionFormat::CA
+    ) {
+        None
+    } else {
+        decoded
+            .operands
+            .iter()
+            .rev()
+            .find_map(|operand| match operand {
+                Operand::Immediate { value } => Some(*value),
+                Operand::Memory { displacement, .. } => Some(*displacement),
+                _ => None,
+            })
+    };
+
+    EncodingBreakdown {
+        format: format.name().to_string(),
+        fields: slice_fields(u32::from(half), layout),
+        immediate,
+        description: describe(&decoded.mnemonic),
+    }
+}
+
+fn slice_fields(word: u32, layout: Layout) -> Vec<EncodingField> {
+    layout
+        .iter()
+        .map(|&(name, msb, lsb)| EncodingField {
+            name,
+            msb,
+            lsb,
+            value: bitfield::extract(u64::from(word), msb, lsb) as u32,
+        })
+        .collect()
+}
+
+fn opcode_name(opcode: u32) -> Option<&'static str> {
+    Some(match opcode {
+        0x03 => "LOAD",
+        0x07 => "LOAD-FP",
+        0x0b => "custom-0",
+        0x0f => "MISC-MEM",
+        0x13 => "OP-IMM",
+        0x17 => "AUIPC",
+        0x1b => "OP-IMM-32",
+        0x23 => "STORE",
+        0x27 => "STORE-FP",
+        0x2b => "custom-1",
+        0x2f => "AMO",
+        0x33 => "OP",
+        0x37 => "LUI",
+        0x3b => "OP-32",
+        0x43 => "MADD",
+        0x47 => "MSUB",
+        0x4b => "NMSUB",
+        0x4f => "NMADD",
+        0x53 => "OP-FP",
+        0x5b => "custom-2",
+        0x63 => "BRANCH",
+        0x67 => "JALR",
+        0x6f => "JAL",
+        0x73 => "SYSTEM",
+        0x7b => "custom-3",
+        _ => return None,
+    })
+}
+
+/// One-line description of a canonical mnemonic.
+fn describe(mnemonic: &str) -> String {
+    if let Some(base) = mnemonic.strip_prefix("c.") {
+        let base = match base {
+            "addi4spn" => return "Add scaled immediate to sp (compressed)".to_string(),
+            "addi16sp" => return "Add scaled immediate to sp in place (compressed)".to_string(),
+            "li" => return "Load immediate (compressed)".to_string(),
+            "mv" => return "Copy register (compressed)".to_string(),
+            "j" => "jal",
+            "jr" | "jalr" => "jalr",
+            "beqz" => "beq",
+            "bnez" => "bne",
+            "unimp" => return "Defined illegal instruction (compressed)".to_string(),
+            other => other.trim_end_matches("sp"),
+        };
+        return format!("{} (compressed)", describe(base));
+    }
+
+    if let Some(description) = describe_base(mnemonic) {
+        return description.to_string();
+    }
+    if let Some(description) = describe_atomic(mnemonic) {
+        return description;
+    }
+    if let Some(description) = describe_float(mnemonic) {
+        return description;
+    }
+    format!("{mnemonic} instruction")
+}
+
+fn describe_base(mnemonic: &str) -> Option<&'static str> {
+    Some(match mnemonic {
+        "lui" => "Load upper immediate",
+        "auipc" => "Add upper immediate to pc",
+        "jal" => "Jump and link",
+        "jalr" => "Jump and link register",
+        "beq" => "Branch if equal",
+        "bne" => "Branch if not equal",
+        "blt" => "Branch if less than",
+        "bge" => "Branch if greater than or equal",
+        "bltu" => "Branch if less than, unsigned",
+        "bgeu" => "Branch if greater than or equal, unsigned",
+        "lb" => "Load byte, sign-extended",
+        "lh" => "Load halfword, sign-extended",
+        "lw" => "Load word, sign-extended",
+        "ld" => "Load doubleword",
+        "lbu" => "Load byte, zero-extended",
+        "lhu" => "Load halfword, zero-extended",
+        "lwu" => "Load word, zero-extended",
+        "sb" => "Store byte",
+        "sh" => "Store halfword",
+        "sw" => "Store word",
+        "sd" => "Store doubleword",
+        "addi" => "Add immediate",
+        "slti" => "Set if less than immediate",
+        "sltiu" => "Set if less than immediate, unsigned",
+        "xori" => "Exclusive-or immediate",
+        "ori" => "Or immediate",
+        "andi" => "And immediate",
+        "slli" => "Shift left logical by immediate",
+        "srli" => "Shift right logical by immediate",
+        "srai" => "Shift right arithmetic by immediate",
+        "add" => "Add",
+        "sub" => "Subtract",
+        "sll" => "Shift left logical",
+        "slt" => "Set if less than",
+        "sltu" => "Set if less than, unsigned",
+        "xor" => "Exclusive-or",
+        "srl" => "Shift right logical",
+        "sra" => "Shift right arithmetic",
+        "or" => "Or",
+        "and" => "And",
+        "addiw" => "Add word immediate",
+        "slliw" => "Shift word left logical by immediate",
+        "srliw" => "Shift word right logical by immediate",
+        "sraiw" => "Shift word right arithmetic by immediate",
+        "addw" => "Add word",
+        "subw" => "Subtract word",
+        "sllw" => "Shift word left logical",
+        "srlw" => "Shift word right logical",
+        "sraw" => "Shift word right arithmetic",
+        "fence" => "Order memory accesses",
+        "fence.i" => "Synchronize instruction and data streams",
+        "ecall" => "Environment call",
+        "ebreak" => "Environment breakpoint",
+        "uret" => "Return from user-mode trap",
+        "sret" => "Return from supervisor-mode trap",
+        "mret" => "Return from machine-mode trap",
+        "dret" => "Return from debug mode",
+        "wfi" => "Wait for interrupt",
+        "sfence.vma" => "Order page-table updates with address translation",
+        "sinval.vma" => "Invalidate address-translation cache entries",
+        "sfence.w.inval" => "Order earlier stores before later invalidations",
+        "sfence.inval.ir" => "Order invalidations before later implicit accesses",
+        "csrrw" => "Atomic read/write CSR",
+        "csrrs" => "Atomic read and set bits in CSR",
+        "csrrc" => "Atomic read and clear bits in CSR",
+        "csrrwi" => "Atomic read/write CSR with immediate",
+        "csrrsi" => "Atomic read and set bits in CSR with immediate",
+        "csrrci" => "Atomic read and clear bits in CSR with immediate",
+        "prefetch.i" => "Prefetch instruction cache block",
+        "prefetch.r" => "Prefetch cache block for read",
+        "prefetch.w" => "Prefetch cache block for write",
+        "prefetch.t" => "Prefetch cache block",
+        "mul" => "Multiply, low bits",
+        "mulh" => "Multiply signed, high bits",
+        "mulhsu" => "Multiply signed by unsigned, high bits",
+        "mulhu" => "Multiply unsigned, high bits",
+        "div" => "Divide",
+        "divu" => "Divide, unsigned",
+        "rem" => "Remainder",
+        "remu" => "Remainder, unsigned",
+        "mulw" => "Multiply word",
+        "divw" => "Divide word",
+        "divuw" => "Divide word, unsigned",
+        "remw" => "Remainder word",
+        "remuw" => "Remainder word, unsigned",
+        "th.mveqz" => "Move if condition register is zero",
+        "th.mvnez" => "Move if condition register is non-zero",
+        _ => return None,
+    })
+}
+
+fn describe_atomic(mnemonic: &str) -> Option<String> {
+    let (mnemonic, ordering) = match mnemonic.rsplit_once('.') {
+        Some((base, "aq")) => (base, " with acquire ordering"),
+        Some((base, "rl")) => (base, " with release ordering"),
+        Some((base, "aqrl")) => (base, " with acquire and release ordering"),
+        _ => (mnemonic, ""),
+    };
+    let (operation, width) = mnemonic.rsplit_once('.')?;
+    let width = match width {
+        "b" => "byte",
+        "h" => "halfword",
+        "w" => "word",
+        "d" => "doubleword",
+        "q" => "quadword",
+        _ => return None,
+    };
+    let action = match operation {
+        "lr" => return Some(format!("Load-reserved {width}{ordering}")),
+        "sc" => return Some(format!("Store-conditional {width}{ordering}")),
+        "amocas" => "compare-and-swap",
+        "amoswap" => "swap",
+        "amoadd" => "add",
+        "amoxor" => "exclusive-or",
+        "amoand" => "and",
+        "amoor" => "or",
+        "amomin" => "minimum",
+        "amomax" => "maximum",
+        "amominu" => "unsigned minimum",
+        "amomaxu" => "unsigned maximum",
+        _ => return None,
+    };
+    Some(format!("Atomic {action} on memory {width}{ordering}"))
+}
+
+fn describe_float(mnemonic: &str) -> Option<String> {
+    let precision = |suffix: &str| match suffix {
+        "s" => Some("single-precision"),
+        "d" => Some("double-precision"),
+        _ => None,
+    };
+
+    match mnemonic {
+        "flw" => return Some("Load single-precision value".to_string()),
+        "fld" => return Some("Load double-precision value".to_string()),
+        "fsw" => return Some("Store single-precision value".to_string()),
+        "fsd" => return Some("Store double-precision value".to_string()),
+        "fmv.x.w" | "fmv.x.d" => {
+            return Some("Move floating-point bits to integer register".to_string());
+        }
+        "fmv.w.x" | "fmv.d.x" => {
+            return Some("Move integer bits to floating-point register".to_string());
+        }
+        _ => {}
+    }
+
+    let mut parts = mnemonic.split('.');
+    let operation = parts.next()?;
+    if operation == "fcvt" {
+        let to = parts.next()?;
+        let from = parts.next()?;
+        return Some(format!("Convert {from} to {to}"));
+    }
+    let precision = precision(parts.next()?)?;
+    let action = match operation {
+        "fadd" => "Add",
+        "fsub" => "Subtract",
+        "fmul" => "Multiply",
+        "fdiv" => "Divide",
+        "fsqrt" => "Square root",
+        "fmin" => "Minimum",
+        "fmax" => "Maximum",
+        "fmadd" => "Fused multiply-add",
+        "fmsub" => "Fused multiply-subtract",
+        "fnmadd" => "Negated fused multiply-add",
+        "fnmsub" => "Negated fused multiply-subtract",
+        "fsgnj" => "Sign-inject",
+        "fsgnjn" => "Negated sign-inject",
+        "fsgnjx" => "Xor sign-inject",
+        "feq" => "Compare equal",
+        "flt" => "Compare less than",
+        "fle" => "Compare less than or equal",
+        "fclass" => "Classify",
+        _ => return None,
+    };
+    Some(format!("{action}, {precision}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RiscVHandler;
+    use robustone_core::ArchitectureHandler;
+
+    fn breakdown(bytes: &[u8]) -> EncodingBreakdown {
+        RiscVHandler::new()
+            .explain_encoding(bytes, "riscv64")
+            .expect("breakdown")
+    }
+
+    #[test]
+    fn test_explain_splits_standard_formats_into_fields() {
+        // addi sp, sp, -16
+        let addi = breakdown(&[0x13, 0x01, 0x01, 0xff]);
+        assert_eq!(addi.format, "I-type (OP-IMM)");
+        assert_eq!(addi.immediate, Some(-16));
+        assert_eq!(addi.description, "Add immediate");
+        let names: Vec<_> = addi.fields.iter().map(|field| field.name).collect();
+        assert_eq!(names, ["imm[11:0]", "rs1", "funct3", "rd", "opcode"]);
+        assert_eq!(addi.fields[0].value, 0xff0);
+        assert_eq!(addi.fields[1].value, 2);
+        assert_eq!(addi.fields[4].value, 0x13);
+
+        // beq a0, a1, 8
+        let beq = breakdown(&[0x63, 0x04, 0xb5, 0x00]);
+        assert_eq!(beq.format, "B-type (BRANCH)");
+        assert_eq!(beq.immediate, Some(8));
+        assert_eq!(
+            beq.fields
+                .iter()
+                .map(|field| field.width() as u32)
+                .sum::<u32>(),
+            32
+        );
+    }
+
+    #[cfg(all(feature = "ext-c", feature = "ext-d"))]
+    #[test]
+    fn test_explain_describes_compressed_and_extension_instructions() {
+        // c.addi a0, 1
+        let c_addi = breakdown(&[0x05, 0x05]);
+        assert_eq!(c_addi.format, "CI");
+        assert_eq!(c_addi.immediate, Some(1));
+        assert_eq!(c_addi.description, "Add immediate (compressed)");
+
+        // fadd.d fa0, fa1, fa2
+        let fadd = breakdown(&[0x53, 0xf5, 0xc5, 0x02]);
+        assert_eq!(fadd.format, "R-type (OP-FP)");
+        assert_eq!(fadd.description, "Add, double-precision");
+        assert_eq!(fadd.fields[3].name, "rm");
+    }
+
+    #[test]
+    fn test_explain_splits_encodings_the_decoder_rejects() {
+        // custom-0 without a vendor extension enabled
+        let custom = breakdown(&[0x0b, 0x50, 0x10, 0x00]);
+        assert_eq!(custom.format, "unknown (custom-0)");
+        assert_eq!(custom.immediate, None);
+        let fields: Vec<_> = custom
+            .fields
+            .iter()
+            .map(|field| (field.name, field.value))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("funct7", 0),
+                ("rs2", 1),
+                ("rs1", 0),
+                ("funct3", 5),
+                ("rd", 0),
+                ("opcode", 0x0b)
+            ]
+        );
+
+        let half = explain_unknown(&[0x00, 0x00]).expect("compressed split");
+        assert_eq!(half.format, "unknown (compressed)");
+        assert_eq!(half.fields.len(), 3);
+        assert!(explain_unknown(&[0x0b, 0x50]).is_none());
+    }
+}
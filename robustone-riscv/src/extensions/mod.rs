@@ -14,7 +14,24 @@ pub mod thead;
 use standard::Standard;
 use thead::THead;
 
+/// Standard extensions that need another one, as `(extension, name,
+/// required, required name)`.
+///
+/// An extension is listed after the one it requires, so walking the table
+/// forwards drops dependents and walking it backwards enables requirements
+/// along a whole chain (V needs D, which needs F).
+///
+/// Compressed floating-point loads and stores (Zcf/Zcd) need no entry: the
+/// C decoder accepts them only when F or D is enabled as well.
+const STANDARD_DEPENDENCIES: &[(Standard, &str, Standard, &str)] = &[
+    (Standard::D, "D", Standard::F, "F"),
+    (Standard::V, "V", Standard::D, "D"),
+    (Standard::ZABHA, "Zabha", Standard::A, "A"),
+    (Standard::ZACAS, "Zacas", Standard::A, "A"),
+];
+
 /// Aggregated extension configuration passed to RISC-V extension handlers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Extensions {
     pub(crate) standard: Standard,
     pub(crate) thead: THead,
@@ -45,61 +62,158 @@ impl Extensions {
         self
     }
 
-    /// Build an extension set from a profile-style list of enabled names.
-    pub fn from_enabled_extensions(
-        enabled_extensions: &[&str],
-    ) -> Result<Self, crate::types::error::DisasmError> {
-        let mut standard = Standard::empty();
-        let mut thead = THead::empty();
+    /// Names of the enabled extensions: the standard ones in ISA-string order
+    /// (`I`, `M`, `A`, `F`, `D`, `C`, `V`, `Zabha`, `Zacas`), then vendor
+    /// extensions.
+    pub fn iter_enabled(&self) -> impl Iterator<Item = &'static str> + '_ {
+        let standard = [
+            (Standard::I, "I"),
+            (Standard::M, "M"),
+            (Standard::A, "A"),
+            (Standard::F, "F"),
+            (Standard::D, "D"),
+            (Standard::C, "C"),
+            (Standard::V, "V"),
+            (Standard::ZABHA, "Zabha"),
+            (Standard::ZACAS, "Zacas"),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| self.standard.contains(*flag));
+        let thead = [(THead::CMOV, "XTheadCondMov")]
+            .into_iter()
+            .filter(|(flag, _)| self.thead.contains(*flag));
+        standard
+            .map(|(_, name)| name)
+            .chain(thead.map(|(_, name)| name))
+    }
 
+    /// Build an extension set from a profile-style list of enabled names.
+    pub fn from_enabled_extensions(enabled_extensions: &[&str]) -> Result<Self, DisasmError> {
+        let mut extensions = Self {
+            standard: Standard::empty(),
+            thead: THead::empty(),
+        };
         for extension in enabled_extensions {
-            match extension.to_ascii_uppercase().as_str() {
-                "I" => standard |= Standard::I,
-                "M" => standard |= Standard::M,
-                "A" => standard |= Standard::A,
-                "F" => standard |= Standard::F,
-                "D" => standard |= Standard::D,
-                "C" => standard |= Standard::C,
-                "G" => standard |= Standard::G,
-                "XTHEADCONDMOV" | "CMOV" => thead |= THead::CMOV,
-                other => {
-                    return Err(crate::types::error::DisasmError::decode_failure(
-                        crate::types::error::DecodeErrorKind::UnsupportedExtension,
-                        None::<String>,
-                        format!("unsupported profile extension `{other}`"),
-                    ));
-                }
-            }
+            let (standard, thead) = extension_flags(extension)?;
+            extensions.standard |= standard;
+            extensions.thead |= thead;
         }
 
-        if !standard.contains(Standard::I) {
-            return Err(crate::types::error::DisasmError::decode_failure(
-                crate::types::error::DecodeErrorKind::UnsupportedExtension,
+        extensions.validate()?;
+        Ok(extensions)
+    }
+
+    /// Enable the extension called `name` and the extensions it depends on.
+    ///
+    /// Names are those accepted by [`Self::from_enabled_extensions`].
+    pub fn enable(&mut self, name: &str) -> Result<(), DisasmError> {
+        let (standard, thead) = extension_flags(name)?;
+        self.standard |= standard;
+        self.thead |= thead;
+        *self = self.with_implied();
+        Ok(())
+    }
+
+    /// Disable the extension called `name` and the extensions that depend on
+    /// it, so disabling F also disables D. The base I cannot be disabled.
+    pub fn disable(&mut self, name: &str) -> Result<(), DisasmError> {
+        let (standard, thead) = extension_flags(name)?;
+        if standard.contains(Standard::I) {
+            return Err(DisasmError::decode_failure(
+                DecodeErrorKind::UnsupportedExtension,
                 None::<String>,
-                "RISC-V profiles must enable the base I extension",
+                "the base I extension cannot be disabled",
             ));
         }
+        self.standard -= standard;
+        self.thead -= thead;
+        for &(extension, _, required, _) in STANDARD_DEPENDENCIES {
+            if !self.standard.contains(required) {
+                self.standard -= extension;
+            }
+        }
+        Ok(())
+    }
 
-        if standard.contains(Standard::D) && !standard.contains(Standard::F) {
-            return Err(crate::types::error::DisasmError::decode_failure(
-                crate::types::error::DecodeErrorKind::UnsupportedExtension,
+    /// Check that every enabled extension has the extensions it depends on.
+    ///
+    /// All violations are reported in one error, for example
+    /// `RISC-V profiles cannot enable D without F`.
+    pub fn validate(&self) -> Result<(), DisasmError> {
+        let mut problems = Vec::new();
+        if !self.standard.contains(Standard::I) {
+            problems.push("RISC-V profiles must enable the base I extension".to_string());
+        }
+        for &(extension, name, required, required_name) in STANDARD_DEPENDENCIES {
+            if self.standard.contains(extension) && !self.standard.contains(required) {
+                problems.push(format!(
+                    "RISC-V profiles cannot enable {name} without {required_name}"
+                ));
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(DisasmError::decode_failure(
+                DecodeErrorKind::UnsupportedExtension,
                 None::<String>,
-                "RISC-V profiles cannot enable D without F",
-            ));
+                problems.join("; "),
+            ))
         }
+    }
 
-        Ok(Self { standard, thead })
+    /// Enable every extension an enabled one depends on, so the set passes
+    /// [`validate`](Self::validate).
+    pub fn with_implied(mut self) -> Self {
+        self.standard |= Standard::I;
+        for &(extension, _, required, _) in STANDARD_DEPENDENCIES.iter().rev() {
+            if self.standard.contains(extension) {
+                self.standard |= required;
+            }
+        }
+        self
     }
 }
 
+/// Standard and vendor flags for an extension name such as `M`, `G`,
+/// `Zacas`, or `XTheadCondMov` (case-insensitive).
+fn extension_flags(name: &str) -> Result<(Standard, THead), DisasmError> {
+    let mut standard = Standard::empty();
+    let mut thead = THead::empty();
+    match name.to_ascii_uppercase().as_str() {
+        "I" => standard = Standard::I,
+        "M" => standard = Standard::M,
+        "A" => standard = Standard::A,
+        "F" => standard = Standard::F,
+        "D" => standard = Standard::D,
+        "C" => standard = Standard::C,
+        "V" => standard = Standard::V,
+        "G" => standard = Standard::G,
+        "ZABHA" => standard = Standard::ZABHA,
+        "ZACAS" => standard = Standard::ZACAS,
+        "XTHEADCONDMOV" | "CMOV" => thead = THead::CMOV,
+        other => {
+            return Err(DisasmError::decode_failure(
+                DecodeErrorKind::UnsupportedExtension,
+                None::<String>,
+                format!("unsupported profile extension `{other}`"),
+            ));
+        }
+    }
+    Ok((standard, thead))
+}
+
 /// Trait that all instruction set extensions must implement.
 #[allow(clippy::too_many_arguments)]
-pub trait InstructionExtension: Sync {
+pub trait InstructionExtension: Send + Sync {
     /// Try to decode a standard 32-bit instruction.
     ///
     /// Returns `Some(Ok(instruction))` if this extension can decode the instruction,
     /// `Some(Err(error))` if decoding fails within this extension,
     /// or `None` if this extension doesn't handle the instruction.
+    ///
+    /// `extensions` is the decoder's full extension set, for encodings that
+    /// only exist when another extension is enabled as well.
     fn try_decode_standard(
         &self,
         opcode: u32,
@@ -115,6 +229,7 @@ pub trait InstructionExtension: Sync {
         imm_u: i64,
         imm_j: i64,
         xlen: Xlen,
+        extensions: &Extensions,
     ) -> Option<Result<DecodedInstruction, DisasmError>>;
 
     /// Try to decode a compressed 16-bit instruction.
@@ -154,21 +269,48 @@ pub trait InstructionExtension: Sync {
     /// Get the name of this extension.
     fn name(&self) -> &'static str;
 
+    /// Canonical mnemonics this extension can decode.
+    fn mnemonics(&self) -> &'static [&'static str];
+
+    /// The [`mnemonics`](Self::mnemonics) that decode for `xlen` with
+    /// `extensions` enabled. Extensions with XLEN-specific or conditional
+    /// encodings override this.
+    fn decodable_mnemonics(&self, _xlen: Xlen, _extensions: &Extensions) -> Vec<&'static str> {
+        self.mnemonics().to_vec()
+    }
+
     /// Check if this extension is enabled for the given configuration.
     fn is_enabled(&self, extensions: &Extensions) -> bool;
 }
 
-/// Create all available standard RISC-V extensions.
+/// Create the extension handlers compiled into this build.
+///
+/// `Rvi` is always present; the others depend on their `ext-*` features, so
+/// an extension that is enabled in a profile but not compiled in decodes as
+/// an unknown instruction.
 pub fn create_extensions() -> Vec<Box<dyn InstructionExtension>> {
-    vec![
-        Box::new(standard::Rvi::new()),
-        Box::new(standard::Rva::new()),
-        Box::new(standard::Rvm::new()),
-        Box::new(standard::Rvf::new()),
-        Box::new(standard::Rvd::new()),
-        Box::new(standard::Rvc::new()),
-        Box::new(thead::CMov::new()),
-    ]
+    // `mut` goes unused when every `ext-*` feature is disabled.
+    #[allow(unused_mut)]
+    let mut extensions: Vec<Box<dyn InstructionExtension>> = vec![Box::new(standard::Rvi::new())];
+    #[cfg(feature = "ext-a")]
+    extensions.push(Box::new(standard::Rva::new()));
+    #[cfg(feature = "ext-zabha")]
+    extensions.push(Box::new(standard::Zabha::new()));
+    #[cfg(feature = "ext-zacas")]
+    extensions.push(Box::new(standard::Zacas::new()));
+    #[cfg(feature = "ext-m")]
+    extensions.push(Box::new(standard::Rvm::new()));
+    #[cfg(feature = "ext-f")]
+    extensions.push(Box::new(standard::Rvf::new()));
+    #[cfg(feature = "ext-d")]
+    extensions.push(Box::new(standard::Rvd::new()));
+    #[cfg(feature = "ext-c")]
+    extensions.push(Box::new(standard::Rvc::new()));
+    #[cfg(feature = "ext-v")]
+    extensions.push(Box::new(standard::Rvv::new()));
+    #[cfg(feature = "ext-thead")]
+    extensions.push(Box::new(thead::CMov::new()));
+    extensions
 }
 
 pub(crate) fn invalid_encoding(detail: impl Into<String>) -> DisasmError {
@@ -178,3 +320,74 @@ pub(crate) fn invalid_encoding(detail: impl Into<String>) -> DisasmError {
 pub(crate) fn unsupported_mode(detail: impl Into<String>) -> DisasmError {
     DisasmError::decode_failure(DecodeErrorKind::UnsupportedMode, None::<String>, detail)
 }
+
+/// Only the compressed and Zacas decoders have reserved forms to report.
+#[cfg(any(feature = "ext-c", feature = "ext-zacas"))]
+pub(crate) fn reserved_encoding(detail: impl Into<String>) -> DisasmError {
+    DisasmError::decode_failure(DecodeErrorKind::ReservedEncoding, None::<String>, detail)
+}
+
+/// Tag an encoding the specification reserves as a HINT.
+pub(crate) fn hint(mut instruction: DecodedInstruction) -> DecodedInstruction {
+    instruction.groups.push("hint".to_string());
+    instruction
+}
+
+/// Tag the canonical `nop` encodings (`addi x0, x0, 0` and `c.nop`).
+pub(crate) fn nop(mut instruction: DecodedInstruction) -> DecodedInstruction {
+    instruction.groups.push("nop".to_string());
+    instruction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extensions(standard: Standard) -> Extensions {
+        Extensions {
+            standard,
+            thead: THead::empty(),
+        }
+    }
+
+    #[test]
+    fn test_validate_reports_every_missing_dependency() {
+        assert!(Extensions::rv64gc().validate().is_ok());
+
+        let error = extensions(Standard::D).validate().unwrap_err();
+        assert_eq!(error.stable_kind(), "unsupported_extension");
+        assert!(
+            error.to_string().contains(
+                "must enable the base I extension; RISC-V profiles cannot enable D without F"
+            ),
+            "{error}"
+        );
+
+        let error = match Extensions::from_enabled_extensions(&["I", "C", "D"]) {
+            Ok(_) => panic!("D without F should be rejected"),
+            Err(error) => error,
+        };
+        assert!(
+            error.to_string().contains("cannot enable D without F"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_with_implied_enables_dependencies() {
+        let implied = extensions(Standard::D | Standard::C).with_implied();
+        assert_eq!(
+            implied.standard,
+            Standard::I | Standard::F | Standard::D | Standard::C
+        );
+        assert!(implied.validate().is_ok());
+
+        let mut vector = extensions(Standard::V).with_implied();
+        assert_eq!(
+            vector.iter_enabled().collect::<Vec<_>>(),
+            ["I", "F", "D", "V"]
+        );
+        vector.disable("F").unwrap();
+        assert_eq!(vector.iter_enabled().collect::<Vec<_>>(), ["I"]);
+    }
+}
@@ -1,28 +1,51 @@
 //! Standard RISC-V extensions and configuration.
 //!
 //! This module defines the `StandardExtensions` bitflags for core RISC-V
-//! extensions (I/M/A/F/D/C) and re-exports the corresponding extension
-//! handler types under the `standard` namespace.
+//! extensions (I/M/A/F/D/C/V, plus the Zabha and Zacas atomics) and re-exports the corresponding extension
+//! handler types under the `standard` namespace. Handlers other than the
+//! base `Rvi` are compiled in only with their `ext-*` Cargo feature.
 
 use bitflags::bitflags;
 
+#[cfg(feature = "ext-a")]
 pub mod rva;
+#[cfg(feature = "ext-c")]
 pub mod rvc;
+#[cfg(feature = "ext-d")]
 pub mod rvd;
+#[cfg(feature = "ext-f")]
 pub mod rvf;
 pub mod rvi;
+#[cfg(feature = "ext-m")]
 pub mod rvm;
+#[cfg(feature = "ext-v")]
+pub mod rvv;
+#[cfg(feature = "ext-zabha")]
+pub mod zabha;
+#[cfg(feature = "ext-zacas")]
+pub mod zacas;
 
+#[cfg(feature = "ext-a")]
 pub use rva::Rva;
+#[cfg(feature = "ext-c")]
 pub use rvc::Rvc;
+#[cfg(feature = "ext-d")]
 pub use rvd::Rvd;
+#[cfg(feature = "ext-f")]
 pub use rvf::Rvf;
 pub use rvi::Rvi;
+#[cfg(feature = "ext-m")]
 pub use rvm::Rvm;
+#[cfg(feature = "ext-v")]
+pub use rvv::Rvv;
+#[cfg(feature = "ext-zabha")]
+pub use zabha::Zabha;
+#[cfg(feature = "ext-zacas")]
+pub use zacas::Zacas;
 
 bitflags! {
     /// Bitflags representing enabled standard RISC-V extensions.
-    #[derive(Debug, PartialEq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct Standard: u32 {
         const I    = 1;
         const M    = 1 << 1;
@@ -30,6 +53,11 @@ bitflags! {
         const F    = 1 << 3;
         const D    = 1 << 4;
         const C    = 1 << 5;
+        const V    = 1 << 6;
+        /// Byte and halfword atomics.
+        const ZABHA = 1 << 7;
+        /// Atomic compare-and-swap.
+        const ZACAS = 1 << 8;
         /// Shorthand for the standard G profile (IMAFD).
         const G    = Self::I.bits()
             | Self::M.bits()
@@ -4,7 +4,7 @@
 //! which provides atomic memory operations for synchronization and concurrency.
 
 use super::Standard;
-use crate::ir::DecodedInstruction;
+use crate::ir::{DecodedInstruction, MemoryOrdering, Operand};
 use crate::riscv::decoder::{Xlen, build_riscv_decoded_instruction};
 use crate::riscv::extensions::{
     Extensions, InstructionExtension, invalid_encoding, unsupported_mode,
@@ -29,17 +29,14 @@ impl Rva {
     // A-extension opcode
     const OPCODE_A: u32 = 0b010_1111;
 
-    // A-extension funct3 values
-    const FUNCT3_AMO_W: u8 = 0b010;
-    const FUNCT3_AMO_D: u8 = 0b011;
-    const FUNCT3_LR_W: u8 = 0b010;
-    const FUNCT3_SC_W: u8 = 0b010;
-    const FUNCT3_LR_D: u8 = 0b011;
-    const FUNCT3_SC_D: u8 = 0b011;
+    // A-extension funct3 values select the access width.
+    const FUNCT3_W: u8 = 0b010;
+    const FUNCT3_D: u8 = 0b011;
 
     // A-extension funct5 values
     const FUNCT5_LR: u8 = 0b00010;
     const FUNCT5_SC: u8 = 0b00011;
+    pub(super) const FUNCT5_AMOCAS: u8 = 0b00101;
     const FUNCT5_AMOSWAP: u8 = 0b00001;
     const FUNCT5_AMOADD: u8 = 0b00000;
     const FUNCT5_AMOXOR: u8 = 0b00100;
@@ -50,24 +47,40 @@ impl Rva {
     const FUNCT5_AMOMINU: u8 = 0b11000;
     const FUNCT5_AMOMAXU: u8 = 0b11100;
 
-    fn decode_amo(
-        &self,
-        mnemonic: &str,
-        rd: u8,
-        rs1: u8,
-        rs2: u8,
-    ) -> Result<DecodedInstruction, DisasmError> {
-        let _ = &self.register_manager;
-        Ok(build_riscv_decoded_instruction(
-            mnemonic,
-            RiscVInstructionFormat::R,
-            4,
-            vec![
-                convenience::register(rd, Access::write()),
-                convenience::memory(rs1, 0),
-                convenience::register(rs2, Access::read()),
-            ],
-        ))
+    // The low funct7 bits are the `aq` and `rl` ordering bits.
+    const AQ: u8 = 0b10;
+    const RL: u8 = 0b01;
+
+    /// Name of the read-modify-write AMO selected by `funct5`, such as
+    /// `amoadd`. Zabha reuses these for its byte and halfword forms.
+    pub(super) fn amo_operation(funct5: u8) -> Option<&'static str> {
+        Some(match funct5 {
+            Self::FUNCT5_AMOSWAP => "amoswap",
+            Self::FUNCT5_AMOADD => "amoadd",
+            Self::FUNCT5_AMOXOR => "amoxor",
+            Self::FUNCT5_AMOAND => "amoand",
+            Self::FUNCT5_AMOOR => "amoor",
+            Self::FUNCT5_AMOMIN => "amomin",
+            Self::FUNCT5_AMOMAX => "amomax",
+            Self::FUNCT5_AMOMINU => "amominu",
+            Self::FUNCT5_AMOMAXU => "amomaxu",
+            _ => return None,
+        })
+    }
+
+    /// The `aq`/`rl` bits of `funct7` and the mnemonic suffix spelling them.
+    pub(super) fn ordering(funct7: u8) -> (MemoryOrdering, &'static str) {
+        let ordering = MemoryOrdering {
+            acquire: funct7 & Self::AQ != 0,
+            release: funct7 & Self::RL != 0,
+        };
+        let suffix = match (ordering.acquire, ordering.release) {
+            (false, false) => "",
+            (true, false) => ".aq",
+            (false, true) => ".rl",
+            (true, true) => ".aqrl",
+        };
+        (ordering, suffix)
     }
 
     fn decode_lr_sc(
@@ -104,6 +117,42 @@ impl InstructionExtension for Rva {
         "A"
     }
 
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &[
+            "lr.w",
+            "sc.w",
+            "amoswap.w",
+            "amoadd.w",
+            "amoxor.w",
+            "amoand.w",
+            "amoor.w",
+            "amomin.w",
+            "amomax.w",
+            "amominu.w",
+            "amomaxu.w",
+            "lr.d",
+            "sc.d",
+            "amoswap.d",
+            "amoadd.d",
+            "amoxor.d",
+            "amoand.d",
+            "amoor.d",
+            "amomin.d",
+            "amomax.d",
+            "amominu.d",
+            "amomaxu.d",
+        ]
+    }
+
+    fn decodable_mnemonics(&self, xlen: Xlen, _extensions: &Extensions) -> Vec<&'static str> {
+        // The doubleword forms are RV64-only.
+        self.mnemonics()
+            .iter()
+            .copied()
+            .filter(|mnemonic| xlen == Xlen::X64 || !mnemonic.ends_with(".d"))
+            .collect()
+    }
+
     fn is_enabled(&self, extensions: &Extensions) -> bool {
         // A extension bit (bit 2)
         extensions.standard.contains(Standard::A)
@@ -124,122 +173,42 @@ impl InstructionExtension for Rva {
         _imm_u: i64,
         _imm_j: i64,
         xlen: Xlen,
+        _extensions: &Extensions,
     ) -> Option<Result<DecodedInstruction, DisasmError>> {
         if opcode != Self::OPCODE_A {
             return None;
         }
 
+        // Other widths belong to Zabha, and compare-and-swap to Zacas.
+        let width = match funct3 {
+            Self::FUNCT3_W => "w",
+            Self::FUNCT3_D => "d",
+            _ => return None,
+        };
         let funct5 = (funct7 >> 2) & 0b11111;
-        let funct2 = funct7 & 0b11;
-
-        match (funct3, funct5, funct2) {
-            // Load-Reserved/Store-Conditional instructions
-            (Self::FUNCT3_LR_W, Self::FUNCT5_LR, 0b00) => {
-                Some(self.decode_lr_sc("lr.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_SC_W, Self::FUNCT5_SC, 0b00) => {
-                Some(self.decode_lr_sc("sc.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_LR_D, Self::FUNCT5_LR, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_lr_sc("lr.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_LR_D, Self::FUNCT5_LR, 0b01) => {
-                Some(Err(unsupported_mode("lr.d requires RV64")))
-            }
-            (Self::FUNCT3_SC_D, Self::FUNCT5_SC, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_lr_sc("sc.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_SC_D, Self::FUNCT5_SC, 0b01) => {
-                Some(Err(unsupported_mode("sc.d requires RV64")))
-            }
-
-            // Atomic Memory Operation instructions
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOSWAP, 0b00) => {
-                Some(self.decode_amo("amoswap.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOADD, 0b00) => {
-                Some(self.decode_amo("amoadd.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOXOR, 0b00) => {
-                Some(self.decode_amo("amoxor.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOAND, 0b00) => {
-                Some(self.decode_amo("amoand.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOOR, 0b00) => {
-                Some(self.decode_amo("amoor.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOMIN, 0b00) => {
-                Some(self.decode_amo("amomin.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOMAX, 0b00) => {
-                Some(self.decode_amo("amomax.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOMINU, 0b00) => {
-                Some(self.decode_amo("amominu.w", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_W, Self::FUNCT5_AMOMAXU, 0b00) => {
-                Some(self.decode_amo("amomaxu.w", rd, rs1, rs2))
-            }
-
-            // 64-bit AMO instructions
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOSWAP, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amoswap.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOSWAP, 0b01) => {
-                Some(Err(unsupported_mode("amoswap.d requires RV64")))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOADD, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amoadd.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOADD, 0b01) => {
-                Some(Err(unsupported_mode("amoadd.d requires RV64")))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOXOR, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amoxor.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOXOR, 0b01) => {
-                Some(Err(unsupported_mode("amoxor.d requires RV64")))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOAND, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amoand.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOAND, 0b01) => {
-                Some(Err(unsupported_mode("amoand.d requires RV64")))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOOR, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amoor.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOOR, 0b01) => {
-                Some(Err(unsupported_mode("amoor.d requires RV64")))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOMIN, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amomin.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOMIN, 0b01) => {
-                Some(Err(unsupported_mode("amomin.d requires RV64")))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOMAX, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amomax.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOMAX, 0b01) => {
-                Some(Err(unsupported_mode("amomax.d requires RV64")))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOMINU, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amominu.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOMINU, 0b01) => {
-                Some(Err(unsupported_mode("amominu.d requires RV64")))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOMAXU, 0b01) if xlen == Xlen::X64 => {
-                Some(self.decode_amo("amomaxu.d", rd, rs1, rs2))
-            }
-            (Self::FUNCT3_AMO_D, Self::FUNCT5_AMOMAXU, 0b01) => {
-                Some(Err(unsupported_mode("amomaxu.d requires RV64")))
-            }
-
-            _ => Some(Err(invalid_encoding("invalid A-extension encoding"))),
+        let operation = match funct5 {
+            Self::FUNCT5_LR => "lr",
+            Self::FUNCT5_SC => "sc",
+            Self::FUNCT5_AMOCAS => return None,
+            _ => match Self::amo_operation(funct5) {
+                Some(operation) => operation,
+                None => return Some(Err(invalid_encoding("invalid A-extension encoding"))),
+            },
+        };
+        if width == "d" && xlen != Xlen::X64 {
+            return Some(Err(unsupported_mode(format!(
+                "{operation}.d requires RV64"
+            ))));
         }
+
+        let (ordering, suffix) = Self::ordering(funct7);
+        let mnemonic = format!("{operation}.{width}{suffix}");
+        let decoded = if matches!(operation, "lr" | "sc") {
+            self.decode_lr_sc(&mnemonic, rd, rs1, rs2)
+        } else {
+            Ok(decode_amo(&mnemonic, rd, rs1, rs2))
+        };
+        Some(decoded.map(|instruction| with_ordering(instruction, ordering)))
     }
 
     fn try_decode_compressed(
@@ -273,6 +242,34 @@ impl InstructionExtension for Rva {
     }
 }
 
+/// Build a read-modify-write AMO: `rd` receives the old memory value and
+/// `rs2` supplies the operand.
+pub(super) fn decode_amo(mnemonic: &str, rd: u8, rs1: u8, rs2: u8) -> DecodedInstruction {
+    build_riscv_decoded_instruction(
+        mnemonic,
+        RiscVInstructionFormat::R,
+        4,
+        vec![
+            convenience::register(rd, Access::write()),
+            convenience::memory(rs1, 0),
+            convenience::register(rs2, Access::read()),
+        ],
+    )
+}
+
+/// Record the `aq`/`rl` bits on the instruction's memory operand.
+pub(super) fn with_ordering(
+    mut instruction: DecodedInstruction,
+    ordering: MemoryOrdering,
+) -> DecodedInstruction {
+    for operand in &mut instruction.operands {
+        if let Operand::Memory { ordering: slot, .. } = operand {
+            *slot = ordering;
+        }
+    }
+    instruction
+}
+
 impl Default for Rva {
     fn default() -> Self {
         Self::new()
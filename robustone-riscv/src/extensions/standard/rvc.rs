@@ -7,7 +7,8 @@ use super::Standard;
 use crate::ir::DecodedInstruction;
 use crate::riscv::decoder::{Xlen, build_riscv_decoded_instruction};
 use crate::riscv::extensions::{
-    Extensions, InstructionExtension, invalid_encoding, unsupported_mode,
+    Extensions, InstructionExtension, hint, invalid_encoding, nop, reserved_encoding,
+    unsupported_mode,
 };
 use crate::riscv::shared::{
     encoding::convenience as encoding_conv, operands::convenience, registers::RegisterManager,
@@ -57,7 +58,10 @@ impl Rvc {
         ))
     }
 
-    fn decode_c_lui(&self, rd: u8, imm: i64) -> Result<DecodedInstruction, DisasmError> {
+    /// `c.lui rd, nzimm`: `nzimm[17:12]` is sign-extended and carried as the
+    /// 20-bit upper immediate of the equivalent `lui`, so `-1` is `0xfffff`.
+    fn decode_c_lui(&self, rd: u8, nzimm: i64) -> Result<DecodedInstruction, DisasmError> {
+        let imm = nzimm & 0xfffff;
         let _ = &self.register_manager;
         Ok(build_riscv_decoded_instruction(
             "c.lui",
@@ -415,6 +419,15 @@ impl Rvc {
         xlen: Xlen,
         imm_ci: i64,
     ) -> Result<DecodedInstruction, DisasmError> {
+        // inst[11:10] selects the CB-format shifts and c.andi; 0b11 is the
+        // CA-format register group, split by inst[12] and inst[6:5].
+        match funct6 & 0b11 {
+            0b00 => return self.decode_c_shift("c.srli", rs1, imm_ci, xlen),
+            0b01 => return self.decode_c_shift("c.srai", rs1, imm_ci, xlen),
+            0b10 => return self.decode_c_andi(rs1, imm_ci),
+            _ => {}
+        }
+
         let (mnemonic, capstone_alias) = match (funct6, funct2, xlen) {
             (0b100011, 0b00, _) => ("c.sub", None),
             (0b100011, 0b01, _) => ("c.xor", None),
@@ -424,12 +437,7 @@ impl Rvc {
             (0b100111, 0b01, Xlen::X64) => ("c.addw", Some("addw")),
             (0b100111, 0b00, _) => return Err(unsupported_mode("c.subw requires RV64")),
             (0b100111, 0b01, _) => return Err(unsupported_mode("c.addw requires RV64")),
-            _ => match funct6 & 0b11 {
-                0b00 => ("c.srli", None),
-                0b01 => ("c.srai", None),
-                0b10 => ("c.andi", None),
-                _ => return Err(invalid_encoding("invalid C.ALU encoding")),
-            },
+            _ => return Err(reserved_encoding("reserved C.ALU encoding")),
         };
 
         let _ = &self.register_manager;
@@ -444,16 +452,6 @@ impl Rvc {
                     convenience::register(rs2 + 8, Access::read()),
                 ],
             )
-        } else if matches!(mnemonic, "c.srli" | "c.srai" | "c.andi") {
-            build_riscv_decoded_instruction(
-                mnemonic,
-                RiscVInstructionFormat::CA,
-                2,
-                vec![
-                    convenience::register(rs1 + 8, Access::read_write()),
-                    convenience::immediate(imm_ci),
-                ],
-            )
         } else {
             build_riscv_decoded_instruction(
                 mnemonic,
@@ -473,6 +471,60 @@ impl Rvc {
         }
     }
 
+    /// `c.srli`/`c.srai`: the 6-bit shamt is `inst[12] | inst[6:2]`,
+    /// zero-extended. RV32 has no shifts of 32 or more, so `inst[12]` must be
+    /// clear there. A zero shamt is the RV128 `c.srli64`/`c.srai64` and a
+    /// HINT on RV32/RV64.
+    fn decode_c_shift(
+        &self,
+        mnemonic: &'static str,
+        rs1: u8,
+        imm_ci: i64,
+        xlen: Xlen,
+    ) -> Result<DecodedInstruction, DisasmError> {
+        let shamt = imm_ci & 0x3f;
+        if xlen == Xlen::X32 && shamt >= 32 {
+            return Err(unsupported_mode(format!(
+                "{mnemonic} shamt >= 32 requires RV64"
+            )));
+        }
+        if shamt == 0 {
+            let mnemonic = if mnemonic == "c.srli" {
+                "c.srli64"
+            } else {
+                "c.srai64"
+            };
+            return Ok(hint(build_riscv_decoded_instruction(
+                mnemonic,
+                RiscVInstructionFormat::CB,
+                2,
+                vec![convenience::register(rs1 + 8, Access::read_write())],
+            )));
+        }
+        Ok(build_riscv_decoded_instruction(
+            mnemonic,
+            RiscVInstructionFormat::CB,
+            2,
+            vec![
+                convenience::register(rs1 + 8, Access::read_write()),
+                convenience::immediate(shamt),
+            ],
+        ))
+    }
+
+    /// `c.andi`: the immediate is the sign-extended `inst[12] | inst[6:2]`.
+    fn decode_c_andi(&self, rs1: u8, imm_ci: i64) -> Result<DecodedInstruction, DisasmError> {
+        Ok(build_riscv_decoded_instruction(
+            "c.andi",
+            RiscVInstructionFormat::CB,
+            2,
+            vec![
+                convenience::register(rs1 + 8, Access::read_write()),
+                convenience::immediate(imm_ci),
+            ],
+        ))
+    }
+
     fn decode_c_j(&self, imm: i64) -> Result<DecodedInstruction, DisasmError> {
         Ok(build_riscv_decoded_instruction(
             "c.j",
@@ -518,17 +570,45 @@ impl Rvc {
         ))
     }
 
-    fn decode_c_slli(&self, rd: u8, imm: i64) -> Result<DecodedInstruction, DisasmError> {
+    /// `c.slli`: the 6-bit shamt is `inst[12] | inst[6:2]`, zero-extended.
+    ///
+    /// `shamt[5]` is reserved on RV32. A zero shamt is the RV128 `c.slli64`
+    /// and a HINT on RV32/RV64, as is any `rd = x0`; HINTs decode but carry
+    /// the `hint` group.
+    fn decode_c_slli(
+        &self,
+        rd: u8,
+        imm_ci: i64,
+        xlen: Xlen,
+    ) -> Result<DecodedInstruction, DisasmError> {
         let _ = &self.register_manager;
-        Ok(build_riscv_decoded_instruction(
-            "c.slli",
-            RiscVInstructionFormat::CI,
-            2,
-            vec![
-                convenience::register(rd, Access::read_write()),
-                convenience::immediate(imm),
-            ],
-        ))
+        let shamt = imm_ci & 0x3f;
+        if xlen == Xlen::X32 && shamt >= 32 {
+            return Err(unsupported_mode("c.slli shamt >= 32 requires RV64"));
+        }
+        let instruction = if shamt == 0 {
+            build_riscv_decoded_instruction(
+                "c.slli64",
+                RiscVInstructionFormat::CI,
+                2,
+                vec![convenience::register(rd, Access::read_write())],
+            )
+        } else {
+            build_riscv_decoded_instruction(
+                "c.slli",
+                RiscVInstructionFormat::CI,
+                2,
+                vec![
+                    convenience::register(rd, Access::read_write()),
+                    convenience::immediate(shamt),
+                ],
+            )
+        };
+        Ok(if shamt == 0 || rd == 0 {
+            hint(instruction)
+        } else {
+            instruction
+        })
     }
 
     fn decode_c_unimp(&self) -> Result<DecodedInstruction, DisasmError> {
@@ -561,6 +641,70 @@ impl InstructionExtension for Rvc {
         "C"
     }
 
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &[
+            "c.unimp",
+            "c.addi4spn",
+            "c.fld",
+            "c.lw",
+            "c.flw",
+            "c.ld",
+            "c.fsd",
+            "c.sw",
+            "c.fsw",
+            "c.sd",
+            "c.addi",
+            "c.jal",
+            "c.addiw",
+            "c.li",
+            "c.addi16sp",
+            "c.lui",
+            "c.srli",
+            "c.srai",
+            "c.andi",
+            "c.sub",
+            "c.xor",
+            "c.or",
+            "c.and",
+            "c.subw",
+            "c.addw",
+            "c.j",
+            "c.beqz",
+            "c.bnez",
+            "c.slli",
+            "c.fldsp",
+            "c.lwsp",
+            "c.flwsp",
+            "c.ldsp",
+            "c.jr",
+            "c.mv",
+            "c.ebreak",
+            "c.jalr",
+            "c.add",
+            "c.fsdsp",
+            "c.swsp",
+            "c.fswsp",
+            "c.sdsp",
+        ]
+    }
+
+    fn decodable_mnemonics(&self, xlen: Xlen, extensions: &Extensions) -> Vec<&'static str> {
+        let rv64 = xlen == Xlen::X64;
+        let f = extensions.standard.contains(Standard::F);
+        let d = extensions.standard.contains(Standard::D);
+        self.mnemonics()
+            .iter()
+            .copied()
+            .filter(|&mnemonic| match mnemonic {
+                "c.ld" | "c.sd" | "c.ldsp" | "c.sdsp" | "c.addiw" | "c.subw" | "c.addw" => rv64,
+                "c.jal" => !rv64,
+                "c.flw" | "c.fsw" | "c.flwsp" | "c.fswsp" => !rv64 && f,
+                "c.fld" | "c.fsd" | "c.fldsp" | "c.fsdsp" => d,
+                _ => true,
+            })
+            .collect()
+    }
+
     fn is_enabled(&self, extensions: &Extensions) -> bool {
         // C extension bit (bit 5)
         extensions.standard.contains(Standard::C)
@@ -581,6 +725,7 @@ impl InstructionExtension for Rvc {
         _imm_u: i64,
         _imm_j: i64,
         _xlen: Xlen,
+        _extensions: &Extensions,
     ) -> Option<Result<DecodedInstruction, DisasmError>> {
         // RVC extension only handles compressed instructions
         None
@@ -615,9 +760,12 @@ impl InstructionExtension for Rvc {
         match (opcode, funct3) {
             // C0 opcode (quarters 0)
             (0b00, 0b000) => {
-                // c.addi4spn with nzuimm == 0 is illegal, should be c.unimp
-                if nzuimm_ciw == 0 {
+                // The all-zero halfword is the defined illegal instruction;
+                // any other zero nzuimm is reserved.
+                if instruction == 0 {
                     Some(self.decode_c_unimp())
+                } else if nzuimm_ciw == 0 {
+                    Some(Err(reserved_encoding("c.addi4spn with nzuimm = 0")))
                 } else {
                     Some(self.decode_c_addi4spn(rdp, nzuimm_ciw))
                 }
@@ -658,23 +806,39 @@ impl InstructionExtension for Rvc {
             }
 
             // C1 opcode (quarters 1)
-            (0b01, 0b000) => Some(self.decode_c_addi(rd_full, imm_ci)),
+            // c.nop is rd = x0 with a zero immediate; either one alone is a HINT.
+            (0b01, 0b000) => Some(self.decode_c_addi(rd_full, imm_ci).map(|decoded| {
+                match (rd_full == 0, imm_ci == 0) {
+                    (true, false) | (false, true) => hint(decoded),
+                    (true, true) => nop(decoded),
+                    (false, false) => decoded,
+                }
+            })),
             (0b01, 0b001) if xlen == Xlen::X32 => Some(self.decode_c_jal(imm_cj)),
             (0b01, 0b001) if rd_full != 0 => Some(self.decode_c_addiw(rd_full, imm_ci)),
-            (0b01, 0b001) => Some(self.decode_c_unknown(instruction)),
+            (0b01, 0b001) => Some(Err(reserved_encoding("c.addiw with rd = x0"))),
+            (0b01, 0b010) if rd_full == 0 => Some(self.decode_c_li(rd_full, imm_ci).map(hint)),
             (0b01, 0b010) => Some(self.decode_c_li(rd_full, imm_ci)),
             (0b01, 0b011) => {
+                // rd == sp selects c.addi16sp; every other rd is c.lui. Both
+                // reserve a zero immediate.
                 if rd_full == 2 {
                     let imm_val = (((instruction >> 12) & 0x1) << 9)
                         | (((instruction >> 3) & 0x3) << 7)
                         | (((instruction >> 5) & 0x1) << 6)
                         | (((instruction >> 2) & 0x1) << 5)
                         | (((instruction >> 6) & 0x1) << 4);
-                    Some(self.decode_c_addi16sp(rd_full, imm_val))
-                } else if rd_full != 0 && imm_ci != 0 {
-                    Some(self.decode_c_lui(rd_full, imm_ci))
+                    if imm_val == 0 {
+                        Some(Err(reserved_encoding("c.addi16sp with nzimm = 0")))
+                    } else {
+                        Some(self.decode_c_addi16sp(rd_full, imm_val))
+                    }
+                } else if imm_ci == 0 {
+                    Some(Err(reserved_encoding("c.lui with nzimm = 0")))
+                } else if rd_full == 0 {
+                    Some(self.decode_c_lui(rd_full, imm_ci).map(hint))
                 } else {
-                    Some(self.decode_c_unknown(instruction))
+                    Some(self.decode_c_lui(rd_full, imm_ci))
                 }
             }
             (0b01, 0b100) => {
@@ -687,7 +851,7 @@ impl InstructionExtension for Rvc {
             (0b01, 0b111) => Some(self.decode_c_bnez(rs1p, imm_cb)),
 
             // C2 opcode (quarters 2)
-            (0b10, 0b000) => Some(self.decode_c_slli(rd_full, imm_ci)),
+            (0b10, 0b000) => Some(self.decode_c_slli(rd_full, imm_ci, xlen)),
             (0b10, 0b001) => {
                 if extensions.standard.contains(Standard::D) {
                     Some(self.decode_c_fldsp(rd_full, uimm_fldsp))
@@ -695,9 +859,12 @@ impl InstructionExtension for Rvc {
                     Some(self.decode_c_unknown(instruction))
                 }
             }
+            (0b10, 0b010) if rd_full == 0 => Some(Err(reserved_encoding("c.lwsp with rd = x0"))),
             (0b10, 0b010) => Some(self.decode_c_lwsp(rd_full, uimm_clsp)),
             (0b10, 0b011) => {
-                if xlen == Xlen::X64 {
+                if xlen == Xlen::X64 && rd_full == 0 {
+                    Some(Err(reserved_encoding("c.ldsp with rd = x0")))
+                } else if xlen == Xlen::X64 {
                     Some(self.decode_c_ldsp(rd_full, uimm_cldsp))
                 } else if extensions.standard.contains(Standard::F) {
                     Some(self.decode_c_flwsp(rd_full, uimm_clsp))
@@ -706,14 +873,28 @@ impl InstructionExtension for Rvc {
                 }
             }
             (0b10, 0b100) => {
-                let bit12 = ((instruction >> 12) & 0x1) as u8;
+                // Bit 12 splits the group, then rd/rs1 (bits 11:7) and rs2
+                // (bits 6:2) pick the instruction:
+                //
+                //   bit 12  rd/rs1  rs2    instruction
+                //   0       0       0      reserved
+                //   0       !=0     0      c.jr rs1
+                //   0       0       !=0    HINT (c.mv)
+                //   0       !=0     !=0    c.mv rd, rs2
+                //   1       0       0      c.ebreak
+                //   1       !=0     0      c.jalr rs1
+                //   1       0       !=0    HINT (c.add)
+                //   1       !=0     !=0    c.add rd, rs2
+                let bit12 = (instruction >> 12) & 0x1 == 1;
                 match (bit12, rd_full, rs2_full) {
-                    (0, rd, 0) if rd != 0 => Some(self.decode_c_jr(rd)),
-                    (0, rd, rs2) if rd != 0 && rs2 != 0 => Some(self.decode_c_mv(rd, rs2)),
-                    (1, 0, 0) => Some(self.decode_c_ebreak()),
-                    (1, rd, 0) if rd != 0 => Some(self.decode_c_jalr(rd)),
-                    (1, rd, rs2) if rd != 0 && rs2 != 0 => Some(self.decode_c_add(rd, rs2)),
-                    _ => Some(self.decode_c_unknown(instruction)),
+                    (false, 0, 0) => Some(Err(reserved_encoding("c.jr with rs1 = x0"))),
+                    (false, rs1, 0) => Some(self.decode_c_jr(rs1)),
+                    (false, 0, rs2) => Some(self.decode_c_mv(0, rs2).map(hint)),
+                    (false, rd, rs2) => Some(self.decode_c_mv(rd, rs2)),
+                    (true, 0, 0) => Some(self.decode_c_ebreak()),
+                    (true, rs1, 0) => Some(self.decode_c_jalr(rs1)),
+                    (true, 0, rs2) => Some(self.decode_c_add(0, rs2).map(hint)),
+                    (true, rd, rs2) => Some(self.decode_c_add(rd, rs2)),
                 }
             }
             (0b10, 0b101) => {
@@ -744,3 +925,173 @@ impl Default for Rvc {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::RiscVHandler;
+    use robustone_core::{ArchitectureHandler, EncodingClass};
+
+    /// Capstone-style `(mnemonic, operands)` of a compressed instruction.
+    fn render(arch: &str, halfword: u16) -> Option<(String, String)> {
+        let handler = if arch == "riscv32" {
+            RiscVHandler::rv32()
+        } else {
+            RiscVHandler::rv64()
+        };
+        handler
+            .disassemble(&halfword.to_le_bytes(), arch, 0)
+            .ok()
+            .map(|(instruction, _)| (instruction.mnemonic, instruction.operands))
+    }
+
+    /// Encoding class of a compressed instruction, decoded or not.
+    fn class(arch: &str, halfword: u16) -> Option<EncodingClass> {
+        let handler = if arch == "riscv32" {
+            RiscVHandler::rv32()
+        } else {
+            RiscVHandler::rv64()
+        };
+        match handler.decode_instruction(&halfword.to_le_bytes(), arch, 0) {
+            Ok((decoded, _)) => Some(decoded.encoding_class()),
+            Err(error) => error.encoding_class(),
+        }
+    }
+
+    fn expect(arch: &str, halfword: u16, mnemonic: &str, operands: &str) {
+        assert_eq!(
+            render(arch, halfword),
+            Some((mnemonic.to_string(), operands.to_string())),
+            "{arch} 0x{halfword:04x}"
+        );
+    }
+
+    #[test]
+    fn test_quadrant1_funct3_011_splits_c_lui_and_c_addi16sp_on_rd() {
+        for arch in ["riscv32", "riscv64"] {
+            expect(arch, 0x6505, "lui", "a0, 1");
+            expect(arch, 0x6541, "lui", "a0, 0x10");
+            expect(arch, 0x757d, "lui", "a0, 0xfffff");
+            expect(arch, 0x7501, "lui", "a0, 0xfffe0");
+            expect(arch, 0x71fd, "lui", "gp, 0xfffff");
+            expect(arch, 0x7139, "c.addi16sp", "sp, -0x40");
+            expect(arch, 0x6105, "c.addi16sp", "sp, 0x20");
+            expect(arch, 0x6141, "c.addi16sp", "sp, 0x10");
+            expect(arch, 0x7171, "c.addi16sp", "sp, -0xb0");
+            expect(arch, 0x617d, "c.addi16sp", "sp, 0x1f0");
+        }
+        // A zero immediate is reserved for both.
+        assert_eq!(render("riscv32", 0x6101), None);
+        assert_eq!(render("riscv32", 0x6501), None);
+    }
+
+    #[test]
+    fn test_quadrant2_funct3_100_selects_on_bit_12() {
+        for arch in ["riscv32", "riscv64"] {
+            expect(arch, 0x8082, "jr", "ra");
+            expect(arch, 0x8502, "jr", "a0");
+            expect(arch, 0x852e, "mv", "a0, a1");
+            expect(arch, 0x9002, "ebreak", "");
+            expect(arch, 0x9502, "jalr", "a0");
+            expect(arch, 0x9782, "jalr", "a5");
+            expect(arch, 0x952e, "add", "a0, a0, a1");
+            expect(arch, 0x97aa, "add", "a5, a5, a0");
+        }
+        // rd/rs1 = 0 with rs2 = 0 is reserved; with rs2 != 0 it is a HINT.
+        assert_eq!(render("riscv32", 0x8002), None);
+        expect("riscv32", 0x802e, "mv", "zero, a1");
+        expect("riscv32", 0x902e, "add", "zero, zero, a1");
+    }
+
+    #[test]
+    fn test_c_addi4spn_immediates_match_objdump() {
+        for arch in ["riscv32", "riscv64"] {
+            expect(arch, 0x0048, "c.addi4spn", "a0, sp, 4");
+            expect(arch, 0x0028, "c.addi4spn", "a0, sp, 8");
+            expect(arch, 0x0034, "c.addi4spn", "a3, sp, 8");
+            expect(arch, 0x0808, "c.addi4spn", "a0, sp, 0x10");
+            expect(arch, 0x1000, "c.addi4spn", "s0, sp, 0x20");
+            expect(arch, 0x0080, "c.addi4spn", "s0, sp, 0x40");
+            expect(arch, 0x0100, "c.addi4spn", "s0, sp, 0x80");
+            expect(arch, 0x0200, "c.addi4spn", "s0, sp, 0x100");
+            expect(arch, 0x0400, "c.addi4spn", "s0, sp, 0x200");
+            expect(arch, 0x1fe0, "c.addi4spn", "s0, sp, 0x3fc");
+        }
+    }
+
+    #[test]
+    fn test_quadrant1_cb_shifts_and_andi_carry_their_immediates() {
+        for arch in ["riscv32", "riscv64"] {
+            expect(arch, 0x8105, "c.srli", "a0, 1");
+            expect(arch, 0x817d, "c.srli", "a0, 0x1f");
+            expect(arch, 0x8505, "c.srai", "a0, 1");
+            expect(arch, 0x851d, "c.srai", "a0, 7");
+            expect(arch, 0x898d, "c.andi", "a1, 3");
+            expect(arch, 0x997d, "c.andi", "a0, -1");
+            expect(arch, 0x9901, "c.andi", "a0, -0x20");
+        }
+        // shamt[5] = inst[12] is zero-extended on RV64 and reserved on RV32.
+        expect("riscv64", 0x9101, "c.srli", "a0, 0x20");
+        expect("riscv64", 0x917d, "c.srli", "a0, 0x3f");
+        expect("riscv64", 0x957d, "c.srai", "a0, 0x3f");
+        assert_eq!(render("riscv32", 0x9101), None);
+        assert_eq!(render("riscv32", 0x957d), None);
+    }
+
+    #[test]
+    fn test_c_slli_zero_extends_shamt_and_flags_hints() {
+        let is_hint =
+            |arch: &str, halfword: u16| class(arch, halfword) == Some(EncodingClass::Hint);
+
+        for arch in ["riscv32", "riscv64"] {
+            expect(arch, 0x0506, "c.slli", "a0, 1");
+            expect(arch, 0x057e, "c.slli", "a0, 0x1f");
+            assert!(!is_hint(arch, 0x0506));
+
+            // shamt = 0 is c.slli64 (RV128) and a HINT here; so is rd = x0.
+            expect(arch, 0x0502, "c.slli64", "a0");
+            assert!(is_hint(arch, 0x0502));
+            expect(arch, 0x0006, "c.slli", "zero, 1");
+            assert!(is_hint(arch, 0x0006));
+        }
+        expect("riscv64", 0x1502, "c.slli", "a0, 0x20");
+        expect("riscv64", 0x157e, "c.slli", "a0, 0x3f");
+        assert_eq!(render("riscv32", 0x1502), None);
+    }
+
+    #[test]
+    fn test_reserved_and_hint_encodings_are_classified() {
+        use EncodingClass::{Defined, Hint, Reserved};
+
+        for arch in ["riscv32", "riscv64"] {
+            for (halfword, expected) in [
+                (0x0000, Defined),  // c.unimp
+                (0x0008, Reserved), // c.addi4spn, nzuimm = 0
+                (0x0001, Defined),  // c.nop
+                (0x0005, Hint),     // c.addi x0, 1
+                (0x0501, Hint),     // c.addi a0, 0
+                (0x4005, Hint),     // c.li x0, 1
+                (0x6005, Hint),     // c.lui x0, 1
+                (0x6101, Reserved), // c.addi16sp, nzimm = 0
+                (0x6501, Reserved), // c.lui, nzimm = 0
+                (0x8101, Hint),     // c.srli64
+                (0x8501, Hint),     // c.srai64
+                (0x0502, Hint),     // c.slli64
+                (0x4002, Reserved), // c.lwsp x0
+                (0x8002, Reserved), // c.jr x0
+                (0x802e, Hint),     // c.mv x0, a1
+                (0x902e, Hint),     // c.add x0, a1
+            ] {
+                assert_eq!(
+                    class(arch, halfword),
+                    Some(expected),
+                    "{arch} 0x{halfword:04x}"
+                );
+            }
+        }
+        assert_eq!(class("riscv64", 0x2001), Some(Reserved)); // c.addiw x0
+        assert_eq!(class("riscv32", 0x2001), Some(Defined)); // c.jal
+        assert_eq!(class("riscv64", 0x6002), Some(Reserved)); // c.ldsp x0
+        assert_eq!(class("riscv64", 0x9d41), Some(Reserved)); // reserved C.ALU
+        expect("riscv64", 0x8101, "c.srli64", "a0");
+    }
+}
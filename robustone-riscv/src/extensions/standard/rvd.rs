@@ -231,6 +231,59 @@ impl InstructionExtension for Rvd {
         "D"
     }
 
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &[
+            "fld",
+            "fsd",
+            "fmadd.d",
+            "fmsub.d",
+            "fnmsub.d",
+            "fnmadd.d",
+            "fadd.d",
+            "fsub.d",
+            "fmul.d",
+            "fdiv.d",
+            "fsqrt.d",
+            "fsgnj.d",
+            "fsgnjn.d",
+            "fsgnjx.d",
+            "fmin.d",
+            "fmax.d",
+            "fcvt.s.d",
+            "fcvt.d.s",
+            "feq.d",
+            "flt.d",
+            "fle.d",
+            "fclass.d",
+            "fcvt.w.d",
+            "fcvt.wu.d",
+            "fcvt.d.w",
+            "fcvt.d.wu",
+            "fcvt.l.d",
+            "fcvt.lu.d",
+            "fmv.x.d",
+            "fcvt.d.l",
+            "fcvt.d.lu",
+            "fmv.d.x",
+        ]
+    }
+
+    fn decodable_mnemonics(&self, xlen: Xlen, _extensions: &Extensions) -> Vec<&'static str> {
+        const RV64_ONLY: &[&str] = &[
+            "fcvt.l.d",
+            "fcvt.lu.d",
+            "fmv.x.d",
+            "fcvt.d.l",
+            "fcvt.d.lu",
+            "fmv.d.x",
+        ];
+        self.mnemonics()
+            .iter()
+            .copied()
+            .filter(|mnemonic| xlen == Xlen::X64 || !RV64_ONLY.contains(mnemonic))
+            .collect()
+    }
+
     fn is_enabled(&self, extensions: &Extensions) -> bool {
         // D extension bit (bit 4)
         extensions.standard.contains(Standard::D)
@@ -251,6 +304,7 @@ impl InstructionExtension for Rvd {
         _imm_u: i64,
         _imm_j: i64,
         xlen: Xlen,
+        _extensions: &Extensions,
     ) -> Option<Result<DecodedInstruction, DisasmError>> {
         match opcode {
             Self::OPCODE_LOAD_FP if funct3 == Self::FUNCT3_LOAD_FLD => {
@@ -231,6 +231,50 @@ impl InstructionExtension for Rvf {
         "F"
     }
 
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &[
+            "flw",
+            "fsw",
+            "fmadd.s",
+            "fmsub.s",
+            "fnmsub.s",
+            "fnmadd.s",
+            "fadd.s",
+            "fsub.s",
+            "fmul.s",
+            "fdiv.s",
+            "fsqrt.s",
+            "fsgnj.s",
+            "fsgnjn.s",
+            "fsgnjx.s",
+            "fmin.s",
+            "fmax.s",
+            "fcvt.w.s",
+            "fcvt.wu.s",
+            "fmv.x.w",
+            "feq.s",
+            "flt.s",
+            "fle.s",
+            "fclass.s",
+            "fcvt.s.w",
+            "fcvt.s.wu",
+            "fmv.w.x",
+            "fcvt.l.s",
+            "fcvt.lu.s",
+            "fcvt.s.l",
+            "fcvt.s.lu",
+        ]
+    }
+
+    fn decodable_mnemonics(&self, xlen: Xlen, _extensions: &Extensions) -> Vec<&'static str> {
+        const RV64_ONLY: &[&str] = &["fcvt.l.s", "fcvt.lu.s", "fcvt.s.l", "fcvt.s.lu"];
+        self.mnemonics()
+            .iter()
+            .copied()
+            .filter(|mnemonic| xlen == Xlen::X64 || !RV64_ONLY.contains(mnemonic))
+            .collect()
+    }
+
     fn is_enabled(&self, extensions: &Extensions) -> bool {
         // F extension bit (bit 3)
         extensions.standard.contains(Standard::F)
@@ -251,6 +295,7 @@ impl InstructionExtension for Rvf {
         _imm_u: i64,
         _imm_j: i64,
         xlen: Xlen,
+        _extensions: &Extensions,
     ) -> Option<Result<DecodedInstruction, DisasmError>> {
         match opcode {
             Self::OPCODE_LOAD_FP if funct3 == Self::FUNCT3_LOAD_FLW => {
@@ -8,7 +8,7 @@ use super::Standard;
 use crate::ir::DecodedInstruction;
 use crate::riscv::decoder::Xlen;
 use crate::riscv::extensions::{
-    Extensions, InstructionExtension, invalid_encoding, unsupported_mode,
+    Extensions, InstructionExtension, hint, invalid_encoding, nop, unsupported_mode,
 };
 use crate::riscv::shared::{
     InstructionFormatter, OperandFactory, encoding::ShamtExtractor,
@@ -368,6 +368,12 @@ impl Rvi {
         imm_i: i64,
         xlen: Xlen,
     ) -> Result<DecodedInstruction, DisasmError> {
+        // On RV64 bit 25 is the top bit of the 6-bit shamt, so only the upper
+        // six bits of funct7 select the shift kind.
+        let shift_funct = match xlen {
+            Xlen::X64 => funct7 & !1,
+            Xlen::X32 => funct7,
+        };
         match funct3 {
             Self::FUNCT3_OP_ADD_SUB => self.decode_i_type("addi", rd, rs1, imm_i),
             Self::FUNCT3_OP_SLT => self.decode_i_type("slti", rd, rs1, imm_i),
@@ -376,14 +382,14 @@ impl Rvi {
             Self::FUNCT3_OP_OR => self.decode_i_type("ori", rd, rs1, imm_i),
             Self::FUNCT3_OP_AND => self.decode_i_type("andi", rd, rs1, imm_i),
             Self::FUNCT3_OP_SLL => {
-                if funct7 == 0 {
+                if shift_funct == 0 {
                     let shamt = ShamtExtractor::extract_shamt(imm_i, xlen);
                     self.decode_i_type("slli", rd, rs1, shamt)
                 } else {
                     Err(invalid_encoding("invalid slli funct7"))
                 }
             }
-            Self::FUNCT3_OP_SRL_SRA => match funct7 {
+            Self::FUNCT3_OP_SRL_SRA => match shift_funct {
                 Self::FUNCT7_OP_SRL => {
                     let shamt = ShamtExtractor::extract_shamt(imm_i, xlen);
                     self.decode_i_type("srli", rd, rs1, shamt)
@@ -528,8 +534,22 @@ impl Rvi {
                 0x302 => Ok(DefaultInstructionFormatter::simple_instruction("mret")),
                 0x7b2 => Ok(DefaultInstructionFormatter::simple_instruction("dret")),
                 0x105 => Ok(DefaultInstructionFormatter::simple_instruction("wfi")),
-                _ if (funct12 >> 5) == 0x09 && rd == 0 => {
-                    // SFENCE.VMA: funct7=0x09 in bits 31:25, rs2 in bits 24:20.
+                // Svinval splits sfence.vma into a write fence, the
+                // invalidations, and a fence back to implicit accesses.
+                0x180 if rd == 0 && rs1 == 0 => Ok(
+                    DefaultInstructionFormatter::simple_instruction("sfence.w.inval"),
+                ),
+                0x181 if rd == 0 && rs1 == 0 => Ok(
+                    DefaultInstructionFormatter::simple_instruction("sfence.inval.ir"),
+                ),
+                _ if matches!(funct12 >> 5, 0x09 | 0x0B) && rd == 0 => {
+                    // SFENCE.VMA (funct7=0x09) or Svinval's SINVAL.VMA
+                    // (funct7=0x0B), rs2 in bits 24:20.
+                    let mnemonic = if funct12 >> 5 == 0x09 {
+                        "sfence.vma"
+                    } else {
+                        "sinval.vma"
+                    };
                     let rs2_vma = (funct12 & 0x1F) as u8;
                     let operands = if rs1 == 0 && rs2_vma == 0 {
                         vec![]
@@ -547,7 +567,7 @@ impl Rvi {
                         ]
                     };
                     Ok(self.formatter.create_decoded_instruction(
-                        "sfence.vma",
+                        mnemonic,
                         RiscVInstructionFormat::R,
                         4,
                         operands,
@@ -596,7 +616,7 @@ impl Rvi {
             vec![
                 self.operand_factory
                     .make_register_operand(rd, Access::write()),
-                self.operand_factory.make_immediate_operand(csr),
+                DefaultOperandFactory::csr(csr as u16, csr_access(mnemonic, rd, rs1 as i64)),
                 self.operand_factory
                     .make_register_operand(rs1, Access::read()),
             ],
@@ -624,24 +644,16 @@ impl Rvi {
             vec![
                 self.operand_factory
                     .make_register_operand(rd, Access::write()),
-                self.operand_factory.make_immediate_operand(csr),
+                DefaultOperandFactory::csr(csr as u16, csr_access(mnemonic, rd, zimm)),
                 self.operand_factory.make_immediate_operand(zimm),
             ],
         );
 
-        let capstone_alias = if rd == 0 {
-            match mnemonic {
-                "csrrwi" => Some("csrwi"),
-                "csrrsi" => Some("csrsi"),
-                "csrrci" => Some("csrci"),
-                _ => None,
-            }
-        } else {
-            None
-        };
+        let (capstone_alias, hidden_operands) =
+            csr_capstone_alias(mnemonic, rd, zimm as u8, csr as u16);
 
         if let Some(capstone_alias) = capstone_alias {
-            Ok(instruction.with_capstone_alias(capstone_alias, vec![0]))
+            Ok(instruction.with_capstone_alias(capstone_alias, hidden_operands))
         } else {
             Ok(instruction)
         }
@@ -658,23 +670,60 @@ fn prefetch_mnemonic(imm: i64) -> Option<&'static str> {
     }
 }
 
+/// How a Zicsr instruction accesses its CSR.
+///
+/// `csrrw[i]` with `rd = x0` does not read the CSR, and `csrrs[i]`/`csrrc[i]`
+/// with a zero source (`rs1 = x0` or `uimm = 0`) do not write it; neither has
+/// the side effects of the omitted access.
+fn csr_access(mnemonic: &str, rd: u8, source: i64) -> Access {
+    match mnemonic {
+        "csrrw" | "csrrwi" => Access {
+            read: rd != 0,
+            write: true,
+        },
+        _ => Access {
+            read: true,
+            write: source != 0,
+        },
+    }
+}
+
+/// Pseudo-instruction spelling of a Zicsr instruction, from the RISC-V
+/// assembly manual, and the operands (`rd`, `csr`, `rs1`/`uimm`) it hides.
+///
+/// `source` is `rs1` for the register forms and `uimm` for the immediate
+/// forms. Counter and floating-point CSR pseudos win over the generic
+/// `csrr`/`csrw` family, as in LLVM's alias priorities.
 fn csr_capstone_alias(
     mnemonic: &str,
     rd: u8,
-    rs1: u8,
+    source: u8,
     csr: u16,
 ) -> (Option<&'static str>, Vec<usize>) {
-    match (mnemonic, rd, rs1, csr) {
+    // `fscsr a0` when the old value is discarded, `fscsr a1, a0` otherwise.
+    let swap_operands = if rd == 0 { vec![0, 1] } else { vec![1] };
+    match (mnemonic, rd, source, csr) {
         ("csrrs", _, 0, 0xC00) => (Some("rdcycle"), vec![1, 2]),
         ("csrrs", _, 0, 0xC01) => (Some("rdtime"), vec![1, 2]),
         ("csrrs", _, 0, 0xC02) => (Some("rdinstret"), vec![1, 2]),
         ("csrrs", _, 0, 0xC80) => (Some("rdcycleh"), vec![1, 2]),
         ("csrrs", _, 0, 0xC81) => (Some("rdtimeh"), vec![1, 2]),
         ("csrrs", _, 0, 0xC82) => (Some("rdinstreth"), vec![1, 2]),
+        ("csrrs", _, 0, 0x001) => (Some("frflags"), vec![1, 2]),
+        ("csrrs", _, 0, 0x002) => (Some("frrm"), vec![1, 2]),
+        ("csrrs", _, 0, 0x003) => (Some("frcsr"), vec![1, 2]),
+        ("csrrw", _, _, 0x001) => (Some("fsflags"), swap_operands),
+        ("csrrw", _, _, 0x002) => (Some("fsrm"), swap_operands),
+        ("csrrw", _, _, 0x003) => (Some("fscsr"), swap_operands),
+        ("csrrwi", _, _, 0x001) => (Some("fsflagsi"), swap_operands),
+        ("csrrwi", _, _, 0x002) => (Some("fsrmi"), swap_operands),
         ("csrrs", _, 0, _) => (Some("csrr"), vec![2]),
         ("csrrw", 0, _, _) => (Some("csrw"), vec![0]),
         ("csrrs", 0, _, _) => (Some("csrs"), vec![0]),
         ("csrrc", 0, _, _) => (Some("csrc"), vec![0]),
+        ("csrrwi", 0, _, _) => (Some("csrwi"), vec![0]),
+        ("csrrsi", 0, _, _) => (Some("csrsi"), vec![0]),
+        ("csrrci", 0, _, _) => (Some("csrci"), vec![0]),
         _ => (None, Vec::new()),
     }
 }
@@ -684,6 +733,95 @@ impl InstructionExtension for Rvi {
         "I"
     }
 
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &[
+            "lui",
+            "auipc",
+            "jal",
+            "jalr",
+            "beq",
+            "bne",
+            "blt",
+            "bge",
+            "bltu",
+            "bgeu",
+            "lb",
+            "lh",
+            "lw",
+            "lbu",
+            "lhu",
+            "lwu",
+            "ld",
+            "sb",
+            "sh",
+            "sw",
+            "sd",
+            "addi",
+            "slti",
+            "sltiu",
+            "xori",
+            "ori",
+            "andi",
+            "slli",
+            "srli",
+            "srai",
+            "add",
+            "sub",
+            "sll",
+            "slt",
+            "sltu",
+            "xor",
+            "srl",
+            "sra",
+            "or",
+            "and",
+            "addiw",
+            "slliw",
+            "srliw",
+            "sraiw",
+            "addw",
+            "subw",
+            "sllw",
+            "srlw",
+            "sraw",
+            "fence",
+            "fence.i",
+            "ecall",
+            "ebreak",
+            "uret",
+            "sret",
+            "mret",
+            "dret",
+            "wfi",
+            "sfence.vma",
+            "sinval.vma",
+            "sfence.w.inval",
+            "sfence.inval.ir",
+            "csrrw",
+            "csrrs",
+            "csrrc",
+            "csrrwi",
+            "csrrsi",
+            "csrrci",
+            "prefetch.i",
+            "prefetch.r",
+            "prefetch.t",
+            "prefetch.w",
+        ]
+    }
+
+    fn decodable_mnemonics(&self, xlen: Xlen, _extensions: &Extensions) -> Vec<&'static str> {
+        const RV64_ONLY: &[&str] = &[
+            "lwu", "ld", "sd", "addiw", "slliw", "srliw", "sraiw", "addw", "subw", "sllw", "srlw",
+            "sraw",
+        ];
+        self.mnemonics()
+            .iter()
+            .copied()
+            .filter(|mnemonic| xlen == Xlen::X64 || !RV64_ONLY.contains(mnemonic))
+            .collect()
+    }
+
     fn is_enabled(&self, extensions: &Extensions) -> bool {
         // I extension is always enabled (bit 0)
         extensions.standard.contains(Standard::I)
@@ -704,33 +842,61 @@ impl InstructionExtension for Rvi {
         imm_u: i64,
         imm_j: i64,
         xlen: Xlen,
+        _extensions: &Extensions,
     ) -> Option<Result<DecodedInstruction, DisasmError>> {
-        match opcode {
-            Self::OPCODE_LUI => Some(self.decode_lui(rd, imm_u)),
-            Self::OPCODE_AUIPC => Some(self.decode_auipc(rd, imm_u)),
-            Self::OPCODE_JAL => Some(self.decode_jal(rd, imm_j, xlen)),
-            Self::OPCODE_JALR => Some(self.decode_jalr(rd, rs1, imm_i)),
-            Self::OPCODE_BRANCH => Some(self.decode_branch(funct3, rs1, rs2, imm_b, xlen)),
-            Self::OPCODE_LOAD => Some(self.decode_load(funct3, rd, rs1, imm_i, xlen)),
-            Self::OPCODE_STORE => Some(self.decode_store(funct3, rs2, rs1, imm_s, xlen)),
-            Self::OPCODE_MISC_MEM => Some(self.decode_misc_mem(funct3, imm_i)),
-            Self::OPCODE_OP_IMM => Some(self.decode_op_imm(funct3, funct7, rd, rs1, imm_i, xlen)),
-            Self::OPCODE_OP if funct7 == Self::FUNCT7_OP_MUL => None,
-            Self::OPCODE_OP => Some(self.decode_op(funct3, funct7, rd, rs1, rs2)),
+        // Computational instructions writing x0 are HINTs, apart from the
+        // canonical `nop` (`addi x0, x0, 0`) and the Zicbop prefetches.
+        let canonical_nop = rd == 0
+            && opcode == Self::OPCODE_OP_IMM
+            && funct3 == Self::FUNCT3_OP_ADD_SUB
+            && rs1 == 0
+            && imm_i == 0;
+        let hint_space = rd == 0
+            && matches!(
+                opcode,
+                Self::OPCODE_LUI
+                    | Self::OPCODE_AUIPC
+                    | Self::OPCODE_OP_IMM
+                    | Self::OPCODE_OP
+                    | Self::OPCODE_OP_IMM_32
+                    | Self::OPCODE_OP_32
+            )
+            && !canonical_nop;
+        let decoded = match opcode {
+            Self::OPCODE_LUI => self.decode_lui(rd, imm_u),
+            Self::OPCODE_AUIPC => self.decode_auipc(rd, imm_u),
+            Self::OPCODE_JAL => self.decode_jal(rd, imm_j, xlen),
+            Self::OPCODE_JALR => self.decode_jalr(rd, rs1, imm_i),
+            Self::OPCODE_BRANCH => self.decode_branch(funct3, rs1, rs2, imm_b, xlen),
+            Self::OPCODE_LOAD => self.decode_load(funct3, rd, rs1, imm_i, xlen),
+            Self::OPCODE_STORE => self.decode_store(funct3, rs2, rs1, imm_s, xlen),
+            Self::OPCODE_MISC_MEM => self.decode_misc_mem(funct3, imm_i),
+            Self::OPCODE_OP_IMM => self.decode_op_imm(funct3, funct7, rd, rs1, imm_i, xlen),
+            Self::OPCODE_OP if funct7 == Self::FUNCT7_OP_MUL => return None,
+            Self::OPCODE_OP => self.decode_op(funct3, funct7, rd, rs1, rs2),
             Self::OPCODE_OP_IMM_32 if xlen == Xlen::X64 => {
-                Some(self.decode_op_imm_32(funct3, funct7, rd, rs1, imm_i))
+                self.decode_op_imm_32(funct3, funct7, rd, rs1, imm_i)
             }
-            Self::OPCODE_OP_IMM_32 => Some(Err(unsupported_mode("OP-IMM-32 requires RV64"))),
-            Self::OPCODE_OP_32 if xlen != Xlen::X64 => {
-                Some(Err(unsupported_mode("OP-32 requires RV64")))
+            Self::OPCODE_OP_IMM_32 => Err(unsupported_mode("OP-IMM-32 requires RV64")),
+            Self::OPCODE_OP_32 if xlen != Xlen::X64 => Err(unsupported_mode("OP-32 requires RV64")),
+            Self::OPCODE_OP_32 if xlen == Xlen::X64 && funct7 == Self::FUNCT7_OP_MUL => {
+                return None;
             }
-            Self::OPCODE_OP_32 if xlen == Xlen::X64 && funct7 == Self::FUNCT7_OP_MUL => None,
             Self::OPCODE_OP_32 if xlen == Xlen::X64 => {
-                Some(self.decode_op_32(funct3, funct7, rd, rs1, rs2))
+                self.decode_op_32(funct3, funct7, rd, rs1, rs2)
             }
-            Self::OPCODE_SYSTEM => Some(self.decode_system(funct3, rd, rs1, imm_i, funct12)),
-            _ => None,
-        }
+            Self::OPCODE_SYSTEM => self.decode_system(funct3, rd, rs1, imm_i, funct12),
+            _ => return None,
+        };
+        Some(decoded.map(|instruction| {
+            if hint_space && !instruction.mnemonic.starts_with("prefetch.") {
+                hint(instruction)
+            } else if canonical_nop {
+                nop(instruction)
+            } else {
+                instruction
+            }
+        }))
     }
 
     fn try_decode_compressed(
@@ -809,6 +975,7 @@ mod tests {
             0,         // imm_u
             0,         // imm_j
             Xlen::X32,
+            &Extensions::rv32gc(),
         );
 
         assert!(result.is_some());
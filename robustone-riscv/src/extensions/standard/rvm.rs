@@ -111,6 +111,22 @@ impl InstructionExtension for Rvm {
         "M"
     }
 
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &[
+            "mul", "mulh", "mulhsu", "mulhu", "div", "divu", "rem", "remu", "mulw", "divw",
+            "divuw", "remw", "remuw",
+        ]
+    }
+
+    fn decodable_mnemonics(&self, xlen: Xlen, _extensions: &Extensions) -> Vec<&'static str> {
+        const RV64_ONLY: &[&str] = &["mulw", "divw", "divuw", "remw", "remuw"];
+        self.mnemonics()
+            .iter()
+            .copied()
+            .filter(|mnemonic| xlen == Xlen::X64 || !RV64_ONLY.contains(mnemonic))
+            .collect()
+    }
+
     fn is_enabled(&self, extensions: &Extensions) -> bool {
         // M extension bit (bit 1)
         extensions.standard.contains(Standard::M)
@@ -131,6 +147,7 @@ impl InstructionExtension for Rvm {
         _imm_u: i64,
         _imm_j: i64,
         xlen: Xlen,
+        _extensions: &Extensions,
     ) -> Option<Result<DecodedInstruction, DisasmError>> {
         // Only handle OP / OP-32 opcodes with M-extension funct7
         if opcode == Self::OPCODE_OP && funct7 == Self::FUNCT7_OP_MUL {
@@ -0,0 +1,166 @@
+//! RVV (Vector) Extension
+//!
+//! This module implements the configuration-setting instructions of the
+//! RISC-V vector extension (`vsetvli`, `vsetivli`, `vsetvl`). Their `vtype`
+//! immediate is decoded into a [`VType`] so the element width, register
+//! grouping, and tail/mask policy are available without parsing text.
+//! Vector arithmetic, loads, and stores are not decoded yet.
+
+use super::Standard;
+use crate::ir::DecodedInstruction;
+use crate::riscv::decoder::{Xlen, build_riscv_decoded_instruction};
+use crate::riscv::extensions::{Extensions, InstructionExtension, invalid_encoding};
+use crate::riscv::shared::{operands::convenience, registers::RegisterManager};
+use crate::riscv::types::*;
+use crate::types::error::DisasmError;
+
+/// RVV Vector Extension
+pub struct Rvv {
+    register_manager: RegisterManager,
+}
+
+impl Rvv {
+    /// Create a new RVV extension instance.
+    pub fn new() -> Self {
+        Self {
+            register_manager: RegisterManager::new(),
+        }
+    }
+
+    // OP-V opcode; funct3 0b111 (OPCFG) selects the vset* instructions.
+    const OPCODE_OP_V: u32 = 0b101_0111;
+    const FUNCT3_OPCFG: u8 = 0b111;
+
+    // funct7 of vsetvl, which also has bit 31 set like vsetivli.
+    const FUNCT7_VSETVL: u8 = 0b100_0000;
+
+    /// The `vtype` operand: the parsed settings, or the raw immediate when it
+    /// selects a reserved configuration.
+    fn vtype_operand(bits: u32) -> RiscVOperand {
+        match VType::from_bits(bits) {
+            Some(vtype) => convenience::vector_type(vtype),
+            None => convenience::immediate(i64::from(bits)),
+        }
+    }
+
+    fn decode_config(
+        &self,
+        funct7: u8,
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+        funct12: u32,
+    ) -> Result<DecodedInstruction, DisasmError> {
+        let _ = &self.register_manager;
+        if funct12 >> 11 == 0 {
+            Ok(build_riscv_decoded_instruction(
+                "vsetvli",
+                RiscVInstructionFormat::I,
+                4,
+                vec![
+                    convenience::register(rd, Access::write()),
+                    convenience::register(rs1, Access::read()),
+                    Self::vtype_operand(funct12 & 0x7ff),
+                ],
+            ))
+        } else if funct12 >> 10 == 0b11 {
+            Ok(build_riscv_decoded_instruction(
+                "vsetivli",
+                RiscVInstructionFormat::I,
+                4,
+                vec![
+                    convenience::register(rd, Access::write()),
+                    convenience::immediate(i64::from(rs1)),
+                    Self::vtype_operand(funct12 & 0x3ff),
+                ],
+            ))
+        } else if funct7 == Self::FUNCT7_VSETVL {
+            Ok(build_riscv_decoded_instruction(
+                "vsetvl",
+                RiscVInstructionFormat::R,
+                4,
+                vec![
+                    convenience::register(rd, Access::write()),
+                    convenience::register(rs1, Access::read()),
+                    convenience::register(rs2, Access::read()),
+                ],
+            ))
+        } else {
+            Err(invalid_encoding("invalid vector configuration encoding"))
+        }
+    }
+}
+
+impl InstructionExtension for Rvv {
+    fn name(&self) -> &'static str {
+        "V"
+    }
+
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &["vsetvli", "vsetivli", "vsetvl"]
+    }
+
+    fn is_enabled(&self, extensions: &Extensions) -> bool {
+        extensions.standard.contains(Standard::V)
+    }
+
+    fn try_decode_standard(
+        &self,
+        opcode: u32,
+        funct3: u8,
+        funct7: u8,
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+        funct12: u32,
+        _imm_i: i64,
+        _imm_s: i64,
+        _imm_b: i64,
+        _imm_u: i64,
+        _imm_j: i64,
+        _xlen: Xlen,
+        _extensions: &Extensions,
+    ) -> Option<Result<DecodedInstruction, DisasmError>> {
+        if opcode == Self::OPCODE_OP_V && funct3 == Self::FUNCT3_OPCFG {
+            Some(self.decode_config(funct7, rd, rs1, rs2, funct12))
+        } else {
+            None
+        }
+    }
+
+    fn try_decode_compressed(
+        &self,
+        _instruction: u16,
+        _opcode: u8,
+        _funct3: u8,
+        _xlen: Xlen,
+        _extensions: &Extensions,
+        _rd_full: u8,
+        _rs1_full: u8,
+        _rs2_full: u8,
+        _rdp: u8,
+        _rs1p: u8,
+        _rs2p: u8,
+        _nzuimm_ciw: u16,
+        _uimm_cl: u16,
+        _uimm_cs: u16,
+        _imm_ci: i64,
+        _imm_cj: i64,
+        _imm_cb: i64,
+        _uimm_css: u16,
+        _uimm_clsp: u16,
+        _uimm_fldsp: u16,
+        _uimm_cld: u16,
+        _uimm_sdsp: u16,
+        _uimm_cldsp: u16,
+    ) -> Option<Result<DecodedInstruction, DisasmError>> {
+        // The vector extension has no compressed encodings
+        None
+    }
+}
+
+impl Default for Rvv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
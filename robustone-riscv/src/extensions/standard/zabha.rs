@@ -0,0 +1,135 @@
+//! Zabha (Byte and Halfword Atomic Memory Operations) Extension
+//!
+//! This module implements the byte (`.b`) and halfword (`.h`) forms of the
+//! A-extension read-modify-write AMOs. Their compare-and-swap forms are
+//! decoded by Zacas, which must be enabled as well.
+
+use super::Standard;
+use super::rva::{Rva, decode_amo, with_ordering};
+use crate::ir::DecodedInstruction;
+use crate::riscv::decoder::Xlen;
+use crate::riscv::extensions::{Extensions, InstructionExtension};
+use crate::types::error::DisasmError;
+
+/// Zabha Byte and Halfword Atomics Extension
+pub struct Zabha;
+
+impl Zabha {
+    /// Create a new Zabha extension instance.
+    pub fn new() -> Self {
+        Self
+    }
+
+    // Zabha shares the A-extension opcode.
+    const OPCODE_A: u32 = 0b010_1111;
+
+    // funct3 values select the access width.
+    const FUNCT3_B: u8 = 0b000;
+    const FUNCT3_H: u8 = 0b001;
+}
+
+impl InstructionExtension for Zabha {
+    fn name(&self) -> &'static str {
+        "Zabha"
+    }
+
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &[
+            "amoswap.b",
+            "amoadd.b",
+            "amoxor.b",
+            "amoand.b",
+            "amoor.b",
+            "amomin.b",
+            "amomax.b",
+            "amominu.b",
+            "amomaxu.b",
+            "amoswap.h",
+            "amoadd.h",
+            "amoxor.h",
+            "amoand.h",
+            "amoor.h",
+            "amomin.h",
+            "amomax.h",
+            "amominu.h",
+            "amomaxu.h",
+        ]
+    }
+
+    fn is_enabled(&self, extensions: &Extensions) -> bool {
+        extensions.standard.contains(Standard::ZABHA)
+    }
+
+    fn try_decode_standard(
+        &self,
+        opcode: u32,
+        funct3: u8,
+        funct7: u8,
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+        _funct12: u32,
+        _imm_i: i64,
+        _imm_s: i64,
+        _imm_b: i64,
+        _imm_u: i64,
+        _imm_j: i64,
+        _xlen: Xlen,
+        _extensions: &Extensions,
+    ) -> Option<Result<DecodedInstruction, DisasmError>> {
+        if opcode != Self::OPCODE_A {
+            return None;
+        }
+
+        let width = match funct3 {
+            Self::FUNCT3_B => "b",
+            Self::FUNCT3_H => "h",
+            _ => return None,
+        };
+        // There are no byte or halfword LR/SC; `amocas` is left to Zacas.
+        let operation = Rva::amo_operation((funct7 >> 2) & 0b11111)?;
+
+        let (ordering, suffix) = Rva::ordering(funct7);
+        let mnemonic = format!("{operation}.{width}{suffix}");
+        Some(Ok(with_ordering(
+            decode_amo(&mnemonic, rd, rs1, rs2),
+            ordering,
+        )))
+    }
+
+    fn try_decode_compressed(
+        &self,
+        _instruction: u16,
+        _opcode: u8,
+        _funct3: u8,
+        _xlen: Xlen,
+        _extensions: &Extensions,
+        _rd_full: u8,
+        _rs1_full: u8,
+        _rs2_full: u8,
+        _rdp: u8,
+        _rs1p: u8,
+        _rs2p: u8,
+        _nzuimm_ciw: u16,
+        _uimm_cl: u16,
+        _uimm_cs: u16,
+        _imm_ci: i64,
+        _imm_cj: i64,
+        _imm_cb: i64,
+        _uimm_css: u16,
+        _uimm_clsp: u16,
+        _uimm_fldsp: u16,
+        _uimm_cld: u16,
+        _uimm_sdsp: u16,
+        _uimm_cldsp: u16,
+    ) -> Option<Result<DecodedInstruction, DisasmError>> {
+        // Zabha has no compressed instructions
+        None
+    }
+}
+
+impl Default for Zabha {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,171 @@
+//! Zacas (Atomic Compare-and-Swap) Extension
+//!
+//! This module implements `amocas.w`, `amocas.d`, and `amocas.q`, plus the
+//! byte and halfword `amocas.b`/`amocas.h` that exist when Zabha is enabled
+//! as well. The doubleword form on RV32 and the quadword form on RV64 work
+//! on even/odd register pairs named by their even register.
+
+use super::Standard;
+use super::rva::{Rva, with_ordering};
+use crate::ir::DecodedInstruction;
+use crate::riscv::decoder::{Xlen, build_riscv_decoded_instruction};
+use crate::riscv::extensions::{
+    Extensions, InstructionExtension, reserved_encoding, unsupported_mode,
+};
+use crate::riscv::shared::{operands::convenience, registers::RegisterManager};
+use crate::riscv::types::*;
+use crate::types::error::DisasmError;
+
+/// Zacas Atomic Compare-and-Swap Extension
+pub struct Zacas {
+    register_manager: RegisterManager,
+}
+
+impl Zacas {
+    /// Create a new Zacas extension instance.
+    pub fn new() -> Self {
+        Self {
+            register_manager: RegisterManager::new(),
+        }
+    }
+
+    // Zacas shares the A-extension opcode.
+    const OPCODE_A: u32 = 0b010_1111;
+
+    // funct3 values select the access width.
+    const FUNCT3_B: u8 = 0b000;
+    const FUNCT3_H: u8 = 0b001;
+    const FUNCT3_W: u8 = 0b010;
+    const FUNCT3_D: u8 = 0b011;
+    const FUNCT3_Q: u8 = 0b100;
+
+    /// Decode `amocas`: `rd` holds the expected value and receives the old
+    /// memory value, `rs2` holds the value to store.
+    fn decode_amocas(
+        &self,
+        mnemonic: &str,
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+    ) -> Result<DecodedInstruction, DisasmError> {
+        let _ = &self.register_manager;
+        Ok(build_riscv_decoded_instruction(
+            mnemonic,
+            RiscVInstructionFormat::R,
+            4,
+            vec![
+                convenience::register(rd, Access::read_write()),
+                convenience::memory(rs1, 0),
+                convenience::register(rs2, Access::read()),
+            ],
+        ))
+    }
+}
+
+impl InstructionExtension for Zacas {
+    fn name(&self) -> &'static str {
+        "Zacas"
+    }
+
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &["amocas.w", "amocas.d", "amocas.q", "amocas.b", "amocas.h"]
+    }
+
+    fn decodable_mnemonics(&self, xlen: Xlen, extensions: &Extensions) -> Vec<&'static str> {
+        let zabha = extensions.standard.contains(Standard::ZABHA);
+        self.mnemonics()
+            .iter()
+            .copied()
+            .filter(|&mnemonic| match mnemonic {
+                "amocas.q" => xlen == Xlen::X64,
+                "amocas.b" | "amocas.h" => zabha,
+                _ => true,
+            })
+            .collect()
+    }
+
+    fn is_enabled(&self, extensions: &Extensions) -> bool {
+        extensions.standard.contains(Standard::ZACAS)
+    }
+
+    fn try_decode_standard(
+        &self,
+        opcode: u32,
+        funct3: u8,
+        funct7: u8,
+        rd: u8,
+        rs1: u8,
+        rs2: u8,
+        _funct12: u32,
+        _imm_i: i64,
+        _imm_s: i64,
+        _imm_b: i64,
+        _imm_u: i64,
+        _imm_j: i64,
+        xlen: Xlen,
+        extensions: &Extensions,
+    ) -> Option<Result<DecodedInstruction, DisasmError>> {
+        if opcode != Self::OPCODE_A || (funct7 >> 2) & 0b11111 != Rva::FUNCT5_AMOCAS {
+            return None;
+        }
+
+        let zabha = extensions.standard.contains(Standard::ZABHA);
+        let (width, pair) = match funct3 {
+            Self::FUNCT3_B if zabha => ("b", false),
+            Self::FUNCT3_H if zabha => ("h", false),
+            Self::FUNCT3_W => ("w", false),
+            Self::FUNCT3_D => ("d", xlen == Xlen::X32),
+            Self::FUNCT3_Q if xlen == Xlen::X64 => ("q", true),
+            Self::FUNCT3_Q => return Some(Err(unsupported_mode("amocas.q requires RV64"))),
+            _ => return None,
+        };
+        if pair && (rd & 1 != 0 || rs2 & 1 != 0) {
+            return Some(Err(reserved_encoding(format!(
+                "amocas.{width} needs even rd and rs2 register pairs"
+            ))));
+        }
+
+        let (ordering, suffix) = Rva::ordering(funct7);
+        let mnemonic = format!("amocas.{width}{suffix}");
+        Some(
+            self.decode_amocas(&mnemonic, rd, rs1, rs2)
+                .map(|instruction| with_ordering(instruction, ordering)),
+        )
+    }
+
+    fn try_decode_compressed(
+        &self,
+        _instruction: u16,
+        _opcode: u8,
+        _funct3: u8,
+        _xlen: Xlen,
+        _extensions: &Extensions,
+        _rd_full: u8,
+        _rs1_full: u8,
+        _rs2_full: u8,
+        _rdp: u8,
+        _rs1p: u8,
+        _rs2p: u8,
+        _nzuimm_ciw: u16,
+        _uimm_cl: u16,
+        _uimm_cs: u16,
+        _imm_ci: i64,
+        _imm_cj: i64,
+        _imm_cb: i64,
+        _uimm_css: u16,
+        _uimm_clsp: u16,
+        _uimm_fldsp: u16,
+        _uimm_cld: u16,
+        _uimm_sdsp: u16,
+        _uimm_cldsp: u16,
+    ) -> Option<Result<DecodedInstruction, DisasmError>> {
+        // Zacas has no compressed instructions
+        None
+    }
+}
+
+impl Default for Zacas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -61,6 +61,10 @@ impl InstructionExtension for CMov {
         "XTheadCondMov"
     }
 
+    fn mnemonics(&self) -> &'static [&'static str] {
+        &["th.mveqz", "th.mvnez"]
+    }
+
     fn is_enabled(&self, extensions: &Extensions) -> bool {
         // XTheadCondMov extension bit
         extensions.thead.contains(THead::CMOV)
@@ -81,6 +85,7 @@ impl InstructionExtension for CMov {
         _imm_u: i64,
         _imm_j: i64,
         _xlen: Xlen,
+        _extensions: &Extensions,
     ) -> Option<Result<DecodedInstruction, DisasmError>> {
         // Check if opcode matches XTheadCondMov custom-0 space
         if opcode != Self::OPCODE {
@@ -156,7 +161,22 @@ mod tests {
 
         // th.mveqz ra, sp, gp (x1, x2, x3)
         // opcode=0x0B, funct3=0x1, funct7=(0x08<<2)|0x00=0x20, rd=1, rs1=2, rs2=3
-        let result = ext.try_decode_standard(0x0B, 0x1, 0x20, 1, 2, 3, 0, 0, 0, 0, 0, 0, Xlen::X32);
+        let result = ext.try_decode_standard(
+            0x0B,
+            0x1,
+            0x20,
+            1,
+            2,
+            3,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            Xlen::X32,
+            &Extensions::rv32gc().thead(),
+        );
 
         assert!(result.is_some());
         let instr = result.unwrap().unwrap();
@@ -180,7 +200,22 @@ mod tests {
 
         // th.mvnez x1, x2, x3
         // opcode=0x0B, funct3=0x1, funct7=(0x08<<2)|0x01=0x21, rd=1, rs1=2, rs2=3
-        let result = ext.try_decode_standard(0x0B, 0x1, 0x21, 1, 2, 3, 0, 0, 0, 0, 0, 0, Xlen::X32);
+        let result = ext.try_decode_standard(
+            0x0B,
+            0x1,
+            0x21,
+            1,
+            2,
+            3,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            Xlen::X32,
+            &Extensions::rv32gc().thead(),
+        );
 
         assert!(result.is_some());
         let instr = result.unwrap().unwrap();
@@ -203,7 +238,22 @@ mod tests {
         let ext = CMov::new();
 
         // Different opcode should not match
-        let result = ext.try_decode_standard(0x33, 0x1, 0x20, 1, 2, 3, 0, 0, 0, 0, 0, 0, Xlen::X32);
+        let result = ext.try_decode_standard(
+            0x33,
+            0x1,
+            0x20,
+            1,
+            2,
+            3,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            Xlen::X32,
+            &Extensions::rv32gc().thead(),
+        );
 
         assert!(result.is_none());
     }
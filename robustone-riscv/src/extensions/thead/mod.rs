@@ -6,12 +6,15 @@
 
 use bitflags::bitflags;
 
+#[cfg(feature = "ext-thead")]
 pub mod condmov;
 
+#[cfg(feature = "ext-thead")]
 pub use condmov::CMov;
 
 bitflags! {
     /// Bitflags representing enabled T-Head custom extensions.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct THead: u32 {
         /// Conditional move extension (XTheadCondMov).
         const CMOV = 1;
@@ -9,9 +9,19 @@
 //! and provides both modern architecture-aware interfaces and legacy
 //! compatibility interfaces.
 
+pub mod aliases;
 pub mod arch;
+#[cfg(feature = "assemble")]
+pub mod assemble;
 pub mod decoder;
+#[cfg(feature = "emulate")]
+pub mod emulate;
+pub mod expand;
+pub mod explain;
 pub mod extensions;
+#[cfg(feature = "lift")]
+pub mod lift;
+pub mod metadata;
 pub mod printer;
 pub mod render;
 pub mod shared;
@@ -48,10 +58,13 @@ use arch::RiscVInstructionDetail;
 use decoder::{RiscVDecoder, Xlen};
 use extensions::Extensions;
 use robustone_core::{
+    canonical_architecture_name,
     common::ArchitectureProfile,
     ir::{DecodedInstruction, TextRenderProfile},
-    traits::ArchitectureHandler,
     traits::instruction::Detail,
+    traits::{
+        ArchitectureHandler, EncodingBreakdown, InstructionMetadata, MnemonicGroup, RegisterInfo,
+    },
     types::error::DisasmError,
 };
 
@@ -112,17 +125,45 @@ impl RiscVHandler {
         }
     }
 
-    fn decoder_for_arch(&self, arch_name: &str) -> Result<&RiscVDecoder, DisasmError> {
-        match (self.configured_xlen, arch_name) {
-            (Some(Xlen::X32), "riscv32") => Ok(&self.rv32_decoder),
-            (Some(Xlen::X64), "riscv64" | "riscv") => Ok(&self.rv64_decoder),
-            (Some(_), _) => Err(DisasmError::UnsupportedArchitecture(arch_name.to_string())),
-            (None, "riscv32") => Ok(&self.rv32_decoder),
-            (None, "riscv64" | "riscv") => Ok(&self.rv64_decoder),
-            _ => Err(DisasmError::UnsupportedArchitecture(arch_name.to_string())),
+    /// XLEN the handler was configured for, or `None` for a handler built
+    /// with [`Self::new`], which decodes both `riscv32` and `riscv64`.
+    pub fn xlen(&self) -> Option<Xlen> {
+        self.configured_xlen
+    }
+
+    /// Extensions of the configured decoder, implied ones included.
+    ///
+    /// A handler built with [`Self::new`] reports its RV64 decoder; both of
+    /// its decoders enable GC.
+    pub fn extensions(&self) -> &Extensions {
+        match self.configured_xlen {
+            Some(Xlen::X32) => self.rv32_decoder.extensions(),
+            _ => self.rv64_decoder.extensions(),
         }
     }
 
+    /// Names of the extensions [`Self::extensions`] enables, such as
+    /// `["I", "M", "A", "F", "D", "C"]` for GC.
+    pub fn iter_enabled_extensions(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.extensions().iter_enabled()
+    }
+
+    /// Resolve `arch_name` through the shared architecture registry, returning
+    /// the decoder for its XLEN and the canonical name to decode under.
+    fn decoder_for_arch(
+        &self,
+        arch_name: &str,
+    ) -> Result<(&RiscVDecoder, &'static str), DisasmError> {
+        let unsupported = || DisasmError::UnsupportedArchitecture(arch_name.to_string());
+        let canonical = canonical_architecture_name(arch_name).ok_or_else(unsupported)?;
+        let decoder = match (self.configured_xlen, canonical) {
+            (Some(Xlen::X32) | None, "riscv32") => &self.rv32_decoder,
+            (Some(Xlen::X64) | None, "riscv64") => &self.rv64_decoder,
+            _ => return Err(unsupported()),
+        };
+        Ok((decoder, canonical))
+    }
+
     pub fn from_profile(profile: &ArchitectureProfile) -> Result<Self, DisasmError> {
         let decoder = RiscVDecoder::from_profile(profile)?;
         match &profile.architecture {
@@ -143,6 +184,21 @@ impl RiscVHandler {
             )),
         }
     }
+
+    /// Lifts a decoded instruction into register-transfer statements.
+    ///
+    /// Compressed instructions are lifted through their 32-bit expansion but
+    /// keep their own address and size. Returns `None` for instructions the
+    /// lifter does not cover.
+    #[cfg(feature = "lift")]
+    pub fn lift(&self, decoded: &DecodedInstruction) -> Option<lift::Lifted> {
+        if decoded.size == 2 {
+            let canonical = self.expand_compressed(decoded)?;
+            lift::lift_as(&canonical, decoded.address, decoded.size)
+        } else {
+            lift::lift(decoded)
+        }
+    }
 }
 
 impl Default for RiscVHandler {
@@ -162,7 +218,7 @@ impl ArchitectureHandler for RiscVHandler {
         arch_name: &str,
         addr: u64,
     ) -> Result<(DecodedInstruction, usize), DisasmError> {
-        let decoder = self.decoder_for_arch(arch_name)?;
+        let (decoder, arch_name) = self.decoder_for_arch(arch_name)?;
         let decoded = decoder.decode(bytes, arch_name, addr)?;
         let size = decoded.size;
         Ok((decoded, size))
@@ -184,7 +240,7 @@ impl ArchitectureHandler for RiscVHandler {
         arch_name: &str,
         addr: u64,
     ) -> Result<(Instruction, usize), DisasmError> {
-        let decoder = self.decoder_for_arch(arch_name)?;
+        let (decoder, arch_name) = self.decoder_for_arch(arch_name)?;
         let ir = decoder.decode(bytes, arch_name, addr)?;
         let (mnemonic, operands) = crate::render::render_riscv_text_parts(
             &ir,
@@ -196,7 +252,9 @@ impl ArchitectureHandler for RiscVHandler {
         );
 
         let detail: Option<Box<dyn Detail>> = if self.detail {
-            let mut riscv_detail = RiscVInstructionDetail::new();
+            let mut riscv_detail = RiscVInstructionDetail::new()
+                .with_encoding(&ir)
+                .with_semantics(&ir);
             for register in ir
                 .registers_read
                 .iter()
@@ -241,12 +299,49 @@ impl ArchitectureHandler for RiscVHandler {
     }
 
     fn supports(&self, arch_name: &str) -> bool {
-        match self.configured_xlen {
-            Some(Xlen::X32) => matches!(arch_name, "riscv32"),
-            Some(Xlen::X64) => matches!(arch_name, "riscv64" | "riscv"),
-            None => matches!(arch_name, "riscv32" | "riscv64" | "riscv"),
+        self.decoder_for_arch(arch_name).is_ok()
+    }
+
+    fn supported_mnemonics(&self, arch_name: &str) -> Vec<MnemonicGroup> {
+        self.decoder_for_arch(arch_name)
+            .map(|(decoder, _)| decoder.supported_mnemonics())
+            .unwrap_or_default()
+    }
+
+    fn supported_mnemonics_with_profile(
+        &self,
+        profile: &ArchitectureProfile,
+    ) -> Vec<MnemonicGroup> {
+        Self::from_profile(profile)
+            .map(|handler| handler.supported_mnemonics(profile.mode_name))
+            .unwrap_or_default()
+    }
+
+    fn explain_encoding(&self, bytes: &[u8], arch_name: &str) -> Option<EncodingBreakdown> {
+        let (decoder, arch_name) = self.decoder_for_arch(arch_name).ok()?;
+        match decoder.decode(bytes, arch_name, 0) {
+            Ok(decoded) => explain::explain(&decoded),
+            Err(_) => explain::explain_unknown(bytes),
         }
     }
+
+    fn instruction_metadata(&self, decoded: &DecodedInstruction) -> Option<InstructionMetadata> {
+        metadata::instruction_metadata(decoded)
+    }
+
+    fn expand_compressed(&self, decoded: &DecodedInstruction) -> Option<DecodedInstruction> {
+        let word = expand::canonical_encoding(decoded)?;
+        let (decoder, _) = self.decoder_for_arch(&decoded.mode).ok()?;
+        decoder
+            .decode(&word.to_le_bytes(), &decoded.mode, decoded.address)
+            .ok()
+    }
+
+    fn registers(&self, arch_name: &str) -> Vec<RegisterInfo> {
+        self.decoder_for_arch(arch_name)
+            .map(|(decoder, _)| decoder.registers())
+            .unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -277,6 +372,63 @@ mod tests {
         assert!(matches!(error, DisasmError::UnsupportedArchitecture(_)));
     }
 
+    #[test]
+    fn test_handler_reports_its_configuration() {
+        let handler = RiscVHandler::with_extensions(Xlen::X32, Extensions::rv32gc().thead());
+        assert_eq!(handler.xlen(), Some(Xlen::X32));
+        assert_eq!(handler.extensions(), &Extensions::rv32gc().thead());
+        assert_eq!(
+            handler.iter_enabled_extensions().collect::<Vec<_>>(),
+            ["I", "M", "A", "F", "D", "C", "XTheadCondMov"]
+        );
+
+        let handler = RiscVHandler::new();
+        assert_eq!(handler.xlen(), None);
+        assert_eq!(handler.extensions(), &Extensions::rv64gc());
+    }
+
+    #[cfg(all(
+        feature = "ext-m",
+        feature = "ext-a",
+        feature = "ext-d",
+        feature = "ext-c",
+        feature = "ext-zacas",
+        feature = "ext-thead"
+    ))]
+    #[test]
+    fn test_supported_mnemonics_follow_xlen_and_enabled_extensions() {
+        let listed = |handler: &RiscVHandler, arch: &str| {
+            handler
+                .supported_mnemonics(arch)
+                .into_iter()
+                .flat_map(|group| group.mnemonics)
+                .collect::<Vec<_>>()
+        };
+
+        let handler = RiscVHandler::new();
+        let rv32 = listed(&handler, "riscv32");
+        assert!(rv32.contains(&"lw") && rv32.contains(&"c.jal"));
+        for rv64_only in ["ld", "addiw", "lwu", "mulw", "amoadd.d", "c.ld", "fmv.x.d"] {
+            assert!(!rv32.contains(&rv64_only), "riscv32 lists {rv64_only}");
+        }
+
+        let rv64 = listed(&handler, "riscv64");
+        assert!(rv64.contains(&"ld") && rv64.contains(&"c.ldsp"));
+        for rejected in [
+            "vsetvli", "th.mveqz", "th.mvnez", "c.jal", "c.flw", "amocas.w",
+        ] {
+            assert!(!rv64.contains(&rejected), "riscv64 lists {rejected}");
+        }
+
+        let mut extensions = Extensions::rv32gc().thead();
+        extensions.enable("Zacas").unwrap();
+        let handler = RiscVHandler::with_extensions(Xlen::X32, extensions);
+        let rv32 = listed(&handler, "riscv32");
+        assert!(rv32.contains(&"th.mveqz") && rv32.contains(&"amocas.d"));
+        assert!(!rv32.contains(&"amocas.q") && !rv32.contains(&"amocas.b"));
+        assert!(listed(&handler, "riscv64").is_empty());
+    }
+
     #[test]
     fn test_riscv_register_names() {
         assert_eq!(RiscVRegister::X0.name(), "zero");
@@ -311,17 +463,154 @@ mod tests {
         assert!(!none_access.read && !none_access.write);
     }
 
+    #[cfg(feature = "ext-a")]
+    #[test]
+    fn test_atomics_record_aq_rl_on_the_memory_operand() {
+        use robustone_core::ir::{MemoryOrdering, Operand};
+
+        let handler = RiscVHandler::rv64();
+        let decode = |bytes: &[u8]| handler.decode_instruction(bytes, "riscv64", 0).unwrap().0;
+        let ordering = |decoded: &DecodedInstruction| {
+            decoded.operands.iter().find_map(|operand| match operand {
+                Operand::Memory { ordering, .. } => Some(*ordering),
+                _ => None,
+            })
+        };
+
+        // amoadd.d a0, a1, (a2) with each combination of aq and rl.
+        for (last, mnemonic, acquire, release) in [
+            (0x00, "amoadd.d", false, false),
+            (0x04, "amoadd.d.aq", true, false),
+            (0x02, "amoadd.d.rl", false, true),
+            (0x06, "amoadd.d.aqrl", true, true),
+        ] {
+            let decoded = decode(&[0x2f, 0x35, 0xb6, last]);
+            assert_eq!(decoded.mnemonic, mnemonic);
+            assert_eq!(
+                ordering(&decoded),
+                Some(MemoryOrdering { acquire, release })
+            );
+        }
+
+        // lr.w.aq a0, (t0) and sc.w.rl t2, t1, (a0)
+        let lr = decode(&[0x2f, 0xa5, 0x02, 0x14]);
+        assert_eq!(lr.mnemonic, "lr.w.aq");
+        assert!(ordering(&lr).unwrap().acquire);
+        let sc = decode(&[0xaf, 0x23, 0x65, 0x1a]);
+        assert_eq!(sc.mnemonic, "sc.w.rl");
+        assert!(ordering(&sc).unwrap().release);
+
+        // Plain loads stay relaxed.
+        let ld = decode(&[0x03, 0x35, 0x81, 0x00]);
+        assert_eq!(ordering(&ld), Some(MemoryOrdering::RELAXED));
+    }
+
+    #[cfg(all(feature = "ext-zabha", feature = "ext-zacas"))]
+    #[test]
+    fn test_zacas_and_zabha_decode_only_when_enabled() {
+        use robustone_core::ir::RegisterId;
+
+        let amocas_w = [0x2f, 0x25, 0xb6, 0x28];
+        let amocas_b_aq = [0x2f, 0x05, 0xb6, 0x2c];
+        let amoadd_b = [0x2f, 0x05, 0xb6, 0x00];
+        let amocas_q_odd = [0x2f, 0xc5, 0xb5, 0x28];
+
+        let error = RiscVHandler::rv64()
+            .decode_instruction(&amocas_w, "riscv64", 0)
+            .expect_err("GC has no Zacas");
+        assert_eq!(error.stable_kind(), "unsupported_extension");
+        assert!(error.to_string().contains("requires Zacas"), "{error}");
+
+        let mut extensions = Extensions::rv64gc();
+        extensions.enable("Zacas").unwrap();
+        let handler = RiscVHandler::with_extensions(Xlen::X64, extensions);
+        let (decoded, _) = handler.decode_instruction(&amocas_w, "riscv64", 0).unwrap();
+        assert_eq!(decoded.mnemonic, "amocas.w");
+        assert_eq!(decoded.decoded_by, Some("Zacas"));
+        // rd holds the expected value and receives the old one.
+        assert!(decoded.registers_read.contains(&RegisterId::riscv(10)));
+        assert!(decoded.registers_written.contains(&RegisterId::riscv(10)));
+        let error = handler
+            .decode_instruction(&amocas_b_aq, "riscv64", 0)
+            .expect_err("amocas.b also needs Zabha");
+        assert!(error.to_string().contains("requires Zabha"), "{error}");
+        let error = handler
+            .decode_instruction(&amocas_q_odd, "riscv64", 0)
+            .expect_err("amocas.q needs an even rs2");
+        assert_eq!(error.stable_kind(), "reserved_encoding");
+
+        let error = match Extensions::from_enabled_extensions(&["I", "Zabha"]) {
+            Ok(_) => panic!("Zabha without A should be rejected"),
+            Err(error) => error,
+        };
+        assert!(error.to_string().contains("cannot enable Zabha without A"));
+
+        let mut extensions = Extensions::rv64gc();
+        extensions.enable("zabha").unwrap();
+        extensions.enable("zacas").unwrap();
+        assert_eq!(
+            extensions.iter_enabled().collect::<Vec<_>>(),
+            ["I", "M", "A", "F", "D", "C", "Zabha", "Zacas"]
+        );
+        let handler = RiscVHandler::with_extensions(Xlen::X64, extensions);
+        let (decoded, _) = handler.decode_instruction(&amoadd_b, "riscv64", 0).unwrap();
+        assert_eq!(decoded.mnemonic, "amoadd.b");
+        assert_eq!(decoded.decoded_by, Some("Zabha"));
+        let (decoded, _) = handler
+            .decode_instruction(&amocas_b_aq, "riscv64", 0)
+            .unwrap();
+        assert_eq!(decoded.mnemonic, "amocas.b.aq");
+    }
+
+    #[cfg(feature = "ext-a")]
     #[test]
     fn test_atomic_doubleword_is_not_tagged_as_floating_point() {
         let handler = RiscVHandler::rv64();
         let (decoded, _) = handler
-            .decode_instruction(&[0x2f, 0xb4, 0x02, 0x12], "riscv64", 0)
+            .decode_instruction(&[0x2f, 0xb4, 0x02, 0x10], "riscv64", 0)
             .expect("lr.d should decode");
 
         assert!(decoded.groups.iter().any(|group| group == "atomic"));
         assert!(!decoded.groups.iter().any(|group| group == "floating_point"));
     }
 
+    #[cfg(feature = "ext-c")]
+    #[test]
+    fn test_llvm_profile_matches_llvm_mc_aliases() {
+        let handler = RiscVHandler::rv64();
+        let render = |bytes: &[u8]| {
+            let (decoded, _) = handler
+                .decode_instruction(bytes, "riscv64", 0x1000)
+                .unwrap();
+            crate::render::render_riscv_text_parts(
+                &decoded,
+                TextRenderProfile::Llvm,
+                true,
+                true,
+                true,
+                false,
+            )
+        };
+        let text = |mnemonic: &str, operands: &str| (mnemonic.to_string(), operands.to_string());
+
+        // addi a0, a0, 0x64 in the Capstone flavor.
+        assert_eq!(
+            render(&[0x13, 0x05, 0x45, 0x06]),
+            text("addi", "a0, a0, 100")
+        );
+        assert_eq!(render(&[0x1b, 0x05, 0x05, 0x00]), text("sext.w", "a0, a0"));
+        assert_eq!(render(&[0x63, 0x04, 0x05, 0x00]), text("beqz", "a0, 8"));
+        assert_eq!(render(&[0x73, 0x25, 0x00, 0xc0]), text("rdcycle", "a0"));
+        assert_eq!(
+            render(&[0x73, 0x10, 0x05, 0x30]),
+            text("csrw", "mstatus, a0")
+        );
+        assert_eq!(render(&[0xe7, 0x00, 0x05, 0x00]), text("jalr", "a0"));
+        // Compressed instructions print as their 32-bit expansion.
+        assert_eq!(render(&[0x82, 0x80]), text("ret", ""));
+    }
+
+    #[cfg(feature = "ext-c")]
     #[test]
     fn test_disassemble_merges_implicit_register_writes_into_detail() {
         let handler = RiscVHandler::rv32();
@@ -335,4 +624,99 @@ mod tests {
         let detail = instruction.detail.expect("detail should be populated");
         assert_eq!(detail.registers_written(), &[1]);
     }
+
+    #[test]
+    fn test_typed_operands_keep_per_operand_access() {
+        let handler = RiscVHandler::rv64();
+        // addi a0, a0, 1: the same register is written and read.
+        let (instruction, _) = handler
+            .disassemble(&[0x13, 0x05, 0x15, 0x00], "riscv64", 0)
+            .unwrap();
+        let accesses: Vec<_> = instruction
+            .typed_operands()
+            .into_iter()
+            .map(|operand| operand.access)
+            .collect();
+        assert_eq!(accesses, [Access::write(), Access::read(), Access::read()]);
+
+        // sw a1, 4(a0): the memory operand is written.
+        let (store, _) = handler
+            .disassemble(&[0x23, 0x22, 0xb5, 0x00], "riscv64", 0)
+            .unwrap();
+        assert_eq!(store.typed_operands()[1].access, Access::write());
+    }
+
+    #[test]
+    fn test_system_level_csrs_and_svinval_print_symbolically() {
+        let handler = RiscVHandler::rv64();
+        let text = |bytes: [u8; 4]| {
+            let (instruction, _) = handler.disassemble(&bytes, "riscv64", 0).unwrap();
+            format!("{} {}", instruction.mnemonic, instruction.operands)
+        };
+
+        assert_eq!(text([0x73, 0x25, 0x00, 0x3a]), "csrr a0, pmpcfg0");
+        assert_eq!(text([0x73, 0x25, 0xf0, 0x3e]), "csrr a0, pmpaddr63");
+        assert_eq!(text([0x73, 0x25, 0xa0, 0x30]), "csrr a0, menvcfg");
+        assert_eq!(text([0x73, 0x25, 0xa0, 0x10]), "csrr a0, senvcfg");
+        assert_eq!(text([0x73, 0x25, 0xc0, 0x30]), "csrr a0, mstateen0");
+        assert_eq!(text([0x73, 0x25, 0x70, 0x74]), "csrr a0, mseccfg");
+
+        assert_eq!(text([0x73, 0x00, 0xb5, 0x16]), "sinval.vma a0, a1");
+        assert_eq!(text([0x73, 0x00, 0x00, 0x18]).trim_end(), "sfence.w.inval");
+        assert_eq!(text([0x73, 0x00, 0x10, 0x18]).trim_end(), "sfence.inval.ir");
+    }
+
+    #[test]
+    fn test_csr_operands_record_whether_the_csr_is_read_or_written() {
+        use robustone_core::ir::Operand;
+
+        let handler = RiscVHandler::rv64();
+        let csr_operand = |bytes: [u8; 4]| {
+            let (instruction, _) = handler.disassemble(&bytes, "riscv64", 0).unwrap();
+            instruction.typed_operands().swap_remove(1)
+        };
+
+        // csrrs t0, cycle, zero: a pure read.
+        let operand = csr_operand([0xf3, 0x22, 0x00, 0xc0]);
+        assert_eq!(operand.operand, Operand::SystemRegister { number: 0xc00 });
+        assert_eq!(operand.access, Access::read());
+        // csrrw zero, mscratch, a0: a pure write.
+        assert_eq!(
+            csr_operand([0x73, 0x10, 0x05, 0x34]).access,
+            Access::write()
+        );
+        // csrrs a0, mstatus, a1: read-modify-write.
+        assert_eq!(
+            csr_operand([0x73, 0xa5, 0x05, 0x30]).access,
+            Access::read_write()
+        );
+        // csrrci zero, mstatus, 0: clears nothing, so only reads.
+        assert_eq!(csr_operand([0x73, 0x70, 0x00, 0x30]).access, Access::read());
+    }
+
+    #[cfg(feature = "ext-c")]
+    #[test]
+    fn test_register_and_operand_count_queries() {
+        use robustone_core::ir::OperandType;
+
+        let mut handler = RiscVHandler::rv32();
+        // sw a1, 4(a0)
+        let (store, _) = handler
+            .disassemble(&[0x23, 0x22, 0xb5, 0x00], "riscv32", 0)
+            .unwrap();
+        assert!(store.reads_reg(10) && store.reads_reg(11));
+        assert!(!store.writes_reg(11));
+        assert_eq!(store.operand_count(OperandType::Memory), 1);
+        assert_eq!(store.operand_count(OperandType::Register), 1);
+        assert_eq!(store.operand_count(OperandType::Immediate), 0);
+        assert!(store.semantics().stores_memory);
+
+        // c.jal writes ra implicitly; without detail the IR answers.
+        handler.set_detail(false);
+        let (call, _) = handler.disassemble(&[0x01, 0x20], "riscv32", 0).unwrap();
+        assert!(call.detail.is_none());
+        assert!(call.writes_reg(1));
+        assert_eq!(call.semantics(), Default::default());
+        assert_eq!(call.registers_accessed(), (Vec::new(), vec![1]));
+    }
 }
@@ -0,0 +1,455 @@
+//! Register-transfer lifting (`lift` feature).
+//!
+//! Translates decoded RISC-V instructions into a small statement IR so that
+//! emulators and symbolic executors can consume instruction semantics without
+//! parsing assembly text:
+//!
+//! ```text
+//! addi a0, a0, 1   =>  SetReg { reg: 10, value: Binary(Add, Reg(10), Const(1)) }
+//! beq  a0, a1, 8   =>  Branch { condition: Binary(Eq, Reg(10), Reg(11)), target: pc + 8 }
+//! ```
+//!
+//! # Semantics
+//!
+//! - Statements run in order. Expressions read the machine state as left by
+//!   the preceding statements of the same instruction.
+//! - Values are XLEN bits wide ([`Lifted::xlen`]). Arithmetic wraps modulo
+//!   2^XLEN, shift amounts are taken modulo XLEN, and comparisons yield 0 or 1.
+//! - Division follows the RISC-V rules: dividing by zero yields all ones
+//!   (quotient) or the dividend (remainder), and signed overflow yields the
+//!   dividend (quotient) or 0 (remainder).
+//! - `Reg(0)` never appears (reads of `x0` become `Const(0)`), and writes to
+//!   `x0` are dropped.
+//! - [`Stmt::Jump`] and [`Stmt::Branch`] only select the next PC; without
+//!   them execution continues at `address + size`.
+//!
+//! RV32I/RV64I and the M extension are lifted, including their compressed
+//! forms. Other instructions (atomics, floating point, CSR access) are not
+//! lifted yet and return `None`.
+
+use robustone_core::ir::{DecodedInstruction, Operand};
+
+/// Binary operators over XLEN-bit values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinOp {
+    Add,
+    Sub,
+    And,
+    Or,
+    Xor,
+    /// Shift left.
+    Shl,
+    /// Logical shift right.
+    Shr,
+    /// Arithmetic shift right.
+    Sar,
+    Eq,
+    Ne,
+    /// Signed less-than.
+    Lt,
+    /// Unsigned less-than.
+    Ltu,
+    /// Signed greater-or-equal.
+    Ge,
+    /// Unsigned greater-or-equal.
+    Geu,
+    /// Low XLEN bits of the product.
+    Mul,
+    /// High XLEN bits of the signed × signed product.
+    MulH,
+    /// High XLEN bits of the signed × unsigned product.
+    MulHsu,
+    /// High XLEN bits of the unsigned × unsigned product.
+    MulHu,
+    Div,
+    DivU,
+    Rem,
+    RemU,
+}
+
+/// Value expressions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expr {
+    /// Constant, truncated to XLEN bits.
+    Const(u64),
+    /// Register value by IR register id.
+    Reg(u32),
+    /// Little-endian memory read of `width` bytes, extended to XLEN.
+    Load {
+        addr: Box<Expr>,
+        width: u8,
+        signed: bool,
+    },
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    /// Keep the low `from_bits` bits of `value` and extend them to XLEN.
+    Extend {
+        value: Box<Expr>,
+        from_bits: u32,
+        signed: bool,
+    },
+}
+
+/// Why an instruction transfers control to the execution environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrapKind {
+    /// `ecall`.
+    EnvironmentCall,
+    /// `ebreak`.
+    Breakpoint,
+}
+
+/// Register-transfer statements.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Stmt {
+    /// Write `value` to register `reg`.
+    SetReg { reg: u32, value: Expr },
+    /// Little-endian write of the low `width` bytes of `value`.
+    Store { addr: Expr, width: u8, value: Expr },
+    /// Continue at `target`.
+    Jump { target: Expr },
+    /// Continue at `target` when `condition` is non-zero.
+    Branch { condition: Expr, target: u64 },
+    /// Hand control to the execution environment.
+    Trap(TrapKind),
+}
+
+/// Lifted semantics of one instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Lifted {
+    pub address: u64,
+    /// Size of the original encoding; the fall-through PC is `address + size`.
+    pub size: usize,
+    /// Register width in bits (32 or 64).
+    pub xlen: u32,
+    pub statements: Vec<Stmt>,
+}
+
+/// Lifts a 32-bit instruction.
+///
+/// Compressed instructions are lifted through their canonical form; use
+/// [`RiscVHandler::lift`](crate::RiscVHandler::lift), which expands them first.
+pub fn lift(decoded: &DecodedInstruction) -> Option<Lifted> {
+    if decoded.size != 4 {
+        return None;
+    }
+    lift_as(decoded, decoded.address, decoded.size)
+}
+
+/// Lifts `canonical` as if it were encoded at `address` in `size` bytes.
+pub(crate) fn lift_as(canonical: &DecodedInstruction, address: u64, size: usize) -> Option<Lifted> {
+    let xlen = if canonical.mode == "riscv32" { 32 } else { 64 };
+    let next = address.wrapping_add(size as u64);
+    let operands = &canonical.operands;
+    let reg = |index: usize| match operands.get(index)? {
+        Operand::Register { register } => Some(register.id),
+        _ => None,
+    };
+    let read = |index: usize| reg(index).map(read_reg);
+    let imm = |index: usize| match operands.get(index)? {
+        Operand::Immediate { value } => Some(*value),
+        _ => None,
+    };
+    let address_of = |index: usize| match operands.get(index)? {
+        Operand::Memory {
+            base: Some(base),
+            displacement,
+            ..
+        } => Some(binary(
+            BinOp::Add,
+            read_reg(base.id),
+            Expr::Const(*displacement as u64),
+        )),
+        _ => None,
+    };
+    let relative = |offset: i64| address.wrapping_add_signed(offset);
+
+    let mnemonic = canonical.mnemonic.as_str();
+    let statements = match mnemonic {
+        "lui" => set(reg(0)?, Expr::Const(upper(imm(1)?))),
+        "auipc" => set(reg(0)?, Expr::Const(relative(upper(imm(1)?) as i64))),
+        "jal" => {
+            let mut statements = vec![Stmt::Jump {
+                target: Expr::Const(relative(imm(1)?)),
+            }];
+            statements.extend(set(reg(0)?, Expr::Const(next)));
+            statements
+        }
+        "jalr" => {
+            let target = binary(
+                BinOp::And,
+                binary(BinOp::Add, read(1)?, Expr::Const(imm(2)? as u64)),
+                Expr::Const(!1),
+            );
+            let mut statements = vec![Stmt::Jump { target }];
+            statements.extend(set(reg(0)?, Expr::Const(next)));
+            statements
+        }
+        "beq" | "bne" | "blt" | "bge" | "bltu" | "bgeu" => vec![Stmt::Branch {
+            condition: binary(branch_op(mnemonic), read(0)?, read(1)?),
+            target: relative(imm(2)?),
+        }],
+        "lb" | "lh" | "lw" | "ld" | "lbu" | "lhu" | "lwu" => {
+            let (width, signed) = load_kind(mnemonic);
+            set(
+                reg(0)?,
+                Expr::Load {
+                    addr: Box::new(address_of(1)?),
+                    width,
+                    signed,
+                },
+            )
+        }
+        "sb" | "sh" | "sw" | "sd" => vec![Stmt::Store {
+            addr: address_of(1)?,
+            width: store_width(mnemonic),
+            value: read(0)?,
+        }],
+        "addi" | "slti" | "sltiu" | "xori" | "ori" | "andi" | "slli" | "srli" | "srai" => set(
+            reg(0)?,
+            binary(
+                immediate_op(mnemonic),
+                read(1)?,
+                Expr::Const(imm(2)? as u64),
+            ),
+        ),
+        "addiw" => set(
+            reg(0)?,
+            sext32(binary(BinOp::Add, read(1)?, Expr::Const(imm(2)? as u64))),
+        ),
+        "slliw" | "srliw" | "sraiw" => set(
+            reg(0)?,
+            shift_word(mnemonic, read(1)?, Expr::Const(imm(2)? as u64)),
+        ),
+        "add" | "sub" | "sll" | "slt" | "sltu" | "xor" | "srl" | "sra" | "or" | "and" | "mul"
+        | "mulh" | "mulhsu" | "mulhu" | "div" | "divu" | "rem" | "remu" => {
+            set(reg(0)?, binary(register_op(mnemonic)?, read(1)?, read(2)?))
+        }
+        "addw" | "subw" | "mulw" => {
+            let op = register_op(mnemonic.trim_end_matches('w'))?;
+            set(reg(0)?, sext32(binary(op, read(1)?, read(2)?)))
+        }
+        "sllw" | "srlw" | "sraw" => set(
+            reg(0)?,
+            shift_word(
+                mnemonic,
+                read(1)?,
+                binary(BinOp::And, read(2)?, Expr::Const(31)),
+            ),
+        ),
+        "divw" | "remw" => {
+            let op = register_op(mnemonic.trim_end_matches('w'))?;
+            set(
+                reg(0)?,
+                sext32(binary(op, sext32(read(1)?), sext32(read(2)?))),
+            )
+        }
+        "divuw" | "remuw" => {
+            let op = register_op(mnemonic.trim_end_matches('w'))?;
+            set(
+                reg(0)?,
+                sext32(binary(op, zext32(read(1)?), zext32(read(2)?))),
+            )
+        }
+        // A single hart observes its own memory accesses in order.
+        "fence" | "fence.i" => Vec::new(),
+        "ecall" => vec![Stmt::Trap(TrapKind::EnvironmentCall)],
+        "ebreak" => vec![Stmt::Trap(TrapKind::Breakpoint)],
+        _ => return None,
+    };
+
+    Some(Lifted {
+        address,
+        size,
+        xlen,
+        statements,
+    })
+}
+
+fn read_reg(id: u32) -> Expr {
+    if id == 0 {
+        Expr::Const(0)
+    } else {
+        Expr::Reg(id)
+    }
+}
+
+fn set(reg: u32, value: Expr) -> Vec<Stmt> {
+    if reg == 0 {
+        Vec::new()
+    } else {
+        vec![Stmt::SetReg { reg, value }]
+    }
+}
+
+fn binary(op: BinOp, lhs: Expr, rhs: Expr) -> Expr {
+    Expr::Binary(op, Box::new(lhs), Box::new(rhs))
+}
+
+fn extend(value: Expr, signed: bool) -> Expr {
+    Expr::Extend {
+        value: Box::new(value),
+        from_bits: 32,
+        signed,
+    }
+}
+
+fn sext32(value: Expr) -> Expr {
+    extend(value, true)
+}
+
+fn zext32(value: Expr) -> Expr {
+    extend(value, false)
+}
+
+/// `lui`/`auipc` immediates hold bits `[31:12]`; the result is sign-extended.
+fn upper(imm: i64) -> u64 {
+    i64::from(((imm as u32) << 12) as i32) as u64
+}
+
+fn shift_word(mnemonic: &str, value: Expr, amount: Expr) -> Expr {
+    match mnemonic {
+        "slliw" | "sllw" => sext32(binary(BinOp::Shl, value, amount)),
+        "srliw" | "srlw" => sext32(binary(BinOp::Shr, zext32(value), amount)),
+        _ => sext32(binary(BinOp::Sar, sext32(value), amount)),
+    }
+}
+
+fn branch_op(mnemonic: &str) -> BinOp {
+    match mnemonic {
+        "beq" => BinOp::Eq,
+        "bne" => BinOp::Ne,
+        "blt" => BinOp::Lt,
+        "bge" => BinOp::Ge,
+        "bltu" => BinOp::Ltu,
+        _ => BinOp::Geu,
+    }
+}
+
+fn load_kind(mnemonic: &str) -> (u8, bool) {
+    match mnemonic {
+        "lb" => (1, true),
+        "lh" => (2, true),
+        "lw" => (4, true),
+        "ld" => (8, true),
+        "lbu" => (1, false),
+        "lhu" => (2, false),
+        _ => (4, false),
+    }
+}
+
+fn store_width(mnemonic: &str) -> u8 {
+    match mnemonic {
+        "sb" => 1,
+        "sh" => 2,
+        "sw" => 4,
+        _ => 8,
+    }
+}
+
+fn immediate_op(mnemonic: &str) -> BinOp {
+    match mnemonic {
+        "addi" => BinOp::Add,
+        "slti" => BinOp::Lt,
+        "sltiu" => BinOp::Ltu,
+        "xori" => BinOp::Xor,
+        "ori" => BinOp::Or,
+        "andi" => BinOp::And,
+        "slli" => BinOp::Shl,
+        "srli" => BinOp::Shr,
+        _ => BinOp::Sar,
+    }
+}
+
+fn register_op(mnemonic: &str) -> Option<BinOp> {
+    Some(match mnemonic {
+        "add" => BinOp::Add,
+        "sub" => BinOp::Sub,
+        "sll" => BinOp::Shl,
+        "slt" => BinOp::Lt,
+        "sltu" => BinOp::Ltu,
+        "xor" => BinOp::Xor,
+        "srl" => BinOp::Shr,
+        "sra" => BinOp::Sar,
+        "or" => BinOp::Or,
+        "and" => BinOp::And,
+        "mul" => BinOp::Mul,
+        "mulh" => BinOp::MulH,
+        "mulhsu" => BinOp::MulHsu,
+        "mulhu" => BinOp::MulHu,
+        "div" => BinOp::Div,
+        "divu" => BinOp::DivU,
+        "rem" => BinOp::Rem,
+        "remu" => BinOp::RemU,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RiscVHandler;
+    use robustone_core::ArchitectureHandler;
+
+    fn lifted(hex: &str, address: u64) -> Option<Lifted> {
+        let handler = RiscVHandler::new();
+        let bytes = hex::decode(hex).unwrap();
+        let (decoded, _) = handler
+            .decode_instruction(&bytes, "riscv64", address)
+            .unwrap();
+        handler.lift(&decoded)
+    }
+
+    #[test]
+    fn test_lift_arithmetic_memory_and_control_flow() {
+        // addi a0, zero, 5
+        assert_eq!(
+            lifted("13055000", 0).unwrap().statements,
+            [Stmt::SetReg {
+                reg: 10,
+                value: binary(BinOp::Add, Expr::Const(0), Expr::Const(5)),
+            }]
+        );
+        // sd ra, 8(sp)
+        assert_eq!(
+            lifted("23341100", 0).unwrap().statements,
+            [Stmt::Store {
+                addr: binary(BinOp::Add, Expr::Reg(2), Expr::Const(8)),
+                width: 8,
+                value: Expr::Reg(1),
+            }]
+        );
+        // bne a0, a1, -4 at 0x100
+        assert_eq!(
+            lifted("e31eb5fe", 0x100).unwrap().statements,
+            [Stmt::Branch {
+                condition: binary(BinOp::Ne, Expr::Reg(10), Expr::Reg(11)),
+                target: 0xfc,
+            }]
+        );
+        // lui a0, 0x80000 sign-extends on RV64
+        assert_eq!(
+            lifted("37050080", 0).unwrap().statements,
+            [Stmt::SetReg {
+                reg: 10,
+                value: Expr::Const(0xffff_ffff_8000_0000),
+            }]
+        );
+    }
+
+    #[cfg(all(feature = "ext-a", feature = "ext-c"))]
+    #[test]
+    fn test_lift_compressed_through_canonical_form() {
+        // c.jal is RV32-only; c.jr ra at 0x10 returns and links nothing.
+        let ret = lifted("8280", 0x10).unwrap();
+        assert_eq!(ret.size, 2);
+        assert_eq!(ret.statements.len(), 1);
+        assert!(matches!(ret.statements[0], Stmt::Jump { .. }));
+
+        // Writes to x0 are dropped; fences have no effect on a single hart.
+        assert!(lifted("13000000", 0).unwrap().statements.is_empty());
+        assert!(lifted("0f00f00f", 0).unwrap().statements.is_empty());
+
+        // amoadd.w is not lifted yet.
+        assert!(lifted("2f25b600", 0).is_none());
+    }
+}
@@ -0,0 +1,190 @@
+//! Per-instruction metadata: required extension, privilege, latency class.
+//!
+//! The extension comes from the decoder's extension tables, refined to the
+//! ratified sub-extension where `I` lumps several together (`Zicsr`,
+//! `Zifencei`, `Zicbop`, and the privileged architecture). CSR accesses take
+//! their privilege level from bits 9:8 of the CSR number. Latency classes
+//! are approximate and meant for spotting expensive instructions in hot
+//! loops, not for cycle counting.
+
+use crate::extensions::create_extensions;
+use robustone_core::ir::{DecodedInstruction, Operand};
+use robustone_core::traits::{InstructionMetadata, LatencyClass, PrivilegeLevel};
+
+/// Cores with their own latency table, in the order they are reported.
+pub const CORES: &[&str] = &["rocket", "sifive-u74"];
+
+/// Metadata for an instruction the decoder accepted.
+pub fn instruction_metadata(decoded: &DecodedInstruction) -> Option<InstructionMetadata> {
+    let mnemonic = decoded.mnemonic.as_str();
+    Some(InstructionMetadata {
+        extension: extension(mnemonic)?,
+        privilege: privilege(decoded),
+        latency: latency(decoded, None),
+        core_latency: CORES
+            .iter()
+            .map(|core| (*core, latency(decoded, Some(core))))
+            .collect(),
+    })
+}
+
+fn extension(mnemonic: &str) -> Option<&'static str> {
+    // The extension tables list atomics without their `aq`/`rl` suffix.
+    let mnemonic = [".aqrl", ".aq", ".rl"]
+        .iter()
+        .find_map(|suffix| mnemonic.strip_suffix(suffix))
+        .unwrap_or(mnemonic);
+    Some(match mnemonic {
+        "csrrw" | "csrrs" | "csrrc" | "csrrwi" | "csrrsi" | "csrrci" => "Zicsr",
+        "fence.i" => "Zifencei",
+        "prefetch.i" | "prefetch.r" | "prefetch.t" | "prefetch.w" => "Zicbop",
+        "uret" | "sret" | "mret" | "dret" | "wfi" | "sfence.vma" => "Privileged",
+        "sinval.vma" | "sfence.w.inval" | "sfence.inval.ir" => "Svinval",
+        _ => create_extensions()
+            .iter()
+            .find(|extension| extension.mnemonics().contains(&mnemonic))?
+            .name(),
+    })
+}
+
+fn privilege(decoded: &DecodedInstruction) -> PrivilegeLevel {
+    match decoded.mnemonic.as_str() {
+        "mret" | "dret" => PrivilegeLevel::Machine,
+        "sret" | "sfence.vma" | "wfi" | "sinval.vma" | "sfence.w.inval" | "sfence.inval.ir" => {
+            PrivilegeLevel::Supervisor
+        }
+        _ => decoded
+            .operands
+            .iter()
+            .find_map(|operand| match operand {
+                Operand::SystemRegister { number } => Some(match (number >> 8) & 0b11 {
+                    0 => PrivilegeLevel::User,
+                    1 => PrivilegeLevel::Supervisor,
+                    2 => PrivilegeLevel::Hypervisor,
+                    _ => PrivilegeLevel::Machine,
+                }),
+                _ => None,
+            })
+            .unwrap_or(PrivilegeLevel::User),
+    }
+}
+
+/// Latency class on `core`, or on a typical in-order core for `None`.
+fn latency(decoded: &DecodedInstruction, core: Option<&str>) -> LatencyClass {
+    let mnemonic = decoded.mnemonic.trim_start_matches("c.");
+    let in_group = |name: &str| decoded.groups.iter().any(|group| group == name);
+    if mnemonic.starts_with("csr")
+        || mnemonic.starts_with("fence")
+        || matches!(
+            mnemonic,
+            "ecall"
+                | "ebreak"
+                | "uret"
+                | "sret"
+                | "mret"
+                | "dret"
+                | "wfi"
+                | "sfence.vma"
+                | "sinval.vma"
+                | "sfence.w.inval"
+                | "sfence.inval.ir"
+        )
+    {
+        LatencyClass::Serializing
+    } else if in_group("load") || in_group("store") || in_group("atomic") {
+        LatencyClass::Memory
+    } else if mnemonic.starts_with("div")
+        || mnemonic.starts_with("rem")
+        || mnemonic.starts_with("fdiv")
+        || mnemonic.starts_with("fsqrt")
+    {
+        LatencyClass::Long
+    } else if mnemonic.starts_with("mul") {
+        // Rocket's default multiplier is iterative, eight bits per cycle.
+        if core == Some("rocket") {
+            LatencyClass::Long
+        } else {
+            LatencyClass::Short
+        }
+    } else if in_group("floating_point") {
+        LatencyClass::Short
+    } else {
+        LatencyClass::Single
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RiscVHandler;
+    use robustone_core::ArchitectureHandler;
+
+    fn metadata(word: &[u8]) -> InstructionMetadata {
+        let handler = RiscVHandler::new();
+        let (decoded, _) = handler.decode_instruction(word, "riscv64", 0).unwrap();
+        instruction_metadata(&decoded).unwrap()
+    }
+
+    #[cfg(all(feature = "ext-m", feature = "ext-c"))]
+    #[test]
+    fn test_extension_and_latency_follow_the_instruction() {
+        // addi a0, a0, 1
+        let addi = metadata(&[0x13, 0x05, 0x15, 0x00]);
+        assert_eq!(addi.extension, "I");
+        assert_eq!(addi.latency, LatencyClass::Single);
+
+        // mul a0, a0, a1
+        let mul = metadata(&[0x33, 0x05, 0xb5, 0x02]);
+        assert_eq!(mul.extension, "M");
+        assert_eq!(mul.latency, LatencyClass::Short);
+        assert_eq!(
+            mul.core_latency,
+            [
+                ("rocket", LatencyClass::Long),
+                ("sifive-u74", LatencyClass::Short)
+            ]
+        );
+
+        // divu a0, a0, a1; c.lw a0, 0(a0)
+        assert_eq!(
+            metadata(&[0x33, 0x55, 0xb5, 0x02]).latency,
+            LatencyClass::Long
+        );
+        let load = metadata(&[0x08, 0x41]);
+        assert_eq!((load.extension, load.latency), ("C", LatencyClass::Memory));
+    }
+
+    #[test]
+    fn test_privilege_comes_from_the_instruction_or_csr() {
+        // csrr a0, cycle
+        let cycle = metadata(&[0x73, 0x25, 0x00, 0xc0]);
+        assert_eq!(cycle.extension, "Zicsr");
+        assert_eq!(cycle.privilege, PrivilegeLevel::User);
+        assert_eq!(cycle.latency, LatencyClass::Serializing);
+        // csrr a0, sstatus; csrr a0, mstatus
+        assert_eq!(
+            metadata(&[0x73, 0x25, 0x00, 0x10]).privilege,
+            PrivilegeLevel::Supervisor
+        );
+        assert_eq!(
+            metadata(&[0x73, 0x25, 0x00, 0x30]).privilege,
+            PrivilegeLevel::Machine
+        );
+        // mret
+        let mret = metadata(&[0x73, 0x00, 0x20, 0x30]);
+        assert_eq!(
+            (mret.extension, mret.privilege),
+            ("Privileged", PrivilegeLevel::Machine)
+        );
+        // sinval.vma a0, a1
+        let sinval = metadata(&[0x73, 0x00, 0xb5, 0x16]);
+        assert_eq!(
+            (sinval.extension, sinval.privilege, sinval.latency),
+            (
+                "Svinval",
+                PrivilegeLevel::Supervisor,
+                LatencyClass::Serializing
+            )
+        );
+    }
+}
@@ -134,6 +134,8 @@ impl RiscVPrinter {
             RiscVOperandValue::Immediate(imm) => self.format_immediate(*imm),
             RiscVOperandValue::RoundingMode(rm) => rounding_mode_name(*rm).to_string(),
             RiscVOperandValue::Memory(mem) => self.format_memory_operand(mem.base, mem.disp),
+            RiscVOperandValue::Csr(csr) => DefaultOperandFactory::new().format_csr(i64::from(*csr)),
+            RiscVOperandValue::VectorType(vtype) => vtype.to_string(),
         }
     }
 
@@ -221,7 +223,9 @@ impl RiscVPrinter {
                 }
             }
             Operand::Text { value } => value.clone(),
-            Operand::Memory { base, displacement } => base
+            Operand::Memory {
+                base, displacement, ..
+            } => base
                 .as_ref()
                 .map(|base| {
                     let disp = if self.unsigned_immediate && *displacement < 0 {
@@ -232,6 +236,9 @@ impl RiscVPrinter {
                     format!("{disp}({})", self.format_ir_register(base))
                 })
                 .unwrap_or_else(|| self.format_immediate(*displacement)),
+            Operand::SystemRegister { number } => csr_name_lookup(*number as u16)
+                .map(str::to_string)
+                .unwrap_or_else(|| self.format_immediate(i64::from(*number))),
         }
     }
 
@@ -244,11 +251,6 @@ impl RiscVPrinter {
         last_visible_index: Option<usize>,
     ) -> String {
         match operand {
-            Operand::Immediate { value } if self.is_csr_operand(mnemonic, index) => {
-                csr_name_lookup(*value as u16)
-                    .map(str::to_string)
-                    .unwrap_or_else(|| self.format_ir_basic_operand(operand, mode))
-            }
             Operand::Immediate { value }
                 if last_visible_index == Some(index) && self.is_control_flow_mnemonic(mnemonic) =>
             {
@@ -311,6 +313,7 @@ impl RiscVPrinter {
                     Operand::Memory {
                         base: Some(base),
                         displacement,
+                        ..
                     },
                 ),
             ] => {
@@ -346,6 +349,7 @@ impl RiscVPrinter {
                     Operand::Memory {
                         base: Some(base),
                         displacement,
+                        ..
                     },
                 ),
                 (_, Operand::Register { register: second }),
@@ -375,13 +379,6 @@ impl RiscVPrinter {
         }
     }
 
-    fn is_csr_operand(&self, mnemonic: &str, index: usize) -> bool {
-        let csr_mnemonics = [
-            "csrrw", "csrrs", "csrrc", "csrrwi", "csrrsi", "csrrci", "csrr", "csrc", "csrw",
-        ];
-        csr_mnemonics.contains(&mnemonic) && index == 1
-    }
-
     fn is_control_flow_mnemonic(&self, mnemonic: &str) -> bool {
         matches!(
             mnemonic,
@@ -635,6 +632,7 @@ mod tests {
         assert_eq!(operands, "x1, x0, 1");
     }
 
+    #[cfg(feature = "ext-f")]
     #[test]
     fn test_canonical_profile_renders_fp_registers_without_aliases() {
         let decoder = RiscVDecoder::rv64gc();
@@ -668,6 +666,7 @@ mod tests {
                 },
                 Operand::Immediate { value: 1 },
             ],
+            operand_access: Vec::new(),
             registers_read: vec![RegisterId::riscv(0)],
             registers_written: vec![RegisterId::riscv(1)],
             implicit_registers_read: Vec::new(),
@@ -678,6 +677,7 @@ mod tests {
                 capstone_mnemonic: Some("li".to_string()),
                 capstone_hidden_operands: vec![1],
             },
+            decoded_by: None,
             render: Some(crate::render::render_riscv_text_parts),
         };
 
@@ -743,6 +743,7 @@ mod tests {
                 },
                 Operand::Immediate { value: -16 },
             ],
+            operand_access: Vec::new(),
             registers_read: vec![RegisterId::riscv(2)],
             registers_written: vec![RegisterId::riscv(2)],
             implicit_registers_read: Vec::new(),
@@ -753,6 +754,7 @@ mod tests {
                 capstone_mnemonic: None,
                 capstone_hidden_operands: Vec::new(),
             },
+            decoded_by: None,
             render: Some(crate::render::render_riscv_text_parts),
         };
         let instruction = Instruction::from_decoded(
@@ -1,10 +1,14 @@
 //! RISC-V instruction text rendering.
 //!
-//! Provides Capstone-compatible and canonical text rendering for RISC-V
-//! decoded instructions. This module was extracted from robustone-core so
-//! that architecture-specific formatting lives in the architecture crate.
+//! Provides Capstone-compatible, canonical, and `llvm-mc` text rendering for
+//! RISC-V decoded instructions. This module was extracted from robustone-core
+//! so that architecture-specific formatting lives in the architecture crate.
 
+use crate::RiscVHandler;
+use crate::aliases::{ALIASES, Alias};
+use robustone_core::ArchitectureHandler;
 use robustone_core::ir::{DecodedInstruction, Operand, TextRenderProfile};
+use std::sync::LazyLock;
 
 /// Render a RISC-V decoded instruction into mnemonic and operand text.
 pub fn render_riscv_text_parts(
@@ -15,6 +19,10 @@ pub fn render_riscv_text_parts(
     compressed_aliases: bool,
     unsigned_immediate: bool,
 ) -> (String, String) {
+    if matches!(profile, TextRenderProfile::Llvm) {
+        return render_llvm_text_parts(instruction, alias_regs, capstone_aliases);
+    }
+
     let use_capstone_aliases =
         capstone_aliases && (compressed_aliases || !instruction.mnemonic.starts_with("c."));
 
@@ -135,6 +143,7 @@ fn format_riscv_atomic_operands(
             Operand::Memory {
                 base: Some(base),
                 displacement,
+                ..
             } if *displacement == 0 => {
                 memory = Some(format!("({})", format_riscv_register(base.id, alias_regs)));
             }
@@ -174,11 +183,6 @@ fn format_riscv_operand(
     last_visible_index: Option<usize>,
 ) -> String {
     match operand {
-        Operand::Immediate { value } if is_riscv_csr_operand(mnemonic, index) => {
-            csr_name_lookup(*value as u16)
-                .map(str::to_string)
-                .unwrap_or_else(|| format_riscv_immediate(*value, "", unsigned_immediate))
-        }
         Operand::Immediate { value }
             if last_visible_index == Some(index) && is_riscv_control_flow_mnemonic(mnemonic) =>
         {
@@ -187,6 +191,7 @@ fn format_riscv_operand(
         Operand::Memory {
             base: Some(base),
             displacement,
+            ..
         } if *displacement == 0 && is_riscv_atomic_memory_mnemonic(mnemonic) => {
             format!("({})", format_riscv_register(base.id, alias_regs))
         }
@@ -211,7 +216,9 @@ fn format_riscv_basic_operand(
             }
         }
         Operand::Text { value } => value.clone(),
-        Operand::Memory { base, displacement } => {
+        Operand::Memory {
+            base, displacement, ..
+        } => {
             let displacement = format_riscv_immediate(*displacement, mode, unsigned_immediate);
             if let Some(base) = base {
                 format!(
@@ -223,6 +230,9 @@ fn format_riscv_basic_operand(
                 displacement
             }
         }
+        Operand::SystemRegister { number } => csr_name_lookup(*number as u16)
+            .map(str::to_string)
+            .unwrap_or_else(|| format_riscv_immediate(i64::from(*number), "", unsigned_immediate)),
     }
 }
 
@@ -364,19 +374,15 @@ fn is_riscv_control_flow_mnemonic(mnemonic: &str) -> bool {
     )
 }
 
-fn is_riscv_csr_operand(mnemonic: &str, index: usize) -> bool {
-    matches!(
-        mnemonic,
-        "csrrw" | "csrrs" | "csrrc" | "csrrwi" | "csrrsi" | "csrrci" | "csrr" | "csrc" | "csrw"
-    ) && index == 1
-}
-
 fn is_riscv_atomic_memory_mnemonic(mnemonic: &str) -> bool {
     mnemonic.starts_with("lr.") || mnemonic.starts_with("sc.") || mnemonic.starts_with("amo")
 }
 
 fn csr_name_lookup(csr: u16) -> Option<&'static str> {
     match csr {
+        0x001 => Some("fflags"),
+        0x002 => Some("frm"),
+        0x003 => Some("fcsr"),
         0x100 => Some("sstatus"),
         0x105 => Some("stvec"),
         0x106 => Some("scounteren"),
@@ -392,6 +398,265 @@ fn csr_name_lookup(csr: u16) -> Option<&'static str> {
         0xc80 => Some("cycleh"),
         0xc81 => Some("timeh"),
         0xc82 => Some("instreth"),
+        _ => crate::shared::operands::system_csr_name(csr),
+    }
+}
+
+/// Aliases `llvm-mc` prints, most specific first. LLVM parses the others in
+/// [`ALIASES`] (`bgt`, `zext.b`, `fmv.x.s`, ...) but never prints them.
+const LLVM_PRINTED_ALIASES: &[&str] = &[
+    "nop",
+    "li",
+    "mv",
+    "not",
+    "neg",
+    "negw",
+    "sext.w",
+    "seqz",
+    "snez",
+    "sltz",
+    "sgtz",
+    "beqz",
+    "bnez",
+    "blez",
+    "bgez",
+    "bltz",
+    "bgtz",
+    "j",
+    "fmv.s",
+    "fabs.s",
+    "fneg.s",
+    "fmv.d",
+    "fabs.d",
+    "fneg.d",
+    "rdcycle",
+    "rdtime",
+    "rdinstret",
+    "frcsr",
+    "fscsr",
+    "frrm",
+    "fsrm",
+    "frflags",
+    "fsflags",
+    "csrr",
+    "csrw",
+    "csrs",
+    "csrc",
+    "csrwi",
+    "csrsi",
+    "csrci",
+];
+
+/// Uncompresses RVC instructions for the LLVM printer, which shows them in
+/// their 32-bit form.
+static UNCOMPRESSOR: LazyLock<RiscVHandler> = LazyLock::new(RiscVHandler::new);
+
+/// Render `instruction` as `llvm-mc --disassemble` prints it: compressed
+/// instructions in their 32-bit form, LLVM's printed aliases, decimal
+/// immediates, and named CSRs.
+/// `aliases` off keeps the instruction's own mnemonic instead of the
+/// pseudo-instructions `llvm-mc` prints (`nop`, `li`, `ret`).
+fn render_llvm_text_parts(
+    instruction: &DecodedInstruction,
+    alias_regs: bool,
+    aliases: bool,
+) -> (String, String) {
+    let expanded = UNCOMPRESSOR.expand_compressed(instruction);
+    let instruction = expanded.as_ref().unwrap_or(instruction);
+    let mnemonic = instruction.mnemonic.as_str();
+    let operands = instruction.operands.as_slice();
+    let text = |operand: &Operand| format_llvm_operand(mnemonic, operand, alias_regs);
+
+    if let Some(rendered) = render_llvm_jump(mnemonic, operands, alias_regs) {
+        return rendered;
+    }
+    if mnemonic == "fence" {
+        return match operands {
+            [
+                Operand::Immediate { value: 15 },
+                Operand::Immediate { value: 15 },
+            ] => ("fence".to_string(), String::new()),
+            [
+                Operand::Immediate { value: pred },
+                Operand::Immediate { value: succ },
+            ] => (
+                "fence".to_string(),
+                format!("{}, {}", fence_set(*pred), fence_set(*succ)),
+            ),
+            _ => (mnemonic.to_string(), join_llvm_operands(operands, &text)),
+        };
+    }
+
+    let printed_aliases: &[&str] = if aliases { LLVM_PRINTED_ALIASES } else { &[] };
+    for name in printed_aliases {
+        let Some(alias) = ALIASES.iter().find(|alias| alias.alias == *name) else {
+            continue;
+        };
+        if alias.canonical != mnemonic {
+            continue;
+        }
+        if let Some(bound) = match_alias_expansion(alias, operands) {
+            let mut placeholders = alias
+                .syntax
+                .split([' ', ','])
+                .filter(|token| !token.is_empty());
+            placeholders.next();
+            let mut rendered: Vec<String> = placeholders
+                .map(|placeholder| {
+                    bound
+                        .iter()
+                        .find(|(name, _)| *name == placeholder)
+                        .map(|(_, operand)| text(operand))
+                        .unwrap_or_default()
+                })
+                .collect();
+            // `fscsr zero, a0` prints as `fscsr a0`, like the other writes.
+            if matches!(alias.alias, "fscsr" | "fsrm" | "fsflags")
+                && matches!(operands.first(), Some(Operand::Register { register }) if register.id == 0)
+            {
+                rendered.remove(0);
+            }
+            return (alias.alias.to_string(), rendered.join(", "));
+        }
+    }
+
+    let operands = match operands {
+        // A dynamic rounding mode is the default and is not printed.
+        [rest @ .., Operand::Text { value }] if value == "dyn" => rest,
+        _ => operands,
+    };
+    (mnemonic.to_string(), join_llvm_operands(operands, &text))
+}
+
+/// `jal` and `jalr`, whose LLVM aliases depend on the link register and
+/// offset in ways the alias table does not spell out.
+fn render_llvm_jump(
+    mnemonic: &str,
+    operands: &[Operand],
+    alias_regs: bool,
+) -> Option<(String, String)> {
+    let register = |id: u32| format_riscv_register(id, alias_regs);
+    match (mnemonic, operands) {
+        (
+            "jal",
+            [
+                Operand::Register { register: rd },
+                Operand::Immediate { value },
+            ],
+        ) => Some(match rd.id {
+            0 => ("j".to_string(), value.to_string()),
+            1 => ("jal".to_string(), value.to_string()),
+            rd => ("jal".to_string(), format!("{}, {value}", register(rd))),
+        }),
+        (
+            "jalr",
+            [
+                Operand::Register { register: rd },
+                Operand::Register { register: rs1 },
+                Operand::Immediate { value },
+            ],
+        ) => Some(match (rd.id, rs1.id, *value) {
+            (0, 1, 0) => ("ret".to_string(), String::new()),
+            (0, rs1, 0) => ("jr".to_string(), register(rs1)),
+            (1, rs1, 0) => ("jalr".to_string(), register(rs1)),
+            (0, rs1, offset) => ("jr".to_string(), format!("{offset}({})", register(rs1))),
+            (1, rs1, offset) => ("jalr".to_string(), format!("{offset}({})", register(rs1))),
+            (rd, rs1, offset) => (
+                "jalr".to_string(),
+                format!("{}, {offset}({})", register(rd), register(rs1)),
+            ),
+        }),
         _ => None,
     }
 }
+
+/// Bind the placeholders of `alias.expansion` to `operands`, or `None` when
+/// a fixed operand (`x0`, `-1`, `cycle`, ...) or a repeated placeholder does
+/// not match.
+fn match_alias_expansion<'a>(
+    alias: &Alias,
+    operands: &'a [Operand],
+) -> Option<Vec<(&'static str, &'a Operand)>> {
+    let mut tokens = alias
+        .expansion
+        .split([' ', ','])
+        .filter(|token| !token.is_empty());
+    tokens.next();
+    let tokens: Vec<&'static str> = tokens.collect();
+    if tokens.len() != operands.len() {
+        return None;
+    }
+
+    let mut bound: Vec<(&'static str, &Operand)> = Vec::new();
+    for (token, operand) in tokens.into_iter().zip(operands) {
+        if matches!(token, "rd" | "rs" | "rt" | "imm" | "offset" | "csr") {
+            match bound.iter().find(|(name, _)| *name == token) {
+                Some((_, previous)) if *previous != operand => return None,
+                Some(_) => {}
+                None => bound.push((token, operand)),
+            }
+            continue;
+        }
+        let matches = match operand {
+            Operand::Register { register } => match token {
+                "x0" => register.id == 0,
+                "x1" => register.id == 1,
+                _ => false,
+            },
+            Operand::Immediate { value } => token.parse() == Ok(*value),
+            Operand::SystemRegister { number } => llvm_csr_name(*number) == Some(token),
+            _ => false,
+        };
+        if !matches {
+            return None;
+        }
+    }
+    Some(bound)
+}
+
+fn join_llvm_operands(operands: &[Operand], text: &dyn Fn(&Operand) -> String) -> String {
+    operands.iter().map(text).collect::<Vec<_>>().join(", ")
+}
+
+fn format_llvm_operand(mnemonic: &str, operand: &Operand, alias_regs: bool) -> String {
+    match operand {
+        Operand::Register { register } => format_riscv_register(register.id, alias_regs),
+        Operand::Immediate { value } => value.to_string(),
+        Operand::Text { value } => value.clone(),
+        Operand::Memory {
+            base: Some(base),
+            displacement: 0,
+            ..
+        } if is_riscv_atomic_memory_mnemonic(mnemonic) => {
+            format!("({})", format_riscv_register(base.id, alias_regs))
+        }
+        Operand::Memory {
+            base, displacement, ..
+        } => match base {
+            Some(base) => format!(
+                "{displacement}({})",
+                format_riscv_register(base.id, alias_regs)
+            ),
+            None => displacement.to_string(),
+        },
+        Operand::SystemRegister { number } => llvm_csr_name(*number)
+            .map(str::to_string)
+            .unwrap_or_else(|| number.to_string()),
+    }
+}
+
+fn llvm_csr_name(number: u32) -> Option<&'static str> {
+    u16::try_from(number)
+        .ok()
+        .and_then(crate::shared::operands::csr_name_lookup)
+}
+
+/// A `fence` predecessor or successor set as LLVM prints it (`iorw`, `rw`, `0`).
+fn fence_set(bits: i64) -> String {
+    let set: String = [(8, 'i'), (4, 'o'), (2, 'r'), (1, 'w')]
+        .into_iter()
+        .filter(|(bit, _)| bits & bit != 0)
+        .map(|(_, name)| name)
+        .collect();
+    if set.is_empty() { "0".to_string() } else { set }
+}
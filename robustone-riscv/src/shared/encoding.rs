@@ -267,13 +267,7 @@ impl InstructionDecoder for DefaultSignExtender {
         let rs2p = ((instruction >> 2) & 0x7) as u8;
 
         // Extract immediate fields for compressed formats
-        let nzuimm_ciw = ((instruction >> 5) & 0x1) << 4
-            | ((instruction >> 6) & 0x1) << 5
-            | ((instruction >> 7) & 0x1) << 6
-            | ((instruction >> 8) & 0x1) << 7
-            | ((instruction >> 9) & 0x1) << 8
-            | ((instruction >> 10) & 0x1) << 9
-            | ((instruction >> 12) & 0x1) << 5;
+        let nzuimm_ciw = convenience::nzuimm_ciw(instruction);
 
         let uimm_cl = ((instruction >> 5) & 0x3) << 6
             | ((instruction >> 10) & 0x1) << 5
@@ -442,6 +436,19 @@ pub mod convenience {
         DefaultSignExtender::instance().extract_compressed_fields(instruction)
     }
 
+    /// Extract the CIW immediate of `c.addi4spn`.
+    ///
+    /// The encoding scatters `nzuimm[9:2]` as
+    /// `inst[12:11] = nzuimm[5:4]`, `inst[10:7] = nzuimm[9:6]`,
+    /// `inst[6] = nzuimm[2]` and `inst[5] = nzuimm[3]`; the result is the
+    /// zero-extended byte offset, always a multiple of 4.
+    pub fn nzuimm_ciw(instruction: u16) -> u16 {
+        ((instruction >> 11) & 0x3) << 4
+            | ((instruction >> 7) & 0xf) << 6
+            | ((instruction >> 6) & 0x1) << 2
+            | ((instruction >> 5) & 0x1) << 3
+    }
+
     /// Extract and validate shift amount.
     pub fn extract_shamt(imm: i64, xlen: crate::decoder::Xlen) -> i64 {
         ShamtExtractor::extract_shamt(imm, xlen)
@@ -548,6 +555,21 @@ mod tests {
         assert_eq!(cb_fields.imm_cb, -86);
     }
 
+    #[test]
+    fn test_ciw_immediate_round_trips_every_offset() {
+        for nzuimm in (4u16..=1020).step_by(4) {
+            let instruction = ((nzuimm >> 4) & 0x3) << 11
+                | ((nzuimm >> 6) & 0xf) << 7
+                | ((nzuimm >> 2) & 0x1) << 6
+                | ((nzuimm >> 3) & 0x1) << 5;
+            assert_eq!(convenience::nzuimm_ciw(instruction), nzuimm);
+            assert_eq!(
+                convenience::extract_compressed_fields(instruction).nzuimm_ciw,
+                nzuimm
+            );
+        }
+    }
+
     #[test]
     fn test_shamt_extractor() {
         // Test RV32
@@ -300,57 +300,7 @@ impl CsrFormatter {
 
     /// Look up CSR name by address.
     pub fn csr_name_lookup(csr: u16) -> Option<&'static str> {
-        match csr {
-            0x000 => Some("ustatus"),
-            0x001 => Some("fflags"),
-            0x002 => Some("frm"),
-            0x003 => Some("fcsr"),
-            0x100 => Some("sstatus"),
-            0x102 => Some("sedeleg"),
-            0x103 => Some("sideleg"),
-            0x104 => Some("sie"),
-            0x105 => Some("stvec"),
-            0x106 => Some("scounteren"),
-            0x140 => Some("sscratch"),
-            0x141 => Some("sepc"),
-            0x142 => Some("scause"),
-            0x143 => Some("stval"),
-            0x144 => Some("sip"),
-            0x180 => Some("satp"),
-            0x300 => Some("mstatus"),
-            0x301 => Some("misa"),
-            0x302 => Some("medeleg"),
-            0x303 => Some("mideleg"),
-            0x304 => Some("mie"),
-            0x305 => Some("mtvec"),
-            0x306 => Some("mcounteren"),
-            0x320 => Some("mcountinhibit"),
-            0x323 => Some("mhpmevent3"),
-            0x340 => Some("mscratch"),
-            0x341 => Some("mepc"),
-            0x342 => Some("mcause"),
-            0x343 => Some("mtval"),
-            0x344 => Some("mip"),
-            0x34A => Some("mtinst"),
-            0x34B => Some("mtval2"),
-            0x7A0 => Some("tselect"),
-            0x7A1 => Some("tdata1"),
-            0x7A2 => Some("tdata2"),
-            0x7A3 => Some("tdata3"),
-            0x7B0 => Some("dcsr"),
-            0x7B1 => Some("dpc"),
-            0x7B2 => Some("dscratch0"),
-            0x7B3 => Some("dscratch1"),
-            0xB03 => Some("mhpmcounter3"),
-            0xB83 => Some("mhpmcounter3h"),
-            0xC00 => Some("cycle"),
-            0xC01 => Some("time"),
-            0xC02 => Some("instret"),
-            0xC80 => Some("cycleh"),
-            0xC81 => Some("timeh"),
-            0xC82 => Some("instreth"),
-            _ => None,
-        }
+        super::operands::csr_name_lookup(csr)
     }
 }
 
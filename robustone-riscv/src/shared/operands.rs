@@ -69,6 +69,25 @@ impl DefaultOperandFactory {
         }
     }
 
+    /// Create a vector type operand for `vsetvli` and `vsetivli`.
+    pub fn vector_type(vtype: VType) -> RiscVOperand {
+        RiscVOperand {
+            op_type: RiscVOperandType::VectorType,
+            access: Access::read(),
+            value: RiscVOperandValue::VectorType(vtype),
+        }
+    }
+
+    /// Create a CSR operand; `access` says whether the CSR is read and/or
+    /// written.
+    pub fn csr(csr: u16, access: Access) -> RiscVOperand {
+        RiscVOperand {
+            op_type: RiscVOperandType::Csr,
+            access,
+            value: RiscVOperandValue::Csr(csr),
+        }
+    }
+
     /// Create a floating-point register operand (convenience method).
     pub fn fp_register(reg: u8, access: Access) -> RiscVOperand {
         RiscVOperand {
@@ -390,10 +409,122 @@ pub(crate) fn csr_name_lookup(csr: u16) -> Option<&'static str> {
         0xC80 => Some("cycleh"),
         0xC81 => Some("timeh"),
         0xC82 => Some("instreth"),
-        _ => None,
+        _ => system_csr_name(csr),
     }
 }
 
+/// Physical memory protection configuration CSRs; the odd ones are RV32-only.
+const PMPCFG: [&str; 16] = [
+    "pmpcfg0", "pmpcfg1", "pmpcfg2", "pmpcfg3", "pmpcfg4", "pmpcfg5", "pmpcfg6", "pmpcfg7",
+    "pmpcfg8", "pmpcfg9", "pmpcfg10", "pmpcfg11", "pmpcfg12", "pmpcfg13", "pmpcfg14", "pmpcfg15",
+];
+
+/// Physical memory protection address CSRs.
+const PMPADDR: [&str; 64] = [
+    "pmpaddr0",
+    "pmpaddr1",
+    "pmpaddr2",
+    "pmpaddr3",
+    "pmpaddr4",
+    "pmpaddr5",
+    "pmpaddr6",
+    "pmpaddr7",
+    "pmpaddr8",
+    "pmpaddr9",
+    "pmpaddr10",
+    "pmpaddr11",
+    "pmpaddr12",
+    "pmpaddr13",
+    "pmpaddr14",
+    "pmpaddr15",
+    "pmpaddr16",
+    "pmpaddr17",
+    "pmpaddr18",
+    "pmpaddr19",
+    "pmpaddr20",
+    "pmpaddr21",
+    "pmpaddr22",
+    "pmpaddr23",
+    "pmpaddr24",
+    "pmpaddr25",
+    "pmpaddr26",
+    "pmpaddr27",
+    "pmpaddr28",
+    "pmpaddr29",
+    "pmpaddr30",
+    "pmpaddr31",
+    "pmpaddr32",
+    "pmpaddr33",
+    "pmpaddr34",
+    "pmpaddr35",
+    "pmpaddr36",
+    "pmpaddr37",
+    "pmpaddr38",
+    "pmpaddr39",
+    "pmpaddr40",
+    "pmpaddr41",
+    "pmpaddr42",
+    "pmpaddr43",
+    "pmpaddr44",
+    "pmpaddr45",
+    "pmpaddr46",
+    "pmpaddr47",
+    "pmpaddr48",
+    "pmpaddr49",
+    "pmpaddr50",
+    "pmpaddr51",
+    "pmpaddr52",
+    "pmpaddr53",
+    "pmpaddr54",
+    "pmpaddr55",
+    "pmpaddr56",
+    "pmpaddr57",
+    "pmpaddr58",
+    "pmpaddr59",
+    "pmpaddr60",
+    "pmpaddr61",
+    "pmpaddr62",
+    "pmpaddr63",
+];
+
+/// Names of the system-level CSRs firmware and kernels program: PMP
+/// (`pmpcfg*`, `pmpaddr*`), the Smepmp `mseccfg`, the environment
+/// configuration (`menvcfg`, `senvcfg`) and the Smstateen state-enable CSRs.
+/// CSRs ending in `h` are the RV32 upper halves.
+pub(crate) fn system_csr_name(csr: u16) -> Option<&'static str> {
+    Some(match csr {
+        0x10A => "senvcfg",
+        0x10C => "sstateen0",
+        0x10D => "sstateen1",
+        0x10E => "sstateen2",
+        0x10F => "sstateen3",
+        0x30A => "menvcfg",
+        0x30C => "mstateen0",
+        0x30D => "mstateen1",
+        0x30E => "mstateen2",
+        0x30F => "mstateen3",
+        0x31A => "menvcfgh",
+        0x31C => "mstateen0h",
+        0x31D => "mstateen1h",
+        0x31E => "mstateen2h",
+        0x31F => "mstateen3h",
+        0x3A0..=0x3AF => PMPCFG[usize::from(csr - 0x3A0)],
+        0x3B0..=0x3EF => PMPADDR[usize::from(csr - 0x3B0)],
+        0x747 => "mseccfg",
+        0x757 => "mseccfgh",
+        _ => return None,
+    })
+}
+
+/// Whether `csr` only exists on RV32: the upper halves of 64-bit CSRs and
+/// the odd-numbered `pmpcfg` registers.
+pub(crate) fn is_rv32_only_csr(csr: u16) -> bool {
+    matches!(
+        csr,
+        0x31A | 0x31C..=0x31F | 0x757 | 0xB80..=0xB9F | 0xC80..=0xC9F
+    ) || (matches!(csr, 0x3A0..=0x3AF) && csr & 1 == 1)
+}
+
 /// Convenience functions for operand creation.
 pub mod convenience {
     use super::*;
@@ -413,6 +544,16 @@ pub mod convenience {
         DefaultOperandFactory::rounding_mode(rm)
     }
 
+    /// Create a CSR operand.
+    pub fn csr(csr: u16, access: Access) -> RiscVOperand {
+        DefaultOperandFactory::csr(csr, access)
+    }
+
+    /// Create a vector type operand.
+    pub fn vector_type(vtype: VType) -> RiscVOperand {
+        DefaultOperandFactory::vector_type(vtype)
+    }
+
     /// Create a floating-point register operand.
     pub fn fp_register(reg: u8, access: Access) -> RiscVOperand {
         DefaultOperandFactory::fp_register(reg, access)
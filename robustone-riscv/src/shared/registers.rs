@@ -3,7 +3,27 @@
 //! Provides centralized register name lookup and management functionality
 //! used across all RISC-V extensions.
 
-// types are not required in this module directly; avoid unused import
+use robustone_core::traits::{RegisterClass, RegisterInfo};
+
+use super::operands::{csr_name_lookup, is_rv32_only_csr};
+
+/// Register id of the first CSR: CSR `n` has id `CSR_ID_BASE + n`.
+///
+/// GPRs use ids 0-31 and FPRs 32-63 in the decoded IR; CSRs only appear as
+/// immediates there, so they get a disjoint id range of their own.
+pub const CSR_ID_BASE: u32 = 0x1000;
+
+const X_NAMES: [&str; 32] = [
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14",
+    "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27",
+    "x28", "x29", "x30", "x31",
+];
+
+const F_NAMES: [&str; 32] = [
+    "f0", "f1", "f2", "f3", "f4", "f5", "f6", "f7", "f8", "f9", "f10", "f11", "f12", "f13", "f14",
+    "f15", "f16", "f17", "f18", "f19", "f20", "f21", "f22", "f23", "f24", "f25", "f26", "f27",
+    "f28", "f29", "f30", "f31",
+];
 
 /// Trait for providing register names in RISC-V extensions.
 pub trait RegisterNameProvider {
@@ -157,10 +177,95 @@ pub fn get_compressed_register_name(reg: u8) -> &'static str {
     manager.compressed_register_name(reg)
 }
 
+/// Register metadata for a RISC-V mode.
+///
+/// `xlen` sizes GPRs and CSRs; `flen` is the FPR width, or `None` without the
+/// F extension. RV32-only high-half CSRs (such as `cycleh`) are listed only
+/// when `xlen` is 32.
+pub fn register_table(xlen: u16, flen: Option<u16>) -> Vec<RegisterInfo> {
+    let manager = RegisterManager::instance();
+    let mut registers = Vec::new();
+
+    for reg in 0..32u8 {
+        let mut aliases = vec![X_NAMES[reg as usize]];
+        if reg == 8 {
+            aliases.push("fp");
+        }
+        registers.push(RegisterInfo {
+            id: u32::from(reg),
+            name: manager.int_register_name(reg),
+            aliases,
+            class: RegisterClass::GeneralPurpose,
+            width: xlen,
+        });
+    }
+
+    if let Some(flen) = flen {
+        for reg in 0..32u8 {
+            registers.push(RegisterInfo {
+                id: 32 + u32::from(reg),
+                name: manager.fp_register_name(reg),
+                aliases: vec![F_NAMES[reg as usize]],
+                class: RegisterClass::FloatingPoint,
+                width: flen,
+            });
+        }
+    }
+
+    for csr in 0..0x1000u16 {
+        let Some(name) = csr_name_lookup(csr) else {
+            continue;
+        };
+        if is_rv32_only_csr(csr) && xlen != 32 {
+            continue;
+        }
+        registers.push(RegisterInfo {
+            id: CSR_ID_BASE + u32::from(csr),
+            name,
+            aliases: Vec::new(),
+            class: RegisterClass::ControlStatus,
+            width: xlen,
+        });
+    }
+
+    registers
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_register_table_names_aliases_and_widths() {
+        let rv64 = register_table(64, Some(64));
+        let fp = rv64.iter().find(|reg| reg.is_named("fp")).unwrap();
+        assert_eq!((fp.id, fp.name, fp.width), (8, "s0", 64));
+        assert!(fp.is_named("x8"));
+
+        let fa0 = rv64.iter().find(|reg| reg.is_named("f10")).unwrap();
+        assert_eq!((fa0.id, fa0.name), (42, "fa0"));
+        assert_eq!(fa0.class, RegisterClass::FloatingPoint);
+
+        let mstatus = rv64.iter().find(|reg| reg.name == "mstatus").unwrap();
+        assert_eq!(mstatus.id, CSR_ID_BASE + 0x300);
+        assert!(!rv64.iter().any(|reg| reg.name == "cycleh"));
+        let pmpaddr63 = rv64.iter().find(|reg| reg.name == "pmpaddr63").unwrap();
+        assert_eq!(pmpaddr63.id, CSR_ID_BASE + 0x3EF);
+        assert!(rv64.iter().any(|reg| reg.name == "pmpcfg2"));
+        assert!(!rv64.iter().any(|reg| reg.name == "pmpcfg1"));
+        assert!(!rv64.iter().any(|reg| reg.name == "menvcfgh"));
+
+        let rv32i = register_table(32, None);
+        assert!(rv32i.iter().any(|reg| reg.name == "cycleh"));
+        assert!(rv32i.iter().any(|reg| reg.name == "pmpcfg1"));
+        assert!(rv32i.iter().any(|reg| reg.name == "mseccfgh"));
+        assert!(
+            rv32i
+                .iter()
+                .all(|reg| reg.class != RegisterClass::FloatingPoint && reg.width == 32)
+        );
+    }
+
     #[test]
     fn test_int_register_names() {
         let manager = RegisterManager::new();
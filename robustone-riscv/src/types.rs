@@ -19,6 +19,10 @@ pub enum RiscVOperandType {
     RoundingMode,
     /// Memory operand.
     Memory,
+    /// Control and status register operand.
+    Csr,
+    /// Vector type settings of `vsetvli` and `vsetivli`.
+    VectorType,
 }
 
 /// Memory operand descriptor (matches `RISCV_OP_MEM`).
@@ -52,43 +56,14 @@ pub enum RiscVOperandValue {
     RoundingMode(u8),
     /// Memory addressing mode.
     Memory(RiscVMemoryOperand),
+    /// Control and status register number.
+    Csr(u16),
+    /// Vector type settings.
+    VectorType(VType),
 }
 
-/// Register access flags (mirrors `cs_ac_type`).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Access {
-    /// Indicates the register is read.
-    pub read: bool,
-    /// Indicates the register is written.
-    pub write: bool,
-}
-
-impl Access {
-    pub fn read() -> Self {
-        Self {
-            read: true,
-            write: false,
-        }
-    }
-    pub fn write() -> Self {
-        Self {
-            read: false,
-            write: true,
-        }
-    }
-    pub fn read_write() -> Self {
-        Self {
-            read: true,
-            write: true,
-        }
-    }
-    pub fn none() -> Self {
-        Self {
-            read: false,
-            write: false,
-        }
-    }
-}
+/// Register access flags (mirrors `cs_ac_type`), shared with `robustone-core`.
+pub use robustone_core::ir::Access;
 
 /// Converts a floating-point rounding mode selector into its mnemonic.
 pub fn rounding_mode_name(rm: u8) -> &'static str {
@@ -103,6 +78,115 @@ pub fn rounding_mode_name(rm: u8) -> &'static str {
     }
 }
 
+/// Vector register group multiplier (`vlmul`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lmul {
+    /// One eighth of a register.
+    Mf8,
+    /// One quarter of a register.
+    Mf4,
+    /// Half a register.
+    Mf2,
+    /// One register.
+    M1,
+    /// A group of two registers.
+    M2,
+    /// A group of four registers.
+    M4,
+    /// A group of eight registers.
+    M8,
+}
+
+impl Lmul {
+    /// Assembler spelling, such as `m4` or `mf2`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Lmul::Mf8 => "mf8",
+            Lmul::Mf4 => "mf4",
+            Lmul::Mf2 => "mf2",
+            Lmul::M1 => "m1",
+            Lmul::M2 => "m2",
+            Lmul::M4 => "m4",
+            Lmul::M8 => "m8",
+        }
+    }
+}
+
+/// Vector type settings in the layout of the `vtype` CSR, as encoded in the
+/// immediate of `vsetvli` and `vsetivli`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VType {
+    /// Selected element width in bits: 8, 16, 32, or 64.
+    pub sew: u16,
+    /// Register group multiplier.
+    pub lmul: Lmul,
+    /// Tail elements may be overwritten (`ta`) rather than left undisturbed (`tu`).
+    pub tail_agnostic: bool,
+    /// Masked-off elements may be overwritten (`ma`) rather than left undisturbed (`mu`).
+    pub mask_agnostic: bool,
+}
+
+impl VType {
+    /// Parse `vtype` bits, or `None` when they select a reserved SEW or
+    /// LMUL or set bits above `vma`.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if bits >> 8 != 0 {
+            return None;
+        }
+        let lmul = match bits & 0b111 {
+            0b000 => Lmul::M1,
+            0b001 => Lmul::M2,
+            0b010 => Lmul::M4,
+            0b011 => Lmul::M8,
+            0b101 => Lmul::Mf8,
+            0b110 => Lmul::Mf4,
+            0b111 => Lmul::Mf2,
+            _ => return None,
+        };
+        let sew = match (bits >> 3) & 0b111 {
+            vsew @ 0b000..=0b011 => 8 << vsew,
+            _ => return None,
+        };
+        Some(Self {
+            sew,
+            lmul,
+            tail_agnostic: bits & (1 << 6) != 0,
+            mask_agnostic: bits & (1 << 7) != 0,
+        })
+    }
+
+    /// The settings of a decoded `vsetvli` or `vsetivli`.
+    pub fn from_decoded(decoded: &robustone_core::ir::DecodedInstruction) -> Option<Self> {
+        let bits = match decoded.mnemonic.as_str() {
+            "vsetvli" | "vsetivli" if decoded.raw_bytes.len() >= 4 => u32::from_le_bytes([
+                decoded.raw_bytes[0],
+                decoded.raw_bytes[1],
+                decoded.raw_bytes[2],
+                decoded.raw_bytes[3],
+            ]),
+            _ => return None,
+        };
+        if decoded.mnemonic == "vsetvli" {
+            Self::from_bits((bits >> 20) & 0x7ff)
+        } else {
+            Self::from_bits((bits >> 20) & 0x3ff)
+        }
+    }
+}
+
+impl std::fmt::Display for VType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "e{}, {}, {}, {}",
+            self.sew,
+            self.lmul.as_str(),
+            if self.tail_agnostic { "ta" } else { "tu" },
+            if self.mask_agnostic { "ma" } else { "mu" }
+        )
+    }
+}
+
 /// Comprehensive RISC-V register enumeration (compatible with `riscv_reg`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RiscVRegister {
@@ -457,4 +541,44 @@ pub enum RiscVInstructionFormat {
     CJ,
 }
 
+impl RiscVInstructionFormat {
+    /// Format name as used in the ISA manual (`R`, `I`, ..., `CJ`).
+    pub fn name(self) -> &'static str {
+        match self {
+            RiscVInstructionFormat::R => "R",
+            RiscVInstructionFormat::I => "I",
+            RiscVInstructionFormat::S => "S",
+            RiscVInstructionFormat::B => "B",
+            RiscVInstructionFormat::U => "U",
+            RiscVInstructionFormat::J => "J",
+            RiscVInstructionFormat::R4 => "R4",
+            RiscVInstructionFormat::CR => "CR",
+            RiscVInstructionFormat::CI => "CI",
+            RiscVInstructionFormat::CSS => "CSS",
+            RiscVInstructionFormat::CIW => "CIW",
+            RiscVInstructionFormat::CL => "CL",
+            RiscVInstructionFormat::CS => "CS",
+            RiscVInstructionFormat::CA => "CA",
+            RiscVInstructionFormat::CB => "CB",
+            RiscVInstructionFormat::CJ => "CJ",
+        }
+    }
+
+    /// Check whether this is one of the 16-bit RVC formats.
+    pub fn is_compressed(self) -> bool {
+        matches!(
+            self,
+            RiscVInstructionFormat::CR
+                | RiscVInstructionFormat::CI
+                | RiscVInstructionFormat::CSS
+                | RiscVInstructionFormat::CIW
+                | RiscVInstructionFormat::CL
+                | RiscVInstructionFormat::CS
+                | RiscVInstructionFormat::CA
+                | RiscVInstructionFormat::CB
+                | RiscVInstructionFormat::CJ
+        )
+    }
+}
+
 pub const HEX_THRESHOLD: i64 = 9;
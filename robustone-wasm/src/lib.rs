@@ -0,0 +1,207 @@
+//! WebAssembly bindings for Robustone.
+//!
+//! This crate wraps [`robustone::Disassembler`] in a small `wasm-bindgen`
+//! surface so the disassembler can run client-side, for example inside
+//! web-based hexdump or firmware-analysis tools.
+//!
+//! Build it for the browser with:
+//!
+//! ```text
+//! cargo build -p robustone-wasm --target wasm32-unknown-unknown --release
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/release/robustone_wasm.wasm
+//! ```
+//!
+//! From JavaScript the exported API looks like:
+//!
+//! ```text
+//! const disasm = new Disassembler("riscv32");
+//! const listing = JSON.parse(disasm.disassembleHex("93001000", 0x1000n));
+//! ```
+//!
+//! Results are returned as JSON strings using the same envelope as the CLI
+//! `--json` output, so web front-ends and scripts can share one schema.
+
+use robustone::{
+    DisasmError, NopStyle, RenderOptions, RenderedDisassembly, RenderedIssue,
+    ir::TextRenderProfile, render_disassembly, shared_dispatcher,
+};
+use wasm_bindgen::prelude::*;
+
+/// Returns the names of the architecture handlers available in this build.
+#[wasm_bindgen(js_name = supportedArchitectures)]
+pub fn supported_architectures() -> Vec<String> {
+    shared_dispatcher()
+        .supported_architectures()
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+/// A disassembler bound to one architecture, exported to JavaScript.
+#[wasm_bindgen]
+pub struct Disassembler {
+    inner: robustone::Disassembler,
+    options: RenderOptions,
+}
+
+impl Disassembler {
+    /// Creates a disassembler for `arch`, failing if no handler supports it.
+    pub fn try_new(arch: &str) -> Result<Self, DisasmError> {
+        Ok(Self {
+            inner: robustone::Disassembler::new(arch)?,
+            options: RenderOptions {
+                text_profile: TextRenderProfile::Capstone,
+                alias_regs: false,
+                capstone_aliases: true,
+                compressed_aliases: true,
+                unsigned_immediate: false,
+                nops: NopStyle::Profile,
+            },
+        })
+    }
+
+    /// Disassembles `bytes` starting at `address`.
+    ///
+    /// Decoding stops at the first failure; instructions decoded before the
+    /// failure are kept and the failure is reported in `errors`.
+    pub fn disassemble_bytes(&self, bytes: &[u8], address: u64) -> RenderedDisassembly {
+        let (instructions, error) = self.inner.disassemble_partial(bytes, address);
+        let processed = error
+            .as_ref()
+            .and_then(DisasmError::location)
+            .map_or(bytes.len(), |location| location.offset);
+
+        render_disassembly(
+            self.inner.arch().to_string(),
+            address,
+            processed,
+            error.iter().map(|error| self.issue(error)).collect(),
+            &instructions,
+            self.options,
+        )
+    }
+
+    /// Parses `hex` as raw instruction bytes and disassembles them.
+    pub fn disassemble_hex_str(
+        &self,
+        hex: &str,
+        address: u64,
+    ) -> Result<RenderedDisassembly, DisasmError> {
+        let bytes = shared_dispatcher().parse_hex(hex, self.inner.arch())?;
+        Ok(self.disassemble_bytes(&bytes, address))
+    }
+
+    fn issue(&self, error: &DisasmError) -> RenderedIssue {
+        let location = error.location();
+        RenderedIssue {
+            kind: error.stable_kind().to_string(),
+            operation: "disassemble".to_string(),
+            message: error.detail_message(),
+            architecture: Some(
+                error
+                    .architecture_name()
+                    .unwrap_or(self.inner.arch())
+                    .to_string(),
+            ),
+            address: location.map(|location| location.address),
+            input_offset: location.map(|location| location.offset),
+            raw_bytes: location.map_or_else(Vec::new, |location| location.bytes.to_vec()),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Disassembler {
+    /// Creates a disassembler for the given architecture name (e.g. `riscv64`).
+    #[wasm_bindgen(constructor)]
+    pub fn new(arch: &str) -> Result<Disassembler, JsError> {
+        Self::try_new(arch).map_err(to_js_error)
+    }
+
+    /// The canonical name of the architecture this disassembler decodes.
+    #[wasm_bindgen(getter)]
+    pub fn arch(&self) -> String {
+        self.inner.arch().to_string()
+    }
+
+    /// Controls whether ABI register aliases are preferred in the output.
+    #[wasm_bindgen(js_name = setAliasRegs)]
+    pub fn set_alias_regs(&mut self, enabled: bool) {
+        self.options.alias_regs = enabled;
+    }
+
+    /// Controls whether immediates are rendered as unsigned values.
+    #[wasm_bindgen(js_name = setUnsignedImmediate)]
+    pub fn set_unsigned_immediate(&mut self, enabled: bool) {
+        self.options.unsigned_immediate = enabled;
+    }
+
+    /// Disassembles a byte buffer and returns the listing as a JSON string.
+    pub fn disassemble(&self, bytes: &[u8], address: u64) -> Result<String, JsError> {
+        to_json(&self.disassemble_bytes(bytes, address))
+    }
+
+    /// Disassembles a hex string and returns the listing as a JSON string.
+    #[wasm_bindgen(js_name = disassembleHex)]
+    pub fn disassemble_hex(&self, hex: &str, address: u64) -> Result<String, JsError> {
+        let rendered = self
+            .disassemble_hex_str(hex, address)
+            .map_err(to_js_error)?;
+        to_json(&rendered)
+    }
+}
+
+fn to_js_error(error: DisasmError) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+fn to_json(rendered: &RenderedDisassembly) -> Result<String, JsError> {
+    serde_json::to_string(rendered).map_err(|error| JsError::new(&error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_new_rejects_unknown_architecture() {
+        let error = match Disassembler::try_new("mips") {
+            Ok(_) => panic!("unknown architecture should be rejected"),
+            Err(error) => error,
+        };
+        assert_eq!(error.stable_kind(), "unsupported_architecture");
+    }
+
+    #[test]
+    fn test_disassemble_hex_renders_riscv_listing() {
+        let disassembler = Disassembler::try_new("riscv32").unwrap();
+        let rendered = disassembler
+            .disassemble_hex_str("93001000", 0x1000)
+            .expect("hex should parse");
+
+        assert_eq!(rendered.bytes_processed, 4);
+        assert!(rendered.errors.is_empty());
+        assert_eq!(rendered.instructions.len(), 1);
+        assert_eq!(rendered.instructions[0].address, 0x1000);
+        assert_eq!(rendered.instructions[0].mnemonic, "li");
+    }
+
+    #[test]
+    fn test_disassemble_bytes_keeps_prefix_and_reports_failure() {
+        let disassembler = Disassembler::try_new("riscv32").unwrap();
+        let rendered = disassembler.disassemble_bytes(&[0x93, 0x00, 0x10, 0x00, 0xff, 0xff], 0);
+
+        assert_eq!(rendered.instructions.len(), 1);
+        assert_eq!(rendered.bytes_processed, 4);
+        assert_eq!(rendered.errors.len(), 1);
+        assert_eq!(rendered.errors[0].input_offset, Some(4));
+        assert_eq!(rendered.errors[0].address, Some(4));
+        assert_eq!(rendered.errors[0].raw_bytes, [0xff, 0xff]);
+    }
+
+    #[test]
+    fn test_supported_architectures_lists_registered_handlers() {
+        assert!(supported_architectures().iter().any(|name| name == "riscv"));
+    }
+}
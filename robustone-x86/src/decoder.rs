@@ -14,6 +14,9 @@ pub enum X86Mode {
     X64,
 }
 
+/// Mnemonics produced by [`X86Decoder`].
+pub const MNEMONICS: &[&str] = &["mov", "nop", "pop", "push", "ret"];
+
 /// Minimal x86 decoder.
 pub struct X86Decoder {
     mode: X86Mode,
@@ -31,11 +34,7 @@ impl X86Decoder {
         addr: u64,
     ) -> Result<DecodedInstruction, DisasmError> {
         if bytes.is_empty() {
-            return Err(DisasmError::DecodeFailure {
-                kind: DecodeErrorKind::NeedMoreBytes,
-                architecture: Some("x86".to_string()),
-                detail: "empty input".to_string(),
-            });
+            return Err(DisasmError::truncated(Some("x86".to_string()), 1, 0));
         }
 
         let opcode = bytes[0];
@@ -71,11 +70,11 @@ impl X86Decoder {
             0xB8..=0xBF => {
                 let reg = opcode - 0xB8;
                 if bytes.len() < 5 {
-                    return Err(DisasmError::DecodeFailure {
-                        kind: DecodeErrorKind::NeedMoreBytes,
-                        architecture: Some("x86".to_string()),
-                        detail: "need 5 bytes for mov imm32".to_string(),
-                    });
+                    return Err(DisasmError::truncated(
+                        Some("x86".to_string()),
+                        5,
+                        bytes.len(),
+                    ));
                 }
                 let imm = i64::from(u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]));
                 (
@@ -110,6 +109,7 @@ impl X86Decoder {
             size,
             raw_bytes: bytes[..size].to_vec(),
             operands,
+            operand_access: Vec::new(),
             registers_read: Vec::new(),
             registers_written: Vec::new(),
             implicit_registers_read: Vec::new(),
@@ -117,6 +117,7 @@ impl X86Decoder {
             groups: Vec::new(),
             status: DecodeStatus::Success,
             render_hints: RenderHints::default(),
+            decoded_by: None,
             render: Some(crate::render::render_x86_text_parts),
         })
     }
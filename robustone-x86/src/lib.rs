@@ -6,8 +6,12 @@ pub mod decoder;
 pub mod render;
 
 use decoder::{X86Decoder, X86Mode};
+pub use render::X86Syntax;
 use robustone_core::{
-    Instruction, common::ArchitectureProfile, ir::DecodedInstruction, traits::ArchitectureHandler,
+    Instruction, canonical_architecture_name,
+    common::ArchitectureProfile,
+    ir::DecodedInstruction,
+    traits::{ArchitectureHandler, MnemonicGroup},
     types::error::DisasmError,
 };
 
@@ -15,6 +19,7 @@ use robustone_core::{
 pub struct X86Handler {
     x86_decoder: X86Decoder,
     x64_decoder: X86Decoder,
+    syntax: X86Syntax,
 }
 
 impl X86Handler {
@@ -23,13 +28,26 @@ impl X86Handler {
         Self {
             x86_decoder: X86Decoder::new(X86Mode::X86),
             x64_decoder: X86Decoder::new(X86Mode::X64),
+            syntax: X86Syntax::Intel,
         }
     }
 
+    /// Selects the operand syntax [`ArchitectureHandler::disassemble`]
+    /// renders; Intel by default.
+    pub fn with_syntax(mut self, syntax: X86Syntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+
+    /// The operand syntax of rendered instructions.
+    pub fn syntax(&self) -> X86Syntax {
+        self.syntax
+    }
+
     fn decoder_for_arch(&self, arch_name: &str) -> Result<&X86Decoder, DisasmError> {
-        match arch_name {
-            "x86" | "x32" | "i386" => Ok(&self.x86_decoder),
-            "x64" | "x86_64" | "amd64" => Ok(&self.x64_decoder),
+        match canonical_architecture_name(arch_name) {
+            Some("x32") => Ok(&self.x86_decoder),
+            Some("x64") => Ok(&self.x64_decoder),
             _ => Err(DisasmError::UnsupportedArchitecture(arch_name.to_string())),
         }
     }
@@ -74,7 +92,7 @@ impl ArchitectureHandler for X86Handler {
         let (decoded, size) = self.decode_instruction(bytes, arch_name, addr)?;
         let (mnemonic, operands) = render::render_x86_text_parts(
             &decoded,
-            robustone_core::ir::TextRenderProfile::Capstone,
+            self.syntax.text_profile(),
             true,
             true,
             true,
@@ -98,10 +116,14 @@ impl ArchitectureHandler for X86Handler {
     }
 
     fn supports(&self, arch_name: &str) -> bool {
-        matches!(
-            arch_name,
-            "x86" | "x32" | "i386" | "x64" | "x86_64" | "amd64"
-        )
+        self.decoder_for_arch(arch_name).is_ok()
+    }
+
+    fn supported_mnemonics(&self, _arch_name: &str) -> Vec<MnemonicGroup> {
+        vec![MnemonicGroup {
+            name: "base",
+            mnemonics: decoder::MNEMONICS.to_vec(),
+        }]
     }
 }
 
@@ -141,4 +163,25 @@ mod tests {
         assert_eq!(instr.mnemonic, "mov");
         assert_eq!(instr.operands, "eax, 0x12345678");
     }
+
+    #[test]
+    fn test_att_syntax_reverses_operands_and_adds_sigils() {
+        let handler = X86Handler::new().with_syntax(X86Syntax::Att);
+        let (instr, _) = handler
+            .disassemble(&[0xB8, 0x78, 0x56, 0x34, 0x12], "x86", 0)
+            .unwrap();
+        assert_eq!(instr.mnemonic, "mov");
+        assert_eq!(instr.operands, "$0x12345678, %eax");
+
+        // The decoded IR re-renders in either syntax.
+        let decoded = instr.decoded.as_ref().unwrap();
+        assert_eq!(
+            decoded.render_text_parts(X86Syntax::Intel.text_profile()).1,
+            "eax, 0x12345678"
+        );
+        assert_eq!(
+            decoded.render_text_parts(X86Syntax::Att.text_profile()).1,
+            "$0x12345678, %eax"
+        );
+    }
 }
@@ -1,32 +1,74 @@
 //! x86/x64 instruction text rendering.
+//!
+//! Intel syntax is the default, as in Capstone. [`TextRenderProfile::Att`]
+//! selects AT&T syntax in the style of GNU objdump: source operand first,
+//! `%` on registers, `$` on immediates, and no operand-size suffix where a
+//! register operand already fixes the size.
 
-use robustone_core::ir::{DecodedInstruction, TextRenderProfile};
+use robustone_core::ir::{DecodedInstruction, Operand, TextRenderProfile};
+
+/// Operand syntax of the x86 printer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum X86Syntax {
+    /// `mov eax, 0x1` (Capstone's default).
+    #[default]
+    Intel,
+    /// `mov $0x1, %eax`.
+    Att,
+}
+
+impl X86Syntax {
+    /// The shared text profile that selects this syntax.
+    pub fn text_profile(self) -> TextRenderProfile {
+        match self {
+            Self::Intel => TextRenderProfile::Capstone,
+            Self::Att => TextRenderProfile::Att,
+        }
+    }
+
+    fn from_profile(profile: TextRenderProfile) -> Self {
+        match profile {
+            TextRenderProfile::Att => Self::Att,
+            _ => Self::Intel,
+        }
+    }
+}
 
 /// Render an x86 decoded instruction into mnemonic and operand text.
 pub fn render_x86_text_parts(
     instruction: &DecodedInstruction,
-    _profile: TextRenderProfile,
+    profile: TextRenderProfile,
     _alias_regs: bool,
     _capstone_aliases: bool,
     _compressed_aliases: bool,
     _unsigned_immediate: bool,
 ) -> (String, String) {
-    let operands = instruction
-        .operands
-        .iter()
-        .map(format_x86_operand)
-        .collect::<Vec<_>>()
-        .join(", ");
+    let operands = match X86Syntax::from_profile(profile) {
+        X86Syntax::Intel => instruction
+            .operands
+            .iter()
+            .map(format_x86_operand)
+            .collect::<Vec<_>>()
+            .join(", "),
+        X86Syntax::Att => instruction
+            .operands
+            .iter()
+            .rev()
+            .map(format_att_operand)
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
     (instruction.mnemonic.clone(), operands)
 }
 
-fn format_x86_operand(operand: &robustone_core::ir::Operand) -> String {
-    use robustone_core::ir::Operand;
+fn format_x86_operand(operand: &Operand) -> String {
     match operand {
         Operand::Register { register } => x86_register_name(register.id),
         Operand::Immediate { value } => format!("0x{value:x}"),
         Operand::Text { value } => value.clone(),
-        Operand::Memory { base, displacement } => {
+        Operand::Memory {
+            base, displacement, ..
+        } => {
             if let Some(base) = base {
                 format!(
                     "[{}{}]",
@@ -37,6 +79,29 @@ fn format_x86_operand(operand: &robustone_core::ir::Operand) -> String {
                 format!("[{}]", displacement)
             }
         }
+        Operand::SystemRegister { number } => format!("0x{number:x}"),
+    }
+}
+
+fn format_att_operand(operand: &Operand) -> String {
+    match operand {
+        Operand::Register { register } => format!("%{}", x86_register_name(register.id)),
+        Operand::Immediate { value } => format!("$0x{value:x}"),
+        Operand::Text { value } => value.clone(),
+        Operand::Memory {
+            base, displacement, ..
+        } => {
+            let disp = match *displacement {
+                0 if base.is_some() => String::new(),
+                disp if disp < 0 => format!("-0x{:x}", disp.unsigned_abs()),
+                disp => format!("0x{disp:x}"),
+            };
+            match base {
+                Some(base) => format!("{disp}(%{})", x86_register_name(base.id)),
+                None => disp,
+            }
+        }
+        Operand::SystemRegister { number } => format!("$0x{number:x}"),
     }
 }
 
@@ -0,0 +1,313 @@
+//! Capstone-shaped types for projects migrating from the `capstone` crate.
+//!
+//! Code written against `capstone` usually keeps its handle configuration
+//! (architecture, mode, extra modes, detail, SKIPDATA) in one place and walks
+//! `Insn` values everywhere else. [`CsConfig`] mirrors the first and converts
+//! into a [`Disassembler`]; [`Insn`] mirrors the second and converts from and
+//! to [`Instruction`], so call sites can move over one at a time.
+//!
+//! ```rust
+//! use robustone::Disassembler;
+//! use robustone::capstone::{Arch, CsConfig, Insn, Mode};
+//!
+//! let config = CsConfig::new(Arch::RiscV, Mode::RiscV64).detail(true);
+//! let disassembler = Disassembler::try_from(&config).unwrap();
+//! let instructions = disassembler
+//!     .disassemble_all(&[0x13, 0x05, 0x50, 0x00], 0x1000)
+//!     .unwrap();
+//! let insn = Insn::from(&instructions[0]);
+//! assert_eq!(insn.mnemonic(), Some("li"));
+//! assert_eq!(insn.op_str(), Some("a0, 5"));
+//! assert_eq!(insn.to_string(), "0x1000: li a0, 5");
+//! ```
+
+use crate::{DisasmError, Disassembler, Instruction, OnDecodeError};
+use std::fmt;
+
+/// Architecture, as in `capstone::Arch`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Arch {
+    Arm,
+    Arm64,
+    X86,
+    RiscV,
+    LoongArch,
+}
+
+/// Architecture mode, as in `capstone::Mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// 32-bit ARM, or the default mode of [`Arch::Arm64`].
+    Arm,
+    Thumb,
+    Mode16,
+    Mode32,
+    Mode64,
+    RiscV32,
+    RiscV64,
+    LoongArch32,
+    LoongArch64,
+}
+
+/// Extra mode flags, as in `capstone::ExtraMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtraMode {
+    /// ARM Cortex-M profile (`+m`).
+    MClass,
+    /// ARMv8 A32 encodings (`+v8`).
+    V8,
+    /// RISC-V compressed instructions (`+c`).
+    RiscVC,
+}
+
+/// Byte order, as in `capstone::Endian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
+/// Handle configuration in the shape of `capstone::Capstone::new()...build()`.
+///
+/// Converts into a [`Disassembler`] with [`TryFrom`]; combinations Robustone
+/// does not decode fail with [`DisasmError::UnsupportedArchitecture`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsConfig {
+    pub arch: Arch,
+    pub mode: Mode,
+    pub extra_mode: Vec<ExtraMode>,
+    pub endian: Endian,
+    /// `CS_OPT_DETAIL`.
+    pub detail: bool,
+    /// `CS_OPT_SKIPDATA`.
+    pub skipdata: bool,
+}
+
+impl CsConfig {
+    /// A little-endian configuration without extra modes, detail, or SKIPDATA.
+    pub fn new(arch: Arch, mode: Mode) -> Self {
+        Self {
+            arch,
+            mode,
+            extra_mode: Vec::new(),
+            endian: Endian::Little,
+            detail: false,
+            skipdata: false,
+        }
+    }
+
+    /// Sets the extra mode flags.
+    pub fn extra_mode(mut self, extra_mode: impl IntoIterator<Item = ExtraMode>) -> Self {
+        self.extra_mode = extra_mode.into_iter().collect();
+        self
+    }
+
+    /// Sets the byte order.
+    pub fn endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
+
+    /// Turns `CS_OPT_DETAIL` on or off.
+    pub fn detail(mut self, detail: bool) -> Self {
+        self.detail = detail;
+        self
+    }
+
+    /// Turns `CS_OPT_SKIPDATA` on or off.
+    pub fn skipdata(mut self, skipdata: bool) -> Self {
+        self.skipdata = skipdata;
+        self
+    }
+
+    /// The `<arch>[+modifier...]` string [`Disassembler::new`] takes for
+    /// this configuration.
+    pub fn arch_string(&self) -> Result<String, DisasmError> {
+        let base = match (self.arch, self.mode) {
+            (Arch::Arm, Mode::Arm) => "arm",
+            (Arch::Arm, Mode::Thumb) => "thumb",
+            (Arch::Arm64, Mode::Arm) => "aarch64",
+            (Arch::X86, Mode::Mode16) => "x16",
+            (Arch::X86, Mode::Mode32) => "x32",
+            (Arch::X86, Mode::Mode64) => "x64",
+            (Arch::RiscV, Mode::RiscV32) => "riscv32",
+            (Arch::RiscV, Mode::RiscV64) => "riscv64",
+            (Arch::LoongArch, Mode::LoongArch32 | Mode::LoongArch64) => "loongarch64",
+            (arch, mode) => {
+                return Err(DisasmError::UnsupportedArchitecture(format!(
+                    "{arch:?} in {mode:?} mode"
+                )));
+            }
+        };
+
+        let mut arch = base.to_string();
+        for extra in &self.extra_mode {
+            arch.push_str(match extra {
+                ExtraMode::MClass => "+m",
+                ExtraMode::V8 => "+v8",
+                ExtraMode::RiscVC => "+c",
+            });
+        }
+        if self.endian == Endian::Big {
+            arch.push_str("+be");
+        }
+        Ok(arch)
+    }
+}
+
+impl TryFrom<&CsConfig> for Disassembler {
+    type Error = DisasmError;
+
+    fn try_from(config: &CsConfig) -> Result<Self, Self::Error> {
+        Ok(Disassembler::new(&config.arch_string()?)?
+            .with_detail(config.detail)
+            .with_on_decode_error(OnDecodeError::from_skip_data(config.skipdata)))
+    }
+}
+
+impl TryFrom<CsConfig> for Disassembler {
+    type Error = DisasmError;
+
+    fn try_from(config: CsConfig) -> Result<Self, Self::Error> {
+        Disassembler::try_from(&config)
+    }
+}
+
+/// One disassembled instruction in the shape of `capstone::Insn`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Insn {
+    address: u64,
+    bytes: Vec<u8>,
+    mnemonic: String,
+    op_str: String,
+}
+
+impl Insn {
+    /// Address of the instruction.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// Encoded bytes of the instruction.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Size of the instruction in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Whether the instruction covers no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The mnemonic; `Option` like capstone's, but always set.
+    pub fn mnemonic(&self) -> Option<&str> {
+        Some(&self.mnemonic)
+    }
+
+    /// The operand text; `Option` like capstone's, but always set.
+    pub fn op_str(&self) -> Option<&str> {
+        Some(&self.op_str)
+    }
+}
+
+impl From<&Instruction> for Insn {
+    fn from(instruction: &Instruction) -> Self {
+        Self {
+            address: instruction.address,
+            bytes: instruction.bytes.clone(),
+            mnemonic: instruction.mnemonic.clone(),
+            op_str: instruction.operands.clone(),
+        }
+    }
+}
+
+impl From<Instruction> for Insn {
+    fn from(instruction: Instruction) -> Self {
+        Self {
+            address: instruction.address,
+            bytes: instruction.bytes,
+            mnemonic: instruction.mnemonic,
+            op_str: instruction.operands,
+        }
+    }
+}
+
+/// Text-only instruction; the shared IR and detail are not recovered.
+impl From<Insn> for Instruction {
+    fn from(insn: Insn) -> Self {
+        Instruction::new(insn.address, insn.bytes, insn.mnemonic, insn.op_str)
+    }
+}
+
+/// Formats as capstone's `Insn` does: `0x1000: li a0, 5`.
+impl fmt::Display for Insn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}: {}", self.address, self.mnemonic)?;
+        if !self.op_str.is_empty() {
+            write!(f, " {}", self.op_str)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_maps_to_arch_string() {
+        let config = CsConfig::new(Arch::Arm, Mode::Thumb).extra_mode([ExtraMode::MClass]);
+        assert_eq!(config.arch_string().unwrap(), "thumb+m");
+        let config = CsConfig::new(Arch::Arm64, Mode::Arm).endian(Endian::Big);
+        assert_eq!(config.arch_string().unwrap(), "aarch64+be");
+        assert_eq!(
+            CsConfig::new(Arch::X86, Mode::Mode64)
+                .arch_string()
+                .unwrap(),
+            "x64"
+        );
+
+        let error = CsConfig::new(Arch::X86, Mode::RiscV64)
+            .arch_string()
+            .unwrap_err();
+        assert_eq!(error.stable_kind(), "unsupported_architecture");
+    }
+
+    #[test]
+    fn test_config_builds_disassembler() {
+        let config = CsConfig::new(Arch::RiscV, Mode::RiscV32)
+            .extra_mode([ExtraMode::RiscVC])
+            .skipdata(true);
+        let disassembler = Disassembler::try_from(config).unwrap();
+        assert_eq!(disassembler.arch(), "riscv32");
+        assert!(disassembler.spec().has_option("c"));
+        assert!(disassembler.on_decode_error().emits_data());
+
+        let instructions = disassembler
+            .disassemble_all(&[0xff, 0xff, 0xff, 0xff, 0x82, 0x80], 0x1000)
+            .unwrap();
+        assert!(instructions[0].detail.is_none());
+        assert_eq!(instructions[0].mnemonic, ".byte");
+    }
+
+    #[test]
+    fn test_insn_round_trips_instruction_text() {
+        let disassembler = Disassembler::new("riscv64").unwrap();
+        let (instruction, _) = disassembler.disassemble(&[0x82, 0x80], 0x2000).unwrap();
+        let insn = Insn::from(&instruction);
+        assert_eq!(insn.address(), 0x2000);
+        assert_eq!(insn.bytes(), [0x82, 0x80]);
+        assert_eq!(insn.len(), 2);
+        assert_eq!(insn.to_string(), format!("0x2000: {instruction}"));
+
+        let back = Instruction::from(insn.clone());
+        assert_eq!(back.to_string(), instruction.to_string());
+        assert_eq!(back.size, 2);
+        assert_eq!(Insn::from(instruction), insn);
+    }
+}
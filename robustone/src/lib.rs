@@ -1,22 +1,347 @@
 //! Robustone meta-crate.
+//!
+//! Re-exports the core engine and every architecture backend enabled through
+//! Cargo features (`riscv`, `arm`, `x86`, `loongarch`, plus additive
+//! `riscv-*` extension features); all are on by default.
+
+use std::sync::LazyLock;
+
+#[cfg(feature = "capstone-interop")]
+pub mod capstone;
+mod listing;
+
+pub use listing::Listing;
 
 #[doc(inline)]
 pub use robustone_core::*;
 
+#[cfg(feature = "arm")]
 #[doc(inline)]
 pub use robustone_arm as arm;
+#[cfg(feature = "loongarch")]
 #[doc(inline)]
 pub use robustone_loongarch as loongarch;
+#[cfg(feature = "riscv")]
 #[doc(inline)]
 pub use robustone_riscv as riscv;
+#[cfg(feature = "x86")]
 #[doc(inline)]
 pub use robustone_x86 as x86;
 
+/// The stable surface of [`robustone_core::prelude`] plus this crate's
+/// [`Disassembler`] handle and dispatcher constructors.
+pub mod prelude {
+    pub use crate::{Disassembler, Listing, StreamStep, dispatcher, shared_dispatcher};
+    pub use robustone_core::prelude::v2::*;
+}
+
+/// Builds a new dispatcher with every compiled-in architecture handler.
+///
+/// Each call constructs the handlers' decode tables from scratch; prefer
+/// [`shared_dispatcher`] or a [`Disassembler`] for repeated calls.
 pub fn dispatcher() -> ArchitectureDispatcher {
+    // `mut` goes unused when every backend feature is disabled.
+    #[allow(unused_mut)]
     let mut dispatcher = ArchitectureDispatcher::new();
+    #[cfg(feature = "riscv")]
     dispatcher.register(Box::new(riscv::RiscVHandler::new()));
+    #[cfg(feature = "arm")]
     dispatcher.register(Box::new(arm::ArmHandler::new()));
+    #[cfg(feature = "x86")]
     dispatcher.register(Box::new(x86::X86Handler::new()));
+    #[cfg(feature = "loongarch")]
     dispatcher.register(Box::new(loongarch::LoongArchHandler::new()));
     dispatcher
 }
+
+static SHARED_DISPATCHER: LazyLock<ArchitectureDispatcher> = LazyLock::new(dispatcher);
+
+/// Process-wide dispatcher with every bundled handler, built on first use.
+///
+/// Handlers produce instruction detail; [`Disassembler`] can drop it per call.
+pub fn shared_dispatcher() -> &'static ArchitectureDispatcher {
+    &SHARED_DISPATCHER
+}
+
+/// Outcome of [`Disassembler::disassemble_streaming`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum StreamStep {
+    /// The instruction at the start of the buffer and its size in bytes.
+    Instruction(Box<Instruction>, usize),
+    /// The buffer ends inside an instruction: read this many more bytes and
+    /// retry from the same offset. For the rare encodings whose length is
+    /// not known from the bytes so far, this is the minimum (see
+    /// [`DisasmError::bytes_needed`]).
+    NeedMoreData(usize),
+}
+
+/// A cheap handle bound to one architecture, in the spirit of Capstone's `csh`.
+///
+/// The handle only records the parsed architecture and options; decoding goes
+/// through [`shared_dispatcher`], so creating and dropping handles costs
+/// nothing beyond parsing the architecture string. Handles are `Send + Sync`
+/// and `Clone`, so server threads can share one or each keep their own.
+///
+/// ```rust
+/// use robustone::Disassembler;
+///
+/// let disassembler = Disassembler::new("rv64gc").unwrap();
+/// assert_eq!(disassembler.arch(), "riscv64");
+///
+/// let instructions = disassembler
+///     .disassemble_all(&[0x13, 0x05, 0x50, 0x00, 0x82, 0x80], 0x1000)
+///     .unwrap();
+/// assert_eq!(instructions[0].to_string(), "li a0, 5");
+/// assert_eq!(instructions[1].address, 0x1004);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Disassembler {
+    spec: ArchSpec,
+    detail: bool,
+    on_decode_error: OnDecodeError,
+}
+
+impl Disassembler {
+    /// Creates a handle for an `<arch>[+modifier...]` string.
+    ///
+    /// Fails with [`DisasmError::UnsupportedArchitecture`] when the string
+    /// does not parse or no bundled handler decodes the architecture.
+    pub fn new(arch: &str) -> Result<Self, DisasmError> {
+        let spec = ArchSpec::parse(arch)
+            .map_err(|_| DisasmError::UnsupportedArchitecture(arch.to_string()))?;
+        if !shared_dispatcher().supports_architecture(spec.name()) {
+            return Err(DisasmError::UnsupportedArchitecture(arch.to_string()));
+        }
+        Ok(Self {
+            spec,
+            detail: true,
+            on_decode_error: OnDecodeError::Stop,
+        })
+    }
+
+    /// Keeps (`true`, the default) or drops instruction detail.
+    pub fn with_detail(mut self, detail: bool) -> Self {
+        self.detail = detail;
+        self
+    }
+
+    /// Chooses what [`Self::disassemble_all`] does with bytes that fail to
+    /// decode; the default is [`OnDecodeError::Stop`].
+    ///
+    /// ```rust
+    /// use robustone::{DataStep, Disassembler, OnDecodeError};
+    ///
+    /// let disassembler = Disassembler::new("riscv32")
+    ///     .unwrap()
+    ///     .with_on_decode_error(OnDecodeError::Emit(DataStep::Resync));
+    /// let instructions = disassembler
+    ///     .disassemble_all(&[0xff, 0xff, 0xff, 0xff, 0x82, 0x80], 0)
+    ///     .unwrap();
+    /// assert_eq!(instructions[0].mnemonic, ".byte");
+    /// assert_eq!(instructions[2].to_string(), "jr ra");
+    /// ```
+    pub fn with_on_decode_error(mut self, policy: OnDecodeError) -> Self {
+        self.on_decode_error = policy;
+        self
+    }
+
+    /// The policy [`Self::disassemble_all`] applies to decode failures.
+    pub fn on_decode_error(&self) -> OnDecodeError {
+        self.on_decode_error
+    }
+
+    /// Canonical name of the handle's architecture.
+    pub fn arch(&self) -> &'static str {
+        self.spec.name()
+    }
+
+    /// The parsed architecture string, including its `+` options.
+    ///
+    /// Options are recorded for the caller; decoding uses the architecture's
+    /// default profile.
+    pub fn spec(&self) -> &ArchSpec {
+        &self.spec
+    }
+
+    /// Decodes the first instruction in `bytes` into the shared IR.
+    pub fn decode(
+        &self,
+        bytes: &[u8],
+        address: u64,
+    ) -> Result<(DecodedInstruction, usize), DisasmError> {
+        shared_dispatcher().decode_instruction(bytes, self.arch(), address)
+    }
+
+    /// Reports the extension, lowest privilege level, and approximate
+    /// latency class of a decoded instruction.
+    ///
+    /// Returns `None` when the architecture does not publish metadata.
+    ///
+    /// ```rust
+    /// use robustone::{Disassembler, LatencyClass};
+    ///
+    /// let disassembler = Disassembler::new("riscv64").unwrap();
+    /// // divu a0, a0, a1
+    /// let (decoded, _) = disassembler.decode(&[0x33, 0x55, 0xb5, 0x02], 0).unwrap();
+    /// let metadata = disassembler.metadata(&decoded).unwrap();
+    /// assert_eq!(metadata.extension, "M");
+    /// assert_eq!(metadata.latency, LatencyClass::Long);
+    /// ```
+    pub fn metadata(&self, decoded: &DecodedInstruction) -> Option<InstructionMetadata> {
+        shared_dispatcher()
+            .get_handler(self.arch())?
+            .instruction_metadata(decoded)
+    }
+
+    /// Disassembles the first instruction in `bytes`.
+    pub fn disassemble(
+        &self,
+        bytes: &[u8],
+        address: u64,
+    ) -> Result<(Instruction, usize), DisasmError> {
+        let (mut instruction, size) =
+            shared_dispatcher().disassemble_bytes(bytes, self.arch(), address)?;
+        if !self.detail {
+            instruction.detail = None;
+        }
+        Ok((instruction, size))
+    }
+
+    /// Disassembles the first instruction of a buffer that is still being
+    /// filled, such as one read from a socket or a file in chunks.
+    ///
+    /// When the buffer ends inside an instruction this returns
+    /// [`StreamStep::NeedMoreData`] with the number of bytes to read before
+    /// retrying, instead of a [`DisasmError::Truncated`] error. Other decode
+    /// failures are returned as errors.
+    ///
+    /// ```rust
+    /// use robustone::{Disassembler, StreamStep};
+    ///
+    /// let disassembler = Disassembler::new("riscv64").unwrap();
+    /// // The first half of `addi a0, zero, 5`.
+    /// let step = disassembler.disassemble_streaming(&[0x13, 0x05], 0x1000).unwrap();
+    /// assert_eq!(step, StreamStep::NeedMoreData(2));
+    ///
+    /// let step = disassembler
+    ///     .disassemble_streaming(&[0x13, 0x05, 0x50, 0x00], 0x1000)
+    ///     .unwrap();
+    /// assert!(matches!(step, StreamStep::Instruction(_, 4)));
+    /// ```
+    pub fn disassemble_streaming(
+        &self,
+        bytes: &[u8],
+        address: u64,
+    ) -> Result<StreamStep, DisasmError> {
+        match self.disassemble(bytes, address) {
+            Ok((instruction, size)) => Ok(StreamStep::Instruction(Box::new(instruction), size)),
+            Err(error) => match error.bytes_needed() {
+                Some(more) => Ok(StreamStep::NeedMoreData(more)),
+                None => Err(error),
+            },
+        }
+    }
+
+    /// Disassembles `bytes` into a [`Listing`] that can be patched and
+    /// re-decoded incrementally.
+    pub fn listing(&self, bytes: Vec<u8>, address: u64) -> Listing {
+        Listing::new(self.clone(), bytes, address)
+    }
+
+    /// Disassembles `bytes` back to back, handling decode failures as
+    /// [`Self::with_on_decode_error`] chose: by default the first failure
+    /// ends the run.
+    ///
+    /// [`OnDecodeError::Callback`] has no callback here and stops; use
+    /// [`Self::disassemble_all_with`] to decide per failure.
+    pub fn disassemble_all(
+        &self,
+        bytes: &[u8],
+        address: u64,
+    ) -> Result<Vec<Instruction>, DisasmError> {
+        let policy = self.on_decode_error;
+        self.disassemble_all_with(bytes, address, |_, _| policy)
+    }
+
+    /// Disassembles `bytes` back to back, asking `on_error` with the input
+    /// offset and error what to do about each decode failure.
+    ///
+    /// `on_error` answering [`OnDecodeError::Callback`] stops the run.
+    pub fn disassemble_all_with<F>(
+        &self,
+        bytes: &[u8],
+        address: u64,
+        on_error: F,
+    ) -> Result<Vec<Instruction>, DisasmError>
+    where
+        F: FnMut(usize, &DisasmError) -> OnDecodeError,
+    {
+        match self.disassemble_run(bytes, address, on_error) {
+            (instructions, None) => Ok(instructions),
+            (_, Some(error)) => Err(error),
+        }
+    }
+
+    /// Like [`Self::disassemble_all`], but keeps the instructions decoded
+    /// before a failure that ends the run and returns them with the
+    /// (located) error.
+    ///
+    /// ```rust
+    /// use robustone::Disassembler;
+    ///
+    /// let disassembler = Disassembler::new("riscv32").unwrap();
+    /// let (instructions, error) =
+    ///     disassembler.disassemble_partial(&[0x13, 0x05, 0x50, 0x00, 0xff, 0xff], 0);
+    /// assert_eq!(instructions[0].to_string(), "li a0, 5");
+    /// assert_eq!(error.unwrap().location().unwrap().offset, 4);
+    /// ```
+    pub fn disassemble_partial(
+        &self,
+        bytes: &[u8],
+        address: u64,
+    ) -> (Vec<Instruction>, Option<DisasmError>) {
+        let policy = self.on_decode_error;
+        self.disassemble_run(bytes, address, |_, _| policy)
+    }
+
+    fn disassemble_run<F>(
+        &self,
+        bytes: &[u8],
+        address: u64,
+        mut on_error: F,
+    ) -> (Vec<Instruction>, Option<DisasmError>)
+    where
+        F: FnMut(usize, &DisasmError) -> OnDecodeError,
+    {
+        let mut instructions = Vec::new();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let current = address.wrapping_add(offset as u64);
+            let rest = &bytes[offset..];
+            let error = match self.disassemble(rest, current) {
+                Ok((_, 0)) => {
+                    let error = DisasmError::DecodingError(
+                        "Decoder returned zero-length instruction".to_string(),
+                    );
+                    return (instructions, Some(error.at(offset, current, rest)));
+                }
+                Ok((instruction, size)) => {
+                    offset += size;
+                    instructions.push(instruction);
+                    continue;
+                }
+                Err(error) => error,
+            };
+
+            let policy = on_error(offset, &error);
+            let Some(size) = policy.step(self.arch(), current, rest.len()) else {
+                return (instructions, Some(error.at(offset, current, rest)));
+            };
+            if policy.emits_data() {
+                instructions.push(listing::byte_item(current, &rest[..size]));
+            }
+            offset += size;
+        }
+        (instructions, None)
+    }
+}
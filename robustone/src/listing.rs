@@ -0,0 +1,243 @@
+//! Patchable listings that re-decode only what a patch touches.
+
+use crate::{DisasmError, Disassembler, Instruction};
+use std::ops::Range;
+
+/// A disassembled buffer that can be patched in place.
+///
+/// The listing is a linear sweep: bytes that do not decode become `.byte`
+/// items (two bytes at a time on RISC-V, one elsewhere) and decoding resumes
+/// after them. [`apply_patch`](Self::apply_patch) overwrites bytes and
+/// re-decodes from the instruction containing the patch until the new
+/// instruction boundaries line up with the old ones again, so the cost of a
+/// patch is proportional to the window it disturbs rather than to the buffer.
+///
+/// ```rust
+/// use robustone::Disassembler;
+///
+/// let disassembler = Disassembler::new("riscv64").unwrap();
+/// // li a0, 5; ret
+/// let mut listing = disassembler.listing(vec![0x13, 0x05, 0x50, 0x00, 0x82, 0x80], 0x1000);
+/// assert_eq!(listing.instructions()[0].to_string(), "li a0, 5");
+///
+/// // li a0, 7
+/// let changed = listing.apply_patch(0x1002, &[0x70, 0x00]).unwrap();
+/// assert_eq!(changed, 0..1);
+/// assert_eq!(listing.instructions()[0].to_string(), "li a0, 7");
+/// ```
+#[derive(Debug)]
+pub struct Listing {
+    disassembler: Disassembler,
+    address: u64,
+    bytes: Vec<u8>,
+    instructions: Vec<Instruction>,
+}
+
+impl Listing {
+    pub(crate) fn new(disassembler: Disassembler, bytes: Vec<u8>, address: u64) -> Self {
+        let mut listing = Self {
+            disassembler,
+            address,
+            bytes,
+            instructions: Vec::new(),
+        };
+        let (instructions, _) = listing.sweep(0, None);
+        listing.instructions = instructions;
+        listing
+    }
+
+    /// Address of the first byte.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The current bytes, including every applied patch.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The decoded items in address order.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Index of the item covering `address`, if it is inside the listing.
+    pub fn index_of(&self, address: u64) -> Option<usize> {
+        let offset = self.offset_of(address)?;
+        let index = self
+            .instructions
+            .partition_point(|instruction| self.item_offset(instruction) <= offset);
+        index.checked_sub(1)
+    }
+
+    /// Overwrite the bytes at `address` with `patch` and re-decode the
+    /// affected window.
+    ///
+    /// Returns the index range of [`instructions`](Self::instructions) that
+    /// now holds the re-decoded items; everything outside it is unchanged.
+    /// Fails with [`DisasmError::InvalidAddress`] when the patch does not lie
+    /// entirely inside the listing, leaving the listing untouched.
+    pub fn apply_patch(&mut self, address: u64, patch: &[u8]) -> Result<Range<usize>, DisasmError> {
+        let start = self
+            .offset_of(address)
+            .filter(|start| start + patch.len() <= self.bytes.len())
+            .ok_or_else(|| {
+                DisasmError::InvalidAddress(format!(
+                    "patch of {} bytes at 0x{address:x} is outside the listing",
+                    patch.len()
+                ))
+            })?;
+        self.bytes[start..start + patch.len()].copy_from_slice(patch);
+
+        let first = self
+            .instructions
+            .partition_point(|instruction| self.item_offset(instruction) <= start)
+            .saturating_sub(1);
+        let resume = self
+            .instructions
+            .get(first)
+            .map_or(0, |instruction| self.item_offset(instruction));
+        let (decoded, sync) = self.sweep(resume, Some(start + patch.len()));
+
+        // The first old item starting at the offset where the sweep stopped.
+        let last = match sync {
+            Some(sync) => self
+                .instructions
+                .partition_point(|instruction| self.item_offset(instruction) < sync),
+            None => self.instructions.len(),
+        };
+        let replaced = decoded.len();
+        self.instructions.splice(first..last, decoded);
+        Ok(first..first + replaced)
+    }
+
+    /// Decode from `offset`. With `patch_end`, stop at the first boundary at
+    /// or past it that is also an item boundary of the current listing, and
+    /// return that boundary.
+    fn sweep(
+        &self,
+        mut offset: usize,
+        patch_end: Option<usize>,
+    ) -> (Vec<Instruction>, Option<usize>) {
+        let mut items = Vec::new();
+        while offset < self.bytes.len() {
+            if let Some(end) = patch_end
+                && offset >= end
+                && self.is_boundary(offset)
+            {
+                return (items, Some(offset));
+            }
+            let address = self.address.wrapping_add(offset as u64);
+            let rest = &self.bytes[offset..];
+            let size = match self.disassembler.disassemble(rest, address) {
+                Ok((instruction, size)) if size > 0 => {
+                    items.push(instruction);
+                    size
+                }
+                _ => {
+                    let size = self.resync_step().min(rest.len());
+                    items.push(byte_item(address, &rest[..size]));
+                    size
+                }
+            };
+            offset += size;
+        }
+        (items, None)
+    }
+
+    fn is_boundary(&self, offset: usize) -> bool {
+        self.instructions
+            .binary_search_by_key(&offset, |instruction| self.item_offset(instruction))
+            .is_ok()
+    }
+
+    /// Bytes skipped after an undecodable position.
+    fn resync_step(&self) -> usize {
+        if self.disassembler.spec().capability.category == "RISC-V" {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn offset_of(&self, address: u64) -> Option<usize> {
+        let offset = usize::try_from(address.wrapping_sub(self.address)).ok()?;
+        (offset < self.bytes.len()).then_some(offset)
+    }
+
+    fn item_offset(&self, instruction: &Instruction) -> usize {
+        instruction.address.wrapping_sub(self.address) as usize
+    }
+}
+
+/// `.byte` item for bytes that did not decode.
+pub(crate) fn byte_item(address: u64, bytes: &[u8]) -> Instruction {
+    let operands = bytes
+        .iter()
+        .map(|byte| format!("0x{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Instruction::new(address, bytes.to_vec(), ".byte".to_string(), operands)
+}
+
+#[cfg(all(test, feature = "riscv", feature = "x86"))]
+mod tests {
+    use super::*;
+
+    fn text(listing: &Listing) -> Vec<String> {
+        listing
+            .instructions()
+            .iter()
+            .map(|instruction| format!("{:x}: {instruction}", instruction.address))
+            .collect()
+    }
+
+    #[test]
+    fn test_patch_matches_a_full_re_disassembly() {
+        let disassembler = Disassembler::new("riscv32").unwrap();
+        // nop; nop; c.nop; nop; nop
+        let original = hex("13000000 13000000 0100 13000000 13000000");
+        let mut listing = disassembler.listing(original.clone(), 0x1000);
+        assert_eq!(listing.instructions().len(), 5);
+
+        // Turn the second nop into two c.li: the window grows by one item.
+        let changed = listing.apply_patch(0x1004, &hex("0545 8545")).unwrap();
+        assert_eq!(changed, 1..3);
+        assert_eq!(listing.instructions().len(), 6);
+
+        // A reserved c.addi4spn does not decode and becomes a `.byte` item.
+        let changed = listing.apply_patch(0x1008, &hex("0400")).unwrap();
+        assert_eq!(changed, 3..4);
+
+        let mut patched = original;
+        patched[4..10].copy_from_slice(&hex("0545 8545 0400"));
+        assert_eq!(text(&listing), text(&disassembler.listing(patched, 0x1000)));
+    }
+
+    #[test]
+    fn test_patch_resynchronises_variable_length_code() {
+        let disassembler = Disassembler::new("x86").unwrap();
+        // nop; mov eax, 1; nop; ret
+        let mut listing = disassembler.listing(hex("90 b801000000 90 c3"), 0x1000);
+        assert_eq!(listing.instructions().len(), 4);
+
+        // Overwrite the `mov` opcode with `nop`: its immediate bytes now
+        // decode on their own until the stream lines up at the old `nop`.
+        let changed = listing.apply_patch(0x1001, &[0x90]).unwrap();
+        assert_eq!(listing.instructions()[changed.start].to_string(), "nop");
+        assert_eq!(listing.index_of(0x1006), Some(changed.end));
+        assert_eq!(listing.instructions().last().unwrap().to_string(), "ret");
+
+        let error = listing.apply_patch(0x1007, &[0x90, 0x90]).unwrap_err();
+        assert_eq!(error.stable_kind(), "invalid_address");
+        assert_eq!(listing.bytes()[7], 0xc3);
+    }
+
+    fn hex(text: &str) -> Vec<u8> {
+        let digits: String = text.split_whitespace().collect();
+        (0..digits.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
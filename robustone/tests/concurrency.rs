@@ -0,0 +1,72 @@
+use robustone::{Disassembler, shared_dispatcher};
+use std::sync::Arc;
+use std::thread;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_public_handles_are_thread_safe() {
+    assert_send_sync::<Disassembler>();
+    assert_send_sync::<robustone::ArchitectureDispatcher>();
+    assert_send_sync::<robustone::Instruction>();
+    assert_send_sync::<robustone::DisasmError>();
+}
+
+#[test]
+fn test_threads_share_one_disassembler() {
+    // li a0, 5; ret; addi sp, sp, -16
+    let code = [0x13, 0x05, 0x50, 0x00, 0x82, 0x80, 0x13, 0x01, 0x01, 0xff].repeat(256);
+    let disassembler = Arc::new(Disassembler::new("rv64gc").unwrap());
+    let render = |disassembler: &Disassembler| -> Vec<String> {
+        let instructions = disassembler.disassemble_all(&code, 0x1000).unwrap();
+        instructions.iter().map(ToString::to_string).collect()
+    };
+    let expected = render(&disassembler);
+    assert_eq!(expected.len(), 3 * 256);
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..8)
+            .map(|_| {
+                let disassembler = Arc::clone(&disassembler);
+                scope.spawn(move || render(&disassembler))
+            })
+            .collect();
+        for worker in workers {
+            assert_eq!(worker.join().unwrap(), expected);
+        }
+    });
+}
+
+#[test]
+fn test_threads_decode_different_architectures_at_once() {
+    let jobs: [(&str, &[u8]); 4] = [
+        ("riscv32", &[0x93, 0x00, 0x10, 0x00]),
+        ("aarch64", &[0x1f, 0x20, 0x03, 0xd5]),
+        ("x86-64", &[0x90]),
+        ("loongarch64", &[0x00, 0x00, 0x40, 0x03]),
+    ];
+    let expected: Vec<String> = jobs
+        .iter()
+        .map(|(arch, bytes)| {
+            let (instruction, _) = shared_dispatcher()
+                .disassemble_bytes(bytes, arch, 0)
+                .unwrap();
+            instruction.to_string()
+        })
+        .collect();
+
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            for (&(arch, bytes), expected) in jobs.iter().zip(&expected) {
+                scope.spawn(move || {
+                    for _ in 0..200 {
+                        let (instruction, _) = shared_dispatcher()
+                            .disassemble_bytes(bytes, arch, 0)
+                            .unwrap();
+                        assert_eq!(&instruction.to_string(), expected);
+                    }
+                });
+            }
+        }
+    });
+}
@@ -1,37 +1,169 @@
-use std::process::Command;
+use std::process::{Command, Output};
 
-#[test]
-fn test_json_mode_wraps_cli_validation_failures() {
-    let output = Command::new(env!("CARGO_BIN_EXE_robustone"))
-        .args(["--json", "riscv32", "xyz"])
+fn run_robustone(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_robustone"))
+        .args(args)
         .output()
-        .expect("robustone binary should run");
+        .expect("robustone binary should run")
+}
 
-    assert!(!output.status.success());
-    assert!(output.stderr.is_empty());
+fn stderr_json(output: &Output) -> serde_json::Value {
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr.clone()).expect("stderr should be utf-8");
+    serde_json::from_str(&stderr).expect("stderr should contain JSON")
+}
 
-    let stdout = String::from_utf8(output.stdout).expect("stdout should be utf-8");
-    let parsed: serde_json::Value =
-        serde_json::from_str(&stdout).expect("stdout should contain JSON");
+#[test]
+fn test_json_mode_wraps_cli_validation_failures() {
+    let output = run_robustone(&["--json", "riscv32", "xyz"]);
 
+    assert_eq!(output.status.code(), Some(4));
+    let parsed = stderr_json(&output);
     assert_eq!(parsed["errors"][0]["kind"], "validation_error");
     assert_eq!(parsed["bytes_processed"], 0);
 }
 
 #[test]
 fn test_json_mode_wraps_clap_parse_failures() {
-    let output = Command::new(env!("CARGO_BIN_EXE_robustone"))
-        .args(["--json", "-z"])
-        .output()
-        .expect("robustone binary should run");
+    let output = run_robustone(&["--json", "-z"]);
 
     assert_eq!(output.status.code(), Some(2));
-    assert!(output.stderr.is_empty());
+    let parsed = stderr_json(&output);
+    assert_eq!(parsed["errors"][0]["kind"], "invalid_command");
+    assert_eq!(parsed["bytes_processed"], 0);
+}
 
-    let stdout = String::from_utf8(output.stdout).expect("stdout should be utf-8");
+#[test]
+fn test_format_json_reports_invalid_architecture_exit_code() {
+    let output = run_robustone(&["--format", "json", "bogus", "00"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    let parsed = stderr_json(&output);
+    assert_eq!(parsed["architecture"], "bogus");
+}
+
+#[test]
+fn test_strict_decode_failure_exit_code() {
+    let output = run_robustone(&["--format=json", "riscv32", "ffffffff"]);
+
+    assert_eq!(output.status.code(), Some(5));
+    let parsed = stderr_json(&output);
+    assert_eq!(parsed["errors"][0]["kind"], "invalid_encoding");
+
+    let text = run_robustone(&["riscv32", "ffffffff"]);
+    assert_eq!(text.status.code(), Some(5));
+
+    let skip_data = run_robustone(&["-s", "riscv32", "ffffffff"]);
+    assert_eq!(skip_data.status.code(), Some(0));
+}
+
+#[test]
+fn test_output_flag_redirects_json_listing_to_file() {
+    let path = std::env::temp_dir().join(format!("robustone-json-{}.json", std::process::id()));
+    let path_arg = path.to_str().expect("temp path should be utf-8");
+    let output = run_robustone(&["--json", "-o", path_arg, "riscv32", "93001000"]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+    let written = std::fs::read_to_string(&path).expect("listing file should exist");
+    std::fs::remove_file(&path).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&written).expect("file should be JSON");
+    assert_eq!(parsed["instructions"][0]["mnemonic"], "li");
+}
+
+#[test]
+fn test_output_flag_reports_unwritable_path_as_json_error() {
+    let output = run_robustone(&[
+        "--json",
+        "-o",
+        "/nonexistent-dir/out.json",
+        "riscv32",
+        "93001000",
+    ]);
+
+    assert_eq!(output.status.code(), Some(1));
+    let parsed = stderr_json(&output);
+    assert_eq!(parsed["errors"][0]["kind"], "io_error");
+}
+
+#[test]
+fn test_json_output_is_identical_across_runs() {
+    let args = [
+        "--json",
+        "-r",
+        "riscv64",
+        "9300100013010101ef00000082800000",
+    ];
+    let first = run_robustone(&args);
+    let second = run_robustone(&args);
+
+    assert!(first.status.success());
+    assert!(!first.stdout.is_empty());
+    assert_eq!(first.stdout, second.stdout);
+}
+
+#[test]
+fn test_dry_run_summarizes_without_disassembling() {
+    let output = run_robustone(&["--dry-run", "--json", "riscv64+c", "13054506", "1000"]);
+
+    assert_eq!(output.status.code(), Some(0));
     let parsed: serde_json::Value =
-        serde_json::from_str(&stdout).expect("stdout should contain JSON");
+        serde_json::from_slice(&output.stdout).expect("stdout should contain JSON");
+    assert_eq!(parsed["dry_run"], true);
+    assert_eq!(parsed["architecture"], "riscv64");
+    assert_eq!(parsed["options"][0], "c");
+    assert_eq!(parsed["ranges"][0]["address"], 0x1000);
+    assert_eq!(parsed["bytes"], 4);
+    assert!(parsed.get("instructions").is_none());
 
-    assert_eq!(parsed["errors"][0]["kind"], "invalid_command");
-    assert_eq!(parsed["bytes_processed"], 0);
+    let invalid = run_robustone(&["--dry-run", "riscv32", "13"]);
+    assert_eq!(invalid.status.code(), Some(4));
+    assert!(invalid.stdout.is_empty());
+}
+
+#[test]
+fn test_v_modifier_enables_vector_configuration_instructions() {
+    let output = run_robustone(&["riscv64", "57f5050d"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("requires V extension"));
+
+    let output = run_robustone(&["riscv64+v", "57f5050d"]);
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).expect("stdout should be utf-8");
+    assert!(stdout.contains("vsetvli"), "{stdout}");
+
+    let output = run_robustone(&["--archs"]);
+    let stdout = String::from_utf8(output.stdout).expect("stdout should be utf-8");
+    let riscv64_options = stdout
+        .lines()
+        .skip_while(|line| !line.contains("riscv64"))
+        .find(|line| line.contains("options:"))
+        .expect("--archs should list riscv64 options");
+    assert!(riscv64_options.contains(" +v "), "{riscv64_options}");
+}
+
+#[test]
+fn test_skip_data_lists_bytes_unless_data_directives_are_requested() {
+    let mnemonics = |args: &[&str]| {
+        let output = run_robustone(args);
+        assert!(output.status.success(), "{output:?}");
+        let parsed: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+        parsed["instructions"]
+            .as_array()
+            .expect("instructions should be an array")
+            .iter()
+            .map(|item| item["mnemonic"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let hex = "ffffffff48656c6c6f00";
+    assert_eq!(
+        mnemonics(&["--json", "-s", "riscv32+m", hex]),
+        [".byte", ".byte", ".byte", ".byte", ".half"]
+    );
+    assert_eq!(
+        mnemonics(&["--json", "-s", "--data-directives", "riscv32+m", hex]),
+        [".word", ".ascii", ".half"]
+    );
 }
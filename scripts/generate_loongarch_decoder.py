@@ -219,6 +219,15 @@ rust_code += '''        _ => return Err(DisasmError::decode_failure(
     };
     Ok((mnemonic, operands, 4))
 }
+
+/// Every mnemonic the generated decoder can produce, sorted.
+pub const MNEMONICS: &[&str] = &[
+'''
+
+for mnemonic in sorted({mnemonic for _, mnemonic, _ in decode_entries}):
+    rust_code += f'    "{mnemonic}",\n'
+
+rust_code += '''];
 '''
 
 with open('robustone-loongarch/src/decoder_generated.rs', 'w') as f:
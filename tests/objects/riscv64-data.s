@@ -0,0 +1,5 @@
+# Data-only member of `libfixture.a`; see README.md.
+	.data
+	.globl	counter
+counter:
+	.word	0
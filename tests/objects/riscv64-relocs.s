@@ -0,0 +1,30 @@
+# Relocation fixture for `--object`; see README.md.
+	.text
+	.globl	f
+	.type	f, @function
+f:
+.Lpcrel_hi0:
+	auipc	a0, %pcrel_hi(counter)
+	lw	a1, %pcrel_lo(.Lpcrel_hi0)(a0)
+	addi	a1, a1, 1
+	sw	a1, %pcrel_lo(.Lpcrel_hi0)(a0)
+	lui	a2, %hi(table+8)
+	addi	a2, a2, %lo(table+8)
+	call	g
+	beqz	a1, f
+	ret
+	.size	f, .-f
+
+	.globl	init_uart
+	.type	init_uart, @function
+init_uart:
+	li	a0, 1
+	ret
+	.size	init_uart, .-init_uart
+
+# No `.type`/`.size`, as in hand-written assembly.
+	.globl	init_timer
+init_timer:
+	li	a0, 2
+	li	a1, 3
+	ret